@@ -0,0 +1,133 @@
+/* Things learned during this exercise:
+   * A quiz on the programming concepts chapter - run it with `cargo run`, or
+   `cargo run -- --review-wrong` to be asked only the questions you got wrong
+   last time. Missed question ids are kept in `quiz_missed.json` in the
+   current directory (`--file PATH` to use another).
+   * The question bank is a `const` slice of structs (questions.rs). Struct
+   fields that hold string literals are `&'static str` - references to text
+   that lives in the binary for the whole run.
+   * serde's `#[derive(Serialize, Deserialize)]` writes the code to convert a
+   struct to and from JSON - see quiz.rs. The derive needs the `derive`
+   feature turned on in Cargo.toml.
+   * `rand::seq::SliceRandom` adds `.shuffle()` to slices and Vecs. Traits
+   have to be in scope (`use`d) for their methods to be callable.
+*/
+mod questions;
+mod quiz;
+
+use std::collections::BTreeSet;
+use std::env;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process;
+
+use questions::QUESTIONS;
+
+fn main() {
+    let mut review_wrong = false;
+    let mut path = PathBuf::from("quiz_missed.json");
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--review-wrong" => review_wrong = true,
+            "--file" => match args.next() {
+                Some(value) => path = PathBuf::from(value),
+                None => exit_with("`--file` needs a path."),
+            },
+            other => exit_with(&format!("Unknown argument `{other}`.")),
+        }
+    }
+
+    let missed = quiz::load_missed(&path)
+        .unwrap_or_else(|error| exit_with(&format!("Couldn't read {}: {error}", path.display())));
+    if review_wrong && missed.is_empty() {
+        println!("There are no missed questions to review - well done!");
+        return;
+    }
+
+    let only = review_wrong.then_some(&missed);
+    let selected = quiz::select_questions(QUESTIONS, only, &mut rand::thread_rng());
+    let mut results = Vec::new();
+
+    for (number, question) in selected.iter().enumerate() {
+        println!();
+        println!(
+            "Question {} of {} ({})",
+            number + 1,
+            selected.len(),
+            question.topic
+        );
+        println!("{}", question.prompt);
+        for (index, choice) in question.choices.iter().enumerate() {
+            println!("  {}) {choice}", quiz::choice_label(index));
+        }
+
+        let Some(answer) = read_answer(question.choices.len()) else {
+            println!();
+            println!("Input closed - stopping early.");
+            break;
+        };
+
+        let correct = answer == question.correct;
+        if correct {
+            println!("Correct!");
+        } else {
+            println!(
+                "Not quite - the answer is {}.",
+                quiz::choice_label(question.correct)
+            );
+        }
+        println!("{}", question.explanation);
+        results.push((question.id, correct));
+    }
+
+    let score = results.iter().filter(|(_, correct)| *correct).count();
+    println!();
+    println!(
+        "You scored {score} out of {} - grade {}.",
+        results.len(),
+        quiz::grade(score, results.len())
+    );
+
+    let missed: BTreeSet<u32> = quiz::update_missed(&missed, &results);
+    if let Err(error) = quiz::save_missed(&path, &missed) {
+        eprintln!(
+            "Couldn't save missed questions to {}: {error}",
+            path.display()
+        );
+    } else if !missed.is_empty() {
+        println!(
+            "{} question(s) to review - run with `--review-wrong` to try them again.",
+            missed.len()
+        );
+    }
+}
+
+// Keeps asking until the answer is valid. Returns `None` if stdin closes.
+fn read_answer(choice_count: usize) -> Option<usize> {
+    loop {
+        print!("> ");
+        io::stdout().flush().expect("Failed to flush stdout.");
+
+        let mut line = String::new();
+        let bytes = io::stdin()
+            .read_line(&mut line)
+            .expect("Failed to read answer.");
+        if bytes == 0 {
+            return None;
+        }
+
+        match quiz::parse_answer(&line, choice_count) {
+            Ok(answer) => return Some(answer),
+            Err(message) => println!("{message}"),
+        }
+    }
+}
+
+// `!` is the 'never' type - this function doesn't return, which lets it be
+// used where any type is expected (like the `unwrap_or_else` above).
+fn exit_with(message: &str) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
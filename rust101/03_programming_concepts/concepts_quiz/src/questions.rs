@@ -0,0 +1,290 @@
+// The question bank. Everything here is known at compile time, so the whole
+// bank is a `const` slice - no allocation, and the `&'static str`s point
+// straight into the binary.
+pub struct Question {
+    // Stable identifier written to the missed-questions file. Never reuse an
+    // id for a different question, or old files would point at the wrong one.
+    pub id: u32,
+    pub topic: &'static str,
+    pub prompt: &'static str,
+    pub choices: &'static [&'static str],
+    pub correct: usize,
+    pub explanation: &'static str,
+}
+
+pub const QUESTIONS: &[Question] = &[
+    Question {
+        id: 1,
+        topic: "mutability",
+        prompt: "What happens when compiling:\n    let x = 5;\n    x = 6;",
+        choices: &[
+            "It compiles and x is 6",
+            "It fails: cannot assign twice to immutable variable",
+            "It compiles with a warning",
+            "It panics at runtime",
+        ],
+        correct: 1,
+        explanation: "Variables are immutable by default - `x` needs `let mut` to be reassigned.",
+    },
+    Question {
+        id: 2,
+        topic: "mutability",
+        prompt: "Which declaration allows `y = 12;` later on?",
+        choices: &["let y = 10;", "const y = 10;", "let mut y = 10;", "static y = 10;"],
+        correct: 2,
+        explanation: "Only `let mut` creates a variable that can be reassigned.",
+    },
+    Question {
+        id: 3,
+        topic: "mutability",
+        prompt: "What does this print?\n    let mut y = 10;\n    y += 2;\n    println!(\"{y}\");",
+        choices: &["10", "12", "102", "It doesn't compile"],
+        correct: 1,
+        explanation: "`y` is mutable, so `y += 2` updates it in place to 12.",
+    },
+    Question {
+        id: 4,
+        topic: "mutability",
+        prompt: "Can a `let mut` variable change its type?\n    let mut s = \"   \";\n    s = s.len();",
+        choices: &[
+            "Yes, mut allows any change",
+            "No - mismatched types, expected `&str`, found `usize`",
+            "Only in release builds",
+            "Only if the new type is smaller",
+        ],
+        correct: 1,
+        explanation: "`mut` lets the value change, never the type. Shadowing is needed for that.",
+    },
+    Question {
+        id: 5,
+        topic: "mutability",
+        prompt: "What does the compiler say about\n    let mut x = 5;\n    println!(\"{x}\");",
+        choices: &[
+            "Nothing",
+            "An error: x is never mutated",
+            "A warning: variable does not need to be mutable",
+            "An error: x must have a type",
+        ],
+        correct: 2,
+        explanation: "Unneeded `mut` is a warning (unused_mut), not an error.",
+    },
+    Question {
+        id: 6,
+        topic: "shadowing",
+        prompt: "What does this print?\n    let x = 5;\n    let x = x + 1;\n    println!(\"{x}\");",
+        choices: &["5", "6", "11", "It doesn't compile"],
+        correct: 1,
+        explanation: "The second `let` creates a new `x` from the old one: 5 + 1 = 6.",
+    },
+    Question {
+        id: 7,
+        topic: "shadowing",
+        prompt: "What does this print?\n    let x = 5;\n    {\n        let x = x * 2;\n    }\n    println!(\"{x}\");",
+        choices: &["5", "10", "It doesn't compile", "0"],
+        correct: 0,
+        explanation: "The inner `x` only exists inside the block - afterwards the outer x (5) is visible again.",
+    },
+    Question {
+        id: 8,
+        topic: "shadowing",
+        prompt: "Does this compile?\n    let spaces = \"   \";\n    let spaces = spaces.len();",
+        choices: &[
+            "Yes - the second `spaces` is a new usize variable",
+            "No - mismatched types",
+            "No - spaces is immutable",
+            "Only with `let mut`",
+        ],
+        correct: 0,
+        explanation: "Shadowing creates a new variable, so the type is allowed to change.",
+    },
+    Question {
+        id: 9,
+        topic: "shadowing",
+        prompt: "After `let x = 1; let x = \"one\";` what is the type of x?",
+        choices: &["i32", "&str", "String", "It doesn't compile"],
+        correct: 1,
+        explanation: "The most recent binding wins, and a string literal is a `&str`.",
+    },
+    Question {
+        id: 10,
+        topic: "shadowing",
+        prompt: "What does this print?\n    let x = 2;\n    let x = x * x;\n    let x = x * x;\n    println!(\"{x}\");",
+        choices: &["2", "4", "8", "16"],
+        correct: 3,
+        explanation: "Each line builds on the last: 2, then 4, then 16.",
+    },
+    Question {
+        id: 11,
+        topic: "shadowing",
+        prompt: "Is the shadowed value still in memory after `let x = 5; let x = 6;`?",
+        choices: &[
+            "No, it is overwritten in place",
+            "Yes, until the end of the scope, but it can't be named any more",
+            "It is freed immediately",
+            "It is moved into the new x",
+        ],
+        correct: 1,
+        explanation: "Shadowing doesn't drop the old value early - it just can no longer be referred to by name.",
+    },
+    Question {
+        id: 12,
+        topic: "constants",
+        prompt: "Which is a valid constant declaration?",
+        choices: &[
+            "const MAX = 100;",
+            "const mut MAX: u32 = 100;",
+            "const MAX: u32 = 100;",
+            "let const MAX: u32 = 100;",
+        ],
+        correct: 2,
+        explanation: "Constants always need a type annotation and can never be `mut`.",
+    },
+    Question {
+        id: 13,
+        topic: "constants",
+        prompt: "What is the naming convention for constants?",
+        choices: &["camelCase", "snake_case", "SCREAMING_SNAKE_CASE", "PascalCase"],
+        correct: 2,
+        explanation: "Constants use upper case with underscores, e.g. THREE_HOURS_IN_SECONDS.",
+    },
+    Question {
+        id: 14,
+        topic: "constants",
+        prompt: "When is `const SECONDS: u32 = 60 * 60 * 3;` calculated?",
+        choices: &[
+            "Every time it's used",
+            "At compile time",
+            "When the program starts",
+            "The first time it's used",
+        ],
+        correct: 1,
+        explanation: "Constant expressions are evaluated by the compiler - the binary just contains 10800.",
+    },
+    Question {
+        id: 15,
+        topic: "constants",
+        prompt: "Can a constant be set from a function's runtime result, like `const N: usize = input.len();`?",
+        choices: &[
+            "Yes, always",
+            "No - the value must be computable at compile time",
+            "Only inside main",
+            "Only if the function returns usize",
+        ],
+        correct: 1,
+        explanation: "Constants must be known at compile time; only `const fn`s and constant expressions are allowed.",
+    },
+    Question {
+        id: 16,
+        topic: "constants",
+        prompt: "Where can a `const` be declared?",
+        choices: &[
+            "Only at the top of main",
+            "Only at module level",
+            "In any scope, including the global scope",
+            "Only inside impl blocks",
+        ],
+        correct: 2,
+        explanation: "Constants can be declared in any scope and are valid for the whole of that scope.",
+    },
+    Question {
+        id: 17,
+        topic: "scopes",
+        prompt: "What happens here?\n    {\n        let y = 3;\n    }\n    println!(\"{y}\");",
+        choices: &[
+            "It prints 3",
+            "It prints 0",
+            "It fails: cannot find value `y` in this scope",
+            "It panics",
+        ],
+        correct: 2,
+        explanation: "`y` is dropped at the closing brace, so the name doesn't exist afterwards.",
+    },
+    Question {
+        id: 18,
+        topic: "scopes",
+        prompt: "What does this print?\n    let mut x = 1;\n    {\n        x = 2;\n    }\n    println!(\"{x}\");",
+        choices: &["1", "2", "It doesn't compile", "3"],
+        correct: 1,
+        explanation: "This is assignment, not shadowing - the outer x itself was changed to 2.",
+    },
+    Question {
+        id: 19,
+        topic: "scopes",
+        prompt: "What does this print?\n    let x = 1;\n    let y = {\n        let x = 10;\n        x + 1\n    };\n    println!(\"{x} {y}\");",
+        choices: &["1 11", "10 11", "1 2", "It doesn't compile"],
+        correct: 0,
+        explanation: "The block's inner x is 10, so y is 11 - and the outer x is untouched.",
+    },
+    Question {
+        id: 20,
+        topic: "scopes",
+        prompt: "Why does the block `{ let a = 1; a + 1 }` have a value?",
+        choices: &[
+            "Blocks are expressions - the final expression without `;` is the value",
+            "Because of the `let`",
+            "It doesn't - blocks have no value",
+            "Because `a` is returned implicitly",
+        ],
+        correct: 0,
+        explanation: "Leaving off the semicolon makes `a + 1` the block's value. Adding one would make it `()`.",
+    },
+    Question {
+        id: 21,
+        topic: "scopes",
+        prompt: "What is the value of `let v = { 5; };`?",
+        choices: &["5", "()", "It doesn't compile", "0"],
+        correct: 1,
+        explanation: "`5;` is a statement, so the block has no final expression and evaluates to the unit type `()`.",
+    },
+    Question {
+        id: 22,
+        topic: "mutability",
+        prompt: "Does this compile?\n    let x;\n    x = 5;\n    println!(\"{x}\");",
+        choices: &[
+            "No - x is immutable",
+            "Yes - an immutable variable may be assigned once, later",
+            "No - x needs a type",
+            "Only with `let mut`",
+        ],
+        correct: 1,
+        explanation: "Deferred initialisation is fine: the compiler checks x is assigned exactly once before use.",
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bank_has_at_least_twenty_questions() {
+        assert!(QUESTIONS.len() >= 20);
+    }
+
+    #[test]
+    fn every_question_is_well_formed() {
+        for question in QUESTIONS {
+            assert!(question.choices.len() >= 2, "question {}", question.id);
+            assert!(
+                question.correct < question.choices.len(),
+                "question {}",
+                question.id
+            );
+            assert!(!question.explanation.is_empty());
+        }
+    }
+
+    #[test]
+    fn ids_are_unique() {
+        let mut ids: Vec<u32> = QUESTIONS.iter().map(|q| q.id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), QUESTIONS.len());
+    }
+
+    #[test]
+    fn every_topic_is_covered() {
+        for topic in ["mutability", "shadowing", "constants", "scopes"] {
+            assert!(QUESTIONS.iter().any(|q| q.topic == topic), "{topic}");
+        }
+    }
+}
@@ -0,0 +1,213 @@
+// The quiz logic, kept apart from the terminal I/O in main.rs so it can be
+// tested: which questions to ask, how to read an answer, how to grade the
+// result and how the missed questions are saved between runs.
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::questions::Question;
+
+// Taking the random number generator as a parameter (rather than calling
+// `rand::thread_rng()` in here) lets the tests pass in a seeded one and get
+// the same 'random' order every time.
+pub fn select_questions<'a, R: Rng>(
+    bank: &'a [Question],
+    only: Option<&BTreeSet<u32>>,
+    rng: &mut R,
+) -> Vec<&'a Question> {
+    let mut selected: Vec<&Question> = bank
+        .iter()
+        .filter(|question| only.is_none_or(|ids| ids.contains(&question.id)))
+        .collect();
+    selected.shuffle(rng);
+    selected
+}
+
+// Accepts a letter (`b`, `B`) or a number (`2`), returning the zero-based
+// choice index.
+pub fn parse_answer(input: &str, choice_count: usize) -> Result<usize, String> {
+    let input = input.trim();
+    let index = match input.chars().collect::<Vec<char>>()[..] {
+        [letter] if letter.is_ascii_alphabetic() => {
+            (letter.to_ascii_lowercase() as u8 - b'a') as usize
+        }
+        _ => match input.parse::<usize>() {
+            Ok(number) if number >= 1 => number - 1,
+            _ => {
+                return Err(format!(
+                    "`{input}` isn't an answer - type a letter or number."
+                ))
+            }
+        },
+    };
+
+    if index < choice_count {
+        Ok(index)
+    } else {
+        Err(format!("There are only {choice_count} choices."))
+    }
+}
+
+pub fn choice_label(index: usize) -> char {
+    (b'a' + index as u8) as char
+}
+
+pub fn grade(correct: usize, total: usize) -> &'static str {
+    if total == 0 {
+        return "n/a";
+    }
+    // Integer maths on purpose: 9 out of 10 is exactly 90, not 89.99999.
+    match correct * 100 / total {
+        90.. => "A",
+        80..=89 => "B",
+        70..=79 => "C",
+        60..=69 => "D",
+        _ => "F",
+    }
+}
+
+// Questions answered correctly leave the missed set, wrong ones join it, and
+// anything not asked this time is left alone.
+pub fn update_missed(previous: &BTreeSet<u32>, results: &[(u32, bool)]) -> BTreeSet<u32> {
+    let mut missed = previous.clone();
+    for (id, was_correct) in results {
+        if *was_correct {
+            missed.remove(id);
+        } else {
+            missed.insert(*id);
+        }
+    }
+    missed
+}
+
+// The on-disk format: `{"missed": [4, 9]}`.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MissedFile {
+    pub missed: BTreeSet<u32>,
+}
+
+// A missing file just means no questions have been missed yet.
+pub fn load_missed(path: &Path) -> io::Result<BTreeSet<u32>> {
+    match fs::read_to_string(path) {
+        Ok(text) => {
+            let file: MissedFile = serde_json::from_str(&text)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            Ok(file.missed)
+        }
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(BTreeSet::new()),
+        Err(error) => Err(error),
+    }
+}
+
+pub fn save_missed(path: &Path, missed: &BTreeSet<u32>) -> io::Result<()> {
+    let file = MissedFile {
+        missed: missed.clone(),
+    };
+    let text = serde_json::to_string_pretty(&file).expect("a set of numbers always serializes");
+    fs::write(path, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::questions::QUESTIONS;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn selects_every_question_once() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let selected = select_questions(QUESTIONS, None, &mut rng);
+        assert_eq!(selected.len(), QUESTIONS.len());
+
+        let mut ids: Vec<u32> = selected.iter().map(|q| q.id).collect();
+        ids.sort();
+        let expected: Vec<u32> = QUESTIONS.iter().map(|q| q.id).collect();
+        assert_eq!(ids, expected);
+    }
+
+    #[test]
+    fn same_seed_gives_same_order() {
+        let order = |seed| -> Vec<u32> {
+            let mut rng = StdRng::seed_from_u64(seed);
+            select_questions(QUESTIONS, None, &mut rng)
+                .iter()
+                .map(|q| q.id)
+                .collect()
+        };
+        assert_eq!(order(1), order(1));
+        assert_ne!(order(1), order(2));
+    }
+
+    #[test]
+    fn review_mode_only_selects_missed_questions() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let missed = BTreeSet::from([3, 17, 999]);
+        let mut ids: Vec<u32> = select_questions(QUESTIONS, Some(&missed), &mut rng)
+            .iter()
+            .map(|q| q.id)
+            .collect();
+        ids.sort();
+        // 999 no longer exists in the bank, so it's quietly skipped.
+        assert_eq!(ids, [3, 17]);
+    }
+
+    #[test]
+    fn parses_letters_and_numbers() {
+        assert_eq!(parse_answer("a", 4), Ok(0));
+        assert_eq!(parse_answer(" C \n", 4), Ok(2));
+        assert_eq!(parse_answer("4", 4), Ok(3));
+        assert!(parse_answer("e", 4).is_err());
+        assert!(parse_answer("0", 4).is_err());
+        assert!(parse_answer("5", 4).is_err());
+        assert!(parse_answer("", 4).is_err());
+        assert!(parse_answer("ab", 4).is_err());
+    }
+
+    #[test]
+    fn grade_boundaries() {
+        assert_eq!(grade(10, 10), "A");
+        assert_eq!(grade(9, 10), "A");
+        assert_eq!(grade(8, 10), "B");
+        assert_eq!(grade(7, 10), "C");
+        assert_eq!(grade(6, 10), "D");
+        assert_eq!(grade(5, 10), "F");
+        assert_eq!(grade(0, 10), "F");
+        assert_eq!(grade(0, 0), "n/a");
+    }
+
+    #[test]
+    fn missed_set_is_updated_by_results() {
+        let previous = BTreeSet::from([1, 2, 3]);
+        let results = [(1, true), (4, false), (2, false)];
+        assert_eq!(
+            update_missed(&previous, &results),
+            BTreeSet::from([2, 3, 4])
+        );
+    }
+
+    #[test]
+    fn missed_file_round_trips() {
+        let path = std::env::temp_dir().join(format!("quiz_missed_{}.json", std::process::id()));
+        let missed = BTreeSet::from([5, 12]);
+        save_missed(&path, &missed).unwrap();
+        assert_eq!(load_missed(&path).unwrap(), missed);
+        fs::remove_file(&path).unwrap();
+
+        // Once it's gone it reads as empty rather than an error.
+        assert!(load_missed(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn corrupt_missed_file_is_an_error() {
+        let path = std::env::temp_dir().join(format!("quiz_corrupt_{}.json", std::process::id()));
+        fs::write(&path, "not json").unwrap();
+        assert!(load_missed(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
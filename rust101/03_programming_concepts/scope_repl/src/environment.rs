@@ -0,0 +1,244 @@
+// The scope stack. Each `{` pushes a new, empty HashMap and each `}` pops it,
+// throwing away every binding made inside the block - which is exactly why a
+// shadowed variable 'comes back' after the block ends. Lookups search from the
+// innermost scope outwards, so the most recent binding of a name wins.
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::parser::{Expression, Operand, Operator, Statement};
+
+pub struct Environment {
+    scopes: Vec<HashMap<String, i64>>,
+}
+
+// What a statement did, so the REPL can describe it.
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Bound {
+        name: String,
+        value: i64,
+        shadowed: bool,
+    },
+    Printed {
+        name: String,
+        value: i64,
+    },
+    Opened,
+    Closed {
+        dropped: Vec<String>,
+    },
+}
+
+impl Environment {
+    pub fn new() -> Environment {
+        // The outermost scope is never popped - it plays the role of the body
+        // of `main`.
+        Environment {
+            scopes: vec![HashMap::new()],
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.scopes.len()
+    }
+
+    pub fn lookup(&self, name: &str) -> Option<i64> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).copied())
+    }
+
+    pub fn execute(&mut self, statement: Statement) -> Result<Outcome, String> {
+        match statement {
+            Statement::Let { name, value } => {
+                // The value is evaluated BEFORE the new binding exists, which
+                // is what lets `let x = x + 1;` read the previous `x`.
+                let value = self.evaluate(&value)?;
+                let shadowed = self.lookup(&name).is_some();
+                self.innermost().insert(name.clone(), value);
+                Ok(Outcome::Bound {
+                    name,
+                    value,
+                    shadowed,
+                })
+            }
+            Statement::Print(name) => {
+                let value = self.lookup(&name).ok_or_else(|| not_found(&name))?;
+                Ok(Outcome::Printed { name, value })
+            }
+            Statement::OpenScope => {
+                self.scopes.push(HashMap::new());
+                Ok(Outcome::Opened)
+            }
+            Statement::CloseScope => {
+                if self.scopes.len() == 1 {
+                    return Err("Unexpected `}` - there is no open block to close.".to_string());
+                }
+                let scope = self.scopes.pop().expect("checked above");
+                let mut dropped: Vec<String> = scope.into_keys().collect();
+                dropped.sort();
+                Ok(Outcome::Closed { dropped })
+            }
+        }
+    }
+
+    fn innermost(&mut self) -> &mut HashMap<String, i64> {
+        self.scopes
+            .last_mut()
+            .expect("the outer scope is never popped")
+    }
+
+    fn evaluate(&self, expression: &Expression) -> Result<i64, String> {
+        let mut total = self.operand(&expression.first)?;
+        for (operator, operand) in &expression.rest {
+            let value = self.operand(operand)?;
+            // Checked arithmetic - a panic in the middle of a REPL session
+            // would throw away everything the user had typed.
+            total = match operator {
+                Operator::Add => total.checked_add(value),
+                Operator::Sub => total.checked_sub(value),
+                Operator::Mul => total.checked_mul(value),
+            }
+            .ok_or("That calculation overflows an i64.")?;
+        }
+        Ok(total)
+    }
+
+    fn operand(&self, operand: &Operand) -> Result<i64, String> {
+        match operand {
+            Operand::Number(number) => Ok(*number),
+            Operand::Name(name) => self.lookup(name).ok_or_else(|| not_found(name)),
+        }
+    }
+}
+
+// The same wording rustc uses for error E0425.
+fn not_found(name: &str) -> String {
+    format!("error[E0425]: cannot find value `{name}` in this scope")
+}
+
+// Prints every scope from the outside in, marking bindings that are hidden by
+// a binding of the same name in an inner scope.
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (depth, scope) in self.scopes.iter().enumerate() {
+            // HashMap iteration order is random, so sort for stable output.
+            let mut names: Vec<&String> = scope.keys().collect();
+            names.sort();
+
+            let bindings: Vec<String> = names
+                .into_iter()
+                .map(|name| {
+                    let hidden = self.scopes[depth + 1..]
+                        .iter()
+                        .any(|inner| inner.contains_key(name));
+                    let marker = if hidden { " (shadowed)" } else { "" };
+                    format!("{name} = {}{marker}", scope[name])
+                })
+                .collect();
+
+            let indent = "  ".repeat(depth);
+            if bindings.is_empty() {
+                writeln!(f, "{indent}scope {depth}: {{}}")?;
+            } else {
+                writeln!(f, "{indent}scope {depth}: {{ {} }}", bindings.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_statement;
+
+    fn run(environment: &mut Environment, line: &str) -> Result<Outcome, String> {
+        environment.execute(parse_statement(line)?)
+    }
+
+    #[test]
+    fn shadowing_inside_a_block_reverts_at_the_closing_brace() {
+        let mut environment = Environment::new();
+        run(&mut environment, "let x = 5;").unwrap();
+        run(&mut environment, "{").unwrap();
+        run(&mut environment, "let x = 10;").unwrap();
+        assert_eq!(environment.lookup("x"), Some(10));
+
+        let outcome = run(&mut environment, "}").unwrap();
+        assert_eq!(
+            outcome,
+            Outcome::Closed {
+                dropped: vec!["x".to_string()]
+            }
+        );
+        assert_eq!(environment.lookup("x"), Some(5));
+    }
+
+    #[test]
+    fn shadowing_uses_the_previous_value() {
+        let mut environment = Environment::new();
+        run(&mut environment, "let x = 5;").unwrap();
+        let outcome = run(&mut environment, "let x = x + 1;").unwrap();
+        assert_eq!(
+            outcome,
+            Outcome::Bound {
+                name: "x".to_string(),
+                value: 6,
+                shadowed: true
+            }
+        );
+        run(&mut environment, "let x = x * 2;").unwrap();
+        assert_eq!(environment.lookup("x"), Some(12));
+    }
+
+    #[test]
+    fn block_can_read_outer_bindings() {
+        let mut environment = Environment::new();
+        run(&mut environment, "let x = 5;").unwrap();
+        run(&mut environment, "{").unwrap();
+        run(&mut environment, "let y = x - 1;").unwrap();
+        assert_eq!(environment.lookup("y"), Some(4));
+        run(&mut environment, "}").unwrap();
+        assert_eq!(environment.lookup("y"), None);
+    }
+
+    #[test]
+    fn referencing_an_undefined_name_is_an_error() {
+        let mut environment = Environment::new();
+        assert_eq!(
+            run(&mut environment, "print y;"),
+            Err("error[E0425]: cannot find value `y` in this scope".to_string())
+        );
+        assert!(run(&mut environment, "let x = y + 1;").is_err());
+        // A failed statement must not leave a binding behind.
+        assert_eq!(environment.lookup("x"), None);
+    }
+
+    #[test]
+    fn closing_the_outer_scope_is_an_error() {
+        let mut environment = Environment::new();
+        assert!(run(&mut environment, "}").is_err());
+        assert_eq!(environment.depth(), 1);
+    }
+
+    #[test]
+    fn overflow_is_an_error_not_a_panic() {
+        let mut environment = Environment::new();
+        run(&mut environment, "let big = 9223372036854775807;").unwrap();
+        assert!(run(&mut environment, "let big = big + 1;").is_err());
+    }
+
+    #[test]
+    fn display_marks_shadowed_bindings() {
+        let mut environment = Environment::new();
+        run(&mut environment, "let x = 5;").unwrap();
+        run(&mut environment, "{").unwrap();
+        run(&mut environment, "let x = 6;").unwrap();
+        assert_eq!(
+            environment.to_string(),
+            "scope 0: { x = 5 (shadowed) }\n  scope 1: { x = 6 }\n"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+/* Things learned during this exercise:
+   * This is a tiny interpreter for a Rust-like language with only `let`,
+   `print`, `{` and `}`. It prints the scopes after every statement, which
+   makes it easy to watch shadowing happen - try:
+       let x = 5;
+       let x = x + 1;
+       {
+       let x = x * 2;
+       print x;
+       }
+       print x;
+   and compare with the book's example at
+   https://doc.rust-lang.org/book/ch03-01-variables-and-mutability.html#shadowing
+   * The scopes are a `Vec<HashMap<String, i64>>` used as a stack - see
+   environment.rs. Rust's real compiler resolves names at compile time rather
+   than keeping maps around at runtime, but the visible rules are the same.
+   * `HashMap` has to be imported with `use std::collections::HashMap;` - it
+   isn't part of the prelude.
+   * Errors are plain `String`s here. `Result<T, String>` plus the `?`
+   operator is enough for a small program - later exercises use custom error
+   types.
+*/
+mod environment;
+mod parser;
+
+use std::io::{self, Write};
+
+use environment::{Environment, Outcome};
+
+fn main() {
+    let mut environment = Environment::new();
+    println!("Type statements like `let x = 5;`, `{{`, `}}` or `print x;`. Type `quit` to exit.");
+
+    loop {
+        // The prompt shows how many blocks are open, like an indented editor.
+        print!("{}> ", "  ".repeat(environment.depth() - 1));
+        io::stdout().flush().expect("Failed to flush stdout.");
+
+        let mut line = String::new();
+        let bytes = io::stdin()
+            .read_line(&mut line)
+            .expect("Failed to read line.");
+        if bytes == 0 || line.trim() == "quit" {
+            break;
+        }
+
+        let result = parser::parse_statement(&line).and_then(|s| environment.execute(s));
+        match result {
+            Ok(outcome) => describe(&outcome),
+            Err(message) => {
+                println!("{message}");
+                continue; // Nothing changed, so don't reprint the scopes.
+            }
+        }
+        print!("{environment}");
+    }
+
+    if environment.depth() > 1 {
+        println!(
+            "Note: {} block(s) were never closed.",
+            environment.depth() - 1
+        );
+    }
+}
+
+fn describe(outcome: &Outcome) {
+    match outcome {
+        Outcome::Bound {
+            name,
+            value,
+            shadowed: true,
+        } => println!("{name} = {value} (shadows the previous `{name}`)"),
+        Outcome::Bound { name, value, .. } => println!("{name} = {value}"),
+        Outcome::Printed { name, value } => println!("The value of {name} is {value}."),
+        Outcome::Opened => println!("Opened a new scope."),
+        Outcome::Closed { dropped } if dropped.is_empty() => println!("Closed the scope."),
+        Outcome::Closed { dropped } => {
+            println!("Closed the scope, dropping: {}.", dropped.join(", "))
+        }
+    }
+}
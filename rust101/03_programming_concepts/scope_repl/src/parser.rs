@@ -0,0 +1,183 @@
+// Turns one line of input into a `Statement`. The grammar is tiny:
+//
+//   statement  = "let" name "=" expression ";" | "print" name ";" | "{" | "}"
+//   expression = operand (("+" | "-" | "*") operand)*
+//   operand    = integer | name
+//
+// Operators are applied left to right with no precedence, so `1 + 2 * 3` is 9.
+// That keeps the parser short - the interesting part of this exercise is the
+// scope handling, not the arithmetic.
+
+#[derive(Debug, PartialEq)]
+pub enum Statement {
+    Let { name: String, value: Expression },
+    Print(String),
+    OpenScope,
+    CloseScope,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Expression {
+    pub first: Operand,
+    pub rest: Vec<(Operator, Operand)>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Operand {
+    Number(i64),
+    Name(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+}
+
+pub fn parse_statement(line: &str) -> Result<Statement, String> {
+    let line = line.trim();
+
+    match line {
+        "{" => return Ok(Statement::OpenScope),
+        "}" => return Ok(Statement::CloseScope),
+        "" => return Err("Empty line - type a statement or `quit`.".to_string()),
+        _ => {}
+    }
+
+    // Every other statement must end with a semicolon, just like in Rust.
+    let Some(body) = line.strip_suffix(';') else {
+        return Err(format!("Expected `;` at the end of `{line}`."));
+    };
+    let tokens: Vec<&str> = body.split_whitespace().collect();
+
+    match tokens.as_slice() {
+        ["let", name, "=", expression @ ..] => {
+            let name = parse_name(name)?;
+            if expression.is_empty() {
+                return Err(format!("Expected a value after `let {name} =`."));
+            }
+            Ok(Statement::Let {
+                name,
+                value: parse_expression(expression)?,
+            })
+        }
+        ["let", name, ..] => Err(format!(
+            "Expected `=` after `let {name}` (put spaces around `=` and operators)."
+        )),
+        ["let"] => Err("Expected a name after `let`.".to_string()),
+        ["print", name] => Ok(Statement::Print(parse_name(name)?)),
+        ["print", ..] => Err("`print` takes exactly one name, e.g. `print x;`.".to_string()),
+        _ => Err(format!(
+            "Unknown statement `{line}` - expected `let`, `print`, `{{` or `}}`."
+        )),
+    }
+}
+
+fn parse_expression(tokens: &[&str]) -> Result<Expression, String> {
+    let first = parse_operand(tokens[0])?;
+    let mut rest = Vec::new();
+
+    // `chunks` hands us the remaining tokens two at a time: an operator and the
+    // operand that follows it. A trailing operator gives a chunk of length 1.
+    for pair in tokens[1..].chunks(2) {
+        let operator = match pair[0] {
+            "+" => Operator::Add,
+            "-" => Operator::Sub,
+            "*" => Operator::Mul,
+            other => return Err(format!("Expected `+`, `-` or `*` but found `{other}`.")),
+        };
+        let Some(operand) = pair.get(1) else {
+            return Err(format!("Expected a value after `{}`.", pair[0]));
+        };
+        rest.push((operator, parse_operand(operand)?));
+    }
+
+    Ok(Expression { first, rest })
+}
+
+fn parse_operand(token: &str) -> Result<Operand, String> {
+    if let Ok(number) = token.parse() {
+        Ok(Operand::Number(number))
+    } else {
+        parse_name(token).map(Operand::Name)
+    }
+}
+
+// Names follow Rust's rules (minus Unicode): a letter or underscore, then
+// letters, digits or underscores.
+fn parse_name(token: &str) -> Result<String, String> {
+    let mut chars = token.chars();
+    let valid_start = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+    let valid_rest = chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if valid_start && valid_rest && token != "let" && token != "print" {
+        Ok(token.to_string())
+    } else {
+        Err(format!("`{token}` isn't a valid variable name."))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_let_with_a_literal() {
+        assert_eq!(
+            parse_statement("let x = 5;"),
+            Ok(Statement::Let {
+                name: "x".to_string(),
+                value: Expression {
+                    first: Operand::Number(5),
+                    rest: vec![],
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn parses_let_using_the_previous_value() {
+        assert_eq!(
+            parse_statement("let x = x + 1;"),
+            Ok(Statement::Let {
+                name: "x".to_string(),
+                value: Expression {
+                    first: Operand::Name("x".to_string()),
+                    rest: vec![(Operator::Add, Operand::Number(1))],
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn parses_braces_and_print() {
+        assert_eq!(parse_statement(" { "), Ok(Statement::OpenScope));
+        assert_eq!(parse_statement("}"), Ok(Statement::CloseScope));
+        assert_eq!(
+            parse_statement("print x;"),
+            Ok(Statement::Print("x".to_string()))
+        );
+    }
+
+    #[test]
+    fn reports_missing_semicolon() {
+        assert_eq!(
+            parse_statement("let x = 5"),
+            Err("Expected `;` at the end of `let x = 5`.".to_string())
+        );
+    }
+
+    #[test]
+    fn reports_malformed_statements() {
+        assert!(parse_statement("let 1x = 5;").is_err());
+        assert!(parse_statement("let x 5;").is_err());
+        assert!(parse_statement("let x = ;").is_err());
+        assert!(parse_statement("let x = 1 +;").is_err());
+        assert!(parse_statement("let x = 1 / 2;").is_err());
+        assert!(parse_statement("print;").is_err());
+        assert!(parse_statement("x = 5;").is_err());
+    }
+}
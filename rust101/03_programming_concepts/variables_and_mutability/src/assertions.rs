@@ -0,0 +1,26 @@
+// Compile-time assertions shared by the sections.
+//
+// `static_assert!(condition, "message")` expands to
+// `const _: () = assert!(condition, "message");`. A const has to be evaluated
+// while compiling, so if the condition is false the build fails with the
+// message:
+//
+//     error[E0080]: evaluation panicked: THREE_HOURS_IN_SECONDS must be 10,800
+//
+// Naming the const `_` means the macro can be used any number of times in the
+// same module without the names clashing, and the condition can use anything
+// that works in a const context - `const` items, `const fn` calls and
+// `size_of`.
+macro_rules! static_assert {
+    ($condition:expr $(,)?) => {
+        const _: () = assert!($condition);
+    };
+    ($condition:expr, $message:literal $(,)?) => {
+        const _: () = assert!($condition, $message);
+    };
+}
+
+// `macro_rules!` macros can only be used below where they're defined, unless
+// they're re-exported like this - then other modules can
+// `use crate::assertions::static_assert;` just like any other item.
+pub(crate) use static_assert;
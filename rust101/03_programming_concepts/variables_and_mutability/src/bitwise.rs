@@ -0,0 +1,340 @@
+/*
+   * Bitwise operators
+     * `&` (AND), `|` (OR), `^` (XOR) work on each pair of bits independently.
+     `!` flips every bit - Rust uses `!` for this rather than C's `~`, because
+     there's no separate logical-not for integers.
+     * `<<` shifts bits left, filling with zeros: `1 << 4` is 16.
+     * `>>` shifts right. For unsigned types it fills with zeros (logical
+     shift), for signed types it copies the sign bit (arithmetic shift), so
+     `-16i32 >> 2` is -4 rather than a huge positive number.
+     * Shifting by the bit width or more (`1u32 << 32`) is an error: the
+     compiler rejects it when it can see the values ("this arithmetic
+     operation will overflow"), and debug builds panic when it can't.
+     `checked_shl`/`checked_shr` return `None` instead.
+     * `{:b}` formats a number in binary and `{:032b}` pads it to 32 digits.
+*/
+use std::io::{self, Write};
+
+use crate::input;
+use crate::Options;
+
+// Renders the 32 bits of `value`, most significant first, in groups of four.
+fn bits(value: u32) -> String {
+    let digits = format!("{value:032b}");
+    let nibbles: Vec<&str> = (0..32).step_by(4).map(|i| &digits[i..i + 4]).collect();
+    nibbles.join(" ")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BitOp {
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr,
+}
+
+impl BitOp {
+    fn symbol(self) -> &'static str {
+        match self {
+            BitOp::And => "&",
+            BitOp::Or => "|",
+            BitOp::Xor => "^",
+            BitOp::Shl => "<<",
+            BitOp::Shr => ">>",
+        }
+    }
+}
+
+// A value is interpreted as an i32 if it was typed with a minus sign (or
+// results from one), and as a u32 otherwise. Either way the bits are stored
+// as a u32.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Value {
+    bits: u32,
+    signed: bool,
+}
+
+impl Value {
+    fn display(self) -> String {
+        if self.signed {
+            (self.bits as i32).to_string()
+        } else {
+            self.bits.to_string()
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Expr {
+    Not(Value),
+    Binary(Value, BitOp, Value),
+}
+
+fn parse_value(text: &str) -> Result<Value, String> {
+    if text.starts_with('-') {
+        text.parse::<i32>()
+            .map(|n| Value {
+                bits: n as u32,
+                signed: true,
+            })
+            .map_err(|_| format!("`{text}` isn't an i32."))
+    } else {
+        text.parse::<u32>()
+            .map(|n| Value {
+                bits: n,
+                signed: false,
+            })
+            .map_err(|_| format!("`{text}` isn't a u32."))
+    }
+}
+
+fn parse_expr(line: &str) -> Result<Expr, String> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    match tokens[..] {
+        [operand] if operand.starts_with('!') && operand.len() > 1 => {
+            Ok(Expr::Not(parse_value(&operand[1..])?))
+        }
+        ["!", operand] => Ok(Expr::Not(parse_value(operand)?)),
+        [a, op, b] => {
+            let op = match op {
+                "&" => BitOp::And,
+                "|" => BitOp::Or,
+                "^" => BitOp::Xor,
+                "<<" => BitOp::Shl,
+                ">>" => BitOp::Shr,
+                other => return Err(format!("`{other}` isn't one of & | ^ << >>.")),
+            };
+            Ok(Expr::Binary(parse_value(a)?, op, parse_value(b)?))
+        }
+        _ => Err("Expected `a OP b` (with spaces) or `!a`.".to_string()),
+    }
+}
+
+fn evaluate(expr: &Expr) -> Result<Value, String> {
+    match *expr {
+        Expr::Not(value) => Ok(Value {
+            bits: !value.bits,
+            signed: value.signed,
+        }),
+        Expr::Binary(a, op, b) => {
+            // For bitwise ops on mixed operands, Rust needs both to be the
+            // same type - here signedness 'wins' as if the u32 were cast.
+            let signed = a.signed || (b.signed && !matches!(op, BitOp::Shl | BitOp::Shr));
+            let bits = match op {
+                BitOp::And => a.bits & b.bits,
+                BitOp::Or => a.bits | b.bits,
+                BitOp::Xor => a.bits ^ b.bits,
+                BitOp::Shl | BitOp::Shr => shift(a, op, b)?,
+            };
+            Ok(Value { bits, signed })
+        }
+    }
+}
+
+fn shift(value: Value, op: BitOp, amount: Value) -> Result<u32, String> {
+    // The same wording rustc uses when it spots this at compile time.
+    let direction = if op == BitOp::Shl { "left" } else { "right" };
+    let suffix = if amount.signed { "i32" } else { "u32" };
+    let overflow = || {
+        format!("this arithmetic operation will overflow: attempt to shift {direction} by `{}_{suffix}`, which would overflow", amount.display())
+    };
+
+    if amount.signed && (amount.bits as i32) < 0 {
+        return Err(overflow());
+    }
+    let result = match (op, value.signed) {
+        (BitOp::Shl, _) => value.bits.checked_shl(amount.bits),
+        // Arithmetic shift: do it on an i32 so the sign bit is copied.
+        (_, true) => (value.bits as i32)
+            .checked_shr(amount.bits)
+            .map(|n| n as u32),
+        (_, false) => value.bits.checked_shr(amount.bits),
+    };
+    result.ok_or_else(overflow)
+}
+
+// Lines everything up under one another, e.g. for `12 & 10`:
+//
+//      12  0000 0000 0000 0000 0000 0000 0000 1100
+//   &  10  0000 0000 0000 0000 0000 0000 0000 1010
+//          ---------------------------------------
+//   =   8  0000 0000 0000 0000 0000 0000 0000 1000
+//                                             &
+//
+// The last row annotates each column - for `&` it marks where both bits were
+// 1, for `|` where either was, for `^` where they differed. Shifts are shown
+// with the amount as a plain number, since its bits aren't combined.
+fn render(expr: &Expr) -> Result<String, String> {
+    let result = evaluate(expr)?;
+    let operands: Vec<(&str, Value)> = match expr {
+        Expr::Not(value) => vec![("!", *value)],
+        Expr::Binary(a, op, b) => vec![("", *a), (op.symbol(), *b)],
+    };
+
+    let width = operands
+        .iter()
+        .map(|(_, value)| value.display().len())
+        .chain([result.display().len()])
+        .max()
+        .unwrap_or(0);
+    let shifting = matches!(expr, Expr::Binary(_, BitOp::Shl | BitOp::Shr, _));
+
+    let mut lines = Vec::new();
+    for (index, (symbol, value)) in operands.iter().enumerate() {
+        let pattern = if shifting && index == 1 {
+            format!("(shift by {})", value.display())
+        } else {
+            bits(value.bits)
+        };
+        lines.push(format!(
+            "{symbol:<2} {:>width$}  {pattern}",
+            value.display()
+        ));
+    }
+    let padding = " ".repeat(width + 5);
+    lines.push(format!("{padding}{}", "-".repeat(39)));
+    lines.push(format!(
+        "{:<2} {:>width$}  {}",
+        "=",
+        result.display(),
+        bits(result.bits)
+    ));
+
+    if let Expr::Binary(a, op @ (BitOp::And | BitOp::Or | BitOp::Xor), b) = expr {
+        let marks: String = bits(a.bits)
+            .chars()
+            .zip(bits(b.bits).chars())
+            .map(|(x, y)| match (x, y) {
+                (' ', _) => ' ',
+                ('1', '1') if *op != BitOp::Xor => op.symbol().chars().next().unwrap(),
+                ('1', _) | (_, '1') if *op != BitOp::And && x != y => {
+                    op.symbol().chars().next().unwrap()
+                }
+                _ => ' ',
+            })
+            .collect();
+        lines.push(format!("{padding}{}", marks.trim_end()));
+    }
+
+    Ok(lines.join("\n"))
+}
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    for line in [
+        "12 & 10",
+        "12 | 10",
+        "12 ^ 10",
+        "!0",
+        "1 << 4",
+        "-16 >> 2",
+        "4294967280 >> 2",
+    ] {
+        writeln!(out, "{line}")?;
+        writeln!(
+            out,
+            "{}",
+            render(&parse_expr(line).expect("valid example")).expect("no overflow")
+        )?;
+        writeln!(out)?;
+    }
+    writeln!(
+        out,
+        "1 << 32 gives: {}",
+        render(&parse_expr("1 << 32").unwrap()).unwrap_err()
+    )?;
+
+    if options.interactive {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Enter an expression like `12 & 10`, `-16 >> 2` or `!5`."
+        )?;
+        while let Some(line) = input::read_line("> ") {
+            match parse_expr(&line).and_then(|expr| render(&expr)) {
+                Ok(rendered) => writeln!(out, "{rendered}")?,
+                Err(message) => writeln!(out, "{message}")?,
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(line: &str) -> Result<String, String> {
+        evaluate(&parse_expr(line)?).map(Value::display)
+    }
+
+    #[test]
+    fn renders_bits_in_nibbles() {
+        assert_eq!(bits(12), "0000 0000 0000 0000 0000 0000 0000 1100");
+        assert_eq!(bits(u32::MAX), "1111 1111 1111 1111 1111 1111 1111 1111");
+        assert_eq!(bits(0x8000_0001), "1000 0000 0000 0000 0000 0000 0000 0001");
+    }
+
+    #[test]
+    fn rendered_rows_line_up() {
+        let rendered = render(&parse_expr("4000000000 ^ 7").unwrap()).unwrap();
+        let lines: Vec<&str> = rendered.lines().collect();
+        // Every bit pattern, and the rule between them, starts in the same
+        // column - however wide the numbers in front of them are.
+        let column = lines[0].len() - bits(0).len();
+        assert_eq!(&lines[0][column..], bits(4_000_000_000));
+        assert_eq!(&lines[1][column..], bits(7));
+        assert_eq!(&lines[2][column..], "-".repeat(39));
+        assert_eq!(&lines[3][column..], bits(4_000_000_000 ^ 7));
+        assert!(lines[2][..column].trim().is_empty());
+    }
+
+    #[test]
+    fn annotation_marks_the_right_columns() {
+        let rendered = render(&parse_expr("12 & 10").unwrap()).unwrap();
+        let marks = rendered.lines().last().unwrap();
+        let result = rendered.lines().nth(3).unwrap();
+        // The only `&` mark sits under the only 1 in the result.
+        assert_eq!(marks.find('&'), result.rfind('1'));
+        assert_eq!(marks.matches('&').count(), 1);
+
+        let rendered = render(&parse_expr("12 ^ 10").unwrap()).unwrap();
+        assert_eq!(rendered.lines().last().unwrap().matches('^').count(), 2);
+    }
+
+    #[test]
+    fn evaluates_operations() {
+        assert_eq!(eval("12 & 10"), Ok("8".to_string()));
+        assert_eq!(eval("12 | 10"), Ok("14".to_string()));
+        assert_eq!(eval("12 ^ 10"), Ok("6".to_string()));
+        assert_eq!(eval("!0"), Ok(u32::MAX.to_string()));
+        assert_eq!(eval("! -1"), Ok("0".to_string()));
+        assert_eq!(eval("1 << 4"), Ok("16".to_string()));
+        assert_eq!(eval("1 << 31"), Ok("2147483648".to_string()));
+        assert_eq!(eval("256 >> 4"), Ok("16".to_string()));
+    }
+
+    #[test]
+    fn signed_right_shift_copies_the_sign_bit() {
+        assert_eq!(eval("-16 >> 2"), Ok("-4".to_string()));
+        assert_eq!(eval("-1 >> 31"), Ok("-1".to_string()));
+        assert_eq!(eval("-2147483648 >> 31"), Ok("-1".to_string()));
+        // The same bits as an unsigned value shift in zeros instead.
+        assert_eq!(eval("4294967280 >> 2"), Ok("1073741820".to_string()));
+    }
+
+    #[test]
+    fn shifting_by_the_width_is_an_error() {
+        let error = eval("1 << 32").unwrap_err();
+        assert!(error.contains("attempt to shift left by `32_u32`, which would overflow"));
+        assert!(eval("-1 >> 40").is_err());
+        assert!(eval("1 << -1").is_err());
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        assert!(parse_expr("12 && 10").is_err());
+        assert!(parse_expr("12&10").is_err());
+        assert!(parse_expr("-3000000000 & 1").is_err());
+    }
+}
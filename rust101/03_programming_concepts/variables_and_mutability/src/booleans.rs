@@ -0,0 +1,243 @@
+/*
+   * Booleans and comparisons
+     * `bool` has two values, `true` and `false`, and takes one byte.
+     * `&&` (and) and `||` (or) short-circuit: the right-hand side is only
+     evaluated when the left-hand side hasn't already decided the answer.
+     `false && anything` is false and `true || anything` is true without ever
+     looking at `anything` - so any side effects in it don't happen.
+     * `&`, `|` and `^` (xor) also work on bools, but always evaluate both
+     sides. `!` is not.
+     * Comparison operators can't be chained - `a < b < c` is
+     "error: comparison operators cannot be chained", because `a < b` is a
+     bool and comparing a bool with `c` isn't what anyone means. Write
+     `a < b && b < c`, or use a range: `(a..c).contains(&b)` is
+     `a <= b && b < c`.
+*/
+use std::cell::Cell;
+use std::io::{self, Write};
+
+use crate::Options;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BoolOp {
+    And,
+    Or,
+    Xor,
+    Not,
+}
+
+impl BoolOp {
+    fn symbol(self) -> &'static str {
+        match self {
+            BoolOp::And => "&&",
+            BoolOp::Or => "||",
+            BoolOp::Xor => "^",
+            BoolOp::Not => "!",
+        }
+    }
+
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            BoolOp::And => a && b,
+            BoolOp::Or => a || b,
+            BoolOp::Xor => a ^ b,
+            BoolOp::Not => !a,
+        }
+    }
+}
+
+// One line of a truth table. `b` is `None` for `!`, which only has one input.
+#[derive(Debug, PartialEq)]
+struct TruthRow {
+    a: bool,
+    b: Option<bool>,
+    result: bool,
+}
+
+fn truth_table(op: BoolOp) -> Vec<TruthRow> {
+    let inputs: &[bool] = &[false, true];
+    let mut rows = Vec::new();
+    for &a in inputs {
+        if op == BoolOp::Not {
+            rows.push(TruthRow {
+                a,
+                b: None,
+                result: op.apply(a, false),
+            });
+            continue;
+        }
+        for &b in inputs {
+            rows.push(TruthRow {
+                a,
+                b: Some(b),
+                result: op.apply(a, b),
+            });
+        }
+    }
+    rows
+}
+
+fn print_truth_table(out: &mut dyn Write, op: BoolOp) -> io::Result<()> {
+    let symbol = op.symbol();
+    for row in truth_table(op) {
+        let TruthRow { a, b, result } = row;
+        let expression = match b {
+            Some(b) => format!("{a} {symbol} {b}"),
+            None => format!("{symbol}{a}"),
+        };
+        writeln!(out, "  {expression:<15} = {result}")?;
+    }
+    Ok(())
+}
+
+// Evaluates `left && right()` and `left || right()`, counting how many times
+// the right-hand side runs. `Cell` lets the closure bump the counter through
+// a shared reference.
+fn short_circuit(left: bool) -> ShortCircuit {
+    let calls = Cell::new(0);
+    let right = || {
+        calls.set(calls.get() + 1);
+        true
+    };
+
+    let and = left && right();
+    let and_calls = calls.replace(0);
+    let or = left || right();
+    let or_calls = calls.get();
+
+    ShortCircuit {
+        and: (and, and_calls),
+        or: (or, or_calls),
+    }
+}
+
+// Each field is the result of the expression and how many times its
+// right-hand side was evaluated.
+#[derive(Debug, PartialEq)]
+struct ShortCircuit {
+    and: (bool, u32),
+    or: (bool, u32),
+}
+
+// `a < b < c` doesn't compile, so these are the two ways to say it.
+fn strictly_between(a: i32, b: i32, c: i32) -> bool {
+    a < b && b < c
+}
+
+fn in_half_open_range(a: i32, b: i32, c: i32) -> bool {
+    (a..c).contains(&b)
+}
+
+pub fn run(_options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    for op in [BoolOp::And, BoolOp::Or, BoolOp::Xor, BoolOp::Not] {
+        writeln!(out, "Truth table for `{}`:", op.symbol())?;
+        print_truth_table(out, op)?;
+    }
+    writeln!(out)?;
+
+    for left in [false, true] {
+        let result = short_circuit(left);
+        // The counts are the point of the demonstration, so check them.
+        assert_eq!(result.and.1, u32::from(left));
+        assert_eq!(result.or.1, u32::from(!left));
+        writeln!(
+            out,
+            "{left} && right() = {} - right() ran {} time(s)",
+            result.and.0, result.and.1
+        )?;
+        writeln!(
+            out,
+            "{left} || right() = {} - right() ran {} time(s)",
+            result.or.0, result.or.1
+        )?;
+    }
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "`1 < 5 < 10` is \"error: comparison operators cannot be chained\"."
+    )?;
+    writeln!(out, "1 < 5 && 5 < 10 = {}", strictly_between(1, 5, 10))?;
+    writeln!(
+        out,
+        "(1..10).contains(&10) = {}",
+        in_half_open_range(1, 10, 10)
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results(op: BoolOp) -> Vec<bool> {
+        truth_table(op).into_iter().map(|row| row.result).collect()
+    }
+
+    #[test]
+    fn binary_tables_cover_every_input_pair() {
+        let table = truth_table(BoolOp::And);
+        let inputs: Vec<_> = table.iter().map(|row| (row.a, row.b)).collect();
+        assert_eq!(
+            inputs,
+            [
+                (false, Some(false)),
+                (false, Some(true)),
+                (true, Some(false)),
+                (true, Some(true)),
+            ]
+        );
+    }
+
+    #[test]
+    fn truth_table_results() {
+        assert_eq!(results(BoolOp::And), [false, false, false, true]);
+        assert_eq!(results(BoolOp::Or), [false, true, true, true]);
+        assert_eq!(results(BoolOp::Xor), [false, true, true, false]);
+    }
+
+    #[test]
+    fn not_has_one_input() {
+        assert_eq!(
+            truth_table(BoolOp::Not),
+            [
+                TruthRow {
+                    a: false,
+                    b: None,
+                    result: true
+                },
+                TruthRow {
+                    a: true,
+                    b: None,
+                    result: false
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn right_hand_side_only_runs_when_needed() {
+        assert_eq!(
+            short_circuit(false),
+            ShortCircuit {
+                and: (false, 0),
+                or: (true, 1),
+            }
+        );
+        assert_eq!(
+            short_circuit(true),
+            ShortCircuit {
+                and: (true, 1),
+                or: (true, 0),
+            }
+        );
+    }
+
+    #[test]
+    fn chained_comparison_alternatives() {
+        assert!(strictly_between(1, 5, 10));
+        assert!(!strictly_between(1, 1, 10));
+        assert!(in_half_open_range(1, 1, 10));
+        assert!(!in_half_open_range(1, 10, 10));
+    }
+}
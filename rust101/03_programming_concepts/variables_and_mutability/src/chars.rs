@@ -0,0 +1,183 @@
+/*
+   * Characters and Unicode
+     * A Rust `char` is a Unicode scalar value - always 4 bytes in memory, and
+     written with single quotes: 'a', 'é', '🦀'.
+     * A `String`/`&str` is NOT an array of chars. It's UTF-8 encoded bytes,
+     where each char takes 1 to 4 bytes. That's why `.len()` (bytes) and
+     `.chars().count()` (chars) can disagree, and why `s[0]` isn't allowed.
+     * `char` has handy methods: `is_alphabetic`, `is_numeric`,
+     `is_whitespace`, `len_utf8`, and `as u32` gives the code point.
+   * Graphemes
+     * Even chars aren't what a reader would call 'characters'. "é" can be one
+     char (U+00E9) or two - 'e' followed by a combining acute accent
+     (U+0301). Both look identical but compare unequal. What a reader sees as
+     one character is a 'grapheme cluster', and the standard library doesn't
+     split strings into those - the `unicode-segmentation` crate does.
+*/
+use std::io::{self, Write};
+
+use crate::input;
+use crate::Options;
+
+#[derive(Debug, PartialEq)]
+struct CharRow {
+    ch: char,
+    code_point: u32,
+    utf8_len: usize,
+    byte_offset: usize,
+    alphabetic: bool,
+    numeric: bool,
+    whitespace: bool,
+}
+
+fn char_table(text: &str) -> Vec<CharRow> {
+    // `char_indices` yields each char with the byte offset it starts at.
+    text.char_indices()
+        .map(|(byte_offset, ch)| CharRow {
+            ch,
+            code_point: ch as u32,
+            utf8_len: ch.len_utf8(),
+            byte_offset,
+            alphabetic: ch.is_alphabetic(),
+            numeric: ch.is_numeric(),
+            whitespace: ch.is_whitespace(),
+        })
+        .collect()
+}
+
+fn print_table(out: &mut dyn Write, text: &str) -> io::Result<()> {
+    writeln!(out, "{text:?}")?;
+    writeln!(out, "char          code point  bytes  offset  kind")?;
+    for row in char_table(text) {
+        let kinds: Vec<&str> = [
+            (row.alphabetic, "alphabetic"),
+            (row.numeric, "numeric"),
+            (row.whitespace, "whitespace"),
+        ]
+        .into_iter()
+        .filter_map(|(is, kind)| is.then_some(kind))
+        .collect();
+        // `{:?}` on a char escapes the invisible ones, like combining marks.
+        let shown = format!("{:?}", row.ch);
+        let code_point = format!("U+{:04X}", row.code_point);
+        let kind = if kinds.is_empty() {
+            "other".to_string()
+        } else {
+            kinds.join(", ")
+        };
+        writeln!(
+            out,
+            "{shown:<13} {code_point:<11} {:<6} {:<7} {kind}",
+            row.utf8_len, row.byte_offset
+        )?;
+    }
+    writeln!(
+        out,
+        "{} bytes (.len()), {} chars (.chars().count())",
+        text.len(),
+        text.chars().count()
+    )?;
+    Ok(())
+}
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    match &options.input {
+        Some(text) => print_table(out, text)?,
+        None => {
+            print_table(out, "Hi 5🦀")?;
+            writeln!(out)?;
+
+            let composed = "\u{e9}";
+            let decomposed = "e\u{301}";
+            writeln!(
+                out,
+                "Grapheme caveat: {composed} and {decomposed} look the same, but:"
+            )?;
+            print_table(out, composed)?;
+            print_table(out, decomposed)?;
+            writeln!(out, "composed == decomposed is {}", composed == decomposed)?;
+        }
+    }
+
+    if options.interactive {
+        writeln!(out)?;
+        writeln!(out, "Type some text to see its characters.")?;
+        while let Some(line) = input::read_line("> ") {
+            print_table(out, &line)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_chars_are_one_byte_each() {
+        let table = char_table("a1 ");
+        assert_eq!(
+            table,
+            [
+                CharRow {
+                    ch: 'a',
+                    code_point: 0x61,
+                    utf8_len: 1,
+                    byte_offset: 0,
+                    alphabetic: true,
+                    numeric: false,
+                    whitespace: false,
+                },
+                CharRow {
+                    ch: '1',
+                    code_point: 0x31,
+                    utf8_len: 1,
+                    byte_offset: 1,
+                    alphabetic: false,
+                    numeric: true,
+                    whitespace: false,
+                },
+                CharRow {
+                    ch: ' ',
+                    code_point: 0x20,
+                    utf8_len: 1,
+                    byte_offset: 2,
+                    alphabetic: false,
+                    numeric: false,
+                    whitespace: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn emoji_is_one_char_of_four_bytes() {
+        let table = char_table("🦀!");
+        assert_eq!(table.len(), 2);
+        assert_eq!(table[0].code_point, 0x1F980);
+        assert_eq!(table[0].utf8_len, 4);
+        assert!(!table[0].alphabetic);
+        assert_eq!(table[1].byte_offset, 4);
+    }
+
+    #[test]
+    fn combining_characters_are_separate_chars() {
+        let composed = char_table("\u{e9}");
+        assert_eq!(composed.len(), 1);
+        assert_eq!(composed[0].utf8_len, 2);
+        assert!(composed[0].alphabetic);
+
+        let decomposed = char_table("e\u{301}");
+        assert_eq!(decomposed.len(), 2);
+        assert_eq!(decomposed[1].code_point, 0x301);
+        assert_eq!(decomposed[1].utf8_len, 2);
+        assert_eq!(decomposed[1].byte_offset, 1);
+        // A combining mark isn't a letter by itself.
+        assert!(!decomposed[1].alphabetic);
+    }
+
+    #[test]
+    fn empty_string_has_no_rows() {
+        assert!(char_table("").is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+/*
+   * Compile-time checks
+     * `assert!` normally runs when the program does, but inside a `const` the
+     compiler has to work the value out while building - so a failed assertion
+     there is a compile error instead of a panic.
+     * `static_assert!` (see `src/assertions.rs`) wraps that up. The tour uses
+     it to pin down things the code relies on:
+       * `variables.rs` checks `THREE_HOURS_IN_SECONDS` really is 10,800,
+       * `statics.rs` checks the lookup table agrees with `seconds_in`,
+       * `data_types.rs` checks a `char` is 4 bytes, as its table says.
+     * Checks like these cost nothing at runtime - once the program compiles
+     they've already passed - and they fail loudly as soon as someone changes
+     an assumption instead of when a user hits the affected code.
+     * A violated assertion looks like this. Changing the check below to
+     `size_of::<Option<&str>>() == size_of::<&str>() + 1` gives:
+
+         error[E0080]: evaluation panicked: Option<&str> should be the same size as &str
+           --> src/assertions.rs:19:23
+            |
+         19 |           const _: () = assert!($condition, $message);
+            |                         ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ evaluation of `compile_checks::_` failed here
+            |
+           ::: src/compile_checks.rs:39:1
+            |
+         39 | / static_assert!(
+         40 | |     size_of::<Option<&str>>() == size_of::<&str>() + 1,
+         41 | |     "Option<&str> should be the same size as &str"
+         42 | | );
+            | |_- in this macro invocation
+*/
+use std::io::{self, Write};
+use std::mem::size_of;
+
+use crate::assertions::static_assert;
+use crate::Options;
+
+// References can never be null, so `Option` uses null to mean `None` and
+// doesn't need any extra space - the "null pointer optimisation".
+static_assert!(
+    size_of::<Option<&str>>() == size_of::<&str>(),
+    "Option<&str> should be the same size as &str"
+);
+// A `&str` is a pointer and a length.
+static_assert!(
+    size_of::<&str>() == 2 * size_of::<usize>(),
+    "&str should be a pointer and a length"
+);
+// The unit type takes no space at all.
+static_assert!(size_of::<()>() == 0);
+
+pub fn run(_options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    writeln!(
+        out,
+        "These all held when the tour was compiled - otherwise it wouldn't have built:"
+    )?;
+    writeln!(out, "  THREE_HOURS_IN_SECONDS == 10_800")?;
+    writeln!(out, "  SECONDS_BY_HOUR[24] == seconds_in(24)")?;
+    writeln!(out, "  size_of::<char>() == 4")?;
+    writeln!(
+        out,
+        "  size_of::<Option<&str>>() == size_of::<&str>() == {}",
+        size_of::<&str>()
+    )?;
+    writeln!(out, "  size_of::<()>() == 0")?;
+    Ok(())
+}
@@ -0,0 +1,147 @@
+/*
+   * Compound types
+     * A tuple groups a fixed number of values of (possibly) different types:
+     `let tup: (i32, f64, u8) = (500, 6.4, 1);`. Get at the parts by
+     destructuring (`let (x, y, z) = tup;`) or by position (`tup.0`).
+     * The empty tuple `()` is the "unit" type - the value of expressions that
+     don't produce anything, and the return type of functions without `->`.
+     * An array has a fixed length that's part of its type: `[i32; 5]`.
+     `[0; 5]` is five zeros. Arrays live on the stack - use a Vec when the
+     length can change.
+     * A slice (`&[T]`) is a view of some or all of an array or Vec. Its
+     length is only known at runtime, which is why functions that should work
+     with any length take `&[T]` rather than `[T; N]`.
+     * `arr[i]` panics if `i` is out of range: "index out of bounds: the len
+     is 5 but the index is 7". `arr.get(i)` returns an `Option` instead, so
+     the caller has to decide what happens.
+*/
+use std::io::{self, Write};
+
+use crate::input;
+use crate::Options;
+
+// `Copy` so the element can be returned by value rather than as a reference.
+fn get_or<T: Copy>(arr: &[T], idx: usize, default: T) -> T {
+    arr.get(idx).copied().unwrap_or(default)
+}
+
+// The middle element - the later of the two for an even length - or `None`
+// for an empty slice, where `slice[slice.len() / 2]` would panic.
+fn middle<T>(slice: &[T]) -> Option<&T> {
+    slice.get(slice.len() / 2)
+}
+
+// The message indexing would panic with, or `None` if it wouldn't.
+fn index_panic_message<T>(slice: &[T], idx: usize) -> Option<String> {
+    if idx < slice.len() {
+        None
+    } else {
+        Some(format!(
+            "index out of bounds: the len is {} but the index is {idx}",
+            slice.len()
+        ))
+    }
+}
+
+const ARRAY: [i32; 5] = [10, 20, 30, 40, 50];
+
+fn show_index(out: &mut dyn Write, idx: usize) -> io::Result<()> {
+    // Checking first means we can say what `ARRAY[idx]` would do without
+    // actually letting it panic.
+    match index_panic_message(&ARRAY, idx) {
+        Some(message) => writeln!(out, "  ARRAY[{idx}]     would panic: {message}")?,
+        None => writeln!(out, "  ARRAY[{idx}]     = {}", ARRAY[idx])?,
+    }
+    writeln!(out, "  ARRAY.get({idx}) = {:?}", ARRAY.get(idx))?;
+    writeln!(
+        out,
+        "  get_or(&ARRAY, {idx}, -1) = {}",
+        get_or(&ARRAY, idx, -1)
+    )
+}
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let tup: (i32, f64, u8) = (500, 6.4, 1);
+    let (x, y, z) = tup;
+    writeln!(out, "let (x, y, z) = {tup:?}; -> x = {x}, y = {y}, z = {z}")?;
+    writeln!(
+        out,
+        "tup.0 = {}, tup.1 = {}, tup.2 = {}",
+        tup.0, tup.1, tup.2
+    )?;
+    let unit = ();
+    writeln!(out, "The unit value prints as {unit:?}")?;
+    writeln!(out)?;
+
+    let zeros = [0; 5];
+    let months = ["Jan", "Feb", "Mar"];
+    writeln!(out, "[0; 5] = {zeros:?}, months = {months:?}")?;
+    writeln!(
+        out,
+        "&ARRAY[1..4] = {:?} - a slice of the middle",
+        &ARRAY[1..4]
+    )?;
+    writeln!(out, "middle(&ARRAY) = {:?}", middle(&ARRAY))?;
+    writeln!(out, "middle(&[] as &[i32]) = {:?}", middle(&[] as &[i32]))?;
+    writeln!(out)?;
+
+    writeln!(out, "ARRAY = {ARRAY:?}")?;
+    for idx in [2, 7] {
+        show_index(out, idx)?;
+    }
+
+    if options.interactive {
+        writeln!(out)?;
+        writeln!(out, "Enter an index into ARRAY.")?;
+        while let Some(line) = input::read_line("> ") {
+            match line.parse::<usize>() {
+                Ok(idx) => show_index(out, idx)?,
+                Err(_) => writeln!(
+                    out,
+                    "`{line}` isn't a valid index - it must be a whole number from 0."
+                )?,
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_or_returns_the_element_when_in_range() {
+        assert_eq!(get_or(&[1, 2, 3], 0, 0), 1);
+        assert_eq!(get_or(&[1, 2, 3], 2, 0), 3);
+    }
+
+    #[test]
+    fn get_or_returns_the_default_out_of_range() {
+        assert_eq!(get_or(&[1, 2, 3], 3, -1), -1);
+        assert_eq!(get_or(&[1, 2, 3], usize::MAX, -1), -1);
+        assert_eq!(get_or(&[] as &[char], 0, '?'), '?');
+    }
+
+    #[test]
+    fn middle_of_odd_and_even_lengths() {
+        assert_eq!(middle(&[1, 2, 3]), Some(&2));
+        assert_eq!(middle(&[1, 2, 3, 4]), Some(&3));
+        assert_eq!(middle(&["only"]), Some(&"only"));
+    }
+
+    #[test]
+    fn middle_of_an_empty_slice_is_none() {
+        assert_eq!(middle(&[] as &[i32]), None);
+    }
+
+    #[test]
+    fn panic_message_matches_the_standard_library() {
+        assert_eq!(index_panic_message(&ARRAY, 4), None);
+        assert_eq!(
+            index_panic_message(&ARRAY, 7).as_deref(),
+            Some("index out of bounds: the len is 5 but the index is 7")
+        );
+        assert!(index_panic_message(&[] as &[i32], 0).is_some());
+    }
+}
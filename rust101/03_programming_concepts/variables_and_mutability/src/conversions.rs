@@ -0,0 +1,266 @@
+/*
+   * Numeric conversions: `as` versus `TryFrom`
+     * `value as u8` always succeeds - it never panics and never returns an
+     error. When the value doesn't fit, it quietly changes it:
+       * integer to smaller integer keeps only the low bits (300 as i8 is 44),
+       * negative to unsigned wraps around (-1 as u8 is 255),
+       * float to integer truncates towards zero (3.9 as i32 is 3) and
+       saturates at the target's MIN/MAX (NaN becomes 0).
+     * `u8::try_from(value)` (from the `TryFrom` trait, in the prelude since
+     the 2021 edition) returns a `Result` instead - `Err(TryFromIntError)` if
+     the value doesn't fit exactly. The error's Display text is "out of range
+     integral type conversion attempted".
+     * There is no `TryFrom<f64>` for integers in the standard library, so
+     `exact_i32` below does the checking by hand to show what a lossless float
+     conversion would have to look like.
+     * Rule of thumb: use `as` only when you WANT the truncating behaviour, and
+     `try_from`/`try_into` everywhere else.
+*/
+use std::fmt;
+use std::io::{self, Write};
+use std::num::TryFromIntError;
+
+use crate::input;
+use crate::Options;
+
+// The i64 -> i8 case from the notes, as its own function.
+fn narrow(value: i64) -> Result<i8, TryFromIntError> {
+    i8::try_from(value)
+}
+
+#[derive(Debug, PartialEq)]
+enum FloatToIntError {
+    NotANumber,
+    Fractional,
+    OutOfRange,
+}
+
+impl fmt::Display for FloatToIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FloatToIntError::NotANumber => write!(f, "NaN has no integer value"),
+            FloatToIntError::Fractional => write!(f, "value has a fractional part"),
+            FloatToIntError::OutOfRange => write!(f, "value is outside the target range"),
+        }
+    }
+}
+
+// A float -> i32 conversion that refuses to lose information. The range is
+// checked before the fractional part: the fractional part of an infinity is
+// NaN, which isn't 0.0, so the other way round ±∞ would count as Fractional.
+fn exact_i32(value: f64) -> Result<i32, FloatToIntError> {
+    if value.is_nan() {
+        Err(FloatToIntError::NotANumber)
+    } else if value < i32::MIN as f64 || value > i32::MAX as f64 {
+        Err(FloatToIntError::OutOfRange)
+    } else if value.fract() != 0.0 {
+        Err(FloatToIntError::Fractional)
+    } else {
+        Ok(value as i32)
+    }
+}
+
+// One line of the comparison: what `as` produced, what the checked conversion
+// produced, and why they differ (if they do).
+#[derive(Debug, PartialEq)]
+struct Conversion {
+    target: &'static str,
+    cast: String,
+    checked: String,
+    explanation: Option<&'static str>,
+}
+
+fn show<T: fmt::Display, E: fmt::Display>(result: Result<T, E>) -> String {
+    match result {
+        Ok(value) => format!("Ok({value})"),
+        Err(error) => format!("Err({error})"),
+    }
+}
+
+fn explain_integer(value: i64, fits: bool, unsigned: bool) -> Option<&'static str> {
+    match (fits, value < 0 && unsigned) {
+        (true, _) => None,
+        (false, true) => Some("negative values wrap around when cast to an unsigned type"),
+        (false, false) => Some("`as` keeps only the low bits that fit in the target"),
+    }
+}
+
+fn compare_integer(value: i64) -> Vec<Conversion> {
+    vec![
+        Conversion {
+            target: "i8",
+            cast: (value as i8).to_string(),
+            checked: show(narrow(value)),
+            explanation: explain_integer(value, narrow(value).is_ok(), false),
+        },
+        Conversion {
+            target: "u8",
+            cast: (value as u8).to_string(),
+            checked: show(u8::try_from(value)),
+            explanation: explain_integer(value, u8::try_from(value).is_ok(), true),
+        },
+        Conversion {
+            target: "u16",
+            cast: (value as u16).to_string(),
+            checked: show(u16::try_from(value)),
+            explanation: explain_integer(value, u16::try_from(value).is_ok(), true),
+        },
+    ]
+}
+
+fn compare_float(value: f64) -> Vec<Conversion> {
+    let checked = exact_i32(value);
+    let explanation = match checked {
+        Ok(_) => None,
+        Err(FloatToIntError::NotANumber) => Some("`as` turns NaN into 0"),
+        Err(FloatToIntError::Fractional) => Some("`as` truncates towards zero"),
+        Err(FloatToIntError::OutOfRange) => Some("`as` saturates at the target's MIN or MAX"),
+    };
+
+    // For u8 the int conversion is chained: first exactly to i32, then try_from.
+    let as_u8 = exact_i32(value)
+        .map_err(|error| error.to_string())
+        .and_then(|int| u8::try_from(int).map_err(|error| error.to_string()));
+    let u8_explanation = match (&as_u8, explanation) {
+        (Ok(_), _) => None,
+        (Err(_), Some(reason)) => Some(reason),
+        (Err(_), None) => Some("`as` saturates at the target's MIN or MAX"),
+    };
+
+    vec![
+        Conversion {
+            target: "i32",
+            cast: (value as i32).to_string(),
+            checked: show(checked),
+            explanation,
+        },
+        Conversion {
+            target: "u8",
+            cast: (value as u8).to_string(),
+            checked: show(as_u8),
+            explanation: u8_explanation,
+        },
+    ]
+}
+
+// Integers first, so that `300` is treated as an i64 rather than a float.
+fn compare(text: &str) -> Option<Vec<Conversion>> {
+    if let Ok(value) = text.parse::<i64>() {
+        Some(compare_integer(value))
+    } else {
+        text.parse::<f64>().ok().map(compare_float)
+    }
+}
+
+fn print_conversions(
+    out: &mut dyn Write,
+    input: &str,
+    conversions: &[Conversion],
+) -> io::Result<()> {
+    for conversion in conversions {
+        writeln!(
+            out,
+            "{input} as {:<4} = {:<6} checked: {}",
+            conversion.target, conversion.cast, conversion.checked
+        )?;
+        if let Some(explanation) = conversion.explanation {
+            writeln!(out, "    they differ - {explanation}")?;
+        }
+    }
+    Ok(())
+}
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    for input in ["-1", "300", "3.9"] {
+        print_conversions(out, input, &compare(input).expect("valid examples"))?;
+        writeln!(out)?;
+    }
+
+    if options.interactive {
+        writeln!(
+            out,
+            "Enter a number to compare `as` with a checked conversion."
+        )?;
+        while let Some(line) = input::read_line("> ") {
+            match compare(&line) {
+                Some(conversions) => print_conversions(out, &line, &conversions)?,
+                None => writeln!(out, "`{line}` isn't a number.")?,
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUT_OF_RANGE: &str = "Err(out of range integral type conversion attempted)";
+
+    #[test]
+    fn minus_one_wraps_when_cast_to_unsigned() {
+        let conversions = compare_integer(-1);
+        assert_eq!(conversions[0].cast, "-1");
+        assert_eq!(conversions[0].checked, "Ok(-1)");
+        assert_eq!(conversions[0].explanation, None);
+
+        assert_eq!(conversions[1].cast, "255");
+        assert_eq!(conversions[1].checked, OUT_OF_RANGE);
+        assert_eq!(
+            conversions[1].explanation,
+            Some("negative values wrap around when cast to an unsigned type")
+        );
+        assert_eq!(conversions[2].cast, "65535");
+    }
+
+    #[test]
+    fn three_hundred_is_truncated_to_the_low_bits() {
+        let conversions = compare_integer(300);
+        assert_eq!(conversions[0].cast, "44");
+        assert_eq!(conversions[0].checked, OUT_OF_RANGE);
+        assert_eq!(conversions[1].cast, "44");
+        assert_eq!(conversions[1].checked, OUT_OF_RANGE);
+        assert_eq!(conversions[2].cast, "300");
+        assert_eq!(conversions[2].checked, "Ok(300)");
+        assert_eq!(conversions[2].explanation, None);
+    }
+
+    #[test]
+    fn three_point_nine_is_truncated_towards_zero() {
+        let conversions = compare_float(3.9);
+        assert_eq!(conversions[0].cast, "3");
+        assert_eq!(conversions[0].checked, "Err(value has a fractional part)");
+        assert_eq!(
+            conversions[0].explanation,
+            Some("`as` truncates towards zero")
+        );
+        assert_eq!(conversions[1].cast, "3");
+    }
+
+    #[test]
+    fn narrow_matches_try_from() {
+        assert_eq!(narrow(127), Ok(127));
+        assert!(narrow(128).is_err());
+        assert_eq!(narrow(-128), Ok(-128));
+    }
+
+    #[test]
+    fn exact_i32_rejects_lossy_floats() {
+        assert_eq!(exact_i32(42.0), Ok(42));
+        assert_eq!(exact_i32(f64::NAN), Err(FloatToIntError::NotANumber));
+        assert_eq!(exact_i32(1e10), Err(FloatToIntError::OutOfRange));
+        assert_eq!(exact_i32(-0.5), Err(FloatToIntError::Fractional));
+        assert_eq!(exact_i32(f64::INFINITY), Err(FloatToIntError::OutOfRange));
+        assert_eq!(
+            exact_i32(f64::NEG_INFINITY),
+            Err(FloatToIntError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn float_casts_saturate() {
+        let conversions = compare_float(-1e10);
+        assert_eq!(conversions[0].cast, i32::MIN.to_string());
+        assert_eq!(conversions[1].cast, "0");
+    }
+}
@@ -0,0 +1,307 @@
+/*
+   * Scalar data types
+     * Rust has four kinds of scalar type: integers, floating-point numbers,
+     booleans and characters.
+     * Integers come in signed (`i`) and unsigned (`u`) flavours of 8, 16, 32,
+     64 and 128 bits. `isize` and `usize` are the size of a pointer on the
+     machine the program is compiled for, so their range isn't fixed - which
+     is why they're mentioned below the table rather than in it.
+     * Floats are `f32` and `f64` (the default). Their MIN is the most negative
+     finite value, not the smallest positive one - that's `MIN_POSITIVE`.
+     * Every numeric type has associated constants `MIN` and `MAX`, and
+     `std::mem::size_of::<T>()` reports how many bytes a value takes.
+   * Generics
+     * Rather than twelve copy-pasted println! lines, the table is built by one
+     generic function, `describe::<T>()`. The `Bounded` trait below gives it
+     access to MIN, MAX and a sample literal for any type that implements it,
+     and a small `macro_rules!` macro writes the twelve trait impls for us.
+     See https://doc.rust-lang.org/book/ch10-00-generics.html.
+*/
+use std::any;
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::mem;
+
+use crate::assertions::static_assert;
+use crate::{Format, Options};
+
+// Lets `describe` ask a type for its limits. The standard library has no trait
+// for this (the constants are inherent to each type), so we make our own.
+trait Bounded: Display + Sized {
+    const MIN: Self;
+    const MAX: Self;
+    const SAMPLE: &'static str;
+
+    // A default method - types can use it as-is or override it.
+    fn render(self) -> String {
+        self.to_string()
+    }
+}
+
+// `$t:ty` matches a type and `$sample:literal` a literal, and the `$(...),*`
+// repetition expands the impl once per pair. The `float` arm overrides
+// `render`, because `f64::MAX` written out in full is 309 digits long.
+macro_rules! impl_bounded {
+    ($($t:ty => $sample:literal),* $(,)?) => {
+        $(
+            impl Bounded for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+                const SAMPLE: &'static str = $sample;
+            }
+        )*
+    };
+    (float $($t:ty => $sample:literal),* $(,)?) => {
+        $(
+            impl Bounded for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+                const SAMPLE: &'static str = $sample;
+
+                fn render(self) -> String {
+                    format!("{self:e}")
+                }
+            }
+        )*
+    };
+}
+
+impl_bounded! {
+    i8 => "-42i8",
+    i16 => "-1_000i16",
+    i32 => "98_222",
+    i64 => "-9_000_000_000i64",
+    i128 => "170_000i128",
+    u8 => "b'A'",
+    u16 => "0xffffu16",
+    u32 => "0o777u32",
+    u64 => "0b1111_0000u64",
+    u128 => "340u128",
+}
+
+impl_bounded! {
+    float
+    f32 => "1.5f32",
+    f64 => "2.0",
+}
+
+#[derive(Debug, PartialEq)]
+struct TypeRow {
+    name: &'static str,
+    size: usize,
+    min: String,
+    max: String,
+    sample: String,
+}
+
+fn describe<T: Bounded + Display>() -> TypeRow {
+    TypeRow {
+        name: any::type_name::<T>(),
+        size: mem::size_of::<T>(),
+        min: T::MIN.render(),
+        max: T::MAX.render(),
+        sample: T::SAMPLE.to_string(),
+    }
+}
+
+// A char holds any Unicode scalar value (up to U+10FFFF), so unlike a char
+// inside a UTF-8 string it always takes four bytes.
+static_assert!(
+    mem::size_of::<char>() == 4,
+    "a char should always be 4 bytes"
+);
+
+fn rows() -> Vec<TypeRow> {
+    vec![
+        describe::<i8>(),
+        describe::<i16>(),
+        describe::<i32>(),
+        describe::<i64>(),
+        describe::<i128>(),
+        describe::<u8>(),
+        describe::<u16>(),
+        describe::<u32>(),
+        describe::<u64>(),
+        describe::<u128>(),
+        describe::<f32>(),
+        describe::<f64>(),
+        // `char` and `bool` don't fit the Bounded pattern: a char's range has
+        // a hole in it (the surrogates U+D800 to U+DFFF aren't valid chars) and
+        // 'min' and 'max' don't mean much for a bool, so they're written out.
+        TypeRow {
+            name: "char",
+            size: mem::size_of::<char>(),
+            min: format!("{:?}", char::from_u32(0).unwrap()),
+            max: format!("{:?}", char::MAX),
+            sample: "'z'".to_string(),
+        },
+        TypeRow {
+            name: "bool",
+            size: mem::size_of::<bool>(),
+            min: false.to_string(),
+            max: true.to_string(),
+            sample: "true".to_string(),
+        },
+    ]
+}
+
+const HEADERS: [&str; 5] = ["type", "bytes", "MIN", "MAX", "sample"];
+
+fn cells(row: &TypeRow) -> [String; 5] {
+    [
+        row.name.to_string(),
+        row.size.to_string(),
+        row.min.clone(),
+        row.max.clone(),
+        row.sample.clone(),
+    ]
+}
+
+// Each column is as wide as its widest cell. Numbers are right-aligned so the
+// digits line up, text is left-aligned.
+fn ascii_table(rows: &[TypeRow]) -> String {
+    let all_cells: Vec<[String; 5]> = rows.iter().map(cells).collect();
+    let mut widths = HEADERS.map(str::len);
+    for row in &all_cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let mut table = String::new();
+    let header: Vec<String> = HEADERS
+        .iter()
+        .zip(widths)
+        .map(|(header, width)| format!("{header:<width$}"))
+        .collect();
+    table.push_str(header.join(" | ").trim_end());
+    table.push('\n');
+    let rule: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    table.push_str(&rule.join("-+-"));
+    table.push('\n');
+
+    for row in &all_cells {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| match column {
+                1..=3 => format!("{cell:>width$}"),
+                _ => format!("{cell:<width$}"),
+            })
+            .collect();
+        table.push_str(line.join(" | ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+// Quotes cells containing a comma or quote, doubling any quotes inside, so a
+// sample like `','` would still be read back as a single cell.
+fn csv_table(rows: &[TypeRow]) -> String {
+    fn escape(cell: &str) -> String {
+        if cell.contains([',', '"']) {
+            format!("\"{}\"", cell.replace('"', "\"\""))
+        } else {
+            cell.to_string()
+        }
+    }
+
+    let mut table = HEADERS.join(",");
+    table.push('\n');
+    for row in rows {
+        let line: Vec<String> = cells(row).iter().map(|cell| escape(cell)).collect();
+        table.push_str(&line.join(","));
+        table.push('\n');
+    }
+    table
+}
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let rows = rows();
+    match options.format {
+        Format::Ascii => write!(out, "{}", ascii_table(&rows))?,
+        Format::Csv => write!(out, "{}", csv_table(&rows))?,
+    }
+
+    if options.format == Format::Ascii {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "isize and usize are pointer-sized: {} bytes on this machine.",
+            mem::size_of::<usize>()
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_i8() {
+        assert_eq!(
+            describe::<i8>(),
+            TypeRow {
+                name: "i8",
+                size: 1,
+                min: "-128".to_string(),
+                max: "127".to_string(),
+                sample: "-42i8".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn describes_u64() {
+        assert_eq!(
+            describe::<u64>(),
+            TypeRow {
+                name: "u64",
+                size: 8,
+                min: "0".to_string(),
+                max: "18446744073709551615".to_string(),
+                sample: "0b1111_0000u64".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn table_includes_all_twelve_numeric_types() {
+        let names: Vec<&str> = rows().iter().map(|row| row.name).collect();
+        let numeric = [
+            "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128", "f32", "f64",
+        ];
+        assert_eq!(names[..12], numeric);
+        assert_eq!(names[12..], ["char", "bool"]);
+    }
+
+    #[test]
+    fn ascii_columns_line_up() {
+        let table = ascii_table(&rows());
+        let separators =
+            |line: &str| -> Vec<usize> { line.match_indices(['|', '+']).map(|(i, _)| i).collect() };
+        let lines: Vec<&str> = table.lines().collect();
+        let expected = separators(lines[0]);
+        assert_eq!(expected.len(), 4);
+        for line in &lines[1..] {
+            assert_eq!(separators(line), expected, "misaligned: {line}");
+        }
+    }
+
+    #[test]
+    fn floats_are_rendered_in_scientific_notation() {
+        assert_eq!(describe::<f64>().max, "1.7976931348623157e308");
+        assert_eq!(describe::<f32>().min, "-3.4028235e38");
+    }
+
+    #[test]
+    fn csv_has_a_header_and_quotes_when_needed() {
+        let table = csv_table(&rows());
+        let mut lines = table.lines();
+        assert_eq!(lines.next(), Some("type,bytes,MIN,MAX,sample"));
+        assert_eq!(lines.next(), Some("i8,1,-128,127,-42i8"));
+        assert_eq!(table.lines().count(), 15);
+    }
+}
@@ -0,0 +1,216 @@
+/*
+   * Statements and expressions
+     * A statement performs an action and has no value - `let x = 5;` is one,
+     which is why `let y = (let x = 5);` doesn't compile.
+     * An expression evaluates to a value. In Rust nearly everything is an
+     expression: blocks, `if`, `match` and `loop` all produce values, so they
+     can appear on the right of a `let` or as the body of a function.
+     * A block's value is its last expression - as long as it doesn't end in a
+     semicolon. Adding a `;` turns the expression into a statement and the
+     block's value becomes `()`, the unit type. Most "mismatched types ...
+     found `()`" errors are a stray semicolon.
+     * Every branch of an `if` or arm of a `match` must have the same type,
+     and an `if` used for its value needs an `else`.
+     * `loop` has no natural last value, so `break value` provides it.
+     * See https://doc.rust-lang.org/book/ch03-03-how-functions-work.html#statements-and-expressions
+*/
+use std::cmp::Ordering;
+use std::io::{self, Write};
+
+use crate::input;
+use crate::Options;
+
+// `if` as an expression - the body of the function is the whole `if`, and
+// each branch's last expression is the value. i32::MIN has no positive
+// partner - the range is -2147483648 to 2147483647 - so `-x` would overflow
+// there, panicking in a debug build; `checked_neg` gives None instead.
+fn abs_via_if(x: i32) -> Option<i32> {
+    if x < 0 {
+        x.checked_neg()
+    } else {
+        Some(x)
+    }
+}
+// The statement version ends each branch with a semicolon, so both branches
+// produce `()`:
+//
+//     fn abs_via_if(x: i32) -> Option<i32> {
+//         if x < 0 { x.checked_neg(); } else { Some(x); }
+//     }
+//
+// error[E0308]: mismatched types - expected `Option<i32>`, found `()`
+//
+// And leaving out the `else` means there's no value when the condition is
+// false:
+//
+//     let y = if x < 0 { -x };
+//
+// error[E0317]: `if` may be missing an `else` clause
+
+// `match` as an expression - each arm's value becomes the function's value.
+fn sign_name(x: i32) -> &'static str {
+    match x.cmp(&0) {
+        Ordering::Less => "negative",
+        Ordering::Equal => "zero",
+        Ordering::Greater => "positive",
+    }
+}
+// The arms must agree on a type:
+//
+//     let name = match x { 0 => "zero", _ => x };
+//
+// error[E0308]: `match` arms have incompatible types - expected `&str`,
+// found `i32`
+
+// `loop` with `break value` - the loop itself evaluates to `power`.
+fn next_power_of_two(n: u32) -> u32 {
+    let mut power = 1;
+    loop {
+        if power >= n {
+            break power;
+        }
+        power *= 2;
+    }
+}
+// A bare `break` leaves the loop with no value, so the loop is `()`:
+//
+//     loop {
+//         if power >= n { break; }
+//         power *= 2;
+//     }
+//
+// error[E0308]: mismatched types - expected `u32`, found `()`
+
+// A block expression - `squared_plus_one` gets the block's last expression.
+// Clippy would rather we returned the block directly, but then there'd be no
+// `let` to show the value being assigned. Anything past 46340 squared is too
+// big for an i32, so the arithmetic is checked: `?` leaves the function with
+// None as soon as a step overflows.
+#[allow(clippy::let_and_return)]
+fn squared_plus_one(x: i32) -> Option<i32> {
+    let squared_plus_one = {
+        let squared = x.checked_mul(x)?;
+        squared.checked_add(1)
+    };
+    squared_plus_one
+}
+// With a semicolon after `squared.checked_add(1)` the block is a statement:
+//
+//     let squared_plus_one: Option<i32> = {
+//         let squared = x.checked_mul(x)?;
+//         squared.checked_add(1);
+//     };
+//
+// error[E0308]: mismatched types - expected `Option<i32>`, found `()`
+// (the compiler even suggests "remove this semicolon to return this value")
+
+// A checked result, or what went wrong.
+fn show(result: Option<i32>) -> String {
+    match result {
+        Some(value) => value.to_string(),
+        None => "too big for an i32".to_string(),
+    }
+}
+
+fn print_results(out: &mut dyn Write, x: i32) -> io::Result<()> {
+    writeln!(
+        out,
+        "abs_via_if({x}) = {} - the value of the `if` branch that ran",
+        show(abs_via_if(x))
+    )?;
+    writeln!(
+        out,
+        "sign_name({x}) = {:?} - the value of the matching `match` arm",
+        sign_name(x)
+    )?;
+    writeln!(
+        out,
+        "squared_plus_one({x}) = {} - the last expression of a block",
+        show(squared_plus_one(x))
+    )?;
+    // Powers of two only make sense for non-negative input. Every one an
+    // i32 can hold is at most 2147483647, whose next power of two, 2^31,
+    // still fits in a u32.
+    match u32::try_from(x) {
+        Ok(n) => writeln!(
+            out,
+            "next_power_of_two({n}) = {} - the value passed to `break` in a `loop`",
+            next_power_of_two(n)
+        )?,
+        _ => writeln!(
+            out,
+            "next_power_of_two needs a number from 0 to 2147483647, so it's skipped."
+        )?,
+    }
+    Ok(())
+}
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    for x in [-7, 0, 5] {
+        print_results(out, x)?;
+        writeln!(out)?;
+    }
+
+    if options.interactive {
+        writeln!(
+            out,
+            "Enter a whole number to run it through each expression."
+        )?;
+        while let Some(line) = input::read_line("> ") {
+            match line.parse::<i32>() {
+                Ok(x) => print_results(out, x)?,
+                Err(_) => writeln!(out, "`{line}` isn't an i32.")?,
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn abs_via_if_takes_either_branch() {
+        assert_eq!(abs_via_if(-7), Some(7));
+        assert_eq!(abs_via_if(0), Some(0));
+        assert_eq!(abs_via_if(5), Some(5));
+        assert_eq!(abs_via_if(-i32::MAX), Some(i32::MAX));
+        assert_eq!(abs_via_if(i32::MIN), None);
+    }
+
+    #[test]
+    fn sign_name_covers_every_arm() {
+        assert_eq!(sign_name(-1), "negative");
+        assert_eq!(sign_name(0), "zero");
+        assert_eq!(sign_name(i32::MAX), "positive");
+    }
+
+    #[test]
+    fn next_power_of_two_breaks_with_a_value() {
+        assert_eq!(next_power_of_two(0), 1);
+        assert_eq!(next_power_of_two(1), 1);
+        assert_eq!(next_power_of_two(5), 8);
+        assert_eq!(next_power_of_two(64), 64);
+        assert_eq!(next_power_of_two(1 << 31), 1 << 31);
+    }
+
+    #[test]
+    fn block_value_is_its_last_expression() {
+        assert_eq!(squared_plus_one(0), Some(1));
+        assert_eq!(squared_plus_one(-3), Some(10));
+        assert_eq!(squared_plus_one(46_340), Some(2_147_395_601));
+        assert_eq!(squared_plus_one(46_341), None);
+        assert_eq!(squared_plus_one(i32::MIN), None);
+    }
+
+    #[test]
+    fn prints_overflows_instead_of_panicking() {
+        let mut out = Vec::new();
+        print_results(&mut out, i32::MIN).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("abs_via_if(-2147483648) = too big for an i32"));
+        assert!(out.contains("squared_plus_one(-2147483648) = too big for an i32"));
+        assert!(out.contains("from 0 to 2147483647"));
+    }
+}
@@ -0,0 +1,269 @@
+/*
+   * Floating-point numbers
+     * `f32` and `f64` are IEEE 754 binary floats. Most decimal fractions,
+     including 0.1, have no exact binary representation, so the literal `0.1`
+     is really the nearest representable value - slightly more than 0.1.
+     * Those tiny errors add up: `0.1 + 0.2` is 0.30000000000000004, and
+     `0.1 + 0.2 == 0.3` is false. Comparing floats with `==` is almost always
+     a bug - compare with a tolerance instead (`approx_eq` below).
+     * `f64::EPSILON` is the gap between 1.0 and the next f64 up. The gap grows
+     with the size of the number, which is why a fixed tolerance isn't enough
+     on its own and `approx_eq` also takes a relative one.
+     * Special values: dividing by zero gives infinity rather than panicking,
+     and undefined results like `0.0 / 0.0` give NaN ("not a number"). NaN is
+     not equal to anything, itself included - `x != x` is the classic NaN test,
+     though `x.is_nan()` says what it means.
+     * Because of NaN, floats implement `PartialEq`/`PartialOrd` but not
+     `Eq`/`Ord` - so you can't `.sort()` a Vec<f64>, and have to use
+     `sort_by(f64::total_cmp)` instead.
+*/
+use std::io::{self, Write};
+
+use crate::input;
+use crate::Options;
+
+// True if `a` and `b` are within `abs_tol` of each other, or within `rel_tol`
+// times the larger of their magnitudes. The absolute tolerance is what makes
+// comparisons against zero work - relative to zero, everything is infinitely
+// far away.
+fn approx_eq(a: f64, b: f64, rel_tol: f64, abs_tol: f64) -> bool {
+    debug_assert!(rel_tol >= 0.0 && abs_tol >= 0.0, "tolerances must be >= 0");
+
+    // Catches identical values, including equal infinities and +0.0 == -0.0.
+    if a == b {
+        return true;
+    }
+    // NaN is never close to anything, and an infinity that isn't exactly
+    // equal to the other value is infinitely far from it.
+    if a.is_nan() || b.is_nan() || a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+
+    let difference = (a - b).abs();
+    difference <= abs_tol || difference <= rel_tol * a.abs().max(b.abs())
+}
+
+// The floats either side of `x`, smaller first - nothing in between exists.
+// `next_down` and `next_up` step to the nearest representable value below
+// and above, whatever the sign: either side of zero (+0.0 or -0.0) they give
+// the smallest subnormals, past f64::MAX they give infinity, and a NaN stays
+// NaN.
+fn neighbours(x: f64) -> (f64, f64) {
+    (x.next_down(), x.next_up())
+}
+
+// Splits an f64 into its sign bit, 11 exponent bits and 52 mantissa bits.
+fn bit_pattern(x: f64) -> String {
+    let bits = format!("{:064b}", x.to_bits());
+    format!("{} {} {}", &bits[..1], &bits[1..12], &bits[12..])
+}
+
+fn calculate(a: f64, op: char, b: f64) -> Option<f64> {
+    match op {
+        '+' => Some(a + b),
+        '-' => Some(a - b),
+        '*' => Some(a * b),
+        '/' => Some(a / b),
+        _ => None,
+    }
+}
+
+fn parse_expression(line: &str) -> Result<(f64, char, f64), String> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let [a, op, b] = parts[..] else {
+        return Err("Expected `<number> <operation> <number>`, e.g. `0.1 + 0.2`.".to_string());
+    };
+    let parse = |text: &str| {
+        text.parse::<f64>()
+            .map_err(|_| format!("`{text}` isn't a number."))
+    };
+    let op = match op {
+        "+" | "-" | "*" | "/" => op.chars().next().expect("non-empty"),
+        _ => return Err(format!("`{op}` isn't one of + - * /.")),
+    };
+    Ok((parse(a)?, op, parse(b)?))
+}
+
+// Prints everything there is to know about one f64.
+fn explain(out: &mut dyn Write, x: f64) -> io::Result<()> {
+    writeln!(out, "  displayed as  {x}")?;
+    // `{:?}` prints the shortest text that parses back to exactly this value.
+    writeln!(out, "  debug         {x:?}")?;
+    writeln!(out, "  bits          {}", bit_pattern(x))?;
+    if x.is_finite() {
+        // With a precision, Rust prints the exact decimal value of the binary
+        // number rather than rounding to the shortest form.
+        writeln!(out, "  exact value   {x:.55}")?;
+        let (below, above) = neighbours(x);
+        writeln!(out, "  neighbours    {below:?} and {above:?}")?;
+        writeln!(
+            out,
+            "  so this result stands for every real number closer to {x:?} than to either neighbour."
+        )?;
+    }
+    Ok(())
+}
+
+// 0.1, 0.2, 0.3 and the sum itself are each rounded by at most half an
+// epsilon of their own size, so a couple of epsilons relative to the larger
+// side is enough to call `0.1 + 0.2` and `0.3` equal - approx_eq does the
+// scaling by magnitude.
+const SUM_TOLERANCE: f64 = 2.0 * f64::EPSILON;
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let sum = 0.1 + 0.2;
+    writeln!(out, "0.1 + 0.2 = {sum:?}")?;
+    writeln!(out, "0.1 + 0.2 == 0.3 is {}", sum == 0.3)?;
+    writeln!(out, "0.1 is really {:.30}", 0.1)?;
+    writeln!(
+        out,
+        "approx_eq(0.1 + 0.2, 0.3) is {}",
+        approx_eq(sum, 0.3, SUM_TOLERANCE, 0.0)
+    )?;
+    writeln!(out)?;
+
+    // The same sum with less precision rounds differently.
+    let tenth_f32: f32 = (0..10).map(|_| 0.1f32).sum();
+    let tenth_f64: f64 = (0..10).map(|_| 0.1f64).sum();
+    writeln!(out, "ten lots of 0.1 as f32 = {tenth_f32:?}")?;
+    writeln!(out, "ten lots of 0.1 as f64 = {tenth_f64:?}")?;
+    writeln!(
+        out,
+        "f32::EPSILON = {:e}, f64::EPSILON = {:e}",
+        f32::EPSILON,
+        f64::EPSILON
+    )?;
+    let nudged = calculate(1.0, '+', f64::EPSILON / 2.0).expect("valid operator");
+    writeln!(
+        out,
+        "1.0 + f64::EPSILON / 2.0 == 1.0 is {} - half an epsilon is lost",
+        nudged == 1.0
+    )?;
+    writeln!(out)?;
+
+    // Going through `calculate` hides the values from the compiler, which
+    // would otherwise (rightly) warn that `0.0 / 0.0` is always NaN.
+    let nan = f64::NAN;
+    for (a, op, b) in [
+        (1.0, '/', 0.0),
+        (-1.0, '/', 0.0),
+        (0.0, '/', 0.0),
+        (f64::INFINITY, '-', f64::INFINITY),
+    ] {
+        writeln!(out, "{a:?} {op} {b:?} = {:?}", calculate(a, op, b).unwrap())?;
+    }
+    writeln!(out, "NaN + 1.0 = {} - NaN propagates", nan + 1.0)?;
+    // Clippy warns about `nan == nan` as it's always false - which is the point.
+    #[allow(clippy::eq_op)]
+    let nan_equals_itself = nan == nan;
+    writeln!(out, "NaN == NaN is {nan_equals_itself}")?;
+    writeln!(out, "NaN.max(1.0) = {} - max/min ignore NaN", nan.max(1.0))?;
+
+    if options.interactive {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Enter a calculation like `0.1 + 0.2` to see exactly what the result is."
+        )?;
+        while let Some(line) = input::read_line("> ") {
+            match parse_expression(&line) {
+                Ok((a, op, b)) => {
+                    let result = calculate(a, op, b).expect("operator checked by the parser");
+                    explain(out, result)?;
+                }
+                Err(message) => writeln!(out, "{message}")?,
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REL: f64 = 1e-9;
+
+    #[test]
+    fn sum_of_tenths_is_approximately_equal() {
+        assert_ne!(0.1 + 0.2, 0.3);
+        assert!(approx_eq(0.1 + 0.2, 0.3, REL, 0.0));
+        assert!(approx_eq(0.1 + 0.2, 0.3, SUM_TOLERANCE, 0.0));
+        assert!(!approx_eq(
+            0.3,
+            0.3f64.next_up().next_up().next_up(),
+            SUM_TOLERANCE,
+            0.0
+        ));
+    }
+
+    #[test]
+    fn different_values_are_not_equal() {
+        assert!(!approx_eq(1.0, 1.1, REL, 0.0));
+        assert!(!approx_eq(1.0, -1.0, REL, 0.0));
+    }
+
+    #[test]
+    fn relative_tolerance_scales_with_magnitude() {
+        assert!(approx_eq(1e20, 1e20 + 1e10, REL, 0.0));
+        assert!(!approx_eq(1.0, 1.0 + 1e-6, REL, 0.0));
+    }
+
+    #[test]
+    fn zero_needs_an_absolute_tolerance() {
+        assert!(approx_eq(0.0, -0.0, REL, 0.0));
+        assert!(!approx_eq(0.0, 1e-12, REL, 0.0));
+        assert!(approx_eq(0.0, 1e-12, REL, 1e-10));
+        assert!(approx_eq(1e-12, -1e-12, 0.0, 1e-10));
+    }
+
+    #[test]
+    fn infinities_only_equal_themselves() {
+        assert!(approx_eq(f64::INFINITY, f64::INFINITY, REL, 0.0));
+        assert!(!approx_eq(f64::INFINITY, f64::NEG_INFINITY, REL, 0.0));
+        assert!(!approx_eq(f64::INFINITY, f64::MAX, 1.0, 0.0));
+        assert!(!approx_eq(f64::MAX, f64::INFINITY, REL, f64::MAX));
+    }
+
+    #[test]
+    fn nan_is_never_equal() {
+        assert!(!approx_eq(f64::NAN, f64::NAN, REL, 0.0));
+        assert!(!approx_eq(f64::NAN, 1.0, 1.0, f64::INFINITY));
+        assert!(!approx_eq(0.0, f64::NAN, REL, 1.0));
+    }
+
+    #[test]
+    fn renders_bit_patterns() {
+        assert_eq!(
+            bit_pattern(1.0),
+            "0 01111111111 0000000000000000000000000000000000000000000000000000"
+        );
+        assert_eq!(
+            bit_pattern(-2.0),
+            "1 10000000000 0000000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn neighbours_are_the_next_floats_down_and_up() {
+        let smallest = f64::from_bits(1);
+        assert_eq!(neighbours(0.0), (-smallest, smallest));
+        assert_eq!(neighbours(-0.0), (-smallest, smallest));
+        assert_eq!(
+            neighbours(1.0),
+            (1.0 - f64::EPSILON / 2.0, 1.0 + f64::EPSILON)
+        );
+        assert_eq!(
+            neighbours(-1.0),
+            (-1.0 - f64::EPSILON, -1.0 + f64::EPSILON / 2.0)
+        );
+        assert_eq!(neighbours(f64::MAX).1, f64::INFINITY);
+    }
+
+    #[test]
+    fn parses_calculator_input() {
+        assert_eq!(parse_expression("0.1 + 0.2"), Ok((0.1, '+', 0.2)));
+        assert!(parse_expression("0.1 % 0.2").is_err());
+        assert!(parse_expression("0.1+0.2").is_err());
+    }
+}
@@ -0,0 +1,401 @@
+/*
+   * Formatting numbers
+     * Everything after the `:` in `{:...}` is the format spec. In order:
+       * alignment with an optional fill character - `<`, `^` or `>` (numbers
+       are right aligned by default),
+       * `+` to always print the sign,
+       * `#` for the "alternate" form - `0x`, `0o` and `0b` prefixes,
+       * `0` to pad with zeros after the sign instead of spaces before it,
+       * the width, the minimum number of characters to print,
+       * `.precision`, the number of digits after the decimal point (ignored
+       for integers),
+       * the type - `x`/`X` hex, `o` octal, `b` binary, `e`/`E` scientific.
+     * `{:8.3}` and `{:width$.prec$}` are the same thing, except the second
+     reads the numbers from variables at runtime.
+     * There's no spec for thousands separators or SI prefixes, so
+     `human_readable` and `engineering` below build them from the digits.
+     * See https://doc.rust-lang.org/std/fmt/#syntax
+*/
+use std::io::{self, Write};
+
+use crate::input;
+use crate::Options;
+
+// Prints a `format!` call next to what it produces. `concat!` and
+// `stringify!` turn the arguments back into the source text.
+macro_rules! show {
+    ($out:expr, $spec:literal, $value:expr) => {
+        writeln!(
+            $out,
+            "{:<28} {:?}",
+            concat!("format!(\"", $spec, "\", ", stringify!($value), ")"),
+            format!($spec, $value)
+        )
+    };
+}
+
+// 1234567 -> "1,234,567".
+fn human_readable(n: u64) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        // A comma goes before every digit that starts a group of three,
+        // counting from the right.
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+const SI_PREFIXES: [&str; 11] = ["p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E"];
+// The index of the empty prefix - 10^0.
+const NO_PREFIX: i32 = 4;
+
+// 1500.0 -> "1.5k", 0.0023 -> "2.3m". One decimal place at most, and none if
+// it would be zero.
+fn engineering(x: f64) -> String {
+    if x == 0.0 || !x.is_finite() {
+        return x.to_string();
+    }
+
+    let max = SI_PREFIXES.len() as i32 - 1 - NO_PREFIX;
+    let min = -NO_PREFIX;
+    let mut power = ((x.abs().log10() / 3.0).floor() as i32).clamp(min, max);
+    let scale = |power: i32| (x / 1000f64.powi(power) * 10.0).round() / 10.0;
+    let mut scaled = scale(power);
+    // 999_960.0 rounds up to 1000.0k, which should be written 1M.
+    if scaled.abs() >= 1000.0 && power < max {
+        power += 1;
+        scaled = scale(power);
+    }
+
+    let prefix = SI_PREFIXES[(power + NO_PREFIX) as usize];
+    if scaled.fract() == 0.0 {
+        format!("{scaled:.0}{prefix}")
+    } else {
+        format!("{scaled:.1}{prefix}")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    Display,
+    LowerHex,
+    UpperHex,
+    Octal,
+    Binary,
+    LowerExp,
+    UpperExp,
+}
+
+// A runtime version of the numeric part of a format spec, for the
+// interactive mode: `[+][#][0][width][.precision][type]`.
+#[derive(Debug, Default, PartialEq)]
+struct Spec {
+    plus: bool,
+    alternate: bool,
+    zero: bool,
+    width: usize,
+    precision: Option<usize>,
+    kind: Option<Kind>,
+}
+
+fn parse_spec(text: &str) -> Result<Spec, String> {
+    let mut spec = Spec::default();
+    let mut rest = text.strip_prefix(':').unwrap_or(text);
+
+    if let Some(after) = rest.strip_prefix('+') {
+        spec.plus = true;
+        rest = after;
+    }
+    if let Some(after) = rest.strip_prefix('#') {
+        spec.alternate = true;
+        rest = after;
+    }
+    if let Some(after) = rest.strip_prefix('0') {
+        spec.zero = true;
+        rest = after;
+    }
+
+    let number_end = |s: &str| s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let end = number_end(rest);
+    if end > 0 {
+        spec.width = rest[..end].parse().map_err(|_| "The width is too large.")?;
+    }
+    rest = &rest[end..];
+
+    if let Some(after) = rest.strip_prefix('.') {
+        let end = number_end(after);
+        if end == 0 {
+            return Err("`.` must be followed by the precision.".to_string());
+        }
+        spec.precision = Some(
+            after[..end]
+                .parse()
+                .map_err(|_| "The precision is too large.")?,
+        );
+        rest = &after[end..];
+    }
+
+    spec.kind = match rest {
+        "" => None,
+        "x" => Some(Kind::LowerHex),
+        "X" => Some(Kind::UpperHex),
+        "o" => Some(Kind::Octal),
+        "b" => Some(Kind::Binary),
+        "e" => Some(Kind::LowerExp),
+        "E" => Some(Kind::UpperExp),
+        other => {
+            return Err(format!(
+                "`{other}` isn't a supported part of a format spec."
+            ))
+        }
+    };
+    Ok(spec)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Number {
+    Int(i64),
+    Float(f64),
+}
+
+fn parse_number(text: &str) -> Result<Number, String> {
+    if let Ok(int) = text.parse() {
+        return Ok(Number::Int(int));
+    }
+    text.parse()
+        .map(Number::Float)
+        .map_err(|_| format!("`{text}` isn't an i64 or an f64."))
+}
+
+// Formats `number` the way `format!` would with `spec`. The digits come from
+// `format!` itself - only the sign, prefix and padding are put together by
+// hand, since those flags can't be chosen at runtime.
+fn apply_spec(number: Number, spec: &Spec) -> Result<String, String> {
+    let kind = spec.kind.unwrap_or(Kind::Display);
+    let precision = spec.precision;
+
+    let (negative, digits) = match (number, kind) {
+        (Number::Float(_), Kind::LowerHex | Kind::UpperHex | Kind::Octal | Kind::Binary) => {
+            return Err("Floats can't be formatted in hex, octal or binary.".to_string());
+        }
+        // Like `format!`, these show negative numbers in two's complement.
+        (Number::Int(n), Kind::LowerHex) => (false, format!("{n:x}")),
+        (Number::Int(n), Kind::UpperHex) => (false, format!("{n:X}")),
+        (Number::Int(n), Kind::Octal) => (false, format!("{n:o}")),
+        (Number::Int(n), Kind::Binary) => (false, format!("{n:b}")),
+        (Number::Int(n), Kind::Display) => (n < 0, n.unsigned_abs().to_string()),
+        (Number::Int(n), Kind::LowerExp) => (n < 0, exp(n.unsigned_abs() as f64, precision)),
+        (Number::Int(n), Kind::UpperExp) => (
+            n < 0,
+            exp(n.unsigned_abs() as f64, precision).to_uppercase(),
+        ),
+        (Number::Float(x), Kind::Display) => (
+            x.is_sign_negative(),
+            match precision {
+                Some(precision) => format!("{:.precision$}", x.abs()),
+                None => x.abs().to_string(),
+            },
+        ),
+        (Number::Float(x), Kind::LowerExp) => (x.is_sign_negative(), exp(x.abs(), precision)),
+        (Number::Float(x), Kind::UpperExp) => {
+            (x.is_sign_negative(), exp(x.abs(), precision).to_uppercase())
+        }
+    };
+
+    let sign = match (negative, spec.plus) {
+        (true, _) => "-",
+        (false, true) => "+",
+        (false, false) => "",
+    };
+    let prefix = match (spec.alternate, kind) {
+        (true, Kind::LowerHex | Kind::UpperHex) => "0x",
+        (true, Kind::Octal) => "0o",
+        (true, Kind::Binary) => "0b",
+        _ => "",
+    };
+
+    let unpadded = sign.len() + prefix.len() + digits.len();
+    let padding = spec.width.saturating_sub(unpadded);
+    Ok(if spec.zero {
+        format!("{sign}{prefix}{}{digits}", "0".repeat(padding))
+    } else {
+        format!("{}{sign}{prefix}{digits}", " ".repeat(padding))
+    })
+}
+
+fn exp(x: f64, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{x:.precision$e}"),
+        None => format!("{x:e}"),
+    }
+}
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    show!(out, "{:8}", 42)?;
+    show!(out, "{:<8}", 42)?;
+    show!(out, "{:^8}", 42)?;
+    show!(out, "{:*>8}", 42)?;
+    show!(out, "{:08}", -42)?;
+    show!(out, "{:+}", 42)?;
+    show!(out, "{:.2}", 1.23456)?;
+    show!(out, "{:8.2}", 1.23456)?;
+    show!(out, "{:x}", 255)?;
+    show!(out, "{:#x}", 255)?;
+    show!(out, "{:#X}", 255)?;
+    show!(out, "{:#o}", 8)?;
+    show!(out, "{:#010b}", 5)?;
+    show!(out, "{:e}", 1234.5)?;
+    show!(out, "{:.2E}", 0.000123)?;
+    writeln!(out)?;
+
+    for n in [999, 1_000, 1_234_567, u64::MAX] {
+        writeln!(out, "human_readable({n}) = {}", human_readable(n))?;
+    }
+    for x in [999.0, 1_500.0, 2_300_000.0, 0.0023, -4.7e9] {
+        writeln!(out, "engineering({x}) = {}", engineering(x))?;
+    }
+
+    if options.interactive {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Enter a number and a format spec, e.g. `255 #010b` or `1.23456 +8.2`."
+        )?;
+        while let Some(line) = input::read_line("> ") {
+            let Some((number, spec)) = line.split_once(char::is_whitespace) else {
+                writeln!(out, "Expected a number, a space and then a format spec.")?;
+                continue;
+            };
+            match parse_number(number)
+                .and_then(|number| Ok((number, parse_spec(spec.trim())?)))
+                .and_then(|(number, spec)| apply_spec(number, &spec))
+            {
+                Ok(formatted) => writeln!(
+                    out,
+                    "format!(\"{{:{}}}\", {number}) = {formatted:?}",
+                    spec.trim()
+                )?,
+                Err(message) => writeln!(out, "{message}")?,
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn human_readable_groups_thousands() {
+        assert_eq!(human_readable(0), "0");
+        assert_eq!(human_readable(7), "7");
+        assert_eq!(human_readable(999), "999");
+        assert_eq!(human_readable(1_000), "1,000");
+        assert_eq!(human_readable(12_345), "12,345");
+        assert_eq!(human_readable(999_999), "999,999");
+        assert_eq!(human_readable(1_000_000), "1,000,000");
+        assert_eq!(human_readable(u64::MAX), "18,446,744,073,709,551,615");
+    }
+
+    #[test]
+    fn engineering_uses_si_prefixes() {
+        assert_eq!(engineering(0.0), "0");
+        assert_eq!(engineering(999.0), "999");
+        assert_eq!(engineering(1_000.0), "1k");
+        assert_eq!(engineering(1_500.0), "1.5k");
+        assert_eq!(engineering(2_300_000.0), "2.3M");
+        assert_eq!(engineering(1e9), "1G");
+        assert_eq!(engineering(-4.7e9), "-4.7G");
+        assert_eq!(engineering(u64::MAX as f64), "18.4E");
+    }
+
+    #[test]
+    fn engineering_handles_small_values() {
+        assert_eq!(engineering(0.5), "500m");
+        assert_eq!(engineering(0.001), "1m");
+        assert_eq!(engineering(0.0000023), "2.3µ");
+        assert_eq!(engineering(1.0), "1");
+    }
+
+    #[test]
+    fn engineering_rounding_moves_to_the_next_prefix() {
+        assert_eq!(engineering(999_960.0), "1M");
+        assert_eq!(engineering(999_940.0), "999.9k");
+    }
+
+    #[test]
+    fn engineering_beyond_the_prefixes() {
+        assert_eq!(engineering(1e21), "1000E");
+        assert_eq!(engineering(f64::INFINITY), "inf");
+        assert_eq!(engineering(f64::NAN), "NaN");
+    }
+
+    #[test]
+    fn parses_specs() {
+        assert_eq!(
+            parse_spec("+#010.3x"),
+            Ok(Spec {
+                plus: true,
+                alternate: true,
+                zero: true,
+                width: 10,
+                precision: Some(3),
+                kind: Some(Kind::LowerHex),
+            })
+        );
+        assert_eq!(
+            parse_spec(":8"),
+            Ok(Spec {
+                width: 8,
+                ..Spec::default()
+            })
+        );
+        assert_eq!(parse_spec(""), Ok(Spec::default()));
+        assert!(parse_spec("8.").is_err());
+        assert!(parse_spec("q").is_err());
+    }
+
+    // The runtime specs must agree with the compile-time ones.
+    #[test]
+    fn apply_spec_matches_format() {
+        let check = |number, spec: &str, expected: String| {
+            let spec = parse_spec(spec).unwrap();
+            assert_eq!(apply_spec(number, &spec).unwrap(), expected, "{spec:?}");
+        };
+        check(Number::Int(42), "8", format!("{:8}", 42));
+        check(Number::Int(-42), "08", format!("{:08}", -42));
+        check(Number::Int(42), "+", format!("{:+}", 42));
+        check(Number::Int(255), "#x", format!("{:#x}", 255));
+        check(Number::Int(255), "#010X", format!("{:#010X}", 255));
+        check(Number::Int(5), "#010b", format!("{:#010b}", 5));
+        check(Number::Int(8), "o", format!("{:o}", 8));
+        check(Number::Int(-1), "x", format!("{:x}", -1i64));
+        check(Number::Int(1234), ".2e", format!("{:.2e}", 1234.0));
+        check(Number::Float(1.23456), "8.2", format!("{:8.2}", 1.23456));
+        check(
+            Number::Float(-1.23456),
+            "+09.3",
+            format!("{:+09.3}", -1.23456),
+        );
+        check(Number::Float(0.000123), ".2E", format!("{:.2E}", 0.000123));
+        check(Number::Float(2.5), "", format!("{}", 2.5));
+    }
+
+    #[test]
+    fn floats_have_no_radix_forms() {
+        let spec = parse_spec("x").unwrap();
+        assert!(apply_spec(Number::Float(1.5), &spec).is_err());
+    }
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(parse_number("-7"), Ok(Number::Int(-7)));
+        assert_eq!(parse_number("2.5"), Ok(Number::Float(2.5)));
+        assert!(parse_number("seven").is_err());
+    }
+}
@@ -0,0 +1,167 @@
+/*
+   * Type inference
+     * Rust is statically typed, but most `let`s don't need a type because the
+     compiler works it out - from the value, and from how the variable is
+     used later on.
+     * Inference flows both ways: `let mut v = Vec::new();` doesn't say what
+     the Vec holds until a later `v.push(1u8)` pins it to `Vec<u8>`.
+     * An integer literal with nothing else to go on is an `i32`, and a float
+     literal an `f64`. An annotation (`let x: u64 = 5;`), a suffix (`5u8`) or
+     the parameter type of a function it's passed to changes that.
+     * `collect()` can build many collection types, so it usually needs to be
+     told which - `let v: Vec<_> = ...` or `.collect::<Vec<_>>()` (the
+     "turbofish"). The `_` asks the compiler to infer the element type.
+     * Every closure has its own unique, unnameable type. `type_name` shows a
+     made-up name for it, not something you could write in your code.
+     * `std::any::type_name` is meant for debugging - the exact text isn't
+     guaranteed to stay the same between compiler versions.
+*/
+use std::any;
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+use crate::Options;
+
+// Generic over whatever `T` the compiler inferred for the argument, so it
+// reports that type rather than converting the value to anything.
+pub fn type_name_of<T>(_: &T) -> &'static str {
+    any::type_name::<T>()
+}
+
+fn takes_i64(x: i64) -> i64 {
+    x
+}
+
+// An expression as written in the source, the type inferred for it and the
+// reason.
+struct Inferred {
+    expression: &'static str,
+    type_name: &'static str,
+    reason: &'static str,
+}
+
+fn inferred_types() -> Vec<Inferred> {
+    let integer = 5;
+    let float = 2.5;
+    let length = "x".len();
+    let annotated: u64 = 5;
+    let suffixed = 5u8;
+    let collected: Vec<_> = (1..4).collect();
+    let set = (1..4).map(|x| x * 2).collect::<HashSet<_>>();
+    let closure = |x: i32| x + 1;
+
+    // These two get their types from code that comes after the `let`.
+    let mut pushed = Vec::new();
+    let passed = 3;
+    pushed.push(1u8);
+    takes_i64(passed);
+
+    vec![
+        Inferred {
+            expression: "let integer = 5;",
+            type_name: type_name_of(&integer),
+            reason: "integer literals default to i32",
+        },
+        Inferred {
+            expression: "let float = 2.5;",
+            type_name: type_name_of(&float),
+            reason: "float literals default to f64",
+        },
+        Inferred {
+            expression: "let length = \"x\".len();",
+            type_name: type_name_of(&length),
+            reason: "the return type of `str::len`",
+        },
+        Inferred {
+            expression: "let annotated: u64 = 5;",
+            type_name: type_name_of(&annotated),
+            reason: "the annotation changes the literal's type",
+        },
+        Inferred {
+            expression: "let suffixed = 5u8;",
+            type_name: type_name_of(&suffixed),
+            reason: "so does a suffix",
+        },
+        Inferred {
+            expression: "let collected: Vec<_> = (1..4).collect();",
+            type_name: type_name_of(&collected),
+            reason: "the annotation picks the collection, the range the element",
+        },
+        Inferred {
+            expression: "(1..4).map(|x| x * 2).collect::<HashSet<_>>()",
+            type_name: type_name_of(&set),
+            reason: "the turbofish picks the collection instead",
+        },
+        Inferred {
+            expression: "let closure = |x: i32| x + 1;",
+            type_name: type_name_of(&closure),
+            reason: "every closure has its own anonymous type",
+        },
+        Inferred {
+            expression: "let mut pushed = Vec::new(); pushed.push(1u8);",
+            type_name: type_name_of(&pushed),
+            reason: "worked out backwards from the later `push`",
+        },
+        Inferred {
+            expression: "let passed = 3; takes_i64(passed);",
+            type_name: type_name_of(&passed),
+            reason: "worked out backwards from the function's parameter",
+        },
+    ]
+}
+
+pub fn run(_options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    for Inferred {
+        expression,
+        type_name,
+        reason,
+    } in inferred_types()
+    {
+        writeln!(out, "{expression}")?;
+        writeln!(out, "  -> {type_name} ({reason})")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_of(expression: &str) -> &'static str {
+        inferred_types()
+            .into_iter()
+            .find(|inferred| inferred.expression == expression)
+            .unwrap_or_else(|| panic!("no example for `{expression}`"))
+            .type_name
+    }
+
+    #[test]
+    fn literals_use_the_default_types() {
+        assert_eq!(type_of("let integer = 5;"), "i32");
+        assert_eq!(type_of("let float = 2.5;"), "f64");
+    }
+
+    #[test]
+    fn annotations_and_suffixes_change_the_type() {
+        assert_eq!(type_of("let annotated: u64 = 5;"), "u64");
+        assert_eq!(type_of("let suffixed = 5u8;"), "u8");
+    }
+
+    #[test]
+    fn method_return_types() {
+        assert_eq!(type_of("let length = \"x\".len();"), "usize");
+        assert_eq!(type_name_of(&"x"), "&str");
+    }
+
+    #[test]
+    fn inference_flows_backwards_from_usage() {
+        assert_eq!(type_of("let passed = 3; takes_i64(passed);"), "i64");
+        // The path to `Vec` isn't guaranteed, but the element type is.
+        assert!(type_of("let mut pushed = Vec::new(); pushed.push(1u8);").ends_with("Vec<u8>"));
+    }
+
+    #[test]
+    fn collected_element_type_comes_from_the_range() {
+        assert!(type_of("let collected: Vec<_> = (1..4).collect();").ends_with("Vec<i32>"));
+    }
+}
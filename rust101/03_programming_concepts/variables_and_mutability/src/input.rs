@@ -0,0 +1,21 @@
+// Shared helpers for the interactive parts of the tour.
+use std::io::{self, Write};
+
+// Prints `prompt`, then reads one line from stdin. Returns `None` once stdin is
+// closed or when the user types `quit`, so callers can simply loop with
+// `while let Some(line) = read_line(...)`.
+pub fn read_line(prompt: &str) -> Option<String> {
+    print!("{prompt}");
+    // `print!` doesn't add a newline, and stdout is line buffered - without
+    // the flush the prompt wouldn't appear until after the user had answered.
+    io::stdout().flush().expect("Failed to flush stdout.");
+
+    let mut line = String::new();
+    match io::stdin().read_line(&mut line) {
+        // `Ok(0)` means end of input (Ctrl+D) rather than an empty line, which
+        // would still contain the newline character.
+        Ok(0) | Err(_) => None,
+        Ok(_) if line.trim() == "quit" => None,
+        Ok(_) => Some(line.trim().to_string()),
+    }
+}
@@ -0,0 +1,519 @@
+/*
+   * Numeric literals
+     * Underscores can go anywhere after the first digit to make a literal
+     easier to read: `1_000_000` is one million. They're ignored by the
+     compiler.
+     * Prefixes change the base: `0x` hex, `0o` octal, `0b` binary. Note the
+     octal prefix is `0o`, not C's leading zero - `017` is seventeen in Rust.
+     * `b'A'` is a byte literal - the u8 value of an ASCII character (65).
+     * A suffix sets the type: `57u8`, `1_000i64`, `2.5f32`. With no suffix the
+     compiler infers the type from how the value is used, falling back to
+     `i32` for integers and `f64` for floats.
+     * Hex literals can't take a float suffix because `f` is a hex digit:
+     `0x1f32` is the integer 7986, not a float. Binary and octal literals
+     can't be floats either, so `0b1f32` is an error rather than 1.0.
+     * Literals are never negative - `-5` is the negation operator applied to
+     the literal `5`.
+     * A literal that doesn't fit its type (`300u8`) is a compile-time error:
+     "literal out of range for `u8`".
+   * `parse_literal` below accepts the same forms at runtime and reports
+   which type the compiler would give the literal.
+*/
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::input;
+use crate::Options;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum NumType {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    F32,
+    F64,
+}
+
+use NumType::*;
+
+// Every type suffix, used both to parse literals and to name the types. No
+// suffix is the end of another one, so the order doesn't matter.
+const SUFFIXES: [(&str, NumType); 14] = [
+    ("i8", I8),
+    ("i16", I16),
+    ("i32", I32),
+    ("i64", I64),
+    ("i128", I128),
+    ("isize", Isize),
+    ("u8", U8),
+    ("u16", U16),
+    ("u32", U32),
+    ("u64", U64),
+    ("u128", U128),
+    ("usize", Usize),
+    ("f32", F32),
+    ("f64", F64),
+];
+
+impl NumType {
+    fn name(self) -> &'static str {
+        SUFFIXES
+            .iter()
+            .find(|(_, ty)| *ty == self)
+            .map(|(name, _)| *name)
+            .expect("every type has a suffix")
+    }
+
+    fn is_float(self) -> bool {
+        matches!(self, F32 | F64)
+    }
+
+    // The largest value a literal of this type can hold. Literals are never
+    // negative, so the signed types top out at their MAX.
+    fn max(self) -> u128 {
+        match self {
+            I8 => i8::MAX as u128,
+            I16 => i16::MAX as u128,
+            I32 => i32::MAX as u128,
+            I64 => i64::MAX as u128,
+            I128 => i128::MAX as u128,
+            Isize => isize::MAX as u128,
+            U8 => u8::MAX as u128,
+            U16 => u16::MAX as u128,
+            U32 => u32::MAX as u128,
+            U64 => u64::MAX as u128,
+            U128 | F32 | F64 => u128::MAX,
+            Usize => usize::MAX as u128,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Value {
+    Int(u128),
+    Float(f64),
+}
+
+#[derive(Debug, PartialEq)]
+struct ParsedLiteral {
+    value: Value,
+    ty: NumType,
+    // `true` when there was no suffix and the type is the compiler's default.
+    inferred: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum ErrorKind {
+    Empty,
+    InvalidDigit { found: char, radix: u32 },
+    MisplacedUnderscore,
+    IntSuffixOnFloat,
+    FloatInRadix,
+    OutOfRange(NumType),
+    BadByteLiteral,
+}
+
+// `index` is the byte offset of the problem in the input, ready to be turned
+// into a `^` under the offending character.
+#[derive(Debug, PartialEq)]
+struct LiteralError {
+    index: usize,
+    kind: ErrorKind,
+}
+
+impl fmt::Display for LiteralError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::Empty => write!(f, "empty literal")?,
+            ErrorKind::InvalidDigit { found, radix } => {
+                write!(f, "invalid digit {found:?} for a base {radix} literal")?
+            }
+            ErrorKind::MisplacedUnderscore => write!(f, "a literal can't start with `_`")?,
+            ErrorKind::IntSuffixOnFloat => write!(f, "integer suffix on a float literal")?,
+            ErrorKind::FloatInRadix => write!(f, "only decimal literals can be floats")?,
+            ErrorKind::OutOfRange(ty) => write!(f, "literal out of range for `{}`", ty.name())?,
+            ErrorKind::BadByteLiteral => write!(f, "malformed byte literal")?,
+        }
+        write!(f, " at index {}", self.index)
+    }
+}
+
+fn error(index: usize, kind: ErrorKind) -> LiteralError {
+    LiteralError { index, kind }
+}
+
+fn parse_literal(text: &str) -> Result<ParsedLiteral, LiteralError> {
+    if text.is_empty() {
+        return Err(error(0, ErrorKind::Empty));
+    }
+    if text.starts_with("b'") {
+        return parse_byte(text);
+    }
+    if text.starts_with('_') {
+        return Err(error(0, ErrorKind::MisplacedUnderscore));
+    }
+
+    let (radix, start) = match text.get(..2) {
+        Some("0x") => (16, 2),
+        Some("0o") => (8, 2),
+        Some("0b") => (2, 2),
+        _ => (10, 0),
+    };
+
+    // Split off the suffix. Only decimal literals can be floats, so float
+    // suffixes are only recognised there - in hex `f` is just a digit, and in
+    // binary or octal it's an invalid one.
+    let (body_end, suffix) = SUFFIXES
+        .iter()
+        .filter(|(_, ty)| radix == 10 || !ty.is_float())
+        .find(|(name, _)| text.len() - start > name.len() && text.ends_with(name))
+        .map(|(name, ty)| (text.len() - name.len(), Some(*ty)))
+        .unwrap_or((text.len(), None));
+    let body = &text[start..body_end];
+
+    let is_float_body = radix == 10 && body.contains(['.', 'e', 'E']);
+    if radix != 10 && body.contains('.') {
+        let index = start + body.find('.').expect("checked by contains");
+        return Err(error(index, ErrorKind::FloatInRadix));
+    }
+    if body.is_empty() {
+        return Err(error(start, ErrorKind::Empty));
+    }
+
+    if is_float_body || suffix.is_some_and(NumType::is_float) {
+        if suffix.is_some_and(|ty| !ty.is_float()) {
+            return Err(error(body_end, ErrorKind::IntSuffixOnFloat));
+        }
+        return parse_float(body, start, suffix);
+    }
+
+    let value = parse_digits(body, start, radix)?;
+    let ty = suffix.unwrap_or(I32);
+    if value > ty.max() {
+        return Err(error(0, ErrorKind::OutOfRange(ty)));
+    }
+    Ok(ParsedLiteral {
+        value: Value::Int(value),
+        ty,
+        inferred: suffix.is_none(),
+    })
+}
+
+// Builds the value digit by digit - the same thing `from_str_radix` does,
+// but done by hand so we know exactly which character was bad.
+fn parse_digits(body: &str, offset: usize, radix: u32) -> Result<u128, LiteralError> {
+    let mut value: u128 = 0;
+    for (i, c) in body.char_indices() {
+        if c == '_' {
+            continue;
+        }
+        let digit = c.to_digit(radix).ok_or(error(
+            offset + i,
+            ErrorKind::InvalidDigit { found: c, radix },
+        ))?;
+        value = value
+            .checked_mul(u128::from(radix))
+            .and_then(|v| v.checked_add(u128::from(digit)))
+            .ok_or(error(0, ErrorKind::OutOfRange(U128)))?;
+    }
+    Ok(value)
+}
+
+fn parse_float(
+    body: &str,
+    offset: usize,
+    suffix: Option<NumType>,
+) -> Result<ParsedLiteral, LiteralError> {
+    // Check the characters ourselves so the error points at the right place;
+    // `f64::from_str` would only say "invalid float literal".
+    let mut previous = ' ';
+    for (i, c) in body.char_indices() {
+        let allowed = c.is_ascii_digit()
+            || c == '_'
+            || c == '.'
+            || c == 'e'
+            || c == 'E'
+            || ((c == '+' || c == '-') && matches!(previous, 'e' | 'E'));
+        if !allowed {
+            return Err(error(
+                offset + i,
+                ErrorKind::InvalidDigit {
+                    found: c,
+                    radix: 10,
+                },
+            ));
+        }
+        previous = c;
+    }
+
+    let cleaned: String = body.chars().filter(|c| *c != '_').collect();
+    let value: f64 = cleaned.parse().map_err(|_| {
+        let index = offset + body.rfind(['.', 'e', 'E']).unwrap_or(0);
+        error(
+            index,
+            ErrorKind::InvalidDigit {
+                found: body[index - offset..].chars().next().unwrap_or(' '),
+                radix: 10,
+            },
+        )
+    })?;
+    let ty = suffix.unwrap_or(F64);
+    if ty == F32 && value.is_finite() && (value as f32).is_infinite() {
+        return Err(error(0, ErrorKind::OutOfRange(F32)));
+    }
+
+    Ok(ParsedLiteral {
+        value: Value::Float(value),
+        ty,
+        inferred: suffix.is_none(),
+    })
+}
+
+fn parse_byte(text: &str) -> Result<ParsedLiteral, LiteralError> {
+    let bad = |index| error(index, ErrorKind::BadByteLiteral);
+    let Some(inner) = text[2..].strip_suffix('\'') else {
+        return Err(bad(text.len()));
+    };
+
+    let value = match inner.as_bytes() {
+        [b'\\', b'n'] => b'\n',
+        [b'\\', b't'] => b'\t',
+        [b'\\', b'r'] => b'\r',
+        [b'\\', b'0'] => b'\0',
+        [b'\\', b'\\'] => b'\\',
+        [b'\\', b'\''] => b'\'',
+        [b'\\', b'x', hex @ ..] if hex.len() == 2 => {
+            u8::from_str_radix(&inner[2..], 16).map_err(|_| bad(4))?
+        }
+        [b'\\', ..] => return Err(bad(3)),
+        [byte] if byte.is_ascii() && *byte != b'\'' => *byte,
+        _ => return Err(bad(2)),
+    };
+
+    Ok(ParsedLiteral {
+        value: Value::Int(u128::from(value)),
+        ty: U8,
+        inferred: false,
+    })
+}
+
+fn describe(text: &str) -> String {
+    match parse_literal(text) {
+        Ok(literal) => {
+            let value = match literal.value {
+                Value::Int(value) => value.to_string(),
+                Value::Float(value) => format!("{value:?}"),
+            };
+            let how = if literal.inferred {
+                "inferred default"
+            } else {
+                "from the literal"
+            };
+            format!("{text} = {value}: {} ({how})", literal.ty.name())
+        }
+        Err(error) => format!("{text}\n{}^ {error}", " ".repeat(error.index)),
+    }
+}
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    // The compiler's view - these are real literals, checked at compile time.
+    writeln!(out, "1_000_000   = {}", 1_000_000)?;
+    writeln!(out, "0xff        = {}", 0xff)?;
+    writeln!(out, "0o77        = {}", 0o77)?;
+    writeln!(out, "0b1111_0000 = {}", 0b1111_0000)?;
+    writeln!(out, "b'A'        = {}", b'A')?;
+    writeln!(out, "57u8        = {}", 57u8)?;
+    writeln!(out, "1e3         = {}", 1e3)?;
+    writeln!(
+        out,
+        "0x1f32      = {} (an integer - `f` is a hex digit)",
+        0x1f32
+    )?;
+    writeln!(out)?;
+
+    // And the same forms through the runtime parser.
+    for text in [
+        "1_000_000",
+        "0xffu16",
+        "0b1010i8",
+        "2.5f32",
+        "300u8",
+        "0b102",
+    ] {
+        writeln!(out, "{}", describe(text))?;
+    }
+
+    if options.interactive {
+        writeln!(out)?;
+        writeln!(out, "Type a numeric literal, e.g. `0x_ff_u8` or `1e-3f32`.")?;
+        while let Some(line) = input::read_line("> ") {
+            match parse_literal(&line) {
+                Ok(_) => writeln!(out, "{}", describe(&line))?,
+                // The input is already on screen after the two character
+                // prompt, so the `^` is offset by two to line up under it.
+                Err(error) => writeln!(out, "  {}^ {error}", " ".repeat(error.index))?,
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int(value: u128, ty: NumType, inferred: bool) -> Result<ParsedLiteral, LiteralError> {
+        Ok(ParsedLiteral {
+            value: Value::Int(value),
+            ty,
+            inferred,
+        })
+    }
+
+    fn float(value: f64, ty: NumType, inferred: bool) -> Result<ParsedLiteral, LiteralError> {
+        Ok(ParsedLiteral {
+            value: Value::Float(value),
+            ty,
+            inferred,
+        })
+    }
+
+    fn err(index: usize, kind: ErrorKind) -> Result<ParsedLiteral, LiteralError> {
+        Err(LiteralError { index, kind })
+    }
+
+    #[test]
+    fn parses_literal_forms() {
+        let cases = [
+            ("0", int(0, I32, true)),
+            ("1_000_000", int(1_000_000, I32, true)),
+            ("0xff", int(255, I32, true)),
+            ("0xffu16", int(255, U16, false)),
+            ("0xFF_FF", int(0xffff, I32, true)),
+            ("0o777", int(0o777, I32, true)),
+            ("0b1010i8", int(10, I8, false)),
+            ("0b_1010", int(10, I32, true)),
+            ("017", int(17, I32, true)),
+            ("57u8", int(57, U8, false)),
+            ("255u8", int(255, U8, false)),
+            ("127i8", int(127, I8, false)),
+            ("1_usize", int(1, Usize, false)),
+            ("0x1f32", int(0x1f32, I32, true)),
+            (
+                "340282366920938463463374607431768211455u128",
+                int(u128::MAX, U128, false),
+            ),
+            ("b'A'", int(65, U8, false)),
+            ("b'\\n'", int(10, U8, false)),
+            ("b'\\x7f'", int(127, U8, false)),
+            ("2.5", float(2.5, F64, true)),
+            ("2.5f32", float(2.5, F32, false)),
+            ("1e3", float(1000.0, F64, true)),
+            ("1E-3", float(0.001, F64, true)),
+            ("1_000.5", float(1000.5, F64, true)),
+            ("1.", float(1.0, F64, true)),
+            ("7f64", float(7.0, F64, false)),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(parse_literal(text), expected, "parsing {text}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_literals() {
+        let cases = [
+            ("", err(0, ErrorKind::Empty)),
+            ("0x", err(2, ErrorKind::Empty)),
+            ("_1", err(0, ErrorKind::MisplacedUnderscore)),
+            ("300u8", err(0, ErrorKind::OutOfRange(U8))),
+            ("128i8", err(0, ErrorKind::OutOfRange(I8))),
+            ("2147483648", err(0, ErrorKind::OutOfRange(I32))),
+            ("1e40f32", err(0, ErrorKind::OutOfRange(F32))),
+            (
+                "0b102",
+                err(
+                    4,
+                    ErrorKind::InvalidDigit {
+                        found: '2',
+                        radix: 2,
+                    },
+                ),
+            ),
+            (
+                "0o8",
+                err(
+                    2,
+                    ErrorKind::InvalidDigit {
+                        found: '8',
+                        radix: 8,
+                    },
+                ),
+            ),
+            (
+                "12a4",
+                err(
+                    2,
+                    ErrorKind::InvalidDigit {
+                        found: 'a',
+                        radix: 10,
+                    },
+                ),
+            ),
+            (
+                "-5",
+                err(
+                    0,
+                    ErrorKind::InvalidDigit {
+                        found: '-',
+                        radix: 10,
+                    },
+                ),
+            ),
+            ("1.5i32", err(3, ErrorKind::IntSuffixOnFloat)),
+            ("0x1.5", err(3, ErrorKind::FloatInRadix)),
+            (
+                "0b1f32",
+                err(
+                    3,
+                    ErrorKind::InvalidDigit {
+                        found: 'f',
+                        radix: 2,
+                    },
+                ),
+            ),
+            (
+                "0o7f64",
+                err(
+                    3,
+                    ErrorKind::InvalidDigit {
+                        found: 'f',
+                        radix: 8,
+                    },
+                ),
+            ),
+            ("b'AB'", err(2, ErrorKind::BadByteLiteral)),
+            ("b'A", err(3, ErrorKind::BadByteLiteral)),
+            ("b'é'", err(2, ErrorKind::BadByteLiteral)),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(parse_literal(text), expected, "parsing {text}");
+        }
+    }
+
+    #[test]
+    fn error_points_at_the_offending_character() {
+        assert_eq!(
+            describe("0b102"),
+            "0b102\n    ^ invalid digit '2' for a base 2 literal at index 4"
+        );
+    }
+}
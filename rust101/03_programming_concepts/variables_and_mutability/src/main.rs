@@ -1,67 +1,480 @@
-/*
-   * Variables
-     * By default, variables in Rust are immutable. Rust will immediately catch
-     mutability errors as soon as you type them and will refuse to compile if
-     you try to build/run anyway.
-     * You don't have to explicitly specify a type for variables, but the
-     rust-analyzer will suggest one based on the value you assign. This is the
-     type that Rust will infer for the type.
-   * Constants
-     * Constants are similar but different to immutable variables. The `mut`
-     keyword doesn't work with the `const` keyword and constants must have a
-     type specified.
-     * Constant identifiers in Rust are ALL_CAPS_SNAKE_CASE.
-     * Rust attempts to evaluate complicated constants at compile time - see
-     https://doc.rust-lang.org/reference/const_eval.html for more.
-     * Constants are valid for the entire runtime of the program WITHIN THE
-     SCOPE THEY WERE DECLARED IN. This makes constants useful for unchanging
-     values that multiple parts of your code may need to know about.
-   * Shadowing
-     * Variables can be shadowed by repeating the `let` keyword. Note that this
-     is different from using the `mut` to denote the variable mutable and is NOT
-     a compile-time error.
-     * Shadowing within a scope is only valid within that scope - variables that
-     are created in one scope and then shadowed in an enclosed scope will keep
-     their value until the end of the scope at which point they take on the
-     original value again (see example below).
-     * Shadowing allows us to modify a variable but also keep the variable
-     immutable once we're finished with it.
-     * Shadowing also effectively allows us to change the type of a variable.
+/* Things learned during this exercise:
+   * The program is now a 'tour' - each concept lives in its own function and
+   is registered in the `SECTIONS` table below. `cargo run` runs every section
+   in order, `cargo run -- shadowing` runs just one and `cargo run -- --list`
+   prints the available names.
+   * Note the `--` in `cargo run -- shadowing` - everything before it is an
+   argument to cargo, everything after it is passed to our binary.
+   * `std::env::args()` returns an iterator over the arguments. The first item
+   is (usually) the path of the binary itself, hence the `.skip(1)`.
+   * Functions are values in Rust - `fn(&Options)` is the type of a plain
+   function pointer that takes a reference to the options and returns
+   nothing, which lets us store the section functions in a `const` table
+   alongside their names.
+   * Some sections have an interactive mode - `cargo run -- overflow
+   --interactive` runs the demonstration and then prompts for input until you
+   type `quit` or close stdin (Ctrl+D, or Ctrl+Z then Enter on Windows).
+   * Anything after the section name is passed to the section as its input,
+   e.g. `cargo run -- chars "naïve café"`.
+   * `cargo run -- --report tour.md` writes a Markdown report instead of
+   printing. Each section writes to a `&mut dyn Write` rather than calling
+   `println!`, so its output can go to stdout or into a `Vec<u8>` buffer
+   without the section knowing which - `Write` is implemented by both.
+   * `mod variables;` tells the compiler to look for the module in
+   `src/variables.rs`. Items inside a module are private by default, so the
+   section functions have to be marked `pub` to be called from here.
 */
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+
+mod assertions;
+mod bitwise;
+mod booleans;
+mod chars;
+mod compile_checks;
+mod compound_types;
+mod conversions;
+mod data_types;
+mod expressions;
+mod floats;
+mod formatting;
+mod inference;
+mod input;
+mod literals;
+mod mut_refs;
+mod overflow;
+mod ranges;
+mod report;
+mod shadow_or_mut;
+mod statics;
+mod strings;
+mod variables;
+
+// A section of the tour - the name used on the command line, a one line
+// description for `--list` and the function that demonstrates the concept.
+struct Section {
+    name: &'static str,
+    description: &'static str,
+    run: fn(&Options, &mut dyn Write) -> io::Result<()>,
+}
+
+// Flags that apply to whichever section is being run. Sections read the
+// options they care about and ignore the rest.
+#[derive(Debug, Default, PartialEq)]
+pub struct Options {
+    pub interactive: bool,
+    pub format: Format,
+    pub input: Option<String>,
+    pub report: Option<PathBuf>,
+}
+
+// How sections that print tables should lay them out.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Format {
+    #[default]
+    Ascii,
+    Csv,
+}
+
+const SECTIONS: &[Section] = &[
+    Section {
+        name: "variables",
+        description: "Immutable variables and type annotations",
+        run: variables::variables,
+    },
+    Section {
+        name: "mutability",
+        description: "Mutable variables with the `mut` keyword",
+        run: variables::mutability,
+    },
+    Section {
+        name: "constants",
+        description: "Compile-time constants with `const`",
+        run: variables::constants,
+    },
+    Section {
+        name: "shadowing",
+        description: "Re-declaring a variable with `let`, including changing its type",
+        run: variables::shadowing,
+    },
+    Section {
+        name: "scope",
+        description: "Shadowing inside a block ends with the block",
+        run: variables::scope,
+    },
+    Section {
+        name: "overflow",
+        description: "Integer overflow: checked, wrapping, saturating and overflowing",
+        run: overflow::run,
+    },
+    Section {
+        name: "data_types",
+        description: "Every primitive scalar type with its size and range (`--format ascii|csv`)",
+        run: data_types::run,
+    },
+    Section {
+        name: "conversions",
+        description: "`as` casts versus `TryFrom` conversions",
+        run: conversions::run,
+    },
+    Section {
+        name: "literals",
+        description: "Numeric literal forms, and a parser that accepts them at runtime",
+        run: literals::run,
+    },
+    Section {
+        name: "floats",
+        description: "Floating-point precision, approximate comparison, NaN and infinity",
+        run: floats::run,
+    },
+    Section {
+        name: "chars",
+        description: "`char`, Unicode code points and UTF-8 (pass text to inspect it)",
+        run: chars::run,
+    },
+    Section {
+        name: "statics",
+        description: "`const` versus `static`, and `const fn` at compile time and runtime",
+        run: statics::run,
+    },
+    Section {
+        name: "shadow_or_mut",
+        description: "The same transformations with shadowing and with `mut`, and when to use each",
+        run: shadow_or_mut::run,
+    },
+    Section {
+        name: "bitwise",
+        description: "Bitwise operators and shifts, with a bit pattern visualiser",
+        run: bitwise::run,
+    },
+    Section {
+        name: "strings",
+        description: "When to use `String` and when to use `&str`, and slicing UTF-8 safely",
+        run: strings::run,
+    },
+    Section {
+        name: "expressions",
+        description: "Blocks, `if`, `match` and `loop` as expressions, and the statement versions",
+        run: expressions::run,
+    },
+    Section {
+        name: "booleans",
+        description: "Truth tables, short-circuit evaluation and chained comparisons",
+        run: booleans::run,
+    },
+    Section {
+        name: "inference",
+        description: "The types the compiler infers, including from later usage",
+        run: inference::run,
+    },
+    Section {
+        name: "formatting",
+        description: "Format specs for numbers, thousands separators and SI prefixes",
+        run: formatting::run,
+    },
+    Section {
+        name: "mut_refs",
+        description: "`&mut` borrows, the aliasing rule, and `mem::swap`, `replace` and `take`",
+        run: mut_refs::run,
+    },
+    Section {
+        name: "compound_types",
+        description: "Tuples, arrays and slices, and indexing without panicking",
+        run: compound_types::run,
+    },
+    Section {
+        name: "compile_checks",
+        description: "Assertions checked at compile time with `static_assert!`",
+        run: compile_checks::run,
+    },
+    Section {
+        name: "ranges",
+        description: "`..` versus `..=`, iterating ranges, and evenly spaced samples",
+        run: ranges::run,
+    },
+];
+
 fn main() {
-    // Variables
-    let x: i8 = 5; // This is an immutable variable - note the type specificity.
-    println!("The value of x is {x}.");
-    // Attempting `x = 6;` is an error - "cannot mutate immutable variable `x`".
-
-    let mut y = 10; // This is a mutable variable - note no type is given.
-    println!("The value of y is {y}.");
-    y = 12;
-    println!("The value of y is now {y}.");
-
-    // Constants
-    // Note the explicit type and the `const` keyword.
-    const THREE_HOURS_IN_SECONDS: u32 = 60 * 60 * 3;
-    println!("Three hours in equal to {THREE_HOURS_IN_SECONDS} seconds.");
-
-    // Shadowing
-    // Initial declaration/initialisation. Note that the compiler will throw a
-    // warning here as we're not using this initial value.
-    let z = 5;
-    // Shadowing - z is equal to 10 from this point on.
-    let z = 10;
-    {
-        let z = 20;
-        // z = 20 at this point
-        println!("The value of z within this scope is {z}.");
-    }
-
-    // z = 10 at this point
-    println!("The value of z within this scope is {z}.");
-
-    // Using shadowing to change a variable's type. Note that we can use the
-    // original variable to construct the shadowed value.
-    let spaces = "     ";
-    let spaces = spaces.len(); // this would fail if we used `let mut`
-    println!("There are {spaces} spaces in the string.")
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (names, options) = match parse_args(&args) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("{message}");
+            process::exit(1);
+        }
+    };
+
+    let selected: Vec<&Section> = match names.first().map(String::as_str) {
+        None => SECTIONS.iter().collect(),
+        Some("--list") => {
+            // `{:<width$}` pads the name to a width chosen at runtime.
+            let width = SECTIONS.iter().map(|s| s.name.len()).max().unwrap_or(0);
+            for section in SECTIONS {
+                println!("{:<width$}  {}", section.name, section.description);
+            }
+            return;
+        }
+        Some(name) => match find_section(name) {
+            Some(section) => vec![section],
+            None => {
+                eprintln!("Unknown section `{name}`.");
+                if let Some(suggestion) = suggest(name) {
+                    eprintln!("Did you mean `{suggestion}`?");
+                }
+                eprintln!("Use `--list` to see every section.");
+                process::exit(1);
+            }
+        },
+    };
+
+    let result = match &options.report {
+        Some(path) => write_report(&selected, &options, path),
+        None => selected
+            .iter()
+            .try_for_each(|section| run_section(section, &options)),
+    };
+    if let Err(error) = result {
+        eprintln!("Failed to write the output: {error}");
+        process::exit(1);
+    }
+}
+
+// Splits the arguments into the section name (or `--list`) and the options
+// that apply to it. Any further words become the section's input.
+fn parse_args(args: &[String]) -> Result<(Vec<String>, Options), String> {
+    let mut names = Vec::new();
+    let mut options = Options::default();
+
+    // A manual iterator rather than a `for` loop, so that flags which take a
+    // value (like `--format csv`) can pull the next argument off it.
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--interactive" | "-i" => options.interactive = true,
+            "--format" => {
+                options.format = match args.next().map(String::as_str) {
+                    Some("ascii") => Format::Ascii,
+                    Some("csv") => Format::Csv,
+                    _ => return Err("`--format` must be followed by `ascii` or `csv`.".to_string()),
+                }
+            }
+            "--report" => match args.next() {
+                Some(path) => options.report = Some(PathBuf::from(path)),
+                None => return Err("`--report` must be followed by a file path.".to_string()),
+            },
+            "--list" => names.push(arg.clone()),
+            flag if flag.starts_with('-') => return Err(format!("Unknown flag `{flag}`.")),
+            _ => names.push(arg.clone()),
+        }
+    }
+
+    if names.len() > 1 {
+        options.input = Some(names[1..].join(" "));
+        names.truncate(1);
+    }
+
+    // A report is written in one go at the end, so there'd be nowhere to
+    // show the prompts.
+    if options.report.is_some() && options.interactive {
+        return Err("`--report` can't be combined with `--interactive`.".to_string());
+    }
+
+    Ok((names, options))
+}
+
+fn run_section(section: &Section, options: &Options) -> io::Result<()> {
+    if options.interactive {
+        let mut stdout = io::stdout();
+        write!(stdout, "{}", report::terminal_header(section.name))?;
+        (section.run)(options, &mut stdout)?;
+        writeln!(stdout)
+    } else {
+        print!("{}", report::terminal(&capture(section, options)?));
+        Ok(())
+    }
+}
+
+// Runs a section with a `Vec<u8>` standing in for stdout.
+fn capture(section: &Section, options: &Options) -> io::Result<report::SectionOutput> {
+    let mut output = Vec::new();
+    // Note the brackets - `section.run(options)` would look for a method.
+    (section.run)(options, &mut output)?;
+    Ok(report::SectionOutput {
+        name: section.name,
+        description: section.description,
+        output: String::from_utf8_lossy(&output).into_owned(),
+    })
+}
+
+fn write_report(sections: &[&Section], options: &Options, path: &Path) -> io::Result<()> {
+    let outputs = sections
+        .iter()
+        .map(|section| capture(section, options))
+        .collect::<io::Result<Vec<_>>>()?;
+    fs::write(path, report::markdown(&outputs))?;
+    println!("Wrote {} sections to {}.", outputs.len(), path.display());
+    Ok(())
+}
+
+fn find_section(name: &str) -> Option<&'static Section> {
+    SECTIONS.iter().find(|section| section.name == name)
+}
+
+// Returns the closest section name, as long as it's close enough to plausibly
+// be a typo rather than something else entirely.
+fn suggest(name: &str) -> Option<&'static str> {
+    const MAX_DISTANCE: usize = 3;
+
+    SECTIONS
+        .iter()
+        .map(|section| (edit_distance(name, section.name), section.name))
+        .min()
+        .filter(|(distance, _)| *distance <= MAX_DISTANCE)
+        .map(|(_, name)| name)
+}
+
+// Levenshtein distance - the number of single character insertions, deletions
+// or substitutions needed to turn `a` into `b`. Only the previous row of the
+// table is needed at any time, so we keep one row rather than the full grid.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != *b_char);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_every_registered_section() {
+        for section in SECTIONS {
+            assert_eq!(find_section(section.name).unwrap().name, section.name);
+        }
+    }
+
+    #[test]
+    fn unknown_section_is_not_found() {
+        assert!(find_section("lifetimes").is_none());
+        assert!(find_section("").is_none());
+    }
+
+    #[test]
+    fn section_names_are_unique() {
+        for (i, section) in SECTIONS.iter().enumerate() {
+            assert!(SECTIONS[i + 1..].iter().all(|s| s.name != section.name));
+        }
+    }
+
+    #[test]
+    fn parses_section_and_interactive_flag() {
+        let args = ["overflow".to_string(), "--interactive".to_string()];
+        let (names, options) = parse_args(&args).unwrap();
+        assert_eq!(names, ["overflow"]);
+        assert!(options.interactive);
+    }
+
+    #[test]
+    fn words_after_the_section_name_are_its_input() {
+        let args = ["chars", "naïve", "café"].map(String::from);
+        let (names, options) = parse_args(&args).unwrap();
+        assert_eq!(names, ["chars"]);
+        assert_eq!(options.input.as_deref(), Some("naïve café"));
+    }
+
+    #[test]
+    fn parses_format_flag() {
+        let args = ["--format".to_string(), "csv".to_string()];
+        let (names, options) = parse_args(&args).unwrap();
+        assert!(names.is_empty());
+        assert_eq!(options.format, Format::Csv);
+
+        assert!(parse_args(&["--format".to_string()]).is_err());
+        assert!(parse_args(&["--format".to_string(), "json".to_string()]).is_err());
+    }
+
+    #[test]
+    fn parses_report_flag() {
+        let args = ["--report", "tour.md"].map(String::from);
+        let (_, options) = parse_args(&args).unwrap();
+        assert_eq!(options.report, Some(PathBuf::from("tour.md")));
+
+        assert!(parse_args(&["--report".to_string()]).is_err());
+        let args = ["--report", "tour.md", "-i"].map(String::from);
+        assert!(parse_args(&args).is_err());
+    }
+
+    #[test]
+    fn report_has_every_section_in_order_inside_fences() {
+        let options = Options::default();
+        let outputs: Vec<_> = SECTIONS
+            .iter()
+            .map(|section| capture(section, &options).unwrap())
+            .collect();
+        let markdown = report::markdown(&outputs);
+
+        // Each heading must come after the previous one.
+        let mut position = 0;
+        for section in SECTIONS {
+            let heading = format!("\n## {}\n", section.name.replace('_', "\\_"));
+            let found = markdown[position..]
+                .find(&heading)
+                .unwrap_or_else(|| panic!("`{}` is missing or out of order", section.name));
+            position += found + heading.len();
+        }
+
+        // Everything between a heading and the next is the description
+        // followed by exactly one fenced block holding that section's output.
+        for (chunk, output) in markdown.split("\n## ").skip(1).zip(&outputs) {
+            let fences: Vec<&str> = chunk
+                .lines()
+                .filter(|line| line.starts_with("```"))
+                .collect();
+            assert_eq!(fences.len(), 2, "in section `{}`", output.name);
+            assert!(fences[0].ends_with("text"));
+            assert!(chunk.contains(&output.output));
+            assert!(chunk.trim_end().ends_with('`'));
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_flags() {
+        assert!(parse_args(&["--verbose".to_string()]).is_err());
+    }
+
+    #[test]
+    fn edit_distance_counts_single_edits() {
+        assert_eq!(edit_distance("scope", "scope"), 0);
+        assert_eq!(edit_distance("scope", "scop"), 1);
+        assert_eq!(edit_distance("scope", "scopes"), 1);
+        assert_eq!(edit_distance("scope", "slope"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn suggests_the_closest_section() {
+        assert_eq!(suggest("shadwing"), Some("shadowing"));
+        assert_eq!(suggest("constant"), Some("constants"));
+        assert_eq!(suggest("mutabilty"), Some("mutability"));
+    }
+
+    #[test]
+    fn no_suggestion_for_unrelated_names() {
+        assert_eq!(suggest("borrow checker"), None);
+    }
 }
@@ -0,0 +1,174 @@
+/*
+   * Mutable references
+     * `&mut T` lets a function change a value it doesn't own. Both sides have
+     to say so - `fn append(s: &mut String)` and `append(&mut name)` - and the
+     variable itself has to be `let mut`.
+     * The rule: at any one time you can have EITHER one `&mut` reference OR
+     any number of `&` references to a value, never both. That's what stops
+     two parts of the code changing the same data at once (a data race) or a
+     Vec being resized while something still points into it.
+     * A borrow lasts until the reference is last used, not until the end of
+     the block (this is "non-lexical lifetimes"), so borrows that don't
+     overlap are fine even in the same scope.
+     * `std::mem` has the tools for the cases that seem to need two borrows or
+     moving out of a `&mut`:
+       * `mem::swap(&mut a, &mut b)` exchanges two values,
+       * `mem::replace(&mut a, new)` puts `new` in and hands back the old value,
+       * `mem::take(&mut a)` is `replace` with the type's `Default` (an empty
+       String or Vec, 0 for numbers).
+*/
+use std::io::{self, Write};
+use std::mem;
+
+use crate::Options;
+
+fn append_exclamation(s: &mut String) {
+    s.push('!');
+}
+
+// A hand-written swap. The temporary copy is what `mem::swap` saves us from -
+// and it only works here because i32 is `Copy`. Clippy spots the pattern and
+// suggests `mem::swap`, which is the point being made.
+#[allow(clippy::manual_swap)]
+fn swap_values(a: &mut i32, b: &mut i32) {
+    let temporary = *a;
+    *a = *b;
+    *b = temporary;
+}
+
+// Two mutable borrows of the same String, one after the other. Each borrow
+// ends with its inner scope, so they never overlap.
+//
+// The overlapping version is rejected:
+//
+//     let first = &mut s;
+//     let second = &mut s;
+//     first.push_str(" first");
+//
+// error[E0499]: cannot borrow `s` as mutable more than once at a time
+fn split_scopes() -> String {
+    let mut s = String::from("borrowed");
+    {
+        let first = &mut s;
+        first.push_str(" first");
+    }
+    {
+        let second = &mut s;
+        second.push_str(" then second");
+    }
+    s
+}
+
+// Moving the old log out of a struct we only have `&mut` to. A plain
+// `let old = log.entries;` fails with
+// error[E0507]: cannot move out of `log.entries` which is behind a mutable reference
+struct Log {
+    entries: Vec<String>,
+}
+
+fn rotate(log: &mut Log) -> Vec<String> {
+    mem::take(&mut log.entries)
+}
+
+fn rename(log: &mut Log, index: usize, name: &str) -> Option<String> {
+    let entry = log.entries.get_mut(index)?;
+    Some(mem::replace(entry, name.to_string()))
+}
+
+pub fn run(_options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let mut greeting = String::from("Hello");
+    append_exclamation(&mut greeting);
+    writeln!(out, "append_exclamation(&mut greeting) -> {greeting:?}")?;
+
+    let (mut a, mut b) = (1, 2);
+    swap_values(&mut a, &mut b);
+    writeln!(out, "swap_values(&mut a, &mut b) -> a = {a}, b = {b}")?;
+    writeln!(out)?;
+
+    writeln!(
+        out,
+        "Two `&mut s` alive at once is error[E0499]. One after the other is fine:"
+    )?;
+    writeln!(out, "  {:?}", split_scopes())?;
+    writeln!(out)?;
+
+    let mut left = String::from("left");
+    let mut right = String::from("right");
+    mem::swap(&mut left, &mut right);
+    writeln!(out, "mem::swap -> left = {left:?}, right = {right:?}")?;
+
+    let mut log = Log {
+        entries: vec!["boot".to_string(), "login".to_string()],
+    };
+    let old = rename(&mut log, 1, "logout");
+    writeln!(
+        out,
+        "mem::replace -> got back {old:?}, log is now {:?}",
+        log.entries
+    )?;
+    let rotated = rotate(&mut log);
+    writeln!(
+        out,
+        "mem::take -> got back {rotated:?}, log is now {:?}",
+        log.entries
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn appends_through_a_mutable_reference() {
+        let mut s = String::from("Hi");
+        append_exclamation(&mut s);
+        append_exclamation(&mut s);
+        assert_eq!(s, "Hi!!");
+    }
+
+    #[test]
+    fn swap_values_exchanges_both() {
+        let (mut a, mut b) = (1, 2);
+        swap_values(&mut a, &mut b);
+        assert_eq!((a, b), (2, 1));
+    }
+
+    #[test]
+    fn sequential_borrows_both_apply() {
+        assert_eq!(split_scopes(), "borrowed first then second");
+    }
+
+    #[test]
+    fn mem_swap_exchanges_values() {
+        let mut a = vec![1, 2];
+        let mut b = vec![3];
+        mem::swap(&mut a, &mut b);
+        assert_eq!(a, [3]);
+        assert_eq!(b, [1, 2]);
+    }
+
+    #[test]
+    fn mem_replace_returns_the_old_value() {
+        let mut log = Log {
+            entries: vec!["a".to_string()],
+        };
+        assert_eq!(rename(&mut log, 0, "b"), Some("a".to_string()));
+        assert_eq!(log.entries, ["b"]);
+        assert_eq!(rename(&mut log, 5, "c"), None);
+        assert_eq!(log.entries, ["b"]);
+    }
+
+    #[test]
+    fn mem_take_leaves_the_default_behind() {
+        let mut log = Log {
+            entries: vec!["a".to_string(), "b".to_string()],
+        };
+        assert_eq!(rotate(&mut log), ["a", "b"]);
+        assert!(log.entries.is_empty());
+
+        let mut count = 5;
+        assert_eq!(mem::take(&mut count), 5);
+        assert_eq!(count, 0);
+    }
+}
@@ -0,0 +1,264 @@
+/*
+   * Integer overflow
+     * A `u8` holds 0 to 255. `255 + 1` doesn't fit, so the question is what
+     Rust should do about it.
+     * With plain `+` the answer depends on the build: debug builds check for
+     overflow and panic ("attempt to add with overflow"), release builds
+     (`cargo build --release`) don't check and the value wraps around to 0.
+     * Relying on either is considered a bug - the standard library provides
+     methods that make the behaviour explicit instead:
+       * `checked_*` returns `None` on overflow.
+       * `wrapping_*` wraps around (modular arithmetic), in every build.
+       * `saturating_*` clamps to the type's MIN or MAX.
+       * `overflowing_*` returns the wrapped value AND a bool saying whether
+       it overflowed.
+     * See https://doc.rust-lang.org/book/ch03-02-data-types.html#integer-overflow
+*/
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::input;
+use crate::Options;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl Op {
+    fn symbol(self) -> char {
+        match self {
+            Op::Add => '+',
+            Op::Sub => '-',
+            Op::Mul => '*',
+        }
+    }
+
+    // The word the compiler uses in its panic message.
+    fn verb(self) -> &'static str {
+        match self {
+            Op::Add => "add",
+            Op::Sub => "subtract",
+            Op::Mul => "multiply",
+        }
+    }
+
+    fn parse(symbol: &str) -> Option<Op> {
+        match symbol {
+            "+" => Some(Op::Add),
+            "-" => Some(Op::Sub),
+            "*" => Some(Op::Mul),
+            _ => None,
+        }
+    }
+}
+
+// The result of one operation computed all four ways.
+#[derive(Debug, PartialEq)]
+struct Behaviours {
+    checked: Option<u8>,
+    wrapping: u8,
+    saturating: u8,
+    overflowing: (u8, bool),
+}
+
+fn behaviours(a: u8, op: Op, b: u8) -> Behaviours {
+    match op {
+        Op::Add => Behaviours {
+            checked: a.checked_add(b),
+            wrapping: a.wrapping_add(b),
+            saturating: a.saturating_add(b),
+            overflowing: a.overflowing_add(b),
+        },
+        Op::Sub => Behaviours {
+            checked: a.checked_sub(b),
+            wrapping: a.wrapping_sub(b),
+            saturating: a.saturating_sub(b),
+            overflowing: a.overflowing_sub(b),
+        },
+        Op::Mul => Behaviours {
+            checked: a.checked_mul(b),
+            wrapping: a.wrapping_mul(b),
+            saturating: a.saturating_mul(b),
+            overflowing: a.overflowing_mul(b),
+        },
+    }
+}
+
+// What plain `a op b` would do. This is worked out from the checked result
+// rather than by actually doing the operation - in a debug build that would
+// panic and end the tour.
+fn plain_operator(op: Op, result: &Behaviours) -> String {
+    match result.checked {
+        Some(value) => format!("`{}` gives {value} in both debug and release builds", op.symbol()),
+        None => format!(
+            "`{}` panics in debug builds (\"attempt to {} with overflow\") and wraps to {} in release builds",
+            op.symbol(),
+            op.verb(),
+            result.wrapping
+        ),
+    }
+}
+
+// A row of the comparison table, e.g. `255 + 1    None   0   255   (0, true)`.
+struct Row(u8, Op, u8);
+
+impl fmt::Display for Row {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Row(a, op, b) = *self;
+        let result = behaviours(a, op, b);
+        // `{:?}` is needed for the Option and the tuple - neither implements
+        // Display - and we `format!` them first so the width applies to the
+        // whole text rather than being ignored by the Debug output.
+        let expression = format!("{a} {} {b}", op.symbol());
+        let checked = format!("{:?}", result.checked);
+        write!(
+            f,
+            "{expression:<10} {checked:<10} {:<9} {:<11} {:?}",
+            result.wrapping, result.saturating, result.overflowing
+        )
+    }
+}
+
+fn print_header(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(
+        out,
+        "{:<10} {:<10} {:<9} {:<11} overflowing",
+        "u8", "checked", "wrapping", "saturating"
+    )?;
+    Ok(())
+}
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    print_header(out)?;
+    for row in [
+        Row(250, Op::Add, 4),
+        Row(254, Op::Add, 1),
+        Row(255, Op::Add, 1),
+        Row(255, Op::Add, 255),
+        Row(0, Op::Sub, 1),
+        Row(16, Op::Mul, 16),
+    ] {
+        writeln!(out, "{row}")?;
+    }
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "255 + 1 with plain `+`: {}.",
+        plain_operator(Op::Add, &behaviours(255, Op::Add, 1))
+    )?;
+
+    if options.interactive {
+        interactive(out)?;
+    }
+    Ok(())
+}
+
+// Reads lines like `200 + 100` and prints every behaviour for them.
+fn interactive(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(
+        out,
+        "Enter two u8 values and an operation (+, - or *), e.g. `200 + 100`."
+    )?;
+
+    while let Some(line) = input::read_line("> ") {
+        match parse_expression(&line) {
+            Ok((a, op, b)) => {
+                print_header(out)?;
+                writeln!(out, "{}", Row(a, op, b))?;
+                writeln!(
+                    out,
+                    "Plain operator: {}.",
+                    plain_operator(op, &behaviours(a, op, b))
+                )?;
+            }
+            Err(message) => writeln!(out, "{message}")?,
+        }
+    }
+    Ok(())
+}
+
+fn parse_expression(line: &str) -> Result<(u8, Op, u8), String> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    let [a, op, b] = parts[..] else {
+        return Err("Expected `<number> <operation> <number>`, with spaces.".to_string());
+    };
+
+    let parse_operand = |text: &str| {
+        text.parse::<u8>()
+            .map_err(|_| format!("`{text}` isn't a u8 - it must be a whole number from 0 to 255."))
+    };
+    let op = Op::parse(op).ok_or_else(|| format!("`{op}` isn't one of +, - or *."))?;
+
+    Ok((parse_operand(a)?, op, parse_operand(b)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn just_below_the_limit_does_not_overflow() {
+        let result = behaviours(254, Op::Add, 1);
+        assert_eq!(
+            result,
+            Behaviours {
+                checked: Some(255),
+                wrapping: 255,
+                saturating: 255,
+                overflowing: (255, false),
+            }
+        );
+    }
+
+    #[test]
+    fn adding_past_the_maximum_overflows() {
+        let result = behaviours(255, Op::Add, 1);
+        assert_eq!(
+            result,
+            Behaviours {
+                checked: None,
+                wrapping: 0,
+                saturating: 255,
+                overflowing: (0, true),
+            }
+        );
+    }
+
+    #[test]
+    fn subtracting_below_zero_overflows() {
+        let result = behaviours(0, Op::Sub, 1);
+        assert_eq!(
+            result,
+            Behaviours {
+                checked: None,
+                wrapping: 255,
+                saturating: 0,
+                overflowing: (255, true),
+            }
+        );
+    }
+
+    #[test]
+    fn plain_operator_explains_debug_and_release() {
+        let explanation = plain_operator(Op::Add, &behaviours(255, Op::Add, 1));
+        assert!(explanation.contains("attempt to add with overflow"));
+        assert!(explanation.contains("wraps to 0"));
+
+        let explanation = plain_operator(Op::Add, &behaviours(254, Op::Add, 1));
+        assert!(explanation.contains("gives 255"));
+    }
+
+    #[test]
+    fn parses_interactive_expressions() {
+        assert_eq!(parse_expression("200 + 100"), Ok((200, Op::Add, 100)));
+        assert_eq!(parse_expression("0 - 1"), Ok((0, Op::Sub, 1)));
+        assert!(parse_expression("256 + 1").is_err());
+        assert!(parse_expression("1 / 1").is_err());
+        assert!(parse_expression("1+1").is_err());
+    }
+}
@@ -0,0 +1,297 @@
+/*
+   * Ranges
+     * `a..b` is a `Range` - from `a` up to but NOT including `b`. `a..=b` is
+     a `RangeInclusive`, which does include `b`. That's why the guessing game
+     uses `gen_range(1..=100)`: with `1..100` the secret number could never
+     be 100.
+     * There are also `a..` (`RangeFrom`), `..b` (`RangeTo`), `..=b` and `..`
+     (`RangeFull`) - mostly used for slicing, e.g. `&s[..3]`.
+     * Ranges of integers are iterators, so they work in `for` loops and with
+     iterator methods like `rev()` and `step_by(n)`.
+     * A range whose start is after its end is empty, not backwards -
+     `10..1` produces nothing. Count down with `(1..10).rev()` instead.
+     * `contains(&x)` checks membership without iterating.
+*/
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+
+use crate::input;
+use crate::Options;
+
+// `parts` evenly spaced points from the start to the end of `r`, both ends
+// included. One part is just the start. If the range has fewer values than
+// `parts`, every value is returned once rather than repeating any.
+fn spread(r: RangeInclusive<i64>, parts: usize) -> Vec<i64> {
+    let (start, end) = (*r.start(), *r.end());
+    if parts == 0 || r.is_empty() {
+        return Vec::new();
+    }
+    if parts == 1 {
+        return vec![start];
+    }
+
+    // i128 so that `end - start` can't overflow, even for the full i64 range.
+    let width = end as i128 - start as i128;
+    if width < parts as i128 {
+        return r.collect();
+    }
+    let gaps = parts as i128 - 1;
+    (0..parts as i128)
+        .map(|i| (start as i128 + width * i / gaps) as i64)
+        .collect()
+}
+
+// An iterator method applied to a range typed in interactive mode.
+#[derive(Debug, PartialEq)]
+enum Adapter {
+    Rev,
+    StepBy(usize),
+}
+
+// A range expression like `(1..=10).rev().step_by(3)`.
+#[derive(Debug, PartialEq)]
+struct RangeExpr {
+    start: i64,
+    end: i64,
+    inclusive: bool,
+    adapters: Vec<Adapter>,
+}
+
+fn parse_range(text: &str) -> Result<RangeExpr, String> {
+    let text = text.trim();
+    // Methods need the range in brackets - `1..10.rev()` would call `rev` on
+    // the 10 - so without brackets the whole text is the range.
+    let (range, mut methods) = match text.strip_prefix('(') {
+        Some(rest) => rest.split_once(')').ok_or("Missing the closing `)`.")?,
+        None => (text, ""),
+    };
+
+    let (start, end, inclusive) = if let Some((start, end)) = range.split_once("..=") {
+        (start, end, true)
+    } else if let Some((start, end)) = range.split_once("..") {
+        (start, end, false)
+    } else {
+        return Err("Expected a range like `1..10` or `1..=10`.".to_string());
+    };
+    let bound = |text: &str| {
+        text.trim()
+            .parse::<i64>()
+            .map_err(|_| format!("`{}` isn't an i64.", text.trim()))
+    };
+
+    let mut adapters = Vec::new();
+    while !methods.is_empty() {
+        if let Some(rest) = methods.strip_prefix(".rev()") {
+            adapters.push(Adapter::Rev);
+            methods = rest;
+        } else if let Some(rest) = methods.strip_prefix(".step_by(") {
+            let (step, rest) = rest
+                .split_once(')')
+                .ok_or("Missing the `)` after step_by.")?;
+            let step: usize = step
+                .trim()
+                .parse()
+                .map_err(|_| format!("`{step}` isn't a valid step."))?;
+            // `step_by(0)` panics, so catch it here.
+            if step == 0 {
+                return Err("step_by(0) would panic - the step must be at least 1.".to_string());
+            }
+            adapters.push(Adapter::StepBy(step));
+            methods = rest;
+        } else {
+            return Err(format!("`{methods}` isn't `.rev()` or `.step_by(n)`."));
+        }
+    }
+
+    Ok(RangeExpr {
+        start: bound(start)?,
+        end: bound(end)?,
+        inclusive,
+        adapters,
+    })
+}
+
+// The first `limit` values the expression produces, and whether there were
+// more.
+//
+// Chaining real iterators through a `Box<dyn Iterator>` doesn't work here:
+// `rev()` after `step_by()` needs to know where the stepped sequence ends,
+// which the standard library only supports for iterators of known length,
+// and `RangeInclusive<i64>` isn't one. Instead the range is tracked as an
+// arithmetic sequence - first value, step and count - which every adapter
+// can transform exactly.
+fn iterate(expr: &RangeExpr, limit: usize) -> (Vec<i64>, bool) {
+    let (start, end) = (expr.start as i128, expr.end as i128);
+    let last = if expr.inclusive { end } else { end - 1 };
+    let mut first = start;
+    let mut step: i128 = 1;
+    let mut count = (last - start + 1).max(0);
+
+    for adapter in &expr.adapters {
+        match adapter {
+            Adapter::Rev => {
+                first += step * (count - 1).max(0);
+                step = -step;
+            }
+            Adapter::StepBy(n) => {
+                let n = *n as i128;
+                step *= n;
+                // step_by always yields the first value, then every nth.
+                count = (count + n - 1) / n;
+            }
+        }
+    }
+
+    let values = (0..count.min(limit as i128))
+        .map(|i| (first + step * i) as i64)
+        .collect();
+    (values, count > limit as i128)
+}
+
+fn show_iteration(out: &mut dyn Write, text: &str) -> io::Result<()> {
+    const LIMIT: usize = 20;
+    match parse_range(text) {
+        Ok(expr) => {
+            let (values, more) = iterate(&expr, LIMIT);
+            let ellipsis = if more { ", ..." } else { "" };
+            writeln!(out, "  {text} -> {values:?}{ellipsis}")
+        }
+        Err(message) => writeln!(out, "  {message}"),
+    }
+}
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    for text in [
+        "1..5",
+        "1..=5",
+        "(1..10).step_by(3)",
+        "(1..5).rev()",
+        "5..1",
+    ] {
+        show_iteration(out, text)?;
+    }
+    writeln!(out)?;
+
+    writeln!(out, "(1..100).contains(&100) = {}", (1..100).contains(&100))?;
+    writeln!(
+        out,
+        "(1..=100).contains(&100) = {}",
+        (1..=100).contains(&100)
+    )?;
+    writeln!(
+        out,
+        "(1..100).len() = {}, (1..=100).count() = {}",
+        (1..100).len(),
+        (1..=100).count()
+    )?;
+    writeln!(out)?;
+
+    for (range, parts) in [(0..=100, 5), (0..=10, 3), (1..=3, 5), (7..=7, 1)] {
+        writeln!(
+            out,
+            "spread({range:?}, {parts}) = {:?}",
+            spread(range.clone(), parts)
+        )?;
+    }
+
+    if options.interactive {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "Enter a range, optionally with `.rev()` and `.step_by(n)`, e.g. `(0..=20).step_by(5)`."
+        )?;
+        while let Some(line) = input::read_line("> ") {
+            show_iteration(out, &line)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spread_includes_both_ends() {
+        assert_eq!(spread(0..=100, 5), [0, 25, 50, 75, 100]);
+        assert_eq!(spread(-10..=10, 2), [-10, 10]);
+    }
+
+    #[test]
+    fn spread_rounds_uneven_gaps_down() {
+        assert_eq!(spread(0..=10, 4), [0, 3, 6, 10]);
+    }
+
+    #[test]
+    fn spread_with_zero_parts_is_empty() {
+        assert!(spread(0..=10, 0).is_empty());
+    }
+
+    #[test]
+    fn spread_with_one_part_is_the_start() {
+        assert_eq!(spread(3..=9, 1), [3]);
+    }
+
+    #[test]
+    fn spread_of_a_small_range_returns_each_value_once() {
+        assert_eq!(spread(1..=3, 5), [1, 2, 3]);
+        assert_eq!(spread(1..=3, 3), [1, 2, 3]);
+        assert_eq!(spread(7..=7, 4), [7]);
+    }
+
+    #[test]
+    fn spread_of_an_empty_range_is_empty() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let backwards = 5..=1;
+        assert!(spread(backwards, 3).is_empty());
+    }
+
+    #[test]
+    fn spread_handles_the_full_i64_range() {
+        assert_eq!(spread(i64::MIN..=i64::MAX, 3), [i64::MIN, -1, i64::MAX]);
+    }
+
+    #[test]
+    fn parses_range_expressions() {
+        assert_eq!(
+            parse_range("(1..=10).rev().step_by(3)"),
+            Ok(RangeExpr {
+                start: 1,
+                end: 10,
+                inclusive: true,
+                adapters: vec![Adapter::Rev, Adapter::StepBy(3)],
+            })
+        );
+        assert_eq!(
+            parse_range("-5..5"),
+            Ok(RangeExpr {
+                start: -5,
+                end: 5,
+                inclusive: false,
+                adapters: Vec::new(),
+            })
+        );
+        assert!(parse_range("1..").is_err());
+        assert!(parse_range("(1..5).step_by(0)").is_err());
+        assert!(parse_range("(1..5).map()").is_err());
+    }
+
+    #[test]
+    fn exclusive_and_inclusive_differ_by_the_end() {
+        let iterated = |text| iterate(&parse_range(text).unwrap(), 100).0;
+        assert_eq!(iterated("1..4"), [1, 2, 3]);
+        assert_eq!(iterated("1..=4"), [1, 2, 3, 4]);
+        assert!(iterated("4..1").is_empty());
+        assert_eq!(iterated("(1..4).rev()"), [3, 2, 1]);
+        // The order of the adapters matters.
+        assert_eq!(iterated("(0..=10).step_by(4).rev()"), [8, 4, 0]);
+        assert_eq!(iterated("(0..=10).rev().step_by(4)"), [10, 6, 2]);
+    }
+
+    #[test]
+    fn long_iterations_are_cut_short() {
+        let (values, more) = iterate(&parse_range("0..1000").unwrap(), 3);
+        assert_eq!(values, [0, 1, 2]);
+        assert!(more);
+    }
+}
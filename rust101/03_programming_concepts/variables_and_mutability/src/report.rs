@@ -0,0 +1,108 @@
+// Renders the captured output of each section, either for the terminal or as
+// a Markdown document for `--report`. Both renderers take the same
+// `SectionOutput` values, so a report always shows exactly what the terminal
+// would have.
+
+// What one section printed, along with the details from its `Section` entry.
+#[derive(Debug)]
+pub struct SectionOutput {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub output: String,
+}
+
+pub fn terminal(section: &SectionOutput) -> String {
+    format!("{}{}\n", terminal_header(section.name), section.output)
+}
+
+// Interactive sections can't be captured - their output has to appear as the
+// user types - so they print this header and then write straight to stdout.
+pub fn terminal_header(name: &str) -> String {
+    format!("== {name} ==\n")
+}
+
+pub fn markdown(sections: &[SectionOutput]) -> String {
+    let mut report = String::from("# Programming concepts tour\n");
+
+    for section in sections {
+        report.push_str(&format!("\n## {}\n\n", escape(section.name)));
+        // Descriptions are written with `code` spans already, so they go in
+        // as they are.
+        report.push_str(section.description);
+        report.push_str("\n\n");
+
+        // Nothing inside a code fence is treated as Markdown except a line
+        // that closes the fence, so the only escaping the output needs is a
+        // fence longer than any run of backticks it contains.
+        let fence = fence_for(&section.output);
+        report.push_str(&format!("{fence}text\n{}", section.output));
+        if !section.output.ends_with('\n') {
+            report.push('\n');
+        }
+        report.push_str(&format!("{fence}\n"));
+    }
+
+    report
+}
+
+// Backslash-escapes the characters that mean something in Markdown prose, so
+// a name like `shadow_or_mut` is shown as written.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if "\\`*_{}[]<>()#+-.!|~".contains(ch) {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+fn fence_for(output: &str) -> String {
+    let mut longest = 0;
+    let mut run = 0;
+    for ch in output.chars() {
+        run = if ch == '`' { run + 1 } else { 0 };
+        longest = longest.max(run);
+    }
+    "`".repeat(longest.max(2) + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(name: &'static str, output: &str) -> SectionOutput {
+        SectionOutput {
+            name,
+            description: "A `description`",
+            output: output.to_string(),
+        }
+    }
+
+    #[test]
+    fn terminal_output_has_a_header_and_a_blank_line() {
+        assert_eq!(terminal(&section("scope", "x\n")), "== scope ==\nx\n\n");
+    }
+
+    #[test]
+    fn escapes_markdown_characters_in_prose() {
+        assert_eq!(escape("shadow_or_mut"), "shadow\\_or\\_mut");
+        assert_eq!(escape("*a* `b` #1"), "\\*a\\* \\`b\\` \\#1");
+        assert_eq!(escape("plain"), "plain");
+    }
+
+    #[test]
+    fn fence_is_longer_than_any_backticks_in_the_output() {
+        assert_eq!(fence_for("no backticks"), "```");
+        assert_eq!(fence_for("a `code` span"), "```");
+        assert_eq!(fence_for("```\nfenced\n```"), "````");
+        assert_eq!(fence_for("`````"), "``````");
+    }
+
+    #[test]
+    fn output_without_a_trailing_newline_is_still_fenced() {
+        let report = markdown(&[section("chars", "```")]);
+        assert!(report.ends_with("````text\n```\n````\n"));
+    }
+}
@@ -0,0 +1,172 @@
+/*
+   * Shadowing or `mut`?
+     * Both let a name refer to a new value, but they're different tools:
+       * `let mut x` is ONE variable whose value changes. Its type is fixed at
+       declaration, and anything can change it until it goes out of scope.
+       * `let x = ...; let x = ...;` creates a NEW variable each time that
+       happens to have the same name. Each one is immutable and can have a
+       different type.
+     * The functions below do the same job both ways. Where both approaches
+     can express it they give identical results (the tests check this); the
+     last pair shows the case only shadowing can handle - changing the type.
+*/
+use std::io::{self, Write};
+
+use crate::inference::type_name_of;
+use crate::Options;
+
+// Trim, lowercase and replace spaces - the same steps both ways. Clippy would
+// rather the last step were returned directly, but here the point is to keep
+// one `let` per step to mirror the `mut` version.
+#[allow(clippy::let_and_return)]
+fn slug_with_shadowing(raw: &str) -> String {
+    let slug = raw.trim(); // &str
+    let slug = slug.to_lowercase(); // String - a new binding, so a new type is fine
+    let slug = slug.replace(' ', "-");
+    slug
+}
+
+fn slug_with_mut(raw: &str) -> String {
+    // One variable, so it needs to be a String from the start.
+    let mut slug = raw.trim().to_string();
+    slug = slug.to_lowercase();
+    slug = slug.replace(' ', "-");
+    slug
+}
+
+// Parse a number out of padded text, then measure how many digits it has.
+fn digits_with_shadowing(raw: &str) -> Option<usize> {
+    let number = raw.trim(); // &str
+    let number: u64 = number.parse().ok()?; // u64
+    let number = number.to_string().len(); // usize
+    Some(number)
+}
+
+fn digits_with_mut(raw: &str) -> Option<usize> {
+    // `mut` can't change the type, so each step needs its own name:
+    //
+    //     let mut number = raw.trim();
+    //     number = number.parse().ok()?;
+    //
+    // error[E0308]: mismatched types
+    //     expected `&str`, found integer
+    let text = raw.trim();
+    let parsed: u64 = text.parse().ok()?;
+    let mut digits = 0;
+    let mut remaining = parsed;
+    // Mutation shines for accumulating in a loop, which shadowing can't do -
+    // a `let` inside the loop body would only last one iteration.
+    loop {
+        digits += 1;
+        remaining /= 10;
+        if remaining == 0 {
+            break;
+        }
+    }
+    Some(digits)
+}
+
+// The runtime equivalent of the compile error above: the shadowed `spaces`
+// really does change type, which a single `mut` variable never could.
+fn shadowed_types(raw: &str) -> (&'static str, &'static str) {
+    let spaces = raw;
+    let before = type_name_of(&spaces);
+    let spaces = spaces.len();
+    let after = type_name_of(&spaces);
+    (before, after)
+}
+
+pub fn run(_options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let raw = "  Hello Rust World ";
+    writeln!(
+        out,
+        "slug_with_shadowing({raw:?}) = {:?}",
+        slug_with_shadowing(raw)
+    )?;
+    writeln!(
+        out,
+        "slug_with_mut({raw:?})       = {:?}",
+        slug_with_mut(raw)
+    )?;
+
+    let raw = "  1234567 ";
+    writeln!(
+        out,
+        "digits_with_shadowing({raw:?}) = {:?}",
+        digits_with_shadowing(raw)
+    )?;
+    writeln!(
+        out,
+        "digits_with_mut({raw:?})       = {:?}",
+        digits_with_mut(raw)
+    )?;
+
+    let (before, after) = shadowed_types("     ");
+    writeln!(
+        out,
+        "`spaces` starts as {before} and, after shadowing, is a {after}."
+    )?;
+    writeln!(out)?;
+
+    writeln!(out, "Prefer shadowing when:")?;
+    writeln!(
+        out,
+        "  * each step produces a new value from the old one (a pipeline),"
+    )?;
+    writeln!(out, "  * the type changes along the way (text -> number),")?;
+    writeln!(
+        out,
+        "  * you want the final value to be immutable once it's ready."
+    )?;
+    writeln!(out, "Prefer `mut` when:")?;
+    writeln!(
+        out,
+        "  * a value is updated in a loop or accumulated over time,"
+    )?;
+    writeln!(
+        out,
+        "  * the change must be visible after an inner block ends,"
+    )?;
+    writeln!(
+        out,
+        "  * the value is large and rebuilding it each step would be wasteful."
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUTS: [&str; 5] = ["  Hello Rust World ", "already-done", "", "   ", "ALL CAPS"];
+
+    #[test]
+    fn slugs_agree() {
+        for input in INPUTS {
+            assert_eq!(
+                slug_with_shadowing(input),
+                slug_with_mut(input),
+                "{input:?}"
+            );
+        }
+        assert_eq!(slug_with_shadowing(INPUTS[0]), "hello-rust-world");
+    }
+
+    #[test]
+    fn digit_counts_agree() {
+        for input in ["0", " 7 ", "10", "1234567", "18446744073709551615", "x", ""] {
+            assert_eq!(
+                digits_with_shadowing(input),
+                digits_with_mut(input),
+                "{input:?}"
+            );
+        }
+        assert_eq!(digits_with_mut(" 1234567 "), Some(7));
+        assert_eq!(digits_with_mut("not a number"), None);
+    }
+
+    #[test]
+    fn shadowing_changes_the_type() {
+        assert_eq!(shadowed_types("  "), ("&str", "usize"));
+    }
+}
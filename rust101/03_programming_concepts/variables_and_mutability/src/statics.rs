@@ -0,0 +1,157 @@
+/*
+   * `const` versus `static`
+     * A `const` has no fixed place in memory - the compiler pastes its value
+     into every place it's used, as if you'd typed the literal there.
+     * A `static` is a single value at a fixed address that lives for the whole
+     program (the `'static` lifetime). Every use refers to the same memory.
+     Use one when the address matters or the value is large and shouldn't be
+     copied around.
+     * Both need an explicit type and a value the compiler can compute.
+     * `static mut` exists too, but every read or write of it is `unsafe` -
+     the compiler can't prove two threads aren't touching it at once. It's
+     mentioned here rather than used: an atomic (like `CALLS` below) or a
+     `Mutex` gives the same 'global that changes' without the unsafety, and
+     since the 2024 edition even taking a reference to a `static mut` is an
+     error by default.
+   * `const fn`
+     * A `const fn` can be run by the compiler, when its result is needed in a
+     const context (a `const`, a `static`, an array length), as well as at
+     runtime like any other function. Only a subset of Rust is allowed inside
+     - e.g. `while` loops are fine but `for` loops aren't (they use the
+     `Iterator` trait, whose methods aren't const).
+*/
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::assertions::static_assert;
+use crate::Options;
+
+/// The number of seconds in `hours` hours.
+///
+/// Used at compile time for [`THREE_HOURS_IN_SECONDS`], the length of
+/// [`ONE_MINUTE_OF_SAMPLES`] and every entry of [`SECONDS_BY_HOUR`], and at
+/// runtime by [`run`] - the same source in both cases.
+const fn seconds_in(hours: u32) -> u32 {
+    hours * 60 * 60
+}
+
+/// Evaluated by the compiler: the binary just contains 10800.
+const THREE_HOURS_IN_SECONDS: u32 = seconds_in(3);
+
+/// The array length is also worked out at compile time: one slot per second
+/// of a minute, i.e. `seconds_in(1) / 60` = 60.
+const ONE_MINUTE_OF_SAMPLES: [u8; seconds_in(1) as usize / 60] = [0; seconds_in(1) as usize / 60];
+
+/// Builds the table of seconds at each hour of a day, 0 to 24 inclusive.
+///
+/// This runs once, inside the compiler, to produce [`SECONDS_BY_HOUR`] - the
+/// finished array is baked into the binary and no loop runs when the program
+/// starts. Note the `while` loop: `for` isn't allowed in a `const fn`.
+const fn build_hour_table() -> [u32; 25] {
+    let mut table = [0; 25];
+    let mut hour = 0;
+    while hour < table.len() {
+        table[hour] = seconds_in(hour as u32);
+        hour += 1;
+    }
+    table
+}
+
+/// Computed at compile time by [`build_hour_table`].
+static SECONDS_BY_HOUR: [u32; 25] = build_hour_table();
+// Reading a `static` is allowed in a const context as long as it isn't `mut`.
+static_assert!(
+    SECONDS_BY_HOUR[24] == seconds_in(24),
+    "the last entry of SECONDS_BY_HOUR must be a whole day"
+);
+
+/// A global counter that's safe to change - the atomic alternative to a
+/// `static mut`.
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// Looks `hour` up in the compile-time table at runtime. `get` returns `None`
+/// for hours outside 0..=24 instead of panicking.
+fn seconds_at_hour(hour: usize) -> Option<u32> {
+    CALLS.fetch_add(1, Ordering::Relaxed);
+    SECONDS_BY_HOUR.get(hour).copied()
+}
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    writeln!(
+        out,
+        "THREE_HOURS_IN_SECONDS (compile time) = {THREE_HOURS_IN_SECONDS}"
+    )?;
+    // `options.input` is only known at runtime, so this call can't be
+    // evaluated by the compiler - the same function runs as ordinary code.
+    // Parsing as a u16 keeps `hours * 3600` well inside a u32.
+    let hours = options
+        .input
+        .as_deref()
+        .and_then(|text| text.parse::<u16>().ok())
+        .map_or(5, u32::from);
+    writeln!(out, "seconds_in({hours}) (runtime) = {}", seconds_in(hours))?;
+    writeln!(
+        out,
+        "ONE_MINUTE_OF_SAMPLES has {} slots",
+        ONE_MINUTE_OF_SAMPLES.len()
+    )?;
+    writeln!(out)?;
+
+    for hour in [0, 1, 12, 24, 25] {
+        match seconds_at_hour(hour) {
+            Some(seconds) => writeln!(out, "SECONDS_BY_HOUR[{hour}] = {seconds}")?,
+            None => writeln!(out, "SECONDS_BY_HOUR has no entry for hour {hour}")?,
+        }
+    }
+    writeln!(
+        out,
+        "The table was read {} times.",
+        CALLS.load(Ordering::Relaxed)
+    )?;
+    writeln!(out)?;
+
+    // A static has one address; a const is a fresh value wherever it's used.
+    let first: *const [u32; 25] = &SECONDS_BY_HOUR;
+    let second: *const [u32; 25] = &SECONDS_BY_HOUR;
+    writeln!(
+        out,
+        "Both references to the static point at {first:p} - same address: {}",
+        std::ptr::eq(first, second)
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_fn_works_at_compile_time() {
+        // Forcing evaluation in a const context inside the test itself.
+        const TWO_HOURS: u32 = seconds_in(2);
+        assert_eq!(TWO_HOURS, 7_200);
+        assert_eq!(THREE_HOURS_IN_SECONDS, 10_800);
+        assert_eq!(ONE_MINUTE_OF_SAMPLES.len(), 60);
+    }
+
+    #[test]
+    fn const_fn_works_at_runtime() {
+        let hours = "4".parse().unwrap();
+        assert_eq!(seconds_in(hours), 14_400);
+    }
+
+    #[test]
+    fn lookup_table_entries() {
+        assert_eq!(seconds_at_hour(0), Some(0));
+        assert_eq!(seconds_at_hour(1), Some(3_600));
+        assert_eq!(seconds_at_hour(24), Some(86_400));
+        assert_eq!(seconds_at_hour(25), None);
+    }
+
+    #[test]
+    fn table_matches_the_function() {
+        for (hour, seconds) in SECONDS_BY_HOUR.iter().enumerate() {
+            assert_eq!(*seconds, seconds_in(hour as u32));
+        }
+    }
+}
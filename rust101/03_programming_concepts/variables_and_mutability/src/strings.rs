@@ -0,0 +1,172 @@
+/*
+   * `String` versus `&str`
+     * `String` owns its text: a heap allocation it can grow, shrink and free.
+     * `&str` (a string slice) borrows text owned by something else - a
+     `String`, or the binary itself for literals like "hello". It's just a
+     pointer and a length, so making one never allocates.
+     * Rule of thumb for function signatures:
+       * take `&str` when you only need to read the text - callers can pass a
+       `&String` too, thanks to deref coercion,
+       * return `String` when you've built new text,
+       * return `&str` when the result is part of the input (like
+       `first_word`) - the compiler ties its lifetime to the argument,
+       * take `impl Into<String>` when you're going to store the text anyway,
+       so callers with a `String` can hand it over without a copy.
+   * Slicing
+     * `&s[a..b]` indexes by BYTE offsets, and panics if either end falls in
+     the middle of a multi-byte character. `char_indices` yields only valid
+     boundaries, which makes it the safe way to find slice positions.
+*/
+use std::io::{self, Write};
+use std::ops::Range;
+
+use crate::input;
+use crate::Options;
+
+// Returns a slice of the input - no allocation.
+fn first_word(s: &str) -> &str {
+    s.split_whitespace().next().unwrap_or("")
+}
+
+// Has to return a String: the upper-case text doesn't exist anywhere yet.
+fn shout(s: &str) -> String {
+    let mut loud = s.to_uppercase(); // allocates
+    loud.push('!'); // may reallocate if there's no spare capacity
+    loud
+}
+
+struct Note {
+    text: String,
+}
+
+// Passing a `String` moves it in (no copy). Passing a `&str` makes exactly
+// one allocation, inside `into()`, because the note must own its text.
+fn note(text: impl Into<String>) -> Note {
+    Note { text: text.into() }
+}
+
+// The byte range and text of each whitespace-separated word. Working from
+// `char_indices` means every range starts and ends on a char boundary, so
+// `&s[range]` can never panic.
+fn word_ranges(s: &str) -> Vec<(Range<usize>, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (index, ch) in s.char_indices() {
+        match (start, ch.is_whitespace()) {
+            (None, false) => start = Some(index),
+            (Some(begin), true) => {
+                words.push((begin..index, &s[begin..index]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(begin) = start {
+        words.push((begin..s.len(), &s[begin..]));
+    }
+    words
+}
+
+fn print_ranges(out: &mut dyn Write, s: &str) -> io::Result<()> {
+    let words = word_ranges(s);
+    if words.is_empty() {
+        writeln!(out, "No words in {s:?}.")?;
+    }
+    for (range, word) in words {
+        writeln!(
+            out,
+            "  bytes {:>2}..{:<2} {word:?} ({} bytes, {} chars)",
+            range.start,
+            range.end,
+            word.len(),
+            word.chars().count()
+        )?;
+    }
+    Ok(())
+}
+
+pub fn run(options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let owned = String::from("hello wonderful world");
+    // `&owned` is a `&String`, which derefs to the `&str` first_word wants.
+    let word = first_word(&owned);
+    writeln!(
+        out,
+        "first_word({owned:?}) = {word:?} - a slice, no allocation"
+    )?;
+    // Uncommenting the next line fails to compile while `word` is still used:
+    //     drop(owned);
+    // error[E0505]: cannot move out of `owned` because it is borrowed
+    writeln!(out, "shout({word:?}) = {:?} - a new String", shout(word))?;
+
+    let from_literal = note("copied into a new String");
+    let from_string = note(String::from("moved in, no copy"));
+    writeln!(out, "note(&str): {:?}", from_literal.text)?;
+    writeln!(out, "note(String): {:?}", from_string.text)?;
+    writeln!(out)?;
+
+    let sentence = "Crème brûlée 🦀 fans";
+    writeln!(out, "Word byte ranges in {sentence:?}:")?;
+    print_ranges(out, sentence)?;
+    // `&sentence[0..3]` would panic: byte 3 is in the middle of 'è'.
+    writeln!(
+        out,
+        "Is byte 3 a char boundary? {}",
+        sentence.is_char_boundary(3)
+    )?;
+
+    if options.interactive {
+        writeln!(out)?;
+        writeln!(out, "Type a sentence to see the byte ranges of its words.")?;
+        while let Some(line) = input::read_line("> ") {
+            print_ranges(out, &line)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_string() {
+        assert_eq!(first_word(""), "");
+        assert_eq!(shout(""), "!");
+        assert!(word_ranges("").is_empty());
+        assert!(word_ranges("   ").is_empty());
+    }
+
+    #[test]
+    fn leading_and_trailing_spaces() {
+        let s = "  hi there  ";
+        assert_eq!(first_word(s), "hi");
+        assert_eq!(word_ranges(s), [(2..4, "hi"), (5..10, "there")]);
+    }
+
+    #[test]
+    fn emoji_and_accents() {
+        let s = "🦀 café";
+        assert_eq!(first_word(s), "🦀");
+        // The crab is 4 bytes and 'é' is 2, so the ranges aren't char counts.
+        assert_eq!(word_ranges(s), [(0..4, "🦀"), (5..10, "café")]);
+        for (range, word) in word_ranges(s) {
+            assert_eq!(&s[range], word);
+        }
+        assert_eq!(shout("café"), "CAFÉ!");
+    }
+
+    #[test]
+    fn other_whitespace_separates_words() {
+        assert_eq!(
+            word_ranges("a\tb\nc"),
+            [(0..1, "a"), (2..3, "b"), (4..5, "c")]
+        );
+    }
+
+    #[test]
+    fn note_accepts_both_string_types() {
+        assert_eq!(note("a").text, "a");
+        assert_eq!(note(String::from("b")).text, "b");
+    }
+}
@@ -0,0 +1,186 @@
+/*
+   * Variables
+     * By default, variables in Rust are immutable. Rust will immediately catch
+     mutability errors as soon as you type them and will refuse to compile if
+     you try to build/run anyway.
+     * You don't have to explicitly specify a type for variables, but the
+     rust-analyzer will suggest one based on the value you assign. This is the
+     type that Rust will infer for the type.
+   * Constants
+     * Constants are similar but different to immutable variables. The `mut`
+     keyword doesn't work with the `const` keyword and constants must have a
+     type specified.
+     * Constant identifiers in Rust are ALL_CAPS_SNAKE_CASE.
+     * Rust attempts to evaluate complicated constants at compile time - see
+     https://doc.rust-lang.org/reference/const_eval.html for more.
+     * Constants are valid for the entire runtime of the program WITHIN THE
+     SCOPE THEY WERE DECLARED IN. This makes constants useful for unchanging
+     values that multiple parts of your code may need to know about.
+   * Shadowing
+     * Variables can be shadowed by repeating the `let` keyword. Note that this
+     is different from using the `mut` to denote the variable mutable and is NOT
+     a compile-time error.
+     * Shadowing within a scope is only valid within that scope - variables that
+     are created in one scope and then shadowed in an enclosed scope will keep
+     their value until the end of the scope at which point they take on the
+     original value again (see example below).
+     * Shadowing allows us to modify a variable but also keep the variable
+     immutable once we're finished with it.
+     * Shadowing also effectively allows us to change the type of a variable.
+*/
+
+use std::io::{self, Write};
+
+use crate::assertions::static_assert;
+use crate::Options;
+
+// Each demonstration is split in two: a function that computes the values the
+// comments talk about, and the section function that prints them. The
+// `debug_assert_eq!` calls check the comments are telling the truth every time
+// the tour runs in a debug build, and the tests at the bottom check them under
+// `cargo test`.
+
+// Variables
+fn immutable_value() -> i8 {
+    let x: i8 = 5; // This is an immutable variable - note the type specificity.
+                   // Attempting `x = 6;` is an error - "cannot mutate immutable variable `x`".
+    x
+}
+
+pub fn variables(_options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let x = immutable_value();
+    debug_assert_eq!(x, 5);
+    writeln!(out, "The value of x is {x}.")?;
+    Ok(())
+}
+
+// Mutability
+// Returns the value before and after the assignment.
+fn mutated_values() -> (i32, i32) {
+    let mut y = 10; // This is a mutable variable - note no type is given.
+    let before = y;
+    y = 12;
+    (before, y)
+}
+
+pub fn mutability(_options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let (before, after) = mutated_values();
+    debug_assert_eq!((before, after), (10, 12));
+    writeln!(out, "The value of y is {before}.")?;
+    writeln!(out, "The value of y is now {after}.")?;
+    Ok(())
+}
+
+// Constants
+// Note the explicit type and the `const` keyword.
+const THREE_HOURS_IN_SECONDS: u32 = 60 * 60 * 3;
+// Checked while compiling, so a mistake above stops the build.
+static_assert!(
+    THREE_HOURS_IN_SECONDS == 10_800,
+    "THREE_HOURS_IN_SECONDS must be 10,800"
+);
+
+pub fn constants(_options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    writeln!(
+        out,
+        "Three hours in equal to {THREE_HOURS_IN_SECONDS} seconds."
+    )?;
+    Ok(())
+}
+
+// Shadowing
+// Returns z before and after it is shadowed.
+fn shadowed_values() -> (i32, i32) {
+    // Initial declaration/initialisation.
+    let z = 5;
+    let before = z;
+    // Shadowing - z is equal to 10 from this point on.
+    let z = 10;
+    (before, z)
+}
+
+// Using shadowing to change a variable's type. Note that we can use the
+// original variable to construct the shadowed value.
+fn shadowed_type() -> usize {
+    let spaces = "     ";
+    let spaces = spaces.len(); // this would fail if we used `let mut`
+    spaces
+}
+
+pub fn shadowing(_options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let (before, after) = shadowed_values();
+    debug_assert_eq!((before, after), (5, 10));
+    writeln!(out, "The value of z is {before}.")?;
+    writeln!(out, "The value of z after shadowing is {after}.")?;
+
+    let spaces = shadowed_type();
+    debug_assert_eq!(spaces, 5);
+    writeln!(out, "There are {spaces} spaces in the string.")?;
+    Ok(())
+}
+
+// Scope
+// Returns z inside the inner scope, z after the inner scope has ended and the
+// final value once z is shadowed again using its outer value.
+fn shadowing_scopes() -> (i32, i32, i32) {
+    let z = 10;
+    let inner = {
+        let z = z * 2;
+        // z = 20 at this point
+        z
+    };
+
+    // z = 10 at this point
+    let outer = z;
+
+    // The outer z is still usable to build the next shadow - z = 11 from here.
+    let z = z + 1;
+    (inner, outer, z)
+}
+
+pub fn scope(_options: &Options, out: &mut dyn Write) -> io::Result<()> {
+    let (inner, outer, last) = shadowing_scopes();
+    debug_assert_eq!((inner, outer, last), (20, 10, 11));
+    writeln!(out, "The value of z within the inner scope is {inner}.")?;
+    writeln!(out, "The value of z after the inner scope is {outer}.")?;
+    writeln!(out, "The value of z after shadowing it again is {last}.")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn immutable_variable_keeps_its_value() {
+        assert_eq!(immutable_value(), 5);
+    }
+
+    #[test]
+    fn mutable_variable_is_reassigned() {
+        assert_eq!(mutated_values(), (10, 12));
+    }
+
+    #[test]
+    fn constant_is_evaluated_correctly() {
+        assert_eq!(THREE_HOURS_IN_SECONDS, 10_800);
+    }
+
+    #[test]
+    fn shadowing_replaces_the_value() {
+        assert_eq!(shadowed_values(), (5, 10));
+    }
+
+    #[test]
+    fn shadowing_can_change_the_type() {
+        assert_eq!(shadowed_type(), 5);
+    }
+
+    #[test]
+    fn shadowing_in_a_block_ends_with_the_block() {
+        let (inner, outer, last) = shadowing_scopes();
+        assert_eq!(inner, 20);
+        assert_eq!(outer, 10);
+        assert_eq!(last, 11);
+    }
+}
@@ -0,0 +1,152 @@
+/* Things learned during this exercise:
+   * A package can contain a library (`src/lib.rs`) AND a binary
+   (`src/main.rs`). Both are built by `cargo build`, and the binary uses the
+   library by the package name - `functions::is_leap_year` - just like any
+   other crate.
+   * `///` starts a doc comment. It documents the item below it, supports
+   Markdown, and `cargo doc --open` turns it into HTML pages.
+   * Code blocks in doc comments are compiled and run by `cargo test` as
+   "doc tests", so the examples can't quietly go out of date.
+   * Only items marked `pub` can be used from outside the library.
+*/
+
+/// Converts a temperature from degrees Celsius to degrees Fahrenheit.
+///
+/// The body is a single expression - there's no `return` and no semicolon, so
+/// its value is what the function returns.
+///
+/// ```
+/// use functions::celsius_to_fahrenheit;
+///
+/// assert_eq!(celsius_to_fahrenheit(100.0), 212.0);
+/// assert_eq!(celsius_to_fahrenheit(-40.0), -40.0);
+/// ```
+pub fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+/// Returns whether `year` is a leap year in the Gregorian calendar.
+///
+/// A year is a leap year if it's divisible by 4 - except for century years,
+/// which are only leap years when they're divisible by 400. So 2024 and 2000
+/// are leap years, but 1900 isn't.
+///
+/// Each rule is an early `return`: as soon as one of them decides the answer,
+/// the rest of the function is skipped.
+///
+/// ```
+/// use functions::is_leap_year;
+///
+/// assert!(is_leap_year(2024));
+/// assert!(!is_leap_year(1900));
+/// ```
+pub fn is_leap_year(year: u32) -> bool {
+    if year.is_multiple_of(400) {
+        return true;
+    }
+    if year.is_multiple_of(100) {
+        return false;
+    }
+    year.is_multiple_of(4)
+}
+
+/// Restricts `value` to the range `min..=max`.
+///
+/// Values below the range become `min`, values above it become `max`, and
+/// values inside it are returned unchanged. This is what the standard
+/// library's `i32::clamp` does - writing it out shows `if` being used as an
+/// expression.
+///
+/// # Panics
+///
+/// Panics if `min` is greater than `max`, since then there's no range to
+/// clamp to. `i32::clamp` does the same.
+///
+/// ```
+/// use functions::clamp_to_range;
+///
+/// assert_eq!(clamp_to_range(150, 0, 100), 100);
+/// assert_eq!(clamp_to_range(-5, 0, 100), 0);
+/// assert_eq!(clamp_to_range(42, 0, 100), 42);
+/// ```
+pub fn clamp_to_range(value: i32, min: i32, max: i32) -> i32 {
+    assert!(
+        min <= max,
+        "min ({min}) must not be greater than max ({max})"
+    );
+
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn freezing_and_boiling_points() {
+        assert_eq!(celsius_to_fahrenheit(0.0), 32.0);
+        assert_eq!(celsius_to_fahrenheit(100.0), 212.0);
+    }
+
+    #[test]
+    fn scales_meet_at_minus_forty() {
+        assert_eq!(celsius_to_fahrenheit(-40.0), -40.0);
+    }
+
+    #[test]
+    fn body_temperature() {
+        assert!((celsius_to_fahrenheit(37.0) - 98.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn years_divisible_by_four_are_leap_years() {
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(1996));
+        assert!(!is_leap_year(2023));
+    }
+
+    #[test]
+    fn century_years_are_not_leap_years() {
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2100));
+    }
+
+    #[test]
+    fn years_divisible_by_four_hundred_are_leap_years() {
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(1600));
+    }
+
+    #[test]
+    fn clamps_below_inside_and_above() {
+        assert_eq!(clamp_to_range(-1, 0, 10), 0);
+        assert_eq!(clamp_to_range(5, 0, 10), 5);
+        assert_eq!(clamp_to_range(11, 0, 10), 10);
+    }
+
+    #[test]
+    fn range_boundaries_are_included() {
+        assert_eq!(clamp_to_range(0, 0, 10), 0);
+        assert_eq!(clamp_to_range(10, 0, 10), 10);
+        assert_eq!(clamp_to_range(7, 7, 7), 7);
+    }
+
+    #[test]
+    fn matches_the_standard_library() {
+        for value in [i32::MIN, -3, 0, 3, i32::MAX] {
+            assert_eq!(clamp_to_range(value, -2, 2), value.clamp(-2, 2));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be greater than max")]
+    fn reversed_range_panics() {
+        clamp_to_range(5, 10, 0);
+    }
+}
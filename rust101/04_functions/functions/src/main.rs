@@ -0,0 +1,113 @@
+/* Things learned during this exercise:
+   * Functions are declared with `fn`, and their names are snake_case like
+   variables. Rust doesn't care whether a function is defined before or
+   after the code that calls it, only that it's in scope.
+   * Every parameter MUST have a type annotation - unlike `let`, the compiler
+   won't infer them. That's deliberate: the signature is the contract, so
+   callers and error messages never depend on the body.
+   * The return type goes after `->`. The function returns the value of the
+   last expression in its body, which must NOT end in a semicolon -
+   `fn plus_one(x: i32) -> i32 { x + 1; }` is "error[E0308]: mismatched
+   types", because `x + 1;` is a statement and the body's value is `()`.
+   * `return` exits early, and is mostly used for exactly that - returning
+   before the end. Writing `return x;` as the last line works but isn't
+   idiomatic.
+   * A function with no `->` returns `()`, the unit type.
+   * The practice functions live in `src/lib.rs`, which shows how a binary
+   uses its package's library.
+   * See https://doc.rust-lang.org/book/ch03-03-how-functions-work.html
+*/
+use functions::{celsius_to_fahrenheit, clamp_to_range, is_leap_year};
+
+fn main() {
+    println!("== Parameters ==");
+    print_labeled_measurement(5, 'h');
+    println!();
+
+    println!("== Expression bodies ==");
+    println!("plus_one(5) = {}", plus_one(5));
+    println!();
+
+    println!("== Early returns ==");
+    for (a, b) in [(7, 2), (7, 0), (i32::MIN, -1)] {
+        println!("checked_divide({a}, {b}) = {:?}", checked_divide(a, b));
+    }
+    println!();
+
+    println!("== Unit-returning functions ==");
+    // `greet` returns `()`. There's nothing useful to do with that value -
+    // `let nothing = greet("Ferris");` compiles, but Clippy points out that
+    // `nothing` can only ever be `()`.
+    greet("Ferris");
+    println!();
+
+    println!("== Practice functions ==");
+    for celsius in [-40.0, 0.0, 37.0, 100.0] {
+        println!("{celsius}°C is {}°F", celsius_to_fahrenheit(celsius));
+    }
+    println!();
+
+    for year in [1900, 2000, 2023, 2024] {
+        let verdict = if is_leap_year(year) { "is" } else { "isn't" };
+        println!("{year} {verdict} a leap year");
+    }
+    println!();
+
+    for value in [-20, 50, 120] {
+        println!(
+            "clamp_to_range({value}, 0, 100) = {}",
+            clamp_to_range(value, 0, 100)
+        );
+    }
+}
+
+// Two parameters of different types - each needs its own annotation.
+fn print_labeled_measurement(value: i32, unit_label: char) {
+    println!("The measurement is: {value}{unit_label}");
+}
+
+// The whole body is one expression, so there's no `return` and no `;`.
+fn plus_one(x: i32) -> i32 {
+    x + 1
+}
+
+// Dividing by zero would panic, so that case returns straight away. So does
+// i32::MIN / -1: the answer, 2^31, is one past i32::MAX and overflows. The
+// last line only runs when neither early return did. (The standard library
+// has this built in as `a.checked_div(b)`.)
+fn checked_divide(a: i32, b: i32) -> Option<i32> {
+    if b == 0 {
+        return None;
+    }
+    if a == i32::MIN && b == -1 {
+        return None;
+    }
+    Some(a / b)
+}
+
+// No `->`, so the return type is `()`. Writing `-> ()` would mean the same
+// thing but nobody does.
+fn greet(name: &str) {
+    println!("Hello, {name}!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plus_one_adds_one() {
+        assert_eq!(plus_one(5), 6);
+        assert_eq!(plus_one(-1), 0);
+    }
+
+    #[test]
+    fn checked_divide_returns_early_for_zero() {
+        assert_eq!(checked_divide(7, 2), Some(3));
+        assert_eq!(checked_divide(-7, 2), Some(-3));
+        assert_eq!(checked_divide(7, 0), None);
+        assert_eq!(checked_divide(i32::MIN, -1), None);
+        assert_eq!(checked_divide(i32::MIN, 1), Some(i32::MIN));
+        assert_eq!(checked_divide(i32::MAX, -1), Some(-i32::MAX));
+    }
+}
@@ -0,0 +1,213 @@
+/* Things learned during this exercise:
+   * `if` conditions must be a `bool` - Rust won't treat a number as true or
+   false, so `if number { ... }` is "error[E0308]: mismatched types -
+   expected `bool`, found integer". Write `if number != 0` instead.
+   * `if` is an expression, so `let x = if condition { 5 } else { 6 };` works
+   as long as both branches have the same type.
+   * A long `else if` chain is often clearer as a `match`.
+   * Rust has three loops:
+     * `loop` repeats until something `break`s out of it, and `break value`
+     makes the loop itself evaluate to `value`,
+     * `while condition` checks before every iteration,
+     * `for item in collection` is the usual way to go over a range, an array
+     or anything else that can be iterated - no index to get wrong.
+   * Loops can be labelled (`'outer: for ...`), and `break 'outer` or
+   `continue 'outer` then applies to that loop rather than the innermost
+   one. Labels start with a single quote.
+   * See https://doc.rust-lang.org/book/ch03-05-control-flow.html
+*/
+
+fn main() {
+    println!("== if / else if ==");
+    for number in [6, 9, 10, 7] {
+        println!("{number} is {}", divisibility(number));
+    }
+    println!();
+
+    println!("== loop with a value ==");
+    let (counter, result) = double_until_over(20);
+    println!("Doubling from 1 passes 20 after {counter} steps, at {result}");
+    println!();
+
+    println!("== while ==");
+    println!("{}", countdown(3));
+    println!();
+
+    println!("== for over ranges and arrays ==");
+    println!("The sum of 1..=10 is {}", sum_to(10));
+    let temperatures = [12, 19, 15, 22, 17];
+    println!(
+        "The warmest of {temperatures:?} is {}",
+        warmest(&temperatures).expect("the array isn't empty")
+    );
+    println!();
+
+    println!("== labelled loops ==");
+    let grid = [[3, 8, 1, 6], [4, 2, 9, 5], [7, 1, 3, 2]];
+    for target in [9, 11, 100] {
+        match find_first_pair_summing(&grid, target) {
+            Some((row, col)) => println!(
+                "Two numbers in row {row} sum to {target}, completed by column {col} ({})",
+                grid[row][col]
+            ),
+            None => println!("No two numbers in the same row sum to {target}"),
+        }
+    }
+}
+
+// An `else if` chain - the branches are checked in order and only the first
+// one that's true runs, so 6 is reported as divisible by 3 and never reaches
+// the "by 2" check.
+fn divisibility(number: i32) -> &'static str {
+    if number % 4 == 0 {
+        "divisible by 4"
+    } else if number % 3 == 0 {
+        "divisible by 3"
+    } else if number % 2 == 0 {
+        "divisible by 2"
+    } else {
+        "not divisible by 4, 3 or 2"
+    }
+}
+
+// `loop` has no condition of its own, so `break` decides when it ends - and
+// `break value` hands a value back to the `let`. Returns how many doublings
+// it took and the first value over `limit`.
+fn double_until_over(limit: u32) -> (u32, u32) {
+    let mut counter = 0;
+    let mut value = 1;
+    let result = loop {
+        counter += 1;
+        value *= 2;
+        if value > limit {
+            break value;
+        }
+    };
+    (counter, result)
+}
+
+// `while` is the loop to use when there's a condition to check before each
+// pass.
+fn countdown(from: u32) -> String {
+    let mut number = from;
+    let mut text = String::new();
+    while number != 0 {
+        text.push_str(&format!("{number}... "));
+        number -= 1;
+    }
+    text.push_str("LIFTOFF!");
+    text
+}
+
+// `for` over an inclusive range.
+fn sum_to(n: u32) -> u32 {
+    let mut total = 0;
+    for number in 1..=n {
+        total += number;
+    }
+    total
+}
+
+// `for` over the elements of a slice. There's no index, so there's nothing
+// to get wrong - compare `while index < 5 { a[index] ... }`, which panics if
+// the array is ever shortened and the 5 isn't updated.
+fn warmest(temperatures: &[i32]) -> Option<i32> {
+    let mut warmest = None;
+    for &temperature in temperatures {
+        if warmest.is_none_or(|current| temperature > current) {
+            warmest = Some(temperature);
+        }
+    }
+    warmest
+}
+
+// Looks for two numbers in the same row that add up to `target`, going
+// through the grid in reading order. Returns the row and column of the cell
+// that completes the first such pair.
+//
+// There are three nested loops, and as soon as a pair is found all three
+// should stop. A plain `break` would only leave the innermost one; labelling
+// the outer loop `'rows` lets `break 'rows` jump straight out of everything.
+fn find_first_pair_summing(grid: &[[i32; 4]], target: i32) -> Option<(usize, usize)> {
+    let mut found = None;
+
+    'rows: for (row, cells) in grid.iter().enumerate() {
+        for col in 1..cells.len() {
+            for earlier in 0..col {
+                if cells[earlier] + cells[col] == target {
+                    found = Some((row, col));
+                    break 'rows;
+                }
+            }
+        }
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: [[i32; 4]; 3] = [[3, 8, 1, 6], [4, 2, 9, 5], [7, 1, 3, 2]];
+
+    #[test]
+    fn else_if_takes_the_first_true_branch() {
+        assert_eq!(divisibility(12), "divisible by 4");
+        assert_eq!(divisibility(6), "divisible by 3");
+        assert_eq!(divisibility(10), "divisible by 2");
+        assert_eq!(divisibility(7), "not divisible by 4, 3 or 2");
+    }
+
+    #[test]
+    fn loop_breaks_with_a_value() {
+        assert_eq!(double_until_over(20), (5, 32));
+        assert_eq!(double_until_over(1), (1, 2));
+    }
+
+    #[test]
+    fn while_counts_down() {
+        assert_eq!(countdown(3), "3... 2... 1... LIFTOFF!");
+        assert_eq!(countdown(0), "LIFTOFF!");
+    }
+
+    #[test]
+    fn for_over_a_range() {
+        assert_eq!(sum_to(10), 55);
+        assert_eq!(sum_to(0), 0);
+    }
+
+    #[test]
+    fn for_over_an_array() {
+        assert_eq!(warmest(&[12, 19, 15, 22, 17]), Some(22));
+        assert_eq!(warmest(&[-5]), Some(-5));
+        assert_eq!(warmest(&[]), None);
+    }
+
+    #[test]
+    fn pair_found_in_the_first_row() {
+        // 3 + 8
+        assert_eq!(find_first_pair_summing(&GRID, 11), Some((0, 1)));
+        // 8 + 1 is found before 3 + 6, and both before 4 + 5 in the next row.
+        assert_eq!(find_first_pair_summing(&GRID, 9), Some((0, 2)));
+    }
+
+    #[test]
+    fn pair_found_in_the_last_cell() {
+        let grid = [[1, 1, 1, 1], [1, 1, 1, 1], [20, 30, 40, 50]];
+        // 40 + 50 - every earlier cell has to be checked first.
+        assert_eq!(find_first_pair_summing(&grid, 90), Some((2, 3)));
+    }
+
+    #[test]
+    fn pair_not_found() {
+        assert_eq!(find_first_pair_summing(&GRID, 100), None);
+        assert_eq!(find_first_pair_summing(&[], 5), None);
+        // Numbers in different rows don't count: 6 + 4 is 10 but never in
+        // the same row.
+        assert_eq!(
+            find_first_pair_summing(&[[6, 0, 0, 0], [4, 0, 0, 0]], 10),
+            None
+        );
+    }
+}
@@ -0,0 +1,115 @@
+// Three ways to compute the nth Fibonacci number, all with the same
+// signature so they can be swapped for one another (and stored in a table as
+// `fn(u32) -> Option<u128>` pointers). Each returns `None` rather than
+// panicking when the answer doesn't fit in a u128.
+//
+// fib(0) = 0, fib(1) = 1, and every later number is the sum of the two before.
+use std::collections::HashMap;
+
+// fib(186) is the largest Fibonacci number that fits in a u128; fib(187)
+// overflows. The tests check this against the iterative version.
+pub const LARGEST_N: u32 = 186;
+
+// Straight from the definition. Every call makes two more, so the number of
+// calls grows about as fast as the answer itself - fib(40) takes hundreds of
+// millions of them. Anything past LARGEST_N is rejected up front, as finding
+// out by recursing would never finish.
+pub fn recursive(n: u32) -> Option<u128> {
+    if n > LARGEST_N {
+        return None;
+    }
+    match n {
+        0 => Some(0),
+        1 => Some(1),
+        _ => recursive(n - 1)?.checked_add(recursive(n - 2)?),
+    }
+}
+
+// Keeps only the last two numbers, so it's one addition per step and no
+// recursion. `checked_add` is what detects the overflow.
+pub fn iterative(n: u32) -> Option<u128> {
+    let (mut previous, mut current): (u128, u128) = (0, 1);
+    if n == 0 {
+        return Some(0);
+    }
+    for _ in 1..n {
+        let next = previous.checked_add(current)?;
+        previous = current;
+        current = next;
+    }
+    Some(current)
+}
+
+// The recursive version, but each answer is stored in a HashMap the first
+// time it's worked out and looked up after that - so each n is only computed
+// once. The early return also stops the recursion going arbitrarily deep
+// (each level is a stack frame) for huge n.
+pub fn memoized(n: u32) -> Option<u128> {
+    if n > LARGEST_N {
+        return None;
+    }
+    let mut cache = HashMap::new();
+    memoized_with(n, &mut cache)
+}
+
+fn memoized_with(n: u32, cache: &mut HashMap<u32, u128>) -> Option<u128> {
+    if n < 2 {
+        return Some(n.into());
+    }
+    if let Some(&known) = cache.get(&n) {
+        return Some(known);
+    }
+    let value = memoized_with(n - 1, cache)?.checked_add(memoized_with(n - 2, cache)?)?;
+    cache.insert(n, value);
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIB_186: u128 = 332_825_110_087_067_562_321_196_029_789_634_457_848;
+
+    #[test]
+    fn first_values() {
+        for fib in [recursive, iterative, memoized] {
+            assert_eq!(fib(0), Some(0));
+            assert_eq!(fib(1), Some(1));
+            assert_eq!(fib(2), Some(1));
+            assert_eq!(fib(10), Some(55));
+        }
+    }
+
+    // The naive version is left out here - it would take far too long.
+    #[test]
+    fn largest_value_that_fits() {
+        assert_eq!(iterative(LARGEST_N), Some(FIB_186));
+        assert_eq!(memoized(LARGEST_N), Some(FIB_186));
+    }
+
+    #[test]
+    fn beyond_the_largest_is_none() {
+        for fib in [recursive, iterative, memoized] {
+            assert_eq!(fib(LARGEST_N + 1), None);
+            assert_eq!(fib(1_000), None);
+            assert_eq!(fib(u32::MAX), None);
+        }
+    }
+
+    #[test]
+    fn largest_n_really_is_the_limit() {
+        // The iterative version has no guard, so this checks the constant the
+        // other two rely on.
+        let previous = iterative(LARGEST_N - 1).unwrap();
+        assert!(FIB_186.checked_add(previous).is_none());
+    }
+
+    #[test]
+    fn all_implementations_agree() {
+        for n in 0..=30 {
+            let expected = iterative(n);
+            assert_eq!(recursive(n), expected, "recursive({n})");
+            assert_eq!(memoized(n), expected, "memoized({n})");
+        }
+    }
+}
@@ -0,0 +1,62 @@
+/* Things learned during this exercise:
+   * The second exercise from the end of chapter 3 of the book: "Generate the
+   nth Fibonacci number". `cargo run -- 50` prints fib(50), worked out by
+   each implementation in fib.rs along with how long each one took.
+   * `std::time::Instant::now()` marks a point in time and `.elapsed()` gives
+   the `Duration` since then. Duration's `{:?}` output picks sensible units.
+   * Try `cargo run --release -- 35` as well - optimised builds make a huge
+   difference to the recursive version, but it still loses.
+   * fib(186) is the largest that fits in a u128. Rather than panicking
+   ("attempt to add with overflow") beyond that, each implementation uses
+   `checked_add` and returns `None`.
+*/
+mod fib;
+
+use std::env;
+use std::process;
+use std::time::Instant;
+
+// Above this the naive recursive version takes too long to wait for - each
+// step up roughly multiplies its running time by 1.6.
+const RECURSIVE_LIMIT: u32 = 35;
+
+// The signature every implementation shares, as a function pointer type.
+type Fib = fn(u32) -> Option<u128>;
+
+const IMPLEMENTATIONS: [(&str, Fib); 3] = [
+    ("recursive", fib::recursive),
+    ("iterative", fib::iterative),
+    ("memoized", fib::memoized),
+];
+
+fn main() {
+    let Some(argument) = env::args().nth(1) else {
+        eprintln!("Usage: fibonacci <n>");
+        process::exit(1);
+    };
+    let n: u32 = match argument.parse() {
+        Ok(n) => n,
+        Err(_) => {
+            eprintln!("`{argument}` isn't a valid n - it must be a whole number from 0.");
+            process::exit(1);
+        }
+    };
+
+    for (name, fib) in IMPLEMENTATIONS {
+        if name == "recursive" && n > RECURSIVE_LIMIT {
+            println!("{name:<10} skipped - it would take too long for n > {RECURSIVE_LIMIT}");
+            continue;
+        }
+
+        let start = Instant::now();
+        let result = fib(n);
+        let elapsed = start.elapsed();
+        match result {
+            Some(value) => println!("{name:<10} fib({n}) = {value} in {elapsed:?}"),
+            None => println!(
+                "{name:<10} fib({n}) doesn't fit in a u128 (the largest is fib({})) - found out in {elapsed:?}",
+                fib::LARGEST_N
+            ),
+        }
+    }
+}
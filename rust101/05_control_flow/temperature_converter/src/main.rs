@@ -0,0 +1,44 @@
+/* Things learned during this exercise:
+   * This is the first exercise suggested at the end of chapter 3 of the book,
+   "Convert temperatures between Fahrenheit and Celsius" - with Kelvin added.
+   Type a temperature like `32F`, `100C` or `283.15K` and it's shown in the
+   other two scales. Type `quit` to exit.
+   * `Temperature` is an enum whose variants each hold an `f64`, so the unit
+   and the value can't get out of step - there's no way to have a number
+   without knowing which scale it's in. Methods on the enum `match` on the
+   variant to pick the right formula.
+   * `parse_temperature` returns `Result<Temperature, TempError>`. `TempError`
+   is an enum with one variant per problem, and implements `Display` so
+   `println!("{error}")` gives a readable message.
+   * `char::to_ascii_uppercase` lets `c` and `C` be handled by one match arm.
+*/
+mod temperature;
+
+use std::io::{self, Write};
+
+use temperature::parse_temperature;
+
+fn main() {
+    println!("Enter a temperature like `32F`, `100C` or `283.15K`. Type `quit` to exit.");
+
+    loop {
+        print!("> ");
+        io::stdout().flush().expect("Failed to flush stdout.");
+
+        let mut line = String::new();
+        let bytes = io::stdin()
+            .read_line(&mut line)
+            .expect("Failed to read line.");
+        if bytes == 0 || line.trim() == "quit" {
+            break;
+        }
+
+        match parse_temperature(&line) {
+            Ok(temperature) => {
+                let [first, second] = temperature.others();
+                println!("{temperature} = {first} = {second}");
+            }
+            Err(error) => println!("{error}"),
+        }
+    }
+}
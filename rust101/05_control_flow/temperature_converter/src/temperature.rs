@@ -0,0 +1,284 @@
+// A temperature in one of three scales, and the parser for lines like `32F`.
+//
+// The error type is an enum rather than a `String`, so the tests (and any
+// caller) can check WHICH thing went wrong instead of comparing messages. Its
+// `Display` impl provides the message for people.
+use std::fmt;
+
+// Absolute zero in each scale - nothing can be colder.
+const ABSOLUTE_ZERO_C: f64 = -273.15;
+const ABSOLUTE_ZERO_F: f64 = -459.67;
+const ABSOLUTE_ZERO_K: f64 = 0.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Temperature {
+    Celsius(f64),
+    Fahrenheit(f64),
+    Kelvin(f64),
+}
+
+impl Temperature {
+    // Each conversion goes through Celsius, so there are two formulas per
+    // scale instead of one per pair of scales.
+    pub fn celsius(self) -> f64 {
+        match self {
+            Temperature::Celsius(c) => c,
+            Temperature::Fahrenheit(f) => (f - 32.0) * 5.0 / 9.0,
+            Temperature::Kelvin(k) => k + ABSOLUTE_ZERO_C,
+        }
+    }
+
+    pub fn fahrenheit(self) -> f64 {
+        match self {
+            Temperature::Fahrenheit(f) => f,
+            other => other.celsius() * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    pub fn kelvin(self) -> f64 {
+        match self {
+            Temperature::Kelvin(k) => k,
+            other => other.celsius() - ABSOLUTE_ZERO_C,
+        }
+    }
+
+    // The same temperature in the other two scales.
+    pub fn others(self) -> [Temperature; 2] {
+        match self {
+            Temperature::Celsius(_) => [
+                Temperature::Fahrenheit(self.fahrenheit()),
+                Temperature::Kelvin(self.kelvin()),
+            ],
+            Temperature::Fahrenheit(_) => [
+                Temperature::Celsius(self.celsius()),
+                Temperature::Kelvin(self.kelvin()),
+            ],
+            Temperature::Kelvin(_) => [
+                Temperature::Celsius(self.celsius()),
+                Temperature::Fahrenheit(self.fahrenheit()),
+            ],
+        }
+    }
+
+    // Compared in the temperature's own scale, so `-273.15C` is exactly
+    // absolute zero rather than off by a rounding error after converting.
+    fn is_below_absolute_zero(self) -> bool {
+        match self {
+            Temperature::Celsius(c) => c < ABSOLUTE_ZERO_C,
+            Temperature::Fahrenheit(f) => f < ABSOLUTE_ZERO_F,
+            Temperature::Kelvin(k) => k < ABSOLUTE_ZERO_K,
+        }
+    }
+}
+
+impl fmt::Display for Temperature {
+    // Two decimal places, with trailing zeros dropped - `0°C` rather than
+    // `0.00°C`. Kelvin is an absolute scale, so it has no degree sign.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (value, unit) = match *self {
+            Temperature::Celsius(c) => (c, "°C"),
+            Temperature::Fahrenheit(f) => (f, "°F"),
+            Temperature::Kelvin(k) => (k, " K"),
+        };
+        let rounded = format!("{value:.2}");
+        let trimmed = rounded.trim_end_matches('0').trim_end_matches('.');
+        // Rounding a tiny negative number gives `-0`, which looks odd.
+        let trimmed = if trimmed == "-0" { "0" } else { trimmed };
+        write!(f, "{trimmed}{unit}")
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TempError {
+    Empty,
+    MissingUnit,
+    UnknownUnit(char),
+    InvalidNumber(String),
+    BelowAbsoluteZero(Temperature),
+}
+
+impl fmt::Display for TempError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TempError::Empty => write!(f, "Type a temperature like `32F`, `100C` or `283.15K`."),
+            TempError::MissingUnit => write!(f, "Add a unit after the number: C, F or K."),
+            TempError::UnknownUnit(unit) => {
+                write!(f, "`{unit}` isn't a unit - use C, F or K.")
+            }
+            TempError::InvalidNumber(text) => write!(f, "`{text}` isn't a number."),
+            TempError::BelowAbsoluteZero(temperature) => write!(
+                f,
+                "{temperature} is below absolute zero ({}), so it can't exist.",
+                match temperature {
+                    Temperature::Celsius(_) => Temperature::Celsius(ABSOLUTE_ZERO_C),
+                    Temperature::Fahrenheit(_) => Temperature::Fahrenheit(ABSOLUTE_ZERO_F),
+                    Temperature::Kelvin(_) => Temperature::Kelvin(ABSOLUTE_ZERO_K),
+                }
+            ),
+        }
+    }
+}
+
+// Accepts a number followed by a unit letter in either case, with optional
+// whitespace around and between them: `32F`, ` -40 c `, `283.15k`.
+pub fn parse_temperature(text: &str) -> Result<Temperature, TempError> {
+    let text = text.trim();
+    let Some(unit) = text.chars().last() else {
+        return Err(TempError::Empty);
+    };
+    if unit.is_ascii_digit() || unit == '.' {
+        return Err(TempError::MissingUnit);
+    }
+
+    // The last char isn't necessarily ASCII (try `32°`), so slice off however
+    // many bytes it takes up rather than assuming one.
+    let number = text[..text.len() - unit.len_utf8()].trim();
+    let value: f64 = number
+        .parse()
+        .map_err(|_| TempError::InvalidNumber(number.to_string()))?;
+    // `"inf".parse::<f64>()` and `"NaN".parse::<f64>()` both succeed.
+    if !value.is_finite() {
+        return Err(TempError::InvalidNumber(number.to_string()));
+    }
+
+    let temperature = match unit.to_ascii_uppercase() {
+        'C' => Temperature::Celsius(value),
+        'F' => Temperature::Fahrenheit(value),
+        'K' => Temperature::Kelvin(value),
+        _ => return Err(TempError::UnknownUnit(unit)),
+    };
+    if temperature.is_below_absolute_zero() {
+        return Err(TempError::BelowAbsoluteZero(temperature));
+    }
+    Ok(temperature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < EPSILON
+    }
+
+    #[test]
+    fn known_conversions() {
+        let boiling = Temperature::Celsius(100.0);
+        assert!(close(boiling.fahrenheit(), 212.0));
+        assert!(close(boiling.kelvin(), 373.15));
+
+        let body = Temperature::Fahrenheit(98.6);
+        assert!(close(body.celsius(), 37.0));
+
+        let freezing = Temperature::Kelvin(273.15);
+        assert!(close(freezing.celsius(), 0.0));
+        assert!(close(freezing.fahrenheit(), 32.0));
+
+        assert!(close(Temperature::Celsius(-40.0).fahrenheit(), -40.0));
+    }
+
+    #[test]
+    fn round_trips_within_epsilon() {
+        for value in [-273.15, -40.0, 0.0, 21.5, 100.0, 5_000.0] {
+            let celsius = Temperature::Celsius(value);
+            assert!(close(
+                Temperature::Fahrenheit(celsius.fahrenheit()).celsius(),
+                value
+            ));
+            assert!(close(
+                Temperature::Kelvin(celsius.kelvin()).celsius(),
+                value
+            ));
+
+            let fahrenheit = Temperature::Fahrenheit(value);
+            assert!(close(
+                Temperature::Kelvin(fahrenheit.kelvin()).fahrenheit(),
+                value
+            ));
+        }
+    }
+
+    #[test]
+    fn others_are_the_remaining_two_scales() {
+        let [f, k] = Temperature::Celsius(0.0).others();
+        assert_eq!(f, Temperature::Fahrenheit(32.0));
+        assert!(matches!(k, Temperature::Kelvin(k) if close(k, 273.15)));
+    }
+
+    #[test]
+    fn parses_units_in_either_case_with_whitespace() {
+        assert_eq!(parse_temperature("32F"), Ok(Temperature::Fahrenheit(32.0)));
+        assert_eq!(parse_temperature("100c"), Ok(Temperature::Celsius(100.0)));
+        assert_eq!(
+            parse_temperature(" 283.15 K "),
+            Ok(Temperature::Kelvin(283.15))
+        );
+        assert_eq!(
+            parse_temperature("-40 f"),
+            Ok(Temperature::Fahrenheit(-40.0))
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(parse_temperature(""), Err(TempError::Empty));
+        assert_eq!(parse_temperature("   "), Err(TempError::Empty));
+        assert_eq!(parse_temperature("32"), Err(TempError::MissingUnit));
+        assert_eq!(parse_temperature("32X"), Err(TempError::UnknownUnit('X')));
+        assert_eq!(parse_temperature("32°"), Err(TempError::UnknownUnit('°')));
+        assert_eq!(
+            parse_temperature("warmC"),
+            Err(TempError::InvalidNumber("warm".to_string()))
+        );
+        assert_eq!(
+            parse_temperature("C"),
+            Err(TempError::InvalidNumber(String::new()))
+        );
+        assert_eq!(
+            parse_temperature("infC"),
+            Err(TempError::InvalidNumber("inf".to_string()))
+        );
+    }
+
+    #[test]
+    fn absolute_zero_is_allowed_but_nothing_colder() {
+        assert!(parse_temperature("-273.15C").is_ok());
+        assert!(parse_temperature("-459.67F").is_ok());
+        assert!(parse_temperature("0K").is_ok());
+
+        assert_eq!(
+            parse_temperature("-300C"),
+            Err(TempError::BelowAbsoluteZero(Temperature::Celsius(-300.0)))
+        );
+        assert_eq!(
+            parse_temperature("-460F"),
+            Err(TempError::BelowAbsoluteZero(Temperature::Fahrenheit(
+                -460.0
+            )))
+        );
+        assert_eq!(
+            parse_temperature("-0.01K"),
+            Err(TempError::BelowAbsoluteZero(Temperature::Kelvin(-0.01)))
+        );
+    }
+
+    #[test]
+    fn absolute_zero_message() {
+        let message = parse_temperature("-300C").unwrap_err().to_string();
+        assert_eq!(
+            message,
+            "-300°C is below absolute zero (-273.15°C), so it can't exist."
+        );
+    }
+
+    #[test]
+    fn display_trims_trailing_zeros() {
+        assert_eq!(Temperature::Celsius(0.0).to_string(), "0°C");
+        assert_eq!(Temperature::Fahrenheit(98.6).to_string(), "98.6°F");
+        assert_eq!(Temperature::Kelvin(273.15).to_string(), "273.15 K");
+        assert_eq!(Temperature::Celsius(-0.001).to_string(), "0°C");
+        assert_eq!(Temperature::Celsius(100.0).to_string(), "100°C");
+    }
+}
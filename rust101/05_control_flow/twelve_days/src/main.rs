@@ -0,0 +1,164 @@
+/* Things learned during this exercise:
+   * The last exercise from the end of chapter 3 of the book: "Print the
+   lyrics to the Christmas carol 'The Twelve Days of Christmas', taking
+   advantage of the repetition in the song". `cargo run` prints the whole
+   song and `cargo run -- --day 3` prints one verse.
+   * The words live in two `const` arrays and the verses are built from them
+   with loops - the song's repetition becomes a `for` loop counting down.
+   * `(1..=day).rev()` iterates from `day` down to 1. A range that counts
+   down directly (`day..=1`) is empty instead.
+   * Arrays are indexed from 0 but days are counted from 1, hence the
+   `day - 1`s.
+   * `Vec<String>::join("\n")` glues strings together with a separator
+   between them (but not after the last).
+   * Capitalising the first letter of a `&str` takes some care: `chars()`
+   yields `char`s, `to_uppercase()` on a char returns an iterator (some
+   characters become more than one when uppercased), and `as_str()` gives
+   back the rest of the text.
+*/
+use std::env;
+use std::process;
+
+const ORDINALS: [&str; 12] = [
+    "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth", "tenth",
+    "eleventh", "twelfth",
+];
+
+const GIFTS: [&str; 12] = [
+    "a partridge in a pear tree",
+    "two turtle doves",
+    "three French hens",
+    "four calling birds",
+    "five gold rings",
+    "six geese a-laying",
+    "seven swans a-swimming",
+    "eight maids a-milking",
+    "nine ladies dancing",
+    "ten lords a-leaping",
+    "eleven pipers piping",
+    "twelve drummers drumming",
+];
+
+fn capitalise(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// The verse for `day`, from 1 to 12. From the second day onwards the
+// partridge is the last of several gifts, so it's introduced with "And".
+fn verse(day: usize) -> String {
+    assert!(
+        (1..=12).contains(&day),
+        "there are only twelve days, not {day}"
+    );
+
+    let mut lines = vec![format!(
+        "On the {} day of Christmas my true love sent to me",
+        ORDINALS[day - 1]
+    )];
+    for gift_day in (1..=day).rev() {
+        let gift = GIFTS[gift_day - 1];
+        let line = match (gift_day, day) {
+            (1, 1) => format!("{}.", capitalise(gift)),
+            (1, _) => format!("And {gift}."),
+            _ => format!("{},", capitalise(gift)),
+        };
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+fn song() -> String {
+    let verses: Vec<String> = (1..=12).map(verse).collect();
+    verses.join("\n\n")
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.as_slice() {
+        [] => println!("{}", song()),
+        [flag, day] if flag == "--day" => match day.parse::<usize>() {
+            Ok(day) if (1..=12).contains(&day) => println!("{}", verse(day)),
+            _ => {
+                eprintln!("`{day}` isn't a day - it must be a number from 1 to 12.");
+                process::exit(1);
+            }
+        },
+        _ => {
+            eprintln!("Usage: twelve_days [--day N]");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_verse() {
+        assert_eq!(
+            verse(1),
+            "On the first day of Christmas my true love sent to me\n\
+             A partridge in a pear tree."
+        );
+    }
+
+    #[test]
+    fn third_verse() {
+        assert_eq!(
+            verse(3),
+            "On the third day of Christmas my true love sent to me\n\
+             Three French hens,\n\
+             Two turtle doves,\n\
+             And a partridge in a pear tree."
+        );
+    }
+
+    #[test]
+    fn partridge_gets_and_from_the_second_day() {
+        assert!(verse(1).ends_with("\nA partridge in a pear tree."));
+        for day in 2..=12 {
+            assert!(
+                verse(day).ends_with("\nAnd a partridge in a pear tree."),
+                "day {day}"
+            );
+        }
+    }
+
+    #[test]
+    fn song_has_twelve_verses_and_seventy_eight_gifts() {
+        let song = song();
+        let verses: Vec<&str> = song.split("\n\n").collect();
+        assert_eq!(verses.len(), 12);
+
+        let gift_lines = song
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with("On the "))
+            .count();
+        // 1 + 2 + ... + 12
+        assert_eq!(gift_lines, 78);
+    }
+
+    #[test]
+    fn verses_appear_in_order() {
+        for (verse, ordinal) in song().split("\n\n").zip(ORDINALS) {
+            assert!(verse.starts_with(&format!("On the {ordinal} day")));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "only twelve days")]
+    fn thirteenth_day_panics() {
+        verse(13);
+    }
+
+    #[test]
+    fn capitalises_the_first_letter() {
+        assert_eq!(capitalise("two turtle doves"), "Two turtle doves");
+        assert_eq!(capitalise(""), "");
+    }
+}
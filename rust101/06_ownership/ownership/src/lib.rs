@@ -0,0 +1,110 @@
+// Ownership exercises. Replace each `todo!()` with a body that makes the
+// tests at the bottom pass - they're the definition of done.
+//
+// Until then `cargo test` fails: each unfinished exercise panics at its
+// `todo!()`, and the test for it reports "not yet implemented" with the
+// hint given there. To work on one at a time, name its function:
+//
+//     cargo test take_and_return
+//
+// No `clone()` is needed for any of them.
+
+// The parameters aren't used until the bodies are written.
+#![allow(unused_variables)]
+
+/// Takes ownership of `s` and gives it back along with its length in bytes.
+///
+/// This is `calculate_length` from `main.rs` - try writing it without
+/// looking.
+pub fn take_and_return(s: String) -> (String, usize) {
+    todo!("return `s` and its length")
+}
+
+/// Adds `suffix` to the end of `s` and returns the result.
+///
+/// `s` is owned, so it can be changed and handed back - no new String needs
+/// to be allocated. (Hint: a parameter can be declared `mut s: String`.)
+pub fn append_owned(s: String, suffix: &str) -> String {
+    todo!("push `suffix` onto `s` and return it")
+}
+
+/// Returns the larger of `a` and `b`. If they're equal, returns `a`.
+///
+/// i32 is `Copy`, so after calling this the caller still has both values.
+pub fn larger(a: i32, b: i32) -> i32 {
+    todo!("return whichever is larger")
+}
+
+/// Returns both strings, swapped.
+///
+/// Both Strings are moved in and both are moved back out, in the other order.
+pub fn swap_owned(first: String, second: String) -> (String, String) {
+    todo!("return the strings the other way round")
+}
+
+/// Takes a Vec, keeps only the even numbers and returns it.
+///
+/// (Hint: `Vec::retain` keeps the elements a closure returns `true` for.)
+pub fn keep_even(values: Vec<i32>) -> Vec<i32> {
+    todo!("remove the odd numbers from `values` and return it")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn take_and_return_gives_the_string_back_with_its_length() {
+        let (s, length) = take_and_return(String::from("hello"));
+        assert_eq!(s, "hello");
+        assert_eq!(length, 5);
+
+        // The length is in bytes, not characters.
+        let (s, length) = take_and_return(String::from("héllo"));
+        assert_eq!(s, "héllo");
+        assert_eq!(length, 6);
+
+        assert_eq!(take_and_return(String::new()), (String::new(), 0));
+    }
+
+    #[test]
+    fn append_owned_reuses_the_string() {
+        let s = String::with_capacity(32);
+        let pointer = s.as_ptr();
+        let s = append_owned(s, "world");
+        assert_eq!(s, "world");
+        // Same buffer, so nothing was copied into a new String.
+        assert_eq!(s.as_ptr(), pointer);
+
+        assert_eq!(
+            append_owned(String::from("hello, "), "world"),
+            "hello, world"
+        );
+        assert_eq!(append_owned(String::from("same"), ""), "same");
+    }
+
+    #[test]
+    fn larger_leaves_both_values_usable() {
+        let (a, b) = (3, 7);
+        assert_eq!(larger(a, b), 7);
+        // Still usable - they were copied, not moved.
+        assert_eq!(larger(b, a), 7);
+        assert_eq!(larger(-1, -5), -1);
+        assert_eq!(larger(4, 4), 4);
+    }
+
+    #[test]
+    fn swap_owned_returns_the_strings_swapped() {
+        let (first, second) = swap_owned(String::from("a"), String::from("b"));
+        assert_eq!(first, "b");
+        assert_eq!(second, "a");
+    }
+
+    #[test]
+    fn keep_even_filters_in_place() {
+        assert_eq!(keep_even(vec![1, 2, 3, 4, 5, 6]), [2, 4, 6]);
+        assert_eq!(keep_even(vec![1, 3]), Vec::<i32>::new());
+        assert_eq!(keep_even(vec![-2, 0, 7]), [-2, 0]);
+        assert_eq!(keep_even(Vec::new()), Vec::<i32>::new());
+    }
+}
@@ -0,0 +1,93 @@
+/* Things learned during this exercise:
+   * The ownership rules:
+     * Each value in Rust has an owner.
+     * There can only be one owner at a time.
+     * When the owner goes out of scope, the value is dropped (its memory is
+     freed - for a String, that's the heap buffer holding the text).
+   * `let s2 = s1;` MOVES a String: s2 now owns the heap buffer and s1 can't
+   be used any more. Only the pointer, length and capacity are copied, not
+   the text - and because s1 is invalidated, the buffer is only freed once.
+   * Passing a value to a function moves it in just like assigning it, and
+   returning a value moves it out to the caller.
+   * Integers, floats, bools, chars and tuples of them are `Copy`: they live
+   entirely on the stack, so "moving" one is just copying its bits and the
+   original stays usable.
+   * `.clone()` makes a deep copy - the heap data too. It's explicit because
+   it can be expensive.
+   * Handing values back and forth in tuples (see `calculate_length`) is
+   tedious, which is what references (the next exercise) are for.
+   * The exercises are in `src/lib.rs`, and `cargo test` fails until you
+   fill them in - the failing tests are the ones still to do.
+   * See https://doc.rust-lang.org/book/ch04-01-what-is-ownership.html
+*/
+
+fn main() {
+    // Moves
+    let s1 = String::from("hello");
+    let s2 = s1;
+    // Using s1 now doesn't compile:
+    //
+    //     println!("{s1}, world!");
+    //
+    // error[E0382]: borrow of moved value: `s1`
+    //   = note: move occurs because `s1` has type `String`, which does not
+    //     implement the `Copy` trait
+    println!("s2 = {s2} - s1 was moved into it");
+
+    // Clone
+    let s3 = s2.clone();
+    println!("s2 = {s2}, s3 = {s3} - clone copied the heap data, so both are usable");
+
+    // Copy
+    let x = 5;
+    let y = x;
+    println!("x = {x}, y = {y} - i32 is Copy, so x is still valid");
+    println!();
+
+    // Moving into a function
+    let s = String::from("moved");
+    takes_ownership(s);
+    // `s` was moved into the function and dropped when it returned:
+    //
+    //     println!("{s}");
+    //
+    // error[E0382]: borrow of moved value: `s`
+    let x = 5;
+    makes_copy(x);
+    println!("x = {x} is still usable after makes_copy");
+    println!();
+
+    // Moving out of a function
+    let given = gives_ownership();
+    println!("gives_ownership() moved {given:?} out to us");
+    let returned = takes_and_gives_back(given);
+    println!("takes_and_gives_back moved it in and back out: {returned:?}");
+
+    // Returning ownership in a tuple
+    let (text, length) = calculate_length(returned);
+    println!("calculate_length handed back {text:?} along with its length, {length}");
+}
+
+// `some_string` comes into scope here and owns the String...
+fn takes_ownership(some_string: String) {
+    println!("takes_ownership got {some_string:?}");
+} // ...and here it goes out of scope, so `drop` is called and the memory freed.
+
+fn makes_copy(some_integer: i32) {
+    println!("makes_copy got {some_integer}");
+} // An i32 has nothing to free, so nothing special happens.
+
+fn gives_ownership() -> String {
+    String::from("yours")
+}
+
+fn takes_and_gives_back(a_string: String) -> String {
+    a_string
+}
+
+// Without references, the only way to use a String and let the caller keep it
+// is to give it back. A reference (`&String`) would let us borrow it instead.
+fn calculate_length(s: String) -> (String, usize) {
+    let length = s.len();
+    (s, length)
+}
@@ -0,0 +1,58 @@
+// The borrow-checker obstacle course. Fill in each `todo!()` - the signature
+// says what kind of borrow you get, and the tests in `lib.rs` say what the
+// function has to do. Run them with `cargo test -- --ignored`.
+
+// The parameters aren't used until the bodies are written, and until then
+// Clippy thinks `&mut String` could be `&mut str` - it can't, as appending
+// needs the String.
+#![allow(unused_variables, clippy::ptr_arg)]
+
+/// Returns the length of `s` in bytes.
+///
+/// `s` is a shared reference: you can read it, but not change it or keep it.
+/// `&str` accepts a borrowed `String` too - `calculate_length(&s)`.
+pub fn calculate_length(s: &str) -> usize {
+    todo!()
+}
+
+/// Appends `", world"` to `s`.
+///
+/// `s` is a mutable reference, so the caller sees the change.
+pub fn append_world(s: &mut String) {
+    todo!()
+}
+
+/// Counts the vowels (a, e, i, o and u, in either case) in `text`.
+///
+/// This must not take ownership - the caller still needs the text.
+pub fn count_vowels(text: &str) -> usize {
+    todo!()
+}
+
+/// Returns whichever of `a` and `b` is longer, or `a` if they're the same
+/// length.
+///
+/// The result is borrowed from one of the arguments rather than copied. The
+/// `'a` tells the compiler the result lives as long as both arguments do -
+/// lifetimes get a whole chapter later on.
+pub fn longer<'a>(a: &'a str, b: &'a str) -> &'a str {
+    todo!()
+}
+
+/// Adds `amount` to the first element of `values`, then to the last. With a
+/// single element that's the same element twice; with none, nothing happens.
+///
+/// `values.first_mut()` and `values.last_mut()` both borrow `values` mutably,
+/// so the two borrows can't be alive at the same time - finish with one
+/// before starting the other (a `{ }` block for each makes this clear).
+pub fn add_to_ends(values: &mut [i32], amount: i32) {
+    todo!()
+}
+
+/// Returns the sum of `scores`, then sets every score to 0.
+///
+/// Reading through a `&mut` is fine, as long as the reading is done before
+/// the writing starts.
+pub fn total_and_reset(scores: &mut [i32]) -> i32 {
+    todo!()
+}
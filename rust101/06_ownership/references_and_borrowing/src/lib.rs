@@ -0,0 +1,112 @@
+/* Things learned during this exercise:
+   * A reference (`&T`) lets a function use a value without taking ownership
+   of it - "borrowing". The owner keeps the value and it isn't dropped when
+   the function returns.
+   * References are immutable by default. `&mut T` is a mutable reference,
+   and the borrowed variable has to be declared `let mut`.
+   * The borrowing rules, checked at compile time:
+     * at any one time you can have EITHER one mutable reference OR any number
+     of immutable references,
+     * references must always be valid - a reference can't outlive the value
+     it points to.
+   * A borrow lasts until the reference's last use. Two mutable borrows of the
+   same value are fine one after the other, just not overlapping.
+   * This crate is an obstacle course: each function in `src/exercises.rs`
+   has its signature written and a `todo!()` body, and only compiles once
+   the body borrows in the way the signature asks for.
+     * `cargo test -- --ignored` runs the tests against your exercises.
+     * `cargo test --features solutions` runs them against the worked
+     solutions in `src/solutions.rs` - try not to peek!
+   * `#[cfg(feature = "solutions")]` includes an item only when the feature is
+   turned on, and `#[cfg(not(...))]` only when it's off. Features are declared
+   in the `[features]` table of Cargo.toml.
+   * See https://doc.rust-lang.org/book/ch04-02-references-and-borrowing.html
+*/
+
+#[cfg(not(feature = "solutions"))]
+mod exercises;
+#[cfg(feature = "solutions")]
+mod solutions;
+
+// Either way the functions are available as `references_and_borrowing::...`,
+// so the tests don't need to know which set they're testing.
+#[cfg(not(feature = "solutions"))]
+pub use exercises::*;
+#[cfg(feature = "solutions")]
+pub use solutions::*;
+
+// The tests are ignored unless the solutions are being tested, so plain
+// `cargo test` passes before the exercises are done.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(not(feature = "solutions"), ignore = "exercise: calculate_length")]
+    fn calculate_length_borrows() {
+        let s = String::from("hello");
+        assert_eq!(calculate_length(&s), 5);
+        // `s` was only borrowed, so it's still ours.
+        assert_eq!(s, "hello");
+        assert_eq!(calculate_length(""), 0);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solutions"), ignore = "exercise: append_world")]
+    fn append_world_changes_the_callers_string() {
+        let mut s = String::from("hello");
+        append_world(&mut s);
+        assert_eq!(s, "hello, world");
+        append_world(&mut s);
+        assert_eq!(s, "hello, world, world");
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solutions"), ignore = "exercise: count_vowels")]
+    fn count_vowels_leaves_the_text_with_the_caller() {
+        let text = String::from("Borrow Checker");
+        assert_eq!(count_vowels(&text), 4);
+        assert_eq!(count_vowels("rhythm"), 0);
+        assert_eq!(count_vowels("AEIOU aeiou"), 10);
+        assert_eq!(text, "Borrow Checker");
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solutions"), ignore = "exercise: longer")]
+    fn longer_returns_one_of_the_borrows() {
+        let short = String::from("hi");
+        let long = String::from("hello");
+        assert_eq!(longer(&short, &long), "hello");
+        assert_eq!(longer(&long, &short), "hello");
+        // A tie goes to the first.
+        let first = String::from("abc");
+        let second = String::from("xyz");
+        assert!(std::ptr::eq(longer(&first, &second), first.as_str()));
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solutions"), ignore = "exercise: add_to_ends")]
+    fn add_to_ends_borrows_each_end_in_turn() {
+        let mut values = [1, 2, 3, 4];
+        add_to_ends(&mut values, 10);
+        assert_eq!(values, [11, 2, 3, 14]);
+
+        // With one element, the first and last are the same one.
+        let mut single = [5];
+        add_to_ends(&mut single, 1);
+        assert_eq!(single, [7]);
+
+        let mut empty: [i32; 0] = [];
+        add_to_ends(&mut empty, 1);
+        assert_eq!(empty, []);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "solutions"), ignore = "exercise: total_and_reset")]
+    fn total_and_reset_reads_then_writes() {
+        let mut scores = vec![3, 4, 5];
+        assert_eq!(total_and_reset(&mut scores), 12);
+        assert_eq!(scores, [0, 0, 0]);
+        assert_eq!(total_and_reset(&mut []), 0);
+    }
+}
@@ -0,0 +1,51 @@
+// Worked solutions to `exercises.rs`, compiled in place of the exercises by
+// `cargo test --features solutions`.
+
+pub fn calculate_length(s: &str) -> usize {
+    s.len()
+}
+
+pub fn append_world(s: &mut String) {
+    s.push_str(", world");
+}
+
+pub fn count_vowels(text: &str) -> usize {
+    text.chars()
+        .filter(|c| matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u'))
+        .count()
+}
+
+pub fn longer<'a>(a: &'a str, b: &'a str) -> &'a str {
+    if b.len() > a.len() {
+        b
+    } else {
+        a
+    }
+}
+
+pub fn add_to_ends(values: &mut [i32], amount: i32) {
+    // Holding both at once is rejected:
+    //
+    //     let first = values.first_mut();
+    //     let last = values.last_mut();
+    //
+    // error[E0499]: cannot borrow `*values` as mutable more than once at a time
+    {
+        if let Some(first) = values.first_mut() {
+            *first += amount;
+        }
+    }
+    {
+        if let Some(last) = values.last_mut() {
+            *last += amount;
+        }
+    }
+}
+
+pub fn total_and_reset(scores: &mut [i32]) -> i32 {
+    let total = scores.iter().sum();
+    for score in scores.iter_mut() {
+        *score = 0;
+    }
+    total
+}
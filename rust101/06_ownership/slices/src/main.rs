@@ -0,0 +1,193 @@
+/* Things learned during this exercise:
+   * A slice is a reference to a contiguous part of a collection rather than
+   the whole thing. `&str` is a string slice and `&[T]` an array (or Vec)
+   slice. Like any reference, a slice doesn't own its data.
+   * `&s[start..end]` slices by BYTE offsets, and panics if either offset is
+   in the middle of a multi-byte UTF-8 character. Every function below gets
+   its offsets from the standard library's whitespace splitting, which only
+   ever cuts at character boundaries, so none of them can panic - the tests
+   check that with emoji and accented text.
+   * Returning a slice ties the result to its input: while `word` (a slice of
+   `s`) is alive, `s` can't be changed. That's the borrow checker stopping
+   the slice from pointing at text that's been cleared or reallocated.
+   * `windows(k)` yields every run of `k` neighbouring elements of a slice,
+   each as a slice of its own - no copying.
+   * Returning `impl Iterator<Item = &str>` hides the real (long) iterator
+   type, and lets the caller decide whether to collect it or stop early.
+   * See https://doc.rust-lang.org/book/ch04-03-slices.html
+*/
+
+fn main() {
+    let mut s = String::from("  hello   wonderful world  ");
+    println!("Text: {s:?}");
+    println!("first_word = {:?}", first_word(&s));
+    println!("last_word = {:?}", last_word(&s));
+    println!("words = {:?}", words(&s).collect::<Vec<_>>());
+
+    let accented = "Crème brûlée 🦀";
+    println!("first_word({accented:?}) = {:?}", first_word(accented));
+    println!("last_word({accented:?}) = {:?}", last_word(accented));
+    println!();
+
+    // The slice borrows `s`, so `s` can't be changed while it's in use:
+    let word = first_word(&s);
+    //     s.clear();
+    //     println!("{word}");
+    //
+    // error[E0502]: cannot borrow `s` as mutable because it is also borrowed
+    // as immutable
+    println!("The first word is still {word:?}");
+    // `word` isn't used after this point, so the borrow is over and `s` can
+    // be changed again.
+    s.clear();
+    println!("After clearing, the text is {s:?}");
+    println!();
+
+    let readings = [3, -1, 4, 1, -5, 9, 2, 6];
+    for k in [1, 3, 8, 9] {
+        println!(
+            "max_window_sum({readings:?}, {k}) = {:?}",
+            max_window_sum(&readings, k)
+        );
+    }
+    println!(
+        "longest_increasing_run = {:?}",
+        longest_increasing_run(&readings)
+    );
+}
+
+// The first whitespace-separated word, or "" if there isn't one.
+fn first_word(s: &str) -> &str {
+    words(s).next().unwrap_or("")
+}
+
+// `split_whitespace` can be iterated from the back too, so the last word
+// doesn't need every other word to be visited first.
+fn last_word(s: &str) -> &str {
+    s.split_whitespace().next_back().unwrap_or("")
+}
+
+// Every word, skipping any amount of whitespace between them. Each item is a
+// slice of `s`, which is why the iterator borrows it.
+fn words(s: &str) -> impl Iterator<Item = &str> {
+    s.split_whitespace()
+}
+
+// The largest sum of `k` neighbouring values. `None` if there's no window of
+// that size (k is 0 or longer than the data), or if a sum doesn't fit in an
+// i32.
+fn max_window_sum(data: &[i32], k: usize) -> Option<i32> {
+    if k == 0 {
+        return None;
+    }
+    // `windows` yields nothing if k > data.len(), so `max` gives `None`.
+    data.windows(k)
+        .map(|window| {
+            window
+                .iter()
+                .try_fold(0i32, |sum, &value| sum.checked_add(value))
+        })
+        .collect::<Option<Vec<i32>>>()?
+        .into_iter()
+        .max()
+}
+
+// The longest run of strictly increasing values, as a slice of the input.
+// The earliest wins a tie.
+fn longest_increasing_run(data: &[i32]) -> &[i32] {
+    let mut best = 0..data.len().min(1);
+    let mut start = 0;
+    // Each window of two is a neighbouring pair - a run ends wherever the
+    // second isn't bigger than the first.
+    for (i, pair) in data.windows(2).enumerate() {
+        if pair[1] <= pair[0] {
+            start = i + 1;
+        }
+        if i + 2 - start > best.len() {
+            best = start..i + 2;
+        }
+    }
+    &data[best]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_and_last_word() {
+        assert_eq!(first_word("hello world"), "hello");
+        assert_eq!(last_word("hello world"), "world");
+        assert_eq!(first_word("single"), "single");
+        assert_eq!(last_word("single"), "single");
+    }
+
+    #[test]
+    fn empty_and_blank_strings() {
+        assert_eq!(first_word(""), "");
+        assert_eq!(last_word(""), "");
+        assert_eq!(first_word("   "), "");
+        assert_eq!(last_word("\t\n"), "");
+        assert_eq!(words("   ").count(), 0);
+    }
+
+    #[test]
+    fn repeated_and_surrounding_spaces() {
+        let text = "  one   two\t three  ";
+        assert_eq!(first_word(text), "one");
+        assert_eq!(last_word(text), "three");
+        assert_eq!(words(text).collect::<Vec<_>>(), ["one", "two", "three"]);
+    }
+
+    #[test]
+    fn multi_byte_text_never_panics() {
+        let text = "🦀 café naïve 日本語";
+        assert_eq!(first_word(text), "🦀");
+        assert_eq!(last_word(text), "日本語");
+        assert_eq!(
+            words(text).collect::<Vec<_>>(),
+            ["🦀", "café", "naïve", "日本語"]
+        );
+        // Non-breaking spaces are whitespace too.
+        assert_eq!(words("é\u{a0}è").collect::<Vec<_>>(), ["é", "è"]);
+    }
+
+    #[test]
+    fn results_are_slices_of_the_input() {
+        let text = String::from("borrowed words");
+        let word = last_word(&text);
+        assert!(text.as_bytes().as_ptr_range().contains(&word.as_ptr()));
+    }
+
+    #[test]
+    fn max_window_sum_picks_the_best_window() {
+        let data = [3, -1, 4, 1, -5, 9, 2, 6];
+        assert_eq!(max_window_sum(&data, 1), Some(9));
+        assert_eq!(max_window_sum(&data, 2), Some(11));
+        assert_eq!(max_window_sum(&data, 3), Some(17));
+        assert_eq!(max_window_sum(&data, 8), Some(19));
+    }
+
+    #[test]
+    fn max_window_sum_without_a_window() {
+        assert_eq!(max_window_sum(&[1, 2, 3], 0), None);
+        assert_eq!(max_window_sum(&[1, 2, 3], 4), None);
+        assert_eq!(max_window_sum(&[], 1), None);
+    }
+
+    #[test]
+    fn max_window_sum_overflow_is_none() {
+        assert_eq!(max_window_sum(&[i32::MAX, 1], 2), None);
+        assert_eq!(max_window_sum(&[i32::MAX, -1], 2), Some(i32::MAX - 1));
+    }
+
+    #[test]
+    fn longest_increasing_run_finds_the_earliest_longest() {
+        assert_eq!(longest_increasing_run(&[3, -1, 4, 1, -5, 9, 2, 6]), [-1, 4]);
+        assert_eq!(longest_increasing_run(&[1, 2, 3, 1, 2]), [1, 2, 3]);
+        assert_eq!(longest_increasing_run(&[5, 1, 2, 3, 4]), [1, 2, 3, 4]);
+        assert_eq!(longest_increasing_run(&[2, 2, 2]), [2]);
+        assert_eq!(longest_increasing_run(&[7]), [7]);
+        assert_eq!(longest_increasing_run(&[]), [] as [i32; 0]);
+    }
+}
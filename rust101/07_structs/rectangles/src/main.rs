@@ -0,0 +1,78 @@
+/* Things learned during this exercise:
+   * This is the rectangle program from chapter 5 of the book, grown one step
+   at a time: a struct instead of separate width/height variables, then
+   `#[derive(Debug)]` to print it, then `area` as a method in an `impl`
+   block.
+   * Methods take `&self` (read only), `&mut self` (change in place - see
+   `scale`) or `self` (consume it). Calling `rect.area()` borrows `rect`
+   automatically, so there's no need to write `(&rect).area()`.
+   * Functions in an `impl` block without a `self` parameter are associated
+   functions, called with `::` - `Rectangle::square(3)` is a constructor.
+   * `{:?}` uses the derived `Debug`, `{:#?}` pretty-prints it over several
+   lines, and `{}` needs a hand-written `Display` impl, as the compiler can't
+   guess how a rectangle should look to people.
+   * `dbg!` prints the file, line, expression and value to stderr, then
+   returns the value - so it can wrap an expression in the middle of code.
+   * See https://doc.rust-lang.org/book/ch05-00-structs.html
+*/
+mod rectangle;
+
+use rectangle::Rectangle;
+
+fn main() {
+    let scale = 2;
+    let rect1 = Rectangle {
+        width: dbg!(30 * scale),
+        height: 50,
+    };
+    println!("rect1 is {rect1:?}");
+    println!("pretty-printed, rect1 is {rect1:#?}");
+    println!("for people, rect1 is {rect1}");
+    println!("The area of rect1 is {} square pixels.", rect1.area());
+    println!();
+
+    let rect2 = Rectangle {
+        width: 10,
+        height: 40,
+    };
+    let rect3 = Rectangle {
+        width: 70,
+        height: 45,
+    };
+    println!(
+        "Can rect1 ({rect1}) hold rect2 ({rect2})? {}",
+        rect1.can_hold(&rect2)
+    );
+    println!(
+        "Can rect1 ({rect1}) hold rect3 ({rect3})? {}",
+        rect1.can_hold(&rect3)
+    );
+    println!(
+        "Can rect3 ({rect3}) hold rect1 ({rect1})? {}",
+        rect3.can_hold(&rect1)
+    );
+    println!();
+
+    let square = Rectangle::square(25);
+    println!(
+        "Rectangle::square(25) is {square}, with area {}",
+        square.area()
+    );
+
+    // Rectangle derives Copy, so this copies `square` rather than moving it.
+    let mut growing = square;
+    growing.scale(3);
+    println!("Scaled by 3 it becomes {growing}; the original is still {square}");
+    println!();
+
+    for (a, b) in [
+        (rect1, rect3),
+        (rect2, square),
+        (rect1, Rectangle::square(0)),
+    ] {
+        match a.intersect(&b) {
+            Some(overlap) => println!("{a} and {b} overlap in {overlap}"),
+            None => println!("{a} and {b} don't overlap"),
+        }
+    }
+}
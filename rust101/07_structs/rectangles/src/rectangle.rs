@@ -0,0 +1,164 @@
+// The book's `Rectangle`, plus a few methods of our own.
+//
+// The sides are `u32`, so `area` returns a `u64`: the product of two u32s
+// can be up to 64 bits wide, and widening first means it can't overflow.
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rectangle {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rectangle {
+    // An associated function rather than a method - there's no `self`, so
+    // it's called as `Rectangle::square(3)`. Constructors are the usual use.
+    pub fn square(size: u32) -> Self {
+        Self {
+            width: size,
+            height: size,
+        }
+    }
+
+    pub fn area(&self) -> u64 {
+        u64::from(self.width) * u64::from(self.height)
+    }
+
+    // True if `other` fits inside `self` without turning it. Not symmetric:
+    // a big rectangle can hold a small one but not the other way round, and
+    // a rectangle can hold one exactly its own size.
+    pub fn can_hold(&self, other: &Rectangle) -> bool {
+        self.width >= other.width && self.height >= other.height
+    }
+
+    // Takes `&mut self` because it changes the rectangle in place. The sides
+    // saturate at u32::MAX rather than overflowing.
+    pub fn scale(&mut self, factor: u32) {
+        self.width = self.width.saturating_mul(factor);
+        self.height = self.height.saturating_mul(factor);
+    }
+
+    // Both rectangles are treated as having a corner at the origin, so their
+    // overlap is the smaller of each side. `None` if they don't overlap at
+    // all, which happens when either one has a side of zero.
+    pub fn intersect(&self, other: &Rectangle) -> Option<Rectangle> {
+        let overlap = Rectangle {
+            width: self.width.min(other.width),
+            height: self.height.min(other.height),
+        };
+        if overlap.area() == 0 {
+            None
+        } else {
+            Some(overlap)
+        }
+    }
+}
+
+impl fmt::Display for Rectangle {
+    // `{:?}` gives the derived `Rectangle { width: 30, height: 50 }`; this is
+    // the shorter form for people.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{}", self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(width: u32, height: u32) -> Rectangle {
+        Rectangle { width, height }
+    }
+
+    #[test]
+    fn area() {
+        assert_eq!(rect(30, 50).area(), 1500);
+        assert_eq!(rect(0, 50).area(), 0);
+        assert_eq!(Rectangle::square(u32::MAX).area(), 18446744065119617025);
+    }
+
+    #[test]
+    fn square_has_equal_sides() {
+        assert_eq!(Rectangle::square(7), rect(7, 7));
+    }
+
+    #[test]
+    fn larger_can_hold_smaller() {
+        let larger = rect(8, 7);
+        let smaller = rect(5, 1);
+        assert!(larger.can_hold(&smaller));
+        assert!(!smaller.can_hold(&larger));
+    }
+
+    #[test]
+    fn can_hold_needs_both_sides_to_fit() {
+        // Wider but shorter, so neither can hold the other.
+        let wide = rect(10, 2);
+        let tall = rect(3, 9);
+        assert!(!wide.can_hold(&tall));
+        assert!(!tall.can_hold(&wide));
+        // No rotating allowed.
+        assert!(!rect(2, 10).can_hold(&wide));
+    }
+
+    #[test]
+    fn can_hold_an_equal_rectangle() {
+        let r = rect(4, 6);
+        assert!(r.can_hold(&r));
+    }
+
+    #[test]
+    fn zero_sized_rectangles() {
+        let empty = rect(0, 0);
+        assert!(rect(1, 1).can_hold(&empty));
+        assert!(empty.can_hold(&empty));
+        assert!(!empty.can_hold(&rect(1, 0)));
+        assert!(rect(0, 5).can_hold(&rect(0, 3)));
+    }
+
+    #[test]
+    fn scale() {
+        let mut r = rect(3, 4);
+        r.scale(3);
+        assert_eq!(r, rect(9, 12));
+        r.scale(0);
+        assert_eq!(r, rect(0, 0));
+    }
+
+    #[test]
+    fn scale_saturates() {
+        let mut r = rect(u32::MAX / 2, 1);
+        r.scale(3);
+        assert_eq!(r, rect(u32::MAX, 3));
+    }
+
+    #[test]
+    fn intersect_takes_the_smaller_sides() {
+        assert_eq!(rect(10, 2).intersect(&rect(3, 9)), Some(rect(3, 2)));
+        assert_eq!(rect(3, 9).intersect(&rect(10, 2)), Some(rect(3, 2)));
+        assert_eq!(rect(5, 5).intersect(&rect(5, 5)), Some(rect(5, 5)));
+    }
+
+    #[test]
+    fn intersect_is_the_rectangle_both_can_hold() {
+        let (a, b) = (rect(8, 3), rect(4, 6));
+        let overlap = a.intersect(&b).unwrap();
+        assert!(a.can_hold(&overlap) && b.can_hold(&overlap));
+    }
+
+    #[test]
+    fn intersect_with_zero_sized_is_none() {
+        assert_eq!(rect(5, 5).intersect(&rect(0, 5)), None);
+        assert_eq!(rect(5, 0).intersect(&rect(5, 5)), None);
+        assert_eq!(rect(0, 0).intersect(&rect(0, 0)), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(rect(30, 50).to_string(), "30x50");
+        assert_eq!(
+            format!("{:?}", rect(1, 2)),
+            "Rectangle { width: 1, height: 2 }"
+        );
+    }
+}
@@ -0,0 +1,190 @@
+// A builder for `User`: start from the defaults, change what you need with
+// chained setters, and `build` checks the result before handing it over.
+//
+// Each setter takes `self` by value and returns it, which is what lets the
+// calls be chained - `UserBuilder::new().username("a").email("a@b")` - without
+// needing a `let mut builder` first.
+use std::fmt;
+
+use crate::user::{Plan, User};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BuildError {
+    EmptyUsername,
+    MissingEmail,
+    InvalidEmail(String),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::EmptyUsername => write!(f, "A user needs a username."),
+            BuildError::MissingEmail => write!(f, "A user needs an email address."),
+            BuildError::InvalidEmail(email) => {
+                write!(f, "`{email}` isn't an email address - it has no `@`.")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct UserBuilder {
+    username: String,
+    email: Option<String>,
+    sign_in_count: u64,
+    active: bool,
+    plan: Plan,
+}
+
+// The same defaults as `build_user`.
+impl Default for UserBuilder {
+    fn default() -> Self {
+        Self {
+            username: String::new(),
+            email: None,
+            sign_in_count: 1,
+            active: true,
+            plan: Plan::default(),
+        }
+    }
+}
+
+impl UserBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // `impl Into<String>` accepts both `&str` and `String`, so callers don't
+    // have to write `.to_string()` everywhere.
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = username.into();
+        self
+    }
+
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    pub fn sign_in_count(mut self, sign_in_count: u64) -> Self {
+        self.sign_in_count = sign_in_count;
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    pub fn plan(mut self, plan: Plan) -> Self {
+        self.plan = plan;
+        self
+    }
+
+    // Whitespace doesn't count as a username. The email check is only
+    // "contains an @" - real email validation is famously hard, and the way to
+    // know an address works is to send it something.
+    pub fn build(self) -> Result<User, BuildError> {
+        if self.username.trim().is_empty() {
+            return Err(BuildError::EmptyUsername);
+        }
+        let email = self.email.ok_or(BuildError::MissingEmail)?;
+        if !email.contains('@') {
+            return Err(BuildError::InvalidEmail(email));
+        }
+        Ok(User {
+            username: self.username,
+            email,
+            sign_in_count: self.sign_in_count,
+            active: self.active,
+            plan: self.plan,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user::build_user;
+
+    #[test]
+    fn defaults_match_build_user() {
+        let built = UserBuilder::new()
+            .username("bob")
+            .email("bob@example.com")
+            .build();
+        let expected = build_user("bob".to_string(), "bob@example.com".to_string());
+        assert_eq!(built, Ok(expected));
+    }
+
+    #[test]
+    fn setters_override_the_defaults() {
+        let user = UserBuilder::new()
+            .username("carol")
+            .email(String::from("carol@example.com"))
+            .sign_in_count(42)
+            .active(false)
+            .plan(Plan::Team)
+            .build()
+            .unwrap();
+        assert_eq!(user.sign_in_count, 42);
+        assert!(!user.active);
+        assert_eq!(user.plan, Plan::Team);
+    }
+
+    #[test]
+    fn later_setters_win() {
+        let user = UserBuilder::new()
+            .username("first")
+            .username("second")
+            .email("x@y")
+            .build()
+            .unwrap();
+        assert_eq!(user.username, "second");
+    }
+
+    #[test]
+    fn rejects_an_empty_username() {
+        let builder = UserBuilder::new().email("a@b");
+        assert_eq!(builder.clone().build(), Err(BuildError::EmptyUsername));
+        assert_eq!(
+            builder.username("  ").build(),
+            Err(BuildError::EmptyUsername)
+        );
+    }
+
+    #[test]
+    fn rejects_a_missing_email() {
+        assert_eq!(
+            UserBuilder::new().username("dave").build(),
+            Err(BuildError::MissingEmail)
+        );
+    }
+
+    #[test]
+    fn rejects_an_email_without_an_at() {
+        assert_eq!(
+            UserBuilder::new()
+                .username("erin")
+                .email("erin.example.com")
+                .build(),
+            Err(BuildError::InvalidEmail("erin.example.com".to_string()))
+        );
+    }
+
+    #[test]
+    fn username_is_checked_first() {
+        assert_eq!(
+            UserBuilder::new().email("nope").build(),
+            Err(BuildError::EmptyUsername)
+        );
+    }
+
+    #[test]
+    fn error_messages() {
+        assert_eq!(
+            BuildError::InvalidEmail("x".to_string()).to_string(),
+            "`x` isn't an email address - it has no `@`."
+        );
+    }
+}
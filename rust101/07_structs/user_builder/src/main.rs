@@ -0,0 +1,80 @@
+/* Things learned during this exercise:
+   * Field init shorthand: when a variable has the same name as a field,
+   `User { username, email, .. }` is enough - no `username: username`.
+   * Struct update syntax, `User { email, ..other }`, fills in every field not
+   listed from `other`. It MOVES the fields it takes, so if any of them isn't
+   Copy (like a String) then `other` can't be used as a whole afterwards -
+   only its remaining fields can. `..other.clone()` avoids that, at the cost
+   of a copy.
+   * The builder pattern is the alternative when a struct has many fields,
+   most of them with sensible defaults, or when it must be checked before it
+   exists. Setters take and return `self`, so they chain, and `build`
+   returns a `Result` so an invalid User is never created.
+   * `#[derive(Default)]` on an enum needs `#[default]` on one variant. On a
+   struct it uses each field's default, which is why `UserBuilder` writes
+   its `Default` impl out: a sign-in count of 0 and `active: false` aren't
+   the defaults we want.
+   * See https://doc.rust-lang.org/book/ch05-01-defining-structs.html
+*/
+mod builder;
+mod user;
+
+use builder::UserBuilder;
+use user::{build_user, upgrade, Plan, User};
+
+fn main() {
+    println!("With a function and field init shorthand:");
+    let user1 = build_user("alice".to_string(), "alice@example.com".to_string());
+    println!("  {user1}");
+
+    println!("With struct update syntax:");
+    let user2 = User {
+        email: String::from("alice@work.example.com"),
+        ..user1.clone()
+    };
+    println!("  {user2}");
+    let user3 = upgrade(user1, Plan::Pro);
+    println!("  {user3}");
+    // `user1` was moved into `upgrade`, so it's gone:
+    //
+    //     println!("{user1}");
+    //
+    // error[E0382]: borrow of moved value: `user1`
+    println!();
+
+    println!("With the builder:");
+    let built = UserBuilder::new()
+        .username("bob")
+        .email("bob@example.com")
+        .plan(Plan::Team)
+        .sign_in_count(12)
+        .build();
+    match built {
+        Ok(user) => println!("  {user}"),
+        Err(error) => println!("  {error}"),
+    }
+    let dormant = UserBuilder::new()
+        .username("dana")
+        .email("dana@example.com")
+        .active(false)
+        .build();
+    match dormant {
+        Ok(user) => println!("  {user}"),
+        Err(error) => println!("  {error}"),
+    }
+
+    let attempts = [
+        UserBuilder::new().email("nobody@example.com"),
+        UserBuilder::new().username("carol"),
+        UserBuilder::new()
+            .username("carol")
+            .email("carol.example.com"),
+    ];
+    println!("Builds that fail:");
+    for attempt in attempts {
+        match attempt.build() {
+            Ok(user) => println!("  unexpectedly built {user}"),
+            Err(error) => println!("  {error}"),
+        }
+    }
+}
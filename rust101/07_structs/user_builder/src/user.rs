@@ -0,0 +1,118 @@
+// The book's `User` struct, with a `plan` added so there's a field that is
+// neither a String nor a plain number.
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Plan {
+    #[default]
+    Free,
+    Pro,
+    Team,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct User {
+    pub username: String,
+    pub email: String,
+    pub sign_in_count: u64,
+    pub active: bool,
+    pub plan: Plan,
+}
+
+// Field init shorthand: the parameters have the same names as the fields, so
+// `username` can be written instead of `username: username`.
+pub fn build_user(username: String, email: String) -> User {
+    User {
+        username,
+        email,
+        sign_in_count: 1,
+        active: true,
+        plan: Plan::Free,
+    }
+}
+
+// Struct update syntax: every field not listed is taken from `user`. Taking
+// `user` by value makes the move explicit - `username` is a String, so it's
+// moved into the new User rather than copied.
+pub fn upgrade(user: User, plan: Plan) -> User {
+    User { plan, ..user }
+}
+
+impl fmt::Display for User {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let status = if self.active { "active" } else { "inactive" };
+        write!(
+            f,
+            "{} <{}> ({:?}, {status}, {} sign-ins)",
+            self.username, self.email, self.plan, self.sign_in_count
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn alice() -> User {
+        build_user("alice".to_string(), "alice@example.com".to_string())
+    }
+
+    #[test]
+    fn build_user_fills_in_the_rest() {
+        let user = alice();
+        assert_eq!(user.username, "alice");
+        assert_eq!(user.email, "alice@example.com");
+        assert_eq!(user.sign_in_count, 1);
+        assert!(user.active);
+        assert_eq!(user.plan, Plan::Free);
+    }
+
+    #[test]
+    fn upgrade_keeps_everything_else() {
+        let upgraded = upgrade(alice(), Plan::Pro);
+        assert_eq!(upgraded.plan, Plan::Pro);
+        assert_eq!(upgraded.username, "alice");
+        assert_eq!(upgraded.sign_in_count, 1);
+    }
+
+    #[test]
+    fn update_syntax_moves_non_copy_fields() {
+        let user1 = alice();
+        let user2 = User {
+            email: "another@example.com".to_string(),
+            ..user1
+        };
+        // `username` was moved into user2, so user1 can't be used as a whole:
+        //
+        //     assert_eq!(user1, user2);
+        //
+        // error[E0382]: borrow of partially moved value: `user1`
+        //
+        // ...but the fields that weren't moved are still fine: `email` was
+        // given a new value instead, and the Copy fields were copied.
+        assert_eq!(user1.email, "alice@example.com");
+        assert!(user1.active);
+        assert_eq!(user2.username, "alice");
+    }
+
+    #[test]
+    fn cloning_keeps_the_original_whole() {
+        let user1 = alice();
+        // Cloning on purpose, so the String is copied instead of moved.
+        let user2 = User {
+            email: "another@example.com".to_string(),
+            ..user1.clone()
+        };
+        assert_eq!(user1, alice());
+        assert_eq!(user2.username, user1.username);
+        assert_ne!(user2.email, user1.email);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            alice().to_string(),
+            "alice <alice@example.com> (Free, active, 1 sign-ins)"
+        );
+    }
+}
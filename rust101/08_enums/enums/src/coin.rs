@@ -0,0 +1,168 @@
+// The book's coin sorting machine, with a tally of quarters by state added.
+use std::collections::HashMap;
+
+// Hash and Eq are needed to use a state as a HashMap key; Copy because a
+// state is just a tag with no data, so there's no reason to move it. Ord lets
+// a tally be sorted for printing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UsState {
+    Alabama,
+    Alaska,
+    Delaware,
+    Hawaii,
+    NewYork,
+}
+
+impl UsState {
+    // The year each state joined the union - enough to date a quarter.
+    pub fn admitted(self) -> u16 {
+        match self {
+            UsState::Alabama => 1819,
+            UsState::Alaska => 1959,
+            UsState::Delaware => 1787,
+            UsState::Hawaii => 1959,
+            UsState::NewYork => 1788,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Coin {
+    Penny,
+    Nickel,
+    Dime,
+    Quarter(UsState),
+}
+
+// `match` has to cover every variant, so adding a coin to the enum makes this
+// stop compiling until it's given a value - no forgotten cases.
+pub fn value_in_cents(coin: &Coin) -> u32 {
+    match coin {
+        Coin::Penny => 1,
+        Coin::Nickel => 5,
+        Coin::Dime => 10,
+        // The pattern binds the state inside the variant to `state`.
+        Coin::Quarter(state) => {
+            println!("State quarter from {state:?}!");
+            25
+        }
+    }
+}
+
+// Counts how many quarters came from each state. States with no quarters
+// aren't in the map at all, rather than being in it with a count of 0.
+pub fn count_quarters_by_state(coins: &[Coin]) -> HashMap<UsState, u32> {
+    let mut counts = HashMap::new();
+    for coin in coins {
+        if let Coin::Quarter(state) = coin {
+            *counts.entry(*state).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+// `matches!` is an `if let` that only asks whether the pattern fits: it's
+// true or false, and binds nothing. Here a full `match` would need a
+// `_ =>` arm for the three coins that aren't quarters.
+pub fn count_non_quarters(coins: &[Coin]) -> usize {
+    coins
+        .iter()
+        .filter(|coin| !matches!(coin, Coin::Quarter(_)))
+        .count()
+}
+
+// `let ... else` binds a pattern for the rest of the function, or runs the
+// `else` block - which must leave the function - if it doesn't match. It
+// keeps the happy path unindented instead of nesting it inside an `if let`.
+pub fn describe_state_quarter(coin: &Coin) -> Option<String> {
+    let Coin::Quarter(state) = coin else {
+        return None;
+    };
+
+    let age = if state.admitted() < 1800 {
+        "pretty old, for America"
+    } else {
+        "relatively new"
+    };
+    Some(format!("{state:?} is {age}!"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_coin_has_a_value() {
+        assert_eq!(value_in_cents(&Coin::Penny), 1);
+        assert_eq!(value_in_cents(&Coin::Nickel), 5);
+        assert_eq!(value_in_cents(&Coin::Dime), 10);
+        assert_eq!(value_in_cents(&Coin::Quarter(UsState::Alaska)), 25);
+    }
+
+    #[test]
+    fn every_state_quarter_is_worth_the_same() {
+        for state in [
+            UsState::Alabama,
+            UsState::Alaska,
+            UsState::Delaware,
+            UsState::Hawaii,
+            UsState::NewYork,
+        ] {
+            assert_eq!(value_in_cents(&Coin::Quarter(state)), 25);
+        }
+    }
+
+    #[test]
+    fn no_coins() {
+        assert!(count_quarters_by_state(&[]).is_empty());
+        assert_eq!(count_non_quarters(&[]), 0);
+    }
+
+    #[test]
+    fn counts_repeated_states() {
+        let coins = [
+            Coin::Quarter(UsState::Alaska),
+            Coin::Penny,
+            Coin::Quarter(UsState::Delaware),
+            Coin::Quarter(UsState::Alaska),
+            Coin::Dime,
+            Coin::Quarter(UsState::Alaska),
+        ];
+        let counts = count_quarters_by_state(&coins);
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&UsState::Alaska], 3);
+        assert_eq!(counts[&UsState::Delaware], 1);
+        assert_eq!(counts.get(&UsState::Hawaii), None);
+    }
+
+    #[test]
+    fn no_quarters_means_an_empty_tally() {
+        let coins = [Coin::Penny, Coin::Nickel, Coin::Dime];
+        assert!(count_quarters_by_state(&coins).is_empty());
+        assert_eq!(count_non_quarters(&coins), 3);
+    }
+
+    #[test]
+    fn counts_everything_but_quarters() {
+        let coins = [
+            Coin::Quarter(UsState::Hawaii),
+            Coin::Nickel,
+            Coin::Quarter(UsState::NewYork),
+            Coin::Penny,
+        ];
+        assert_eq!(count_non_quarters(&coins), 2);
+    }
+
+    #[test]
+    fn describes_only_quarters() {
+        assert_eq!(
+            describe_state_quarter(&Coin::Quarter(UsState::Delaware)),
+            Some("Delaware is pretty old, for America!".to_string())
+        );
+        assert_eq!(
+            describe_state_quarter(&Coin::Quarter(UsState::Hawaii)),
+            Some("Hawaii is relatively new!".to_string())
+        );
+        assert_eq!(describe_state_quarter(&Coin::Dime), None);
+    }
+}
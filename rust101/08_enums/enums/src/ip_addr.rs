@@ -0,0 +1,51 @@
+// The book's `IpAddr`: the data goes straight into each variant, so there's
+// no separate `kind` field to keep in step with the address. The two kinds
+// hold different types - four bytes for V4, text for V6.
+//
+// The standard library has its own `std::net::IpAddr` built the same way,
+// just with a struct in each variant.
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IpAddr {
+    V4(u8, u8, u8, u8),
+    V6(String),
+}
+
+impl IpAddr {
+    pub fn is_loopback(&self) -> bool {
+        match self {
+            IpAddr::V4(first, ..) => *first == 127,
+            IpAddr::V6(address) => address == "::1",
+        }
+    }
+}
+
+impl fmt::Display for IpAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IpAddr::V4(a, b, c, d) => write!(f, "{a}.{b}.{c}.{d}"),
+            IpAddr::V6(address) => write!(f, "{address}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_both_kinds() {
+        assert_eq!(IpAddr::V4(127, 0, 0, 1).to_string(), "127.0.0.1");
+        assert_eq!(IpAddr::V6("::1".to_string()).to_string(), "::1");
+    }
+
+    #[test]
+    fn loopback() {
+        assert!(IpAddr::V4(127, 0, 0, 1).is_loopback());
+        assert!(IpAddr::V4(127, 1, 2, 3).is_loopback());
+        assert!(!IpAddr::V4(192, 168, 0, 1).is_loopback());
+        assert!(IpAddr::V6("::1".to_string()).is_loopback());
+        assert!(!IpAddr::V6("fe80::1".to_string()).is_loopback());
+    }
+}
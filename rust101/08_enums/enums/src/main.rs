@@ -0,0 +1,78 @@
+/* Things learned during this exercise:
+   * An enum is a type that is exactly one of its variants, and each variant
+   can hold its own data - nothing (`Coin::Penny`), a value
+   (`Coin::Quarter(UsState)`), several values (`IpAddr::V4(127, 0, 0, 1)`) or
+   named fields (`Message::Move { x, y }`).
+   * `match` compares a value against patterns in order and runs the first arm
+   that fits. It must be exhaustive, so every variant has to be handled -
+   `_` catches whatever's left.
+   * Patterns can bind the data inside a variant, as in
+   `Coin::Quarter(state) => ...`.
+   * `if let` is shorthand for a match that only cares about one pattern, and
+   `let ... else` binds a pattern for the rest of the function or bails out
+   (the `else` block has to `return`, `break`, `continue` or panic).
+   * See https://doc.rust-lang.org/book/ch06-00-enums.html
+*/
+mod coin;
+mod ip_addr;
+mod message;
+
+use coin::{
+    count_non_quarters, count_quarters_by_state, describe_state_quarter, value_in_cents, Coin,
+    UsState,
+};
+use ip_addr::IpAddr;
+use message::Message;
+
+fn main() {
+    let purse = [
+        Coin::Penny,
+        Coin::Quarter(UsState::Alaska),
+        Coin::Dime,
+        Coin::Quarter(UsState::Delaware),
+        Coin::Nickel,
+        Coin::Quarter(UsState::Alaska),
+        Coin::Quarter(UsState::Hawaii),
+        Coin::Quarter(UsState::NewYork),
+        Coin::Penny,
+        Coin::Quarter(UsState::Alabama),
+    ];
+    let total: u32 = purse.iter().map(value_in_cents).sum();
+    println!("The purse holds {total} cents.");
+    println!(
+        "{} of the coins aren't quarters.",
+        count_non_quarters(&purse)
+    );
+
+    // A HashMap doesn't keep any order, so sort the tally for printing. The
+    // derived `Ord` orders variants as they're declared - alphabetically.
+    let mut tally: Vec<(UsState, u32)> = count_quarters_by_state(&purse).into_iter().collect();
+    tally.sort();
+    for (state, count) in tally {
+        println!("{count} quarter(s) from {state:?}");
+    }
+    for coin in &purse {
+        if let Some(description) = describe_state_quarter(coin) {
+            println!("{description}");
+        }
+    }
+    println!();
+
+    let messages = [
+        Message::Write("hello".to_string()),
+        Message::Move { x: 10, y: -3 },
+        Message::ChangeColor(0, 160, 255),
+        Message::Quit,
+    ];
+    for message in &messages {
+        println!("{message:?} -> {}", message.call());
+    }
+    println!();
+
+    let home = IpAddr::V4(127, 0, 0, 1);
+    let router = IpAddr::V4(192, 168, 1, 1);
+    let loopback = IpAddr::V6(String::from("::1"));
+    for address in [home, router, loopback] {
+        println!("{address} is a loopback address: {}", address.is_loopback());
+    }
+}
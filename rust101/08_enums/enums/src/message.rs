@@ -0,0 +1,47 @@
+// The book's `Message` enum: each variant holds different data, which would
+// otherwise take four separate structs - and a function couldn't accept "any
+// one of them" without an enum to group them.
+
+#[derive(Debug, PartialEq)]
+pub enum Message {
+    // No data at all.
+    Quit,
+    // Named fields, like a struct.
+    Move { x: i32, y: i32 },
+    // A single String, like a tuple struct.
+    Write(String),
+    // Three values.
+    ChangeColor(i32, i32, i32),
+}
+
+impl Message {
+    // Methods work on enums just like on structs. The book leaves the body
+    // empty; here it says what would happen, so there's something to test.
+    pub fn call(&self) -> String {
+        match self {
+            Message::Quit => "quit".to_string(),
+            Message::Move { x, y } => format!("move to ({x}, {y})"),
+            Message::Write(text) => format!("write {text:?}"),
+            Message::ChangeColor(r, g, b) => format!("change color to rgb({r}, {g}, {b})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_message_can_be_called() {
+        assert_eq!(Message::Quit.call(), "quit");
+        assert_eq!(Message::Move { x: 3, y: -4 }.call(), "move to (3, -4)");
+        assert_eq!(
+            Message::Write("hello".to_string()).call(),
+            "write \"hello\""
+        );
+        assert_eq!(
+            Message::ChangeColor(255, 0, 128).call(),
+            "change color to rgb(255, 0, 128)"
+        );
+    }
+}
@@ -0,0 +1,188 @@
+/* Things learned during this exercise:
+   * `Option<T>` is Rust's replacement for null: an enum that is either
+   `Some(value)` or `None`, so the compiler makes you handle the missing case
+   before you can use the value.
+   * `plus_one` does that with a `match`, like the book. Everything after it
+   uses combinators instead - methods on Option that do the matching for you:
+     * `map` changes the value inside a `Some` and leaves `None` alone.
+     * `Result::ok` turns a Result into an Option, throwing away the error.
+     * `Iterator::find` returns the first matching item, or `None`.
+     * `zip` pairs two Options up - `Some` only if both are.
+     * `unwrap_or(default)` always evaluates `default`, even when it isn't
+     needed; `unwrap_or_else(|| ...)` only calls the closure for `None`. The
+     difference matters when the default is expensive, and is shown with a
+     counter below.
+   * See https://doc.rust-lang.org/std/option/enum.Option.html
+*/
+use std::cell::Cell;
+
+fn main() {
+    let five = Some(5);
+    println!("plus_one({five:?}) = {:?}", plus_one(five));
+    println!("plus_one(None) = {:?}", plus_one(None));
+    println!();
+
+    for text in ["21", " 21 ", "twenty-one", "2000000000"] {
+        println!("parse_and_double({text:?}) = {:?}", parse_and_double(text));
+    }
+    println!();
+
+    for nums in [&[1, 3, 4, 6][..], &[1, 3, 5], &[]] {
+        println!("first_even({nums:?}) = {:?}", first_even(nums));
+    }
+    println!();
+
+    for (a, b) in [(Some(1), Some(2)), (Some(1), None), (None, None)] {
+        println!(
+            "both_or_neither({a:?}, {b:?}) = {:?}",
+            both_or_neither(a, b)
+        );
+    }
+    println!();
+
+    let calls = Cell::new(0);
+    let lazy = lazy_default(Some(7), &calls);
+    println!(
+        "lazy_default(Some(7)) = {lazy}, expensive_default called {} times",
+        calls.get()
+    );
+    let lazy = lazy_default(None, &calls);
+    println!(
+        "lazy_default(None) = {lazy}, expensive_default called {} times",
+        calls.get()
+    );
+
+    let calls = Cell::new(0);
+    let eager = eager_default(Some(7), &calls);
+    println!(
+        "eager_default(Some(7)) = {eager}, expensive_default called {} times",
+        calls.get()
+    );
+}
+
+// The book's version: the match has to handle `None`, or it won't compile.
+// Clippy points out this is `x.map(|i| i + 1)` - which is where the rest of
+// the file picks up.
+#[allow(clippy::manual_map)]
+fn plus_one(x: Option<i32>) -> Option<i32> {
+    match x {
+        None => None,
+        Some(i) => Some(i + 1),
+    }
+}
+
+// Doubling can overflow, so it's done as an i64 and converted back - an
+// out-of-range result is `None` too. `and_then` is `map` for a closure that
+// itself returns an Option.
+fn parse_and_double(s: &str) -> Option<i32> {
+    s.trim()
+        .parse::<i32>()
+        .ok()
+        .map(|n| i64::from(n) * 2)
+        .and_then(|n| i32::try_from(n).ok())
+}
+
+// Returns a reference into the slice, so nothing is copied.
+fn first_even(nums: &[i32]) -> Option<&i32> {
+    nums.iter().find(|n| *n % 2 == 0)
+}
+
+fn both_or_neither(a: Option<i32>, b: Option<i32>) -> Option<(i32, i32)> {
+    a.zip(b)
+}
+
+// Stands in for something slow, like reading a file. `calls` counts how many
+// times it runs; a Cell lets a shared reference change the count.
+fn expensive_default(calls: &Cell<u32>) -> i32 {
+    calls.set(calls.get() + 1);
+    42
+}
+
+fn lazy_default(value: Option<i32>, calls: &Cell<u32>) -> i32 {
+    value.unwrap_or_else(|| expensive_default(calls))
+}
+
+// For comparison: the argument to `unwrap_or` is worked out before the call,
+// whether or not it's needed. Clippy suggests `unwrap_or_else`, which is the
+// point being made.
+#[allow(clippy::or_fun_call)]
+fn eager_default(value: Option<i32>, calls: &Cell<u32>) -> i32 {
+    value.unwrap_or(expensive_default(calls))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plus_one_handles_both_cases() {
+        assert_eq!(plus_one(Some(5)), Some(6));
+        assert_eq!(plus_one(Some(-1)), Some(0));
+        assert_eq!(plus_one(None), None);
+    }
+
+    #[test]
+    fn parse_and_double_valid_numbers() {
+        assert_eq!(parse_and_double("21"), Some(42));
+        assert_eq!(parse_and_double(" -4 "), Some(-8));
+        assert_eq!(parse_and_double("0"), Some(0));
+    }
+
+    #[test]
+    fn parse_and_double_rejects_bad_input() {
+        assert_eq!(parse_and_double(""), None);
+        assert_eq!(parse_and_double("twenty-one"), None);
+        assert_eq!(parse_and_double("1.5"), None);
+    }
+
+    #[test]
+    fn parse_and_double_overflow_is_none() {
+        assert_eq!(parse_and_double("1073741823"), Some(2147483646));
+        assert_eq!(parse_and_double("1073741824"), None);
+        assert_eq!(parse_and_double("-1073741824"), Some(i32::MIN));
+    }
+
+    #[test]
+    fn first_even_finds_the_first() {
+        assert_eq!(first_even(&[1, 3, 4, 6]), Some(&4));
+        assert_eq!(first_even(&[-2, 1]), Some(&-2));
+        assert_eq!(first_even(&[1, 3, 5]), None);
+        assert_eq!(first_even(&[]), None);
+    }
+
+    #[test]
+    fn first_even_borrows_from_the_slice() {
+        let nums = [1, 2, 3];
+        assert!(std::ptr::eq(first_even(&nums).unwrap(), &nums[1]));
+    }
+
+    #[test]
+    fn both_or_neither_needs_both() {
+        assert_eq!(both_or_neither(Some(1), Some(2)), Some((1, 2)));
+        assert_eq!(both_or_neither(Some(1), None), None);
+        assert_eq!(both_or_neither(None, Some(2)), None);
+        assert_eq!(both_or_neither(None, None), None);
+    }
+
+    #[test]
+    fn lazy_default_skips_the_closure_for_some() {
+        let calls = Cell::new(0);
+        assert_eq!(lazy_default(Some(7), &calls), 7);
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[test]
+    fn lazy_default_calls_the_closure_for_none() {
+        let calls = Cell::new(0);
+        assert_eq!(lazy_default(None, &calls), 42);
+        assert_eq!(lazy_default(None, &calls), 42);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn eager_default_always_calls_it() {
+        let calls = Cell::new(0);
+        assert_eq!(eager_default(Some(7), &calls), 7);
+        assert_eq!(calls.get(), 1);
+    }
+}
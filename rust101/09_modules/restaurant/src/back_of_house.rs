@@ -0,0 +1,87 @@
+// The kitchen: the menu types, and cooking what's been ordered.
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Breakfast {
+    pub toast: String,
+    // The chef picks the fruit by season, so customers can't change it.
+    seasonal_fruit: String,
+}
+
+impl Breakfast {
+    // Because `seasonal_fruit` is private, code outside this module can't
+    // write `Breakfast { .. }` at all, so this is the only way to make one.
+    pub fn summer(toast: &str) -> Breakfast {
+        Breakfast {
+            toast: String::from(toast),
+            seasonal_fruit: String::from("peaches"),
+        }
+    }
+
+    // Lets customers see the fruit without being able to change it.
+    pub fn seasonal_fruit(&self) -> &str {
+        &self.seasonal_fruit
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Appetizer {
+    Soup,
+    Salad,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Meal {
+    Breakfast(Breakfast),
+    Appetizer(Appetizer),
+}
+
+// Returns what was made and its price in cents, before the service charge.
+pub(crate) fn cook(meal: &Meal) -> (String, u32) {
+    let dish = match meal {
+        Meal::Breakfast(breakfast) => format!(
+            "{} toast with {}",
+            breakfast.toast, breakfast.seasonal_fruit
+        ),
+        Meal::Appetizer(Appetizer::Soup) => "Soup of the day".to_string(),
+        Meal::Appetizer(Appetizer::Salad) => "House salad".to_string(),
+    };
+    (dish, price(meal))
+}
+
+fn price(meal: &Meal) -> u32 {
+    match meal {
+        Meal::Breakfast(_) => 850,
+        Meal::Appetizer(Appetizer::Soup) => 450,
+        Meal::Appetizer(Appetizer::Salad) => 500,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summer_breakfast_comes_with_peaches() {
+        let breakfast = Breakfast::summer("Rye");
+        assert_eq!(breakfast.toast, "Rye");
+        // The tests are inside the module, so private fields are visible.
+        assert_eq!(breakfast.seasonal_fruit, "peaches");
+    }
+
+    #[test]
+    fn cooks_each_meal() {
+        let breakfast = Meal::Breakfast(Breakfast::summer("Wheat"));
+        assert_eq!(
+            cook(&breakfast),
+            ("Wheat toast with peaches".to_string(), 850)
+        );
+        assert_eq!(
+            cook(&Meal::Appetizer(Appetizer::Soup)),
+            ("Soup of the day".to_string(), 450)
+        );
+        assert_eq!(
+            cook(&Meal::Appetizer(Appetizer::Salad)),
+            ("House salad".to_string(), 500)
+        );
+    }
+}
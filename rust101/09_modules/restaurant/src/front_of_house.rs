@@ -0,0 +1,57 @@
+// The customer-facing side: seating people and taking their orders.
+//
+// `hosting` lives in `src/front_of_house/hosting.rs` - a module's children
+// go in a directory named after it. `serving` is small enough to stay inline.
+pub(crate) mod hosting;
+
+pub(crate) mod serving {
+    use crate::{Meal, Receipt};
+
+    // What the waiter writes down. Only the crate needs to see it, so it
+    // isn't part of the public API.
+    pub(crate) struct Ticket {
+        pub(crate) number: u32,
+        pub(crate) meal: Meal,
+    }
+
+    pub(crate) fn take_order(number: u32, meal: Meal) -> Ticket {
+        Ticket { number, meal }
+    }
+
+    // Receipt's fields are private to the crate root, but `serving` is a
+    // descendant of the root, so it can see them - privacy in Rust is about
+    // WHERE code is, not which type it belongs to.
+    pub(crate) fn serve_order(number: u32, description: String, price_cents: u32) -> Receipt {
+        Receipt {
+            order_number: number,
+            description,
+            price_cents: price_cents + service_charge(price_cents),
+        }
+    }
+
+    // Private: nothing outside `serving` needs to know how it's worked out.
+    // Ten percent, rounded to the nearest cent.
+    fn service_charge(price_cents: u32) -> u32 {
+        (price_cents + 5) / 10
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn service_charge_is_ten_percent() {
+            assert_eq!(service_charge(1000), 100);
+            assert_eq!(service_charge(0), 0);
+            assert_eq!(service_charge(95), 10);
+            assert_eq!(service_charge(94), 9);
+        }
+
+        #[test]
+        fn serving_adds_the_charge() {
+            let receipt = serve_order(3, "Salad".to_string(), 500);
+            assert_eq!(receipt.order_number, 3);
+            assert_eq!(receipt.price_cents, 550);
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// The queue of parties waiting for a table, first come first served.
+use std::collections::VecDeque;
+
+#[derive(Debug, Default)]
+pub(crate) struct Waitlist {
+    parties: VecDeque<String>,
+}
+
+impl Waitlist {
+    pub(crate) fn len(&self) -> usize {
+        self.parties.len()
+    }
+}
+
+// Adding the same name twice is allowed - two parties can share a name.
+pub(crate) fn add_to_waitlist(waitlist: &mut Waitlist, name: &str) -> usize {
+    waitlist.parties.push_back(name.to_string());
+    waitlist.parties.len()
+}
+
+pub(crate) fn seat_at_table(waitlist: &mut Waitlist) -> Option<String> {
+    waitlist.parties.pop_front()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_come_first_served() {
+        let mut waitlist = Waitlist::default();
+        assert_eq!(add_to_waitlist(&mut waitlist, "Ada"), 1);
+        assert_eq!(add_to_waitlist(&mut waitlist, "Grace"), 2);
+        assert_eq!(seat_at_table(&mut waitlist), Some("Ada".to_string()));
+        assert_eq!(waitlist.len(), 1);
+    }
+
+    #[test]
+    fn seating_an_empty_waitlist() {
+        let mut waitlist = Waitlist::default();
+        assert_eq!(seat_at_table(&mut waitlist), None);
+    }
+}
@@ -0,0 +1,100 @@
+/* Things learned during this exercise:
+   * This is the restaurant from chapter 7 of the book, filled in so it runs.
+   It's a library crate (`src/lib.rs`, no `main`), so `cargo test` is the
+   way to try it - the tests in `tests/` use it exactly as another crate
+   would.
+   * `mod name;` tells the compiler a module's code is in `src/name.rs` (or
+   `src/name/mod.rs`). `back_of_house` is in its own file, and so is
+   `front_of_house`, which in turn declares `hosting` from
+   `src/front_of_house/hosting.rs` and keeps `serving` inline in a
+   `mod serving { ... }` block.
+   * Everything is private by default. Items can be private (visible in their
+   module and its children), `pub(crate)` (anywhere in this crate, but not
+   outside it), or `pub` (anyone who can reach the module).
+   * Both modules here are private, so the public API is exactly what this
+   file re-exports with `pub use` - the modules can be rearranged without
+   breaking anyone, because nobody outside can name them.
+   * A `pub` struct's fields are still private unless marked `pub` themselves.
+   `Breakfast` lets customers pick the toast but not the fruit, which also
+   means it can only be built by `Breakfast::summer`. A `pub` enum's
+   variants are all public.
+   * Paths: `crate::` starts from the root of the crate, `super::` from the
+   parent module, and `self::` from the current one.
+   * See https://doc.rust-lang.org/book/ch07-00-managing-growing-projects-with-packages-crates-and-modules.html
+*/
+mod back_of_house;
+mod front_of_house;
+
+use std::fmt;
+
+pub use back_of_house::{Appetizer, Breakfast, Meal};
+
+use front_of_house::{hosting, serving};
+
+pub struct Receipt {
+    order_number: u32,
+    description: String,
+    price_cents: u32,
+}
+
+// The fields are private so a receipt can't be forged or altered - only
+// `Restaurant::order` creates one - but anyone can read them.
+impl Receipt {
+    pub fn order_number(&self) -> u32 {
+        self.order_number
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn price_cents(&self) -> u32 {
+        self.price_cents
+    }
+}
+
+impl fmt::Display for Receipt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "#{} {} - ${}.{:02}",
+            self.order_number,
+            self.description,
+            self.price_cents / 100,
+            self.price_cents % 100
+        )
+    }
+}
+
+#[derive(Default)]
+pub struct Restaurant {
+    waitlist: hosting::Waitlist,
+    orders_taken: u32,
+}
+
+impl Restaurant {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Returns the party's place in the queue, starting at 1.
+    pub fn add_to_waitlist(&mut self, name: &str) -> usize {
+        hosting::add_to_waitlist(&mut self.waitlist, name)
+    }
+
+    // `None` if nobody is waiting.
+    pub fn seat_next(&mut self) -> Option<String> {
+        hosting::seat_at_table(&mut self.waitlist)
+    }
+
+    pub fn waiting(&self) -> usize {
+        self.waitlist.len()
+    }
+
+    pub fn order(&mut self, meal: Meal) -> Receipt {
+        self.orders_taken += 1;
+        let ticket = serving::take_order(self.orders_taken, meal);
+        let (description, price_cents) = back_of_house::cook(&ticket.meal);
+        serving::serve_order(ticket.number, description, price_cents)
+    }
+}
@@ -0,0 +1,73 @@
+// Integration tests are compiled as a separate crate, so they can only use
+// what `restaurant` makes public - the same as any other user of it.
+use restaurant::{Appetizer, Breakfast, Meal, Restaurant};
+
+#[test]
+fn waitlist_is_first_come_first_served() {
+    let mut restaurant = Restaurant::new();
+    assert_eq!(restaurant.add_to_waitlist("Ada"), 1);
+    assert_eq!(restaurant.add_to_waitlist("Grace"), 2);
+    assert_eq!(restaurant.waiting(), 2);
+    assert_eq!(restaurant.seat_next().as_deref(), Some("Ada"));
+    assert_eq!(restaurant.seat_next().as_deref(), Some("Grace"));
+    assert_eq!(restaurant.seat_next(), None);
+}
+
+#[test]
+fn orders_are_numbered_in_sequence() {
+    let mut restaurant = Restaurant::new();
+    let first = restaurant.order(Meal::Appetizer(Appetizer::Soup));
+    let second = restaurant.order(Meal::Appetizer(Appetizer::Salad));
+    assert_eq!(first.order_number(), 1);
+    assert_eq!(second.order_number(), 2);
+}
+
+#[test]
+fn receipt_includes_the_service_charge() {
+    let mut restaurant = Restaurant::new();
+    let receipt = restaurant.order(Meal::Breakfast(Breakfast::summer("Rye")));
+    assert_eq!(receipt.description(), "Rye toast with peaches");
+    assert_eq!(receipt.price_cents(), 935);
+    assert_eq!(receipt.to_string(), "#1 Rye toast with peaches - $9.35");
+}
+
+#[test]
+fn customers_can_change_the_toast_but_not_the_fruit() {
+    let mut breakfast = Breakfast::summer("Rye");
+    breakfast.toast = String::from("Wheat");
+    // The fruit can be read, but not assigned:
+    //
+    //     breakfast.seasonal_fruit = String::from("blueberries");
+    //
+    // error[E0616]: field `seasonal_fruit` of struct `Breakfast` is private
+    assert_eq!(breakfast.seasonal_fruit(), "peaches");
+
+    let receipt = Restaurant::new().order(Meal::Breakfast(breakfast));
+    assert_eq!(receipt.description(), "Wheat toast with peaches");
+}
+
+// None of these compile from outside the crate, which is what makes the
+// design work:
+//
+//     restaurant::back_of_house::cook(&meal);
+//     // error[E0603]: module `back_of_house` is private
+//
+//     let breakfast = Breakfast { toast: .., seasonal_fruit: .. };
+//     // error[E0451]: field `seasonal_fruit` of struct `Breakfast` is private
+//
+//     let receipt = restaurant::Receipt { order_number: 1, .. };
+//     // error[E0451]: field `order_number` of struct `Receipt` is private
+#[test]
+fn enum_variants_are_public() {
+    // Unlike struct fields, every variant of a pub enum is usable.
+    let meals = [
+        Meal::Appetizer(Appetizer::Soup),
+        Meal::Appetizer(Appetizer::Salad),
+    ];
+    let mut restaurant = Restaurant::new();
+    let total: u32 = meals
+        .into_iter()
+        .map(|meal| restaurant.order(meal).price_cents())
+        .sum();
+    assert_eq!(total, 495 + 550);
+}
@@ -0,0 +1,224 @@
+// Parsing a line of input into a `Command`.
+//
+// The keywords (`add`, `to`, `list`, ...) are matched case-insensitively, so
+// `ADD Sally TO Engineering` works too. Names and departments are kept as
+// typed, and may be more than one word: `add Sally Smith to Sales Team`.
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Add { name: String, department: String },
+    Remove { name: String, department: String },
+    List(String),
+    ListAll,
+    Quit,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    UnknownCommand(String),
+    // `add` without a `to`, or `remove` without a `from`.
+    MissingKeyword { usage: &'static str },
+    MissingName { usage: &'static str },
+    MissingDepartment { usage: &'static str },
+    UnexpectedArguments(String),
+}
+
+const ADD_USAGE: &str = "add NAME to DEPARTMENT";
+const REMOVE_USAGE: &str = "remove NAME from DEPARTMENT";
+const LIST_USAGE: &str = "list DEPARTMENT or list all";
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "Type a command, or `help` to see them all."),
+            ParseError::UnknownCommand(word) => {
+                write!(f, "`{word}` isn't a command - type `help` to see them all.")
+            }
+            ParseError::MissingKeyword { usage } => write!(f, "Usage: {usage}"),
+            ParseError::MissingName { usage } => write!(f, "Who? Usage: {usage}"),
+            ParseError::MissingDepartment { usage } => {
+                write!(f, "Which department? Usage: {usage}")
+            }
+            ParseError::UnexpectedArguments(command) => {
+                write!(f, "`{command}` doesn't take anything after it.")
+            }
+        }
+    }
+}
+
+pub const HELP: &str = "Commands:
+  add NAME to DEPARTMENT
+  remove NAME from DEPARTMENT
+  list DEPARTMENT
+  list all
+  quit";
+
+pub fn parse_command(line: &str) -> Result<Command, ParseError> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let Some((first, rest)) = words.split_first() else {
+        return Err(ParseError::Empty);
+    };
+
+    match first.to_lowercase().as_str() {
+        "add" => {
+            let (name, department) = split_on_keyword(rest, "to", ADD_USAGE)?;
+            Ok(Command::Add { name, department })
+        }
+        "remove" => {
+            let (name, department) = split_on_keyword(rest, "from", REMOVE_USAGE)?;
+            Ok(Command::Remove { name, department })
+        }
+        "list" => match rest {
+            [] => Err(ParseError::MissingDepartment { usage: LIST_USAGE }),
+            [all] if all.eq_ignore_ascii_case("all") => Ok(Command::ListAll),
+            department => Ok(Command::List(department.join(" "))),
+        },
+        "quit" | "exit" => {
+            if rest.is_empty() {
+                Ok(Command::Quit)
+            } else {
+                Err(ParseError::UnexpectedArguments(first.to_string()))
+            }
+        }
+        _ => Err(ParseError::UnknownCommand(first.to_string())),
+    }
+}
+
+// Splits `NAME <keyword> DEPARTMENT` at the LAST keyword, which lets the
+// name contain the word: `add Otto to Toronto to Sales` adds "Otto to
+// Toronto" to Sales. The department can't contain it.
+fn split_on_keyword(
+    words: &[&str],
+    keyword: &str,
+    usage: &'static str,
+) -> Result<(String, String), ParseError> {
+    let at = words
+        .iter()
+        .rposition(|word| word.eq_ignore_ascii_case(keyword))
+        .ok_or(ParseError::MissingKeyword { usage })?;
+    let (name, department) = (&words[..at], &words[at + 1..]);
+    if name.is_empty() {
+        return Err(ParseError::MissingName { usage });
+    }
+    if department.is_empty() {
+        return Err(ParseError::MissingDepartment { usage });
+    }
+    Ok((name.join(" "), department.join(" ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add(name: &str, department: &str) -> Command {
+        Command::Add {
+            name: name.to_string(),
+            department: department.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_add() {
+        assert_eq!(
+            parse_command("add Sally to Engineering"),
+            Ok(add("Sally", "Engineering"))
+        );
+    }
+
+    #[test]
+    fn keywords_ignore_case() {
+        assert_eq!(parse_command("ADD Amir TO Sales"), Ok(add("Amir", "Sales")));
+        assert_eq!(parse_command("List ALL"), Ok(Command::ListAll));
+        assert_eq!(parse_command("QUIT"), Ok(Command::Quit));
+    }
+
+    #[test]
+    fn names_and_departments_can_be_several_words() {
+        assert_eq!(
+            parse_command("  add  Sally   Smith to  Sales Team "),
+            Ok(add("Sally Smith", "Sales Team"))
+        );
+        assert_eq!(
+            parse_command("add Otto to Toronto to Sales"),
+            Ok(add("Otto to Toronto", "Sales"))
+        );
+    }
+
+    #[test]
+    fn parses_remove() {
+        assert_eq!(
+            parse_command("remove Sally from Engineering"),
+            Ok(Command::Remove {
+                name: "Sally".to_string(),
+                department: "Engineering".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_list() {
+        assert_eq!(
+            parse_command("list Engineering"),
+            Ok(Command::List("Engineering".to_string()))
+        );
+        assert_eq!(
+            parse_command("list Sales Team"),
+            Ok(Command::List("Sales Team".to_string()))
+        );
+        assert_eq!(parse_command("list all"), Ok(Command::ListAll));
+    }
+
+    #[test]
+    fn empty_and_unknown() {
+        assert_eq!(parse_command(""), Err(ParseError::Empty));
+        assert_eq!(parse_command("   "), Err(ParseError::Empty));
+        assert_eq!(
+            parse_command("hire Sally"),
+            Err(ParseError::UnknownCommand("hire".to_string()))
+        );
+    }
+
+    #[test]
+    fn malformed_add_and_remove() {
+        assert_eq!(
+            parse_command("add Sally"),
+            Err(ParseError::MissingKeyword { usage: ADD_USAGE })
+        );
+        assert_eq!(
+            parse_command("add to Sales"),
+            Err(ParseError::MissingName { usage: ADD_USAGE })
+        );
+        assert_eq!(
+            parse_command("add Sally to"),
+            Err(ParseError::MissingDepartment { usage: ADD_USAGE })
+        );
+        assert_eq!(
+            parse_command("remove Sally to Sales"),
+            Err(ParseError::MissingKeyword {
+                usage: REMOVE_USAGE
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_list_and_quit() {
+        assert_eq!(
+            parse_command("list"),
+            Err(ParseError::MissingDepartment { usage: LIST_USAGE })
+        );
+        assert_eq!(
+            parse_command("quit now"),
+            Err(ParseError::UnexpectedArguments("quit".to_string()))
+        );
+    }
+
+    #[test]
+    fn errors_explain_the_usage() {
+        assert_eq!(
+            parse_command("add Sally").unwrap_err().to_string(),
+            "Usage: add NAME to DEPARTMENT"
+        );
+    }
+}
@@ -0,0 +1,166 @@
+// Who works where. Each department maps to a BTreeSet of names, which keeps
+// them sorted (so listings need no extra sorting) and ignores duplicates.
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+#[derive(Debug, Default)]
+pub struct Directory {
+    departments: HashMap<String, BTreeSet<String>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RemoveError {
+    UnknownDepartment(String),
+    NotInDepartment { name: String, department: String },
+}
+
+impl fmt::Display for RemoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RemoveError::UnknownDepartment(department) => {
+                write!(f, "There's nobody in {department} to remove.")
+            }
+            RemoveError::NotInDepartment { name, department } => {
+                write!(f, "{name} doesn't work in {department}.")
+            }
+        }
+    }
+}
+
+impl Directory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // False if they were already in that department.
+    pub fn add(&mut self, name: &str, department: &str) -> bool {
+        self.departments
+            .entry(department.to_string())
+            .or_default()
+            .insert(name.to_string())
+    }
+
+    // A department with nobody left in it is removed entirely, so it doesn't
+    // show up as an empty line in `all`.
+    pub fn remove(&mut self, name: &str, department: &str) -> Result<(), RemoveError> {
+        let Some(people) = self.departments.get_mut(department) else {
+            return Err(RemoveError::UnknownDepartment(department.to_string()));
+        };
+        if !people.remove(name) {
+            return Err(RemoveError::NotInDepartment {
+                name: name.to_string(),
+                department: department.to_string(),
+            });
+        }
+        if people.is_empty() {
+            self.departments.remove(department);
+        }
+        Ok(())
+    }
+
+    // Sorted by name. Empty for a department nobody works in.
+    pub fn department(&self, department: &str) -> Vec<&str> {
+        self.departments
+            .get(department)
+            .map(|people| people.iter().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    // Every department and its people, sorted by department. The HashMap has
+    // no order of its own, so the departments are sorted here.
+    pub fn all(&self) -> Vec<(&str, Vec<&str>)> {
+        let mut all: Vec<(&str, Vec<&str>)> = self
+            .departments
+            .iter()
+            .map(|(department, people)| {
+                (
+                    department.as_str(),
+                    people.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        all.sort_unstable_by_key(|&(department, _)| department);
+        all
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Directory {
+        let mut directory = Directory::new();
+        directory.add("Sally", "Engineering");
+        directory.add("Amir", "Sales");
+        directory.add("Bob", "Engineering");
+        directory.add("Carla", "Engineering");
+        directory
+    }
+
+    #[test]
+    fn departments_are_sorted_by_name() {
+        assert_eq!(
+            sample().department("Engineering"),
+            ["Bob", "Carla", "Sally"]
+        );
+    }
+
+    #[test]
+    fn adding_twice_is_a_no_op() {
+        let mut directory = sample();
+        assert!(!directory.add("Sally", "Engineering"));
+        assert_eq!(directory.department("Engineering").len(), 3);
+        // The same person can be in two departments, though.
+        assert!(directory.add("Sally", "Sales"));
+    }
+
+    #[test]
+    fn unknown_department_is_empty() {
+        assert!(sample().department("Marketing").is_empty());
+        assert!(Directory::new().all().is_empty());
+    }
+
+    #[test]
+    fn all_is_sorted_by_department() {
+        assert_eq!(
+            sample().all(),
+            [
+                ("Engineering", vec!["Bob", "Carla", "Sally"]),
+                ("Sales", vec!["Amir"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn removes_people() {
+        let mut directory = sample();
+        assert_eq!(directory.remove("Bob", "Engineering"), Ok(()));
+        assert_eq!(directory.department("Engineering"), ["Carla", "Sally"]);
+    }
+
+    #[test]
+    fn removing_the_last_person_removes_the_department() {
+        let mut directory = sample();
+        assert_eq!(directory.remove("Amir", "Sales"), Ok(()));
+        assert_eq!(directory.all().len(), 1);
+        assert_eq!(
+            directory.remove("Amir", "Sales"),
+            Err(RemoveError::UnknownDepartment("Sales".to_string()))
+        );
+    }
+
+    #[test]
+    fn removing_someone_who_is_not_there() {
+        let mut directory = sample();
+        let error = directory.remove("Amir", "Engineering").unwrap_err();
+        assert_eq!(
+            error,
+            RemoveError::NotInDepartment {
+                name: "Amir".to_string(),
+                department: "Engineering".to_string(),
+            }
+        );
+        assert_eq!(error.to_string(), "Amir doesn't work in Engineering.");
+        assert_eq!(directory.department("Engineering").len(), 3);
+    }
+}
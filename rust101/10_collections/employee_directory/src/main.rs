@@ -0,0 +1,142 @@
+/* Things learned during this exercise:
+   * This is the last exercise suggested at the end of chapter 8 of the book:
+   "Using a hash map and vectors, create a text interface to allow a user to
+   add employee names to a department in a company". Type `help` to see the
+   commands.
+   * `HashMap::entry(key).or_default()` returns a `&mut` to the value for
+   `key`, inserting an empty one first if needed - one lookup instead of a
+   `get` followed by an `insert`.
+   * A HashMap iterates in an unpredictable order, while a BTreeSet (or
+   BTreeMap) keeps its contents sorted. Here the departments are in a
+   HashMap and sorted only when listing them all, and each department's
+   people are in a BTreeSet, so they're always in order.
+   * Slice patterns like `[all]` and `[]` match on the length and contents of
+   a slice, which suits parsing a line that's been split into words.
+   * Keeping the parsing (`command.rs`) and the data (`directory.rs`) apart from
+   the input loop means both can be tested without typing anything.
+   * See https://doc.rust-lang.org/book/ch08-03-hash-maps.html
+*/
+mod command;
+mod directory;
+
+use std::io::{self, Write};
+
+use command::{parse_command, Command, HELP};
+use directory::Directory;
+
+fn main() {
+    let mut directory = Directory::new();
+    println!("Employee directory. Type `help` for the commands.");
+
+    loop {
+        print!("> ");
+        io::stdout().flush().expect("Failed to flush stdout.");
+
+        let mut line = String::new();
+        let bytes = io::stdin()
+            .read_line(&mut line)
+            .expect("Failed to read line.");
+        if bytes == 0 {
+            break;
+        }
+        if line.trim().eq_ignore_ascii_case("help") {
+            println!("{HELP}");
+            continue;
+        }
+
+        match parse_command(&line) {
+            Ok(Command::Quit) => break,
+            Ok(command) => println!("{}", respond(&mut directory, command)),
+            Err(error) => println!("{error}"),
+        }
+    }
+}
+
+// Carries out a command and says what happened. The loop stops before
+// `Quit` gets here, but the match still has to cover it.
+fn respond(directory: &mut Directory, command: Command) -> String {
+    match command {
+        Command::Add { name, department } => {
+            if directory.add(&name, &department) {
+                format!("Added {name} to {department}.")
+            } else {
+                format!("{name} is already in {department}.")
+            }
+        }
+        Command::Remove { name, department } => match directory.remove(&name, &department) {
+            Ok(()) => format!("Removed {name} from {department}."),
+            Err(error) => error.to_string(),
+        },
+        Command::List(department) => {
+            let people = directory.department(&department);
+            if people.is_empty() {
+                format!("Nobody works in {department} yet.")
+            } else {
+                format!("{department}: {}", people.join(", "))
+            }
+        }
+        Command::ListAll => {
+            let all = directory.all();
+            if all.is_empty() {
+                return "The directory is empty.".to_string();
+            }
+            let lines: Vec<String> = all
+                .iter()
+                .map(|(department, people)| format!("{department}: {}", people.join(", ")))
+                .collect();
+            lines.join("\n")
+        }
+        Command::Quit => "Bye!".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(directory: &mut Directory, line: &str) -> String {
+        respond(directory, parse_command(line).unwrap())
+    }
+
+    #[test]
+    fn a_session() {
+        let mut directory = Directory::new();
+        assert_eq!(run(&mut directory, "list all"), "The directory is empty.");
+        assert_eq!(
+            run(&mut directory, "add Sally to Engineering"),
+            "Added Sally to Engineering."
+        );
+        assert_eq!(
+            run(&mut directory, "add Sally to Engineering"),
+            "Sally is already in Engineering."
+        );
+        run(&mut directory, "add Amir to Sales");
+        run(&mut directory, "add Bob to Engineering");
+        assert_eq!(
+            run(&mut directory, "list Engineering"),
+            "Engineering: Bob, Sally"
+        );
+        assert_eq!(
+            run(&mut directory, "list all"),
+            "Engineering: Bob, Sally\nSales: Amir"
+        );
+    }
+
+    #[test]
+    fn friendly_messages_for_missing_things() {
+        let mut directory = Directory::new();
+        assert_eq!(
+            run(&mut directory, "list Marketing"),
+            "Nobody works in Marketing yet."
+        );
+        assert_eq!(
+            run(&mut directory, "remove Sally from Marketing"),
+            "There's nobody in Marketing to remove."
+        );
+        run(&mut directory, "add Amir to Sales");
+        assert_eq!(
+            run(&mut directory, "remove Sally from Sales"),
+            "Sally doesn't work in Sales."
+        );
+    }
+}
@@ -0,0 +1,41 @@
+/* Things learned during this exercise:
+   * This is the second exercise suggested at the end of chapter 8 of the
+   book: "Convert strings to pig latin". Pass a sentence as arguments
+   (`cargo run -- Hello, world!`) or pipe text in.
+   * A String is UTF-8 bytes, and a char can take 1 to 4 of them, so
+   `word[0..1]` isn't "the first letter" - for "élan" it panics, because byte
+   1 is in the middle of 'é'. `chars()` walks the text a char at a time, and
+   `Chars::as_str` gives back whatever hasn't been walked yet.
+   * Byte offsets are fine when they come from the string itself: `find`,
+   `rfind` and `char_indices` only ever return char boundaries.
+   * Changing case isn't one char to one char - `'ß'.to_uppercase()` is "SS" -
+   so `to_uppercase` and `to_lowercase` on a char return iterators.
+   * `split_inclusive` keeps the separator on the end of each piece, which is
+   how the sentence keeps its original spacing.
+   * See https://doc.rust-lang.org/book/ch08-02-strings.html
+*/
+mod pig_latin;
+
+use std::env;
+use std::io::{self, BufRead};
+
+use pig_latin::pig_latin_sentence;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if !args.is_empty() {
+        println!("{}", pig_latin_sentence(&args.join(" ")));
+        return;
+    }
+
+    // Line by line, so piped text comes out as it goes in.
+    for line in io::stdin().lock().lines() {
+        match line {
+            Ok(line) => println!("{}", pig_latin_sentence(&line)),
+            Err(error) => {
+                eprintln!("Couldn't read stdin: {error}");
+                break;
+            }
+        }
+    }
+}
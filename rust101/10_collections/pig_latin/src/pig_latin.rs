@@ -0,0 +1,160 @@
+// Pig latin, following the book's rules: a word starting with a consonant has
+// that consonant moved to the end with "ay" ("first" -> "irst-fay"), and a
+// word starting with a vowel gets "hay" on the end ("apple" -> "apple-hay").
+//
+// Everything works on chars rather than bytes, so "élan" and "über" keep
+// their letters whole, and byte offsets only ever come from `find`/`rfind`,
+// which return char boundaries.
+
+// Lowercase vowels, including the accented ones common in European languages.
+const VOWELS: &str = "aeiouàáâäãåāæèéêëēìíîïīòóôöõøōœùúûüū";
+
+fn is_vowel(c: char) -> bool {
+    c.to_lowercase().all(|lower| VOWELS.contains(lower))
+}
+
+// Uppercases the first char and lowercases the rest. `to_uppercase` returns
+// an iterator because some chars become more than one: 'ß' is "SS".
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+// Splits off any leading and trailing non-letters, so "(Hello," becomes
+// ("(", "Hello", ","). Apostrophes and hyphens between letters stay in the
+// middle part. Returns `None` if there are no letters at all.
+fn split_punctuation(token: &str) -> Option<(&str, &str, &str)> {
+    let start = token.find(char::is_alphabetic)?;
+    let (last, letter) = token.char_indices().rfind(|(_, c)| c.is_alphabetic())?;
+    let end = last + letter.len_utf8();
+    Some((&token[..start], &token[start..end], &token[end..]))
+}
+
+// Converts a bare word, with no punctuation around it.
+fn convert(word: &str) -> String {
+    let mut chars = word.chars();
+    let Some(first) = chars.next() else {
+        return String::new();
+    };
+    let rest = chars.as_str();
+
+    let converted = if is_vowel(first) {
+        format!("{word}-hay")
+    } else if rest.is_empty() {
+        // A lone consonant like "x" has nothing to move it behind.
+        format!("{word}-ay")
+    } else {
+        format!("{rest}-{first}ay")
+    };
+
+    let has_lowercase = word.chars().any(char::is_lowercase);
+    if first.is_uppercase() && !has_lowercase && word.chars().count() > 1 {
+        // SHOUTING stays shouting.
+        converted.to_uppercase()
+    } else if first.is_uppercase() {
+        capitalize(&converted)
+    } else {
+        converted
+    }
+}
+
+// Converts one whitespace-free token, keeping its punctuation in place.
+// Tokens without any letters ("--", "42") are returned unchanged.
+pub fn pig_latin_word(word: &str) -> String {
+    match split_punctuation(word) {
+        Some((before, letters, after)) => format!("{before}{}{after}", convert(letters)),
+        None => word.to_string(),
+    }
+}
+
+// Converts every word, keeping the whitespace between them exactly as it was.
+pub fn pig_latin_sentence(s: &str) -> String {
+    // Each piece is a word followed by at most one whitespace char, so runs
+    // of spaces come through as pieces with an empty word.
+    s.split_inclusive(char::is_whitespace)
+        .map(|piece| {
+            let word = piece.trim_end_matches(char::is_whitespace);
+            format!("{}{}", pig_latin_word(word), &piece[word.len()..])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words() {
+        let cases = [
+            // Consonants and vowels, from the book.
+            ("first", "irst-fay"),
+            ("apple", "apple-hay"),
+            ("rust", "ust-ray"),
+            ("orange", "orange-hay"),
+            // Single letters.
+            ("a", "a-hay"),
+            ("I", "I-hay"),
+            ("x", "x-ay"),
+            ("y", "y-ay"),
+            // Capitalization is kept on the first letter.
+            ("Hello", "Ello-hay"),
+            ("Apple", "Apple-hay"),
+            ("HELLO", "ELLO-HAY"),
+            ("McDonald", "Cdonald-may"),
+            // Punctuation stays where it was.
+            ("Hello,", "Ello-hay,"),
+            ("(first)", "(irst-fay)"),
+            ("\"quoted!\"", "\"uoted-qay!\""),
+            ("don't", "on't-day"),
+            ("well-known", "ell-known-way"),
+            // Nothing to convert.
+            ("--", "--"),
+            ("...", "..."),
+            ("42", "42"),
+            ("", ""),
+            // Unicode letters are whole chars.
+            ("élan", "élan-hay"),
+            ("Über", "Über-hay"),
+            ("ñandú", "andú-ñay"),
+            ("Ñandú", "Andú-ñay"),
+            ("straße", "traße-say"),
+            ("Ωmega", "Mega-ωay"),
+            ("日本", "本-日ay"),
+            ("🦀crab", "🦀rab-cay"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(pig_latin_word(input), expected, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn sentences() {
+        let cases = [
+            ("Hello, world!", "Ello-hay, orld-way!"),
+            ("the quick brown fox", "he-tay uick-qay rown-bay ox-fay"),
+            ("I ate an apple.", "I-hay ate-hay an-hay apple-hay."),
+            ("", ""),
+            ("   ", "   "),
+            ("  two  spaces\n", "  wo-tay  paces-say\n"),
+            ("tabs\tand\nnewlines", "abs-tay\tand-hay\newlines-nay"),
+            ("crème brûlée", "rème-cay rûlée-bay"),
+            ("... -- !!!", "... -- !!!"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(pig_latin_sentence(input), expected, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn capitalize_handles_multi_char_uppercase() {
+        assert_eq!(capitalize("ßo"), "SSo");
+        assert_eq!(capitalize("ÉLAN"), "Élan");
+        assert_eq!(capitalize(""), "");
+    }
+}
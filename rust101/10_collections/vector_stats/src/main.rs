@@ -0,0 +1,118 @@
+/* Things learned during this exercise:
+   * This is the first exercise suggested at the end of chapter 8 of the book:
+   "Given a list of integers, use a vector and return the median and mode".
+   Run it with numbers as arguments (`cargo run -- 3 1 4 1 5`), or pipe them
+   in (`echo 3,1,4,1,5 | cargo run`). Commas, spaces and newlines all
+   separate numbers.
+   * A `Vec<T>` owns a growable list of values stored next to each other.
+   `&[T]` borrows all or part of one, and is what most functions should take
+   - unless, like `median`, they need to change the list.
+   * Indexing with `v[i]` panics when `i` is out of range, and `first`/`last`
+   return an Option instead. Checking `is_empty` up front lets the rest of
+   the code index freely.
+   * A HashMap counts occurrences in one pass: `entry(key).or_insert(0)`
+   returns a `&mut` to the count, inserting 0 first if the key is new.
+   * See https://doc.rust-lang.org/book/ch08-01-vectors.html
+*/
+mod stats;
+
+use std::env;
+use std::io::{self, Read};
+use std::process;
+
+use stats::{summarize, Stats};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let text = if args.is_empty() {
+        let mut text = String::new();
+        if let Err(error) = io::stdin().read_to_string(&mut text) {
+            eprintln!("Couldn't read stdin: {error}");
+            process::exit(1);
+        }
+        text
+    } else {
+        args.join(" ")
+    };
+
+    let values = match parse_numbers(&text) {
+        Ok(values) => values,
+        Err(message) => {
+            eprintln!("{message}");
+            process::exit(1);
+        }
+    };
+    match summarize(values) {
+        Some(stats) => print!("{}", report(&stats)),
+        None => println!("No data - give some integers as arguments or on stdin."),
+    }
+}
+
+// Splits on commas and whitespace, so `1,2, 3` and `1 2\n3` both work. Empty
+// pieces (from `1,,2` or trailing commas) are skipped.
+fn parse_numbers(text: &str) -> Result<Vec<i64>, String> {
+    text.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|piece| !piece.is_empty())
+        .map(|piece| {
+            piece
+                .parse::<i64>()
+                .map_err(|_| format!("`{piece}` isn't an integer."))
+        })
+        .collect()
+}
+
+fn report(stats: &Stats) -> String {
+    let modes: Vec<String> = stats.modes.iter().map(i64::to_string).collect();
+    let label = if stats.modes.len() == 1 {
+        "mode"
+    } else {
+        "modes"
+    };
+    format!(
+        "count:  {}\nsum:    {}\nmean:   {}\nmedian: {}\n{label}:{} {}\nmin:    {}\nmax:    {}\n",
+        stats.count,
+        stats.sum,
+        stats.mean,
+        stats.median,
+        " ".repeat(6 - label.len()),
+        modes.join(", "),
+        stats.min,
+        stats.max
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_mixed_separators() {
+        assert_eq!(parse_numbers("1,2, 3\n-4\t5,"), Ok(vec![1, 2, 3, -4, 5]));
+    }
+
+    #[test]
+    fn parses_empty_input_as_no_numbers() {
+        assert_eq!(parse_numbers(""), Ok(Vec::new()));
+        assert_eq!(parse_numbers(" ,\n, "), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn rejects_non_integers() {
+        assert_eq!(
+            parse_numbers("1 2.5 3"),
+            Err("`2.5` isn't an integer.".to_string())
+        );
+        assert!(parse_numbers("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn report_lines_up() {
+        let stats = summarize(vec![1, 1, 2, 2, 3]).unwrap();
+        assert_eq!(
+            report(&stats),
+            "count:  5\nsum:    9\nmean:   1.8\nmedian: 2\nmodes:  1, 2\nmin:    1\nmax:    3\n"
+        );
+        let stats = summarize(vec![7]).unwrap();
+        assert!(report(&stats).contains("\nmode:   7\n"));
+    }
+}
@@ -0,0 +1,164 @@
+// Summary statistics for a list of integers.
+//
+// Every function here is total: given an empty slice it returns `None` (or
+// an empty Vec) rather than panicking or dividing by zero. Sums are done in
+// i128, which can't overflow for anything a Vec<i64> can hold.
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub sum: i128,
+    pub mean: f64,
+    pub median: f64,
+    pub modes: Vec<i64>,
+    pub min: i64,
+    pub max: i64,
+}
+
+// Sorts `values` in place to find the middle, which is why it needs `&mut`.
+// An even number of values has two middles, and the median is halfway
+// between them - so it can end in .5, and has to be a float.
+//
+// Clippy would rather this took `&mut [i64]`, as sorting only needs a
+// slice, but the exercise asks for a `&mut Vec<i64>`.
+#[allow(clippy::ptr_arg)]
+pub fn median(values: &mut Vec<i64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+    let middle = values.len() / 2;
+    if values.len() % 2 == 1 {
+        Some(values[middle] as f64)
+    } else {
+        let pair = i128::from(values[middle - 1]) + i128::from(values[middle]);
+        Some(pair as f64 / 2.0)
+    }
+}
+
+// Every value that appears the most times, smallest first. All the values in
+// a list with no repeats are tied, so they're all modes.
+pub fn modes(values: &[i64]) -> Vec<i64> {
+    let mut counts: HashMap<i64, usize> = HashMap::new();
+    for &value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    let Some(&highest) = counts.values().max() else {
+        return Vec::new();
+    };
+    let mut modes: Vec<i64> = counts
+        .into_iter()
+        .filter(|&(_, count)| count == highest)
+        .map(|(value, _)| value)
+        .collect();
+    modes.sort_unstable();
+    modes
+}
+
+pub fn mean(values: &[i64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(sum(values) as f64 / values.len() as f64)
+}
+
+fn sum(values: &[i64]) -> i128 {
+    values.iter().map(|&value| i128::from(value)).sum()
+}
+
+// `None` for no data. Takes the values by value because finding the median
+// sorts them.
+pub fn summarize(mut values: Vec<i64>) -> Option<Stats> {
+    let median = median(&mut values)?;
+    // `values` is sorted now, so the ends are the min and max.
+    Some(Stats {
+        count: values.len(),
+        sum: sum(&values),
+        mean: mean(&values)?,
+        median,
+        modes: modes(&values),
+        min: *values.first()?,
+        max: *values.last()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(median(&mut Vec::new()), None);
+        assert_eq!(modes(&[]), Vec::<i64>::new());
+        assert_eq!(mean(&[]), None);
+        assert_eq!(summarize(Vec::new()), None);
+    }
+
+    #[test]
+    fn single_element() {
+        assert_eq!(
+            summarize(vec![-7]),
+            Some(Stats {
+                count: 1,
+                sum: -7,
+                mean: -7.0,
+                median: -7.0,
+                modes: vec![-7],
+                min: -7,
+                max: -7,
+            })
+        );
+    }
+
+    #[test]
+    fn odd_length_median() {
+        assert_eq!(median(&mut vec![3, 1, 2]), Some(2.0));
+        assert_eq!(median(&mut vec![5, -10, 7, 7, 0]), Some(5.0));
+    }
+
+    #[test]
+    fn even_length_median() {
+        assert_eq!(median(&mut vec![4, 1, 3, 2]), Some(2.5));
+        assert_eq!(median(&mut vec![10, 20]), Some(15.0));
+        assert_eq!(median(&mut vec![-3, -2]), Some(-2.5));
+    }
+
+    #[test]
+    fn median_of_extremes_does_not_overflow() {
+        assert_eq!(median(&mut vec![i64::MAX, i64::MAX]), Some(i64::MAX as f64));
+        assert_eq!(median(&mut vec![i64::MIN, i64::MAX]), Some(-0.5));
+    }
+
+    #[test]
+    fn single_mode() {
+        assert_eq!(modes(&[1, 2, 2, 3]), [2]);
+    }
+
+    #[test]
+    fn multimodal_data() {
+        assert_eq!(modes(&[5, 1, 5, 3, 1, 3, 4]), [1, 3, 5]);
+    }
+
+    #[test]
+    fn no_repeats_means_every_value_is_a_mode() {
+        assert_eq!(modes(&[3, -1, 2]), [-1, 2, 3]);
+    }
+
+    #[test]
+    fn summary() {
+        let stats = summarize(vec![4, 8, 15, 16, 23, 42, 8]).unwrap();
+        assert_eq!(stats.count, 7);
+        assert_eq!(stats.sum, 116);
+        assert!((stats.mean - 116.0 / 7.0).abs() < 1e-12);
+        assert_eq!(stats.median, 15.0);
+        assert_eq!(stats.modes, [8]);
+        assert_eq!((stats.min, stats.max), (4, 42));
+    }
+
+    #[test]
+    fn sum_does_not_overflow() {
+        let stats = summarize(vec![i64::MAX, i64::MAX]).unwrap();
+        assert_eq!(stats.sum, 2 * i128::from(i64::MAX));
+    }
+}
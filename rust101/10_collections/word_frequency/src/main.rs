@@ -0,0 +1,170 @@
+/* Things learned during this exercise:
+   * A capstone for chapter 8 of the book that uses all three collections:
+   the text is a String, its words are counted in a HashMap, and the counts
+   are sorted in a Vec.
+   * Usage: `word_frequency FILE [--top N] [--min-length N]`. It prints the N
+   most common words (10 by default), ignoring case and punctuation, and
+   skipping words shorter than the minimum length (1 by default).
+   * `HashMap` has no order, so anything printed from one should be sorted
+   first. Sorting by (count descending, word ascending) makes ties come out
+   the same way every run - which is what lets the integration test in
+   `tests/` check the output exactly.
+   * `Ordering::then` chains comparisons: compare by the first key, and only
+   if that's equal, by the second.
+   * Integration tests can run the compiled program: Cargo sets the
+   `CARGO_BIN_EXE_<name>` environment variable to its path while building
+   them.
+*/
+mod words;
+
+use std::env;
+use std::fs;
+use std::process;
+
+use words::{count_words, top_n};
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    path: String,
+    top: usize,
+    min_length: usize,
+}
+
+const USAGE: &str = "Usage: word_frequency FILE [--top N] [--min-length N]";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}\n{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    let text = match fs::read_to_string(&options.path) {
+        Ok(text) => text,
+        Err(error) => {
+            eprintln!("Couldn't read {}: {error}", options.path);
+            process::exit(1);
+        }
+    };
+
+    let counts = count_words(&text, options.min_length);
+    let top = top_n(&counts, options.top);
+    if top.is_empty() {
+        println!("No words found.");
+    } else {
+        print!("{}", table(&top));
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut path = None;
+    let mut top = 10;
+    let mut min_length = 1;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--top" => top = parse_number(arg, args.next())?,
+            "--min-length" => min_length = parse_number(arg, args.next())?,
+            flag if flag.starts_with("--") => return Err(format!("Unknown option `{flag}`.")),
+            _ if path.is_some() => return Err("Only one file can be given.".to_string()),
+            _ => path = Some(arg.clone()),
+        }
+    }
+
+    Ok(Options {
+        path: path.ok_or("No file given.")?,
+        top,
+        min_length,
+    })
+}
+
+fn parse_number(flag: &str, value: Option<&String>) -> Result<usize, String> {
+    let value = value.ok_or(format!("`{flag}` needs a number after it."))?;
+    value
+        .parse()
+        .map_err(|_| format!("`{value}` isn't a valid number for `{flag}`."))
+}
+
+// Rank, word and count columns. The words are padded to the longest one, in
+// chars - `{:<width$}` pads by chars, so "café" lines up with "cafe".
+fn table(rows: &[(&str, usize)]) -> String {
+    let rank_width = rows.len().to_string().len();
+    let word_width = rows
+        .iter()
+        .map(|(word, _)| word.chars().count())
+        .chain(["word".len()])
+        .max()
+        .unwrap_or(0);
+    let count_width = rows
+        .iter()
+        .map(|(_, count)| count.to_string().len())
+        .chain(["count".len()])
+        .max()
+        .unwrap_or(0);
+
+    let mut table = format!(
+        "{:>rank_width$}  {:<word_width$}  {:>count_width$}\n",
+        "#", "word", "count"
+    );
+    for (rank, (word, count)) in rows.iter().enumerate() {
+        table.push_str(&format!(
+            "{:>rank_width$}  {word:<word_width$}  {count:>count_width$}\n",
+            rank + 1
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn defaults() {
+        assert_eq!(
+            parse_args(&args("book.txt")),
+            Ok(Options {
+                path: "book.txt".to_string(),
+                top: 10,
+                min_length: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn flags_can_go_anywhere() {
+        assert_eq!(
+            parse_args(&args("--top 3 book.txt --min-length 5")),
+            Ok(Options {
+                path: "book.txt".to_string(),
+                top: 3,
+                min_length: 5,
+            })
+        );
+    }
+
+    #[test]
+    fn bad_arguments() {
+        assert_eq!(parse_args(&[]), Err("No file given.".to_string()));
+        assert!(parse_args(&args("a.txt b.txt")).is_err());
+        assert!(parse_args(&args("a.txt --top")).is_err());
+        assert!(parse_args(&args("a.txt --top -1")).is_err());
+        assert!(parse_args(&args("a.txt --bottom 3")).is_err());
+    }
+
+    #[test]
+    fn table_aligns_columns() {
+        assert_eq!(
+            table(&[("the", 12), ("café", 3)]),
+            "#  word  count\n1  the      12\n2  café      3\n"
+        );
+    }
+}
@@ -0,0 +1,103 @@
+// Splitting text into words, counting them, and picking the most common.
+
+use std::collections::HashMap;
+
+// Lowercase words with the punctuation around them removed: `"Hello,` is
+// `hello`. Punctuation INSIDE a word is kept, so `don't` and `well-known`
+// are one word each. Anything left with no letters or digits (a lone `--`)
+// isn't a word.
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split_whitespace()
+        .map(|token| token.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+// Only words at least `min_length` chars long are counted - chars rather than
+// bytes, so "café" is 4 long.
+pub fn count_words(text: &str, min_length: usize) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in tokenize(text).filter(|word| word.chars().count() >= min_length) {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    counts
+}
+
+// The `n` most common words, most common first. Words with the same count are
+// in alphabetical order, so the output is the same on every run even though
+// a HashMap iterates in a different order each time.
+pub fn top_n(counts: &HashMap<String, usize>, n: usize) -> Vec<(&str, usize)> {
+    let mut sorted: Vec<(&str, usize)> = counts
+        .iter()
+        .map(|(word, &count)| (word.as_str(), count))
+        .collect();
+    sorted.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    sorted.truncate(n);
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(text: &str) -> Vec<String> {
+        tokenize(text).collect()
+    }
+
+    #[test]
+    fn tokenize_strips_punctuation_and_case() {
+        assert_eq!(
+            tokens("\"Hello,\" she said. HELLO!"),
+            ["hello", "she", "said", "hello"]
+        );
+    }
+
+    #[test]
+    fn tokenize_keeps_inner_punctuation() {
+        assert_eq!(
+            tokens("don't well-known (e.g.)"),
+            ["don't", "well-known", "e.g"]
+        );
+    }
+
+    #[test]
+    fn tokenize_skips_punctuation_only_tokens() {
+        assert_eq!(tokens("a -- b ... !"), ["a", "b"]);
+        assert!(tokens("").is_empty());
+    }
+
+    #[test]
+    fn tokenize_handles_unicode() {
+        assert_eq!(tokens("Café ÉCOLE «naïve»"), ["café", "école", "naïve"]);
+    }
+
+    #[test]
+    fn counts_case_insensitively() {
+        let counts = count_words("The cat and the hat. THE END", 1);
+        assert_eq!(counts["the"], 3);
+        assert_eq!(counts["cat"], 1);
+        assert_eq!(counts.len(), 5);
+    }
+
+    #[test]
+    fn min_length_filters_short_words() {
+        let counts = count_words("a an the café cafés", 4);
+        let mut words: Vec<&String> = counts.keys().collect();
+        words.sort();
+        assert_eq!(words, ["café", "cafés"]);
+    }
+
+    #[test]
+    fn top_n_breaks_ties_alphabetically() {
+        let counts = count_words("b a c b a d", 1);
+        assert_eq!(top_n(&counts, 10), [("a", 2), ("b", 2), ("c", 1), ("d", 1)]);
+        assert_eq!(top_n(&counts, 3), [("a", 2), ("b", 2), ("c", 1)]);
+    }
+
+    #[test]
+    fn top_n_edge_cases() {
+        let counts = count_words("one two", 1);
+        assert!(top_n(&counts, 0).is_empty());
+        assert!(top_n(&HashMap::new(), 5).is_empty());
+    }
+}
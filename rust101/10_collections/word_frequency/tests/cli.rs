@@ -0,0 +1,71 @@
+// Runs the real binary against `tests/data/sample.txt` and checks the output.
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    let sample = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/sample.txt");
+    Command::new(env!("CARGO_BIN_EXE_word_frequency"))
+        .arg(sample)
+        .args(args)
+        .output()
+        .expect("failed to run word_frequency")
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).expect("output is UTF-8")
+}
+
+#[test]
+fn prints_the_top_words() {
+    let output = run(&["--top", "5"]);
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "\
+#  word   count
+1  the        7
+2  dog        4
+3  fox        4
+4  a          3
+5  quick      2
+"
+    );
+}
+
+#[test]
+fn min_length_skips_short_words() {
+    let output = run(&["--top", "3", "--min-length", "4"]);
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "\
+#  word   count
+1  quick      2
+2  brown      1
+3  café       1
+"
+    );
+}
+
+#[test]
+fn defaults_to_ten() {
+    let output = run(&[]);
+    assert_eq!(stdout(&output).lines().count(), 1 + 10);
+}
+
+#[test]
+fn no_matching_words() {
+    let output = run(&["--min-length", "50"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "No words found.\n");
+}
+
+#[test]
+fn missing_file_fails() {
+    let output = Command::new(env!("CARGO_BIN_EXE_word_frequency"))
+        .arg("tests/data/does-not-exist.txt")
+        .output()
+        .expect("failed to run word_frequency");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Couldn't read"));
+}
@@ -0,0 +1,233 @@
+// The last rung of the ladder: a `Config` read from `key = value` lines, with
+// a `ConfigError` that says exactly what went wrong and where.
+//
+// The format: one `key = value` per line, spaces around either side ignored.
+// Blank lines and lines starting with `#` are skipped. If a key appears
+// twice, the later line wins.
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Config {
+    pub name: String,
+    pub port: u16,
+    pub debug: bool,
+}
+
+pub enum ConfigError {
+    Io(io::Error),
+    Malformed {
+        line: usize,
+        text: String,
+    },
+    MissingKey(&'static str),
+    BadValue {
+        line: usize,
+        key: String,
+        value: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(error) => write!(f, "couldn't read the config: {error}"),
+            ConfigError::Malformed { line, text } => {
+                write!(f, "line {line}: expected `key = value`, found `{text}`")
+            }
+            ConfigError::MissingKey(key) => write!(f, "the config needs a `{key}` line"),
+            ConfigError::BadValue { line, key, value } => {
+                write!(f, "line {line}: `{value}` isn't a valid value for `{key}`")
+            }
+        }
+    }
+}
+
+// When `main` returns an `Err`, Rust prints it with `{:?}`. A derived Debug
+// would print `MissingKey("port")`, so this one prints the Display message
+// instead, which is meant for people.
+impl fmt::Debug for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+// `source` lets a caller dig out the underlying io::Error, for example to
+// check `error.kind()`.
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+// This is what lets `?` turn an io::Error into a ConfigError: `?` calls
+// `From::from` on the error before returning it.
+impl From<io::Error> for ConfigError {
+    fn from(error: io::Error) -> Self {
+        ConfigError::Io(error)
+    }
+}
+
+// Shared by every version of the reader: `None` for a line with no `=`, or
+// with nothing before it.
+pub fn split_line(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once('=')?;
+    let key = key.trim();
+    if key.is_empty() {
+        return None;
+    }
+    Some((key, value.trim()))
+}
+
+pub fn is_skipped(line: &str) -> bool {
+    let line = line.trim();
+    line.is_empty() || line.starts_with('#')
+}
+
+pub fn read_config(path: impl AsRef<Path>) -> Result<Config, ConfigError> {
+    let text = fs::read_to_string(path)?;
+    parse_config(&text)
+}
+
+// `debug` is optional and defaults to false; `name` and `port` are required.
+pub fn parse_config(text: &str) -> Result<Config, ConfigError> {
+    let mut name = None;
+    let mut port = None;
+    let mut debug = false;
+
+    // Line numbers start at 1, as in an editor.
+    for (index, text) in text.lines().enumerate() {
+        let line = index + 1;
+        if is_skipped(text) {
+            continue;
+        }
+        let (key, value) = split_line(text).ok_or_else(|| ConfigError::Malformed {
+            line,
+            text: text.to_string(),
+        })?;
+        let bad_value = || ConfigError::BadValue {
+            line,
+            key: key.to_string(),
+            value: value.to_string(),
+        };
+        match key {
+            "name" if !value.is_empty() => name = Some(value.to_string()),
+            "port" => port = Some(value.parse::<u16>().map_err(|_| bad_value())?),
+            "debug" => debug = value.parse::<bool>().map_err(|_| bad_value())?,
+            // An empty name, or a key we don't know about.
+            _ => return Err(bad_value()),
+        }
+    }
+
+    Ok(Config {
+        name: name.ok_or(ConfigError::MissingKey("name"))?,
+        port: port.ok_or(ConfigError::MissingKey("port"))?,
+        debug,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Fixtures are found from the crate root, wherever the tests are run from.
+    fn fixture(name: &str) -> String {
+        format!("{}/tests/data/{name}", env!("CARGO_MANIFEST_DIR"))
+    }
+
+    #[test]
+    fn reads_a_good_config() {
+        assert_eq!(
+            read_config(fixture("good.conf")).unwrap(),
+            Config {
+                name: "demo server".to_string(),
+                port: 8080,
+                debug: true,
+            }
+        );
+    }
+
+    #[test]
+    fn missing_file() {
+        let error = read_config(fixture("does-not-exist.conf")).unwrap_err();
+        let ConfigError::Io(io_error) = &error else {
+            panic!("expected an Io error, got {error}");
+        };
+        assert_eq!(io_error.kind(), io::ErrorKind::NotFound);
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn malformed_line() {
+        let error = read_config(fixture("malformed.conf")).unwrap_err();
+        assert!(matches!(
+            &error,
+            ConfigError::Malformed { line: 2, text } if text == "port 8080"
+        ));
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected `key = value`, found `port 8080`"
+        );
+    }
+
+    #[test]
+    fn missing_required_key() {
+        let error = read_config(fixture("missing_key.conf")).unwrap_err();
+        assert!(matches!(error, ConfigError::MissingKey("port")));
+        assert_eq!(error.to_string(), "the config needs a `port` line");
+    }
+
+    #[test]
+    fn bad_value_reports_its_line() {
+        let error = read_config(fixture("bad_value.conf")).unwrap_err();
+        assert!(matches!(
+            &error,
+            ConfigError::BadValue { line: 4, key, value } if key == "port" && value == "eighty"
+        ));
+    }
+
+    #[test]
+    fn other_bad_values() {
+        for text in [
+            "name = x\nport = 70000",
+            "name = x\nport = 1\ndebug = yes",
+            "name =\nport = 1",
+            "colour = red",
+        ] {
+            assert!(
+                matches!(parse_config(text), Err(ConfigError::BadValue { .. })),
+                "{text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn debug_defaults_to_false() {
+        let config = parse_config("port = 1\nname = x").unwrap();
+        assert!(!config.debug);
+    }
+
+    #[test]
+    fn debug_shows_the_message() {
+        assert_eq!(
+            format!("{:?}", ConfigError::MissingKey("name")),
+            "the config needs a `name` line"
+        );
+    }
+
+    #[test]
+    fn splits_lines() {
+        assert_eq!(split_line(" key = a = b "), Some(("key", "a = b")));
+        assert_eq!(split_line("key="), Some(("key", "")));
+        assert_eq!(split_line("= value"), None);
+        assert_eq!(split_line("no equals"), None);
+        assert!(is_skipped("  # comment"));
+        assert!(is_skipped("   "));
+    }
+}
@@ -0,0 +1,123 @@
+// The first three rungs of the error-handling ladder. Each reads the same
+// file into a map of keys to values, and each handles failure a little
+// better than the one before. `config.rs` is the fourth.
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+
+use crate::config::{is_skipped, split_line};
+
+pub type Settings = HashMap<String, String>;
+
+// Rung 1: `panic!` on anything unexpected. Fine for a quick script, but the
+// program dies, and whoever called this function gets no say in it.
+pub fn read_with_panic(path: &str) -> Settings {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) => panic!("Problem reading {path}: {error:?}"),
+    };
+
+    let mut settings = HashMap::new();
+    for line in text.lines().filter(|line| !is_skipped(line)) {
+        match split_line(line) {
+            Some((key, value)) => settings.insert(key.to_string(), value.to_string()),
+            None => panic!("Malformed line: {line}"),
+        };
+    }
+    settings
+}
+
+// Rung 2: `expect` says the same thing in less code, and its message should
+// explain why the value was EXPECTED to be there. It still panics.
+pub fn read_with_expect(path: &str) -> Settings {
+    let text = fs::read_to_string(path).expect("the config file should exist and be readable");
+
+    text.lines()
+        .filter(|line| !is_skipped(line))
+        .map(|line| {
+            let (key, value) = split_line(line).expect("every line should be `key = value`");
+            (key.to_string(), value.to_string())
+        })
+        .collect()
+}
+
+// Rung 3: return the error with `?` and let the caller decide. Everything has
+// to be an io::Error now, so a malformed line is squeezed into one with
+// `ErrorKind::InvalidData` - and the caller can no longer tell "missing file"
+// from "bad line" without reading the message. The custom error type fixes
+// that.
+pub fn read_with_question_mark(path: &str) -> Result<Settings, io::Error> {
+    let text = fs::read_to_string(path)?;
+
+    let mut settings = HashMap::new();
+    for line in text.lines().filter(|line| !is_skipped(line)) {
+        let (key, value) = split_line(line).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("malformed line: {line}"),
+            )
+        })?;
+        settings.insert(key.to_string(), value.to_string());
+    }
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> String {
+        format!("{}/tests/data/{name}", env!("CARGO_MANIFEST_DIR"))
+    }
+
+    #[test]
+    fn every_rung_reads_a_good_file() {
+        let expected = read_with_panic(&fixture("good.conf"));
+        assert_eq!(expected["port"], "8080");
+        assert_eq!(expected["name"], "demo server");
+        assert_eq!(read_with_expect(&fixture("good.conf")), expected);
+        assert_eq!(
+            read_with_question_mark(&fixture("good.conf")).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Problem reading")]
+    fn panic_on_a_missing_file() {
+        read_with_panic(&fixture("does-not-exist.conf"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Malformed line: port 8080")]
+    fn panic_on_a_malformed_line() {
+        read_with_panic(&fixture("malformed.conf"));
+    }
+
+    #[test]
+    #[should_panic(expected = "the config file should exist")]
+    fn expect_on_a_missing_file() {
+        read_with_expect(&fixture("does-not-exist.conf"));
+    }
+
+    #[test]
+    #[should_panic(expected = "every line should be `key = value`")]
+    fn expect_on_a_malformed_line() {
+        read_with_expect(&fixture("malformed.conf"));
+    }
+
+    #[test]
+    fn question_mark_returns_the_errors() {
+        let missing = read_with_question_mark(&fixture("does-not-exist.conf")).unwrap_err();
+        assert_eq!(missing.kind(), io::ErrorKind::NotFound);
+        let malformed = read_with_question_mark(&fixture("malformed.conf")).unwrap_err();
+        assert_eq!(malformed.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn only_the_custom_error_checks_the_keys() {
+        // Without a Config to fill in, nothing notices `port` is missing.
+        let settings = read_with_question_mark(&fixture("missing_key.conf")).unwrap();
+        assert!(!settings.contains_key("port"));
+    }
+}
@@ -0,0 +1,73 @@
+/* Things learned during this exercise:
+   * Chapter 9 of the book splits errors into two kinds: unrecoverable ones,
+   where `panic!` stops the program, and recoverable ones, returned as a
+   `Result<T, E>` for the caller to deal with.
+   * This program reads a `key = value` config file four ways, each a rung up
+   the ladder (see `ladder.rs` and `config.rs`):
+     1. `panic!` in a `match` on the Result,
+     2. `expect`, which panics with a message saying what was expected,
+     3. `?`, which returns the error early instead of panicking,
+     4. a custom `ConfigError` enum, so callers can tell the failures apart
+     and the messages include line numbers.
+   * `?` only works in a function that returns a Result (or Option), and it
+   converts the error with `From::from` on the way out - implementing
+   `From<io::Error> for ConfigError` is what lets `fs::read_to_string(path)?`
+   work in a function returning `ConfigError`.
+   * The `Error` trait needs `Debug` and `Display`. `source` links to the
+   error that caused this one.
+   * `main` can return `Result<(), E>` when E implements Debug. An `Err`
+   prints `Error: ` followed by the Debug output and exits with code 1.
+   * Usage: `config_reader [--with panic|expect|question] FILE`. Without
+   `--with` it uses the final version. Try the files in `tests/data/`.
+   * See https://doc.rust-lang.org/book/ch09-00-error-handling.html
+*/
+mod config;
+mod ladder;
+
+use std::env;
+use std::process;
+
+use config::{read_config, ConfigError};
+use ladder::{read_with_expect, read_with_panic, read_with_question_mark, Settings};
+
+fn main() -> Result<(), ConfigError> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (rung, path) = match &args[..] {
+        [path] => (None, path),
+        [flag, rung, path] if flag == "--with" => (Some(rung.as_str()), path),
+        _ => {
+            eprintln!("Usage: config_reader [--with panic|expect|question] FILE");
+            process::exit(2);
+        }
+    };
+
+    match rung {
+        None => {
+            let config = read_config(path)?;
+            println!("{config:#?}");
+        }
+        Some("panic") => print_settings(read_with_panic(path)),
+        Some("expect") => print_settings(read_with_expect(path)),
+        // read_with_question_mark returns an io::Error and main returns a
+        // ConfigError. A bare `?` would convert it through the From impl on
+        // its own; `map_err(ConfigError::from)` is written out only to show
+        // what `?` does.
+        Some("question") => {
+            print_settings(read_with_question_mark(path).map_err(ConfigError::from)?)
+        }
+        Some(other) => {
+            eprintln!("`{other}` isn't one of panic, expect or question.");
+            process::exit(2);
+        }
+    }
+    Ok(())
+}
+
+// A HashMap has no order, so sort the keys to print them.
+fn print_settings(settings: Settings) {
+    let mut settings: Vec<(String, String)> = settings.into_iter().collect();
+    settings.sort();
+    for (key, value) in settings {
+        println!("{key} = {value}");
+    }
+}
@@ -0,0 +1,118 @@
+// An email address that has passed a basic check: one `@`, with something on
+// both sides and no whitespace. That's far from the full standard (which
+// allows some surprising things), but it catches typos, and any code holding
+// an `Email` knows the check happened.
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Email(String);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum EmailError {
+    Empty,
+    MissingAt,
+    TooManyAts,
+    EmptyLocalPart,
+    EmptyDomain,
+    ContainsWhitespace,
+}
+
+impl fmt::Display for EmailError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let problem = match self {
+            EmailError::Empty => "it's empty",
+            EmailError::MissingAt => "it has no `@`",
+            EmailError::TooManyAts => "it has more than one `@`",
+            EmailError::EmptyLocalPart => "there's nothing before the `@`",
+            EmailError::EmptyDomain => "there's nothing after the `@`",
+            EmailError::ContainsWhitespace => "it contains whitespace",
+        };
+        write!(f, "not a valid email address: {problem}")
+    }
+}
+
+impl Error for EmailError {}
+
+impl Email {
+    // Spaces around the address are trimmed off first, since they're easy to
+    // paste in by accident.
+    pub fn parse(address: &str) -> Result<Email, EmailError> {
+        let address = address.trim();
+        if address.is_empty() {
+            return Err(EmailError::Empty);
+        }
+        if address.contains(char::is_whitespace) {
+            return Err(EmailError::ContainsWhitespace);
+        }
+        let (local, domain) = address.split_once('@').ok_or(EmailError::MissingAt)?;
+        if domain.contains('@') {
+            return Err(EmailError::TooManyAts);
+        }
+        if local.is_empty() {
+            return Err(EmailError::EmptyLocalPart);
+        }
+        if domain.is_empty() {
+            return Err(EmailError::EmptyDomain);
+        }
+        Ok(Email(address.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    // The part before the `@`. These can't fail: `parse` already checked
+    // there's exactly one.
+    pub fn local_part(&self) -> &str {
+        self.0.split_once('@').map_or("", |(local, _)| local)
+    }
+
+    pub fn domain(&self) -> &str {
+        self.0.split_once('@').map_or("", |(_, domain)| domain)
+    }
+}
+
+impl fmt::Display for Email {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_splits() {
+        let email = Email::parse("  ferris@rust-lang.org ").unwrap();
+        assert_eq!(email.as_str(), "ferris@rust-lang.org");
+        assert_eq!(email.local_part(), "ferris");
+        assert_eq!(email.domain(), "rust-lang.org");
+        assert_eq!(email.to_string(), "ferris@rust-lang.org");
+    }
+
+    #[test]
+    fn rejects_invalid_addresses() {
+        let cases = [
+            ("", EmailError::Empty),
+            ("   ", EmailError::Empty),
+            ("ferris.rust-lang.org", EmailError::MissingAt),
+            ("a@b@c", EmailError::TooManyAts),
+            ("@rust-lang.org", EmailError::EmptyLocalPart),
+            ("ferris@", EmailError::EmptyDomain),
+            ("fer ris@rust-lang.org", EmailError::ContainsWhitespace),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(Email::parse(input), Err(expected), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn error_messages() {
+        assert_eq!(
+            EmailError::MissingAt.to_string(),
+            "not a valid email address: it has no `@`"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+// The book's `Guess`: a number from 1 to 100, checked once when it's made.
+//
+// The field is private, so the only ways to get a Guess are `new` and
+// `try_new` - code anywhere else can't build one with `Guess { value: 500 }`.
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Guess {
+    value: i32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct GuessError(pub i32);
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a guess must be between 1 and 100, got {}", self.0)
+    }
+}
+
+impl Error for GuessError {}
+
+impl Guess {
+    // For values the program itself chose, where an out-of-range one is a
+    // bug - the book's version. Panics if `value` isn't 1 to 100.
+    pub fn new(value: i32) -> Guess {
+        match Guess::try_new(value) {
+            Ok(guess) => guess,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    // For values from outside the program, like user input, where being out
+    // of range is expected and should be handled.
+    pub fn try_new(value: i32) -> Result<Guess, GuessError> {
+        if (1..=100).contains(&value) {
+            Ok(Guess { value })
+        } else {
+            Err(GuessError(value))
+        }
+    }
+
+    // A getter, since the field is private. Guess is Copy, so it takes `self`.
+    pub fn value(self) -> i32 {
+        self.value
+    }
+}
+
+impl fmt::Display for Guess {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_the_whole_range() {
+        assert_eq!(Guess::new(1).value(), 1);
+        assert_eq!(Guess::new(100).value(), 100);
+        assert_eq!(Guess::try_new(50).map(Guess::value), Ok(50));
+    }
+
+    #[test]
+    #[should_panic(expected = "a guess must be between 1 and 100, got 200")]
+    fn new_panics_above_100() {
+        Guess::new(200);
+    }
+
+    #[test]
+    #[should_panic(expected = "got 0")]
+    fn new_panics_below_1() {
+        Guess::new(0);
+    }
+
+    #[test]
+    fn try_new_returns_an_error() {
+        assert_eq!(Guess::try_new(0), Err(GuessError(0)));
+        assert_eq!(Guess::try_new(101), Err(GuessError(101)));
+        assert_eq!(Guess::try_new(i32::MIN), Err(GuessError(i32::MIN)));
+    }
+
+    #[test]
+    fn displays_the_number() {
+        assert_eq!(Guess::new(42).to_string(), "42");
+    }
+}
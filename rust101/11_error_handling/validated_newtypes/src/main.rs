@@ -0,0 +1,83 @@
+/* Things learned during this exercise:
+   * The book's `Guess` type (chapter 9.3) checks its value once, in the
+   constructor, and keeps the field private so there's no other way to make
+   one. After that, every function that takes a Guess can rely on it being
+   1 to 100 without checking again - the type is the proof.
+   * A "newtype" is a struct with one field wrapping another type, like
+   `struct Email(String)`. It costs nothing at runtime, but the compiler
+   treats it as a different type, so a plain String can't be passed where
+   an Email is expected.
+   * Two constructors for two situations: `new` panics, for values that come
+   from the program itself and can only be wrong if there's a bug;
+   `try_new` (and `Email::parse`) return a Result, for values from outside
+   that might reasonably be wrong.
+   * `TryFrom` is the standard trait for a conversion that can fail, and
+   serde's `#[serde(try_from = "...")]` uses it, so deserializing can't
+   sneak an invalid value past the check.
+   * See https://doc.rust-lang.org/book/ch09-03-to-panic-or-not-to-panic.html
+*/
+mod email;
+mod guess;
+mod percentage;
+
+use email::Email;
+use guess::Guess;
+use percentage::Percentage;
+
+// The payoff: this function can't be given an invalid address, guess or
+// percentage, so it has no checks and no error cases of its own.
+fn score_message(to: &Email, guess: Guess, accuracy: Percentage) -> String {
+    format!(
+        "Hi {}, you guessed {guess} with {accuracy} accuracy, scoring {:.0} points.",
+        to.local_part(),
+        accuracy.of(f64::from(guess.value()))
+    )
+}
+
+fn main() {
+    let email = Email::parse("ferris@rust-lang.org").expect("a valid example address");
+    let guess = Guess::new(42);
+    let accuracy = Percentage::new(87.5);
+    println!("To: {} (at {})", email.as_str(), email.domain());
+    println!("{}", score_message(&email, guess, accuracy));
+    println!();
+
+    println!("Values that don't make it into the types:");
+    for value in [0, 101, -5] {
+        if let Err(error) = Guess::try_new(value) {
+            println!("  Guess::try_new({value}): {error}");
+        }
+    }
+    for address in ["ferris", "@rust-lang.org", "fer ris@rust-lang.org"] {
+        if let Err(error) = Email::parse(address) {
+            println!("  Email::parse({address:?}): {error}");
+        }
+    }
+    for value in [-1.0, 100.1, f64::NAN] {
+        if let Err(error) = Percentage::try_new(value) {
+            println!("  Percentage::try_new({value}): {error}");
+        }
+    }
+
+    // None of these compile, so the checks can't be skipped:
+    //
+    //     score_message("ferris", 42, 87.5);
+    //     // error[E0308]: mismatched types - expected `&Email`, found `&str`
+    //
+    //     let guess = Guess { value: 500 };
+    //     // error[E0451]: field `value` of struct `Guess` is private
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score_message_uses_the_validated_values() {
+        let email = Email::parse("ada@example.com").unwrap();
+        assert_eq!(
+            score_message(&email, Guess::new(80), Percentage::new(50.0)),
+            "Hi ada, you guessed 80 with 50% accuracy, scoring 40 points."
+        );
+    }
+}
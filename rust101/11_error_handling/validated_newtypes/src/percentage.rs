@@ -0,0 +1,143 @@
+// A percentage from 0 to 100 inclusive, stored as an f64.
+//
+// `#[serde(try_from = "f64", into = "f64")]` makes serde read and write a
+// plain number, and - the important part - send every number it reads
+// through `TryFrom<f64>`. Without it, the derived Deserialize would build a
+// Percentage from any number at all, skipping the check.
+use std::error::Error;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "f64", into = "f64")]
+pub struct Percentage(f64);
+
+#[derive(Debug, PartialEq)]
+pub struct PercentageError(pub f64);
+
+impl fmt::Display for PercentageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a percentage must be between 0 and 100, got {}", self.0)
+    }
+}
+
+impl Error for PercentageError {}
+
+impl Percentage {
+    // Panics if `value` is outside 0 to 100, or NaN.
+    pub fn new(value: f64) -> Percentage {
+        match Percentage::try_new(value) {
+            Ok(percentage) => percentage,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    // `contains` is false for NaN, since NaN isn't >= 0 or <= 100 - so NaN is
+    // rejected along with everything else out of range.
+    pub fn try_new(value: f64) -> Result<Percentage, PercentageError> {
+        if (0.0..=100.0).contains(&value) {
+            Ok(Percentage(value))
+        } else {
+            Err(PercentageError(value))
+        }
+    }
+
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    // As a fraction of 1: 50% is 0.5.
+    pub fn fraction(self) -> f64 {
+        self.0 / 100.0
+    }
+
+    // That percentage of `amount`. Takes a Percentage rather than an f64, so
+    // there's no mixing up "50" and "0.5".
+    pub fn of(self, amount: f64) -> f64 {
+        amount * self.fraction()
+    }
+}
+
+impl TryFrom<f64> for Percentage {
+    type Error = PercentageError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Percentage::try_new(value)
+    }
+}
+
+impl From<Percentage> for f64 {
+    fn from(percentage: Percentage) -> f64 {
+        percentage.0
+    }
+}
+
+impl fmt::Display for Percentage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_the_whole_range() {
+        assert_eq!(Percentage::new(0.0).value(), 0.0);
+        assert_eq!(Percentage::new(100.0).value(), 100.0);
+        assert_eq!(Percentage::new(12.5).fraction(), 0.125);
+        assert_eq!(Percentage::new(25.0).of(80.0), 20.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "a percentage must be between 0 and 100, got 100.5")]
+    fn new_panics_above_100() {
+        Percentage::new(100.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "got NaN")]
+    fn new_panics_on_nan() {
+        Percentage::new(f64::NAN);
+    }
+
+    #[test]
+    fn try_new_returns_an_error() {
+        assert_eq!(Percentage::try_new(-0.1), Err(PercentageError(-0.1)));
+        assert_eq!(
+            Percentage::try_new(f64::INFINITY),
+            Err(PercentageError(f64::INFINITY))
+        );
+        assert!(Percentage::try_new(f64::NAN).is_err());
+        assert_eq!(Percentage::try_from(50.0), Ok(Percentage(50.0)));
+    }
+
+    #[test]
+    fn displays_with_a_percent_sign() {
+        assert_eq!(Percentage::new(99.5).to_string(), "99.5%");
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let percentage = Percentage::new(42.5);
+        let json = serde_json::to_string(&percentage).unwrap();
+        assert_eq!(json, "42.5");
+        assert_eq!(
+            serde_json::from_str::<Percentage>(&json).unwrap(),
+            percentage
+        );
+    }
+
+    #[test]
+    fn deserialize_rejects_out_of_range_values() {
+        let error = serde_json::from_str::<Percentage>("150").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "a percentage must be between 0 and 100, got 150"
+        );
+        assert!(serde_json::from_str::<Percentage>("-1").is_err());
+        assert!(serde_json::from_str::<Vec<Percentage>>("[10, 20, 101]").is_err());
+    }
+}
@@ -0,0 +1,108 @@
+// The book's `largest`, and a `second_largest` to go with it.
+//
+// Both return a reference into the slice, so they work for types that can't
+// be copied (like String) without cloning anything - and an Option, so an
+// empty slice is `None` rather than a panic.
+
+// `PartialOrd` is enough to use `>`, and covers f64 too. If there's a tie
+// the first of the largest values is returned.
+pub fn largest<T: PartialOrd>(list: &[T]) -> Option<&T> {
+    let mut items = list.iter();
+    let mut largest = items.next()?;
+    for item in items {
+        if item > largest {
+            largest = item;
+        }
+    }
+    Some(largest)
+}
+
+// The largest value that's smaller than the largest - so in [5, 5, 3] it's
+// 3, not the second 5. `None` if there aren't two different values.
+//
+// This needs `Ord` rather than `PartialOrd`: with NaN in a list of floats,
+// "smaller than the largest" stops meaning anything.
+pub fn second_largest<T: Ord>(list: &[T]) -> Option<&T> {
+    let mut largest: Option<&T> = None;
+    let mut second: Option<&T> = None;
+    for item in list {
+        match largest {
+            None => largest = Some(item),
+            Some(top) if item > top => {
+                second = largest;
+                largest = Some(item);
+            }
+            Some(top) if item < top && second.is_none_or(|second| item > second) => {
+                second = Some(item);
+            }
+            // Equal to the largest, or no bigger than the second.
+            _ => {}
+        }
+    }
+    second
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn largest_i32() {
+        assert_eq!(largest(&[34, 50, 25, 100, 65]), Some(&100));
+        assert_eq!(largest(&[-3, -1, -2]), Some(&-1));
+        assert_eq!(largest(&[7]), Some(&7));
+    }
+
+    #[test]
+    fn largest_char_and_string() {
+        assert_eq!(largest(&['y', 'm', 'a', 'q']), Some(&'y'));
+        let words = [
+            "pear".to_string(),
+            "apple".to_string(),
+            "zucchini".to_string(),
+        ];
+        assert_eq!(largest(&words).map(String::as_str), Some("zucchini"));
+    }
+
+    #[test]
+    fn largest_f64() {
+        assert_eq!(largest(&[1.5, -2.0, 9.25]), Some(&9.25));
+    }
+
+    #[test]
+    fn largest_returns_the_first_of_a_tie() {
+        let words = ["b".to_string(), "b".to_string()];
+        assert!(std::ptr::eq(largest(&words).unwrap(), &words[0]));
+    }
+
+    #[test]
+    fn empty_slices() {
+        assert_eq!(largest::<i32>(&[]), None);
+        assert_eq!(largest::<String>(&[]), None);
+        assert_eq!(second_largest::<i32>(&[]), None);
+        assert_eq!(second_largest::<char>(&[]), None);
+    }
+
+    #[test]
+    fn second_largest_i32() {
+        assert_eq!(second_largest(&[34, 50, 25, 100, 65]), Some(&65));
+        assert_eq!(second_largest(&[1, 2]), Some(&1));
+        assert_eq!(second_largest(&[2, 1]), Some(&1));
+        assert_eq!(second_largest(&[7]), None);
+    }
+
+    #[test]
+    fn second_largest_skips_duplicates_of_the_largest() {
+        assert_eq!(second_largest(&[5, 5, 3]), Some(&3));
+        assert_eq!(second_largest(&[3, 5, 5]), Some(&3));
+        assert_eq!(second_largest(&[5, 3, 5, 3]), Some(&3));
+        assert_eq!(second_largest(&[4, 4, 4]), None);
+    }
+
+    #[test]
+    fn second_largest_string_and_char() {
+        let words = ["pear", "apple", "zucchini", "zucchini"].map(String::from);
+        assert_eq!(second_largest(&words).map(String::as_str), Some("pear"));
+        assert_eq!(second_largest(&['a', 'c', 'b', 'c']), Some(&'b'));
+    }
+}
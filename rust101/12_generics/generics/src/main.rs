@@ -0,0 +1,57 @@
+/* Things learned during this exercise:
+   * Generics let one function or type work for many types. `largest<T>`
+   replaces a `largest_i32` and a `largest_char` that were identical apart
+   from their signatures.
+   * A type parameter can't be used for much until it's bounded by traits:
+   `T: PartialOrd` is what allows `>` in `largest`. The bounds are a promise
+   the caller has to keep, and the compiler checks it at every call.
+   * Structs, enums and methods can be generic too. An `impl<T> Point<T>`
+   block applies to every Point, `impl Point<f64>` to one concrete type,
+   and `impl<T: Display + PartialOrd> Pair<T>` only to Pairs whose type
+   meets the bounds.
+   * Generics cost nothing at runtime: the compiler generates a copy of the
+   code for each concrete type used ("monomorphization"), as if it had
+   been written out by hand.
+   * See https://doc.rust-lang.org/book/ch10-01-syntax.html
+*/
+mod largest;
+mod pair;
+mod point;
+
+use largest::{largest, second_largest};
+use pair::Pair;
+use point::Point;
+
+fn main() {
+    let numbers = vec![34, 50, 25, 100, 65, 100];
+    println!("largest of {numbers:?} = {:?}", largest(&numbers));
+    println!(
+        "second_largest of {numbers:?} = {:?}",
+        second_largest(&numbers)
+    );
+
+    let chars = vec!['y', 'm', 'a', 'q'];
+    println!("largest of {chars:?} = {:?}", largest(&chars));
+    println!("second_largest of {chars:?} = {:?}", second_largest(&chars));
+
+    let words: Vec<String> = ["pear", "apple", "zucchini"].map(String::from).into();
+    println!("largest of {words:?} = {:?}", largest(&words));
+
+    let empty: Vec<i32> = Vec::new();
+    println!("largest of {empty:?} = {:?}", largest(&empty));
+    println!();
+
+    let p = Point { x: 3.0, y: 4.0 };
+    println!(
+        "{p:?} has p.x() = {} and is {} from the origin",
+        p.x(),
+        p.distance_from_origin()
+    );
+    let p1 = Point { x: 5, y: 10.4 };
+    let p2 = Point { x: "Hello", y: 'c' };
+    println!("{p1:?}.mixup({p2:?}) = {:?}", p1.mixup(p2));
+    println!();
+
+    println!("{}", Pair::new(3, 7).cmp_display());
+    println!("{}", Pair::new("kiwi", "fig").cmp_display());
+}
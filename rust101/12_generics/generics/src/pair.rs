@@ -0,0 +1,63 @@
+// The book's `Pair`: every Pair has `new`, but only a pair of values that
+// can be compared and displayed has `cmp_display`. Trying to call it on a
+// `Pair<Vec<i32>>` is a compile error, since Vec isn't Display.
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq)]
+pub struct Pair<T> {
+    x: T,
+    y: T,
+}
+
+impl<T> Pair<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Display + PartialOrd> Pair<T> {
+    // The book's version prints the message; returning it means it can be
+    // tested. An equal pair counts as x being the largest, as in the book.
+    pub fn cmp_display(&self) -> String {
+        if self.x >= self.y {
+            format!("The largest member is x = {}", self.x)
+        } else {
+            format!("The largest member is y = {}", self.y)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cmp_display_i32() {
+        assert_eq!(Pair::new(3, 7).cmp_display(), "The largest member is y = 7");
+        assert_eq!(Pair::new(9, 7).cmp_display(), "The largest member is x = 9");
+        assert_eq!(Pair::new(2, 2).cmp_display(), "The largest member is x = 2");
+    }
+
+    #[test]
+    fn cmp_display_string_and_char() {
+        let pair = Pair::new("apple".to_string(), "banana".to_string());
+        assert_eq!(pair.cmp_display(), "The largest member is y = banana");
+        assert_eq!(
+            Pair::new('z', 'a').cmp_display(),
+            "The largest member is x = z"
+        );
+    }
+
+    #[test]
+    fn any_type_can_make_a_pair() {
+        // Vec isn't Display, so this pair has `new` but no `cmp_display`.
+        let pair = Pair::new(vec![1], vec![2]);
+        assert_eq!(
+            pair,
+            Pair {
+                x: vec![1],
+                y: vec![2]
+            }
+        );
+    }
+}
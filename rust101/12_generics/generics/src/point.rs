@@ -0,0 +1,88 @@
+// The book's `Point`, which starts with one type for both coordinates and
+// then needs two.
+//
+// `Y = X` is a default type parameter: `Point<i32>` means `Point<i32, i32>`,
+// so the one-type Point and the two-type Point are the same struct, and
+// `mixup` can still mix the types.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Point<X, Y = X> {
+    pub x: X,
+    pub y: Y,
+}
+
+// Methods for every Point, whatever its types. The `<X, Y>` after `impl`
+// declares them, so that `Point<X, Y>` refers to them instead of to some
+// concrete types called X and Y.
+impl<X, Y> Point<X, Y> {
+    pub fn x(&self) -> &X {
+        &self.x
+    }
+
+    // The type parameters on the method (X2, Y2) are separate from the ones
+    // on the impl: the result takes its x from `self` and its y from
+    // `other`, so it can be a type neither of them is.
+    pub fn mixup<X2, Y2>(self, other: Point<X2, Y2>) -> Point<X, Y2> {
+        Point {
+            x: self.x,
+            y: other.y,
+        }
+    }
+}
+
+// Methods only for one concrete type - a `Point<i32>` has no
+// `distance_from_origin`, because `sqrt` works on floats.
+impl Point<f64> {
+    pub fn distance_from_origin(&self) -> f64 {
+        (self.x.powi(2) + self.y.powi(2)).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_type_for_both() {
+        let integer = Point { x: 5, y: 10 };
+        let float: Point<f64> = Point { x: 1.0, y: 4.0 };
+        assert_eq!(*integer.x(), 5);
+        assert_eq!(*float.x(), 1.0);
+        // Mixing them needs two type parameters:
+        //
+        //     let wont_work: Point<i32> = Point { x: 5, y: 4.0 };
+        //
+        // error[E0308]: mismatched types - expected integer, found floating-point number
+        let mixed: Point<i32, f64> = Point { x: 5, y: 4.0 };
+        assert_eq!(mixed.y, 4.0);
+    }
+
+    #[test]
+    fn mixup_takes_x_from_self_and_y_from_other() {
+        let p1 = Point { x: 5, y: 10.4 };
+        let p2 = Point { x: "Hello", y: 'c' };
+        let p3 = p1.mixup(p2);
+        assert_eq!(p3, Point { x: 5, y: 'c' });
+    }
+
+    #[test]
+    fn mixup_with_strings() {
+        let names = Point {
+            x: "left".to_string(),
+            y: "right".to_string(),
+        };
+        let numbers = Point { x: 1, y: 2 };
+        assert_eq!(
+            names.mixup(numbers),
+            Point {
+                x: "left".to_string(),
+                y: 2
+            }
+        );
+    }
+
+    #[test]
+    fn distance_from_origin() {
+        assert_eq!(Point { x: 3.0, y: 4.0 }.distance_from_origin(), 5.0);
+    }
+}
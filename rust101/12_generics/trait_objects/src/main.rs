@@ -0,0 +1,54 @@
+/* Things learned during this exercise:
+   * Rust has two ways to write code that works with "anything that can be
+   drawn", and this crate does the same job both ways (see `render.rs`):
+     * Generics, `fn render_all<T: Draw>(items: &[T])`, are resolved at
+     compile time. The compiler makes a copy of the function for each type
+     (monomorphization), so calls are direct and as fast as hand-written
+     code - but each call works with a single type, so a `&[T]` can't mix
+     circles and triangles.
+     * Trait objects, `Vec<Box<dyn Draw>>`, are resolved at runtime. Each
+     `dyn Draw` is a pointer to the value plus a pointer to a table of its
+     methods, so one Vec can hold different shapes, at the cost of an
+     extra indirection per call and a heap allocation per Box.
+   * Not every trait can be a trait object ("dyn compatibility", once
+   called object safety). A method that returns `Self`, or has generic
+   parameters of its own, can't be called through a `dyn` pointer, since
+   the concrete type is unknown. Marking such a method `where Self: Sized`
+   keeps the rest of the trait usable as `dyn Draw` - which is what
+   `Draw::scaled` does.
+   * A rule of thumb: use generics by default, and reach for `dyn` when the
+   set of types is only known at runtime or a collection must mix them.
+   * See https://doc.rust-lang.org/book/ch18-02-trait-objects.html
+*/
+mod render;
+mod shapes;
+
+use render::{render_all, render_all_dyn, render_all_scaled};
+use shapes::{Circle, Draw, Rectangle, Triangle};
+
+fn main() {
+    println!("== Trait objects: one Vec, three kinds of shape ==");
+    let shapes: Vec<Box<dyn Draw>> = vec![
+        Box::new(Rectangle {
+            width: 6,
+            height: 2,
+        }),
+        Box::new(Triangle { height: 3 }),
+        Box::new(Circle { radius: 5 }),
+    ];
+    print!("{}", render_all_dyn(&shapes));
+    println!();
+
+    println!("== Generics: one kind of shape per call ==");
+    let triangles = [Triangle { height: 1 }, Triangle { height: 2 }];
+    print!("{}", render_all(&triangles));
+    // A mixed slice has no single type to fill in for T:
+    //
+    //     render_all(&[Triangle { height: 1 }, Circle { radius: 2 }]);
+    //
+    // error[E0308]: mismatched types - expected `Triangle`, found `Circle`
+    println!();
+
+    println!("== Only generics can call methods that return Self ==");
+    print!("{}", render_all_scaled(&triangles, 2));
+}
@@ -0,0 +1,103 @@
+// The same job done two ways: draw every shape in a list, one after another.
+
+use crate::shapes::Draw;
+
+// Trait objects: each Box points at some shape, and a `dyn Draw` carries a
+// pointer to that shape's methods (a "vtable"), looked up at runtime. So the
+// list can hold a mix of shapes - but every call goes through the pointer,
+// and the compiler can't inline it.
+pub fn render_all_dyn(items: &[Box<dyn Draw>]) -> String {
+    items.iter().map(|item| item.render()).collect()
+}
+
+// Generics: the compiler writes a separate `render_all` for each type it's
+// used with, so calls are direct and can be inlined. But `T` is ONE type per
+// call, so every item in the slice has to be the same shape.
+pub fn render_all<T: Draw>(items: &[T]) -> String {
+    items.iter().map(Draw::render).collect()
+}
+
+// Only possible with generics: `scaled` returns `Self`, which needs the
+// concrete type to be known. There's no `dyn` version of this:
+//
+//     fn render_all_scaled_dyn(items: &[Box<dyn Draw>], factor: usize) -> String {
+//         items.iter().map(|item| item.scaled(factor).render()).collect()
+//     }
+//
+// error: the `scaled` method cannot be invoked on a trait object
+pub fn render_all_scaled<T: Draw>(items: &[T], factor: usize) -> String {
+    items
+        .iter()
+        .map(|item| item.scaled(factor).render())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shapes::{Circle, Rectangle, Triangle};
+
+    #[test]
+    fn both_paths_agree_on_rectangles() {
+        let rectangles = vec![
+            Rectangle {
+                width: 2,
+                height: 1,
+            },
+            Rectangle {
+                width: 4,
+                height: 3,
+            },
+        ];
+        let boxed: Vec<Box<dyn Draw>> = rectangles
+            .iter()
+            .cloned()
+            .map(|rectangle| Box::new(rectangle) as Box<dyn Draw>)
+            .collect();
+        assert_eq!(render_all(&rectangles), render_all_dyn(&boxed));
+    }
+
+    #[test]
+    fn both_paths_agree_on_triangles_and_circles() {
+        let triangles = [Triangle { height: 1 }, Triangle { height: 4 }];
+        let boxed: Vec<Box<dyn Draw>> = vec![
+            Box::new(Triangle { height: 1 }),
+            Box::new(Triangle { height: 4 }),
+        ];
+        assert_eq!(render_all(&triangles), render_all_dyn(&boxed));
+
+        let circles = [Circle { radius: 3 }];
+        let boxed: Vec<Box<dyn Draw>> = vec![Box::new(Circle { radius: 3 })];
+        assert_eq!(render_all(&circles), render_all_dyn(&boxed));
+    }
+
+    #[test]
+    fn dyn_handles_a_mixed_vector() {
+        let shapes: Vec<Box<dyn Draw>> = vec![
+            Box::new(Triangle { height: 2 }),
+            Box::new(Rectangle {
+                width: 1,
+                height: 1,
+            }),
+            Box::new(Circle { radius: 2 }),
+        ];
+        assert_eq!(
+            render_all_dyn(&shapes),
+            "Triangle of height 2:\n*\n**\nRectangle 1x1:\n#\nCircle of radius 2:\noooo\noooo\n"
+        );
+    }
+
+    #[test]
+    fn empty_lists_render_nothing() {
+        assert_eq!(render_all::<Circle>(&[]), "");
+        assert_eq!(render_all_dyn(&[]), "");
+    }
+
+    #[test]
+    fn scaling_needs_generics() {
+        assert_eq!(
+            render_all_scaled(&[Triangle { height: 1 }], 2),
+            render_all(&[Triangle { height: 2 }])
+        );
+    }
+}
@@ -0,0 +1,149 @@
+// The `Draw` trait and three shapes that draw themselves as ASCII art.
+
+pub trait Draw {
+    fn name(&self) -> String;
+
+    // One String per row of the picture.
+    fn draw(&self) -> Vec<String>;
+
+    // A default method: the name, then the picture underneath. Every shape
+    // gets this without writing it.
+    fn render(&self) -> String {
+        let mut out = format!("{}:\n", self.name());
+        for row in self.draw() {
+            out.push_str(&row);
+            out.push('\n');
+        }
+        out
+    }
+
+    // Returns a new shape of the SAME type, which a trait object can't do -
+    // behind a `dyn Draw` nobody knows what `Self` is, or how big it is. The
+    // `where Self: Sized` bound leaves this method out of `dyn Draw`, so the
+    // rest of the trait can still be used as a trait object.
+    fn scaled(&self, factor: usize) -> Self
+    where
+        Self: Sized;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rectangle {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Draw for Rectangle {
+    fn name(&self) -> String {
+        format!("Rectangle {}x{}", self.width, self.height)
+    }
+
+    fn draw(&self) -> Vec<String> {
+        vec!["#".repeat(self.width); self.height]
+    }
+
+    fn scaled(&self, factor: usize) -> Self {
+        Rectangle {
+            width: self.width * factor,
+            height: self.height * factor,
+        }
+    }
+}
+
+// A right-angled triangle, one more star on each row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Triangle {
+    pub height: usize,
+}
+
+impl Draw for Triangle {
+    fn name(&self) -> String {
+        format!("Triangle of height {}", self.height)
+    }
+
+    fn draw(&self) -> Vec<String> {
+        (1..=self.height).map(|width| "*".repeat(width)).collect()
+    }
+
+    fn scaled(&self, factor: usize) -> Self {
+        Triangle {
+            height: self.height * factor,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Circle {
+    pub radius: usize,
+}
+
+impl Draw for Circle {
+    fn name(&self) -> String {
+        format!("Circle of radius {}", self.radius)
+    }
+
+    // Marks each cell whose centre is inside the circle. Cells are twice as
+    // tall as they are wide in most terminals, so each row covers two units
+    // of height and the circle doesn't come out as a tall oval.
+    fn draw(&self) -> Vec<String> {
+        let r = self.radius as f64;
+        let size = 2 * self.radius;
+        (0..self.radius)
+            .map(|row| {
+                let y = 2.0 * row as f64 + 1.0 - r;
+                (0..size)
+                    .map(|column| {
+                        let x = column as f64 + 0.5 - r;
+                        if x * x + y * y <= r * r {
+                            'o'
+                        } else {
+                            ' '
+                        }
+                    })
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    fn scaled(&self, factor: usize) -> Self {
+        Circle {
+            radius: self.radius * factor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rectangle() {
+        let rectangle = Rectangle {
+            width: 3,
+            height: 2,
+        };
+        assert_eq!(rectangle.draw(), ["###", "###"]);
+        assert_eq!(rectangle.render(), "Rectangle 3x2:\n###\n###\n");
+    }
+
+    #[test]
+    fn triangle() {
+        assert_eq!(Triangle { height: 3 }.draw(), ["*", "**", "***"]);
+    }
+
+    #[test]
+    fn circle() {
+        assert_eq!(
+            Circle { radius: 4 }.draw(),
+            [" oooooo", "oooooooo", "oooooooo", " oooooo"]
+        );
+        assert!(Circle { radius: 0 }.draw().is_empty());
+    }
+
+    #[test]
+    fn scaled_keeps_the_type() {
+        assert_eq!(Triangle { height: 2 }.scaled(3), Triangle { height: 6 });
+        assert_eq!(Circle { radius: 1 }.scaled(2), Circle { radius: 2 });
+    }
+}
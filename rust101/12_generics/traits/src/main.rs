@@ -0,0 +1,171 @@
+/* Things learned during this exercise:
+   * A trait is a set of methods a type promises to have. `impl Summary for
+   Tweet` keeps the promise for Tweet. A trait method can have a default
+   body, which implementors inherit unless they write their own.
+   * There are three ways to say "any type that implements Summary":
+     * `item: &impl Summary` - short, for simple cases,
+     * `<T: Summary>(item: &T)` - a trait bound, needed when two arguments
+     must be the SAME type, or the type is used more than once,
+     * `where T: Summary + Display` - the same bounds, moved after the
+     signature so long lists of them don't bury it.
+   * `-> impl Summary` returns some type implementing Summary without saying
+   which - but it must always be the same one.
+   * A blanket impl implements a trait for every type meeting some bounds,
+   like `impl<T: Display> Summary for Wrapper<T>`.
+   * The orphan rule: you can implement a trait for a type only if the trait
+   or the type is defined in your crate. That's why `Display` can't be
+   implemented for `Vec<T>` here, but Summary can be implemented for
+   anything.
+   * See https://doc.rust-lang.org/book/ch10-02-traits.html
+*/
+mod summary;
+mod wrapper;
+
+use std::fmt::Display;
+
+use summary::{NewsArticle, Summary, Tweet};
+use wrapper::Wrapper;
+
+// `impl Trait` in argument position.
+fn notify(item: &impl Summary) -> String {
+    format!("Breaking news! {}", item.summarize())
+}
+
+// The same function with a trait bound and a where clause. Unlike
+// `notify(a: &impl Summary, b: &impl Summary)`, this forces both items to
+// be the same type.
+fn notify_both<T>(first: &T, second: &T) -> String
+where
+    T: Summary,
+{
+    format!("{} / {}", notify(first), notify(second))
+}
+
+// Two bounds on one type: `T` must be summarizable AND printable.
+fn notify_with_details<T>(item: &T) -> String
+where
+    T: Summary + Display,
+{
+    format!("{} [{item}]", notify(item))
+}
+
+fn summarize_all<T: Summary>(items: &[T]) -> Vec<String> {
+    items.iter().map(Summary::summarize).collect()
+}
+
+// The caller only knows it's getting something with `summarize`.
+fn returns_summarizable() -> impl Summary {
+    Tweet {
+        username: String::from("horse_ebooks"),
+        content: String::from("of course, as you probably already know, people"),
+        reply: false,
+        retweet: false,
+    }
+}
+
+fn main() {
+    let tweet = returns_summarizable();
+    println!("1 new tweet: {}", tweet.summarize());
+
+    let article = NewsArticle {
+        headline: String::from("Penguins win the Stanley Cup Championship!"),
+        location: String::from("Pittsburgh, PA, USA"),
+        author: String::from("Iceburgh"),
+        content: String::from(
+            "The Pittsburgh Penguins once again are the best \
+             hockey team in the NHL.",
+        ),
+    };
+    println!("New article available! {}", article.summarize());
+    println!("({} characters of content)", article.content.len());
+    println!("{}", notify(&article));
+    println!();
+
+    let tweets = [
+        Tweet {
+            username: String::from("ferris"),
+            content: String::from("Hello, world!"),
+            reply: false,
+            retweet: true,
+        },
+        Tweet {
+            username: String::from("bors"),
+            content: String::from("Test successful"),
+            reply: true,
+            retweet: false,
+        },
+    ];
+    for (tweet, summary) in tweets.iter().zip(summarize_all(&tweets)) {
+        let kind = match (tweet.reply, tweet.retweet) {
+            (true, _) => "reply",
+            (_, true) => "retweet",
+            _ => "tweet",
+        };
+        println!("{summary} - a {kind}: {:?}", tweet.content);
+    }
+    println!("{}", notify_both(&tweets[0], &tweets[1]));
+    println!();
+
+    println!("{}", notify(&Wrapper(42)));
+    println!("{:?}", summarize_all(&[Wrapper(1.5), Wrapper(2.0)]));
+    println!("{}", notify_with_details(&Headline("Rust 2024 released")));
+}
+
+// Both Summary and Display, for `notify_with_details`.
+struct Headline(&'static str);
+
+impl Summary for Headline {
+    fn summarize_author(&self) -> String {
+        "the Rust team".to_string()
+    }
+}
+
+impl Display for Headline {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notify_uses_the_summary() {
+        assert_eq!(
+            notify(&returns_summarizable()),
+            "Breaking news! (Read more from @horse_ebooks...)"
+        );
+        assert_eq!(notify(&Wrapper(7)), "Breaking news! Just 7");
+    }
+
+    #[test]
+    fn notify_both_needs_one_type() {
+        assert_eq!(
+            notify_both(&Wrapper(1), &Wrapper(2)),
+            "Breaking news! Just 1 / Breaking news! Just 2"
+        );
+        // With different types it doesn't compile:
+        //
+        //     notify_both(&Wrapper(1), &returns_summarizable());
+        //
+        // error[E0308]: mismatched types
+    }
+
+    #[test]
+    fn notify_with_details_displays_the_item() {
+        assert_eq!(
+            notify_with_details(&Headline("News")),
+            "Breaking news! (Read more from the Rust team...) [News]"
+        );
+    }
+
+    #[test]
+    fn summarize_all_keeps_the_order() {
+        assert_eq!(
+            summarize_all(&[Wrapper("a"), Wrapper("b")]),
+            ["Just a", "Just b"]
+        );
+        assert!(summarize_all::<Wrapper<i32>>(&[]).is_empty());
+    }
+}
@@ -0,0 +1,84 @@
+// The book's `Summary` trait and the two types that implement it.
+//
+// `summarize_author` has no body, so every implementor must write one.
+// `summarize` has a default body built on top of it - a type gets that for
+// free, or can override it with its own.
+
+pub trait Summary {
+    fn summarize_author(&self) -> String;
+
+    fn summarize(&self) -> String {
+        format!("(Read more from {}...)", self.summarize_author())
+    }
+}
+
+pub struct NewsArticle {
+    pub headline: String,
+    pub location: String,
+    pub author: String,
+    pub content: String,
+}
+
+// Overrides `summarize`: a headline says more than "read more".
+impl Summary for NewsArticle {
+    fn summarize_author(&self) -> String {
+        self.author.clone()
+    }
+
+    fn summarize(&self) -> String {
+        format!("{}, by {} ({})", self.headline, self.author, self.location)
+    }
+}
+
+pub struct Tweet {
+    pub username: String,
+    pub content: String,
+    pub reply: bool,
+    pub retweet: bool,
+}
+
+// Only the required method - `summarize` is inherited from the trait.
+impl Summary for Tweet {
+    fn summarize_author(&self) -> String {
+        format!("@{}", self.username)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tweet() -> Tweet {
+        Tweet {
+            username: "horse_ebooks".to_string(),
+            content: "of course, as you probably already know, people".to_string(),
+            reply: false,
+            retweet: false,
+        }
+    }
+
+    fn article() -> NewsArticle {
+        NewsArticle {
+            headline: "Penguins win the Stanley Cup Championship!".to_string(),
+            location: "Pittsburgh, PA, USA".to_string(),
+            author: "Iceburgh".to_string(),
+            content: "The Pittsburgh Penguins once again are the best hockey team in the NHL."
+                .to_string(),
+        }
+    }
+
+    #[test]
+    fn tweet_inherits_the_default_summary() {
+        assert_eq!(tweet().summarize_author(), "@horse_ebooks");
+        assert_eq!(tweet().summarize(), "(Read more from @horse_ebooks...)");
+    }
+
+    #[test]
+    fn article_overrides_the_default_summary() {
+        assert_eq!(article().summarize_author(), "Iceburgh");
+        assert_eq!(
+            article().summarize(),
+            "Penguins win the Stanley Cup Championship!, by Iceburgh (Pittsburgh, PA, USA)"
+        );
+    }
+}
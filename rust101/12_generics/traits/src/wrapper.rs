@@ -0,0 +1,48 @@
+// A blanket implementation: `Summary` for a `Wrapper` around ANY type that
+// implements Display, written once.
+//
+// The standard library does the same on a bigger scale with
+// `impl<T: Display> ToString for T`, which is why every Display type has a
+// `to_string` method. Writing `impl<T: Display> Summary for T` here would
+// work too, but then no Display type could have a Summary impl of its own -
+// giving NewsArticle a Display impl would make the two overlap - so the
+// wrapper keeps the blanket impl to types that opt in.
+use std::fmt::Display;
+
+use crate::summary::Summary;
+
+pub struct Wrapper<T>(pub T);
+
+impl<T: Display> Summary for Wrapper<T> {
+    fn summarize_author(&self) -> String {
+        "anonymous".to_string()
+    }
+
+    fn summarize(&self) -> String {
+        format!("Just {}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_to_an_i32_wrapper() {
+        assert_eq!(Wrapper(42).summarize(), "Just 42");
+        assert_eq!(Wrapper(-1i32).summarize_author(), "anonymous");
+    }
+
+    #[test]
+    fn applies_to_any_display_type() {
+        assert_eq!(Wrapper("text").summarize(), "Just text");
+        assert_eq!(Wrapper(2.5).summarize(), "Just 2.5");
+        assert_eq!(Wrapper('x').summarize(), "Just x");
+        // But not to a type that isn't Display:
+        //
+        //     Wrapper(vec![1]).summarize();
+        //
+        // error[E0599]: the method `summarize` exists for struct
+        // `Wrapper<Vec<{integer}>>`, but its trait bounds were not satisfied
+    }
+}
@@ -0,0 +1,88 @@
+// A struct that holds a reference needs a lifetime on it: an
+// `ImportantExcerpt<'a>` can't outlive the text its `part` points into.
+//
+//     struct ImportantExcerpt {
+//         part: &str,
+//     }
+//
+// error[E0106]: missing lifetime specifier
+
+#[derive(Debug)]
+pub struct ImportantExcerpt<'a> {
+    part: &'a str,
+}
+
+impl<'a> ImportantExcerpt<'a> {
+    pub fn new(part: &'a str) -> Self {
+        ImportantExcerpt { part }
+    }
+
+    // No references in the result, so no lifetimes needed.
+    pub fn level(&self) -> i32 {
+        3
+    }
+
+    // Returns `&'a str`, not `&str`: the part lives as long as the original
+    // text, not just as long as this borrow of the excerpt. So the part can
+    // be kept after the excerpt itself is gone.
+    pub fn part(&self) -> &'a str {
+        self.part
+    }
+
+    // The book's example of the third elision rule: with `&self` among the
+    // inputs, the output is given self's lifetime, so nothing needs writing
+    // out - and `announcement` is free to be a short-lived borrow.
+    pub fn announce_and_return_part(&self, announcement: &str) -> &str {
+        println!("Attention please: {announcement}");
+        self.part
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sentences::first_sentence;
+
+    #[test]
+    fn excerpt_points_into_the_source() {
+        let novel = String::from("Call me Ishmael. Some years ago...");
+        let excerpt = ImportantExcerpt::new(first_sentence(&novel));
+        assert_eq!(excerpt.part(), "Call me Ishmael.");
+        // Not a copy: the same bytes as the start of `novel`.
+        assert_eq!(excerpt.part().as_ptr(), novel.as_ptr());
+        assert_eq!(excerpt.level(), 3);
+    }
+
+    #[test]
+    fn announce_returns_the_part() {
+        let text = "Hello. World.";
+        let excerpt = ImportantExcerpt::new(&text[..6]);
+        let announcement = String::from("one moment");
+        assert_eq!(excerpt.announce_and_return_part(&announcement), "Hello.");
+    }
+
+    #[test]
+    fn part_outlives_the_excerpt() {
+        let text = String::from("Borrowed, not owned.");
+        let part = {
+            let excerpt = ImportantExcerpt::new(&text);
+            excerpt.part()
+        };
+        assert_eq!(part, "Borrowed, not owned.");
+    }
+
+    #[test]
+    fn excerpt_cannot_outlive_the_source() {
+        let excerpt;
+        {
+            let text = String::from("Short-lived.");
+            excerpt = ImportantExcerpt::new(&text);
+            assert_eq!(excerpt.part(), "Short-lived.");
+        }
+        // The excerpt is still in scope, but using it now doesn't compile:
+        //
+        //     excerpt.part();
+        //
+        // error[E0597]: `text` does not live long enough
+    }
+}
@@ -0,0 +1,107 @@
+// Functions that return one of two borrowed strings.
+//
+// The compiler can't tell from the body which argument the result comes
+// from - it could be either, depending on the values - so the signature has
+// to say: `'a` on both inputs and the output means "the result lives no
+// longer than the shorter-lived of `x` and `y`".
+//
+// Without the annotations it doesn't compile:
+//
+//     fn longest(x: &str, y: &str) -> &str {
+//
+// error[E0106]: missing lifetime specifier
+//   = help: this function's return type contains a borrowed value, but the
+//     signature does not say whether it is borrowed from `x` or `y`
+
+// The book's version. On a tie it returns `y`, as the book's does.
+pub fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    longest_by(x, y, str::len)
+}
+
+// The same, comparing by any measure. `key` only looks at the strings while
+// the function runs, so its own `&str` parameter needs no lifetime tied to
+// the result. Like `longest`, a tie goes to `b`.
+pub fn longest_by<'a, F: Fn(&str) -> usize>(a: &'a str, b: &'a str, key: F) -> &'a str {
+    if key(a) > key(b) {
+        a
+    } else {
+        b
+    }
+}
+
+// The result can only come from `x`, so only `x` needs the lifetime - `y`
+// can be a short-lived borrow.
+pub fn always_first<'a>(x: &'a str, _y: &str) -> &'a str {
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_the_longer_string() {
+        assert_eq!(longest("abcd", "xyz"), "abcd");
+        assert_eq!(longest("xyz", "abcd"), "abcd");
+    }
+
+    #[test]
+    fn equal_lengths_return_the_second() {
+        assert_eq!(longest("abc", "xyz"), "xyz");
+        assert_eq!(longest_by("abc", "xyz", str::len), "xyz");
+    }
+
+    #[test]
+    fn empty_strings() {
+        assert_eq!(longest("", "a"), "a");
+        assert_eq!(longest("a", ""), "a");
+        assert_eq!(longest("", ""), "");
+    }
+
+    #[test]
+    fn longest_by_chars_rather_than_bytes() {
+        // "héé" is 5 bytes but 3 chars.
+        assert_eq!(longest("héé", "abcd"), "héé");
+        assert_eq!(longest_by("héé", "abcd", |s| s.chars().count()), "abcd");
+    }
+
+    #[test]
+    fn longest_by_any_key() {
+        let words = |s: &str| s.split_whitespace().count();
+        assert_eq!(
+            longest_by("one two three", "a much longer sentence", words),
+            "a much longer sentence"
+        );
+        assert_eq!(longest_by("a b c", "abcdefghij", words), "a b c");
+    }
+
+    #[test]
+    fn result_borrows_from_an_input() {
+        let string1 = String::from("long string is long");
+        let result;
+        {
+            let string2 = String::from("xyz");
+            result = longest(string1.as_str(), string2.as_str());
+            assert!(std::ptr::eq(result, string1.as_str()));
+        }
+        // Using `result` here, after string2 is gone, doesn't compile - even
+        // though it's string1 that was returned, the signature says it might
+        // have been string2:
+        //
+        //     println!("The longest string is {result}");
+        //
+        // error[E0597]: `string2` does not live long enough
+    }
+
+    #[test]
+    fn always_first_outlives_the_second() {
+        let kept = String::from("kept");
+        let result;
+        {
+            let temporary = String::from("temporary");
+            result = always_first(&kept, &temporary);
+        }
+        // Fine: the result is only tied to `kept`.
+        assert_eq!(result, "kept");
+    }
+}
@@ -0,0 +1,66 @@
+/* Things learned during this exercise:
+   * Every reference has a lifetime - the part of the program where the thing
+   it points to is still alive. Usually the compiler works it out; lifetime
+   annotations like `'a` are for when it can't.
+   * Annotations don't change how long anything lives. They describe how
+   the lifetimes of references relate, so the borrow checker can check
+   callers: `fn longest<'a>(x: &'a str, y: &'a str) -> &'a str` says the
+   result is valid only while BOTH inputs are.
+   * A struct holding a reference needs a lifetime parameter, and can't
+   outlive what it refers to.
+   * The elision rules, which let most signatures skip annotations:
+     1. each reference parameter gets its own lifetime,
+     2. if there's exactly one input lifetime, outputs get it,
+     3. if one input is `&self` or `&mut self`, outputs get self's lifetime.
+   If the rules don't settle every output lifetime, it's a compile error
+   and they have to be written out.
+   * `'static` means "lives for the whole program" - string literals are
+   `&'static str`, baked into the binary.
+   * The comments in each module keep the classic examples that DON'T
+   compile, with the error they give.
+   * See https://doc.rust-lang.org/book/ch10-03-lifetime-syntax.html
+*/
+mod excerpt;
+mod longest;
+mod sentences;
+
+use excerpt::ImportantExcerpt;
+use longest::{always_first, longest, longest_by};
+use sentences::first_sentence;
+
+fn main() {
+    let string1 = String::from("abcd");
+    let string2 = "xyz";
+    println!(
+        "longest({string1:?}, {string2:?}) = {:?}",
+        longest(string1.as_str(), string2)
+    );
+
+    let by_chars = longest_by("héé", "abcd", |s| s.chars().count());
+    println!("longest_by chars(\"héé\", \"abcd\") = {by_chars:?} - \"héé\" is more bytes, but fewer chars");
+
+    // The book's lifetime example that does compile: `result` is only used
+    // while both strings are alive.
+    let string1 = String::from("long string is long");
+    {
+        let string2 = String::from("xyz");
+        let result = longest(string1.as_str(), string2.as_str());
+        println!("The longest string is {result}");
+    }
+    println!(
+        "always_first = {:?}",
+        always_first("first", &String::from("second"))
+    );
+    println!();
+
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let first = first_sentence(&novel);
+    let excerpt = ImportantExcerpt::new(first);
+    println!("{excerpt:?} has level {}", excerpt.level());
+    let part = excerpt.announce_and_return_part("the first sentence is");
+    println!("{part}");
+
+    // A string literal is `&'static str`, so this excerpt could live forever.
+    let forever: ImportantExcerpt<'static> = ImportantExcerpt::new("I have a static lifetime.");
+    println!("{}", forever.part());
+}
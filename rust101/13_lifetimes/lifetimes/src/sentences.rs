@@ -0,0 +1,54 @@
+// Lifetime elision: the compiler fills in lifetimes itself when the rules
+// leave only one sensible answer. Here there's one reference in and one
+// out, so the output must come from the input, and
+//
+//     fn first_sentence(text: &str) -> &str
+//
+// means exactly
+//
+//     fn first_sentence<'a>(text: &'a str) -> &'a str
+
+// Everything up to and including the first `.`, `!` or `?`, with leading
+// whitespace skipped. Text with no sentence ending is one unfinished
+// sentence, so all of it is returned.
+pub fn first_sentence(text: &str) -> &str {
+    let text = text.trim_start();
+    match text.find(['.', '!', '?']) {
+        // The punctuation is one byte, so `end + 1` is still a char boundary.
+        Some(end) => &text[..end + 1],
+        None => text.trim_end(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_first_sentence() {
+        assert_eq!(
+            first_sentence("Call me Ishmael. Some years ago..."),
+            "Call me Ishmael."
+        );
+        assert_eq!(first_sentence("Really? Yes."), "Really?");
+        assert_eq!(first_sentence("  Stop! Now."), "Stop!");
+    }
+
+    #[test]
+    fn unfinished_text_is_one_sentence() {
+        assert_eq!(first_sentence("no ending here  "), "no ending here");
+    }
+
+    #[test]
+    fn empty_strings() {
+        assert_eq!(first_sentence(""), "");
+        assert_eq!(first_sentence("   "), "");
+        assert_eq!(first_sentence("."), ".");
+    }
+
+    #[test]
+    fn multi_byte_text() {
+        assert_eq!(first_sentence("Café au lait. Merci."), "Café au lait.");
+        assert_eq!(first_sentence("🦀🦀!"), "🦀🦀!");
+    }
+}
@@ -0,0 +1,171 @@
+/* Things learned during this exercise:
+   * This crate is a reference for `cargo test`, using every kind of test
+   from chapter 11 of the book. The code under test is small on purpose.
+   * `cargo test` builds and runs three kinds of test:
+     * unit tests, in a `#[cfg(test)] mod tests` next to the code (they can
+     test private functions too, like `internal_adder` below),
+     * integration tests, one crate per file in `tests/`, which can only
+     use the public API - shared helpers go in `tests/common/mod.rs` so
+     Cargo doesn't treat the helpers as a test file of their own,
+     * doc tests, the code blocks in `///` and `//!` comments, so examples
+     in the documentation can't quietly go stale.
+   * A test fails if it panics. `assert!`, `assert_eq!` and `assert_ne!`
+   panic with a message, and take an optional format string to explain it.
+   * `#[should_panic(expected = "...")]` passes only if the test panics with
+   a message containing that text. A test can also return `Result<(), E>`
+   and fail by returning `Err`, which lets it use `?`.
+   * Useful flags, after a `--` so they go to the test binary, not Cargo:
+     * `cargo test -- --ignored` runs only the `#[ignore]`d tests, and
+     `-- --include-ignored` runs everything,
+     * `cargo test -- --show-output` prints what passing tests printed,
+     * `cargo test -- --test-threads=1` runs tests one at a time,
+     * `cargo test greeting` runs only tests whose name contains "greeting",
+     * `cargo test --test guessing` runs one integration test file, and
+     `cargo test --doc` only the doc tests.
+   * See https://doc.rust-lang.org/book/ch11-00-testing.html
+*/
+//! A tiny crate for practising `cargo test`.
+//!
+//! ```
+//! assert_eq!(adder::add_two(40), 42);
+//! ```
+
+/// Adds two to `a`.
+///
+/// ```
+/// use adder::add_two;
+///
+/// assert_eq!(add_two(2), 4);
+/// assert_eq!(add_two(-2), 0);
+/// ```
+pub fn add_two(a: i32) -> i32 {
+    internal_adder(a, 2)
+}
+
+// Private, but the unit tests below can still call it - they're in a child
+// module, and child modules can see their parent's private items.
+fn internal_adder(left: i32, right: i32) -> i32 {
+    left + right
+}
+
+/// A greeting for `name`.
+///
+/// ```
+/// assert_eq!(adder::greeting("Carol"), "Hello Carol!");
+/// ```
+pub fn greeting(name: &str) -> String {
+    format!("Hello {name}!")
+}
+
+/// A number from 1 to 100.
+pub struct Guess {
+    value: i32,
+}
+
+impl Guess {
+    /// # Panics
+    ///
+    /// Panics if `value` is less than 1 or greater than 100, with a message
+    /// saying which.
+    ///
+    /// ```should_panic
+    /// adder::Guess::new(0);
+    /// ```
+    pub fn new(value: i32) -> Guess {
+        if value < 1 {
+            panic!("Guess value must be greater than or equal to 1, got {value}.");
+        } else if value > 100 {
+            panic!("Guess value must be less than or equal to 100, got {value}.");
+        }
+        Guess { value }
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exploration() {
+        assert_eq!(add_two(2), 4);
+    }
+
+    #[test]
+    fn internal() {
+        // A private function, tested directly.
+        assert_eq!(internal_adder(2, 2), 4);
+        assert_eq!(internal_adder(-3, 3), 0);
+    }
+
+    // Returning a Result: the test fails if it returns Err, and the error is
+    // printed. `#[should_panic]` can't be used on tests like this one.
+    #[test]
+    fn it_works() -> Result<(), String> {
+        let result = add_two(2);
+        if result == 4 {
+            Ok(())
+        } else {
+            Err(format!("two plus two does not equal four, got {result}"))
+        }
+    }
+
+    // `?` works in a test that returns a Result.
+    #[test]
+    fn parses_then_adds() -> Result<(), std::num::ParseIntError> {
+        let number: i32 = "40".parse()?;
+        assert_eq!(add_two(number), 42);
+        Ok(())
+    }
+
+    // The custom message is what makes a failure easy to debug. Without it,
+    // a broken `greeting` that returned "Hello!" would only report
+    // "assertion failed: result.contains(\"Carol\")" - with it, the output
+    // shows what the greeting actually was.
+    #[test]
+    fn greeting_contains_name() {
+        let result = greeting("Carol");
+        assert!(
+            result.contains("Carol"),
+            "Greeting did not contain name, value was `{result}`"
+        );
+    }
+
+    #[test]
+    fn add_two_is_not_identity() {
+        assert_ne!(add_two(5), 5, "add_two should change its input");
+    }
+
+    // `expected` is matched against part of the panic message, so this test
+    // fails if `new` panics for the wrong reason - say, the "must be greater
+    // than or equal to 1" branch.
+    #[test]
+    #[should_panic(expected = "less than or equal to 100")]
+    fn greater_than_100() {
+        Guess::new(200);
+    }
+
+    #[test]
+    #[should_panic(expected = "greater than or equal to 1")]
+    fn less_than_1() {
+        Guess::new(0);
+    }
+
+    #[test]
+    fn guess_keeps_its_value() {
+        assert_eq!(Guess::new(1).value(), 1);
+        assert_eq!(Guess::new(100).value(), 100);
+    }
+
+    // Skipped by a plain `cargo test`; `cargo test -- --ignored` runs it.
+    #[test]
+    #[ignore = "checks every i32, which takes a while in debug builds"]
+    fn add_two_never_returns_its_input() {
+        for a in i32::MIN..=i32::MAX - 2 {
+            assert_ne!(add_two(a), a);
+        }
+    }
+}
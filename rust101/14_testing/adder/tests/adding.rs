@@ -0,0 +1,19 @@
+// Integration tests only see what `adder` makes public: `internal_adder`
+// isn't reachable from here.
+//
+//     adder::internal_adder(2, 2);
+//
+// error[E0603]: function `internal_adder` is private
+mod common;
+
+#[test]
+fn adds_two_to_every_sample() {
+    for input in common::sample_inputs() {
+        assert_eq!(adder::add_two(input), input + 2, "input was {input}");
+    }
+}
+
+#[test]
+fn greets_by_name() {
+    assert_eq!(adder::greeting("Ferris"), "Hello Ferris!");
+}
@@ -0,0 +1,11 @@
+// Helpers shared by the integration tests. Living in `common/mod.rs` rather
+// than `common.rs` stops Cargo compiling this as a test file of its own,
+// which would show up in the output as "running 0 tests".
+//
+// Each test file includes it with `mod common;`, so it's compiled into each
+// one separately.
+
+// Numbers to try in every test, at the edges and in the middle.
+pub fn sample_inputs() -> [i32; 5] {
+    [1, 2, 50, 99, 100]
+}
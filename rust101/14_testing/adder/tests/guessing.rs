@@ -0,0 +1,16 @@
+mod common;
+
+use adder::Guess;
+
+#[test]
+fn every_sample_is_a_valid_guess() {
+    for input in common::sample_inputs() {
+        assert_eq!(Guess::new(input).value(), input);
+    }
+}
+
+#[test]
+#[should_panic(expected = "got 101")]
+fn just_over_the_limit() {
+    Guess::new(101);
+}
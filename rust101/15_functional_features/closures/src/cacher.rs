@@ -0,0 +1,82 @@
+// The book's (first edition) memoizing `Cacher`: it holds an expensive
+// calculation and only runs it when it hasn't seen the argument before.
+//
+// The book's version stored a single `Option<u32>`, so after the first call
+// it returned the same value whatever the argument - `value(1)` then
+// `value(2)` gave 1 both times. Keying the cache on the argument in a HashMap
+// fixes that.
+use std::collections::HashMap;
+
+pub struct Cacher<T>
+where
+    T: Fn(u32) -> u32,
+{
+    calculation: T,
+    values: HashMap<u32, u32>,
+}
+
+impl<T> Cacher<T>
+where
+    T: Fn(u32) -> u32,
+{
+    pub fn new(calculation: T) -> Cacher<T> {
+        Cacher {
+            calculation,
+            values: HashMap::new(),
+        }
+    }
+
+    // Takes `&mut self` because it may add to the cache. The closure passed
+    // to `or_insert_with` is only called for a new argument - and it
+    // borrows `self.calculation` while `entry` borrows `self.values`, which
+    // is fine as they're different fields.
+    pub fn value(&mut self, arg: u32) -> u32 {
+        *self
+            .values
+            .entry(arg)
+            .or_insert_with(|| (self.calculation)(arg))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn different_arguments_get_different_values() {
+        let mut c = Cacher::new(|a| a);
+        assert_eq!(c.value(1), 1);
+        // The book's Cacher returned 1 here.
+        assert_eq!(c.value(2), 2);
+    }
+
+    #[test]
+    fn runs_at_most_once_per_argument() {
+        // A Cell lets an `Fn` closure - which only gets a shared reference to
+        // what it captures - still change the count.
+        let calls = Cell::new(0);
+        let mut c = Cacher::new(|a| {
+            calls.set(calls.get() + 1);
+            a * 10
+        });
+        for arg in [3, 3, 4, 3, 4, 5] {
+            c.value(arg);
+        }
+        assert_eq!(c.value(4), 40);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn caches_zero_and_max() {
+        let calls = Cell::new(0);
+        let mut c = Cacher::new(|a: u32| {
+            calls.set(calls.get() + 1);
+            a.wrapping_add(1)
+        });
+        assert_eq!(c.value(0), 1);
+        assert_eq!(c.value(u32::MAX), 0);
+        assert_eq!(c.value(0), 1);
+        assert_eq!(calls.get(), 2);
+    }
+}
@@ -0,0 +1,149 @@
+// The three ways a closure can capture its environment, and the three `Fn`
+// traits that follow from them.
+//
+// A closure captures each variable in the least demanding way its body
+// allows: by shared reference if it only reads it, by mutable reference if
+// it changes it, by value if it moves it out. `move` forces capture by value
+// even when a reference would do.
+//
+// Which traits a closure implements depends on what it does with what it
+// captured, not how it captured it:
+//   * `FnOnce` - can be called at least once. Every closure is FnOnce. A
+//     closure that moves a captured value OUT is only FnOnce, as the value is
+//     gone after the first call.
+//   * `FnMut` - can be called many times, may change what it captured.
+//   * `Fn` - can be called many times, even at the same time, and only reads.
+// Fn closures are also FnMut, and FnMut closures are also FnOnce.
+
+// Takes the most flexible bound a caller of `f` can give: any closure at all.
+pub fn call_once<F: FnOnce() -> String>(f: F) -> String {
+    f()
+}
+
+// Calls `f` several times, so it can't be FnOnce - but it may change state.
+pub fn call_n_times<F: FnMut() -> u32>(n: usize, mut f: F) -> Vec<u32> {
+    (0..n).map(|_| f()).collect()
+}
+
+// Only needs to read, so it can take `&F` and share the closure.
+pub fn call_twice<F: Fn(i32) -> i32>(f: &F, x: i32) -> i32 {
+    f(f(x))
+}
+
+// Returning closures. Each closure has its own unnamed type, so the return
+// type is written `impl Fn...`. `move` is required: `n` is a parameter, so
+// it's dropped when `make_adder` returns, and a closure borrowing it would
+// outlive it.
+//
+//     pub fn make_adder(n: i32) -> impl Fn(i32) -> i32 {
+//         |x| x + n
+//     }
+//
+// error[E0373]: closure may outlive the current function, but it borrows `n`
+pub fn make_adder(n: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + n
+}
+
+// The count lives inside the closure, so each counter is independent. It
+// changes the count, so it's FnMut.
+pub fn make_counter() -> impl FnMut() -> u32 {
+    let mut count = 0;
+    move || {
+        count += 1;
+        count
+    }
+}
+
+// Gives the captured String away on the first call, so it's only FnOnce.
+pub fn make_farewell(name: String) -> impl FnOnce() -> String {
+    move || {
+        let mut message = name;
+        message.insert_str(0, "Goodbye, ");
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn borrow_only_reads() {
+        let text = String::from("abc");
+        let only_borrows = || text.len();
+        // `text` is only borrowed, so it can still be read alongside.
+        assert_eq!(only_borrows(), text.len());
+        assert_eq!(only_borrows(), 3);
+    }
+
+    #[test]
+    fn mutable_borrow_changes_the_original() {
+        let mut list = vec![1, 2, 3];
+        let mut borrows_mutably = |x| list.push(x);
+        // No `println!("{list:?}")` here - the closure holds a mutable borrow
+        // until its last use:
+        //
+        // error[E0502]: cannot borrow `list` as immutable because it is also
+        // borrowed as mutable
+        borrows_mutably(4);
+        borrows_mutably(5);
+        assert_eq!(list, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn move_takes_ownership() {
+        // A String rather than an array of numbers: an array of i32 is Copy,
+        // so `move` would copy it and the original would still be usable.
+        let text = String::from("abc");
+        let owns = move || text.len();
+        // `text` was moved into the closure, so it can't be used here.
+        assert_eq!(owns(), 3);
+        // The moved-into closure can go to another thread, which a borrow
+        // couldn't: the thread might outlive `name`.
+        let name = String::from("thread");
+        let handle = std::thread::spawn(move || name.len());
+        assert_eq!(handle.join().unwrap(), 6);
+    }
+
+    #[test]
+    fn call_once_takes_any_closure() {
+        assert_eq!(
+            call_once(make_farewell("Ferris".to_string())),
+            "Goodbye, Ferris"
+        );
+        assert_eq!(call_once(|| "Fn works too".to_string()), "Fn works too");
+    }
+
+    #[test]
+    fn counters_keep_their_own_state() {
+        let mut first = make_counter();
+        let second = make_counter();
+        assert_eq!(first(), 1);
+        assert_eq!(first(), 2);
+        assert_eq!(call_n_times(3, second), [1, 2, 3]);
+        assert_eq!(call_n_times(2, &mut first), [3, 4]);
+    }
+
+    #[test]
+    fn adders_capture_their_argument() {
+        let add_five = make_adder(5);
+        let add_ten = make_adder(10);
+        assert_eq!(add_five(1), 6);
+        assert_eq!(call_twice(&add_ten, 1), 21);
+        assert_eq!(call_twice(&|x| x * 3, 2), 18);
+    }
+
+    #[test]
+    fn sort_by_key_takes_fn_mut() {
+        // sort_by_key calls the key closure many times, so it needs FnMut -
+        // and FnMut means the closure may count its calls.
+        let mut list = [5, 1, 4];
+        let mut calls = 0;
+        list.sort_by_key(|x| {
+            calls += 1;
+            *x
+        });
+        assert_eq!(list, [1, 4, 5]);
+        assert!(calls >= list.len());
+    }
+}
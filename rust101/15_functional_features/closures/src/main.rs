@@ -0,0 +1,62 @@
+/* Things learned during this exercise:
+   * Closures are anonymous functions that can capture variables from where
+   they're defined: `|x| x + n` uses `n` without it being a parameter.
+   * Parameter and return types can usually be inferred, but each closure
+   gets ONE type - calling `|x| x` with a String and then an i32 is an
+   error, as the first call fixed the type.
+   * How a closure captures (borrow, mutable borrow, or move) and which of
+   `Fn`, `FnMut` and `FnOnce` it implements are in `capture.rs`.
+   * A struct can hold a closure with a generic type and an `Fn` bound, as
+   `Cacher<T: Fn(u32) -> u32>` does to remember the results of an
+   expensive calculation.
+   * The workout example comes from the first edition of the book: a plan
+   that needs a slow calculation, which the Cacher makes run at most once.
+   * See https://doc.rust-lang.org/book/ch13-01-closures.html
+*/
+mod cacher;
+mod capture;
+mod workout;
+
+use std::thread;
+use std::time::Duration;
+
+use capture::{call_n_times, call_once, call_twice, make_adder, make_counter, make_farewell};
+use workout::generate_workout;
+
+fn main() {
+    let add_five = make_adder(5);
+    println!("make_adder(5)(10) = {}", add_five(10));
+    println!(
+        "call_twice(make_adder(5), 10) = {}",
+        call_twice(&add_five, 10)
+    );
+
+    let counter = make_counter();
+    println!(
+        "A counter called four times: {:?}",
+        call_n_times(4, counter)
+    );
+
+    let farewell = make_farewell(String::from("Ferris"));
+    println!("{}", call_once(farewell));
+    // `farewell` was moved into `call_once` and used up - and even if it
+    // hadn't been, it's only FnOnce:
+    //
+    //     farewell();
+    //
+    // error[E0382]: use of moved value: `farewell`
+    println!();
+
+    // The expensive calculation: slow, and it says when it runs.
+    let simulated_expensive_calculation = |num| {
+        println!("calculating slowly...");
+        thread::sleep(Duration::from_millis(500));
+        num
+    };
+    for (intensity, random_number) in [(10, 7), (30, 3), (30, 1)] {
+        println!("Intensity {intensity}, random number {random_number}:");
+        for line in generate_workout(intensity, random_number, simulated_expensive_calculation) {
+            println!("  {line}");
+        }
+    }
+}
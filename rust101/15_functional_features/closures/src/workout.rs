@@ -0,0 +1,72 @@
+// The book's (first edition) workout plan generator. Working out a plan
+// needs an expensive calculation, which should run only when the plan needs
+// it - and only once, even if the plan uses its result twice.
+use crate::cacher::Cacher;
+
+// Returns the plan's lines rather than printing them, so it can be tested.
+// `expensive` is passed in so the tests can count its calls, and `main`
+// can make it slow.
+pub fn generate_workout<F>(intensity: u32, random_number: u32, expensive: F) -> Vec<String>
+where
+    F: Fn(u32) -> u32,
+{
+    let mut expensive_result = Cacher::new(expensive);
+
+    if intensity < 25 {
+        vec![
+            format!("Today, do {} pushups!", expensive_result.value(intensity)),
+            format!("Next, do {} situps!", expensive_result.value(intensity)),
+        ]
+    } else if random_number == 3 {
+        // The calculation isn't needed at all here, so it never runs.
+        vec!["Take a break today! Remember to stay hydrated!".to_string()]
+    } else {
+        vec![format!(
+            "Today, run for {} minutes!",
+            expensive_result.value(intensity)
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn counting(calls: &Cell<u32>) -> impl Fn(u32) -> u32 + '_ {
+        move |num| {
+            calls.set(calls.get() + 1);
+            num
+        }
+    }
+
+    #[test]
+    fn low_intensity_calculates_once() {
+        let calls = Cell::new(0);
+        assert_eq!(
+            generate_workout(10, 7, counting(&calls)),
+            ["Today, do 10 pushups!", "Next, do 10 situps!"]
+        );
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn break_day_never_calculates() {
+        let calls = Cell::new(0);
+        assert_eq!(
+            generate_workout(30, 3, counting(&calls)),
+            ["Take a break today! Remember to stay hydrated!"]
+        );
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[test]
+    fn high_intensity_runs() {
+        let calls = Cell::new(0);
+        assert_eq!(
+            generate_workout(30, 1, counting(&calls)),
+            ["Today, run for 30 minutes!"]
+        );
+        assert_eq!(calls.get(), 1);
+    }
+}
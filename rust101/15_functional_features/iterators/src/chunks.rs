@@ -0,0 +1,118 @@
+/// Splits a slice into pieces of `size` elements, front to back. If the
+/// length isn't a multiple of `size`, the last piece has what's left over.
+///
+/// The pieces are slices of the original, so nothing is copied. This is a
+/// hand-written version of the standard library's `slice::chunks`.
+///
+/// ```
+/// use iterators::Chunks;
+///
+/// let data = [1, 2, 3, 4, 5];
+/// let chunks: Vec<&[i32]> = Chunks::new(&data, 2).collect();
+/// assert_eq!(chunks, [&[1, 2][..], &[3, 4], &[5]]);
+/// ```
+#[derive(Debug)]
+pub struct Chunks<'a, T> {
+    rest: &'a [T],
+    size: usize,
+}
+
+impl<'a, T> Chunks<'a, T> {
+    /// # Panics
+    ///
+    /// Panics if `size` is 0, as `slice::chunks` does - there's no way to
+    /// split anything into pieces of nothing.
+    pub fn new(slice: &'a [T], size: usize) -> Chunks<'a, T> {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunks { rest: slice, size }
+    }
+}
+
+impl<'a, T> Iterator for Chunks<'a, T> {
+    // `&'a [T]`, not `&[T]` tied to `&mut self`: a chunk borrows from the
+    // original slice, so it's still usable after the iterator moves on.
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (chunk, rest) = self.rest.split_at(self.size.min(self.rest.len()));
+        self.rest = rest;
+        Some(chunk)
+    }
+
+    // Exact, so `collect` can allocate the right amount up front.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.rest.len().div_ceil(self.size);
+        (remaining, Some(remaining))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn even_split() {
+        let data = [1, 2, 3, 4, 5, 6];
+        let chunks: Vec<&[i32]> = Chunks::new(&data, 3).collect();
+        assert_eq!(chunks, [&[1, 2, 3][..], &[4, 5, 6]]);
+    }
+
+    #[test]
+    fn remainder_goes_in_the_last_chunk() {
+        let data = [1, 2, 3, 4, 5, 6, 7];
+        let sizes: Vec<usize> = Chunks::new(&data, 3).map(<[i32]>::len).collect();
+        assert_eq!(sizes, [3, 3, 1]);
+    }
+
+    #[test]
+    fn size_bigger_than_the_slice() {
+        let data = ['a', 'b'];
+        let chunks: Vec<&[char]> = Chunks::new(&data, 10).collect();
+        assert_eq!(chunks, [&['a', 'b'][..]]);
+    }
+
+    #[test]
+    fn empty_slice_has_no_chunks() {
+        assert_eq!(Chunks::<u8>::new(&[], 4).next(), None);
+    }
+
+    #[test]
+    fn matches_the_standard_library() {
+        let data: Vec<u32> = (0..23).collect();
+        for size in 1..=25 {
+            assert!(
+                Chunks::new(&data, size).eq(data.chunks(size)),
+                "size {size}"
+            );
+        }
+    }
+
+    #[test]
+    fn size_hint_is_exact() {
+        let data = [0; 10];
+        let mut chunks = Chunks::new(&data, 4);
+        assert_eq!(chunks.size_hint(), (3, Some(3)));
+        chunks.next();
+        assert_eq!(chunks.size_hint(), (2, Some(2)));
+        assert_eq!(chunks.count(), 2);
+    }
+
+    #[test]
+    fn chunks_outlive_the_iterator() {
+        let data = [1, 2, 3];
+        let first = {
+            let mut chunks = Chunks::new(&data, 2);
+            chunks.next().unwrap()
+        };
+        assert_eq!(first, [1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn zero_size_panics() {
+        Chunks::new(&[1], 0);
+    }
+}
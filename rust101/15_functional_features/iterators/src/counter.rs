@@ -0,0 +1,63 @@
+/// The book's `Counter`: an iterator that counts from 1 to 5.
+///
+/// ```
+/// use iterators::Counter;
+///
+/// let counted: Vec<u32> = Counter::new().collect();
+/// assert_eq!(counted, [1, 2, 3, 4, 5]);
+///
+/// // Every adapter comes for free with `next`.
+/// let doubled: Vec<u32> = Counter::new().map(|n| n * 2).collect();
+/// assert_eq!(doubled, [2, 4, 6, 8, 10]);
+/// ```
+#[derive(Debug, Default)]
+pub struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    pub fn new() -> Counter {
+        Counter { count: 0 }
+    }
+}
+
+impl Iterator for Counter {
+    // The "associated type": what `next` hands out.
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count < 5 {
+            self.count += 1;
+            Some(self.count)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calling_next_directly() {
+        let mut counter = Counter::new();
+        assert_eq!(counter.next(), Some(1));
+        assert_eq!(counter.next(), Some(2));
+        assert_eq!(counter.next(), Some(3));
+        assert_eq!(counter.next(), Some(4));
+        assert_eq!(counter.next(), Some(5));
+        assert_eq!(counter.next(), None);
+        // And it stays finished.
+        assert_eq!(counter.next(), None);
+    }
+
+    #[test]
+    fn works_in_a_for_loop() {
+        let mut total = 0;
+        for n in Counter::new() {
+            total += n;
+        }
+        assert_eq!(total, 15);
+    }
+}
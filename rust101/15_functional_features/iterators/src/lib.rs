@@ -0,0 +1,90 @@
+/* Things learned during this exercise:
+   * An iterator is anything implementing the `Iterator` trait, which has one
+   required method: `next`, returning `Some(item)` until it runs out and
+   then `None`. Every other method - `map`, `filter`, `zip`, `sum` and
+   dozens more - is a default method built on `next`.
+   * Iterators are lazy. Adapters like `map` and `filter` only build a new
+   iterator; nothing runs until a consuming method (`sum`, `collect`, a
+   `for` loop) asks for items.
+   * `iter()` yields references, `iter_mut()` mutable references, and
+   `into_iter()` owned values.
+   * An iterator over a slice can hand out slices of it (`Chunks`), so long
+   as it says how long they live - the `'a` in `Chunks<'a, T>` ties every
+   chunk to the original slice, not to the iterator.
+   * The code is in a library so the examples in its doc comments run as
+   doc tests; `main.rs` walks through them.
+   * See https://doc.rust-lang.org/book/ch13-02-iterators.html
+*/
+mod chunks;
+mod counter;
+
+pub use chunks::Chunks;
+pub use counter::Counter;
+
+/// The book's chain: pairs each of 1 to 5 with the next one of 2 to 5, keeps
+/// the products divisible by 3, and adds them up.
+///
+/// ```
+/// assert_eq!(iterators::counter_chain(), 18);
+/// ```
+pub fn counter_chain() -> u32 {
+    Counter::new()
+        .zip(Counter::new().skip(1))
+        .map(|(a, b)| a * b)
+        .filter(|x| x % 3 == 0)
+        .sum()
+}
+
+/// The difference between each value and the one after it - one fewer than
+/// there are values. A difference too big for an i32 saturates at
+/// `i32::MAX` or `i32::MIN` instead of overflowing.
+///
+/// ```
+/// assert_eq!(iterators::pairwise_diffs(&[1, 4, 9, 16]), [3, 5, 7]);
+/// assert!(iterators::pairwise_diffs(&[7]).is_empty());
+/// ```
+pub fn pairwise_diffs(v: &[i32]) -> Vec<i32> {
+    // `zip` stops when the shorter iterator does, so pairing the values with
+    // themselves shifted by one gives exactly the neighbouring pairs.
+    v.iter()
+        .zip(v.iter().skip(1))
+        .map(|(a, b)| b.saturating_sub(*a))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counter_chain_is_18() {
+        // (1*2) + (2*3) + (3*4) + (4*5) = 2 + 6 + 12 + 20, and only 6 and 12
+        // are divisible by 3.
+        assert_eq!(counter_chain(), 18);
+    }
+
+    #[test]
+    fn pairwise_diffs_of_short_slices() {
+        assert!(pairwise_diffs(&[]).is_empty());
+        assert!(pairwise_diffs(&[1]).is_empty());
+        assert_eq!(pairwise_diffs(&[1, 1]), [0]);
+    }
+
+    #[test]
+    fn pairwise_diffs_can_go_down() {
+        assert_eq!(pairwise_diffs(&[10, 4, 4, 9]), [-6, 0, 5]);
+    }
+
+    #[test]
+    fn pairwise_diffs_match_windows() {
+        let v = [3, -8, 15, 0, 2, 2, -1];
+        let with_windows: Vec<i32> = v.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        assert_eq!(pairwise_diffs(&v), with_windows);
+    }
+
+    #[test]
+    fn pairwise_diffs_saturate() {
+        assert_eq!(pairwise_diffs(&[i32::MIN, i32::MAX]), [i32::MAX]);
+        assert_eq!(pairwise_diffs(&[i32::MAX, i32::MIN]), [i32::MIN]);
+    }
+}
@@ -0,0 +1,43 @@
+use iterators::{counter_chain, pairwise_diffs, Chunks, Counter};
+
+fn main() {
+    println!(
+        "Counter::new() counts: {:?}",
+        Counter::new().collect::<Vec<_>>()
+    );
+
+    // The chain from the book, one step at a time.
+    let zipped: Vec<(u32, u32)> = Counter::new().zip(Counter::new().skip(1)).collect();
+    println!("zipped with itself, skipping one: {zipped:?}");
+    let products: Vec<u32> = zipped.iter().map(|(a, b)| a * b).collect();
+    println!("multiplied: {products:?}");
+    let divisible: Vec<u32> = products.iter().copied().filter(|x| x % 3 == 0).collect();
+    println!("divisible by 3: {divisible:?}");
+    println!("summed: {}", counter_chain());
+    println!();
+
+    let squares = [1, 4, 9, 16, 25];
+    println!(
+        "pairwise_diffs({squares:?}) = {:?}",
+        pairwise_diffs(&squares)
+    );
+    let diffs_of_diffs = pairwise_diffs(&pairwise_diffs(&squares));
+    println!("and the differences of those: {diffs_of_diffs:?}");
+    println!();
+
+    let letters: Vec<char> = "abcdefg".chars().collect();
+    for size in [2, 3, 7] {
+        let chunks: Vec<String> = Chunks::new(&letters, size)
+            .map(|chunk| chunk.iter().collect())
+            .collect();
+        println!(
+            "{:?} in chunks of {size}: {chunks:?}",
+            letters.iter().collect::<String>()
+        );
+    }
+
+    // Nothing happens until the iterator is consumed: this closure never
+    // prints, because nothing asks the `inspect` for an item.
+    let _lazy = Counter::new().inspect(|n| println!("never printed: {n}"));
+    println!("(an adapter that was never consumed printed nothing)");
+}
@@ -0,0 +1,54 @@
+// Times the iterator and loop versions of the searches against each other:
+//
+//     cargo run --release --example timing
+//
+// A rough harness, not a benchmark suite - the numbers vary from run to run,
+// and only mean anything in a release build. `black_box` stops the compiler
+// from noticing the results are never used and skipping the work.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use minigrep::{
+    search, search_case_insensitive, search_case_insensitive_with_loop, search_with_loop,
+};
+
+const POEM: &str = include_str!("../tests/data/poem.txt");
+const COPIES: usize = 20_000;
+const RUNS: u32 = 20;
+
+type Search = for<'a> fn(&str, &'a str) -> Vec<&'a str>;
+
+// The average time for one search of `contents`.
+fn time(search: Search, query: &str, contents: &str) -> Duration {
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        black_box(search(black_box(query), black_box(contents)));
+    }
+    start.elapsed() / RUNS
+}
+
+fn main() {
+    let contents = POEM.repeat(COPIES);
+    println!(
+        "Searching {} lines, averaged over {RUNS} runs:",
+        contents.lines().count()
+    );
+
+    let searches: [(&str, Search, Search); 2] = [
+        ("search", search, search_with_loop),
+        (
+            "search_case_insensitive",
+            search_case_insensitive,
+            search_case_insensitive_with_loop,
+        ),
+    ];
+    for query in ["to", "monkey"] {
+        for (name, iterator, looped) in searches {
+            println!(
+                "  {name}({query:?}): iterator {:?}, loop {:?}",
+                time(iterator, query, &contents),
+                time(looped, query, &contents)
+            );
+        }
+    }
+}
@@ -0,0 +1,319 @@
+/* Things learned during this exercise:
+   * This is minigrep, the I/O project from chapter 12 of the book: a small
+   grep that prints the lines of a file containing a query.
+       cargo run -- [-n] QUERY FILE
+   `-n` puts the line number in front of each match, and a FILE of `-`
+   reads from stdin. Setting the `IGNORE_CASE` environment variable (to
+   anything) makes the search case-insensitive.
+   * The logic lives in `lib.rs` and `main.rs` only deals with the outside
+   world (arguments, exit codes, printing errors), so most of it can be
+   tested without running the program.
+   * `run` returns `Result<(), Box<dyn Error>>`: a box holding ANY type that
+   implements Error, so `?` can pass on an io::Error without wrapping it.
+   * `search` returns `Vec<&'a str>` - slices of `contents`, not copies - and
+   the `'a` says so: the results live as long as the text they came from.
+   * Errors go to stderr with `eprintln!`, so `minigrep x file > out.txt`
+   writes only the matches to the file.
+   * Chapter 13 comes back to minigrep with iterators:
+     * `Config::build` takes `impl Iterator<Item = String>` - `env::args()`
+     itself - and takes ownership of each String as it goes, where the
+     slice version had to `clone` them out of a borrowed Vec.
+     * `search` is one `filter` and `collect` instead of a loop pushing onto
+     a Vec. The loop versions are kept as `search_with_loop` and
+     `search_case_insensitive_with_loop`, the tests check both give the
+     same answers, and `cargo run --release --example timing` times them
+     against each other. Iterators are a "zero-cost abstraction": they
+     compile down to much the same code as the loop.
+   * See https://doc.rust-lang.org/book/ch12-00-an-io-project.html and
+   https://doc.rust-lang.org/book/ch13-03-improving-our-io-project.html
+*/
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read};
+use std::ptr;
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub query: String,
+    pub file_path: String,
+    pub ignore_case: bool,
+    pub line_numbers: bool,
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`. `-n` can go anywhere. Each String is moved out of the
+    // iterator into the Config, so nothing is cloned.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, &'static str> {
+        args.next();
+
+        let mut line_numbers = false;
+        let mut query = None;
+        let mut file_path = None;
+        for arg in args {
+            if arg == "-n" {
+                line_numbers = true;
+            } else if query.is_none() {
+                query = Some(arg);
+            } else if file_path.is_none() {
+                file_path = Some(arg);
+            } else {
+                return Err("too many arguments");
+            }
+        }
+        let (Some(query), Some(file_path)) = (query, file_path) else {
+            return Err("not enough arguments");
+        };
+
+        let ignore_case = env::var("IGNORE_CASE").is_ok();
+
+        Ok(Config {
+            query,
+            file_path,
+            ignore_case,
+            line_numbers,
+        })
+    }
+}
+
+pub fn run(config: Config) -> Result<(), Box<dyn Error>> {
+    let contents = if config.file_path == "-" {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        contents
+    } else {
+        fs::read_to_string(&config.file_path)?
+    };
+
+    let results = if config.ignore_case {
+        search_case_insensitive(&config.query, &contents)
+    } else {
+        search(&config.query, &contents)
+    };
+
+    if config.line_numbers {
+        for (number, line) in number_results(&contents, &results) {
+            println!("{number}:{line}");
+        }
+    } else {
+        for line in results {
+            println!("{line}");
+        }
+    }
+    Ok(())
+}
+
+pub fn search<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    contents
+        .lines()
+        .filter(|line| line.contains(query))
+        .collect()
+}
+
+// `to_lowercase` makes a new String, so `query` is a String here and is
+// borrowed with `&query` when passed to `contains`.
+pub fn search_case_insensitive<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let query = query.to_lowercase();
+    contents
+        .lines()
+        .filter(|line| line.to_lowercase().contains(&query))
+        .collect()
+}
+
+// The chapter 12 versions, kept to compare against.
+pub fn search_with_loop<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let mut results = Vec::new();
+
+    for line in contents.lines() {
+        if line.contains(query) {
+            results.push(line);
+        }
+    }
+
+    results
+}
+
+pub fn search_case_insensitive_with_loop<'a>(query: &str, contents: &'a str) -> Vec<&'a str> {
+    let query = query.to_lowercase();
+    let mut results = Vec::new();
+
+    for line in contents.lines() {
+        if line.to_lowercase().contains(&query) {
+            results.push(line);
+        }
+    }
+
+    results
+}
+
+// Pairs each result with its line number, counting from 1. Because the
+// results are slices of `contents` rather than copies, each one can be
+// recognised by WHERE it is (`ptr::eq` compares addresses), so two identical
+// lines still get their own numbers.
+pub fn number_results<'a>(contents: &'a str, results: &[&'a str]) -> Vec<(usize, &'a str)> {
+    let mut results = results.iter().peekable();
+    let mut numbered = Vec::new();
+
+    for (index, line) in contents.lines().enumerate() {
+        if results.next_if(|result| ptr::eq(**result, line)).is_some() {
+            numbered.push((index + 1, line));
+        }
+    }
+
+    numbered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENTS: &str = "\
+Rust:
+safe, fast, productive.
+Pick three.
+Duct tape.
+Trust me.";
+
+    fn args(line: &str) -> impl Iterator<Item = String> + '_ {
+        line.split_whitespace().map(String::from)
+    }
+
+    // Every query is run against every text, by both versions of each search.
+    const CORPUS: [&str; 5] = [
+        CONTENTS,
+        "",
+        "\n\n\n",
+        "same\nSAME\nsame\n",
+        "un été\nÉTÉ\nhiver\n  trailing spaces  \n",
+    ];
+    const QUERIES: [&str; 8] = ["", "duct", "rUsT", "same", "été", "monkey", " ", "\n"];
+
+    #[test]
+    fn case_sensitive() {
+        assert_eq!(search("duct", CONTENTS), ["safe, fast, productive."]);
+    }
+
+    #[test]
+    fn case_insensitive() {
+        assert_eq!(
+            search_case_insensitive("rUsT", CONTENTS),
+            ["Rust:", "Trust me."]
+        );
+    }
+
+    #[test]
+    fn no_matches() {
+        assert!(search("monkey", CONTENTS).is_empty());
+        assert!(search_case_insensitive("MONKEY", CONTENTS).is_empty());
+        assert!(search("anything", "").is_empty());
+    }
+
+    #[test]
+    fn empty_query_matches_every_line() {
+        assert_eq!(search("", CONTENTS).len(), 5);
+        assert_eq!(search_case_insensitive("", CONTENTS).len(), 5);
+    }
+
+    #[test]
+    fn case_insensitive_unicode() {
+        assert_eq!(search_case_insensitive("ÉTÉ", "un été\nhiver"), ["un été"]);
+    }
+
+    #[test]
+    fn numbers_results() {
+        let results = search("u", CONTENTS);
+        assert_eq!(
+            number_results(CONTENTS, &results),
+            [
+                (1, "Rust:"),
+                (2, "safe, fast, productive."),
+                (4, "Duct tape."),
+                (5, "Trust me."),
+            ]
+        );
+    }
+
+    #[test]
+    fn numbers_identical_lines_separately() {
+        let contents = "same\nother\nsame";
+        let results = search("same", contents);
+        assert_eq!(
+            number_results(contents, &results),
+            [(1, "same"), (3, "same")]
+        );
+    }
+
+    #[test]
+    fn iterators_match_loops() {
+        for contents in CORPUS {
+            for query in QUERIES {
+                assert_eq!(
+                    search(query, contents),
+                    search_with_loop(query, contents),
+                    "{query:?} in {contents:?}"
+                );
+                assert_eq!(
+                    search_case_insensitive(query, contents),
+                    search_case_insensitive_with_loop(query, contents),
+                    "{query:?} in {contents:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn iterators_match_loops_for_empty_and_missing_queries() {
+        // Spelled out, as these are the cases most likely to differ.
+        assert_eq!(search("", CONTENTS), search_with_loop("", CONTENTS));
+        assert_eq!(search("", CONTENTS).len(), 5);
+        assert!(search("monkey", CONTENTS).is_empty());
+        assert!(search_with_loop("monkey", CONTENTS).is_empty());
+        assert!(search_case_insensitive_with_loop("MONKEY", CONTENTS).is_empty());
+    }
+
+    #[test]
+    fn builds_config() {
+        let config = Config::build(args("minigrep to poem.txt")).unwrap();
+        assert_eq!(config.query, "to");
+        assert_eq!(config.file_path, "poem.txt");
+        assert!(!config.line_numbers);
+    }
+
+    #[test]
+    fn line_number_flag_can_go_anywhere() {
+        for line in [
+            "minigrep -n to poem.txt",
+            "minigrep to -n poem.txt",
+            "minigrep to poem.txt -n",
+        ] {
+            let config = Config::build(args(line)).unwrap();
+            assert!(config.line_numbers, "{line}");
+            assert_eq!(
+                (config.query.as_str(), config.file_path.as_str()),
+                ("to", "poem.txt")
+            );
+        }
+    }
+
+    #[test]
+    fn missing_arguments() {
+        assert_eq!(Config::build(args("minigrep")), Err("not enough arguments"));
+        assert_eq!(
+            Config::build(args("minigrep to")),
+            Err("not enough arguments")
+        );
+        assert_eq!(
+            Config::build(args("minigrep -n to")),
+            Err("not enough arguments")
+        );
+    }
+
+    #[test]
+    fn too_many_arguments() {
+        assert_eq!(
+            Config::build(args("minigrep to poem.txt extra")),
+            Err("too many arguments")
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use std::env;
+use std::process;
+
+use minigrep::Config;
+
+// Exit codes: 0 when the search ran (even if nothing matched), 1 if it
+// failed, and 2 for bad arguments.
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: minigrep [-n] QUERY FILE");
+        process::exit(2);
+    });
+
+    if let Err(e) = minigrep::run(config) {
+        eprintln!("Application error: {e}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,102 @@
+// Runs the real binary against `tests/data/poem.txt`.
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+fn poem() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/data/poem.txt")
+}
+
+// IGNORE_CASE is removed so a value set in the shell running the tests
+// can't change the results.
+fn minigrep() -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_minigrep"));
+    command.env_remove("IGNORE_CASE");
+    command
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).expect("output is UTF-8")
+}
+
+#[test]
+fn prints_matching_lines() {
+    let output = minigrep().arg("frog").arg(poem()).output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "How public, like a frog\n");
+}
+
+#[test]
+fn prints_several_matches_in_order() {
+    let output = minigrep().args(["body"]).arg(poem()).output().unwrap();
+    assert_eq!(
+        stdout(&output),
+        "I'm nobody! Who are you?\nAre you nobody, too?\nHow dreary to be somebody!\n"
+    );
+}
+
+#[test]
+fn line_numbers() {
+    let output = minigrep()
+        .args(["-n", "tell"])
+        .arg(poem())
+        .output()
+        .unwrap();
+    assert_eq!(
+        stdout(&output),
+        "3:Then there's a pair of us - don't tell!\n8:To tell your name the livelong day\n"
+    );
+}
+
+#[test]
+fn ignore_case_from_the_environment() {
+    let output = minigrep()
+        .env("IGNORE_CASE", "1")
+        .arg("TO")
+        .arg(poem())
+        .output()
+        .unwrap();
+    assert_eq!(stdout(&output).lines().count(), 4);
+}
+
+#[test]
+fn no_matches_is_still_success() {
+    let output = minigrep().arg("monkey").arg(poem()).output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "");
+}
+
+#[test]
+fn reads_stdin_for_a_dash() {
+    let mut child = minigrep()
+        .args(["-n", "b", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"alpha\nbeta\ngamma\nbravo\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "2:beta\n4:bravo\n");
+}
+
+#[test]
+fn missing_file_exits_with_1() {
+    let output = minigrep().args(["x", "no-such-file.txt"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Application error:"));
+    assert_eq!(stdout(&output), "");
+}
+
+#[test]
+fn missing_arguments_exit_with_2() {
+    let output = minigrep().arg("x").output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .starts_with("Problem parsing arguments: not enough arguments"));
+}
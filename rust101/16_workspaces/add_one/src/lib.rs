@@ -0,0 +1,88 @@
+//! Adds one - or, with the help of the `rand` crate, a dice roll.
+//!
+//! ```
+//! assert_eq!(add_one::add_one(41), 42);
+//! ```
+
+use rand::Rng;
+
+/// ```
+/// assert_eq!(add_one::add_one(-1), 0);
+/// ```
+///
+/// # Panics
+///
+/// If `x` is `i32::MAX`, which has nothing one above it. This is checked in
+/// release builds too, rather than wrapping round to `i32::MIN`.
+pub fn add_one(x: i32) -> i32 {
+    x.checked_add(1).expect("add_one overflowed an i32")
+}
+
+/// Adds a roll of a six-sided die. Taking the random number generator as an
+/// argument, rather than calling `rand::thread_rng()` in here, lets the
+/// tests hand in a seeded one and get the same rolls every time.
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let total = add_one::add_roll(10, &mut rng);
+/// assert!((11..=16).contains(&total));
+/// ```
+///
+/// # Panics
+///
+/// If the total doesn't fit in an i32, as `add_one` does.
+pub fn add_roll<R: Rng + ?Sized>(x: i32, rng: &mut R) -> i32 {
+    x.checked_add(rng.gen_range(1..=6))
+        .expect("add_roll overflowed an i32")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn adds_one() {
+        assert_eq!(add_one(3), 4);
+        assert_eq!(add_one(0), 1);
+        assert_eq!(add_one(i32::MAX - 1), i32::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "add_one overflowed")]
+    fn adding_one_to_the_largest_i32_panics() {
+        add_one(i32::MAX);
+    }
+
+    #[test]
+    fn rolls_stay_on_the_die() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..1_000 {
+            let roll = add_roll(0, &mut rng);
+            assert!((1..=6).contains(&roll), "rolled {roll}");
+        }
+    }
+
+    #[test]
+    fn every_face_comes_up() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut seen = [false; 6];
+        for _ in 0..1_000 {
+            seen[add_roll(-1, &mut rng) as usize] = true;
+        }
+        assert_eq!(seen, [true; 6]);
+    }
+
+    #[test]
+    fn same_seed_same_rolls() {
+        let rolls = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..20).map(|_| add_roll(0, &mut rng)).collect::<Vec<_>>()
+        };
+        assert_eq!(rolls(42), rolls(42));
+    }
+}
@@ -0,0 +1,31 @@
+//! Adds two, by adding one twice - a library depending on another member of
+//! the same workspace.
+//!
+//! ```
+//! assert_eq!(add_two::add_two(40), 42);
+//! ```
+
+/// ```
+/// assert_eq!(add_two::add_two(-2), 0);
+/// ```
+pub fn add_two(x: i32) -> i32 {
+    add_one::add_one(add_one::add_one(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_two() {
+        assert_eq!(add_two(3), 5);
+        assert_eq!(add_two(-5), -3);
+    }
+
+    #[test]
+    fn agrees_with_add_one() {
+        for x in -10..10 {
+            assert_eq!(add_two(x), add_one::add_one(x) + 1);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+/* Things learned during this exercise:
+   * A workspace is a set of packages that share one `Cargo.lock` and one
+   `target` directory. The top-level Cargo.toml has no `[package]`, just a
+   `[workspace]` listing the members.
+   * Because there's one lockfile, every member that uses `rand` uses the same
+   version of it - here add_one and adder both list `rand = "0.8.5"` and cargo
+   resolves them together. A member can't `use` a crate just because another
+   member depends on it, though: adder has to list rand itself.
+   * Members depend on each other by path (`add_one = { path = "../add_one" }`).
+   Cargo won't assume it.
+   * From the workspace root, `cargo run -p adder` picks the binary to run and
+   `cargo test` tests every member; `cargo test -p add_one` tests one.
+   * The xtask member is a binary for workspace chores. `cargo xtask test`
+   is an alias (in .cargo/config.toml) that runs every member's tests and
+   prints a summary per crate.
+   * See https://doc.rust-lang.org/book/ch14-03-cargo-workspaces.html
+*/
+use std::env;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let num = match args.as_slice() {
+        [] => 10,
+        [arg] => arg.parse().unwrap_or_else(|_| {
+            eprintln!("`{arg}` isn't a whole number.");
+            process::exit(2);
+        }),
+        _ => {
+            eprintln!("usage: adder [NUMBER]");
+            process::exit(2);
+        }
+    };
+    // The biggest thing added below is a six on the die.
+    if num > i32::MAX - 6 {
+        eprintln!("`{num}` is too big - adding up to 6 to it would overflow an i32.");
+        process::exit(2);
+    }
+
+    println!("Hello, world! {num} plus one is {}!", add_one::add_one(num));
+    println!("{num} plus two is {}!", add_two::add_two(num));
+    println!(
+        "{num} plus a dice roll is {}!",
+        add_one::add_roll(num, &mut rand::thread_rng())
+    );
+}
@@ -0,0 +1,49 @@
+use std::process::{Command, Output};
+
+fn adder(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_adder"))
+        .args(args)
+        .output()
+        .expect("failed to run adder")
+}
+
+#[test]
+fn defaults_to_ten() {
+    let output = adder(&[]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines = stdout.lines();
+    assert_eq!(lines.next(), Some("Hello, world! 10 plus one is 11!"));
+    assert_eq!(lines.next(), Some("10 plus two is 12!"));
+}
+
+#[test]
+fn adds_to_the_given_number() {
+    let output = adder(&["40"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("40 plus one is 41!"));
+    assert!(stdout.contains("40 plus two is 42!"));
+}
+
+#[test]
+fn dice_roll_is_between_one_and_six() {
+    let output = adder(&["0"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let roll_line = stdout.lines().nth(2).expect("a dice roll line");
+    let total: i32 = roll_line
+        .strip_prefix("0 plus a dice roll is ")
+        .and_then(|rest| rest.strip_suffix('!'))
+        .and_then(|total| total.parse().ok())
+        .unwrap_or_else(|| panic!("unexpected line: {roll_line}"));
+    assert!((1..=6).contains(&total));
+}
+
+#[test]
+fn rejects_bad_input() {
+    let output = adder(&["ten"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8(output.stderr).unwrap().contains("`ten`"));
+
+    assert_eq!(adder(&["1", "2"]).status.code(), Some(2));
+}
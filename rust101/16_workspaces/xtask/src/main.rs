@@ -0,0 +1,335 @@
+/* Things learned during this exercise:
+   * "xtask" is a convention rather than a cargo feature: a workspace member
+   that's a plain binary for project chores, run through an alias in
+   `.cargo/config.toml`. It needs nothing but a Rust toolchain, unlike a
+   Makefile or shell script.
+   * `cargo metadata --format-version 1` describes the workspace as JSON - its
+   members, where their manifests are, and much more - so tools don't have to
+   parse Cargo.toml themselves.
+   * When cargo runs a program it sets the `CARGO` environment variable to the
+   cargo binary it's using, so the xtask calls back into the same version.
+   * libtest ends every test binary's output (unit tests, each integration
+   test file, doc tests) with one `test result:` line, which is what the
+   summary below adds up.
+*/
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+const USAGE: &str = "usage: cargo xtask test";
+
+#[derive(Debug, PartialEq)]
+struct Member {
+    name: String,
+    dir: PathBuf,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Totals {
+    suites: usize,
+    passed: usize,
+    failed: usize,
+    ignored: usize,
+}
+
+impl Totals {
+    fn add(&mut self, other: Totals) {
+        self.suites += other.suites;
+        self.passed += other.passed;
+        self.failed += other.failed;
+        self.ignored += other.ignored;
+    }
+}
+
+struct Report {
+    name: String,
+    totals: Totals,
+    // False if cargo itself failed - a test failed, or the crate didn't build.
+    success: bool,
+}
+
+// The xtask lives one directory below the workspace root.
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask is inside the workspace")
+}
+
+fn cargo() -> Command {
+    let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    command.current_dir(workspace_root());
+    command
+}
+
+fn members_from_metadata(json: &str) -> Result<Vec<Member>, String> {
+    let metadata: serde_json::Value =
+        serde_json::from_str(json).map_err(|err| format!("bad cargo metadata: {err}"))?;
+    let packages = metadata["packages"]
+        .as_array()
+        .ok_or("cargo metadata has no packages")?;
+    packages
+        .iter()
+        .map(|package| {
+            let name = package["name"].as_str().ok_or("a package has no name")?;
+            let manifest = package["manifest_path"]
+                .as_str()
+                .ok_or("a package has no manifest_path")?;
+            let dir = Path::new(manifest)
+                .parent()
+                .ok_or("a manifest_path has no directory")?;
+            Ok(Member {
+                name: name.to_string(),
+                dir: dir.to_path_buf(),
+            })
+        })
+        .collect()
+}
+
+fn workspace_members() -> Result<Vec<Member>, String> {
+    let output = cargo()
+        .args(["metadata", "--no-deps", "--format-version", "1"])
+        .output()
+        .map_err(|err| format!("couldn't run cargo metadata: {err}"))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+    members_from_metadata(&String::from_utf8_lossy(&output.stdout))
+}
+
+// Reads a line like
+// `test result: ok. 4 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.00s`.
+fn parse_result_line(line: &str) -> Option<Totals> {
+    let (_, counts) = line.strip_prefix("test result: ")?.split_once(". ")?;
+    let mut totals = Totals {
+        suites: 1,
+        ..Totals::default()
+    };
+    for count in counts.split("; ") {
+        let Some((number, label)) = count.split_once(' ') else {
+            continue;
+        };
+        let Ok(number) = number.parse() else {
+            continue;
+        };
+        match label {
+            "passed" => totals.passed = number,
+            "failed" => totals.failed = number,
+            "ignored" => totals.ignored = number,
+            _ => {}
+        }
+    }
+    Some(totals)
+}
+
+fn summarize(test_output: &str) -> Totals {
+    let mut totals = Totals::default();
+    for line in test_output.lines() {
+        if let Some(suite) = parse_result_line(line.trim()) {
+            totals.add(suite);
+        }
+    }
+    totals
+}
+
+fn test_member(member: &Member) -> Result<Report, String> {
+    println!("testing {}...", member.name);
+    let output = cargo()
+        .args(["test", "--package", &member.name])
+        .output()
+        .map_err(|err| format!("couldn't run cargo test: {err}"))?;
+    if !output.status.success() {
+        // Pass cargo's complaints on, so a failure can be read without
+        // running the tests again by hand.
+        eprint!("{}", String::from_utf8_lossy(&output.stdout));
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+    }
+    Ok(Report {
+        name: member.name.clone(),
+        totals: summarize(&String::from_utf8_lossy(&output.stdout)),
+        success: output.status.success(),
+    })
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Totals {
+            suites,
+            passed,
+            failed,
+            ignored,
+        } = self.totals;
+        let result = if self.success { "ok" } else { "FAILED" };
+        write!(
+            f,
+            "{:<10} {suites:>6} {passed:>6} {failed:>6} {ignored:>7}  {result}",
+            self.name
+        )
+    }
+}
+
+fn print_summary(reports: &[Report]) {
+    println!();
+    println!(
+        "{:<10} {:>6} {:>6} {:>6} {:>7}  result",
+        "crate", "suites", "passed", "failed", "ignored"
+    );
+    let mut total = Report {
+        name: "total".to_string(),
+        totals: Totals::default(),
+        success: true,
+    };
+    for report in reports {
+        println!("{report}");
+        total.totals.add(report.totals);
+        total.success &= report.success;
+    }
+    println!("{}", "-".repeat(46));
+    println!("{total}");
+}
+
+// Inside a workspace cargo only reads the Cargo.lock at the root - a stray
+// one in a member directory is ignored, and that's worth a warning.
+fn check_lockfile(members: &[Member]) {
+    let root = workspace_root();
+    for member in members {
+        if member.dir != root && member.dir.join("Cargo.lock").exists() {
+            println!(
+                "warning: {} has its own Cargo.lock, which cargo ignores in a workspace",
+                member.name
+            );
+        }
+    }
+    if root.join("Cargo.lock").exists() {
+        println!(
+            "All {} members share {}.",
+            members.len(),
+            root.join("Cargo.lock").display()
+        );
+    }
+}
+
+fn run_tests() -> Result<bool, String> {
+    let members = workspace_members()?;
+    let mut reports = Vec::new();
+    for member in &members {
+        reports.push(test_member(member)?);
+    }
+    print_summary(&reports);
+    check_lockfile(&members);
+    Ok(reports.iter().all(|report| report.success))
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args != ["test"] {
+        eprintln!("{USAGE}");
+        process::exit(2);
+    }
+    match run_tests() {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(message) => {
+            eprintln!("xtask: {message}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_result_line() {
+        let line = "test result: ok. 4 passed; 1 failed; 2 ignored; 0 measured; 0 filtered out; finished in 0.00s";
+        assert_eq!(
+            parse_result_line(line),
+            Some(Totals {
+                suites: 1,
+                passed: 4,
+                failed: 1,
+                ignored: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn ignores_other_lines() {
+        assert_eq!(parse_result_line("test tests::adds_one ... ok"), None);
+        assert_eq!(parse_result_line("running 4 tests"), None);
+    }
+
+    #[test]
+    fn adds_up_every_suite() {
+        let output = "\
+running 2 tests
+test tests::a ... ok
+test tests::b ... ok
+
+test result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+
+running 1 test
+test src/lib.rs - add_one (line 3) ... ignored
+
+test result: ok. 0 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.01s
+";
+        assert_eq!(
+            summarize(output),
+            Totals {
+                suites: 2,
+                passed: 2,
+                failed: 0,
+                ignored: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn reads_members_from_metadata() {
+        let json = r#"{
+            "packages": [
+                {"name": "add_one", "manifest_path": "/ws/add_one/Cargo.toml"},
+                {"name": "adder", "manifest_path": "/ws/adder/Cargo.toml"}
+            ],
+            "workspace_root": "/ws"
+        }"#;
+        assert_eq!(
+            members_from_metadata(json),
+            Ok(vec![
+                Member {
+                    name: "add_one".to_string(),
+                    dir: PathBuf::from("/ws/add_one"),
+                },
+                Member {
+                    name: "adder".to_string(),
+                    dir: PathBuf::from("/ws/adder"),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn rejects_broken_metadata() {
+        assert!(members_from_metadata("not json").is_err());
+        assert!(members_from_metadata(r#"{"packages": [{"name": "x"}]}"#).is_err());
+    }
+
+    #[test]
+    fn formats_a_report_row() {
+        let report = Report {
+            name: "add_two".to_string(),
+            totals: Totals {
+                suites: 3,
+                passed: 5,
+                failed: 0,
+                ignored: 0,
+            },
+            success: true,
+        };
+        assert_eq!(
+            report.to_string(),
+            "add_two         3      5      0       0  ok"
+        );
+    }
+}
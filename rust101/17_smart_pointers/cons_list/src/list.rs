@@ -0,0 +1,138 @@
+use std::mem;
+
+// The book's cons list. `Box` gives `Cons` a known size - an i32 and a
+// pointer - however long the list behind the pointer is.
+#[derive(Debug)]
+pub enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+use List::{Cons, Nil};
+
+impl List {
+    // Built back to front, so each new node can point at the list so far.
+    pub fn from_vec(v: Vec<i32>) -> List {
+        v.into_iter()
+            .rev()
+            .fold(Nil, |rest, value| Cons(value, Box::new(rest)))
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter { next: self }
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Nil)
+    }
+
+    // i64, so a long list of large i32s can't overflow the total.
+    pub fn sum(&self) -> i64 {
+        self.iter().map(i64::from).sum()
+    }
+}
+
+// The obvious recursive methods - `Cons(_, rest) => 1 + rest.len()` - use one
+// stack frame per node, so a long enough list overflows the stack. The
+// iterator walks the list in a loop instead, and `len` and `sum` use it.
+pub struct Iter<'a> {
+    next: &'a List,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        match self.next {
+            Cons(value, rest) => {
+                self.next = rest;
+                Some(*value)
+            }
+            Nil => None,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a List {
+    type Item = i32;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+// Dropping has the same problem: the automatic drop of a `Cons` drops its
+// Box, which drops the next `Cons`, and so on, one frame per node. Taking
+// each tail out before its node is dropped leaves nothing to recurse into.
+// `Nil` has to return straight away: replacing it with another `Nil` would
+// only drop that one, forever.
+impl Drop for List {
+    fn drop(&mut self) {
+        let mut next = match self {
+            Cons(_, rest) => mem::replace(rest.as_mut(), Nil),
+            Nil => return,
+        };
+        while let Cons(_, rest) = &mut next {
+            next = mem::replace(rest.as_mut(), Nil);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn empty_list() {
+        let list = List::from_vec(vec![]);
+        assert!(matches!(list, Nil));
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.sum(), 0);
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    fn built_by_hand_like_the_book() {
+        let list = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.sum(), 6);
+        assert!(!list.is_empty());
+    }
+
+    #[test]
+    fn round_trips_through_the_iterator() {
+        let values = vec![5, -3, 0, 42, i32::MAX];
+        let list = List::from_vec(values.clone());
+        assert_eq!(list.iter().collect::<Vec<_>>(), values);
+        assert_eq!((&list).into_iter().count(), values.len());
+    }
+
+    #[test]
+    fn sum_does_not_overflow() {
+        let list = List::from_vec(vec![i32::MAX, i32::MAX, i32::MAX]);
+        assert_eq!(list.sum(), 3 * i32::MAX as i64);
+    }
+
+    #[test]
+    fn ten_thousand_elements_on_a_small_stack() {
+        // 64 KiB is far too little for 10,000 recursive calls, so this only
+        // passes if building, walking and dropping the list are all loops.
+        let handle = thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(|| {
+                let list = List::from_vec((1..=10_000).collect());
+                assert_eq!(list.len(), 10_000);
+                assert_eq!(list.sum(), 50_005_000);
+                assert_eq!(list.iter().last(), Some(10_000));
+            })
+            .unwrap();
+        handle.join().expect("the list overflowed the stack");
+    }
+}
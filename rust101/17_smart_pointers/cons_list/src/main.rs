@@ -0,0 +1,69 @@
+/* Things learned during this exercise:
+   * `Box<T>` puts a value on the heap and keeps only a pointer on the stack.
+   Its main use is a type whose size can't be known at compile time - like a
+   recursive type.
+   * Rust needs to know how big every type is. A `List` that contains a
+   `List` directly would need to be infinitely big, which the compiler
+   rejects (see below). Putting the inner list behind a Box makes `Cons` an
+   i32 plus a pointer, whatever the list holds.
+   * Recursion over a recursive type looks natural but uses a stack frame per
+   node, so a long list can overflow the stack - even when it's dropped. The
+   iterator and the Drop impl in list.rs walk the list in loops instead.
+   * See https://doc.rust-lang.org/book/ch15-01-box.html
+*/
+mod list;
+
+use list::List::{self, Cons, Nil};
+use std::mem;
+
+// Without the Box:
+//
+//     enum List {
+//         Cons(i32, List),
+//         Nil,
+//     }
+//
+// error[E0072]: recursive type `List` has infinite size
+//  --> src/main.rs:1:1
+//   |
+// 1 | enum List {
+//   | ^^^^^^^^^
+// 2 |     Cons(i32, List),
+//   |               ---- recursive without indirection
+//   |
+// help: insert some indirection (e.g., a `Box`, `Rc`, or `&`) to break the cycle
+//   |
+// 2 |     Cons(i32, Box<List>),
+//   |               ++++    +
+
+fn main() {
+    let b = Box::new(5);
+    println!("b = {b}, a Box holding an i32 on the heap");
+
+    let list = Cons(1, Box::new(Cons(2, Box::new(Cons(3, Box::new(Nil))))));
+    println!("built by hand: {list:?}");
+    println!(
+        "a List is {} bytes, whatever its length: an i32 and a pointer",
+        mem::size_of::<List>()
+    );
+
+    let list = List::from_vec(vec![10, 20, 30, 40]);
+    let values: Vec<i32> = list.iter().collect();
+    println!("from_vec(vec![10, 20, 30, 40]) iterates as {values:?}");
+    println!("len = {}, sum = {}", list.len(), list.sum());
+    for value in &list {
+        print!("{value} -> ");
+    }
+    println!("Nil");
+
+    let long = List::from_vec((1..=1_000_000).collect());
+    println!(
+        "a million elements: len = {}, sum = {}, and dropping it won't overflow the stack",
+        long.len(),
+        long.sum()
+    );
+    println!(
+        "empty list: is_empty = {}",
+        List::from_vec(vec![]).is_empty()
+    );
+}
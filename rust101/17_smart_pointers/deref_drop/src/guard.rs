@@ -0,0 +1,100 @@
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Creates a file when it's made and deletes it when it's dropped, so the file
+// can't outlive the code using it - even if that code returns early or
+// panics.
+#[derive(Debug)]
+pub struct TempFileGuard {
+    path: PathBuf,
+}
+
+impl TempFileGuard {
+    // Fails with `AlreadyExists` if there's a file there already. `File::create`
+    // would empty it, and then the drop would delete it - a guard should only
+    // ever clean up a file it made.
+    pub fn create(path: impl Into<PathBuf>) -> io::Result<TempFileGuard> {
+        let path = path.into();
+        File::create_new(&path)?;
+        Ok(TempFileGuard { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempFileGuard {
+    fn drop(&mut self) {
+        println!("Dropping the guard for {}", self.path.display());
+        // `drop` can't return an error, and panicking while already
+        // unwinding would abort, so a file that's already gone is ignored.
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    // Includes the process id so two test runs at once can't collide.
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("deref_drop_{}_{name}", process::id()))
+    }
+
+    #[test]
+    fn file_exists_until_the_scope_ends() {
+        let path = temp_path("scope");
+        {
+            let guard = TempFileGuard::create(&path).unwrap();
+            assert!(guard.path().exists());
+            assert!(path.exists());
+        }
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn early_drop_removes_it_immediately() {
+        let path = temp_path("early");
+        let guard = TempFileGuard::create(&path).unwrap();
+        assert!(path.exists());
+        drop(guard);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn moving_the_guard_moves_the_cleanup() {
+        let path = temp_path("moved");
+        let guards = vec![TempFileGuard::create(&path).unwrap()];
+        assert!(path.exists());
+        drop(guards);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn a_file_removed_by_hand_is_not_an_error() {
+        let path = temp_path("by_hand");
+        let guard = TempFileGuard::create(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        drop(guard);
+    }
+
+    #[test]
+    fn an_existing_file_is_left_alone() {
+        let path = temp_path("existing");
+        fs::write(&path, "keep me").unwrap();
+        let err = TempFileGuard::create(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "keep me");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn creating_in_a_missing_directory_fails() {
+        let path = temp_path("no_such_dir").join("file");
+        assert!(TempFileGuard::create(path).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+/* Things learned during this exercise:
+   * A smart pointer is a struct that acts like a reference because it
+   implements `Deref` (and `DerefMut`), and usually does something when it's
+   done because it implements `Drop`.
+   * `*y` on a type implementing Deref is `*(y.deref())`. The compiler also
+   inserts `deref` calls for us to make types line up - deref coercion - so a
+   `&MyBox<String>` can be passed where a `&str` is wanted. It happens at
+   compile time, so it costs nothing at runtime.
+   * Coercion from `&mut T` to `&U` is allowed (via Deref), but never from
+   `&T` to `&mut U`.
+   * `Drop::drop` runs automatically when a value goes out of scope.
+   Variables are dropped in the reverse order they were created.
+   * `drop` can't be called as a method (error E0040, below), because Rust
+   would then call it again at the end of the scope. `std::mem::drop(x)`
+   takes ownership of `x` instead, so it's dropped right away.
+   * See https://doc.rust-lang.org/book/ch15-02-deref.html and
+   https://doc.rust-lang.org/book/ch15-03-drop.html
+*/
+mod guard;
+mod my_box;
+
+use guard::TempFileGuard;
+use my_box::{hello, MyBox};
+use std::env;
+use std::io;
+use std::process;
+
+fn main() -> io::Result<()> {
+    let x = 5;
+    let y = MyBox::new(x);
+    println!("x = {x}, *y = {}", *y);
+
+    let mut m = MyBox::new(String::from("Rust"));
+    println!("{}", hello(&m));
+    m.push_str("acean");
+    println!("{} - push_str went through DerefMut", hello(&m));
+    println!();
+
+    let dir = env::temp_dir();
+    let id = process::id();
+    let first = TempFileGuard::create(dir.join(format!("deref_drop_{id}_first")))?;
+    let second = TempFileGuard::create(dir.join(format!("deref_drop_{id}_second")))?;
+    println!(
+        "Created {} and {}",
+        first.path().display(),
+        second.path().display()
+    );
+
+    // Calling the method directly:
+    //
+    //     first.drop();
+    //
+    // error[E0040]: explicit use of destructor method
+    //   |
+    //   |     first.drop();
+    //   |           ^^^^ explicit destructor calls not allowed
+    let early = TempFileGuard::create(dir.join(format!("deref_drop_{id}_early")))?;
+    let early_path = early.path().to_path_buf();
+    drop(early);
+    println!(
+        "After drop(early), does its file exist? {}",
+        early_path.exists()
+    );
+
+    println!("End of main - the guards are dropped in reverse order:");
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use std::ops::{Deref, DerefMut};
+
+// The book's MyBox: unlike `Box`, it keeps its value on the stack, so it's
+// only a smart pointer because of the traits below.
+#[derive(Debug)]
+pub struct MyBox<T>(T);
+
+impl<T> MyBox<T> {
+    pub fn new(x: T) -> MyBox<T> {
+        MyBox(x)
+    }
+}
+
+// `*my_box` becomes `*(my_box.deref())`: deref hands out a reference, and the
+// outer `*` follows it. Returning the value itself would move it out.
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+// Lets `*my_box = ...` assign, and `&mut MyBox<String>` coerce to `&mut String`.
+impl<T> DerefMut for MyBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+pub fn hello(name: &str) -> String {
+    format!("Hello, {name}!")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derefs_to_the_value() {
+        let x = 5;
+        let y = MyBox::new(x);
+        assert_eq!(5, x);
+        assert_eq!(5, *y);
+    }
+
+    #[test]
+    fn coerces_through_my_box_string() {
+        let m = MyBox::new(String::from("Rust"));
+        // &MyBox<String> -> &String -> &str, all decided at compile time.
+        assert_eq!(hello(&m), "Hello, Rust!");
+        // What we'd have to write without deref coercion.
+        assert_eq!(hello(&(*m)[..]), "Hello, Rust!");
+    }
+
+    #[test]
+    fn methods_are_found_through_deref() {
+        let m = MyBox::new(String::from("Rust"));
+        assert_eq!(m.len(), 4);
+        assert!(m.starts_with('R'));
+    }
+
+    #[test]
+    fn deref_mut_writes_through() {
+        let mut m = MyBox::new(String::from("Hello"));
+        m.push_str(", world");
+        assert_eq!(*m, "Hello, world");
+        *m = String::from("replaced");
+        assert_eq!(hello(&m), "Hello, replaced!");
+    }
+}
@@ -0,0 +1,246 @@
+/* Things learned during this exercise:
+   * `RefCell<T>` moves the borrowing rules from compile time to run time.
+   `borrow()` and `borrow_mut()` hand out `Ref` and `RefMut` guards, and the
+   RefCell counts them: any number of `Ref`s or one `RefMut`, just like `&`
+   and `&mut`. Breaking the rule is a panic instead of a compile error.
+   * That's "interior mutability": changing a value through a shared
+   reference. The `Messenger` trait's `send` takes `&self`, so a mock that
+   wants to record what it was sent needs a RefCell around its Vec.
+   * Holding a `RefMut` while calling something that borrows the same RefCell
+   again is the classic bug - see `BufferedMessenger::send` and the tests.
+   `try_borrow_mut` reports it as an Err instead of panicking.
+   * See https://doc.rust-lang.org/book/ch15-05-interior-mutability.html
+*/
+mod messengers;
+
+pub use messengers::{BufferedMessenger, StdoutMessenger};
+
+pub trait Messenger {
+    fn send(&self, msg: &str);
+}
+
+pub struct LimitTracker<'a, T: Messenger> {
+    messenger: &'a T,
+    value: usize,
+    max: usize,
+}
+
+impl<'a, T> LimitTracker<'a, T>
+where
+    T: Messenger,
+{
+    pub fn new(messenger: &'a T, max: usize) -> LimitTracker<'a, T> {
+        LimitTracker {
+            messenger,
+            value: 0,
+            max,
+        }
+    }
+
+    pub fn value(&self) -> usize {
+        self.value
+    }
+
+    // The book divides as f64s. Comparing `value * 100` with `max * percent`
+    // in whole numbers does the same without rounding at the boundaries, and
+    // u128 can't overflow for any pair of usizes. A limit of 0 is always
+    // exceeded.
+    pub fn set_value(&mut self, value: usize) {
+        self.value = value;
+
+        let reached = |percent: u128| value as u128 * 100 >= self.max as u128 * percent;
+        if reached(100) {
+            self.messenger.send("Error: You are over your quota!");
+        } else if reached(90) {
+            self.messenger
+                .send("Urgent warning: You've used up over 90% of your quota!");
+        } else if reached(75) {
+            self.messenger
+                .send("Warning: You've used up over 75% of your quota!");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::panic;
+
+    struct MockMessenger {
+        sent_messages: RefCell<Vec<String>>,
+    }
+
+    impl MockMessenger {
+        fn new() -> MockMessenger {
+            MockMessenger {
+                sent_messages: RefCell::new(vec![]),
+            }
+        }
+    }
+
+    impl Messenger for MockMessenger {
+        fn send(&self, message: &str) {
+            self.sent_messages.borrow_mut().push(String::from(message));
+        }
+    }
+
+    // Sets one value against a limit of 100 and returns what was sent.
+    fn messages_at(value: usize) -> Vec<String> {
+        let mock_messenger = MockMessenger::new();
+        let mut limit_tracker = LimitTracker::new(&mock_messenger, 100);
+        limit_tracker.set_value(value);
+        mock_messenger.sent_messages.take()
+    }
+
+    #[test]
+    fn nothing_below_75_percent() {
+        assert!(messages_at(0).is_empty());
+        assert!(messages_at(74).is_empty());
+    }
+
+    #[test]
+    fn it_sends_an_over_75_percent_warning_message() {
+        assert_eq!(
+            messages_at(75),
+            ["Warning: You've used up over 75% of your quota!"]
+        );
+        assert_eq!(messages_at(89).len(), 1);
+    }
+
+    #[test]
+    fn urgent_from_90_percent() {
+        assert_eq!(
+            messages_at(90),
+            ["Urgent warning: You've used up over 90% of your quota!"]
+        );
+        assert_eq!(messages_at(99), messages_at(90));
+    }
+
+    #[test]
+    fn error_at_and_over_100_percent() {
+        let error = ["Error: You are over your quota!"];
+        assert_eq!(messages_at(100), error);
+        assert_eq!(messages_at(101), error);
+        assert_eq!(messages_at(usize::MAX), error);
+    }
+
+    #[test]
+    fn boundaries_are_exact_for_awkward_limits() {
+        // 75% of 7 is 5.25, so 5 is just under and 6 is over.
+        let mock_messenger = MockMessenger::new();
+        let mut limit_tracker = LimitTracker::new(&mock_messenger, 7);
+        limit_tracker.set_value(5);
+        assert!(mock_messenger.sent_messages.borrow().is_empty());
+        limit_tracker.set_value(6);
+        assert_eq!(mock_messenger.sent_messages.borrow().len(), 1);
+        assert_eq!(limit_tracker.value(), 6);
+    }
+
+    #[test]
+    fn a_zero_limit_is_always_exceeded() {
+        let mock_messenger = MockMessenger::new();
+        let mut limit_tracker = LimitTracker::new(&mock_messenger, 0);
+        limit_tracker.set_value(0);
+        assert_eq!(
+            *mock_messenger.sent_messages.borrow(),
+            ["Error: You are over your quota!"]
+        );
+    }
+
+    #[test]
+    fn one_message_per_set_value() {
+        let mock_messenger = MockMessenger::new();
+        let mut limit_tracker = LimitTracker::new(&mock_messenger, 100);
+        for value in [80, 95, 100, 20] {
+            limit_tracker.set_value(value);
+        }
+        assert_eq!(mock_messenger.sent_messages.borrow().len(), 3);
+    }
+
+    // The book's deliberately broken mock: two `borrow_mut`s alive at once.
+    // It compiles fine - RefCell only finds out when `send` runs.
+    struct DoubleBorrowMessenger {
+        sent_messages: RefCell<Vec<String>>,
+    }
+
+    impl Messenger for DoubleBorrowMessenger {
+        fn send(&self, message: &str) {
+            let mut one_borrow = self.sent_messages.borrow_mut();
+            let mut two_borrow = self.sent_messages.borrow_mut();
+
+            one_borrow.push(String::from(message));
+            two_borrow.push(String::from(message));
+        }
+    }
+
+    #[test]
+    fn double_borrow_panics_at_run_time() {
+        let result = panic::catch_unwind(|| {
+            let messenger = DoubleBorrowMessenger {
+                sent_messages: RefCell::new(vec![]),
+            };
+            LimitTracker::new(&messenger, 100).set_value(80);
+        });
+        assert!(result.is_err(), "the second borrow_mut should panic");
+    }
+
+    #[test]
+    fn try_borrow_mut_reports_it_instead() {
+        let cell = RefCell::new(vec![1]);
+        let reading = cell.borrow();
+        assert!(cell.try_borrow_mut().is_err());
+        assert!(cell.try_borrow().is_ok(), "sharing is still fine");
+        drop(reading);
+        cell.try_borrow_mut().expect("free again").push(2);
+        assert_eq!(cell.into_inner(), [1, 2]);
+    }
+
+    #[test]
+    fn buffered_messages_arrive_in_batches() {
+        let buffered = BufferedMessenger::new(MockMessenger::new(), 2);
+        let mut limit_tracker = LimitTracker::new(&buffered, 100);
+
+        limit_tracker.set_value(80);
+        assert_eq!(buffered.pending(), 1);
+        assert!(buffered.inner().sent_messages.borrow().is_empty());
+
+        limit_tracker.set_value(95);
+        assert_eq!(buffered.pending(), 0);
+        assert_eq!(
+            *buffered.inner().sent_messages.borrow(),
+            ["Warning: You've used up over 75% of your quota!\n\
+              Urgent warning: You've used up over 90% of your quota!"]
+        );
+    }
+
+    #[test]
+    fn flush_sends_a_partial_batch() {
+        let buffered = BufferedMessenger::new(MockMessenger::new(), 10);
+        buffered.send("one");
+        buffered.send("two");
+        buffered.flush();
+        assert_eq!(*buffered.inner().sent_messages.borrow(), ["one\ntwo"]);
+
+        // Nothing left, so nothing more to send.
+        buffered.flush();
+        assert_eq!(buffered.inner().sent_messages.borrow().len(), 1);
+    }
+
+    #[test]
+    fn dropping_flushes_what_is_left() {
+        let log = RefCell::new(vec![]);
+        struct Recorder<'a>(&'a RefCell<Vec<String>>);
+        impl Messenger for Recorder<'_> {
+            fn send(&self, message: &str) {
+                self.0.borrow_mut().push(message.to_string());
+            }
+        }
+
+        let buffered = BufferedMessenger::new(Recorder(&log), 3);
+        buffered.send("last words");
+        assert!(log.borrow().is_empty());
+        drop(buffered);
+        assert_eq!(*log.borrow(), ["last words"]);
+    }
+}
@@ -0,0 +1,21 @@
+use limit_tracker::{BufferedMessenger, LimitTracker, StdoutMessenger};
+
+fn main() {
+    println!("A quota of 200, reporting straight to stdout:");
+    let messenger = StdoutMessenger;
+    let mut tracker = LimitTracker::new(&messenger, 200);
+    for value in [100, 150, 185, 200, 250] {
+        println!("- set to {value}");
+        tracker.set_value(value);
+    }
+    println!();
+
+    println!("The same, batched two messages at a time:");
+    let buffered = BufferedMessenger::new(StdoutMessenger, 2);
+    let mut tracker = LimitTracker::new(&buffered, 200);
+    for value in [100, 150, 185, 200] {
+        tracker.set_value(value);
+        println!("- set to {value} ({} waiting)", buffered.pending());
+    }
+    println!("- dropping the messenger flushes the rest:");
+}
@@ -0,0 +1,77 @@
+use std::cell::RefCell;
+
+use crate::Messenger;
+
+pub struct StdoutMessenger;
+
+impl Messenger for StdoutMessenger {
+    fn send(&self, msg: &str) {
+        println!("{msg}");
+    }
+}
+
+// Holds messages back until `batch_size` have arrived, then passes them on
+// to the inner messenger as one, separated by newlines. Whatever's left is
+// sent by `flush` or when the BufferedMessenger is dropped.
+pub struct BufferedMessenger<M: Messenger> {
+    inner: M,
+    batch_size: usize,
+    pending: RefCell<Vec<String>>,
+}
+
+impl<M: Messenger> BufferedMessenger<M> {
+    pub fn new(inner: M, batch_size: usize) -> BufferedMessenger<M> {
+        BufferedMessenger {
+            inner,
+            batch_size,
+            pending: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    pub fn pending(&self) -> usize {
+        self.pending.borrow().len()
+    }
+
+    pub fn flush(&self) {
+        // `take` swaps in an empty Vec, so the RefMut is gone before the
+        // inner messenger is called.
+        let batch = self.pending.take();
+        if !batch.is_empty() {
+            self.inner.send(&batch.join("\n"));
+        }
+    }
+}
+
+impl<M: Messenger> Messenger for BufferedMessenger<M> {
+    fn send(&self, msg: &str) {
+        // The RefMut has to be dropped before `flush` borrows `pending`
+        // again. Written as
+        //
+        //     let mut pending = self.pending.borrow_mut();
+        //     pending.push(msg.to_string());
+        //     if pending.len() >= self.batch_size {
+        //         self.flush();
+        //     }
+        //
+        // it compiles, then panics with "already borrowed" on the first full
+        // batch - `pending` lives to the end of the function.
+        let full = {
+            let mut pending = self.pending.borrow_mut();
+            pending.push(msg.to_string());
+            pending.len() >= self.batch_size
+        };
+        if full {
+            self.flush();
+        }
+    }
+}
+
+impl<M: Messenger> Drop for BufferedMessenger<M> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
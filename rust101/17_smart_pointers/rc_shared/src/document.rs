@@ -0,0 +1,116 @@
+use std::rc::Rc;
+
+#[derive(Debug, PartialEq)]
+pub struct Document {
+    pub title: String,
+    pub body: String,
+}
+
+impl Document {
+    pub fn new(title: &str, body: &str) -> Document {
+        Document {
+            title: title.to_string(),
+            body: body.to_string(),
+        }
+    }
+}
+
+// Several views of one document - an outline, a word count - each keeping
+// it alive. None of them owns it outright, so none can change it: `Rc` only
+// hands out shared references.
+#[derive(Debug)]
+pub struct View {
+    pub name: String,
+    document: Rc<Document>,
+}
+
+impl View {
+    pub fn new(name: &str, document: &Rc<Document>) -> View {
+        View {
+            name: name.to_string(),
+            document: Rc::clone(document),
+        }
+    }
+
+    pub fn render(&self) -> String {
+        format!(
+            "[{}] {}: {} words",
+            self.name,
+            self.document.title,
+            self.document.body.split_whitespace().count()
+        )
+    }
+
+    // The same document, not just an equal one: compares the pointers.
+    pub fn shares_document_with(&self, other: &View) -> bool {
+        Rc::ptr_eq(&self.document, &other.document)
+    }
+}
+
+// Every Rc pointing at the document counts, including the caller's own, so
+// the views are all but one of them.
+pub fn view_count(document: &Rc<Document>) -> usize {
+    Rc::strong_count(document) - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Rc<Document> {
+        Rc::new(Document::new("Notes", "Rc counts its owners"))
+    }
+
+    #[test]
+    fn counts_views_as_they_come_and_go() {
+        let document = sample();
+        assert_eq!(Rc::strong_count(&document), 1);
+        assert_eq!(view_count(&document), 0);
+
+        let outline = View::new("outline", &document);
+        let word_count = View::new("word count", &document);
+        assert_eq!(Rc::strong_count(&document), 3);
+        assert_eq!(view_count(&document), 2);
+
+        drop(outline);
+        assert_eq!(view_count(&document), 1);
+        drop(word_count);
+        assert_eq!(view_count(&document), 0);
+    }
+
+    #[test]
+    fn renders_through_the_shared_document() {
+        let document = sample();
+        let view = View::new("outline", &document);
+        assert_eq!(view.render(), "[outline] Notes: 4 words");
+    }
+
+    #[test]
+    fn try_unwrap_fails_while_views_exist() {
+        let document = sample();
+        let view = View::new("outline", &document);
+
+        // The Err gives the Rc back, so nothing is lost by trying.
+        let document = Rc::try_unwrap(document).unwrap_err();
+        assert_eq!(Rc::strong_count(&document), 2);
+
+        drop(view);
+        let owned = Rc::try_unwrap(document).expect("the last Rc");
+        assert_eq!(owned, Document::new("Notes", "Rc counts its owners"));
+    }
+
+    #[test]
+    fn ptr_eq_tells_clones_from_equal_copies() {
+        let document = sample();
+        let lookalike = sample();
+        assert_eq!(document, lookalike);
+        assert!(!Rc::ptr_eq(&document, &lookalike));
+        assert!(Rc::ptr_eq(&document, &Rc::clone(&document)));
+
+        let a = View::new("a", &document);
+        let b = View::new("b", &document);
+        let c = View::new("c", &lookalike);
+        assert!(a.shares_document_with(&b));
+        assert!(!a.shares_document_with(&c));
+    }
+}
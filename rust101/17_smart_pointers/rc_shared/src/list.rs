@@ -0,0 +1,75 @@
+use std::rc::Rc;
+
+// The book's shared cons list: `b` and `c` both start with `a`, which a
+// `Box` can't do - `a` would be moved into `b`, and then can't go into `c`.
+//
+//     let a = Cons(5, Box::new(Cons(10, Box::new(Nil))));
+//     let b = Cons(3, Box::new(a));
+//     let c = Cons(4, Box::new(a));
+//
+// error[E0382]: use of moved value: `a`
+#[derive(Debug)]
+pub enum List {
+    Cons(i32, Rc<List>),
+    Nil,
+}
+
+use List::{Cons, Nil};
+
+impl List {
+    pub fn values(&self) -> Vec<i32> {
+        let mut values = Vec::new();
+        let mut next = self;
+        while let Cons(value, rest) = next {
+            values.push(*value);
+            next = rest;
+        }
+        values
+    }
+}
+
+// Runs the book's example and records `a`'s strong count at each step, so
+// `main` can print them and the tests can check them.
+pub fn shared_list_counts() -> Vec<(&'static str, usize)> {
+    let mut counts = Vec::new();
+    let a = Rc::new(Cons(5, Rc::new(Cons(10, Rc::new(Nil)))));
+    counts.push(("after creating a", Rc::strong_count(&a)));
+    // `Rc::clone` rather than `a.clone()`, by convention: it makes clear this
+    // only bumps a count, rather than deep-copying the list.
+    let b = Cons(3, Rc::clone(&a));
+    counts.push(("after creating b", Rc::strong_count(&a)));
+    {
+        let c = Cons(4, Rc::clone(&a));
+        counts.push(("after creating c", Rc::strong_count(&a)));
+        assert_eq!(c.values(), [4, 5, 10]);
+    }
+    counts.push(("after c goes out of scope", Rc::strong_count(&a)));
+    assert_eq!(b.values(), [3, 5, 10]);
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_match_the_book() {
+        let counts: Vec<usize> = shared_list_counts()
+            .into_iter()
+            .map(|(_, count)| count)
+            .collect();
+        assert_eq!(counts, [1, 2, 3, 2]);
+    }
+
+    #[test]
+    fn shared_tails_are_the_same_list() {
+        let a = Rc::new(Cons(5, Rc::new(Nil)));
+        let b = Cons(3, Rc::clone(&a));
+        let c = Cons(4, Rc::clone(&a));
+        let (Cons(_, b_tail), Cons(_, c_tail)) = (&b, &c) else {
+            unreachable!("both were built with Cons");
+        };
+        assert!(Rc::ptr_eq(b_tail, c_tail));
+        assert_eq!(Rc::strong_count(&a), 3);
+    }
+}
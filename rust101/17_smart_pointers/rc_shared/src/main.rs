@@ -0,0 +1,70 @@
+/* Things learned during this exercise:
+   * `Rc<T>` ("reference counted") lets one value have several owners. Each
+   `Rc::clone` adds one to the strong count and each drop takes one away;
+   when it reaches zero the value is cleaned up.
+   * `Rc::clone(&a)` doesn't copy the data, it only increments the count -
+   which is why it's written that way rather than `a.clone()`, to stand out
+   from deep copies when reading the code.
+   * `Rc` is for single-threaded code only, and only hands out shared
+   references, so the shared value can't be changed (RefCell is next).
+   * `Rc::try_unwrap` gets the value back out if this is the last Rc, and
+   otherwise returns the Rc unharmed. `Rc::ptr_eq` says whether two Rcs point
+   at the same value, which `==` can't - it compares the values themselves.
+   * See https://doc.rust-lang.org/book/ch15-04-rc.html
+*/
+mod document;
+mod list;
+
+use document::{view_count, Document, View};
+use std::rc::Rc;
+
+fn main() {
+    for (step, count) in list::shared_list_counts() {
+        println!("count {step} = {count}");
+    }
+    println!();
+
+    let document = Rc::new(Document::new(
+        "Shopping",
+        "eggs flour milk and something for dinner",
+    ));
+    println!("views of {:?}: {}", document.title, view_count(&document));
+    let views = [
+        View::new("outline", &document),
+        View::new("word count", &document),
+        View::new("preview", &document),
+    ];
+    for view in &views {
+        println!("{}", view.render());
+    }
+    println!(
+        "views of {:?}: {} (strong count {})",
+        document.title,
+        view_count(&document),
+        Rc::strong_count(&document)
+    );
+
+    let copy = View::new("copy", &Rc::new(Document::new("Shopping", &document.body)));
+    println!(
+        "outline and preview share a document: {}",
+        views[0].shares_document_with(&views[2])
+    );
+    println!(
+        "outline and {} share a document: {}, though the documents are equal",
+        copy.name,
+        views[0].shares_document_with(&copy)
+    );
+
+    let document = match Rc::try_unwrap(document) {
+        Ok(_) => unreachable!("the views still hold it"),
+        Err(document) => {
+            println!("try_unwrap with views alive: Err - it's still shared");
+            document
+        }
+    };
+    drop(views);
+    match Rc::try_unwrap(document) {
+        Ok(document) => println!("try_unwrap after dropping them: Ok({document:?})"),
+        Err(_) => unreachable!("no views are left"),
+    }
+}
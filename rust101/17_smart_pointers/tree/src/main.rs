@@ -0,0 +1,74 @@
+/* Things learned during this exercise:
+   * Rc alone can build a reference cycle - two values owning each other -
+   and then neither count ever reaches zero, so the memory leaks. Rust's
+   guarantees don't cover leaks.
+   * `Rc::downgrade` makes a `Weak<T>`: a pointer that doesn't own the value.
+   It adds to the weak count, which doesn't keep the value alive.
+   `weak.upgrade()` returns `Some(Rc)` while the value exists and `None`
+   once it's gone.
+   * In a tree, parents own children and children point back weakly, so
+   dropping a parent frees the children no one else holds.
+   * `RefCell` around `parent` and `children` is what lets a node that's
+   already shared (inside an Rc) have its links changed.
+   * See https://doc.rust-lang.org/book/ch15-06-reference-cycles.html
+*/
+mod node;
+
+use node::{collect_values, depth, Node};
+use std::rc::Rc;
+
+fn counts(name: &str, node: &Rc<Node>) {
+    println!(
+        "{name} strong = {}, weak = {}",
+        Rc::strong_count(node),
+        Rc::weak_count(node)
+    );
+}
+
+fn main() {
+    let leaf = Node::new(3);
+    counts("leaf", &leaf);
+    println!(
+        "leaf parent = {:?}",
+        leaf.parent().map(|parent| parent.value)
+    );
+
+    {
+        let branch = Node::new(5);
+        Node::add_child(&branch, Rc::clone(&leaf));
+        println!(
+            "leaf parent = {:?}",
+            leaf.parent().map(|parent| parent.value)
+        );
+        counts("branch", &branch);
+        counts("leaf", &leaf);
+        println!("depth of leaf = {}", depth(&leaf));
+    }
+
+    println!("branch has gone out of scope");
+    println!(
+        "leaf parent = {:?}",
+        leaf.parent().map(|parent| parent.value)
+    );
+    counts("leaf", &leaf);
+    println!();
+
+    let root = Node::new(1);
+    let middle = Node::new(2);
+    Node::add_child(&middle, Node::new(4));
+    Node::add_child(&middle, Node::new(5));
+    Node::add_child(&root, Rc::clone(&middle));
+    Node::add_child(&root, Node::new(3));
+    println!("depth first from the root: {:?}", collect_values(&root));
+    for child in middle.children() {
+        println!("{} is at depth {}", child.value, depth(&child));
+    }
+
+    let grandchild = Rc::downgrade(&middle.children()[0]);
+    drop(middle);
+    drop(root);
+    println!(
+        "after dropping the root, can the grandchild be upgraded? {}",
+        grandchild.upgrade().is_some()
+    );
+}
@@ -0,0 +1,137 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// The book's tree node. A parent owns its children (strong `Rc`s), but a
+// child only refers to its parent (a `Weak`): if both directions were
+// strong, parent and child would keep each other alive forever.
+#[derive(Debug)]
+pub struct Node {
+    pub value: i32,
+    parent: RefCell<Weak<Node>>,
+    children: RefCell<Vec<Rc<Node>>>,
+}
+
+impl Node {
+    pub fn new(value: i32) -> Rc<Node> {
+        Rc::new(Node {
+            value,
+            parent: RefCell::new(Weak::new()),
+            children: RefCell::new(vec![]),
+        })
+    }
+
+    // An associated function rather than a method, because it needs the
+    // parent's `Rc` - not just `&self` - to make a Weak pointing back at it.
+    pub fn add_child(parent: &Rc<Node>, child: Rc<Node>) {
+        *child.parent.borrow_mut() = Rc::downgrade(parent);
+        parent.children.borrow_mut().push(child);
+    }
+
+    // `None` for a root, and for a node whose parent has been dropped.
+    pub fn parent(&self) -> Option<Rc<Node>> {
+        self.parent.borrow().upgrade()
+    }
+
+    pub fn children(&self) -> Vec<Rc<Node>> {
+        self.children.borrow().clone()
+    }
+}
+
+// How many parents there are above `node`: 0 for the root.
+pub fn depth(node: &Rc<Node>) -> usize {
+    let mut depth = 0;
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        depth += 1;
+        current = parent.parent();
+    }
+    depth
+}
+
+// Depth first, each node before its children and the children in the order
+// they were added. An explicit stack instead of recursion, so a very deep
+// tree can't overflow the call stack.
+pub fn collect_values(root: &Rc<Node>) -> Vec<i32> {
+    let mut values = Vec::new();
+    let mut stack = vec![Rc::clone(root)];
+    while let Some(node) = stack.pop() {
+        values.push(node.value);
+        // Reversed, so the first child is on top and comes out next.
+        stack.extend(node.children.borrow().iter().rev().cloned());
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1
+    // +-- 2
+    // |   +-- 4
+    // |   +-- 5
+    // +-- 3
+    //     +-- 6
+    fn three_levels() -> (Rc<Node>, Rc<Node>) {
+        let root = Node::new(1);
+        let two = Node::new(2);
+        let three = Node::new(3);
+        let six = Node::new(6);
+        Node::add_child(&two, Node::new(4));
+        Node::add_child(&two, Node::new(5));
+        Node::add_child(&three, Rc::clone(&six));
+        Node::add_child(&root, two);
+        Node::add_child(&root, three);
+        (root, six)
+    }
+
+    #[test]
+    fn depth_walks_up_to_the_root() {
+        let (root, six) = three_levels();
+        assert_eq!(depth(&root), 0);
+        assert_eq!(depth(&root.children()[0]), 1);
+        assert_eq!(depth(&six), 2);
+        assert_eq!(six.parent().map(|parent| parent.value), Some(3));
+    }
+
+    #[test]
+    fn values_come_out_depth_first() {
+        let (root, six) = three_levels();
+        assert_eq!(collect_values(&root), [1, 2, 4, 5, 3, 6]);
+        assert_eq!(collect_values(&six), [6]);
+    }
+
+    #[test]
+    fn counts_like_the_book() {
+        let leaf = Node::new(3);
+        assert_eq!((Rc::strong_count(&leaf), Rc::weak_count(&leaf)), (1, 0));
+
+        let branch = Node::new(5);
+        Node::add_child(&branch, Rc::clone(&leaf));
+        assert_eq!((Rc::strong_count(&branch), Rc::weak_count(&branch)), (1, 1));
+        assert_eq!((Rc::strong_count(&leaf), Rc::weak_count(&leaf)), (2, 0));
+
+        drop(branch);
+        assert!(leaf.parent().is_none());
+        assert_eq!((Rc::strong_count(&leaf), Rc::weak_count(&leaf)), (1, 0));
+    }
+
+    #[test]
+    fn dropping_the_root_frees_unowned_children() {
+        let (root, six) = three_levels();
+        let two = Rc::downgrade(&root.children()[0]);
+        let four = Rc::downgrade(&two.upgrade().unwrap().children()[0]);
+        let root_weak = Rc::downgrade(&root);
+
+        drop(root);
+        assert!(root_weak.upgrade().is_none());
+        assert!(two.upgrade().is_none());
+        assert!(four.upgrade().is_none());
+
+        // 6 is still owned from outside the tree, so it lives on - an orphan.
+        assert_eq!(six.value, 6);
+        assert!(six.parent().is_none());
+        assert_eq!(depth(&six), 0);
+        assert_eq!(Rc::strong_count(&six), 1);
+    }
+}
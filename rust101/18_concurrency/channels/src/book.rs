@@ -0,0 +1,98 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+// One message from a spawned thread to this one. `recv` blocks until it
+// arrives.
+pub fn single_message() -> String {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let val = String::from("hi");
+        tx.send(val).unwrap();
+        // `send` took ownership, so using `val` here would be
+        //
+        // error[E0382]: borrow of moved value: `val`
+    });
+
+    rx.recv().unwrap()
+}
+
+// The receiver as an iterator: each loop waits for the next value, and the
+// loop ends when the sender is dropped - here, when the thread finishes. The
+// pause is only there to make the waiting visible; nothing depends on it.
+pub fn receive_all(vals: Vec<String>, pause: Duration) -> Vec<String> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        for val in vals {
+            tx.send(val).unwrap();
+            thread::sleep(pause);
+        }
+    });
+
+    rx.iter().collect()
+}
+
+// Several producers cloned from one sender. Each message is (producer,
+// sequence number). The channel only ends once every Sender is dropped -
+// the original included, which is why it's dropped as soon as the clones
+// have been handed out.
+pub fn multiple_producers(
+    producers: usize,
+    per_producer: usize,
+    pause: Duration,
+) -> Vec<(usize, usize)> {
+    let (tx, rx) = mpsc::channel();
+
+    for producer in 0..producers {
+        let tx = tx.clone();
+        thread::spawn(move || {
+            for seq in 0..per_producer {
+                tx.send((producer, seq)).unwrap();
+                thread::sleep(pause);
+            }
+        });
+    }
+    drop(tx);
+
+    rx.iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn receives_the_single_message() {
+        assert_eq!(single_message(), "hi");
+    }
+
+    #[test]
+    fn iterating_ends_when_the_sender_is_gone() {
+        let vals: Vec<String> = ["hi", "from", "the", "thread"].map(String::from).to_vec();
+        assert_eq!(receive_all(vals.clone(), Duration::ZERO), vals);
+        assert!(receive_all(vec![], Duration::ZERO).is_empty());
+    }
+
+    #[test]
+    fn every_producer_delivers_everything_in_its_own_order() {
+        let received = multiple_producers(4, 250, Duration::ZERO);
+        assert_eq!(received.len(), 1_000);
+        // However the producers interleave, each one's messages arrive in
+        // the order it sent them - a channel is first in, first out.
+        for producer in 0..4 {
+            let seqs: Vec<usize> = received
+                .iter()
+                .filter(|(from, _)| *from == producer)
+                .map(|(_, seq)| *seq)
+                .collect();
+            assert_eq!(seqs, (0..250).collect::<Vec<_>>(), "producer {producer}");
+        }
+    }
+
+    #[test]
+    fn no_producers_means_an_empty_channel() {
+        assert!(multiple_producers(0, 10, Duration::ZERO).is_empty());
+    }
+}
@@ -0,0 +1,48 @@
+/* Things learned during this exercise:
+   * `mpsc::channel()` returns a (Sender, Receiver) pair - "multiple
+   producer, single consumer". `send` moves the value into the channel, so
+   the sending thread can't touch it afterwards.
+   * `recv` blocks until a value arrives and returns `Err` once every Sender
+   has been dropped; iterating over the Receiver stops at the same point.
+   That's the whole shutdown story - no flags, no sleeps - but it only
+   works if every Sender really is dropped. A stray clone in the main thread
+   keeps the receiver waiting forever.
+   * `Sender::clone` makes more producers. Messages from one producer arrive
+   in the order sent; messages from different producers interleave.
+   * A Receiver can't be cloned, so several workers sharing one need it
+   behind a Mutex, and should only hold the lock long enough to `recv`.
+   * See https://doc.rust-lang.org/book/ch16-02-message-passing.html
+*/
+mod book;
+mod pipeline;
+
+use std::time::Duration;
+
+fn main() {
+    println!("Got: {}", book::single_message());
+
+    let vals = ["hi", "from", "the", "thread"].map(String::from).to_vec();
+    for received in book::receive_all(vals, Duration::from_millis(200)) {
+        println!("Got: {received}");
+    }
+
+    let received = book::multiple_producers(2, 4, Duration::from_millis(50));
+    println!("Two producers, interleaved: {received:?}");
+    println!();
+
+    let counts = pipeline::word_counts(pipeline::SONNET, 4);
+    let mut top: Vec<(&String, &usize)> = counts.iter().collect();
+    top.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    println!(
+        "Sonnet 18 through 4 workers: {} words, {} different",
+        counts.values().sum::<usize>(),
+        counts.len()
+    );
+    for (word, count) in top.iter().take(5) {
+        println!("{count:>3} {word}");
+    }
+    println!(
+        "same as counting serially: {}",
+        counts == pipeline::count_words(pipeline::SONNET)
+    );
+}
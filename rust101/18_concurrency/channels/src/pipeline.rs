@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+pub const SONNET: &str = include_str!("../data/sonnet18.txt");
+
+// Lowercased, with punctuation trimmed from the ends but not the middle, so
+// "summer's" and "dimm'd" stay whole.
+pub fn count_words(text: &str) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for word in text.split_whitespace() {
+        let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+        if !word.is_empty() {
+            *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+// producer --lines--> workers --counts--> this thread
+//
+// One thread sends the lines, `workers` threads each take the next line and
+// send back its word counts, and this thread adds them all up. Nothing waits
+// on a timer: the workers stop when the producer drops its sender (their
+// `recv` fails), and the totting up stops when the last worker drops its
+// clone of the counts sender.
+pub fn word_counts(text: &str, workers: usize) -> HashMap<String, usize> {
+    let workers = workers.max(1);
+    let (line_tx, line_rx) = mpsc::channel::<&str>();
+    let (count_tx, count_rx) = mpsc::channel();
+    // A Receiver can't be cloned, so the workers share one behind a Mutex.
+    let line_rx = Mutex::new(line_rx);
+
+    thread::scope(|s| {
+        s.spawn(move || {
+            for line in text.lines() {
+                line_tx.send(line).unwrap();
+            }
+        });
+
+        for _ in 0..workers {
+            let count_tx = count_tx.clone();
+            let line_rx = &line_rx;
+            s.spawn(move || loop {
+                // The lock guard is a temporary, dropped at the end of this
+                // statement - so the lock is free again while this worker
+                // counts, and the others can take the next lines.
+                let line = line_rx.lock().unwrap().recv();
+                match line {
+                    Ok(line) => count_tx.send(count_words(line)).unwrap(),
+                    Err(_) => break,
+                }
+            });
+        }
+        // Without this the loop below would wait forever: the original
+        // sender would still be alive, right here.
+        drop(count_tx);
+
+        let mut totals = HashMap::new();
+        for counts in count_rx {
+            for (word, count) in counts {
+                *totals.entry(word).or_insert(0) += count;
+            }
+        }
+        totals
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = "\
+The cat sat.
+The cat, the hat!
+
+A hat; a cat? THE END";
+
+    #[test]
+    fn counts_words_serially() {
+        let counts = count_words(FIXTURE);
+        assert_eq!(counts["the"], 4);
+        assert_eq!(counts["cat"], 3);
+        assert_eq!(counts["hat"], 2);
+        assert_eq!(counts["end"], 1);
+        assert_eq!(counts.len(), 6);
+    }
+
+    #[test]
+    fn keeps_apostrophes_inside_words() {
+        let counts = count_words("summer's 'dimm'd' -- ow'st");
+        assert_eq!(counts.len(), 3);
+        assert!(counts.contains_key("dimm'd"));
+        assert!(!counts.contains_key("--"));
+    }
+
+    #[test]
+    fn pipeline_matches_the_serial_count() {
+        for workers in [1, 2, 3, 8, 50] {
+            assert_eq!(word_counts(FIXTURE, workers), count_words(FIXTURE));
+            assert_eq!(word_counts(SONNET, workers), count_words(SONNET));
+        }
+    }
+
+    #[test]
+    fn zero_workers_still_counts() {
+        assert_eq!(word_counts(FIXTURE, 0), count_words(FIXTURE));
+    }
+
+    #[test]
+    fn empty_text() {
+        assert!(word_counts("", 4).is_empty());
+    }
+
+    #[test]
+    fn sonnet_totals() {
+        let counts = word_counts(SONNET, 4);
+        assert_eq!(counts.values().sum::<usize>(), 114);
+        assert_eq!(counts["thou"], 4);
+    }
+}
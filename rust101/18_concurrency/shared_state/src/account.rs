@@ -0,0 +1,264 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+#[derive(Debug, PartialEq)]
+pub enum AccountError {
+    InsufficientFunds { balance: u64, requested: u64 },
+    Overflow,
+    SameAccount,
+}
+
+impl fmt::Display for AccountError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AccountError::InsufficientFunds { balance, requested } => write!(
+                f,
+                "can't withdraw {requested} with only {balance} in the account"
+            ),
+            AccountError::Overflow => write!(f, "the balance would be too large"),
+            AccountError::SameAccount => write!(f, "can't transfer to the same account"),
+        }
+    }
+}
+
+impl Error for AccountError {}
+
+// Every account gets the next id, so no two share one - which transfers rely
+// on to decide what to lock first.
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+// Amounts are whole cents, so there's no rounding, and u64 because a balance
+// can't go below zero.
+#[derive(Debug)]
+pub struct BankAccount {
+    id: u64,
+    balance: Mutex<u64>,
+}
+
+impl BankAccount {
+    pub fn new(opening_balance: u64) -> BankAccount {
+        BankAccount {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            balance: Mutex::new(opening_balance),
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn balance(&self) -> u64 {
+        *self.balance.lock().unwrap()
+    }
+
+    // Deposits and withdrawals return the new balance. Checking and updating
+    // happen under one lock, so no other thread can change the balance in
+    // between - checking with `balance()` first and then withdrawing could
+    // overdraw the account.
+    pub fn deposit(&self, amount: u64) -> Result<u64, AccountError> {
+        let mut balance = self.balance.lock().unwrap();
+        *balance = balance.checked_add(amount).ok_or(AccountError::Overflow)?;
+        Ok(*balance)
+    }
+
+    pub fn withdraw(&self, amount: u64) -> Result<u64, AccountError> {
+        let mut balance = self.balance.lock().unwrap();
+        *balance = balance
+            .checked_sub(amount)
+            .ok_or(AccountError::InsufficientFunds {
+                balance: *balance,
+                requested: amount,
+            })?;
+        Ok(*balance)
+    }
+
+    // Both balances have to be locked together, or money could be seen in
+    // neither account (or both) part way through. Locking `from` and then
+    // `to` can deadlock: a transfer from A to B holds A and waits for B while
+    // one from B to A holds B and waits for A. Always locking the lower id
+    // first means two transfers can never each hold what the other needs.
+    pub fn transfer(from: &BankAccount, to: &BankAccount, amount: u64) -> Result<(), AccountError> {
+        // Locking the same Mutex twice on one thread deadlocks (or panics),
+        // so this has to be caught before any locking.
+        if from.id == to.id {
+            return Err(AccountError::SameAccount);
+        }
+        let (mut from_balance, mut to_balance) = if from.id < to.id {
+            let from_balance = from.balance.lock().unwrap();
+            (from_balance, to.balance.lock().unwrap())
+        } else {
+            let to_balance = to.balance.lock().unwrap();
+            (from.balance.lock().unwrap(), to_balance)
+        };
+
+        let new_from = from_balance
+            .checked_sub(amount)
+            .ok_or(AccountError::InsufficientFunds {
+                balance: *from_balance,
+                requested: amount,
+            })?;
+        let new_to = to_balance
+            .checked_add(amount)
+            .ok_or(AccountError::Overflow)?;
+        *from_balance = new_from;
+        *to_balance = new_to;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{mpsc, Arc};
+    use std::thread;
+    use std::time::Duration;
+
+    // Runs `work` on another thread and fails the test if it hasn't finished
+    // in time, rather than letting a deadlock hang the test run.
+    fn within_timeout(work: impl FnOnce() + Send + 'static) {
+        let (done_tx, done_rx) = mpsc::channel();
+        thread::spawn(move || {
+            work();
+            done_tx.send(()).unwrap();
+        });
+        done_rx
+            .recv_timeout(Duration::from_secs(10))
+            .expect("deadlocked (or panicked)");
+    }
+
+    #[test]
+    fn deposits_and_withdrawals() {
+        let account = BankAccount::new(100);
+        assert_eq!(account.deposit(50), Ok(150));
+        assert_eq!(account.withdraw(150), Ok(0));
+        assert_eq!(account.balance(), 0);
+    }
+
+    #[test]
+    fn rejects_overdrafts() {
+        let account = BankAccount::new(100);
+        assert_eq!(
+            account.withdraw(101),
+            Err(AccountError::InsufficientFunds {
+                balance: 100,
+                requested: 101,
+            })
+        );
+        assert_eq!(
+            account.balance(),
+            100,
+            "a failed withdrawal changes nothing"
+        );
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        let account = BankAccount::new(u64::MAX);
+        assert_eq!(account.deposit(1), Err(AccountError::Overflow));
+        assert_eq!(account.balance(), u64::MAX);
+    }
+
+    #[test]
+    fn ids_are_unique() {
+        let a = BankAccount::new(0);
+        let b = BankAccount::new(0);
+        assert_ne!(a.id(), b.id());
+    }
+
+    #[test]
+    fn hammered_from_many_threads_the_balance_is_exact() {
+        let account = Arc::new(BankAccount::new(1_000));
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let account = Arc::clone(&account);
+                thread::spawn(move || {
+                    for _ in 0..1_000 {
+                        account.deposit(3).unwrap();
+                        account.withdraw(2).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(account.balance(), 1_000 + 8 * 1_000);
+    }
+
+    #[test]
+    fn concurrent_withdrawals_never_overdraw() {
+        let account = Arc::new(BankAccount::new(500));
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let account = Arc::clone(&account);
+                // Each thread tries to take 100 ten times; only five
+                // withdrawals in total can succeed.
+                thread::spawn(move || (0..10).filter(|_| account.withdraw(100).is_ok()).count())
+            })
+            .collect();
+        let succeeded: usize = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .sum();
+        assert_eq!(succeeded, 5);
+        assert_eq!(account.balance(), 0);
+    }
+
+    #[test]
+    fn transfers_move_money() {
+        let a = BankAccount::new(100);
+        let b = BankAccount::new(0);
+        BankAccount::transfer(&a, &b, 30).unwrap();
+        BankAccount::transfer(&b, &a, 10).unwrap();
+        assert_eq!((a.balance(), b.balance()), (80, 20));
+    }
+
+    #[test]
+    fn failed_transfers_change_nothing() {
+        let a = BankAccount::new(10);
+        let b = BankAccount::new(u64::MAX);
+        assert!(matches!(
+            BankAccount::transfer(&a, &b, 11),
+            Err(AccountError::InsufficientFunds { .. })
+        ));
+        assert_eq!(
+            BankAccount::transfer(&a, &b, 1),
+            Err(AccountError::Overflow)
+        );
+        assert_eq!(
+            BankAccount::transfer(&a, &a, 1),
+            Err(AccountError::SameAccount)
+        );
+        assert_eq!((a.balance(), b.balance()), (10, u64::MAX));
+    }
+
+    #[test]
+    fn transfers_both_ways_do_not_deadlock() {
+        within_timeout(|| {
+            let a = Arc::new(BankAccount::new(10_000));
+            let b = Arc::new(BankAccount::new(10_000));
+            let handles: Vec<_> = (0..8)
+                .map(|n| {
+                    let (a, b) = (Arc::clone(&a), Arc::clone(&b));
+                    thread::spawn(move || {
+                        for _ in 0..2_000 {
+                            // Half the threads go each way. A failed transfer
+                            // is fine - only deadlock or lost money isn't.
+                            let _ = if n % 2 == 0 {
+                                BankAccount::transfer(&a, &b, 7)
+                            } else {
+                                BankAccount::transfer(&b, &a, 7)
+                            };
+                        }
+                    })
+                })
+                .collect();
+            for handle in handles {
+                handle.join().unwrap();
+            }
+            assert_eq!(a.balance() + b.balance(), 20_000);
+        });
+    }
+}
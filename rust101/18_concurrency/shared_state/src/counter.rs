@@ -0,0 +1,50 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// The book's counter, with the number of threads passed in. Each thread
+// locks the Mutex, adds one, and unlocks it when the guard is dropped at the
+// end of the closure.
+//
+// A plain `Rc` can't be shared this way:
+//
+//     let counter = Rc::new(Mutex::new(0));
+//
+// error[E0277]: `Rc<Mutex<i32>>` cannot be sent between threads safely
+//
+// `Arc` is the atomic - thread-safe - version of Rc.
+pub fn count_with_threads(threads: usize) -> i32 {
+    let counter = Arc::new(Mutex::new(0));
+    let mut handles = vec![];
+
+    for _ in 0..threads {
+        let counter = Arc::clone(&counter);
+        let handle = thread::spawn(move || {
+            let mut num = counter.lock().unwrap();
+            *num += 1;
+        });
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let result = *counter.lock().unwrap();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ten_threads_count_to_ten() {
+        assert_eq!(count_with_threads(10), 10);
+    }
+
+    #[test]
+    fn no_increments_are_lost() {
+        assert_eq!(count_with_threads(500), 500);
+        assert_eq!(count_with_threads(0), 0);
+    }
+}
@@ -0,0 +1,85 @@
+/* Things learned during this exercise:
+   * A `Mutex<T>` only gives access to its data through `lock()`, which
+   blocks until no other thread holds the lock and returns a guard. The
+   guard derefs to the data and unlocks when it's dropped, so forgetting to
+   unlock isn't possible.
+   * To share a Mutex between threads it needs several owners, and `Rc`
+   isn't thread-safe (it doesn't implement `Send`). `Arc` is: its count is
+   updated atomically, which costs a little, so it isn't the default.
+   * Mutex gives interior mutability like RefCell does - `Arc<Mutex<T>>` is
+   the thread-safe cousin of `Rc<RefCell<T>>`.
+   * Two locks can deadlock: each thread holds one and waits for the other.
+   Always taking them in the same order (here, by account id) prevents it.
+   * `RwLock` allows many readers or one writer at a time, which suits data
+   that's read much more often than it's written.
+   * See https://doc.rust-lang.org/book/ch16-03-shared-state.html
+*/
+mod account;
+mod counter;
+mod rw_account;
+
+use account::BankAccount;
+use rw_account::ReadMostlyAccount;
+use std::sync::Arc;
+use std::thread;
+
+fn main() {
+    println!("Result: {}", counter::count_with_threads(10));
+    println!();
+
+    let savings = Arc::new(BankAccount::new(10_000));
+    let current = Arc::new(BankAccount::new(2_500));
+    println!(
+        "savings (id {}) = {}, current (id {}) = {}",
+        savings.id(),
+        savings.balance(),
+        current.id(),
+        current.balance()
+    );
+
+    let handles: Vec<_> = (0..4)
+        .map(|n| {
+            let (savings, current) = (Arc::clone(&savings), Arc::clone(&current));
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    let result = if n % 2 == 0 {
+                        BankAccount::transfer(&savings, &current, 25)
+                    } else {
+                        BankAccount::transfer(&current, &savings, 10)
+                    };
+                    result.unwrap();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!(
+        "after 400 transfers in both directions: savings = {}, current = {}, total = {}",
+        savings.balance(),
+        current.balance(),
+        savings.balance() + current.balance()
+    );
+
+    match current.withdraw(1_000_000) {
+        Ok(balance) => println!("withdrew, leaving {balance}"),
+        Err(err) => println!("withdrawing 1000000 from current: {err}"),
+    }
+    if let Err(err) = BankAccount::transfer(&savings, &savings, 1) {
+        println!("transferring from savings to savings: {err}");
+    }
+    current.deposit(500).unwrap();
+    println!();
+
+    let shared = ReadMostlyAccount::new(100);
+    thread::scope(|s| {
+        for reader in 0..3 {
+            let shared = &shared;
+            s.spawn(move || println!("reader {reader} sees {}", shared.balance()));
+        }
+        s.spawn(|| shared.deposit(50).unwrap());
+    });
+    println!("after the deposit: {}", shared.balance());
+    shared.withdraw(150).unwrap();
+}
@@ -0,0 +1,84 @@
+use std::sync::RwLock;
+
+use crate::account::AccountError;
+
+// For balances that are read far more often than they change. A Mutex lets
+// one thread in at a time, readers included; an RwLock lets any number of
+// readers in together, and a writer waits until they've all left.
+#[derive(Debug)]
+pub struct ReadMostlyAccount {
+    balance: RwLock<u64>,
+}
+
+impl ReadMostlyAccount {
+    pub fn new(opening_balance: u64) -> ReadMostlyAccount {
+        ReadMostlyAccount {
+            balance: RwLock::new(opening_balance),
+        }
+    }
+
+    pub fn balance(&self) -> u64 {
+        *self.balance.read().unwrap()
+    }
+
+    pub fn deposit(&self, amount: u64) -> Result<u64, AccountError> {
+        let mut balance = self.balance.write().unwrap();
+        *balance = balance.checked_add(amount).ok_or(AccountError::Overflow)?;
+        Ok(*balance)
+    }
+
+    pub fn withdraw(&self, amount: u64) -> Result<u64, AccountError> {
+        let mut balance = self.balance.write().unwrap();
+        *balance = balance
+            .checked_sub(amount)
+            .ok_or(AccountError::InsufficientFunds {
+                balance: *balance,
+                requested: amount,
+            })?;
+        Ok(*balance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn readers_share_the_lock() {
+        let account = ReadMostlyAccount::new(42);
+        // Two read guards at once - a Mutex would deadlock here.
+        let first = account.balance.read().unwrap();
+        let second = account.balance.read().unwrap();
+        assert_eq!((*first, *second), (42, 42));
+        assert!(account.balance.try_write().is_err(), "a writer has to wait");
+    }
+
+    #[test]
+    fn readers_only_see_whole_updates() {
+        let account = ReadMostlyAccount::new(0);
+        thread::scope(|s| {
+            s.spawn(|| {
+                for _ in 0..1_000 {
+                    account.deposit(2).unwrap();
+                }
+            });
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..1_000 {
+                        // Deposits are of 2, so any balance seen is even.
+                        assert_eq!(account.balance() % 2, 0);
+                    }
+                });
+            }
+        });
+        assert_eq!(account.balance(), 2_000);
+    }
+
+    #[test]
+    fn rejects_overdrafts() {
+        let account = ReadMostlyAccount::new(5);
+        assert!(account.withdraw(6).is_err());
+        assert_eq!(account.withdraw(5), Ok(0));
+    }
+}
@@ -0,0 +1,97 @@
+use std::thread;
+use std::time::Duration;
+
+// The book's first example, with the loop counts passed in. Without a
+// `join`, main's loop can finish - and end the program - before the spawned
+// thread has printed everything.
+pub fn spawn_and_maybe_join(join: bool) {
+    let handle = thread::spawn(|| {
+        for i in 1..10 {
+            println!("hi number {i} from the spawned thread!");
+            thread::sleep(Duration::from_millis(1));
+        }
+    });
+
+    for i in 1..5 {
+        println!("hi number {i} from the main thread!");
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    if join {
+        handle.join().unwrap();
+    }
+}
+
+// The closure only uses `v` by reference, so Rust would borrow it - but the
+// thread might outlive this function, and `v` with it:
+//
+//     let handle = thread::spawn(|| v.iter().sum());
+//
+// error[E0373]: closure may outlive the current function, but it borrows
+// `v`, which is owned by the current function
+//
+// `move` hands the thread ownership instead.
+pub fn sum_in_a_thread(v: Vec<i32>) -> i32 {
+    let handle = thread::spawn(move || v.iter().sum());
+    // `v` has moved into the thread, so it can't be used here any more.
+    handle.join().unwrap()
+}
+
+// Each thread's return value comes back out of `join`. Joining in spawn
+// order gives the results in that order, however the threads were scheduled.
+pub fn squares_from_threads(count: u64) -> Vec<u64> {
+    let handles: Vec<thread::JoinHandle<u64>> =
+        (0..count).map(|n| thread::spawn(move || n * n)).collect();
+    handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect()
+}
+
+// Scoped threads are all joined before `thread::scope` returns, so they can
+// borrow from the enclosing function - no `move`, no clones - and one of
+// them can even borrow mutably while the others only read.
+pub fn scoped_stats(words: &[&str]) -> (usize, usize, Vec<String>) {
+    let mut shouted = Vec::new();
+    let (total_len, longest) = thread::scope(|s| {
+        let total = s.spawn(|| words.iter().map(|word| word.len()).sum());
+        let longest = s.spawn(|| words.iter().map(|word| word.len()).max().unwrap_or(0));
+        s.spawn(|| shouted.extend(words.iter().map(|word| word.to_uppercase())));
+        (total.join().unwrap(), longest.join().unwrap())
+    });
+    (total_len, longest, shouted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moved_vec_is_summed() {
+        assert_eq!(sum_in_a_thread(vec![1, 2, 3]), 6);
+        assert_eq!(sum_in_a_thread(vec![]), 0);
+    }
+
+    #[test]
+    fn results_come_back_in_spawn_order() {
+        assert_eq!(squares_from_threads(5), [0, 1, 4, 9, 16]);
+        assert!(squares_from_threads(0).is_empty());
+    }
+
+    #[test]
+    fn scoped_threads_borrow_local_data() {
+        let words = ["scoped", "threads", "borrow"];
+        let (total, longest, shouted) = scoped_stats(&words);
+        assert_eq!(total, 19);
+        assert_eq!(longest, 7);
+        assert_eq!(shouted, ["SCOPED", "THREADS", "BORROW"]);
+        // Still ours: nothing was moved.
+        assert_eq!(words.len(), 3);
+    }
+
+    #[test]
+    fn a_panicking_thread_is_an_err_from_join() {
+        let handle = thread::spawn(|| panic!("oops"));
+        assert!(handle.join().is_err());
+    }
+}
@@ -0,0 +1,51 @@
+/* Things learned during this exercise:
+   * `thread::spawn` runs a closure on a new OS thread and returns a
+   `JoinHandle`. When main returns the whole program ends, finished or not -
+   `handle.join()` waits for the thread and hands back its return value.
+   * Threads interleave however the OS schedules them, so output order
+   changes from run to run.
+   * A spawned thread might outlive the function that started it, so its
+   closure can't borrow local variables (E0373) - `move` gives it ownership.
+   * `thread::scope` joins every thread spawned inside it before returning,
+   so the borrow checker knows borrowed data outlives them: scoped threads
+   can use the enclosing function's data without moving or cloning it.
+   * A thread that panics doesn't bring down the others; its `join` returns
+   an `Err` instead.
+   * See https://doc.rust-lang.org/book/ch16-01-threads.html
+*/
+mod basics;
+mod parallel;
+
+use parallel::parallel_sum;
+use std::time::Instant;
+
+fn main() {
+    println!("Without join - the spawned thread may be cut short:");
+    basics::spawn_and_maybe_join(false);
+    println!();
+    println!("With join - it always finishes:");
+    basics::spawn_and_maybe_join(true);
+    println!();
+
+    println!(
+        "sum moved into a thread: {}",
+        basics::sum_in_a_thread(vec![1, 2, 3, 4])
+    );
+    println!(
+        "squares from 6 threads: {:?}",
+        basics::squares_from_threads(6)
+    );
+    let (total, longest, shouted) = basics::scoped_stats(&["fearless", "concurrency"]);
+    println!("scoped threads: total length {total}, longest {longest}, shouted {shouted:?}");
+    println!();
+
+    let data: Vec<u64> = (1..=10_000_000).collect();
+    for workers in [0, 1, 2, 4, 8] {
+        let start = Instant::now();
+        let sum = parallel_sum(&data, workers);
+        println!(
+            "parallel_sum with {workers} workers = {sum} in {:?}",
+            start.elapsed()
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use std::thread;
+
+// Splits `data` into at most `workers` chunks and sums each in its own scoped
+// thread. Asking for more workers than there are values just uses one per
+// value, and 0 workers sums on this thread. Like `Iterator::sum`, it panics
+// on overflow in a debug build.
+pub fn parallel_sum(data: &[u64], workers: usize) -> u64 {
+    if workers == 0 || data.is_empty() {
+        return data.iter().sum();
+    }
+    // Rounding up means the chunks cover everything in `workers` or fewer.
+    let chunk_size = data.len().div_ceil(workers);
+    thread::scope(|s| {
+        let handles: Vec<_> = data
+            .chunks(chunk_size)
+            .map(|chunk| s.spawn(|| chunk.iter().sum::<u64>()))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .sum()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn random_data(seed: u64, len: usize) -> Vec<u64> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..len).map(|_| rng.gen_range(0..1_000_000)).collect()
+    }
+
+    #[test]
+    fn matches_the_serial_sum_on_random_data() {
+        for (seed, len) in [(1, 10), (2, 1_000), (3, 12_345)] {
+            let data = random_data(seed, len);
+            let serial: u64 = data.iter().sum();
+            for workers in [1, 2, 3, 4, 7, 16] {
+                assert_eq!(
+                    parallel_sum(&data, workers),
+                    serial,
+                    "{len} values, {workers} workers"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn zero_workers_sums_serially() {
+        assert_eq!(parallel_sum(&[1, 2, 3], 0), 6);
+    }
+
+    #[test]
+    fn more_workers_than_values() {
+        assert_eq!(parallel_sum(&[1, 2, 3], 100), 6);
+        assert_eq!(parallel_sum(&[42], usize::MAX), 42);
+    }
+
+    #[test]
+    fn empty_data() {
+        assert_eq!(parallel_sum(&[], 0), 0);
+        assert_eq!(parallel_sum(&[], 4), 0);
+    }
+}
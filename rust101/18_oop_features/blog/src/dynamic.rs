@@ -0,0 +1,259 @@
+// The book's trait-object blog post, with its suggested extensions: `reject`
+// sends a post under review back to draft, publishing takes two approvals,
+// and text can only be added to a draft.
+pub struct Post {
+    state: Option<Box<dyn State>>,
+    content: String,
+}
+
+impl Post {
+    pub fn new() -> Post {
+        Post {
+            state: Some(Box::new(Draft {})),
+            content: String::new(),
+        }
+    }
+
+    pub fn add_text(&mut self, text: &str) {
+        if self.state.as_ref().unwrap().can_edit() {
+            self.content.push_str(text);
+        }
+    }
+
+    // Empty until the post is published.
+    pub fn content(&self) -> &str {
+        self.state.as_ref().unwrap().content(self)
+    }
+
+    pub fn status(&self) -> &'static str {
+        self.state.as_ref().unwrap().name()
+    }
+
+    pub fn request_review(&mut self) {
+        if let Some(s) = self.state.take() {
+            self.state = Some(s.request_review())
+        }
+    }
+
+    pub fn approve(&mut self) {
+        if let Some(s) = self.state.take() {
+            self.state = Some(s.approve())
+        }
+    }
+
+    pub fn reject(&mut self) {
+        if let Some(s) = self.state.take() {
+            self.state = Some(s.reject())
+        }
+    }
+}
+
+impl Default for Post {
+    fn default() -> Post {
+        Post::new()
+    }
+}
+
+// Every transition defaults to staying put, so each state only spells out
+// the ones that change something.
+trait State {
+    fn name(&self) -> &'static str;
+
+    fn request_review(self: Box<Self>) -> Box<dyn State>;
+
+    fn approve(self: Box<Self>) -> Box<dyn State>;
+
+    fn reject(self: Box<Self>) -> Box<dyn State>;
+
+    fn can_edit(&self) -> bool {
+        false
+    }
+
+    fn content<'a>(&self, _post: &'a Post) -> &'a str {
+        ""
+    }
+}
+
+struct Draft {}
+
+impl State for Draft {
+    fn name(&self) -> &'static str {
+        "draft"
+    }
+
+    fn request_review(self: Box<Self>) -> Box<dyn State> {
+        Box::new(PendingReview { approvals: 0 })
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn can_edit(&self) -> bool {
+        true
+    }
+}
+
+const APPROVALS_NEEDED: u32 = 2;
+
+struct PendingReview {
+    approvals: u32,
+}
+
+impl State for PendingReview {
+    fn name(&self) -> &'static str {
+        "pending review"
+    }
+
+    fn request_review(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn State> {
+        let approvals = self.approvals + 1;
+        if approvals >= APPROVALS_NEEDED {
+            Box::new(Published {})
+        } else {
+            Box::new(PendingReview { approvals })
+        }
+    }
+
+    // Back to draft, and any approvals so far are forgotten.
+    fn reject(self: Box<Self>) -> Box<dyn State> {
+        Box::new(Draft {})
+    }
+}
+
+struct Published {}
+
+impl State for Published {
+    fn name(&self) -> &'static str {
+        "published"
+    }
+
+    fn request_review(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn approve(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn reject(self: Box<Self>) -> Box<dyn State> {
+        self
+    }
+
+    fn content<'a>(&self, post: &'a Post) -> &'a str {
+        &post.content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "I ate a salad for lunch today";
+
+    fn post_for_review() -> Post {
+        let mut post = Post::new();
+        post.add_text(TEXT);
+        post.request_review();
+        post
+    }
+
+    #[test]
+    fn the_books_happy_path_with_two_approvals() {
+        let mut post = Post::new();
+        post.add_text(TEXT);
+        assert_eq!(post.status(), "draft");
+        assert_eq!("", post.content());
+
+        post.request_review();
+        assert_eq!(post.status(), "pending review");
+        assert_eq!("", post.content());
+
+        post.approve();
+        assert_eq!(post.status(), "pending review");
+        assert_eq!("", post.content());
+
+        post.approve();
+        assert_eq!(post.status(), "published");
+        assert_eq!(TEXT, post.content());
+    }
+
+    #[test]
+    fn approving_a_draft_does_nothing() {
+        let mut post = Post::new();
+        post.add_text(TEXT);
+        post.approve();
+        post.approve();
+        assert_eq!(post.status(), "draft");
+        assert_eq!(post.content(), "");
+    }
+
+    #[test]
+    fn rejecting_sends_it_back_to_draft() {
+        let mut post = post_for_review();
+        post.reject();
+        assert_eq!(post.status(), "draft");
+        assert_eq!(post.content(), "");
+    }
+
+    #[test]
+    fn rejecting_forgets_earlier_approvals() {
+        let mut post = post_for_review();
+        post.approve();
+        post.reject();
+        post.request_review();
+        post.approve();
+        assert_eq!(
+            post.status(),
+            "pending review",
+            "one approval since rejection"
+        );
+        post.approve();
+        assert_eq!(post.status(), "published");
+    }
+
+    #[test]
+    fn rejecting_a_draft_or_published_post_does_nothing() {
+        let mut post = Post::new();
+        post.reject();
+        assert_eq!(post.status(), "draft");
+
+        let mut post = post_for_review();
+        post.approve();
+        post.approve();
+        post.reject();
+        assert_eq!(post.status(), "published");
+        assert_eq!(post.content(), TEXT);
+    }
+
+    #[test]
+    fn requesting_review_twice_does_not_count_as_approval() {
+        let mut post = post_for_review();
+        post.request_review();
+        post.approve();
+        post.request_review();
+        assert_eq!(post.status(), "pending review");
+    }
+
+    #[test]
+    fn text_can_only_be_added_to_drafts() {
+        let mut post = Post::new();
+        post.add_text("Draft text. ");
+        post.request_review();
+        post.add_text("Added in review. ");
+        post.reject();
+        post.add_text("Redrafted.");
+        post.request_review();
+        post.approve();
+        post.approve();
+        post.add_text(" Added after publishing.");
+        assert_eq!(post.content(), "Draft text. Redrafted.");
+    }
+}
@@ -0,0 +1,18 @@
+/* Things learned during this exercise:
+   * The state pattern: a `Post` holds a `Box<dyn State>`, and each state
+   decides what its methods do and which state comes next. Adding a state
+   means adding a type, not editing a `match` in every method - but the
+   states know about each other, and a mistake like calling `approve` on a
+   draft only shows up at run time, as the call silently doing nothing.
+   * `self: Box<Self>` lets a state method take ownership of the boxed
+   state and return a replacement. `Option::take` moves the state out of
+   the post while that happens, because Rust won't leave a field empty.
+   * The type-state version encodes each state as its own type, and each
+   transition as a method that consumes one and returns the next. Invalid
+   transitions aren't ignored, they don't compile: a `DraftPost` has no
+   `content` method at all. The price is that callers must rebind the post
+   with each step (`let post = post.approve();`).
+   * See https://doc.rust-lang.org/book/ch18-03-oo-design-patterns.html
+*/
+pub mod dynamic;
+pub mod typestate;
@@ -0,0 +1,34 @@
+use blog::{dynamic, typestate};
+
+fn main() {
+    println!("The state pattern:");
+    let mut post = dynamic::Post::new();
+    post.add_text("I ate a salad for lunch today");
+    let show = |step: &str, post: &dynamic::Post| {
+        println!("  {step:<16} {:<15} {:?}", post.status(), post.content());
+    };
+    show("new", &post);
+    post.approve();
+    show("approve (draft)", &post);
+    post.request_review();
+    show("request_review", &post);
+    post.reject();
+    show("reject", &post);
+    post.request_review();
+    post.approve();
+    show("approve", &post);
+    post.add_text(" with extra dressing");
+    show("add_text", &post);
+    post.approve();
+    show("approve again", &post);
+    println!();
+
+    println!("Types for states:");
+    let mut post = typestate::Post::new();
+    post.add_text("I ate a salad for lunch today");
+    let post = post.request_review();
+    let mut post = post.reject();
+    post.add_text(", again");
+    let post = post.request_review().approve().approve();
+    println!("  published {:?}", post.content());
+}
@@ -0,0 +1,134 @@
+//! The blog post as types: `DraftPost` → `PendingReviewPost` →
+//! `ApprovedOncePost` → `Post`, each transition consuming the old value.
+//! Mistakes are compile errors. A draft has no content to read:
+//!
+//! ```compile_fail,E0599
+//! let post = blog::typestate::Post::new();
+//! post.content();
+//! ```
+//!
+//! Text can't be added once it's under review:
+//!
+//! ```compile_fail,E0599
+//! let mut post = blog::typestate::Post::new().request_review();
+//! post.add_text("too late");
+//! ```
+//!
+//! One approval isn't enough to publish:
+//!
+//! ```compile_fail,E0308
+//! use blog::typestate::Post;
+//! let post: Post = Post::new().request_review().approve();
+//! ```
+//!
+//! And an old state can't be used after moving on from it:
+//!
+//! ```compile_fail,E0382
+//! let draft = blog::typestate::Post::new();
+//! let pending = draft.request_review();
+//! draft.request_review();
+//! ```
+
+pub struct Post {
+    content: String,
+}
+
+pub struct DraftPost {
+    content: String,
+}
+
+impl Post {
+    // Every post starts as a draft, so there's no way to make a `Post`
+    // without going through review. Clippy expects `new` to return `Self`,
+    // but returning a different type is the whole point here.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> DraftPost {
+        DraftPost {
+            content: String::new(),
+        }
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+impl DraftPost {
+    pub fn add_text(&mut self, text: &str) {
+        self.content.push_str(text);
+    }
+
+    pub fn request_review(self) -> PendingReviewPost {
+        PendingReviewPost {
+            content: self.content,
+        }
+    }
+}
+
+pub struct PendingReviewPost {
+    content: String,
+}
+
+impl PendingReviewPost {
+    pub fn approve(self) -> ApprovedOncePost {
+        ApprovedOncePost {
+            content: self.content,
+        }
+    }
+
+    pub fn reject(self) -> DraftPost {
+        DraftPost {
+            content: self.content,
+        }
+    }
+}
+
+// Halfway there: the second approval publishes it.
+pub struct ApprovedOncePost {
+    content: String,
+}
+
+impl ApprovedOncePost {
+    pub fn approve(self) -> Post {
+        Post {
+            content: self.content,
+        }
+    }
+
+    pub fn reject(self) -> DraftPost {
+        DraftPost {
+            content: self.content,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_approvals_publish_it() {
+        let mut post = Post::new();
+        post.add_text("I ate a salad for lunch today");
+        let post = post.request_review().approve().approve();
+        assert_eq!(post.content(), "I ate a salad for lunch today");
+    }
+
+    #[test]
+    fn rejected_posts_can_be_edited_again() {
+        let mut post = Post::new();
+        post.add_text("First go.");
+        let mut post = post.request_review().reject();
+        post.add_text(" Second go.");
+        let mut post = post.request_review().approve().reject();
+        post.add_text(" Third go.");
+        let post = post.request_review().approve().approve();
+        assert_eq!(post.content(), "First go. Second go. Third go.");
+    }
+
+    #[test]
+    fn an_empty_post_can_still_be_published() {
+        let post = Post::new().request_review().approve().approve();
+        assert_eq!(post.content(), "");
+    }
+}
@@ -0,0 +1,105 @@
+// The book's AveragedCollection. The fields are private so the average can't
+// get out of step with the list: every change goes through a method that
+// recalculates it.
+#[derive(Debug, Default)]
+pub struct AveragedCollection {
+    list: Vec<i32>,
+    average: f64,
+}
+
+impl AveragedCollection {
+    pub fn new() -> AveragedCollection {
+        AveragedCollection::default()
+    }
+
+    pub fn add(&mut self, value: i32) {
+        self.list.push(value);
+        self.update_average();
+    }
+
+    // Removes the most recently added value.
+    pub fn remove(&mut self) -> Option<i32> {
+        let result = self.list.pop();
+        if result.is_some() {
+            self.update_average();
+        }
+        result
+    }
+
+    // 0.0 for an empty collection, rather than the NaN that 0 / 0 would be.
+    pub fn average(&self) -> f64 {
+        self.average
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    // Sums as i64, so a few i32::MAXes don't overflow.
+    fn update_average(&mut self) {
+        self.average = if self.list.is_empty() {
+            0.0
+        } else {
+            let total: i64 = self.list.iter().map(|&value| i64::from(value)).sum();
+            total as f64 / self.list.len() as f64
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_empty() {
+        let collection = AveragedCollection::new();
+        assert!(collection.is_empty());
+        assert_eq!(collection.average(), 0.0);
+    }
+
+    #[test]
+    fn average_follows_adds_and_removes() {
+        let mut collection = AveragedCollection::new();
+        collection.add(1);
+        assert_eq!(collection.average(), 1.0);
+        collection.add(2);
+        collection.add(6);
+        assert_eq!(collection.average(), 3.0);
+
+        assert_eq!(collection.remove(), Some(6));
+        assert_eq!(collection.average(), 1.5);
+        assert_eq!(collection.remove(), Some(2));
+        assert_eq!(collection.remove(), Some(1));
+        assert_eq!(collection.len(), 0);
+        assert_eq!(collection.average(), 0.0);
+    }
+
+    #[test]
+    fn removing_from_empty_is_none() {
+        let mut collection = AveragedCollection::new();
+        assert_eq!(collection.remove(), None);
+        assert_eq!(collection.average(), 0.0);
+
+        collection.add(-4);
+        collection.remove();
+        assert_eq!(collection.remove(), None);
+        assert_eq!(collection.average(), 0.0);
+    }
+
+    #[test]
+    fn negative_and_huge_values() {
+        let mut collection = AveragedCollection::new();
+        collection.add(-3);
+        collection.add(3);
+        assert_eq!(collection.average(), 0.0);
+
+        let mut collection = AveragedCollection::new();
+        collection.add(i32::MAX);
+        collection.add(i32::MAX);
+        assert_eq!(collection.average(), i32::MAX as f64);
+    }
+}
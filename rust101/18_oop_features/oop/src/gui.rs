@@ -0,0 +1,153 @@
+pub trait Draw {
+    // The component as lines of text, without a trailing newline.
+    fn draw(&self) -> String;
+}
+
+// Holds any mix of components. `Vec<Box<dyn Draw>>` rather than a generic
+// `Screen<T: Draw>`, which could only ever hold one kind of component.
+pub struct Screen {
+    pub components: Vec<Box<dyn Draw>>,
+}
+
+impl Screen {
+    pub fn run(&self) -> String {
+        let mut screen = String::new();
+        for component in self.components.iter() {
+            screen.push_str(&component.draw());
+            screen.push('\n');
+        }
+        screen
+    }
+}
+
+// A box `width` characters wide inside the border and `height` rows tall,
+// with the label centred on the middle row. It grows to fit a long label.
+pub struct Button {
+    pub width: u32,
+    pub height: u32,
+    pub label: String,
+}
+
+impl Draw for Button {
+    fn draw(&self) -> String {
+        let width = (self.width as usize).max(self.label.len());
+        let height = self.height.max(1);
+        let border = format!("+{}+", "-".repeat(width));
+
+        let mut lines = vec![border.clone()];
+        for row in 0..height {
+            let text = if row == (height - 1) / 2 {
+                self.label.as_str()
+            } else {
+                ""
+            };
+            lines.push(format!("|{text:^width$}|"));
+        }
+        lines.push(border);
+        lines.join("\n")
+    }
+}
+
+// The first option is shown as selected, then up to `height` options are
+// listed, with "..." if some don't fit.
+pub struct SelectBox {
+    pub width: u32,
+    pub height: u32,
+    pub options: Vec<String>,
+}
+
+impl Draw for SelectBox {
+    fn draw(&self) -> String {
+        let width = self.width as usize;
+        let selected = self.options.first().map_or("", String::as_str);
+        let mut lines = vec![format!("[{selected:<width$} v]")];
+        for option in self.options.iter().take(self.height as usize) {
+            lines.push(format!("  {option}"));
+        }
+        if self.options.len() > self.height as usize {
+            lines.push("  ...".to_string());
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn button_centres_its_label() {
+        let button = Button {
+            width: 6,
+            height: 3,
+            label: String::from("OK"),
+        };
+        assert_eq!(
+            button.draw(),
+            "+------+\n\
+             |      |\n\
+             |  OK  |\n\
+             |      |\n\
+             +------+"
+        );
+    }
+
+    #[test]
+    fn button_grows_to_fit_its_label() {
+        let button = Button {
+            width: 2,
+            height: 0,
+            label: String::from("Cancel"),
+        };
+        assert_eq!(button.draw(), "+------+\n|Cancel|\n+------+");
+    }
+
+    #[test]
+    fn select_box_lists_what_fits() {
+        let select_box = SelectBox {
+            width: 5,
+            height: 2,
+            options: options(&["Yes", "Maybe", "No"]),
+        };
+        assert_eq!(select_box.draw(), "[Yes   v]\n  Yes\n  Maybe\n  ...");
+    }
+
+    #[test]
+    fn empty_select_box() {
+        let select_box = SelectBox {
+            width: 3,
+            height: 4,
+            options: vec![],
+        };
+        assert_eq!(select_box.draw(), "[    v]");
+    }
+
+    #[test]
+    fn screen_runs_every_component_in_order() {
+        let screen = Screen {
+            components: vec![
+                Box::new(SelectBox {
+                    width: 2,
+                    height: 1,
+                    options: options(&["A"]),
+                }),
+                Box::new(Button {
+                    width: 2,
+                    height: 1,
+                    label: String::from("Go"),
+                }),
+            ],
+        };
+        assert_eq!(screen.run(), "[A  v]\n  A\n+--+\n|Go|\n+--+\n");
+    }
+
+    #[test]
+    fn empty_screen() {
+        let screen = Screen { components: vec![] };
+        assert_eq!(screen.run(), "");
+    }
+}
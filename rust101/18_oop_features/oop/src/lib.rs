@@ -0,0 +1,19 @@
+/* Things learned during this exercise:
+   * Rust has objects in the sense of data plus behaviour - structs and enums
+   with `impl` blocks - but no inheritance. Code is shared through default
+   trait methods, and polymorphism comes from generics or trait objects.
+   * Encapsulation is done with privacy: `AveragedCollection` keeps its
+   fields private, so the cached average can only change through `add` and
+   `remove`, which keep it up to date. Its internals could switch from a Vec
+   to something else without breaking any caller.
+   * A trait object (`Box<dyn Draw>`) lets a library work with types it has
+   never heard of. `Screen` only knows that its components can be drawn, so
+   the binary adds its own `Checkbox` without touching the library - the
+   extensibility that inheritance is often used for elsewhere.
+   * Drawing to a String rather than printing keeps the library testable,
+   and leaves the printing to whoever calls it.
+   * See https://doc.rust-lang.org/book/ch18-01-what-is-oo.html and
+   https://doc.rust-lang.org/book/ch18-02-trait-objects.html
+*/
+pub mod averaged;
+pub mod gui;
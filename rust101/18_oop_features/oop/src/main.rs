@@ -0,0 +1,104 @@
+use oop::averaged::AveragedCollection;
+use oop::gui::{Button, Draw, Screen, SelectBox};
+
+// A component the gui library has never heard of. Implementing Draw is all
+// it takes for a Screen to hold and draw it.
+struct Checkbox {
+    label: String,
+    checked: bool,
+}
+
+impl Draw for Checkbox {
+    fn draw(&self) -> String {
+        let mark = if self.checked { 'x' } else { ' ' };
+        format!("[{mark}] {}", self.label)
+    }
+}
+
+fn main() {
+    let mut collection = AveragedCollection::new();
+    for value in [4, 8, 15, 16, 23, 42] {
+        collection.add(value);
+        println!("added {value:>2}: average {:.2}", collection.average());
+    }
+    while let Some(value) = collection.remove() {
+        println!("removed {value:>2}: average {:.2}", collection.average());
+    }
+    println!();
+
+    let screen = Screen {
+        components: vec![
+            Box::new(SelectBox {
+                width: 8,
+                height: 3,
+                options: vec![
+                    String::from("Yes"),
+                    String::from("Maybe"),
+                    String::from("No"),
+                ],
+            }),
+            Box::new(Checkbox {
+                label: String::from("Remember me"),
+                checked: true,
+            }),
+            Box::new(Checkbox {
+                label: String::from("Send me spam"),
+                checked: false,
+            }),
+            Box::new(Button {
+                width: 10,
+                height: 3,
+                label: String::from("OK"),
+            }),
+        ],
+    };
+    print!("{}", screen.run());
+    // Only things that implement Draw can go in:
+    //
+    //     let screen = Screen {
+    //         components: vec![Box::new(String::from("Hi"))],
+    //     };
+    //
+    // error[E0277]: the trait bound `String: Draw` is not satisfied
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkbox_draws_its_state() {
+        let checkbox = |checked| Checkbox {
+            label: String::from("Tick"),
+            checked,
+        };
+        assert_eq!(checkbox(true).draw(), "[x] Tick");
+        assert_eq!(checkbox(false).draw(), "[ ] Tick");
+    }
+
+    #[test]
+    fn screen_draws_a_mix_including_our_own_component() {
+        let screen = Screen {
+            components: vec![
+                Box::new(Checkbox {
+                    label: String::from("Agree"),
+                    checked: false,
+                }),
+                Box::new(Button {
+                    width: 4,
+                    height: 1,
+                    label: String::from("Next"),
+                }),
+                Box::new(SelectBox {
+                    width: 3,
+                    height: 0,
+                    options: vec![String::from("One")],
+                }),
+            ],
+        };
+        assert_eq!(
+            screen.run(),
+            "[ ] Agree\n+----+\n|Next|\n+----+\n[One v]\n  ...\n"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum CalcError {
+    NotANumber(String),
+    UnknownOperation(String),
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CalcError::NotANumber(text) => write!(f, "`{text}` isn't a number"),
+            CalcError::UnknownOperation(name) => write!(f, "no operation called `{name}`"),
+        }
+    }
+}
+
+// Wrapping arithmetic, so no input can make an operation panic.
+fn double(x: i32) -> i32 {
+    x.wrapping_mul(2)
+}
+
+fn square(x: i32) -> i32 {
+    x.wrapping_mul(x)
+}
+
+fn negate(x: i32) -> i32 {
+    x.wrapping_neg()
+}
+
+fn increment(x: i32) -> i32 {
+    x.wrapping_add(1)
+}
+
+// Operations looked up by name. Plain function pointers are enough because
+// none of them capture anything - and unlike closures they all have the
+// same type, so they fit in one HashMap without boxing.
+pub struct Calculator {
+    operations: HashMap<&'static str, fn(i32) -> i32>,
+}
+
+impl Calculator {
+    pub fn new() -> Calculator {
+        let mut operations: HashMap<&'static str, fn(i32) -> i32> = HashMap::new();
+        operations.insert("double", double);
+        operations.insert("square", square);
+        operations.insert("negate", negate);
+        operations.insert("inc", increment);
+        // `i32::wrapping_abs` is a method, but it's also a plain function
+        // taking the i32 as its first argument.
+        operations.insert("abs", i32::wrapping_abs);
+        Calculator { operations }
+    }
+
+    pub fn operation_names(&self) -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = self.operations.keys().copied().collect();
+        names.sort();
+        names
+    }
+
+    pub fn apply(&self, name: &str, value: i32) -> Result<i32, CalcError> {
+        let operation = self
+            .operations
+            .get(name)
+            .ok_or_else(|| CalcError::UnknownOperation(name.to_string()))?;
+        Ok(operation(value))
+    }
+
+    // A number followed by operations, applied left to right:
+    // "3 inc double" is (3 + 1) * 2.
+    pub fn evaluate(&self, program: &str) -> Result<i32, CalcError> {
+        let mut words = program.split_whitespace();
+        let first = words.next().unwrap_or("");
+        let start: i32 = first
+            .parse()
+            .map_err(|_| CalcError::NotANumber(first.to_string()))?;
+        words.try_fold(start, |value, name| self.apply(name, value))
+    }
+}
+
+impl Default for Calculator {
+    fn default() -> Calculator {
+        Calculator::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_each_operation() {
+        let calculator = Calculator::new();
+        assert_eq!(calculator.apply("double", 4), Ok(8));
+        assert_eq!(calculator.apply("square", -3), Ok(9));
+        assert_eq!(calculator.apply("negate", 7), Ok(-7));
+        assert_eq!(calculator.apply("inc", 7), Ok(8));
+        assert_eq!(calculator.apply("abs", -7), Ok(7));
+    }
+
+    #[test]
+    fn unknown_operations_are_errors() {
+        let calculator = Calculator::new();
+        assert_eq!(
+            calculator.apply("triple", 1),
+            Err(CalcError::UnknownOperation("triple".to_string()))
+        );
+        // Names are exact - no case folding.
+        assert!(calculator.apply("Double", 1).is_err());
+        assert_eq!(
+            calculator.evaluate("2 inc nope double"),
+            Err(CalcError::UnknownOperation("nope".to_string()))
+        );
+    }
+
+    #[test]
+    fn evaluates_left_to_right() {
+        let calculator = Calculator::new();
+        assert_eq!(calculator.evaluate("3 inc double"), Ok(8));
+        assert_eq!(calculator.evaluate("3 double inc"), Ok(7));
+        assert_eq!(calculator.evaluate("  -4  abs square "), Ok(16));
+        assert_eq!(calculator.evaluate("12"), Ok(12));
+    }
+
+    #[test]
+    fn needs_a_number_first() {
+        let calculator = Calculator::new();
+        assert_eq!(
+            calculator.evaluate("double 3"),
+            Err(CalcError::NotANumber("double".to_string()))
+        );
+        assert_eq!(
+            calculator.evaluate(""),
+            Err(CalcError::NotANumber(String::new()))
+        );
+    }
+
+    #[test]
+    fn never_overflows() {
+        let calculator = Calculator::new();
+        assert_eq!(calculator.apply("double", i32::MAX), Ok(-2));
+        assert_eq!(calculator.apply("negate", i32::MIN), Ok(i32::MIN));
+        assert_eq!(calculator.apply("abs", i32::MIN), Ok(i32::MIN));
+    }
+
+    #[test]
+    fn lists_its_operations() {
+        assert_eq!(
+            Calculator::new().operation_names(),
+            ["abs", "double", "inc", "negate", "square"]
+        );
+    }
+}
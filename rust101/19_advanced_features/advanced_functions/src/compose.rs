@@ -0,0 +1,49 @@
+// `compose(f, g)` is "f, then g". The result is a closure that owns both,
+// hence `move`.
+pub fn compose<A, B, C>(f: impl Fn(A) -> B, g: impl Fn(B) -> C) -> impl Fn(A) -> C {
+    move |x| g(f(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pointers::add_one;
+
+    fn double(x: i32) -> i32 {
+        x * 2
+    }
+
+    fn square(x: i32) -> i32 {
+        x * x
+    }
+
+    #[test]
+    fn applies_left_to_right() {
+        assert_eq!(compose(add_one, double)(3), 8);
+        assert_eq!(compose(double, add_one)(3), 7);
+    }
+
+    #[test]
+    fn changes_types_along_the_way() {
+        let describe = compose(|s: &str| s.len(), |n: usize| format!("{n} bytes"));
+        assert_eq!(describe("hello"), "5 bytes");
+    }
+
+    #[test]
+    fn composition_is_associative() {
+        for x in -10..=10 {
+            let left = compose(compose(add_one, double), square);
+            let right = compose(add_one, compose(double, square));
+            assert_eq!(left(x), right(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn identity_changes_nothing() {
+        let identity = |x: i32| x;
+        for x in [-5, 0, 42] {
+            assert_eq!(compose(identity, double)(x), double(x));
+            assert_eq!(compose(double, identity)(x), double(x));
+        }
+    }
+}
@@ -0,0 +1,83 @@
+/* Things learned during this exercise:
+   * `fn(i32) -> i32` is a function pointer type. Functions, and closures
+   that capture nothing, coerce to it, and it implements all three closure
+   traits - so named functions can be passed wherever closures are.
+   * Method paths (`ToString::to_string`, `i32::wrapping_abs`) and tuple
+   variants and structs (`Status::Value`) are functions too.
+   * Every closure has a unique anonymous type. `-> impl Fn(i32) -> i32`
+   returns one closure without naming its type; returning one of several
+   needs `Box<dyn Fn(i32) -> i32>`.
+   * Function pointers all share a type, which makes them handy in data
+   structures, like a table of operations looked up by name.
+   * See https://doc.rust-lang.org/book/ch20-04-advanced-functions-and-closures.html
+*/
+mod calculator;
+mod compose;
+mod pointers;
+mod returning;
+
+use calculator::Calculator;
+use compose::compose;
+use pointers::{add_one, do_twice, Status};
+use std::env;
+use std::process;
+
+fn main() {
+    println!("do_twice(add_one, 5) = {}", do_twice(add_one, 5));
+    let numbers = [1, 2, 3];
+    println!(
+        "with a closure: {:?}, with ToString::to_string: {:?}",
+        pointers::to_strings_with_closure(&numbers),
+        pointers::to_strings_with_function(&numbers)
+    );
+    println!("(0..3).map(Status::Value) = {:?}", pointers::statuses(3));
+    println!("and a variant without data: {:?}", Status::Stop);
+    println!();
+
+    let add_one_closure = returning::returns_closure();
+    let add_ten = returning::make_adder(10);
+    println!("impl Fn: {} and {}", add_one_closure(1), add_ten(1));
+    for double in [true, false] {
+        let operation = returning::make_operation(double, 3);
+        println!(
+            "Box<dyn Fn> chosen with double = {double}, n = 3: 5 -> {}",
+            operation(5)
+        );
+    }
+    let shout = compose(str::trim, str::to_uppercase);
+    let exclaim = compose(shout, |s: String| s + "!");
+    println!(
+        "compose(trim, to_uppercase) then add '!': {}",
+        exclaim("  hello ")
+    );
+    println!();
+
+    let calculator = Calculator::new();
+    println!(
+        "calculator operations: {}",
+        calculator.operation_names().join(", ")
+    );
+    let programs: Vec<String> = env::args().skip(1).collect();
+    let programs = if programs.is_empty() {
+        vec![
+            "3 inc double".to_string(),
+            "-7 abs square negate".to_string(),
+            "2 triple".to_string(),
+        ]
+    } else {
+        programs
+    };
+    let mut failed = false;
+    for program in &programs {
+        match calculator.evaluate(program) {
+            Ok(result) => println!("{program} = {result}"),
+            Err(err) => {
+                println!("{program}: {err}");
+                failed = true;
+            }
+        }
+    }
+    if failed && env::args().len() > 1 {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,78 @@
+// `fn` (lower case) is the type of a function pointer. Unlike the closure
+// traits it's a concrete type, and every function pointer implements Fn,
+// FnMut and FnOnce - so a function can go anywhere a closure can.
+
+pub fn add_one(x: i32) -> i32 {
+    x + 1
+}
+
+pub fn do_twice(f: fn(i32) -> i32, arg: i32) -> i32 {
+    f(arg) + f(arg)
+}
+
+// The same conversion twice: once with a closure, once naming the method.
+// `ToString::to_string` is the fully qualified name, because several traits
+// could have a `to_string`.
+pub fn to_strings_with_closure(list_of_numbers: &[i32]) -> Vec<String> {
+    list_of_numbers.iter().map(|i| i.to_string()).collect()
+}
+
+pub fn to_strings_with_function(list_of_numbers: &[i32]) -> Vec<String> {
+    list_of_numbers.iter().map(ToString::to_string).collect()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Value(u32),
+    Stop,
+}
+
+// A tuple variant's name is also a function that builds it, so
+// `Status::Value` is a `fn(u32) -> Status`.
+pub fn statuses(count: u32) -> Vec<Status> {
+    (0u32..count).map(Status::Value).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn function_pointers_are_passed_by_name() {
+        assert_eq!(do_twice(add_one, 5), 12);
+    }
+
+    #[test]
+    fn a_non_capturing_closure_coerces_to_fn() {
+        assert_eq!(do_twice(|x| x * 10, 2), 40);
+        // One that captures can't - there'd be nowhere to keep what it
+        // captured:
+        //
+        //     let offset = 3;
+        //     do_twice(|x| x + offset, 2);
+        //
+        // error[E0308]: mismatched types
+        //   expected fn pointer, found closure
+        //   note: closures can only be coerced to `fn` types if they do not
+        //   capture any variables
+    }
+
+    #[test]
+    fn method_names_work_like_closures() {
+        let numbers = [1, -2, 30];
+        assert_eq!(to_strings_with_closure(&numbers), ["1", "-2", "30"]);
+        assert_eq!(
+            to_strings_with_function(&numbers),
+            to_strings_with_closure(&numbers)
+        );
+    }
+
+    #[test]
+    fn variants_are_constructors() {
+        assert_eq!(
+            statuses(3),
+            [Status::Value(0), Status::Value(1), Status::Value(2)]
+        );
+        assert_ne!(statuses(1)[0], Status::Stop);
+    }
+}
@@ -0,0 +1,62 @@
+// Every closure has its own anonymous type, so a function returning one
+// either names it through `impl Fn` - one closure, its type hidden - or
+// boxes it as a `dyn Fn` trait object, which can be any closure at all.
+
+pub fn returns_closure() -> impl Fn(i32) -> i32 {
+    |x| x + 1
+}
+
+// `impl Fn` has to be a single type, so choosing between two closures at
+// run time doesn't compile:
+//
+//     fn make_operation(double: bool, n: i32) -> impl Fn(i32) -> i32 {
+//         if double { move |x| x * n } else { move |x| x + n }
+//     }
+//
+// error[E0308]: `if` and `else` have incompatible types
+//   = note: no two closures, even if identical, have the same type
+//
+// (Closures that capture nothing would compile: both coerce to the same
+// `fn(i32) -> i32` pointer type.) Boxing them makes them the same type:
+// `Box<dyn Fn(i32) -> i32>`.
+pub fn make_operation(double: bool, n: i32) -> Box<dyn Fn(i32) -> i32> {
+    if double {
+        Box::new(move |x| x * n)
+    } else {
+        Box::new(move |x| x + n)
+    }
+}
+
+// `move` because the closure outlives this call, and `step` with it.
+pub fn make_adder(step: i32) -> impl Fn(i32) -> i32 {
+    move |x| x + step
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn impl_fn_closure() {
+        let add_one = returns_closure();
+        assert_eq!(add_one(1), 2);
+        assert_eq!(make_adder(10)(5), 15);
+    }
+
+    #[test]
+    fn boxed_closures_chosen_at_run_time() {
+        let operations = [make_operation(true, 2), make_operation(false, 1)];
+        let results: Vec<i32> = operations.iter().map(|op| op(5)).collect();
+        assert_eq!(results, [10, 6]);
+    }
+
+    #[test]
+    fn impl_fn_values_can_be_boxed_too() {
+        let mixed: Vec<Box<dyn Fn(i32) -> i32>> = vec![
+            Box::new(returns_closure()),
+            Box::new(make_adder(-3)),
+            make_operation(true, 2),
+        ];
+        assert_eq!(mixed.iter().map(|f| f(1)).collect::<Vec<_>>(), [2, -2, 2]);
+    }
+}
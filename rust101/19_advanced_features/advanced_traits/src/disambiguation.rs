@@ -0,0 +1,80 @@
+// The book's Pilot/Wizard example, returning Strings so the tests can check
+// which method ran.
+pub trait Pilot {
+    fn fly(&self) -> String;
+}
+
+pub trait Wizard {
+    fn fly(&self) -> String;
+}
+
+pub struct Human;
+
+impl Pilot for Human {
+    fn fly(&self) -> String {
+        "This is your captain speaking.".to_string()
+    }
+}
+
+impl Wizard for Human {
+    fn fly(&self) -> String {
+        "Up!".to_string()
+    }
+}
+
+// With three `fly`s to choose from, `person.fly()` picks the one defined on
+// the type itself.
+impl Human {
+    pub fn fly(&self) -> String {
+        "*waving arms furiously*".to_string()
+    }
+}
+
+// Associated functions have no `self` to say whose they are, which is where
+// fully qualified syntax comes in.
+pub trait Animal {
+    fn baby_name() -> String;
+}
+
+pub struct Dog;
+
+impl Dog {
+    pub fn baby_name() -> String {
+        String::from("Spot")
+    }
+}
+
+impl Animal for Dog {
+    fn baby_name() -> String {
+        String::from("puppy")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_types_own_method_wins() {
+        let person = Human;
+        assert_eq!(person.fly(), "*waving arms furiously*");
+        assert_eq!(Human::fly(&person), "*waving arms furiously*");
+    }
+
+    #[test]
+    fn naming_the_trait_picks_its_method() {
+        let person = Human;
+        assert_eq!(Pilot::fly(&person), "This is your captain speaking.");
+        assert_eq!(Wizard::fly(&person), "Up!");
+    }
+
+    #[test]
+    fn fully_qualified_syntax_for_associated_functions() {
+        assert_eq!(Dog::baby_name(), "Spot");
+        // `Animal::baby_name()` can't work out which type is meant:
+        //
+        // error[E0790]: cannot call associated function on trait without
+        // specifying the corresponding `impl` type
+        assert_eq!(<Dog as Animal>::baby_name(), "puppy");
+    }
+}
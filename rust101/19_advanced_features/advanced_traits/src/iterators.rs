@@ -0,0 +1,83 @@
+// Associated type or generic parameter?
+//
+//     pub trait Iterator {
+//         type Item;
+//         fn next(&mut self) -> Option<Self::Item>;
+//     }
+//
+//     pub trait GenericIterator<T> {
+//         fn next(&mut self) -> Option<T>;
+//     }
+//
+// With an associated type a type implements Iterator once, choosing its
+// Item. With a parameter it can implement `GenericIterator<u32>`,
+// `GenericIterator<String>` and so on, all at once - so every call has to
+// say which one it means. `Countdown` below implements both versions to
+// show the difference.
+
+pub struct Countdown {
+    from: u32,
+}
+
+impl Countdown {
+    pub fn new(from: u32) -> Countdown {
+        Countdown { from }
+    }
+}
+
+impl Iterator for Countdown {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.from == 0 {
+            None
+        } else {
+            self.from -= 1;
+            Some(self.from + 1)
+        }
+    }
+}
+
+pub trait GenericIterator<T> {
+    fn next_item(&mut self) -> Option<T>;
+}
+
+impl GenericIterator<u32> for Countdown {
+    fn next_item(&mut self) -> Option<u32> {
+        Iterator::next(self)
+    }
+}
+
+impl GenericIterator<String> for Countdown {
+    fn next_item(&mut self) -> Option<String> {
+        Iterator::next(self).map(|n| match n {
+            1 => "liftoff".to_string(),
+            n => n.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_associated_type_needs_no_annotation() {
+        let counted: Vec<u32> = Countdown::new(3).collect();
+        assert_eq!(counted, [3, 2, 1]);
+        assert_eq!(Countdown::new(0).next(), None);
+    }
+
+    #[test]
+    fn the_generic_version_has_to_be_told_which() {
+        let mut countdown = Countdown::new(2);
+        // `countdown.next_item()` alone is ambiguous:
+        //
+        // error[E0283]: type annotations needed
+        let first: Option<u32> = countdown.next_item();
+        let second = GenericIterator::<String>::next_item(&mut countdown);
+        assert_eq!(first, Some(2));
+        assert_eq!(second.as_deref(), Some("liftoff"));
+        assert_eq!(GenericIterator::<u32>::next_item(&mut countdown), None);
+    }
+}
@@ -0,0 +1,68 @@
+/* Things learned during this exercise:
+   * Operators are traits in `std::ops`: implementing `Add` makes `+` work,
+   `AddAssign` makes `+=` work, `Neg` is unary `-`, `Index` is `[]`. Only
+   the operators listed there can be overloaded.
+   * Generic type parameters can have defaults, like `Add<Rhs = Self>`.
+   Overriding the default gives mixed-type operators: Millimeters + Meters.
+   * An associated type (`Iterator::Item`) means one implementation per
+   type; a generic parameter means any number of them, and callers have to
+   say which one they want.
+   * When several traits (and the type) have a method of the same name,
+   `Trait::method(&value)` picks one. Associated functions have no `self`,
+   so they need the fully qualified `<Type as Trait>::function()`.
+   * A supertrait (`trait OutlinePrint: Display`) requires another trait,
+   and its default methods can use that trait's methods.
+   * The newtype pattern gets round the orphan rule: wrap a foreign type in a
+   local struct, and implement foreign traits on that.
+   * See https://doc.rust-lang.org/book/ch20-02-advanced-traits.html
+*/
+mod disambiguation;
+mod iterators;
+mod outline;
+mod point;
+mod units;
+mod wrapper;
+
+use disambiguation::{Animal, Dog, Human, Pilot, Wizard};
+use iterators::{Countdown, GenericIterator};
+use outline::OutlinePrint;
+use point::{Axis, Point};
+use units::{Meters, Millimeters};
+use wrapper::Wrapper;
+
+fn main() {
+    let mut point = Point { x: 1, y: 0 } + Point { x: 2, y: 3 };
+    println!("(1, 0) + (2, 3) = {point}");
+    point += Point { x: 10, y: 10 };
+    println!("+= (10, 10) gives {point}, negated {}", -point);
+    println!("x = {}, y = {}", point[Axis::X], point[Axis::Y]);
+    println!(
+        "{:?} + {:?} = {:?}",
+        Millimeters(500),
+        Meters(1),
+        Millimeters(500) + Meters(1)
+    );
+    println!();
+
+    println!(
+        "Countdown as an Iterator: {:?}",
+        Countdown::new(3).collect::<Vec<_>>()
+    );
+    let mut countdown = Countdown::new(2);
+    let as_number: Option<u32> = countdown.next_item();
+    let as_text: Option<String> = countdown.next_item();
+    println!("as a GenericIterator: {as_number:?}, then {as_text:?}");
+    println!();
+
+    let person = Human;
+    println!("{}", Pilot::fly(&person));
+    println!("{}", Wizard::fly(&person));
+    println!("{}", person.fly());
+    println!("A baby dog is called a {}", Dog::baby_name());
+    println!("A baby dog is called a {}", <Dog as Animal>::baby_name());
+    println!();
+
+    println!("{}", Point { x: 1, y: 3 }.outline_print());
+    let w = Wrapper::new(vec![String::from("hello"), String::from("world")]);
+    println!("w = {w}, with {} items", w.len());
+}
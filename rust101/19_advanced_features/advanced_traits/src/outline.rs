@@ -0,0 +1,54 @@
+use std::fmt;
+
+// A supertrait: only types that already implement Display can implement
+// OutlinePrint, which is what lets `outline_print` call `to_string`.
+pub trait OutlinePrint: fmt::Display {
+    fn outline_print(&self) -> String {
+        let output = self.to_string();
+        let len = output.chars().count();
+        let rows = [
+            "*".repeat(len + 4),
+            format!("*{}*", " ".repeat(len + 2)),
+            format!("* {output} *"),
+            format!("*{}*", " ".repeat(len + 2)),
+            "*".repeat(len + 4),
+        ];
+        rows.join("\n")
+    }
+}
+
+// Nothing to write: the default method does it all.
+impl OutlinePrint for crate::point::Point {}
+
+// Without Display the impl is rejected:
+//
+//     struct Blank;
+//     impl OutlinePrint for Blank {}
+//
+// error[E0277]: `Blank` doesn't implement `std::fmt::Display`
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::point::Point;
+
+    #[test]
+    fn outlines_a_point() {
+        assert_eq!(
+            Point { x: 1, y: 3 }.outline_print(),
+            "**********\n\
+             *        *\n\
+             * (1, 3) *\n\
+             *        *\n\
+             **********"
+        );
+    }
+
+    #[test]
+    fn outline_fits_wider_text() {
+        let outline = Point { x: -100, y: 2000 }.outline_print();
+        let lines: Vec<&str> = outline.lines().collect();
+        assert_eq!(lines[2], "* (-100, 2000) *");
+        assert!(lines.iter().all(|line| line.len() == lines[0].len()));
+    }
+}
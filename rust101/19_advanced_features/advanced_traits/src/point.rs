@@ -0,0 +1,107 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, Neg};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+// `Add` has a default type parameter, `Rhs = Self`, so `impl Add for Point`
+// means adding a Point to a Point.
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+// `+=` is a separate trait. It changes the left side in place, so there's
+// no Output.
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+// Unary minus.
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+// Indexed by an enum rather than 0 and 1, so `point[Axis::X]` can't be out
+// of bounds - there's nothing to panic about.
+impl Index<Axis> for Point {
+    type Output = i32;
+
+    fn index(&self, axis: Axis) -> &i32 {
+        match axis {
+            Axis::X => &self.x,
+            Axis::Y => &self.y,
+        }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_points() {
+        assert_eq!(
+            Point { x: 1, y: 0 } + Point { x: 2, y: 3 },
+            Point { x: 3, y: 3 }
+        );
+    }
+
+    #[test]
+    fn add_assigns_points() {
+        let mut point = Point { x: 1, y: 1 };
+        point += Point { x: -4, y: 2 };
+        point += Point { x: 0, y: 1 };
+        assert_eq!(point, Point { x: -3, y: 4 });
+    }
+
+    #[test]
+    fn negates_points() {
+        assert_eq!(-Point { x: 3, y: -7 }, Point { x: -3, y: 7 });
+        let origin = Point { x: 0, y: 0 };
+        assert_eq!(-origin, origin);
+    }
+
+    #[test]
+    fn indexes_by_axis() {
+        let point = Point { x: 5, y: -2 };
+        assert_eq!(point[Axis::X], 5);
+        assert_eq!(point[Axis::Y], -2);
+    }
+
+    #[test]
+    fn displays_as_a_pair() {
+        assert_eq!(Point { x: 1, y: 3 }.to_string(), "(1, 3)");
+    }
+}
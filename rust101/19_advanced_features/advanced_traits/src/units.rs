@@ -0,0 +1,47 @@
+use std::ops::Add;
+
+// Newtypes for the units, so millimetres and metres can't be mixed up by
+// accident - only through impls that convert properly.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Millimeters(pub u32);
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Meters(pub u32);
+
+impl Add for Millimeters {
+    type Output = Millimeters;
+
+    fn add(self, other: Millimeters) -> Millimeters {
+        Millimeters(self.0 + other.0)
+    }
+}
+
+// Overriding the default `Rhs = Self`: Millimeters + Meters.
+impl Add<Meters> for Millimeters {
+    type Output = Millimeters;
+
+    fn add(self, other: Meters) -> Millimeters {
+        Millimeters(self.0 + (other.0 * 1000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_millimeters() {
+        assert_eq!(Millimeters(5) + Millimeters(7), Millimeters(12));
+    }
+
+    #[test]
+    fn adds_meters_to_millimeters() {
+        assert_eq!(Millimeters(250) + Meters(2), Millimeters(2250));
+        // There's no `impl Add<Millimeters> for Meters`, so the other way
+        // round doesn't compile:
+        //
+        //     Meters(2) + Millimeters(250)
+        //
+        // error[E0369]: cannot add `Millimeters` to `Meters`
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+use std::ops::Deref;
+
+// The orphan rule: a trait can only be implemented for a type if the trait
+// or the type is local to this crate. Display and Vec both belong to the
+// standard library, so `impl fmt::Display for Vec<String>` is E0117. A
+// newtype around the Vec is a local type, so it can have Display. The field
+// is private, so once it's built the only way in is through the methods below.
+pub struct Wrapper(Vec<String>);
+
+impl Wrapper {
+    pub fn new(items: Vec<String>) -> Wrapper {
+        Wrapper(items)
+    }
+}
+
+impl fmt::Display for Wrapper {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}]", self.0.join(", "))
+    }
+}
+
+// The newtype hides all of Vec's methods. Deref brings back the read-only
+// ones (`len`, `iter`, indexing...), without letting callers push to it.
+impl Deref for Wrapper {
+    type Target = Vec<String>;
+
+    fn deref(&self) -> &Vec<String> {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_its_contents() {
+        let w = Wrapper::new(vec![String::from("hello"), String::from("world")]);
+        assert_eq!(w.to_string(), "[hello, world]");
+        assert_eq!(Wrapper::new(vec![]).to_string(), "[]");
+    }
+
+    #[test]
+    fn vec_methods_through_deref() {
+        let w = Wrapper::new(vec![String::from("a"), String::from("b")]);
+        assert_eq!(w.len(), 2);
+        assert_eq!(w[1], "b");
+        assert!(w.contains(&String::from("a")));
+    }
+}
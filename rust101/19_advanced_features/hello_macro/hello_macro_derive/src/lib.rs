@@ -0,0 +1,63 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, LitStr};
+
+// `attributes(hello)` registers `#[hello(...)]` as ours, so it's allowed on
+// items deriving HelloMacro.
+#[proc_macro_derive(HelloMacro, attributes(hello))]
+pub fn hello_macro_derive(input: TokenStream) -> TokenStream {
+    // The book unwraps here. Derives only ever see items the compiler has
+    // already parsed, so this can't fail - unlike what follows, where a
+    // mistake by the user becomes a compile error instead of a panic.
+    let ast: DeriveInput = syn::parse(input).unwrap();
+
+    impl_hello_macro(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn impl_hello_macro(ast: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    // Nothing about greeting a type by name stops it working for unions.
+    // They're turned away anyway, to show what an error from a derive
+    // looks like - tests/ui/fail_union.stderr.
+    if let Data::Union(union) = &ast.data {
+        return Err(syn::Error::new_spanned(
+            union.union_token,
+            "HelloMacro can only be derived for structs and enums",
+        ));
+    }
+
+    let greeting = greeting(ast)?;
+    let name = &ast.ident;
+    // Copies any generics over, so `Stack<T>` gets `impl<T> HelloMacro for
+    // Stack<T>`.
+    let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics HelloMacro for #name #type_generics #where_clause {
+            fn hello_macro() {
+                println!("{}, Macro! My name is {}!", #greeting, stringify!(#name));
+            }
+        }
+    })
+}
+
+// "Hello", unless there's a `#[hello(greeting = "...")]`.
+fn greeting(ast: &DeriveInput) -> syn::Result<String> {
+    let mut greeting = String::from("Hello");
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("hello"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("greeting") {
+                let value: LitStr = meta.value()?.parse()?;
+                greeting = value.value();
+                Ok(())
+            } else {
+                Err(meta.error("expected `greeting = \"...\"`"))
+            }
+        })?;
+    }
+    Ok(greeting)
+}
@@ -0,0 +1,10 @@
+// Compiles each file in tests/ui. The `pass` ones must build (and run); the
+// `fail` ones must fail with exactly the errors in the matching .stderr file.
+// After changing an error message, `TRYBUILD=overwrite cargo test` rewrites
+// the .stderr files - check the diff before committing them.
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass_*.rs");
+    t.compile_fail("tests/ui/fail_*.rs");
+}
@@ -0,0 +1,24 @@
+use hello_macro::HelloMacro;
+use hello_macro_derive::HelloMacro;
+
+#[derive(HelloMacro)]
+struct Pancakes;
+
+#[derive(HelloMacro)]
+#[hello(greeting = "Howdy")]
+enum Waffles {
+    Belgian,
+    Liege,
+}
+
+#[derive(HelloMacro)]
+struct Stack<T: Clone> {
+    items: Vec<T>,
+}
+
+fn main() {
+    Pancakes::hello_macro();
+    Waffles::hello_macro();
+    Stack::<u8>::hello_macro();
+    let _ = (Waffles::Belgian, Waffles::Liege, Stack { items: vec![1u8] }.items);
+}
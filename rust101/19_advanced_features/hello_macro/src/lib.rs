@@ -0,0 +1,22 @@
+/* Things learned during this exercise:
+   * A procedural macro is a function from tokens to tokens that runs at
+   compile time. A custom derive - `#[derive(HelloMacro)]` - is handed the
+   struct or enum it's attached to and returns extra code to add after it,
+   here an `impl HelloMacro`.
+   * Proc macros must be in their own crate with `proc-macro = true`, which
+   can't export anything else. So there are two crates: this one with the
+   trait, and `hello_macro_derive` with the derive, named by convention.
+   * `syn` parses the tokens into a syntax tree (`DeriveInput`), and
+   `quote!` turns Rust code with `#variables` in it back into tokens.
+   `stringify!` makes the type's name into a string literal at compile
+   time.
+   * A derive can claim helper attributes - `#[hello(greeting = "Howdy")]` -
+   that it reads off the item. The compiler otherwise rejects them.
+   * Bad input should be a compile error pointing at the problem
+   (`syn::Error::to_compile_error`), not a panic. `trybuild` tests check
+   those errors by compiling small programs and comparing the output.
+   * See https://doc.rust-lang.org/book/ch20-05-macros.html
+*/
+pub trait HelloMacro {
+    fn hello_macro();
+}
@@ -0,0 +1,100 @@
+/// A `HashMap` literal: `hashmap! { key => value, ... }`, with or without a
+/// trailing comma.
+///
+/// ```
+/// use macros::hashmap;
+/// use std::collections::HashMap;
+///
+/// let scores = hashmap! {
+///     "Blue" => 10,
+///     "Yellow" => 50,
+/// };
+/// assert_eq!(scores["Yellow"], 50);
+///
+/// let empty: HashMap<&str, i32> = hashmap! {};
+/// assert!(empty.is_empty());
+/// ```
+///
+/// A key given twice keeps its last value, as `insert` would.
+#[macro_export]
+macro_rules! hashmap {
+    () => {
+        ::std::collections::HashMap::new()
+    };
+    ( $( $key:expr => $value:expr ),+ $(,)? ) => {{
+        let mut map = ::std::collections::HashMap::new();
+        $(
+            map.insert($key, $value);
+        )+
+        map
+    }};
+}
+
+// A key without a value:
+//
+//     hashmap! { "a" => 1, "b" };
+//
+// error: unexpected end of macro invocation
+// note: while trying to match `=>`
+//
+// And `:` where `=>` was expected:
+//
+//     hashmap! { "a": 1 };
+//
+// error: no rules expected `:`
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    #[test]
+    fn empty() {
+        let map: HashMap<String, u8> = hashmap! {};
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn entries() {
+        let map = hashmap! { "one" => 1, "two" => 2 };
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["one"], 1);
+        assert_eq!(map["two"], 2);
+    }
+
+    #[test]
+    fn trailing_comma() {
+        let map = hashmap! {
+            'a' => true,
+        };
+        assert_eq!(map, HashMap::from([('a', true)]));
+    }
+
+    #[test]
+    fn later_duplicates_win() {
+        let map = hashmap! { "k" => 1, "k" => 2 };
+        assert_eq!(map.len(), 1);
+        assert_eq!(map["k"], 2);
+    }
+
+    #[test]
+    fn any_brackets_will_do() {
+        let braces = hashmap! { 1 => 2 };
+        let parens = hashmap!(1 => 2);
+        let square = hashmap![1 => 2];
+        assert_eq!(braces, parens);
+        assert_eq!(parens, square);
+    }
+
+    #[test]
+    fn nested() {
+        let map = hashmap! {
+            "evens" => crate::my_vec![2, 4],
+            "none" => crate::my_vec![],
+        };
+        assert_eq!(map["evens"], [2, 4]);
+        assert!(map["none"].is_empty());
+
+        let outer = hashmap! { "inner" => hashmap! { 1 => "one" } };
+        assert_eq!(outer["inner"][&1], "one");
+    }
+}
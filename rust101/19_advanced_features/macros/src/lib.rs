@@ -0,0 +1,21 @@
+/* Things learned during this exercise:
+   * `macro_rules!` macros match their input against patterns, like `match`
+   does, and replace the call with the code of the first arm that fits.
+   They work on the code itself - tokens - before any type checking.
+   * Fragment specifiers say what to match: `$x:expr` an expression,
+   `$t:ty` a type, `$i:ident` a name, and so on. `$( ... ),*` repeats zero
+   or more times separated by commas (`+` for one or more, `?` for at most
+   once), and `$( ... )*` in the output repeats once per match.
+   * A trailing comma has to be allowed explicitly, with `$(,)?`.
+   * Macros are hygienic: a variable made inside one (like `temp_vec`)
+   can't clash with the caller's variables. Paths aren't, so they're written
+   in full (`::std::collections::HashMap`) to work wherever the macro's used.
+   * `#[macro_export]` puts a macro at the root of the crate, whatever
+   module it's defined in, so users write `use macros::hashmap;`.
+   * When no arm matches, the error is about tokens ("no rules expected
+   this token"), not types - see the comments in each module.
+   * See https://doc.rust-lang.org/book/ch20-05-macros.html
+*/
+mod hashmap;
+mod my_vec;
+mod retry;
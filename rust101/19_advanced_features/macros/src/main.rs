@@ -0,0 +1,37 @@
+use macros::{hashmap, my_vec, retry};
+use std::cell::Cell;
+
+fn main() {
+    let numbers = my_vec![1, 2, 3];
+    let zeros = my_vec![0; 5];
+    let empty: Vec<i32> = my_vec![];
+    println!("my_vec![1, 2, 3] = {numbers:?}");
+    println!("my_vec![0; 5] = {zeros:?}");
+    println!("my_vec![] = {empty:?}");
+
+    let capitals = hashmap! {
+        "France" => "Paris",
+        "Japan" => "Tokyo",
+        "Peru" => "Lima",
+    };
+    let mut countries: Vec<_> = capitals.keys().collect();
+    countries.sort();
+    for country in countries {
+        println!("the capital of {country} is {}", capitals[country]);
+    }
+
+    let attempts = Cell::new(0);
+    let connect = || {
+        attempts.set(attempts.get() + 1);
+        if attempts.get() < 3 {
+            println!("attempt {}: connection refused", attempts.get());
+            Err("connection refused")
+        } else {
+            println!("attempt {}: connected", attempts.get());
+            Ok("connected")
+        }
+    };
+    println!("retry!(5, connect()) = {:?}", retry!(5, connect()));
+    attempts.set(0);
+    println!("retry!(2, connect()) = {:?}", retry!(2, connect()));
+}
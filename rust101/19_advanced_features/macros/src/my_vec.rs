@@ -0,0 +1,109 @@
+/// The book's simplified `vec!`, plus the `[value; count]` form.
+///
+/// ```
+/// use macros::my_vec;
+///
+/// let empty: Vec<i32> = my_vec![];
+/// assert!(empty.is_empty());
+/// assert_eq!(my_vec![1, 2, 3], vec![1, 2, 3]);
+/// assert_eq!(my_vec!["a", "b",], ["a", "b"]);
+/// assert_eq!(my_vec![0; 4], [0, 0, 0, 0]);
+/// ```
+#[macro_export]
+macro_rules! my_vec {
+    () => {
+        ::std::vec::Vec::new()
+    };
+    ( $elem:expr ; $count:expr ) => {{
+        let mut temp_vec = ::std::vec::Vec::new();
+        // `resize` clones `$elem` into every slot, so it's only evaluated
+        // once - `my_vec![rand(); 3]` repeats one value, like `vec!` does.
+        temp_vec.resize($count, $elem);
+        temp_vec
+    }};
+    ( $( $x:expr ),+ $(,)? ) => {{
+        let mut temp_vec = ::std::vec::Vec::new();
+        // `temp_vec.push($x)` as the book writes it, but spelled out in full:
+        // Clippy spots a new Vec followed by pushes and suggests `vec![]`,
+        // which is the macro this one is standing in for.
+        $(
+            ::std::vec::Vec::push(&mut temp_vec, $x);
+        )+
+        temp_vec
+    }};
+}
+
+// Invocations that don't match any arm:
+//
+//     my_vec![1 2];
+//
+// error: no rules expected `2`
+//    |         -^ no rules expected this token in macro call
+// note: while trying to match `;`
+//
+// (`1` matched `$elem:expr` in the `[value; count]` arm, which then wanted
+// a `;`.) A mix of types matches fine, because
+// macros don't know about types - the error comes from the code they
+// produce:
+//
+//     my_vec![1, "two"];
+//
+// error[E0308]: mismatched types
+//    expected integer, found `&str`
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn empty() {
+        let v: Vec<String> = my_vec![];
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn a_list_of_values() {
+        assert_eq!(my_vec![1], [1]);
+        assert_eq!(my_vec![1, 2, 3], [1, 2, 3]);
+        assert_eq!(my_vec![String::from("a")], [String::from("a")]);
+    }
+
+    #[test]
+    fn trailing_comma() {
+        assert_eq!(my_vec![1, 2, 3,], [1, 2, 3]);
+        assert_eq!(my_vec![1,], [1]);
+    }
+
+    #[test]
+    fn repeated_value() {
+        assert_eq!(my_vec!['x'; 3], ['x', 'x', 'x']);
+        let none: Vec<u8> = my_vec![7; 0];
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn each_expression_is_evaluated_once_in_order() {
+        let mut calls = Vec::new();
+        let mut next = |n: i32| {
+            calls.push(n);
+            n * 10
+        };
+        let v = my_vec![next(1), next(2), next(3)];
+        assert_eq!(v, [10, 20, 30]);
+        assert_eq!(calls, [1, 2, 3]);
+    }
+
+    #[test]
+    fn nested() {
+        let grid: Vec<Vec<i32>> = my_vec![my_vec![1, 2], my_vec![], my_vec![0; 2]];
+        assert_eq!(grid, [vec![1, 2], vec![], vec![0, 0]]);
+        let maps = my_vec![crate::hashmap! { 1 => 'a' }, crate::hashmap! {}];
+        assert_eq!(maps[0][&1], 'a');
+        assert!(maps[1].is_empty());
+    }
+
+    #[test]
+    fn hygiene_keeps_our_temp_vec_separate() {
+        let temp_vec = "the caller's";
+        let v = my_vec![temp_vec, temp_vec];
+        assert_eq!(v, ["the caller's", "the caller's"]);
+    }
+}
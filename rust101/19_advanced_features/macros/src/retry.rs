@@ -0,0 +1,117 @@
+/// Evaluates a `Result` expression up to `times` times, stopping at the
+/// first `Ok`. Gives back that `Ok`, or the last `Err` if every attempt
+/// failed.
+///
+/// ```
+/// use macros::retry;
+///
+/// let mut attempts = 0;
+/// let result: Result<u32, String> = retry!(3, {
+///     attempts += 1;
+///     if attempts < 3 { Err(format!("attempt {attempts} failed")) } else { Ok(attempts) }
+/// });
+/// assert_eq!(result, Ok(3));
+/// ```
+///
+/// The expression is pasted into a loop, so it runs again on each attempt -
+/// which a function taking a `Result` couldn't do, since the argument would
+/// be evaluated once before the call.
+///
+/// # Panics
+///
+/// Panics if `times` is 0: there'd be no result to give back.
+#[macro_export]
+macro_rules! retry {
+    ( $times:expr, $body:expr $(,)? ) => {{
+        let times: usize = $times;
+        assert!(times > 0, "retry! needs at least one attempt");
+        let mut attempt = 1;
+        loop {
+            match $body {
+                ::std::result::Result::Ok(value) => break ::std::result::Result::Ok(value),
+                ::std::result::Result::Err(err) if attempt >= times => {
+                    break ::std::result::Result::Err(err)
+                }
+                ::std::result::Result::Err(_) => attempt += 1,
+            }
+        }
+    }};
+}
+
+// Leaving out the count:
+//
+//     retry!(connect());
+//
+// error: unexpected end of macro invocation
+// note: while trying to match `,`
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    // Fails until it's been called `succeed_on` times.
+    fn flaky(calls: &Cell<usize>, succeed_on: usize) -> Result<usize, String> {
+        calls.set(calls.get() + 1);
+        if calls.get() >= succeed_on {
+            Ok(calls.get())
+        } else {
+            Err(format!("failure {}", calls.get()))
+        }
+    }
+
+    #[test]
+    fn stops_at_the_first_success() {
+        let calls = Cell::new(0);
+        assert_eq!(retry!(5, flaky(&calls, 2)), Ok(2));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn gives_up_with_the_last_error() {
+        let calls = Cell::new(0);
+        assert_eq!(retry!(3, flaky(&calls, 10)), Err("failure 3".to_string()));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn one_attempt_means_no_retries() {
+        let calls = Cell::new(0);
+        assert!(retry!(1, flaky(&calls, 2)).is_err());
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn trailing_comma() {
+        let result: Result<i32, ()> = retry!(2, Ok(7),);
+        assert_eq!(result, Ok(7));
+    }
+
+    #[test]
+    #[should_panic(expected = "retry! needs at least one attempt")]
+    fn zero_attempts_panics() {
+        let _: Result<(), ()> = retry!(0, Ok(()));
+    }
+
+    #[test]
+    fn nested() {
+        let outer = Cell::new(0);
+        let inner = Cell::new(0);
+        // Each outer attempt makes up to two inner ones.
+        let result = retry!(3, {
+            outer.set(outer.get() + 1);
+            retry!(2, flaky(&inner, 5))
+        });
+        assert_eq!(result, Ok(5));
+        assert_eq!(outer.get(), 3);
+
+        let built = retry!(2, Ok::<_, ()>(crate::my_vec![1, 2]));
+        assert_eq!(built, Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn hygiene_keeps_our_attempt_counter_separate() {
+        let attempt = 100;
+        let result: Result<i32, ()> = retry!(2, Ok(attempt));
+        assert_eq!(result, Ok(100));
+    }
+}
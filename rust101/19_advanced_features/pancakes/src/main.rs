@@ -0,0 +1,24 @@
+use hello_macro::HelloMacro;
+use hello_macro_derive::HelloMacro;
+
+#[derive(HelloMacro)]
+struct Pancakes;
+
+#[derive(HelloMacro)]
+#[hello(greeting = "Howdy")]
+struct Waffles;
+
+// Writing the impl by hand still works - the derive only saves typing.
+struct Crepes;
+
+impl HelloMacro for Crepes {
+    fn hello_macro() {
+        println!("Bonjour! I was written by hand.");
+    }
+}
+
+fn main() {
+    Pancakes::hello_macro();
+    Waffles::hello_macro();
+    Crepes::hello_macro();
+}
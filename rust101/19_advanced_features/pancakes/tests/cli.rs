@@ -0,0 +1,17 @@
+use std::process::Command;
+
+// The generated `hello_macro` prints, so the way to see what it printed is
+// to run the program and read its stdout.
+#[test]
+fn generated_functions_print_the_type_names() {
+    let output = Command::new(env!("CARGO_BIN_EXE_pancakes"))
+        .output()
+        .expect("failed to run pancakes");
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Hello, Macro! My name is Pancakes!\n\
+         Howdy, Macro! My name is Waffles!\n\
+         Bonjour! I was written by hand.\n"
+    );
+}
@@ -0,0 +1,57 @@
+use std::sync::Mutex;
+
+// The book's mutable static, behind a safe API. The book's version is only
+// sound on one thread: two threads doing `COUNTER += inc` at once is a data
+// race, which is undefined behaviour - and `cargo test` runs tests on
+// several threads. Every access here goes through LOCK, so there's never
+// more than one. (In real code an `AtomicU32` does this without unsafe.)
+static mut COUNTER: u32 = 0;
+static LOCK: Mutex<()> = Mutex::new(());
+
+// Adds `inc` and returns the new count, wrapping rather than overflowing.
+pub fn add_to_count(inc: u32) -> u32 {
+    let _guard = LOCK.lock().unwrap();
+    // SAFETY: COUNTER is only touched in this module, always while holding
+    // LOCK, so this thread has it to itself. It's read and written by
+    // value, never through a reference that could outlive the guard.
+    unsafe {
+        COUNTER = COUNTER.wrapping_add(inc);
+        COUNTER
+    }
+}
+
+pub fn count() -> u32 {
+    let _guard = LOCK.lock().unwrap();
+    // SAFETY: as above - LOCK is held, and the value is copied out.
+    unsafe { COUNTER }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    // The counter is shared by every test, so this only checks that the
+    // count goes up by at least what was added - other tests may add too.
+    #[test]
+    fn adds_up() {
+        let before = count();
+        let after = add_to_count(3);
+        assert!(after.wrapping_sub(before) >= 3);
+    }
+
+    #[test]
+    fn no_increments_lost_across_threads() {
+        let before = count();
+        thread::scope(|s| {
+            for _ in 0..8 {
+                s.spawn(|| {
+                    for _ in 0..1_000 {
+                        add_to_count(1);
+                    }
+                });
+            }
+        });
+        assert!(count().wrapping_sub(before) >= 8_000);
+    }
+}
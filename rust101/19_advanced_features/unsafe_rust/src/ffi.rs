@@ -0,0 +1,34 @@
+// `abs` from the C standard library, which Rust programs link anyway.
+// Every foreign function is unsafe to call: Rust can't check that the
+// declaration matches the real function, or what the function does.
+unsafe extern "C" {
+    fn abs(input: i32) -> i32;
+}
+
+// C's `abs(INT_MIN)` is undefined behaviour - the answer doesn't fit in an
+// int - so a safe wrapper can't just pass everything through. Like
+// `i32::checked_abs`, it returns None for that one value.
+pub fn c_abs(input: i32) -> Option<i32> {
+    if input == i32::MIN {
+        return None;
+    }
+    // SAFETY: `abs` is declared with C's signature, `int abs(int)`, and i32
+    // is a C int on every platform Rust supports. It has no side effects and
+    // is defined for every int except INT_MIN, which is excluded above.
+    Some(unsafe { abs(input) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Miri can't run foreign functions, so skip this one under
+    // `cargo +nightly miri test`.
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn agrees_with_checked_abs() {
+        for x in [-3, 0, 3, i32::MAX, i32::MIN + 1, i32::MIN] {
+            assert_eq!(c_abs(x), x.checked_abs(), "{x}");
+        }
+    }
+}
@@ -0,0 +1,52 @@
+/* Things learned during this exercise:
+   * `unsafe` unlocks five things the compiler can't check: dereferencing
+   raw pointers, calling unsafe functions (including foreign ones), using
+   mutable statics, implementing unsafe traits, and accessing union fields.
+   The borrow checker and everything else still apply inside the block.
+   * The point is to keep unsafe code small and wrap it in a safe API whose
+   checks (like split_at_mut's assert) make every call sound. Each unsafe
+   block here has a `// SAFETY:` comment saying why the rules are upheld.
+   * Raw pointers (`*const T`, `*mut T`) can be null, dangling or aliased,
+   and aren't cleaned up automatically. `&raw const x` / `&raw mut x`
+   make them without going through a reference.
+   * Foreign functions are declared in an `unsafe extern "C"` block and are
+   unsafe to call - including `abs`, which is undefined for INT_MIN.
+   * `static mut` is unsafe to access because two threads could race on it.
+   * Miri, an interpreter that detects undefined behaviour, can check all of
+   this: `rustup +nightly component add miri`, then `cargo +nightly miri
+   test`.
+   * See https://doc.rust-lang.org/book/ch20-01-unsafe-rust.html
+*/
+mod counter;
+mod ffi;
+mod raw;
+mod split;
+
+fn main() {
+    let mut v = vec![1, 2, 3, 4, 5, 6];
+    let (left, right) = split::split_at_mut(&mut v, 3);
+    left[0] = 10;
+    right[0] = 40;
+    println!("split_at_mut, then writing to both halves: {v:?}");
+
+    let mut num = 5;
+    let read_back = raw::increment_through_pointers(&mut num);
+    println!("incremented through a *mut, read through a *const: {read_back}");
+
+    let (mut a, mut b) = (String::from("a"), String::from("b"));
+    raw::swap(&mut a, &mut b);
+    println!("swapped with raw pointers: a = {a}, b = {b}");
+    println!(
+        "an address survives a trip through usize: {}",
+        raw::address_round_trip(&num)
+    );
+
+    for inc in [1, 2, 3] {
+        println!("add_to_count({inc}) -> {}", counter::add_to_count(inc));
+    }
+    println!("COUNTER: {}", counter::count());
+
+    for x in [-3, i32::MIN] {
+        println!("Absolute value of {x} according to C: {:?}", ffi::c_abs(x));
+    }
+}
@@ -0,0 +1,82 @@
+// Raw pointers can be made in safe code - only following them needs
+// `unsafe`, because the compiler can no longer promise they point anywhere
+// valid. Between them, `*const` and `*mut` can also break the
+// one-writer-or-many-readers rule, which references never can.
+
+// The book's example: a const and a mut pointer to the same i32 at once.
+// Returns what was read through the const pointer after writing through the
+// mut one. The book makes both from the variable; here `r1` is made from
+// `r2`, because reborrowing the reference for `r2` after making `r1` can
+// invalidate `r1` under Stacked Borrows, the aliasing model Miri checks.
+pub fn increment_through_pointers(value: &mut i32) -> i32 {
+    let r2 = &raw mut *value;
+    let r1 = r2 as *const i32;
+
+    // SAFETY: both pointers were just made from a live `&mut i32`, which
+    // isn't used again until they're done, so they're valid and aligned.
+    // They're used one after the other, never at the same time.
+    unsafe {
+        *r2 += 1;
+        *r1
+    }
+}
+
+// Swaps two values through raw pointers, the way `std::mem::swap` does
+// underneath.
+pub fn swap<T>(a: &mut T, b: &mut T) {
+    let a = &raw mut *a;
+    let b = &raw mut *b;
+
+    // SAFETY: both pointers come from `&mut T`s, so they're valid, aligned
+    // and can't overlap - two `&mut` to the same place can't exist. `read`
+    // makes a bitwise copy, leaving `*a` logically moved-out until `write`
+    // refills it, and nothing in between can panic and drop it twice.
+    unsafe {
+        let temp = a.read();
+        a.write(b.read());
+        b.write(temp);
+    }
+}
+
+// An address as a number and back. Turning an integer into a pointer is
+// safe; dereferencing it is where the risk is, so this compares addresses
+// only.
+pub fn address_round_trip(value: &i32) -> bool {
+    let address = value as *const i32 as usize;
+    let pointer = address as *const i32;
+    std::ptr::eq(pointer, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_back_what_was_written() {
+        let mut num = 5;
+        assert_eq!(increment_through_pointers(&mut num), 6);
+        assert_eq!(num, 6);
+    }
+
+    #[test]
+    fn swaps_owned_values() {
+        let mut a = String::from("left");
+        let mut b = String::from("right");
+        swap(&mut a, &mut b);
+        assert_eq!((a.as_str(), b.as_str()), ("right", "left"));
+    }
+
+    #[test]
+    fn swaps_copy_values() {
+        let mut a = [1, 2];
+        let mut b = [3, 4];
+        swap(&mut a, &mut b);
+        assert_eq!((a, b), ([3, 4], [1, 2]));
+    }
+
+    #[test]
+    fn addresses_round_trip() {
+        let x = 42;
+        assert!(address_round_trip(&x));
+    }
+}
@@ -0,0 +1,97 @@
+use std::slice;
+
+// The book's hand-written `split_at_mut`. Safe code can't write it:
+//
+//     let len = values.len();
+//     assert!(mid <= len);
+//     (&mut values[..mid], &mut values[mid..])
+//
+// error[E0499]: cannot borrow `*values` as mutable more than once at a time
+//
+// The borrow checker only sees two mutable borrows of the same slice, not
+// that they cover different halves of it. We know they don't overlap, so
+// raw pointers and an unsafe block say so.
+pub fn split_at_mut<T>(values: &mut [T], mid: usize) -> (&mut [T], &mut [T]) {
+    let len = values.len();
+    let ptr = values.as_mut_ptr();
+
+    // Without this the unsafe code below could make slices running past the
+    // end of `values` - the assert is what makes the function safe to call.
+    assert!(
+        mid <= len,
+        "mid {mid} is past the end of a slice of length {len}"
+    );
+
+    // SAFETY: `ptr` comes from a valid `&mut [T]` of `len` elements, and
+    // `mid <= len`, so `ptr..ptr + mid` and `ptr + mid..ptr + len` are both
+    // inside it and don't overlap. Each returned slice borrows from `values`
+    // for the same lifetime, so nothing else can use the memory meanwhile.
+    unsafe {
+        (
+            slice::from_raw_parts_mut(ptr, mid),
+            slice::from_raw_parts_mut(ptr.add(mid), len - mid),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_in_the_middle() {
+        let mut v = vec![1, 2, 3, 4, 5, 6];
+        let (a, b) = split_at_mut(&mut v, 3);
+        assert_eq!(a, &mut [1, 2, 3]);
+        assert_eq!(b, &mut [4, 5, 6]);
+    }
+
+    #[test]
+    fn splits_at_zero() {
+        let mut v = [1, 2, 3];
+        let (a, b) = split_at_mut(&mut v, 0);
+        assert!(a.is_empty());
+        assert_eq!(b, [1, 2, 3]);
+    }
+
+    #[test]
+    fn splits_at_the_length() {
+        let mut v = [1, 2, 3];
+        let (a, b) = split_at_mut(&mut v, 3);
+        assert_eq!(a, [1, 2, 3]);
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn both_halves_can_be_written_at_once() {
+        let mut v = [0; 4];
+        let (a, b) = split_at_mut(&mut v, 1);
+        a[0] = 1;
+        b[0] = 2;
+        b[2] = 3;
+        assert_eq!(v, [1, 2, 0, 3]);
+    }
+
+    #[test]
+    fn matches_the_standard_library() {
+        let mut ours: Vec<String> = ["a", "b", "c", "d"].map(String::from).to_vec();
+        let mut theirs = ours.clone();
+        for mid in 0..=ours.len() {
+            assert_eq!(split_at_mut(&mut ours, mid), theirs.split_at_mut(mid));
+        }
+    }
+
+    #[test]
+    fn empty_slice() {
+        let mut v: [u8; 0] = [];
+        let (a, b) = split_at_mut(&mut v, 0);
+        assert!(a.is_empty() && b.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "mid 4 is past the end of a slice of length 3")]
+    fn out_of_bounds_panics() {
+        let mut v = [1, 2, 3];
+        split_at_mut(&mut v, 4);
+    }
+}
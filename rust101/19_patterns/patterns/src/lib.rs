@@ -0,0 +1,465 @@
+/* Things learned during this exercise:
+   * Patterns are everywhere, not just in `match`: `let`, function
+   parameters, `for` loops, `if let`, `while let` and `let ... else` all
+   take one.
+   * Irrefutable patterns always match (`let (x, y) = point;`); refutable
+   ones might not (`Some(x)`). `let` and parameters need irrefutable
+   patterns, so a refutable one needs an `else` - E0005 otherwise.
+   * Literals, `|` for alternatives and `..=` ranges (numbers and chars)
+   test values. Struct, enum, tuple, slice and reference patterns take them
+   apart, and nest as deep as the data does.
+   * `_` ignores one value without binding it, `..` ignores the rest, and a
+   name starting with `_` binds but silences the unused warning.
+   * A match guard (`if ...` after the pattern) adds a condition the
+   pattern can't express. `name @ pattern` tests and binds at once.
+   * The compiler checks `match` is exhaustive, which is why adding an enum
+   variant breaks every match that forgot it.
+   * See https://doc.rust-lang.org/book/ch19-00-patterns.html
+*/
+
+// -- Literals and ranges -------------------------------------------------
+
+/// Names small numbers, matching literals and alternatives with `|`.
+pub fn number_name(n: i32) -> &'static str {
+    match n {
+        0 => "zero",
+        1 => "one",
+        2 | 3 => "a couple",
+        4..=9 => "a few",
+        i32::MIN..=-1 => "negative",
+        _ => "lots",
+    }
+}
+
+/// A letter grade from a percentage. Ranges must not overlap from the top
+/// down, and the last arm catches the impossible 101 to 255.
+pub fn grade(score: u8) -> Option<char> {
+    match score {
+        90..=100 => Some('A'),
+        80..=89 => Some('B'),
+        70..=79 => Some('C'),
+        60..=69 => Some('D'),
+        0..=59 => Some('F'),
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CharKind {
+    Digit,
+    Lowercase,
+    Uppercase,
+    Whitespace,
+    Other,
+}
+
+/// Char ranges work too, but only for ASCII here: `'a'..='z'` doesn't
+/// include 'é'.
+pub fn char_kind(c: char) -> CharKind {
+    match c {
+        '0'..='9' => CharKind::Digit,
+        'a'..='z' => CharKind::Lowercase,
+        'A'..='Z' => CharKind::Uppercase,
+        ' ' | '\t' | '\n' | '\r' => CharKind::Whitespace,
+        _ => CharKind::Other,
+    }
+}
+
+// -- Structs, and guards -------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Position {
+    Origin,
+    OnXAxis(i32),
+    OnYAxis(i32),
+    Elsewhere { x: i32, y: i32 },
+}
+
+/// Destructures a struct, with literals in some fields to test them and
+/// names in others to bind them.
+pub fn position(point: Point) -> Position {
+    match point {
+        Point { x: 0, y: 0 } => Position::Origin,
+        Point { x, y: 0 } => Position::OnXAxis(x),
+        Point { x: 0, y } => Position::OnYAxis(y),
+        Point { x, y } => Position::Elsewhere { x, y },
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Quadrant {
+    First,
+    Second,
+    Third,
+    Fourth,
+    OnAnAxis,
+}
+
+/// Match guards compare the fields with zero, which no pattern can do. The
+/// compiler doesn't look inside guards when checking exhaustiveness, so the
+/// last arm needs no guard.
+pub fn categorize(point: Point) -> Quadrant {
+    match point {
+        Point { x, y } if x > 0 && y > 0 => Quadrant::First,
+        Point { x, y } if x < 0 && y > 0 => Quadrant::Second,
+        Point { x, y } if x < 0 && y < 0 => Quadrant::Third,
+        Point { x, y } if x > 0 && y < 0 => Quadrant::Fourth,
+        _ => Quadrant::OnAnAxis,
+    }
+}
+
+// -- Enums and nesting ---------------------------------------------------
+
+#[derive(Debug)]
+pub enum Color {
+    Rgb(u8, u8, u8),
+    Hsv(u16, u8, u8),
+}
+
+#[derive(Debug)]
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(Color),
+}
+
+/// Every variant's shape: unit, struct-like, tuple-like, and one with
+/// another enum nested inside it - matched in a single pattern.
+pub fn describe(message: &Message) -> String {
+    match message {
+        Message::Quit => "quit".to_string(),
+        Message::Move { x: 0, y: 0 } => "stay put".to_string(),
+        Message::Move { x, y } => format!("move by ({x}, {y})"),
+        Message::Write(text) if text.is_empty() => "write nothing".to_string(),
+        Message::Write(text) => format!("write {text:?}"),
+        Message::ChangeColor(Color::Rgb(r, g, b)) => format!("rgb #{r:02x}{g:02x}{b:02x}"),
+        Message::ChangeColor(Color::Hsv(h, s, v)) => format!("hsv {h}° {s}% {v}%"),
+    }
+}
+
+// -- Tuples --------------------------------------------------------------
+
+/// Matches on a tuple built just for the purpose.
+pub fn fizz_buzz(n: u32) -> String {
+    match (n % 3, n % 5) {
+        (0, 0) => "FizzBuzz".to_string(),
+        (0, _) => "Fizz".to_string(),
+        (_, 0) => "Buzz".to_string(),
+        _ => n.to_string(),
+    }
+}
+
+/// The parameter itself is an irrefutable tuple pattern.
+pub fn swap((a, b): (i32, i32)) -> (i32, i32) {
+    (b, a)
+}
+
+/// Nested tuple and struct destructuring in a single parameter.
+pub fn flatten(((feet, inches), Point { x, y }): ((u32, u32), Point)) -> [i64; 4] {
+    [feet.into(), inches.into(), x.into(), y.into()]
+}
+
+// -- References ----------------------------------------------------------
+
+/// `&(a, b)` matches a reference and copies the fields out, so `a` and `b`
+/// are i32s rather than &i32s.
+pub fn sum_of_products(pairs: &[(i32, i32)]) -> i32 {
+    pairs.iter().map(|&(a, b)| a * b).sum()
+}
+
+/// Matching on a reference without `&` in the pattern binds references -
+/// "default binding modes" - so `name` here is a `&String`.
+pub fn longest_name(people: &[(String, u32)]) -> Option<&str> {
+    let mut longest: Option<&str> = None;
+    for (name, _age) in people {
+        if longest.is_none_or(|current| name.len() > current.len()) {
+            longest = Some(name);
+        }
+    }
+    longest
+}
+
+// -- Ignoring with _ and .. ----------------------------------------------
+
+/// Slice patterns with `..` for "whatever's in between", which can match
+/// nothing at all.
+pub fn first_and_last(numbers: &[i32]) -> Option<(i32, i32)> {
+    match numbers {
+        [] => None,
+        [only] => Some((*only, *only)),
+        [first, .., last] => Some((*first, *last)),
+    }
+}
+
+/// The book's settings example: `_` in both positions ignores what the
+/// values are and only checks that both are `Some`.
+pub fn update_setting(current: Option<i32>, new: Option<i32>) -> Result<Option<i32>, &'static str> {
+    match (current, new) {
+        (Some(_), Some(_)) => Err("Can't overwrite an existing customized value"),
+        _ => Ok(new.or(current)),
+    }
+}
+
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+/// `..` in a struct pattern skips the fields not mentioned.
+pub fn height(point: &Point3) -> i32 {
+    let Point3 { z, .. } = point;
+    *z
+}
+
+/// Every other value of a tuple, `_` standing in for the rest.
+pub fn odd_positions(numbers: (i32, i32, i32, i32, i32)) -> (i32, i32) {
+    let (_, second, _, fourth, _) = numbers;
+    (second, fourth)
+}
+
+// -- @ bindings ----------------------------------------------------------
+
+/// `id @ 3..=7` checks the range and keeps the value that matched it.
+pub fn classify_id(id: u32) -> String {
+    match id {
+        id @ 3..=7 => format!("found an id in range: {id}"),
+        10..=12 => "found an id in another range".to_string(),
+        id => format!("found some other id: {id}"),
+    }
+}
+
+/// `@` with a slice pattern binds the whole middle at once.
+pub fn middle(numbers: &[i32]) -> &[i32] {
+    match numbers {
+        [_, inner @ .., _] => inner,
+        _ => &[],
+    }
+}
+
+// -- while let, if let chains and let ... else ---------------------------
+
+/// `while let` keeps going until the pattern stops matching - here when
+/// `pop` returns `None`.
+pub fn drain_stack(mut stack: Vec<i32>) -> Vec<i32> {
+    let mut popped = Vec::new();
+    while let Some(top) = stack.pop() {
+        popped.push(top);
+    }
+    popped
+}
+
+/// Reads "host:port". A chain of `let`s and conditions joined with `&&`
+/// that all have to match, each able to use what the earlier ones bound -
+/// without it, this is three nested `if`s.
+pub fn parse_address(text: &str) -> Option<(&str, u16)> {
+    if let Some((host, port)) = text.split_once(':')
+        && !host.is_empty()
+        && let Ok(port) = port.parse::<u16>()
+        && port != 0
+    {
+        Some((host, port))
+    } else {
+        None
+    }
+}
+
+/// `let ... else` for a refutable pattern: the `else` block must leave the
+/// function (or loop), so after it the bindings are always there.
+pub fn parse_key_value(line: &str) -> Result<(&str, &str), String> {
+    let Some((key, value)) = line.split_once('=') else {
+        return Err(format!("no `=` in {line:?}"));
+    };
+    // A plain `let` has to be irrefutable:
+    //
+    //     let Some((key, value)) = line.split_once('=');
+    //
+    // error[E0005]: refutable pattern in local binding
+    //   = note: `let` bindings require an "irrefutable pattern", like a
+    //     `struct` or an `enum` with only one variant
+    let (key, value) = (key.trim(), value.trim());
+    Ok((key, value))
+}
+
+/// The `matches!` macro is a `match` that returns whether the pattern
+/// matched.
+pub fn is_vowel(c: char) -> bool {
+    matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literals_and_alternatives() {
+        assert_eq!(number_name(0), "zero");
+        assert_eq!(number_name(1), "one");
+        assert_eq!(number_name(3), "a couple");
+        assert_eq!(number_name(10), "lots");
+    }
+
+    #[test]
+    fn numeric_ranges() {
+        assert_eq!(number_name(9), "a few");
+        assert_eq!(number_name(-1), "negative");
+        assert_eq!(grade(100), Some('A'));
+        assert_eq!(grade(90), Some('A'));
+        assert_eq!(grade(89), Some('B'));
+        assert_eq!(grade(0), Some('F'));
+        assert_eq!(grade(101), None);
+    }
+
+    #[test]
+    fn char_ranges() {
+        assert_eq!(char_kind('7'), CharKind::Digit);
+        assert_eq!(char_kind('q'), CharKind::Lowercase);
+        assert_eq!(char_kind('Q'), CharKind::Uppercase);
+        assert_eq!(char_kind('\t'), CharKind::Whitespace);
+        assert_eq!(char_kind('é'), CharKind::Other);
+    }
+
+    #[test]
+    fn destructuring_structs() {
+        assert_eq!(position(Point { x: 0, y: 0 }), Position::Origin);
+        assert_eq!(position(Point { x: 4, y: 0 }), Position::OnXAxis(4));
+        assert_eq!(position(Point { x: 0, y: -2 }), Position::OnYAxis(-2));
+        assert_eq!(
+            position(Point { x: 1, y: 2 }),
+            Position::Elsewhere { x: 1, y: 2 }
+        );
+    }
+
+    #[test]
+    fn match_guards() {
+        let quadrant = |x, y| categorize(Point { x, y });
+        assert_eq!(quadrant(1, 1), Quadrant::First);
+        assert_eq!(quadrant(-1, 1), Quadrant::Second);
+        assert_eq!(quadrant(-1, -1), Quadrant::Third);
+        assert_eq!(quadrant(1, -1), Quadrant::Fourth);
+        assert_eq!(quadrant(0, 5), Quadrant::OnAnAxis);
+        assert_eq!(quadrant(0, 0), Quadrant::OnAnAxis);
+    }
+
+    #[test]
+    fn destructuring_enums() {
+        assert_eq!(describe(&Message::Quit), "quit");
+        assert_eq!(describe(&Message::Move { x: 0, y: 0 }), "stay put");
+        assert_eq!(describe(&Message::Move { x: 3, y: -1 }), "move by (3, -1)");
+        assert_eq!(describe(&Message::Write(String::new())), "write nothing");
+        assert_eq!(describe(&Message::Write("hi".to_string())), "write \"hi\"");
+    }
+
+    #[test]
+    fn nested_destructuring() {
+        assert_eq!(
+            describe(&Message::ChangeColor(Color::Rgb(255, 160, 0))),
+            "rgb #ffa000"
+        );
+        assert_eq!(
+            describe(&Message::ChangeColor(Color::Hsv(200, 50, 75))),
+            "hsv 200° 50% 75%"
+        );
+        assert_eq!(flatten(((3, 10), Point { x: -3, y: 7 })), [3, 10, -3, 7]);
+    }
+
+    #[test]
+    fn destructuring_tuples() {
+        let fizz: Vec<String> = (1..=15).map(fizz_buzz).collect();
+        assert_eq!(fizz[2], "Fizz");
+        assert_eq!(fizz[4], "Buzz");
+        assert_eq!(fizz[14], "FizzBuzz");
+        assert_eq!(fizz[6], "7");
+        assert_eq!(swap((1, 2)), (2, 1));
+    }
+
+    #[test]
+    fn destructuring_references() {
+        assert_eq!(sum_of_products(&[(1, 2), (3, 4)]), 14);
+        assert_eq!(sum_of_products(&[]), 0);
+        let people = [
+            ("Ann".to_string(), 31),
+            ("Bartholomew".to_string(), 8),
+            ("Cy".to_string(), 52),
+        ];
+        assert_eq!(longest_name(&people), Some("Bartholomew"));
+        assert_eq!(longest_name(&[]), None);
+    }
+
+    #[test]
+    fn ignoring_with_underscore() {
+        assert!(update_setting(Some(5), Some(10)).is_err());
+        assert_eq!(update_setting(None, Some(10)), Ok(Some(10)));
+        assert_eq!(update_setting(Some(5), None), Ok(Some(5)));
+        assert_eq!(update_setting(None, None), Ok(None));
+        assert_eq!(odd_positions((1, 2, 3, 4, 5)), (2, 4));
+    }
+
+    #[test]
+    fn ignoring_the_rest_with_dot_dot() {
+        assert_eq!(first_and_last(&[]), None);
+        assert_eq!(first_and_last(&[4]), Some((4, 4)));
+        assert_eq!(first_and_last(&[1, 2]), Some((1, 2)));
+        assert_eq!(first_and_last(&[1, 2, 3, 4]), Some((1, 4)));
+        assert_eq!(height(&Point3 { x: 1, y: 2, z: 3 }), 3);
+    }
+
+    #[test]
+    fn at_bindings() {
+        assert_eq!(classify_id(5), "found an id in range: 5");
+        assert_eq!(classify_id(3), "found an id in range: 3");
+        assert_eq!(classify_id(11), "found an id in another range");
+        assert_eq!(classify_id(8), "found some other id: 8");
+        assert_eq!(middle(&[1, 2, 3, 4]), [2, 3]);
+        assert_eq!(middle(&[1, 2]), [] as [i32; 0]);
+        assert_eq!(middle(&[1]), [] as [i32; 0]);
+    }
+
+    #[test]
+    fn while_let() {
+        assert_eq!(drain_stack(vec![1, 2, 3]), [3, 2, 1]);
+        assert!(drain_stack(vec![]).is_empty());
+    }
+
+    #[test]
+    fn if_let_chains() {
+        assert_eq!(parse_address("localhost:8080"), Some(("localhost", 8080)));
+        assert_eq!(parse_address("localhost"), None);
+        assert_eq!(parse_address(":8080"), None);
+        assert_eq!(parse_address("localhost:http"), None);
+        assert_eq!(parse_address("localhost:0"), None);
+        assert_eq!(parse_address("localhost:65536"), None);
+    }
+
+    #[test]
+    fn refutable_patterns_need_else() {
+        assert_eq!(parse_key_value(" name = Ferris "), Ok(("name", "Ferris")));
+        assert_eq!(parse_key_value("a=b=c"), Ok(("a", "b=c")));
+        assert!(parse_key_value("no equals sign").is_err());
+    }
+
+    #[test]
+    fn irrefutable_patterns_in_let_and_for() {
+        let Point { x, y } = Point { x: 1, y: 2 };
+        assert_eq!((x, y), (1, 2));
+        let mut indexed = Vec::new();
+        for (index, value) in ['a', 'b'].iter().enumerate() {
+            indexed.push(format!("{value} at {index}"));
+        }
+        assert_eq!(indexed, ["a at 0", "b at 1"]);
+    }
+
+    #[test]
+    fn matches_macro() {
+        assert!(is_vowel('a'));
+        assert!(is_vowel('E'));
+        assert!(!is_vowel('y'));
+    }
+}
@@ -0,0 +1,81 @@
+use patterns::*;
+
+fn main() {
+    for n in [0, 3, 7, -12, 100] {
+        println!("{n} is {}", number_name(n));
+    }
+    for score in [95, 83, 42] {
+        println!("{score}% is a {:?}", grade(score));
+    }
+    println!("'x', '8' and '!' are {:?}", ['x', '8', '!'].map(char_kind));
+    println!();
+
+    for point in [
+        Point { x: 0, y: 0 },
+        Point { x: 0, y: 7 },
+        Point { x: -3, y: 4 },
+    ] {
+        println!(
+            "{point:?} is {:?}, in quadrant {:?}",
+            position(point),
+            categorize(point)
+        );
+    }
+    println!();
+
+    let messages = [
+        Message::Quit,
+        Message::Move { x: 10, y: -5 },
+        Message::Write("hello".to_string()),
+        Message::ChangeColor(Color::Rgb(0, 128, 255)),
+        Message::ChangeColor(Color::Hsv(120, 100, 50)),
+    ];
+    for message in &messages {
+        println!("{message:?} means {}", describe(message));
+    }
+    println!();
+
+    let fizz: Vec<String> = (1..=15).map(fizz_buzz).collect();
+    println!("{}", fizz.join(" "));
+    println!("swap((1, 2)) = {:?}", swap((1, 2)));
+    println!(
+        "flatten(((5, 11), Point {{ x: 1, y: 2 }})) = {:?}",
+        flatten(((5, 11), Point { x: 1, y: 2 }))
+    );
+    println!("sum of products = {}", sum_of_products(&[(2, 3), (4, 5)]));
+    let people = [("Ana".to_string(), 40), ("Benedikt".to_string(), 2)];
+    println!("longest name: {:?}", longest_name(&people));
+    println!();
+
+    println!(
+        "first and last of [1, 2, 3]: {:?}",
+        first_and_last(&[1, 2, 3])
+    );
+    println!("middle of [1, 2, 3, 4, 5]: {:?}", middle(&[1, 2, 3, 4, 5]));
+    println!(
+        "update_setting(Some(1), Some(2)) = {:?}",
+        update_setting(Some(1), Some(2))
+    );
+    println!(
+        "height of (1, 2, 3) = {}, odd positions of (1, 2, 3, 4, 5) = {:?}",
+        height(&Point3 { x: 1, y: 2, z: 3 }),
+        odd_positions((1, 2, 3, 4, 5))
+    );
+    for id in [5, 11, 20] {
+        println!("{}", classify_id(id));
+    }
+    println!();
+
+    println!("drained: {:?}", drain_stack(vec![1, 2, 3]));
+    for address in ["example.com:443", "example.com", "example.com:99999"] {
+        println!("parse_address({address:?}) = {:?}", parse_address(address));
+    }
+    for line in ["colour = blue", "nonsense"] {
+        println!("parse_key_value({line:?}) = {:?}", parse_key_value(line));
+    }
+    let vowels: String = "pattern matching"
+        .chars()
+        .filter(|&c| is_vowel(c))
+        .collect();
+    println!("vowels in \"pattern matching\": {vowels}");
+}
@@ -0,0 +1,198 @@
+/* Things learned during this exercise:
+   * HTTP is text over TCP. `TcpListener::bind` listens on a port and
+   `incoming()` hands out one `TcpStream` per connection, which can be read
+   and written like a file.
+   * A request starts with a request line - "GET / HTTP/1.1" - then headers,
+   one per line, then a blank line. Lines end with "\r\n". A response is the
+   same shape: a status line ("HTTP/1.1 200 OK"), headers, a blank line and
+   the body. `Content-Length` tells the client where the body ends.
+   * Reading the headers through to the blank line, even if they're not
+   used, matters: closing a socket with unread data in it can reset the
+   connection before the client has read the response.
+   * Binding to port 0 lets the OS pick a free port, and `local_addr()` says
+   which. That's how the tests run a real server without clashing with
+   anything.
+   * Handling one connection at a time means a slow request - the `/sleep`
+   route - holds up every request behind it. A thread per connection would
+   fix that but lets anyone start as many threads as they like; a pool of a
+   few threads, fixed up front, caps it.
+   * The pool hands out work over a channel. Each worker needs the
+   receiving end, so it's shared as `Arc<Mutex<Receiver<Job>>>`, and a job
+   is a `Box<dyn FnOnce() + Send + 'static>` - the same bounds
+   `thread::spawn` has.
+   * Dropping the pool is how it shuts down: closing the channel tells the
+   workers there's nothing more coming, and joining them waits until they've
+   finished what was already queued. The sender is kept in an `Option` so
+   `drop` can `take()` it out and drop it before joining - joining first
+   would wait for ever.
+   * `incoming().take(n)` stops the server after n connections, and the
+   pool's Drop then lets the requests it's still handling finish.
+   * See https://doc.rust-lang.org/book/ch21-01-single-threaded.html,
+   https://doc.rust-lang.org/book/ch21-02-multithreaded.html and
+   https://doc.rust-lang.org/book/ch21-03-graceful-shutdown-and-cleanup.html
+*/
+mod pool;
+mod request;
+
+pub use pool::{PoolCreationError, ThreadPool};
+pub use request::{parse_request_line, Method, Path};
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+// Compiled in, so the server works whichever directory it's started from.
+const HELLO: &str = include_str!("../hello.html");
+const NOT_FOUND: &str = include_str!("../404.html");
+const BAD_REQUEST: &str = include_str!("../400.html");
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    // How long `/sleep` takes to answer.
+    pub sleep: Duration,
+    // How many connections can be handled at once.
+    pub workers: usize,
+    // Stop after accepting this many connections. None serves for ever.
+    pub max_requests: Option<usize>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            sleep: Duration::from_secs(5),
+            workers: 4,
+            max_requests: None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status_line: &'static str,
+    pub body: &'static str,
+}
+
+impl Response {
+    fn ok(body: &'static str) -> Response {
+        Response {
+            status_line: "HTTP/1.1 200 OK",
+            body,
+        }
+    }
+
+    pub fn to_http(&self) -> String {
+        let Response { status_line, body } = self;
+        let length = body.len();
+        format!("{status_line}\r\nContent-Length: {length}\r\n\r\n{body}")
+    }
+}
+
+// Picks the response for a request line. As in the book, anything that
+// isn't `GET /` or `GET /sleep` - other methods included - is a 404, and
+// only a line that doesn't parse is a 400.
+pub fn respond(request_line: &str, options: &Options) -> Response {
+    match parse_request_line(request_line) {
+        Some((Method::Get, path)) if path.as_str() == "/" => Response::ok(HELLO),
+        Some((Method::Get, path)) if path.as_str() == "/sleep" => {
+            thread::sleep(options.sleep);
+            Response::ok(HELLO)
+        }
+        Some(_) => Response {
+            status_line: "HTTP/1.1 404 NOT FOUND",
+            body: NOT_FOUND,
+        },
+        None => Response {
+            status_line: "HTTP/1.1 400 BAD REQUEST",
+            body: BAD_REQUEST,
+        },
+    }
+}
+
+pub fn handle_connection(stream: TcpStream, options: &Options) -> io::Result<()> {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line)? == 0 {
+        // Connected and hung up without sending anything.
+        return Ok(());
+    }
+    let mut header = String::new();
+    loop {
+        header.clear();
+        if reader.read_line(&mut header)? == 0 || header.trim_end().is_empty() {
+            break;
+        }
+    }
+
+    let response = respond(request_line.trim_end(), options);
+    (&stream).write_all(response.to_http().as_bytes())
+}
+
+// Serves connections, `options.workers` at a time, until it's accepted
+// `options.max_requests` of them. A connection that fails is reported and
+// the server carries on. Returns once every accepted request is answered.
+pub fn run(listener: &TcpListener, options: &Options) -> Result<(), PoolCreationError> {
+    let pool = ThreadPool::build(options.workers)?;
+    // Each job gets its own copy of the options, since it can't borrow.
+    let options = *options;
+    let limit = options.max_requests.unwrap_or(usize::MAX);
+    for stream in listener.incoming().take(limit) {
+        match stream {
+            Ok(stream) => pool.execute(move || {
+                if let Err(err) = handle_connection(stream, &options) {
+                    eprintln!("connection failed: {err}");
+                }
+            }),
+            Err(err) => eprintln!("connection failed: {err}"),
+        }
+    }
+    println!("Shutting down.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quick() -> Options {
+        Options {
+            sleep: Duration::from_millis(1),
+            workers: 1,
+            max_requests: None,
+        }
+    }
+
+    #[test]
+    fn routes() {
+        let options = quick();
+        assert_eq!(respond("GET / HTTP/1.1", &options), Response::ok(HELLO));
+        assert_eq!(
+            respond("GET /sleep HTTP/1.1", &options),
+            Response::ok(HELLO)
+        );
+        assert_eq!(
+            respond("GET /nope HTTP/1.1", &options).status_line,
+            "HTTP/1.1 404 NOT FOUND"
+        );
+        assert_eq!(
+            respond("POST / HTTP/1.1", &options).status_line,
+            "HTTP/1.1 404 NOT FOUND"
+        );
+        assert_eq!(
+            respond("hello?", &options).status_line,
+            "HTTP/1.1 400 BAD REQUEST"
+        );
+    }
+
+    #[test]
+    fn content_length_counts_bytes() {
+        let response = Response {
+            status_line: "HTTP/1.1 200 OK",
+            body: "héllo",
+        };
+        assert_eq!(
+            response.to_http(),
+            "HTTP/1.1 200 OK\r\nContent-Length: 6\r\n\r\nhéllo"
+        );
+    }
+}
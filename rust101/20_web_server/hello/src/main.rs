@@ -0,0 +1,53 @@
+use std::env;
+use std::net::TcpListener;
+use std::process;
+
+use hello::Options;
+
+const USAGE: &str = "Usage: hello [--max-requests N] [ADDRESS]";
+
+// Gets the listening address (127.0.0.1:7878 by default, like the book) and
+// the options from the command line.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<(String, Options), String> {
+    let mut options = Options::default();
+    let mut address = None;
+    while let Some(arg) = args.next() {
+        if arg == "--max-requests" {
+            let value = args.next().ok_or("--max-requests needs a number")?;
+            let n = value
+                .parse()
+                .map_err(|_| format!("not a number of requests: {value}"))?;
+            options.max_requests = Some(n);
+        } else if arg.starts_with("--") {
+            return Err(format!("unknown option: {arg}"));
+        } else if address.replace(arg).is_some() {
+            return Err("only one address, please".to_string());
+        }
+    }
+    let address = address.unwrap_or_else(|| "127.0.0.1:7878".to_string());
+    Ok((address, options))
+}
+
+// Visit http://127.0.0.1:7878 in a browser. The server stops by itself
+// after --max-requests connections; otherwise Ctrl-C ends it straight away,
+// without the graceful shutdown.
+fn main() {
+    let (address, options) = parse_args(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        eprintln!("{USAGE}");
+        process::exit(2);
+    });
+    let listener = TcpListener::bind(&address).unwrap_or_else(|err| {
+        eprintln!("couldn't listen on {address}: {err}");
+        process::exit(1);
+    });
+    // The real address, which differs from the one asked for with port 0.
+    let local = listener
+        .local_addr()
+        .expect("a bound listener has an address");
+    println!("Listening on http://{local}");
+    if let Err(err) = hello::run(&listener, &options) {
+        eprintln!("couldn't start the server: {err}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,269 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::{self, JoinHandle, ThreadId};
+
+// Any closure that can be run once on another thread.
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+#[derive(Debug, PartialEq)]
+pub struct PoolCreationError;
+
+impl fmt::Display for PoolCreationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a thread pool needs at least one thread")
+    }
+}
+
+impl Error for PoolCreationError {}
+
+// A fixed number of threads, started up front, that take turns running the
+// jobs given to `execute`.
+//
+// Dropping the pool shuts it down and waits for every worker to finish.
+// Jobs already queued still run first: the channel delivers whatever was
+// sent before the sender went away, so a worker only hears it's closed once
+// the queue is empty.
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    // Only None while the pool is being dropped.
+    sender: Option<mpsc::Sender<Job>>,
+}
+
+impl ThreadPool {
+    /// Creates a pool with `size` threads.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is zero. `build` returns an error instead.
+    pub fn new(size: usize) -> ThreadPool {
+        ThreadPool::build(size).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub fn build(size: usize) -> Result<ThreadPool, PoolCreationError> {
+        if size == 0 {
+            return Err(PoolCreationError);
+        }
+
+        // One channel for all the workers. A Receiver can't be shared - it's
+        // meant for a single consumer - so it goes in an Arc to give every
+        // worker a handle on it and a Mutex so only one takes a job at once.
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (0..size)
+            .map(|id| Worker::new(id, Arc::clone(&receiver)))
+            .collect();
+        Ok(ThreadPool {
+            workers,
+            sender: Some(sender),
+        })
+    }
+
+    // The same bounds as `thread::spawn`: the job runs on another thread
+    // (Send), exactly once (FnOnce), and for however long it needs, so it
+    // can't borrow anything ('static).
+    pub fn execute<F>(&self, f: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.sender
+            .as_ref()
+            .unwrap()
+            .send(Box::new(f))
+            .expect("the workers should outlive the pool");
+    }
+
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+
+    // The IDs of the threads doing the work, to check which ones ran a job.
+    pub fn thread_ids(&self) -> Vec<ThreadId> {
+        self.workers
+            .iter()
+            .map(|worker| worker.thread.thread().id())
+            .collect()
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        // Closing the channel is the signal to stop: once the queue is empty,
+        // `recv` returns an error and each worker leaves its loop. Joining
+        // first instead would wait for ever, since they'd still be waiting
+        // for jobs.
+        drop(self.sender.take());
+
+        for worker in self.workers.drain(..) {
+            println!("Shutting down worker {}", worker.id);
+            // A job that panicked took its worker down with it. That's worth
+            // a message, but not a second panic from inside drop.
+            if worker.thread.join().is_err() {
+                eprintln!("Worker {} had panicked", worker.id);
+            }
+        }
+    }
+}
+
+struct Worker {
+    id: usize,
+    thread: JoinHandle<()>,
+}
+
+impl Worker {
+    fn new(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Job>>>) -> Worker {
+        let thread = thread::spawn(move || loop {
+            // The lock is a temporary, so it's released at the end of this
+            // statement - before the job runs, not after. Writing
+            // `while let Ok(job) = receiver.lock().unwrap().recv()` would
+            // hold it for the whole loop body, one job at a time.
+            let message = receiver.lock().unwrap().recv();
+            match message {
+                Ok(job) => {
+                    println!("Worker {id} got a job; executing.");
+                    job();
+                    println!("Worker {id} finished its job.");
+                }
+                // The pool, and with it the sender, has gone.
+                Err(_) => {
+                    println!("Worker {id} disconnected; shutting down.");
+                    break;
+                }
+            }
+        });
+        Worker { id, thread }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Barrier;
+    use std::time::Duration;
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    // Drops the pool on another thread, so a drop that never returns fails
+    // the test instead of hanging it.
+    fn drop_within_timeout(pool: ThreadPool) {
+        let (done_tx, done_rx) = mpsc::channel();
+        thread::spawn(move || {
+            drop(pool);
+            done_tx.send(()).unwrap();
+        });
+        done_rx
+            .recv_timeout(TIMEOUT)
+            .expect("dropping the pool hung");
+    }
+
+    #[test]
+    fn runs_every_job() {
+        let pool = ThreadPool::new(4);
+        let counter = Arc::new(AtomicUsize::new(0));
+        let (done_tx, done_rx) = mpsc::channel();
+        for _ in 0..100 {
+            let counter = Arc::clone(&counter);
+            let done_tx = done_tx.clone();
+            pool.execute(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                done_tx.send(()).unwrap();
+            });
+        }
+        for _ in 0..100 {
+            done_rx.recv_timeout(TIMEOUT).expect("a job never finished");
+        }
+        assert_eq!(counter.load(Ordering::SeqCst), 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "a thread pool needs at least one thread")]
+    fn new_panics_for_zero_threads() {
+        ThreadPool::new(0);
+    }
+
+    #[test]
+    fn build_errors_for_zero_threads() {
+        assert_eq!(ThreadPool::build(0).err(), Some(PoolCreationError));
+        assert_eq!(ThreadPool::build(3).unwrap().size(), 3);
+    }
+
+    #[test]
+    fn work_is_shared_between_workers() {
+        // Each job waits at the barrier until the other one arrives, so both
+        // can only finish if two different workers are running them at once.
+        let pool = ThreadPool::new(4);
+        let barrier = Arc::new(Barrier::new(2));
+        let (id_tx, id_rx) = mpsc::channel();
+        for _ in 0..2 {
+            let barrier = Arc::clone(&barrier);
+            let id_tx = id_tx.clone();
+            pool.execute(move || {
+                barrier.wait();
+                id_tx.send(thread::current().id()).unwrap();
+            });
+        }
+        let ids: HashSet<ThreadId> = (0..2)
+            .map(|_| id_rx.recv_timeout(TIMEOUT).expect("the jobs never met"))
+            .collect();
+        assert_eq!(ids.len(), 2);
+        let workers: HashSet<ThreadId> = pool.thread_ids().into_iter().collect();
+        assert!(ids.is_subset(&workers));
+        assert!(!workers.contains(&thread::current().id()));
+    }
+
+    #[test]
+    fn dropping_the_pool_joins_every_worker() {
+        let pool = ThreadPool::new(3);
+        let in_flight = Arc::new(());
+        for _ in 0..3 {
+            // Each job holds a clone until it's done, so once the pool is
+            // dropped only the original should be left.
+            let in_flight = Arc::clone(&in_flight);
+            pool.execute(move || {
+                thread::sleep(Duration::from_millis(20));
+                drop(in_flight);
+            });
+        }
+        drop_within_timeout(pool);
+        assert_eq!(Arc::strong_count(&in_flight), 1);
+    }
+
+    #[test]
+    fn queued_jobs_run_before_the_pool_shuts_down() {
+        // One worker, so nine of the ten jobs are still queued when the pool
+        // is dropped.
+        let pool = ThreadPool::new(1);
+        let counter = Arc::new(AtomicUsize::new(0));
+        for _ in 0..10 {
+            let counter = Arc::clone(&counter);
+            pool.execute(move || {
+                thread::sleep(Duration::from_millis(5));
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        drop_within_timeout(pool);
+        assert_eq!(counter.load(Ordering::SeqCst), 10);
+    }
+
+    #[test]
+    fn an_idle_pool_shuts_down() {
+        drop_within_timeout(ThreadPool::new(8));
+    }
+
+    #[test]
+    fn a_panicking_job_does_not_stop_the_others_or_the_drop() {
+        let pool = ThreadPool::new(2);
+        let counter = Arc::new(AtomicUsize::new(0));
+        pool.execute(|| panic!("this job fails on purpose"));
+        for _ in 0..5 {
+            let counter = Arc::clone(&counter);
+            pool.execute(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        drop_within_timeout(pool);
+        assert_eq!(counter.load(Ordering::SeqCst), 5);
+    }
+}
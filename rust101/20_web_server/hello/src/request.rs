@@ -0,0 +1,123 @@
+use std::fmt;
+
+// The methods HTTP/1.1 defines for ordinary requests. Only GET gets a real
+// answer, but knowing the others means "POST /" is a request we can say no
+// to, rather than one that makes no sense.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Head,
+    Post,
+    Put,
+    Delete,
+}
+
+impl Method {
+    // Methods are case-sensitive, so "get" isn't GET.
+    fn parse(word: &str) -> Option<Method> {
+        match word {
+            "GET" => Some(Method::Get),
+            "HEAD" => Some(Method::Head),
+            "POST" => Some(Method::Post),
+            "PUT" => Some(Method::Put),
+            "DELETE" => Some(Method::Delete),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Method::Get => "GET",
+            Method::Head => "HEAD",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Delete => "DELETE",
+        };
+        write!(f, "{name}")
+    }
+}
+
+// The path part of the request line, borrowed from it. Always starts with
+// '/'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Path<'a>(&'a str);
+
+impl<'a> Path<'a> {
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+}
+
+// Reads the first line of a request, like "GET /sleep HTTP/1.1": a method,
+// a path and a version with single spaces between them. Anything else is
+// malformed and gives None.
+pub fn parse_request_line(line: &str) -> Option<(Method, Path<'_>)> {
+    let mut parts = line.split(' ');
+    let method = Method::parse(parts.next()?)?;
+    let path = parts.next()?;
+    let version = parts.next()?;
+    if parts.next().is_some() || !path.starts_with('/') || !version.starts_with("HTTP/1.") {
+        return None;
+    }
+    Some((method, Path(path)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_book_requests() {
+        assert_eq!(
+            parse_request_line("GET / HTTP/1.1"),
+            Some((Method::Get, Path("/")))
+        );
+        assert_eq!(
+            parse_request_line("GET /sleep HTTP/1.1"),
+            Some((Method::Get, Path("/sleep")))
+        );
+    }
+
+    #[test]
+    fn parses_other_methods_and_http_1_0() {
+        assert_eq!(
+            parse_request_line("POST /form HTTP/1.0"),
+            Some((Method::Post, Path("/form")))
+        );
+        assert_eq!(
+            parse_request_line("DELETE /x HTTP/1.1")
+                .unwrap()
+                .0
+                .to_string(),
+            "DELETE"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_request_lines() {
+        for line in [
+            "",
+            "GET",
+            "GET /",
+            "GET / HTTP/1.1 extra",
+            "GET  / HTTP/1.1",
+            "get / HTTP/1.1",
+            "FETCH / HTTP/1.1",
+            "GET index.html HTTP/1.1",
+            "GET / HTTP/2",
+            "GET / FTP/1.1",
+            "\u{0}\u{1}\u{2}",
+        ] {
+            assert_eq!(parse_request_line(line), None, "{line:?}");
+        }
+    }
+
+    #[test]
+    fn path_borrows_from_the_line() {
+        let line = String::from("GET /hello.html HTTP/1.1");
+        let (_, path) = parse_request_line(&line).unwrap();
+        assert_eq!(path.as_str(), "/hello.html");
+    }
+}
@@ -0,0 +1,74 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn get(address: &str, path: &str) -> String {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(stream, "GET {path} HTTP/1.1\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn the_server_exits_after_max_requests() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_hello"))
+        .args(["--max-requests", "2", "127.0.0.1:0"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut first = String::new();
+    stdout.read_line(&mut first).unwrap();
+    let address = first
+        .trim_end()
+        .strip_prefix("Listening on http://")
+        .unwrap_or_else(|| panic!("unexpected first line: {first:?}"))
+        .to_string();
+
+    assert!(get(&address, "/").starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(get(&address, "/gone").starts_with("HTTP/1.1 404 NOT FOUND\r\n"));
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+        if start.elapsed() > Duration::from_secs(10) {
+            child.kill().unwrap();
+            panic!("the server didn't exit after two requests");
+        }
+        thread::sleep(Duration::from_millis(20));
+    };
+    assert!(status.success());
+
+    let mut log = String::new();
+    stdout.read_to_string(&mut log).unwrap();
+    assert_eq!(log.matches("got a job; executing.").count(), 2, "{log}");
+    assert_eq!(log.matches("finished its job.").count(), 2, "{log}");
+    let shutdown = log.find("Shutting down.\n").expect("no shutdown message");
+    for id in 0..4 {
+        let worker = format!("Shutting down worker {id}\n");
+        assert!(log[shutdown..].contains(&worker), "{log}");
+        assert!(log.contains(&format!("Worker {id} disconnected; shutting down.")));
+    }
+}
+
+#[test]
+fn bad_arguments_are_a_usage_error() {
+    for args in [
+        &["--max-requests"][..],
+        &["--max-requests", "lots"],
+        &["--verbose"],
+        &["a", "b"],
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_hello"))
+            .args(args)
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: hello"));
+    }
+}
@@ -0,0 +1,174 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hello::Options;
+
+// Starts a server on a port the OS picks and returns its address. The
+// thread runs until the test binary exits.
+fn start_server_with(options: Options) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || hello::run(&listener, &options));
+    address
+}
+
+fn start_server(sleep: Duration) -> SocketAddr {
+    start_server_with(Options {
+        sleep,
+        ..Options::default()
+    })
+}
+
+// Sends `request` as is and reads until the server closes the connection.
+fn send(address: SocketAddr, request: &str) -> String {
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(10)))
+        .unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+fn get(address: SocketAddr, path: &str) -> String {
+    send(
+        address,
+        &format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n"),
+    )
+}
+
+fn split(response: &str) -> (&str, &str) {
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    (head.lines().next().unwrap(), body)
+}
+
+#[test]
+fn serves_hello_for_the_root() {
+    let address = start_server(Duration::ZERO);
+    let response = get(address, "/");
+    let (status_line, body) = split(&response);
+    assert_eq!(status_line, "HTTP/1.1 200 OK");
+    assert!(body.contains("<h1>Hello!</h1>"), "{body}");
+    assert!(response.contains(&format!("Content-Length: {}\r\n", body.len())));
+}
+
+#[test]
+fn unknown_paths_are_not_found() {
+    let address = start_server(Duration::ZERO);
+    let response = get(address, "/missing.html");
+    let (status_line, body) = split(&response);
+    assert_eq!(status_line, "HTTP/1.1 404 NOT FOUND");
+    assert!(body.contains("<h1>Oops!</h1>"), "{body}");
+}
+
+#[test]
+fn garbage_is_a_bad_request() {
+    let address = start_server(Duration::ZERO);
+    let response = send(address, "hello there\r\n\r\n");
+    assert_eq!(split(&response).0, "HTTP/1.1 400 BAD REQUEST");
+}
+
+#[test]
+fn sleep_answers_after_the_delay() {
+    let delay = Duration::from_millis(200);
+    let address = start_server(delay);
+    let start = Instant::now();
+    let response = get(address, "/sleep");
+    assert!(start.elapsed() >= delay);
+    assert_eq!(split(&response).0, "HTTP/1.1 200 OK");
+}
+
+// Requests /sleep, then / while the first is still being handled, and
+// returns how long the second one took.
+fn time_behind_a_slow_request(address: SocketAddr) -> Duration {
+    let slow = thread::spawn(move || get(address, "/sleep"));
+    thread::sleep(Duration::from_millis(50));
+    let start = Instant::now();
+    get(address, "/");
+    let elapsed = start.elapsed();
+    slow.join().unwrap();
+    elapsed
+}
+
+#[test]
+fn one_worker_handles_one_connection_at_a_time() {
+    let address = start_server_with(Options {
+        sleep: Duration::from_millis(500),
+        workers: 1,
+        max_requests: None,
+    });
+    assert!(time_behind_a_slow_request(address) >= Duration::from_millis(300));
+}
+
+#[test]
+fn more_workers_keep_a_slow_request_from_blocking_the_rest() {
+    let address = start_server_with(Options {
+        sleep: Duration::from_secs(2),
+        workers: 4,
+        max_requests: None,
+    });
+    assert!(time_behind_a_slow_request(address) < Duration::from_secs(1));
+}
+
+#[test]
+fn zero_workers_is_an_error() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let options = Options {
+        workers: 0,
+        ..Options::default()
+    };
+    assert_eq!(
+        hello::run(&listener, &options),
+        Err(hello::PoolCreationError)
+    );
+}
+
+#[test]
+fn survives_a_client_that_hangs_up() {
+    let address = start_server(Duration::ZERO);
+    drop(TcpStream::connect(address).unwrap());
+    assert_eq!(split(&get(address, "/")).0, "HTTP/1.1 200 OK");
+}
+
+#[test]
+fn run_returns_after_the_request_limit() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let options = Options {
+        max_requests: Some(3),
+        ..Options::default()
+    };
+    let server = thread::spawn(move || hello::run(&listener, &options));
+    for _ in 0..3 {
+        assert_eq!(split(&get(address, "/")).0, "HTTP/1.1 200 OK");
+    }
+    let start = Instant::now();
+    while !server.is_finished() {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "run never returned"
+        );
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(server.join().unwrap(), Ok(()));
+}
+
+#[test]
+fn shutdown_waits_for_requests_in_progress() {
+    // The limit is reached while /sleep is still being handled; its answer
+    // should still arrive before run returns.
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let options = Options {
+        sleep: Duration::from_millis(300),
+        workers: 2,
+        max_requests: Some(1),
+    };
+    let server = thread::spawn(move || hello::run(&listener, &options));
+    let response = get(address, "/sleep");
+    assert_eq!(split(&response).0, "HTTP/1.1 200 OK");
+    server.join().unwrap().unwrap();
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use crate::key::key;
+
+// A word list sorted into buckets by key, so finding a word's anagrams is a
+// hash lookup instead of a pass over every word. For a 100,000-word list
+// that's one pass to build, then each query only looks at its own bucket.
+pub struct Index {
+    buckets: HashMap<String, Vec<String>>,
+}
+
+impl Index {
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> Index {
+        let mut buckets: HashMap<String, Vec<String>> = HashMap::new();
+        for word in words {
+            let word = word.as_ref();
+            let key = key(word);
+            if key.is_empty() {
+                continue;
+            }
+            let bucket = buckets.entry(key).or_default();
+            if !bucket.iter().any(|known| known == word) {
+                bucket.push(word.to_string());
+            }
+        }
+        Index { buckets }
+    }
+
+    // How many different words it holds.
+    pub fn len(&self) -> usize {
+        self.buckets.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+
+    // The words made of the same letters as `target`, in the order they
+    // were given. The target itself isn't its own anagram, in any case.
+    pub fn anagrams_of(&self, target: &str) -> Vec<&str> {
+        let target_lower = target.to_lowercase();
+        self.buckets
+            .get(&key(target))
+            .into_iter()
+            .flatten()
+            .filter(|word| word.to_lowercase() != target_lower)
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn looks_up_by_key() {
+        let index = Index::new(["enlist", "google", "inlets", "banana", "Silent", "tinsel"]);
+        assert_eq!(
+            index.anagrams_of("listen"),
+            ["enlist", "inlets", "Silent", "tinsel"]
+        );
+        assert_eq!(index.anagrams_of("silent"), ["enlist", "inlets", "tinsel"]);
+        assert!(index.anagrams_of("apple").is_empty());
+    }
+
+    #[test]
+    fn skips_duplicates_and_words_without_letters() {
+        let index = Index::new(["stop", "pots", "stop", "---", ""]);
+        assert_eq!(index.len(), 2);
+        assert!(index.anagrams_of("").is_empty());
+        assert!(Index::new(Vec::<String>::new()).is_empty());
+    }
+
+    #[test]
+    fn a_hundred_thousand_words() {
+        // No real word list in the tests, so random six-letter "words" from
+        // a small alphabet, which makes plenty of anagrams.
+        let mut rng = StdRng::seed_from_u64(181);
+        let words: Vec<String> = (0..100_000)
+            .map(|_| (0..6).map(|_| rng.gen_range('a'..='h')).collect())
+            .collect();
+        let start = Instant::now();
+        let index = Index::new(&words);
+        for word in words.iter().take(1000) {
+            let anagrams = index.anagrams_of(word);
+            assert!(anagrams.iter().all(|anagram| key(anagram) == key(word)));
+        }
+        // Generous, for slow machines and debug builds: a linear scan per
+        // query would take far longer than this.
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+}
@@ -0,0 +1,53 @@
+// Two strings are anagrams when they have the same letters, however many
+// of each, in any order. Sorting the letters gives every anagram the same
+// key: "Listen" and "Silent" both become "eilnst".
+//
+// Only letters and digits count - spaces and punctuation are dropped - and
+// case is folded with `to_lowercase`, which knows about more than ASCII
+// ('Σ' and 'σ' match) and can give more than one char ('İ' becomes "i̇").
+//
+// Combining accents count as punctuation here, so "é" written as 'e' plus
+// U+0301 is just 'e', while a precomposed 'é' is a letter of its own.
+// Treating those two alike would need Unicode normalization, which isn't in
+// the standard library.
+pub fn key(word: &str) -> String {
+    let mut letters: Vec<char> = word
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+    letters.sort_unstable();
+    letters.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts_lowercased_letters() {
+        assert_eq!(key("Listen"), "eilnst");
+        assert_eq!(key("Silent"), "eilnst");
+        assert_eq!(key("Dormitory"), key("dirty room!!"));
+    }
+
+    #[test]
+    fn drops_everything_but_letters_and_digits() {
+        assert_eq!(key("Tom Marvolo Riddle"), key("I am Lord Voldemort."));
+        assert_eq!(key("r2-d2"), "22dr");
+        assert_eq!(key(" \t-,.!? "), "");
+    }
+
+    #[test]
+    fn folds_case_beyond_ascii() {
+        assert_eq!(key("ΣΟΦΊΑ"), key("σοφία"));
+        assert_eq!(key("Ärger"), key("rÄGER"));
+        assert_eq!(key("İ"), "i\u{307}");
+    }
+
+    #[test]
+    fn combining_accents_are_dropped() {
+        assert_eq!(key("cafe\u{301}"), "acef");
+        assert_ne!(key("café"), key("cafe\u{301}"));
+    }
+}
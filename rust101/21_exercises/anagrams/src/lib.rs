@@ -0,0 +1,143 @@
+/* Things learned during this exercise:
+   * Sorting a word's letters gives a key that all its anagrams share, so
+   "are these anagrams?" becomes "are the keys equal?", and grouping
+   anagrams is grouping by key - one `HashMap` entry per key.
+   * `char::to_lowercase` returns an iterator, not a char, because some
+   letters lowercase to more than one ('İ' is 'i' plus a combining dot).
+   `flat_map` flattens those into one stream of chars.
+   * `is_alphanumeric` and `to_lowercase` follow Unicode, so Greek or
+   Cyrillic words work the same as English. What std doesn't do is
+   normalization: 'é' can be one char or 'e' plus an accent, and telling
+   them apart needs a crate like unicode-normalization.
+   * A `HashMap` iterates in a different order every run. For output that
+   has to be the same each time, either use a `BTreeMap`/`BTreeSet` or sort
+   at the end.
+   * Building an index once and looking things up in it beats scanning a
+   100,000-word list on every query.
+   * `entry(key).or_default()` inserts an empty value the first time a key
+   is seen - a BTreeSet in `anagram_groups`, a Vec in `Index` - so grouping
+   needs no "is it there yet?" check.
+   * See https://doc.rust-lang.org/std/primitive.char.html#method.to_lowercase and
+   https://doc.rust-lang.org/std/collections/index.html
+*/
+mod index;
+mod key;
+
+pub use index::Index;
+pub use key::key;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+// Whether `a` and `b` use exactly the same letters, ignoring case, spaces
+// and punctuation. An anagram rearranges letters, so strings with none -
+// the empty string, or only punctuation - aren't anagrams of anything, not
+// even each other.
+pub fn are_anagrams(a: &str, b: &str) -> bool {
+    let key_a = key(a);
+    !key_a.is_empty() && key_a == key(b)
+}
+
+// Every word, in groups of words that are anagrams of each other. The
+// output is sorted - within each group and then by each group's first word
+// - so the same words always give the same groups, whatever their order.
+// Duplicates are dropped, and words without letters are left out.
+pub fn anagram_groups(words: &[&str]) -> Vec<Vec<String>> {
+    let mut groups: BTreeMap<String, BTreeSet<&str>> = BTreeMap::new();
+    for &word in words {
+        let key = key(word);
+        if !key.is_empty() {
+            groups.entry(key).or_default().insert(word);
+        }
+    }
+    let mut groups: Vec<Vec<String>> = groups
+        .into_values()
+        .map(|group| group.into_iter().map(str::to_string).collect())
+        .collect();
+    groups.sort();
+    groups
+}
+
+// The words in `wordlist` that are anagrams of `target`, in wordlist
+// order, not counting the target itself. For many queries against one list,
+// an `Index` avoids going through the whole list each time.
+pub fn find_anagrams(target: &str, wordlist: &[String]) -> Vec<String> {
+    let target_lower = target.to_lowercase();
+    wordlist
+        .iter()
+        .filter(|word| word.to_lowercase() != target_lower && are_anagrams(target, word))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(words: &[&str]) -> Vec<String> {
+        words.iter().map(|word| word.to_string()).collect()
+    }
+
+    #[test]
+    fn ignores_case_spaces_and_punctuation() {
+        assert!(are_anagrams("Listen", "Silent"));
+        assert!(are_anagrams("Astronomer", "Moon starer"));
+        assert!(are_anagrams("A gentleman.", "Elegant man!"));
+        assert!(!are_anagrams("listen", "listens"));
+        assert!(!are_anagrams("aab", "abb"));
+    }
+
+    #[test]
+    fn empty_strings_are_not_anagrams() {
+        assert!(!are_anagrams("", ""));
+        assert!(!are_anagrams("...", "!!!"));
+        assert!(!are_anagrams("", "a"));
+    }
+
+    #[test]
+    fn non_latin_scripts() {
+        assert!(are_anagrams("апельсин", "спаниель"));
+        assert!(are_anagrams("ΑΓΑΠΗ", "πηγαα"));
+        assert!(are_anagrams("日本", "本日"));
+        assert!(!are_anagrams("日本", "日日"));
+    }
+
+    #[test]
+    fn groups_are_sorted() {
+        let words = ["tea", "bat", "eat", "ate", "tab", "nat", "Tan", "eat"];
+        let expected = vec![
+            strings(&["Tan", "nat"]),
+            strings(&["ate", "eat", "tea"]),
+            strings(&["bat", "tab"]),
+        ];
+        assert_eq!(anagram_groups(&words), expected);
+        // Any other order of the same words gives the same groups.
+        let mut reversed = words;
+        reversed.reverse();
+        assert_eq!(anagram_groups(&reversed), expected);
+    }
+
+    #[test]
+    fn grouping_leaves_out_words_without_letters() {
+        assert_eq!(anagram_groups(&["", "--", "a"]), vec![strings(&["a"])]);
+        assert!(anagram_groups(&[]).is_empty());
+    }
+
+    #[test]
+    fn finds_anagrams_but_not_the_word_itself() {
+        let wordlist = strings(&["enlist", "google", "LISTEN", "inlets", "banana", "Silent"]);
+        assert_eq!(
+            find_anagrams("Listen", &wordlist),
+            strings(&["enlist", "inlets", "Silent"])
+        );
+        assert!(find_anagrams("", &wordlist).is_empty());
+    }
+
+    #[test]
+    fn finding_agrees_with_the_index() {
+        let wordlist = strings(&["stop", "pots", "tops", "spot", "post", "opts", "stoop"]);
+        let index = Index::new(&wordlist);
+        for target in ["stop", "POTS", "stoop", "nothing"] {
+            assert_eq!(index.anagrams_of(target), find_anagrams(target, &wordlist));
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+use anagrams::Index;
+
+fn repl(index: &Index) -> io::Result<()> {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        io::stdout().flush()?;
+        let Some(line) = lines.next().transpose()? else {
+            println!();
+            return Ok(());
+        };
+        let word = line.trim();
+        if word.is_empty() {
+            return Ok(());
+        }
+        let anagrams = index.anagrams_of(word);
+        if anagrams.is_empty() {
+            println!("no anagrams of {word:?}");
+        } else {
+            println!("{}", anagrams.join(" "));
+        }
+    }
+}
+
+// Usage: anagrams WORDLIST. The word list has one word or phrase per line.
+// Then each line typed is a query, until an empty line or end of input.
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let [path] = args.as_slice() else {
+        eprintln!("Usage: anagrams WORDLIST");
+        process::exit(2);
+    };
+    let contents = fs::read_to_string(path).unwrap_or_else(|err| {
+        eprintln!("couldn't read {path}: {err}");
+        process::exit(1);
+    });
+
+    let index = Index::new(contents.lines().map(str::trim));
+    println!("{} words loaded from {path}", index.len());
+    if let Err(err) = repl(&index) {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
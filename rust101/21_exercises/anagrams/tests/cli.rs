@@ -0,0 +1,59 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const WORDS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/words.txt");
+
+fn anagrams(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_anagrams"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn answers_each_query() {
+    let output = anagrams(&[WORDS], "Listen\nsTOP\nDirty-Room\nспаниель\nxyz\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "17 words loaded from ".to_string()
+            + WORDS
+            + "\n\
+               > enlist inlets silent tinsel\n\
+               > pots tops spot post opts\n\
+               > dormitory dirty room\n\
+               > апельсин\n\
+               > no anagrams of \"xyz\"\n\
+               > \n"
+    );
+}
+
+#[test]
+fn an_empty_line_stops() {
+    let output = anagrams(&[WORDS], "stop\n\npots\n");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // The query after the empty line is never answered.
+    assert!(
+        stdout.ends_with("\n> pots tops spot post opts\n> "),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn needs_a_readable_word_list() {
+    assert_eq!(anagrams(&[], "").status.code(), Some(2));
+    assert_eq!(anagrams(&[WORDS, "extra"], "").status.code(), Some(2));
+    let output = anagrams(&["no/such/words.txt"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("couldn't read"));
+}
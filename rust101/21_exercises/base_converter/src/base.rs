@@ -0,0 +1,184 @@
+use crate::error::BaseError;
+
+pub const MIN_BASE: u32 = 2;
+pub const MAX_BASE: u32 = 36;
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+fn check_base(base: u32) -> Result<(), BaseError> {
+    if (MIN_BASE..=MAX_BASE).contains(&base) {
+        Ok(())
+    } else {
+        Err(BaseError::InvalidBase(base))
+    }
+}
+
+// What a digit is worth: 0-9, then a (or A) is 10 up to z (or Z) at 35.
+// Whether that's allowed in a given base is up to the caller.
+fn digit_value(ch: char) -> Option<u32> {
+    match ch {
+        '0'..='9' => Some(ch as u32 - '0' as u32),
+        'a'..='z' => Some(ch as u32 - 'a' as u32 + 10),
+        'A'..='Z' => Some(ch as u32 - 'A' as u32 + 10),
+        _ => None,
+    }
+}
+
+// Reads `s` as digits in `base`, most significant first: each digit
+// multiplies what's been read so far by the base and adds itself. No sign,
+// prefix or spaces - just digits.
+pub fn parse_in_base(s: &str, base: u32) -> Result<u128, BaseError> {
+    check_base(base)?;
+    if s.is_empty() {
+        return Err(BaseError::Empty);
+    }
+    let mut n: u128 = 0;
+    for (i, ch) in s.chars().enumerate() {
+        let digit =
+            digit_value(ch)
+                .filter(|&digit| digit < base)
+                .ok_or(BaseError::InvalidDigit {
+                    ch,
+                    position: i + 1,
+                    base,
+                })?;
+        n = n
+            .checked_mul(base.into())
+            .and_then(|n| n.checked_add(digit.into()))
+            .ok_or(BaseError::Overflow)?;
+    }
+    Ok(n)
+}
+
+// Writes `n` in `base` with lowercase letters for digits past 9. The digits
+// come out least significant first - the remainders of dividing by the base
+// again and again - so they're reversed at the end.
+//
+// # Panics
+//
+// If `base` isn't between 2 and 36.
+pub fn format_in_base(mut n: u128, base: u32) -> String {
+    if let Err(err) = check_base(base) {
+        panic!("{err}");
+    }
+    if n == 0 {
+        return "0".to_string();
+    }
+    let base = u128::from(base);
+    let mut digits = Vec::new();
+    while n > 0 {
+        digits.push(DIGITS[(n % base) as usize]);
+        n /= base;
+    }
+    digits.reverse();
+    String::from_utf8(digits).expect("the digits are all ASCII")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn parses_digits() {
+        assert_eq!(parse_in_base("ff", 16), Ok(255));
+        assert_eq!(parse_in_base("FF", 16), Ok(255));
+        assert_eq!(parse_in_base("fF", 16), Ok(255));
+        assert_eq!(parse_in_base("101", 2), Ok(5));
+        assert_eq!(parse_in_base("777", 8), Ok(511));
+        assert_eq!(parse_in_base("zz", 36), Ok(36 * 36 - 1));
+        assert_eq!(parse_in_base("0000", 7), Ok(0));
+    }
+
+    #[test]
+    fn formats_digits() {
+        assert_eq!(format_in_base(255, 16), "ff");
+        assert_eq!(format_in_base(5, 2), "101");
+        assert_eq!(format_in_base(0, 2), "0");
+        assert_eq!(format_in_base(35, 36), "z");
+        assert_eq!(format_in_base(u128::MAX, 2), "1".repeat(128));
+        assert_eq!(format_in_base(u128::MAX, 16), "f".repeat(32));
+    }
+
+    #[test]
+    fn rejects_digits_too_big_for_the_base() {
+        assert_eq!(
+            parse_in_base("102", 2),
+            Err(BaseError::InvalidDigit {
+                ch: '2',
+                position: 3,
+                base: 2
+            })
+        );
+        assert_eq!(
+            parse_in_base("fg", 16).unwrap_err().to_string(),
+            "'g' at position 2 isn't a digit in base 16"
+        );
+        // Positions count chars, not bytes.
+        assert_eq!(
+            parse_in_base("1é", 10),
+            Err(BaseError::InvalidDigit {
+                ch: 'é',
+                position: 2,
+                base: 10
+            })
+        );
+        for bad in ["-1", "+1", " 1", "1_000", "0x1"] {
+            assert!(parse_in_base(bad, 10).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn rejects_empty_input_and_bad_bases() {
+        assert_eq!(parse_in_base("", 10), Err(BaseError::Empty));
+        assert_eq!(parse_in_base("1", 1), Err(BaseError::InvalidBase(1)));
+        assert_eq!(parse_in_base("1", 37), Err(BaseError::InvalidBase(37)));
+    }
+
+    #[test]
+    #[should_panic(expected = "base 0 isn't supported")]
+    fn formatting_in_a_bad_base_panics() {
+        format_in_base(1, 0);
+    }
+
+    #[test]
+    fn overflow() {
+        let max = u128::MAX.to_string();
+        assert_eq!(parse_in_base(&max, 10), Ok(u128::MAX));
+        // One more than the largest u128.
+        assert_eq!(
+            parse_in_base("340282366920938463463374607431768211456", 10),
+            Err(BaseError::Overflow)
+        );
+        assert_eq!(parse_in_base(&"1".repeat(129), 2), Err(BaseError::Overflow));
+    }
+
+    #[test]
+    fn round_trips_in_every_base() {
+        let mut rng = StdRng::seed_from_u64(187);
+        let mut values = vec![
+            0,
+            1,
+            2,
+            35,
+            36,
+            255,
+            256,
+            u128::from(u64::MAX),
+            u128::MAX,
+            u128::MAX - 1,
+        ];
+        values.extend((0..128).map(|bit| 1u128 << bit));
+        values.extend((0..200).map(|_| rng.gen::<u128>() >> rng.gen_range(0..128)));
+        for base in MIN_BASE..=MAX_BASE {
+            for &n in &values {
+                let formatted = format_in_base(n, base);
+                assert_eq!(parse_in_base(&formatted, base), Ok(n), "{n} in base {base}");
+                assert_eq!(parse_in_base(&formatted.to_uppercase(), base), Ok(n));
+                // The standard library agrees - checked here, not used above.
+                assert_eq!(u128::from_str_radix(&formatted, base), Ok(n));
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::error::Error;
+use std::fmt;
+
+// Positions count characters from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BaseError {
+    InvalidBase(u32),
+    Empty,
+    InvalidDigit {
+        ch: char,
+        position: usize,
+        base: u32,
+    },
+    // Bigger than u128::MAX.
+    Overflow,
+}
+
+impl fmt::Display for BaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BaseError::InvalidBase(base) => {
+                write!(f, "base {base} isn't supported: use 2 to 36")
+            }
+            BaseError::Empty => write!(f, "no digits"),
+            BaseError::InvalidDigit { ch, position, base } => write!(
+                f,
+                "{ch:?} at position {position} isn't a digit in base {base}"
+            ),
+            BaseError::Overflow => write!(f, "too big: the most is {}", u128::MAX),
+        }
+    }
+}
+
+impl Error for BaseError {}
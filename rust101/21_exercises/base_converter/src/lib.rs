@@ -0,0 +1,195 @@
+/* Things learned during this exercise:
+   * Reading digits is a fold: start at zero, and for each digit multiply
+   by the base and add it. Writing is the reverse: the remainder after
+   dividing by the base is the last digit, so the digits come out backwards
+   and need reversing.
+   * `checked_mul` and `checked_add` return None instead of wrapping, which
+   turns "too many digits for a u128" into an ordinary error.
+   * A char range like 'a'..='z' works in `match`, and `ch as u32 - 'a' as
+   u32` is a letter's place in the alphabet - fine once the match has made
+   sure it's ASCII.
+   * `u128::from_str_radix` does all this already. Writing it by hand is the
+   point, but the standard version is still handy for checking against in
+   the tests.
+   * Prefixes like 0x are a convention of the text, not part of the number,
+   so they're stripped before parsing - and a digit's reported position has
+   to add them back on to point at the right place in what was typed.
+   * See https://doc.rust-lang.org/std/primitive.u128.html#method.checked_mul
+*/
+mod base;
+mod error;
+
+pub use base::{format_in_base, parse_in_base, MAX_BASE, MIN_BASE};
+pub use error::BaseError;
+
+const PREFIXES: [(&str, u32); 6] = [
+    ("0x", 16),
+    ("0X", 16),
+    ("0o", 8),
+    ("0O", 8),
+    ("0b", 2),
+    ("0B", 2),
+];
+
+// Splits a 0x, 0o or 0b prefix off `s`, returning the base it stands for
+// and the digits after it.
+pub fn detect_prefix(s: &str) -> Option<(u32, &str)> {
+    PREFIXES
+        .iter()
+        .find_map(|&(prefix, base)| s.strip_prefix(prefix).map(|digits| (base, digits)))
+}
+
+// Reads a number as typed. With `from`, it's in that base, though a prefix
+// that agrees with it ("0xff" with base 16) is allowed. Without, a prefix
+// decides the base, and no prefix means decimal.
+//
+// Hexadecimal digits include b, so with base 16 "0b1" is the number 0xb1,
+// not binary - an explicit base wins over a prefix that disagrees with it.
+pub fn parse_number(input: &str, from: Option<u32>) -> Result<u128, BaseError> {
+    let (base, digits) = match (detect_prefix(input), from) {
+        (Some((prefix_base, digits)), None) => (prefix_base, digits),
+        (Some((prefix_base, digits)), Some(from)) if prefix_base == from => (from, digits),
+        (_, from) => (from.unwrap_or(10), input),
+    };
+    let skipped = input.len() - digits.len();
+    parse_in_base(digits, base).map_err(|err| match err {
+        BaseError::InvalidDigit { ch, position, base } => BaseError::InvalidDigit {
+            ch,
+            position: position + skipped,
+            base,
+        },
+        err => err,
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub number: String,
+    pub from: Option<u32>,
+    pub to: u32,
+}
+
+fn parse_base(value: Option<String>, option: &str) -> Result<u32, String> {
+    let value = value.ok_or(format!("{option} needs a base"))?;
+    value
+        .parse()
+        .ok()
+        .filter(|base| (MIN_BASE..=MAX_BASE).contains(base))
+        .ok_or(format!("{option} needs a base from 2 to 36, not {value:?}"))
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut number = None;
+        let mut from = None;
+        let mut to = 10;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--from" => from = Some(parse_base(args.next(), "--from")?),
+                "--to" => to = parse_base(args.next(), "--to")?,
+                _ if arg.starts_with("--") => return Err(format!("unknown option: {arg:?}")),
+                _ if number.is_some() => return Err(format!("only one number, not {arg:?} too")),
+                _ => number = Some(arg),
+            }
+        }
+        let number = number.ok_or("which number? For example 0xff")?;
+        Ok(Config { number, from, to })
+    }
+}
+
+pub fn run(config: &Config) -> Result<String, BaseError> {
+    let n = parse_number(&config.number, config.from)?;
+    Ok(format_in_base(n, config.to))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["convert".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    #[test]
+    fn prefixes_pick_the_base() {
+        assert_eq!(parse_number("0xff", None), Ok(255));
+        assert_eq!(parse_number("0XFF", None), Ok(255));
+        assert_eq!(parse_number("0o17", None), Ok(15));
+        assert_eq!(parse_number("0b101", None), Ok(5));
+        assert_eq!(parse_number("255", None), Ok(255));
+        assert_eq!(parse_number("0", None), Ok(0));
+    }
+
+    #[test]
+    fn from_gives_the_base_of_unprefixed_input() {
+        assert_eq!(parse_number("ff", Some(16)), Ok(255));
+        assert_eq!(parse_number("0xff", Some(16)), Ok(255));
+        assert_eq!(parse_number("zz", Some(36)), Ok(1295));
+        // Not a binary prefix in base 16, but the digits 0, b and 1.
+        assert_eq!(parse_number("0b1", Some(16)), Ok(0xb1));
+    }
+
+    #[test]
+    fn positions_include_the_prefix() {
+        assert_eq!(
+            parse_number("0b102", None),
+            Err(BaseError::InvalidDigit {
+                ch: '2',
+                position: 5,
+                base: 2
+            })
+        );
+        assert_eq!(parse_number("0x", None), Err(BaseError::Empty));
+        assert_eq!(
+            parse_number("0xff", Some(10)),
+            Err(BaseError::InvalidDigit {
+                ch: 'x',
+                position: 2,
+                base: 10
+            })
+        );
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&["0xff", "--to", "2"])),
+            Ok(Config {
+                number: "0xff".to_string(),
+                from: None,
+                to: 2
+            })
+        );
+        assert_eq!(
+            Config::build(args(&["--from", "36", "zz"])),
+            Ok(Config {
+                number: "zz".to_string(),
+                from: Some(36),
+                to: 10
+            })
+        );
+        for bad in [
+            &[][..],
+            &["1", "2"],
+            &["1", "--to", "1"],
+            &["1", "--to"],
+            &["1", "--from", "x"],
+        ] {
+            assert!(Config::build(args(bad)).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn runs() {
+        let config = Config::build(args(&["0xff", "--to", "2"])).unwrap();
+        assert_eq!(run(&config), Ok("11111111".to_string()));
+        let config = Config::build(args(&["--from", "2", "--to", "36", &"1".repeat(128)])).unwrap();
+        assert_eq!(run(&config), Ok(format_in_base(u128::MAX, 36)));
+    }
+}
@@ -0,0 +1,19 @@
+use std::env;
+use std::process;
+
+use base_converter::Config;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: convert NUMBER [--from BASE] [--to BASE]");
+        process::exit(2);
+    });
+    match base_converter::run(&config) {
+        Ok(converted) => println!("{converted}"),
+        Err(err) => {
+            eprintln!("{:?}: {err}", config.number);
+            process::exit(1);
+        }
+    }
+}
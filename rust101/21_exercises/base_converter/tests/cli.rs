@@ -0,0 +1,42 @@
+use std::process::{Command, Output};
+
+fn convert(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_convert"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn converts_between_bases() {
+    let output = convert(&["0xff", "--to", "2"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "11111111\n");
+    assert_eq!(stdout(&convert(&["255"])), "255\n");
+    assert_eq!(stdout(&convert(&["0b1010", "--to", "16"])), "a\n");
+    assert_eq!(
+        stdout(&convert(&["--from", "36", "RUST", "--to", "10"])),
+        "1299629\n"
+    );
+}
+
+#[test]
+fn invalid_digits_are_pointed_out() {
+    let output = convert(&["0o178"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "\"0o178\": '8' at position 5 isn't a digit in base 8\n"
+    );
+}
+
+#[test]
+fn bad_bases_are_usage_errors() {
+    let output = convert(&["10", "--to", "37"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: convert"));
+}
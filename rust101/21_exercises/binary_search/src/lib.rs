@@ -0,0 +1,209 @@
+/* Things learned during this exercise:
+   * Binary search, written out rather than borrowed from std, with the same
+   contract as `slice::binary_search_by`: the closure says whether an
+   element is Less, Equal or Greater than what's wanted, the answer is
+   `Ok(index)` of a match or `Err(index)` of where it would have to be
+   inserted to keep the slice sorted.
+   * It's the guessing game strategy: guess the middle, and each "too big"
+   or "too small" halves what's left, so 100 numbers need at most 7
+   guesses. `cargo run` plays it.
+   * Keeping the range half-open - `low..high`, `high` not included - makes
+   the loop simple: it ends when the range is empty, and `low` is then the
+   insertion point.
+   * `low + (high - low) / 2` rather than `(low + high) / 2`, which could
+   overflow on a huge slice.
+   * With duplicates, `Ok` gives the index of some match, not necessarily
+   the first. `lower_bound` and `upper_bound` find the edges of the run of
+   equal elements, by searching with a closure that never says Equal.
+   * The tests compare against std on random sorted vectors, with a fixed
+   seed so a failure can be repeated.
+*/
+use std::cmp::Ordering;
+
+/// Searches a slice sorted in the order `f` describes. `f` compares an
+/// element to the target: `Less` if the element comes before it.
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// let squares = [1, 4, 9, 16, 25];
+/// assert_eq!(binary_search::binary_search_by(&squares, |x| x.cmp(&9)), Ok(2));
+/// // 10 isn't there, but would go at index 3.
+/// assert_eq!(binary_search::binary_search_by(&squares, |x| x.cmp(&10)), Err(3));
+/// ```
+pub fn binary_search_by<T, F>(slice: &[T], f: F) -> Result<usize, usize>
+where
+    F: Fn(&T) -> Ordering,
+{
+    // The target, if it's there, is somewhere in low..high.
+    let mut low = 0;
+    let mut high = slice.len();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match f(&slice[mid]) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+    Err(low)
+}
+
+pub fn binary_search<T: Ord>(slice: &[T], target: &T) -> Result<usize, usize> {
+    binary_search_by(slice, |element| element.cmp(target))
+}
+
+/// The index of the first element not less than `target` - where the run
+/// of elements equal to it starts, or would start.
+///
+/// ```
+/// use binary_search::{lower_bound, upper_bound};
+///
+/// let rolls = [1, 2, 4, 4, 4, 6];
+/// // The 4s are rolls[2..5].
+/// assert_eq!(lower_bound(&rolls, &4), 2);
+/// assert_eq!(upper_bound(&rolls, &4), 5);
+/// // There are no 3s, so both are where one would go.
+/// assert_eq!(lower_bound(&rolls, &3), 2);
+/// assert_eq!(upper_bound(&rolls, &3), 2);
+/// ```
+pub fn lower_bound<T: Ord>(slice: &[T], target: &T) -> usize {
+    // Treating "equal" as "too big" keeps going left past every match, so
+    // the search always fails - at the first element that isn't less.
+    binary_search_by(slice, |element| {
+        if element < target {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    })
+    .unwrap_err()
+}
+
+/// The index just past the last element not greater than `target`.
+pub fn upper_bound<T: Ord>(slice: &[T], target: &T) -> usize {
+    binary_search_by(slice, |element| {
+        if element <= target {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    })
+    .unwrap_err()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    // A sorted vector of up to 50 small numbers, so there are plenty of
+    // duplicates and gaps.
+    fn random_sorted(rng: &mut StdRng) -> Vec<i32> {
+        let len = rng.gen_range(0..50);
+        let mut numbers: Vec<i32> = (0..len).map(|_| rng.gen_range(0..30)).collect();
+        numbers.sort();
+        numbers
+    }
+
+    #[test]
+    fn empty_slice() {
+        let empty: [i32; 0] = [];
+        assert_eq!(binary_search(&empty, &5), Err(0));
+        assert_eq!(lower_bound(&empty, &5), 0);
+        assert_eq!(upper_bound(&empty, &5), 0);
+    }
+
+    #[test]
+    fn single_element() {
+        assert_eq!(binary_search(&[5], &5), Ok(0));
+        assert_eq!(binary_search(&[5], &4), Err(0));
+        assert_eq!(binary_search(&[5], &6), Err(1));
+    }
+
+    #[test]
+    fn all_equal_elements() {
+        let sevens = [7; 10];
+        let found = binary_search(&sevens, &7).unwrap();
+        assert!(found < 10);
+        assert_eq!(lower_bound(&sevens, &7), 0);
+        assert_eq!(upper_bound(&sevens, &7), 10);
+    }
+
+    #[test]
+    fn targets_outside_the_range() {
+        let odds = [1, 3, 5, 7, 9];
+        assert_eq!(binary_search(&odds, &0), Err(0));
+        assert_eq!(binary_search(&odds, &i32::MIN), Err(0));
+        assert_eq!(binary_search(&odds, &10), Err(5));
+        assert_eq!(binary_search(&odds, &i32::MAX), Err(5));
+        assert_eq!(lower_bound(&odds, &100), 5);
+        assert_eq!(upper_bound(&odds, &-100), 0);
+    }
+
+    #[test]
+    fn every_element_and_every_gap() {
+        let odds = [1, 3, 5, 7, 9];
+        for (i, x) in odds.iter().enumerate() {
+            assert_eq!(binary_search(&odds, x), Ok(i));
+            assert_eq!(binary_search(&odds, &(x - 1)), Err(i));
+        }
+    }
+
+    #[test]
+    fn by_a_key_in_descending_order() {
+        let words = ["banana", "cherry", "kiwi", "fig"];
+        // Sorted longest first, so the comparison is the wrong way round.
+        let by_length = |target: usize| move |word: &&str| target.cmp(&word.len());
+        assert_eq!(binary_search_by(&words, by_length(4)), Ok(2));
+        assert_eq!(binary_search_by(&words, by_length(5)), Err(2));
+        assert_eq!(binary_search_by(&words, by_length(3)), Ok(3));
+    }
+
+    #[test]
+    fn a_hundred_numbers_take_at_most_seven_guesses() {
+        let numbers: Vec<u32> = (1..=100).collect();
+        for secret in 1..=100 {
+            let guesses = std::cell::Cell::new(0);
+            let found = binary_search_by(&numbers, |x| {
+                guesses.set(guesses.get() + 1);
+                x.cmp(&secret)
+            });
+            assert_eq!(found, Ok(secret as usize - 1));
+            assert!(guesses.get() <= 7, "{secret} took {}", guesses.get());
+        }
+    }
+
+    #[test]
+    fn agrees_with_std() {
+        let mut rng = StdRng::seed_from_u64(174);
+        for _ in 0..2_000 {
+            let numbers = random_sorted(&mut rng);
+            let target = rng.gen_range(-2..32);
+            match (
+                binary_search(&numbers, &target),
+                numbers.binary_search(&target),
+            ) {
+                // With duplicates the two may find different copies.
+                (Ok(ours), Ok(_)) => assert_eq!(numbers[ours], target),
+                (ours, theirs) => assert_eq!(ours, theirs, "{numbers:?} {target}"),
+            }
+        }
+    }
+
+    #[test]
+    fn bounds_agree_with_partition_point() {
+        let mut rng = StdRng::seed_from_u64(1740);
+        for _ in 0..2_000 {
+            let numbers = random_sorted(&mut rng);
+            let target = rng.gen_range(-2..32);
+            let lower = lower_bound(&numbers, &target);
+            let upper = upper_bound(&numbers, &target);
+            assert_eq!(lower, numbers.partition_point(|&x| x < target));
+            assert_eq!(upper, numbers.partition_point(|&x| x <= target));
+            let copies = numbers.iter().filter(|&&x| x == target).count();
+            assert_eq!(upper - lower, copies, "{numbers:?} {target}");
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::env;
+use std::process;
+
+use binary_search::binary_search_by;
+
+// The guessing game played by binary search: each guess is the middle of
+// what's left, and the answer - too small, too big - halves it.
+// Usage: binary_search [SECRET], a number from 1 to 100 (default 37).
+fn main() {
+    let secret: u32 = match env::args().nth(1) {
+        None => 37,
+        Some(arg) => match arg.parse() {
+            Ok(n @ 1..=100) => n,
+            _ => {
+                eprintln!("Usage: binary_search [SECRET], with SECRET from 1 to 100");
+                process::exit(2);
+            }
+        },
+    };
+
+    let numbers: Vec<u32> = (1..=100).collect();
+    // The closure is `Fn`, so it can't push to a Vec it captures mutably;
+    // a RefCell lets it record the guesses anyway.
+    let guesses = RefCell::new(Vec::new());
+    let found = binary_search_by(&numbers, |&guess| {
+        let ordering = guess.cmp(&secret);
+        guesses.borrow_mut().push((guess, ordering));
+        ordering
+    });
+
+    for (guess, ordering) in guesses.borrow().iter() {
+        let answer = match ordering {
+            Ordering::Less => "Too small!",
+            Ordering::Greater => "Too big!",
+            Ordering::Equal => "You win!",
+        };
+        println!("Guess {guess}: {answer}");
+    }
+    let index = found.expect("every number from 1 to 100 is in the list");
+    println!(
+        "Found {} in {} guesses.",
+        numbers[index],
+        guesses.borrow().len()
+    );
+}
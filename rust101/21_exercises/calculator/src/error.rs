@@ -0,0 +1,59 @@
+use std::error::Error;
+use std::fmt;
+
+// Columns count characters from 1, as an editor would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalcError {
+    UnexpectedChar { ch: char, column: usize },
+    // `token` is how it was written, so the message can quote it.
+    UnexpectedToken { token: String, column: usize },
+    UnexpectedEnd,
+    UnclosedParen { column: usize },
+    NumberTooBig { column: usize },
+    DivisionByZero { column: usize },
+    Overflow { column: usize },
+}
+
+impl CalcError {
+    // Where the problem is, for pointing at it. None for problems at the
+    // end of the input as a whole.
+    pub fn column(&self) -> Option<usize> {
+        match *self {
+            CalcError::UnexpectedChar { column, .. }
+            | CalcError::UnexpectedToken { column, .. }
+            | CalcError::UnclosedParen { column }
+            | CalcError::NumberTooBig { column }
+            | CalcError::DivisionByZero { column }
+            | CalcError::Overflow { column } => Some(column),
+            CalcError::UnexpectedEnd => None,
+        }
+    }
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CalcError::UnexpectedChar { ch, column } => {
+                write!(f, "unexpected character {ch:?} at column {column}")
+            }
+            CalcError::UnexpectedToken { token, column } => {
+                write!(f, "unexpected '{token}' at column {column}")
+            }
+            CalcError::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            CalcError::UnclosedParen { column } => {
+                write!(f, "unclosed '(' at column {column}")
+            }
+            CalcError::NumberTooBig { column } => {
+                write!(f, "number too big at column {column}")
+            }
+            CalcError::DivisionByZero { column } => {
+                write!(f, "division by zero at column {column}")
+            }
+            CalcError::Overflow { column } => {
+                write!(f, "result too big at column {column}")
+            }
+        }
+    }
+}
+
+impl Error for CalcError {}
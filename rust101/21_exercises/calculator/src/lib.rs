@@ -0,0 +1,209 @@
+/* Things learned during this exercise:
+   * A calculator for whole numbers with `+ - * / %`, parentheses and unary
+   minus, in two steps:
+     * `tokenize` turns the text into tokens - numbers, operators,
+     parentheses - each with the column it started at;
+     * `parse` reorders them into reverse Polish notation with the
+     shunting-yard algorithm, which `Rpn::evaluate` then runs on a stack.
+       cargo run -- "2 * (3 + 4)"
+       cargo run              (a REPL; an empty line or Ctrl-D ends it)
+   * RPN puts operators after their operands - `2 3 4 + *` - so precedence
+   and parentheses are settled once, up front, and evaluating needs no
+   lookahead at all.
+   * Errors are values with positions, not panics: "unexpected ')' at
+   column 7". Division by zero and overflow (`checked_add` and friends
+   return None) are found while evaluating, and point at the operator.
+   * The tests are mostly a table: input, expected answer or error. Adding a
+   case is one line. The property test builds `a op b` from random numbers
+   and checks the answer against Rust's own arithmetic.
+*/
+mod error;
+mod rpn;
+mod token;
+
+pub use error::CalcError;
+pub use rpn::{parse, Rpn};
+pub use token::{tokenize, Token, TokenKind};
+
+/// Converts an expression to RPN without evaluating it.
+///
+/// ```
+/// assert_eq!(calculator::to_rpn("(1 + 2) * -3").unwrap().to_string(), "1 2 + 3 neg *");
+/// ```
+pub fn to_rpn(input: &str) -> Result<Rpn, CalcError> {
+    parse(&tokenize(input)?)
+}
+
+/// Evaluates an expression.
+///
+/// ```
+/// assert_eq!(calculator::eval("2 * (3 + 4)"), Ok(14));
+/// assert_eq!(
+///     calculator::eval("(1 + 2))").unwrap_err().to_string(),
+///     "unexpected ')' at column 8"
+/// );
+/// ```
+pub fn eval(input: &str) -> Result<i64, CalcError> {
+    to_rpn(input)?.evaluate()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    type CheckedOp = fn(i64, i64) -> Option<i64>;
+    type IntOp = fn(i64, i64) -> i64;
+
+    #[test]
+    fn evaluates() {
+        let cases: &[(&str, i64)] = &[
+            ("0", 0),
+            ("42", 42),
+            ("  7  ", 7),
+            ("1 + 2", 3),
+            ("1+2*3", 7),
+            ("(1 + 2) * 3", 9),
+            ("2 * 3 + 4", 10),
+            ("2 * (3 + 4)", 14),
+            ("8 - 3 - 2", 3),
+            ("8 - (3 - 2)", 7),
+            ("64 / 4 / 2", 8),
+            ("64 / (4 / 2)", 32),
+            ("7 / 2", 3),
+            ("-7 / 2", -3),
+            ("7 % 3", 1),
+            ("-7 % 3", -1),
+            ("7 % -3", 1),
+            ("2 + 3 % 2", 3),
+            ("-5", -5),
+            ("--5", 5),
+            ("- - -5", -5),
+            ("-(2 + 3)", -5),
+            ("-2 * 3", -6),
+            ("2 * -3", -6),
+            ("2 - -3", 5),
+            ("-2 - -3", 1),
+            ("((((1))))", 1),
+            ("(1 + (2 * (3 + (4 * 5))))", 47),
+            ("10 - 2 * 3 + 8 / 4 % 3", 6),
+            ("9223372036854775807", i64::MAX),
+            ("-9223372036854775807 - 1", i64::MIN),
+        ];
+        for &(input, expected) in cases {
+            assert_eq!(eval(input), Ok(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let cases: &[(&str, &str)] = &[
+            ("", "unexpected end of expression"),
+            ("   ", "unexpected end of expression"),
+            ("1 +", "unexpected end of expression"),
+            ("-", "unexpected end of expression"),
+            ("(", "unexpected end of expression"),
+            ("(1 + 2", "unclosed '(' at column 1"),
+            ("((1 + 2)", "unclosed '(' at column 1"),
+            ("(1 + 2))", "unexpected ')' at column 8"),
+            ("(1 + 2) )", "unexpected ')' at column 9"),
+            (")", "unexpected ')' at column 1"),
+            ("()", "unexpected ')' at column 2"),
+            ("1 + )", "unexpected ')' at column 5"),
+            ("* 2", "unexpected '*' at column 1"),
+            ("1 * * 2", "unexpected '*' at column 5"),
+            ("1 2", "unexpected '2' at column 3"),
+            ("2 (3)", "unexpected '(' at column 3"),
+            ("(1) 2", "unexpected '2' at column 5"),
+            ("+1", "unexpected '+' at column 1"),
+            ("1 ^ 2", "unexpected character '^' at column 3"),
+            ("1.5", "unexpected character '.' at column 2"),
+            ("x", "unexpected character 'x' at column 1"),
+            ("99999999999999999999", "number too big at column 1"),
+            ("1 / 0", "division by zero at column 3"),
+            ("1 % 0", "division by zero at column 3"),
+            ("1 / (2 - 2)", "division by zero at column 3"),
+            ("9223372036854775807 + 1", "result too big at column 21"),
+            (
+                "(-9223372036854775807 - 1) / -1",
+                "result too big at column 28",
+            ),
+            ("-(-9223372036854775807 - 1)", "result too big at column 1"),
+            ("4611686018427387904 * 2", "result too big at column 21"),
+        ];
+        for &(input, expected) in cases {
+            match eval(input) {
+                Err(err) => assert_eq!(err.to_string(), expected, "{input}"),
+                Ok(value) => panic!("{input} gave {value}, not an error"),
+            }
+        }
+    }
+
+    #[test]
+    fn errors_know_their_column() {
+        assert_eq!(eval("1 / 0").unwrap_err().column(), Some(3));
+        assert_eq!(eval("1 +").unwrap_err().column(), None);
+    }
+
+    #[test]
+    fn rpn() {
+        let cases: &[(&str, &str)] = &[
+            ("1 + 2 * 3", "1 2 3 * +"),
+            ("(1 + 2) * 3", "1 2 + 3 *"),
+            ("8 - 3 - 2", "8 3 - 2 -"),
+            ("-2 * 3", "2 neg 3 *"),
+            ("2 * -3", "2 3 neg *"),
+            ("- -2", "2 neg neg"),
+        ];
+        for &(input, expected) in cases {
+            assert_eq!(to_rpn(input).unwrap().to_string(), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn binary_operations_match_rust() {
+        let mut rng = StdRng::seed_from_u64(177);
+        let ops: [(&str, CheckedOp); 5] = [
+            ("+", i64::checked_add),
+            ("-", i64::checked_sub),
+            ("*", i64::checked_mul),
+            ("/", i64::checked_div),
+            ("%", i64::checked_rem),
+        ];
+        for _ in 0..5_000 {
+            let a = rng.gen_range(-1_000..=1_000);
+            let b = rng.gen_range(-20..=20);
+            let (symbol, op) = ops[rng.gen_range(0..ops.len())];
+            // Negative numbers are written as they'd be printed: "3 - -4".
+            let input = format!("{a} {symbol} {b}");
+            match op(a, b) {
+                Some(expected) => assert_eq!(eval(&input), Ok(expected), "{input}"),
+                None => assert_eq!(
+                    eval(&input),
+                    Err(CalcError::DivisionByZero {
+                        column: a.to_string().len() + 2
+                    }),
+                    "{input}"
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn parenthesized_sums_match_rust() {
+        // (a op b) op (c op d), with only + - * so nothing can fail.
+        let mut rng = StdRng::seed_from_u64(1770);
+        let ops: [(&str, IntOp); 3] = [
+            ("+", |a, b| a + b),
+            ("-", |a, b| a - b),
+            ("*", |a, b| a * b),
+        ];
+        for _ in 0..2_000 {
+            let [a, b, c, d] = [(); 4].map(|_| rng.gen_range(-100..=100));
+            let [(s1, f1), (s2, f2), (s3, f3)] = [(); 3].map(|_| ops[rng.gen_range(0..3)]);
+            let input = format!("({a} {s1} {b}) {s2} ({c} {s3} {d})");
+            assert_eq!(eval(&input), Ok(f2(f1(a, b), f3(c, d))), "{input}");
+        }
+    }
+}
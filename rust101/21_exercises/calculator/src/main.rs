@@ -0,0 +1,57 @@
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+use calculator::CalcError;
+
+// The error, then the input with a caret under the column it's about.
+fn report(input: &str, err: &CalcError) -> String {
+    let mut report = format!("error: {err}");
+    if let Some(column) = err.column() {
+        report += &format!("\n  {input}\n  {:>column$}", "^");
+    }
+    report
+}
+
+fn repl() -> io::Result<()> {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("> ");
+        io::stdout().flush()?;
+        let Some(line) = lines.next().transpose()? else {
+            println!();
+            return Ok(());
+        };
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+        match calculator::eval(&line) {
+            Ok(value) => println!("{value}"),
+            Err(err) => println!("{}", report(&line, &err)),
+        }
+    }
+}
+
+// Usage: calculator [EXPRESSION...]. The arguments are joined with spaces,
+// so `calculator 1 + 2` works without quotes - though `*` and parentheses
+// need quoting from the shell anyway. With no arguments it's a REPL.
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() {
+        if let Err(err) = repl() {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+        return;
+    }
+
+    let input = args.join(" ");
+    match calculator::eval(&input) {
+        Ok(value) => println!("{value}"),
+        Err(err) => {
+            eprintln!("{}", report(&input, &err));
+            process::exit(1);
+        }
+    }
+}
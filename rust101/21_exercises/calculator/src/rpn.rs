@@ -0,0 +1,200 @@
+use std::fmt;
+
+use crate::token::{Token, TokenKind};
+use crate::CalcError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Neg,
+}
+
+impl Op {
+    // Higher binds tighter. Negation is highest, so -2 * 3 is (-2) * 3.
+    fn precedence(self) -> u8 {
+        match self {
+            Op::Add | Op::Sub => 1,
+            Op::Mul | Op::Div | Op::Rem => 2,
+            Op::Neg => 3,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "*",
+            Op::Div => "/",
+            Op::Rem => "%",
+            // Spelled out, since in RPN "-" on its own would mean subtract.
+            Op::Neg => "neg",
+        }
+    }
+}
+
+// The column goes with the operator so a runtime error can point at it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Item {
+    Number(i64),
+    Op(Op, usize),
+}
+
+/// An expression in reverse Polish notation: operators after their
+/// operands, so `(1 + 2) * 3` is `1 2 + 3 *`, and no parentheses are
+/// needed. Only `parse` makes one, so it's always well-formed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rpn(Vec<Item>);
+
+// What waits on the operator stack during parsing.
+enum Pending {
+    Op(Op, usize),
+    LParen(usize),
+}
+
+/// Dijkstra's shunting-yard algorithm. Numbers go straight to the output;
+/// operators wait on a stack until one that binds less tightly arrives, or
+/// a ')' closes their group, and then move across. The order they come off
+/// the stack is the order they're applied in.
+///
+/// Whether a '-' subtracts or negates depends on what came before it: if an
+/// operand is expected - at the start, after an operator or after '(' -
+/// it's a negation.
+pub fn parse(tokens: &[Token]) -> Result<Rpn, CalcError> {
+    let mut output = Vec::new();
+    let mut ops: Vec<Pending> = Vec::new();
+    let mut expect_operand = true;
+    for token in tokens {
+        let unexpected = || CalcError::UnexpectedToken {
+            token: token.kind.to_string(),
+            column: token.column,
+        };
+        let binary = match token.kind {
+            TokenKind::Number(n) => {
+                if !expect_operand {
+                    return Err(unexpected());
+                }
+                output.push(Item::Number(n));
+                expect_operand = false;
+                continue;
+            }
+            TokenKind::LParen => {
+                if !expect_operand {
+                    return Err(unexpected());
+                }
+                ops.push(Pending::LParen(token.column));
+                continue;
+            }
+            TokenKind::RParen => {
+                if expect_operand {
+                    return Err(unexpected());
+                }
+                loop {
+                    match ops.pop() {
+                        Some(Pending::Op(op, column)) => output.push(Item::Op(op, column)),
+                        Some(Pending::LParen(_)) => break,
+                        // Nothing left to close.
+                        None => return Err(unexpected()),
+                    }
+                }
+                continue;
+            }
+            // A prefix operator: nothing is waiting for it to finish, so it
+            // goes straight on the stack.
+            TokenKind::Minus if expect_operand => {
+                ops.push(Pending::Op(Op::Neg, token.column));
+                continue;
+            }
+            TokenKind::Plus => Op::Add,
+            TokenKind::Minus => Op::Sub,
+            TokenKind::Star => Op::Mul,
+            TokenKind::Slash => Op::Div,
+            TokenKind::Percent => Op::Rem,
+        };
+
+        if expect_operand {
+            return Err(unexpected());
+        }
+        // `>=` rather than `>` makes equal precedence go left to right:
+        // 8 - 3 - 2 is (8 - 3) - 2.
+        while let Some(&Pending::Op(top, column)) = ops.last() {
+            if top.precedence() < binary.precedence() {
+                break;
+            }
+            output.push(Item::Op(top, column));
+            ops.pop();
+        }
+        ops.push(Pending::Op(binary, token.column));
+        expect_operand = true;
+    }
+
+    // Covers empty input and a trailing operator alike.
+    if expect_operand {
+        return Err(CalcError::UnexpectedEnd);
+    }
+    while let Some(pending) = ops.pop() {
+        match pending {
+            Pending::Op(op, column) => output.push(Item::Op(op, column)),
+            Pending::LParen(column) => return Err(CalcError::UnclosedParen { column }),
+        }
+    }
+    Ok(Rpn(output))
+}
+
+impl Rpn {
+    /// Runs the expression on a stack: a number is pushed, an operator pops
+    /// its operands and pushes the result. Division rounds towards zero and
+    /// `%` takes the sign of the left side, as in Rust.
+    pub fn evaluate(&self) -> Result<i64, CalcError> {
+        const WELL_FORMED: &str = "parse only makes well-formed RPN";
+
+        let mut stack: Vec<i64> = Vec::new();
+        for item in &self.0 {
+            let (op, column) = match *item {
+                Item::Number(n) => {
+                    stack.push(n);
+                    continue;
+                }
+                Item::Op(op, column) => (op, column),
+            };
+            let result = if op == Op::Neg {
+                stack.pop().expect(WELL_FORMED).checked_neg()
+            } else {
+                let b = stack.pop().expect(WELL_FORMED);
+                let a = stack.pop().expect(WELL_FORMED);
+                if b == 0 && matches!(op, Op::Div | Op::Rem) {
+                    return Err(CalcError::DivisionByZero { column });
+                }
+                match op {
+                    Op::Add => a.checked_add(b),
+                    Op::Sub => a.checked_sub(b),
+                    Op::Mul => a.checked_mul(b),
+                    Op::Div => a.checked_div(b),
+                    Op::Rem => a.checked_rem(b),
+                    Op::Neg => unreachable!(),
+                }
+            };
+            // With b = 0 ruled out, `checked_*` only fails on overflow -
+            // i64::MIN / -1 included.
+            stack.push(result.ok_or(CalcError::Overflow { column })?);
+        }
+        Ok(stack.pop().expect(WELL_FORMED))
+    }
+}
+
+impl fmt::Display for Rpn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let words: Vec<String> = self
+            .0
+            .iter()
+            .map(|item| match item {
+                Item::Number(n) => n.to_string(),
+                Item::Op(op, _) => op.symbol().to_string(),
+            })
+            .collect();
+        write!(f, "{}", words.join(" "))
+    }
+}
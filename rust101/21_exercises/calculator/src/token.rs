@@ -0,0 +1,139 @@
+use std::fmt;
+
+use crate::CalcError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Number(i64),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    LParen,
+    RParen,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub column: usize,
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TokenKind::Number(n) => write!(f, "{n}"),
+            TokenKind::Plus => write!(f, "+"),
+            TokenKind::Minus => write!(f, "-"),
+            TokenKind::Star => write!(f, "*"),
+            TokenKind::Slash => write!(f, "/"),
+            TokenKind::Percent => write!(f, "%"),
+            TokenKind::LParen => write!(f, "("),
+            TokenKind::RParen => write!(f, ")"),
+        }
+    }
+}
+
+// Splits the input into numbers, operators and parentheses, skipping
+// whitespace. It doesn't know whether a '-' means subtract or negate -
+// that depends on what comes before it, which is the parser's business.
+pub fn tokenize(input: &str) -> Result<Vec<Token>, CalcError> {
+    let mut tokens = Vec::new();
+    // Columns are 1-based character positions, not byte offsets, so they
+    // still line up after a non-ASCII character.
+    let mut chars = input.chars().zip(1..).peekable();
+    while let Some((ch, column)) = chars.next() {
+        let kind = match ch {
+            '+' => TokenKind::Plus,
+            '-' => TokenKind::Minus,
+            '*' => TokenKind::Star,
+            '/' => TokenKind::Slash,
+            '%' => TokenKind::Percent,
+            '(' => TokenKind::LParen,
+            ')' => TokenKind::RParen,
+            '0'..='9' => {
+                let mut digits = String::from(ch);
+                while let Some(&(digit @ '0'..='9', _)) = chars.peek() {
+                    digits.push(digit);
+                    chars.next();
+                }
+                let n = digits
+                    .parse()
+                    .map_err(|_| CalcError::NumberTooBig { column })?;
+                TokenKind::Number(n)
+            }
+            ch if ch.is_whitespace() => continue,
+            ch => return Err(CalcError::UnexpectedChar { ch, column }),
+        };
+        tokens.push(Token { kind, column });
+    }
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(input: &str) -> Vec<TokenKind> {
+        tokenize(input)
+            .unwrap()
+            .into_iter()
+            .map(|token| token.kind)
+            .collect()
+    }
+
+    #[test]
+    fn operators_numbers_and_parens() {
+        use TokenKind::*;
+        assert_eq!(
+            kinds("(12+3)*-4 / 5 % 6"),
+            [
+                LParen,
+                Number(12),
+                Plus,
+                Number(3),
+                RParen,
+                Star,
+                Minus,
+                Number(4),
+                Slash,
+                Number(5),
+                Percent,
+                Number(6)
+            ]
+        );
+        assert_eq!(kinds("   "), []);
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let columns: Vec<usize> = tokenize(" 10 +\t2")
+            .unwrap()
+            .iter()
+            .map(|token| token.column)
+            .collect();
+        assert_eq!(columns, [2, 5, 7]);
+        assert_eq!(
+            tokenize("é + x"),
+            Err(CalcError::UnexpectedChar {
+                ch: 'é', column: 1
+            })
+        );
+        assert_eq!(
+            tokenize("1 + é"),
+            Err(CalcError::UnexpectedChar {
+                ch: 'é', column: 5
+            })
+        );
+    }
+
+    #[test]
+    fn numbers_must_fit_an_i64() {
+        assert_eq!(kinds("9223372036854775807"), [TokenKind::Number(i64::MAX)]);
+        assert_eq!(
+            tokenize("1 + 9223372036854775808"),
+            Err(CalcError::NumberTooBig { column: 5 })
+        );
+    }
+}
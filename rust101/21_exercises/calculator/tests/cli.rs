@@ -0,0 +1,65 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn calculator(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_calculator"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn evaluates_an_argument() {
+    let output = calculator(&["2 * (3 + 4)"]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "14\n");
+}
+
+#[test]
+fn joins_several_arguments() {
+    let output = calculator(&["10", "-", "-3"]);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "13\n");
+}
+
+#[test]
+fn points_at_the_error() {
+    let output = calculator(&["(1 + 2))"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "error: unexpected ')' at column 8\n  (1 + 2))\n         ^\n"
+    );
+}
+
+#[test]
+fn repl_evaluates_each_line() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_calculator"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"1 + 1\n7 / 0\n2 * 21\n\nnot reached\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "> 2\n> error: division by zero at column 3\n  7 / 0\n    ^\n> 42\n> "
+    );
+}
+
+#[test]
+fn repl_ends_at_end_of_input() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_calculator"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"3 % 2\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "> 1\n> \n");
+}
@@ -0,0 +1,100 @@
+// Moves an ASCII letter `shift` places along the alphabet, wrapping from z
+// back to a and keeping its case. Everything else - digits, punctuation,
+// and letters outside ASCII like 'é' - is left alone: the cipher only knows
+// 26 letters.
+pub fn shift_char(c: char, shift: u8) -> char {
+    let base = match c {
+        'a'..='z' => b'a',
+        'A'..='Z' => b'A',
+        _ => return c,
+    };
+    // Both are below 26, so the sum can't overflow a u8.
+    char::from(base + (c as u8 - base + shift % 26) % 26)
+}
+
+// Any shift works, negative or more than 26: only its remainder mod 26
+// matters. rem_euclid keeps that remainder in 0..26 even for negatives.
+fn normalize(shift: i64) -> u8 {
+    shift.rem_euclid(26) as u8
+}
+
+pub fn encrypt(text: &str, shift: i64) -> String {
+    let shift = normalize(shift);
+    text.chars().map(|c| shift_char(c, shift)).collect()
+}
+
+pub fn decrypt(text: &str, shift: i64) -> String {
+    encrypt(text, -shift)
+}
+
+// A shift of 13 is half the alphabet, so doing it twice gets back where
+// you started: encrypting and decrypting are the same thing.
+pub fn rot13(text: &str) -> String {
+    encrypt(text, 13)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn shifts_and_wraps() {
+        assert_eq!(encrypt("abc xyz", 3), "def abc");
+        assert_eq!(encrypt("Hello, World!", 3), "Khoor, Zruog!");
+        assert_eq!(decrypt("Khoor, Zruog!", 3), "Hello, World!");
+    }
+
+    #[test]
+    fn any_shift_is_taken_mod_26() {
+        assert_eq!(encrypt("abc", 29), encrypt("abc", 3));
+        assert_eq!(encrypt("abc", -1), "zab");
+        assert_eq!(encrypt("abc", 26), "abc");
+        assert_eq!(
+            encrypt("abc", i64::MIN),
+            encrypt("abc", i64::MIN.rem_euclid(26))
+        );
+    }
+
+    #[test]
+    fn every_shift_round_trips() {
+        let text = "The quick brown fox jumps over the lazy dog. 0123456789!";
+        for shift in -60..60 {
+            let encrypted = encrypt(text, shift);
+            assert_eq!(decrypt(&encrypted, shift), text, "shift {shift}");
+            if shift.rem_euclid(26) != 0 {
+                assert_ne!(encrypted, text, "shift {shift}");
+            }
+        }
+    }
+
+    #[test]
+    fn non_ascii_passes_through() {
+        assert_eq!(encrypt("café ñandú Ærø", 1), "dbgé ñboeú Æsø");
+        assert_eq!(encrypt("日本語 ελληνικά 🦀", 7), "日本語 ελληνικά 🦀");
+    }
+
+    #[test]
+    fn rot13_undoes_itself() {
+        assert_eq!(
+            rot13("Why did the chicken cross the road?"),
+            "Jul qvq gur puvpxra pebff gur ebnq?"
+        );
+        // Random strings mixing ASCII letters with everything else.
+        let mut rng = StdRng::seed_from_u64(180);
+        for _ in 0..200 {
+            let len = rng.gen_range(0..40);
+            let text: String = (0..len)
+                .map(|_| {
+                    if rng.gen_bool(0.7) {
+                        rng.gen_range('A'..='z')
+                    } else {
+                        rng.gen::<char>()
+                    }
+                })
+                .collect();
+            assert_eq!(rot13(&rot13(&text)), text, "{text:?}");
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::caesar::decrypt;
+
+// How often each letter turns up in English, as a percentage of all
+// letters, a to z.
+const ENGLISH_FREQUENCIES: [f64; 26] = [
+    8.167, 1.492, 2.782, 4.253, 12.702, 2.228, 2.015, 6.094, 6.966, 0.153, 0.772, 4.025, 2.406,
+    6.749, 7.507, 1.929, 0.095, 5.987, 6.327, 9.056, 2.758, 0.978, 2.360, 0.150, 1.974, 0.074,
+];
+
+fn letter_counts(text: &str) -> [usize; 26] {
+    let mut counts = [0; 26];
+    for c in text.chars().filter(char::is_ascii_alphabetic) {
+        counts[(c.to_ascii_lowercase() as u8 - b'a') as usize] += 1;
+    }
+    counts
+}
+
+// The chi-squared statistic: for each letter, how far the count is from
+// what English would give, squared and scaled by what English would give.
+// The smaller it is, the more English the text looks.
+pub fn chi_squared(text: &str) -> f64 {
+    let counts = letter_counts(text);
+    let total: usize = counts.iter().sum();
+    if total == 0 {
+        return 0.0;
+    }
+    counts
+        .iter()
+        .zip(ENGLISH_FREQUENCIES)
+        .map(|(&count, frequency)| {
+            let expected = total as f64 * frequency / 100.0;
+            let difference = count as f64 - expected;
+            difference * difference / expected
+        })
+        .sum()
+}
+
+// There are only 26 shifts, so try them all and keep the one whose
+// decryption looks most like English. That needs enough text for the letter
+// counts to mean something: a short phrase can easily crack wrong.
+//
+// Text with no letters in it decrypts the same under every shift, and
+// gives a shift of 0.
+pub fn crack_caesar(ciphertext: &str) -> (u8, String) {
+    let (shift, plaintext, _) = (0..26u8)
+        .map(|shift| {
+            let plaintext = decrypt(ciphertext, shift.into());
+            let score = chi_squared(&plaintext);
+            (shift, plaintext, score)
+        })
+        // The first of any equally good shifts wins, so ties go to 0.
+        .reduce(|best, next| if next.2 < best.2 { next } else { best })
+        .unwrap();
+    (shift, plaintext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::caesar::encrypt;
+
+    const PARAGRAPH: &str = "It was the best of times, it was the worst of times, it was the \
+        age of wisdom, it was the age of foolishness, it was the epoch of belief, it was the \
+        epoch of incredulity, it was the season of Light, it was the season of Darkness, it was \
+        the spring of hope, it was the winter of despair.";
+
+    #[test]
+    fn english_scores_better_than_gibberish() {
+        assert!(chi_squared(PARAGRAPH) < chi_squared(&encrypt(PARAGRAPH, 1)));
+        assert!(chi_squared("the rain in spain") < chi_squared("qzjx kvwq xzzj"));
+    }
+
+    #[test]
+    fn cracks_every_shift_of_a_paragraph() {
+        for shift in 0..26 {
+            let ciphertext = encrypt(PARAGRAPH, shift.into());
+            assert_eq!(crack_caesar(&ciphertext), (shift, PARAGRAPH.to_string()));
+        }
+    }
+
+    #[test]
+    fn no_letters_means_shift_zero() {
+        assert_eq!(crack_caesar(""), (0, String::new()));
+        assert_eq!(crack_caesar("123 -- ?!"), (0, "123 -- ?!".to_string()));
+    }
+}
@@ -0,0 +1,170 @@
+/* Things learned during this exercise:
+   * A `char` is a Unicode scalar value, not a byte, but ASCII letters fit in
+   a u8, so `c as u8 - b'a'` gives a letter's place in the alphabet and
+   `char::from` turns a byte back into one. Checking the char is in 'a'..='z'
+   first is what makes the `as` safe: for 'é' it would silently truncate.
+   * `%` keeps the sign of the left side, so -1 % 26 is -1. `rem_euclid`
+   always gives 0..26, which is what wrapping around an alphabet needs.
+   * `iter().cycle()` repeats a key for as long as it's needed, and the
+   cycle only moves on when `next()` is called - so the key can skip
+   punctuation by only advancing on letters.
+   * Collecting an iterator of `Result`s into `Result<Vec<_>, _>` stops at
+   the first error, which makes validating every character a one-liner.
+   * f64 doesn't implement `Ord`, so `min_by_key` can't pick the lowest
+   score; `reduce` with an explicit comparison can, and decides ties.
+   * Frequency analysis breaks Caesar because each letter always becomes
+   the same letter, so English's letter counts survive encryption, only
+   shifted. Vigenère spreads each letter across several shifts.
+   * See https://doc.rust-lang.org/std/primitive.char.html and
+   https://en.wikipedia.org/wiki/Caesar_cipher
+*/
+mod caesar;
+mod crack;
+mod vigenere;
+
+pub use caesar::{decrypt, encrypt, rot13, shift_char};
+pub use crack::{chi_squared, crack_caesar};
+pub use vigenere::{Key, KeyError};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cipher {
+    Caesar(i64),
+    Rot13,
+    Vigenere(Key),
+}
+
+impl Cipher {
+    pub fn encrypt(&self, text: &str) -> String {
+        match self {
+            Cipher::Caesar(shift) => encrypt(text, *shift),
+            Cipher::Rot13 => rot13(text),
+            Cipher::Vigenere(key) => key.encrypt(text),
+        }
+    }
+
+    pub fn decrypt(&self, text: &str) -> String {
+        match self {
+            Cipher::Caesar(shift) => decrypt(text, *shift),
+            Cipher::Rot13 => rot13(text),
+            Cipher::Vigenere(key) => key.decrypt(text),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Encrypt(Cipher),
+    Decrypt(Cipher),
+    Crack,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub command: Command,
+    // The words after the options, joined with spaces. None reads stdin.
+    pub text: Option<String>,
+}
+
+// Reads the cipher options - exactly one of them - after `encrypt` or
+// `decrypt`, stopping at the first argument that isn't one.
+fn parse_cipher(
+    args: &mut std::iter::Peekable<impl Iterator<Item = String>>,
+) -> Result<Cipher, String> {
+    let cipher = match args.next().as_deref() {
+        Some("--shift") => {
+            let value = args.next().ok_or("--shift needs a number")?;
+            let shift = value
+                .parse()
+                .map_err(|_| format!("not a number to shift by: {value:?}"))?;
+            Cipher::Caesar(shift)
+        }
+        Some("--rot13") => Cipher::Rot13,
+        Some("--key") => {
+            let word = args.next().ok_or("--key needs a word")?;
+            Cipher::Vigenere(Key::new(&word).map_err(|err| err.to_string())?)
+        }
+        _ => return Err("choose a cipher with --shift N, --rot13 or --key WORD".to_string()),
+    };
+    if let Some(arg) = args.next_if(|arg| arg.starts_with("--")) {
+        return Err(format!("only one cipher at a time, not {arg:?} as well"));
+    }
+    Ok(cipher)
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(args: impl Iterator<Item = String>) -> Result<Config, String> {
+        let mut args = args.skip(1).peekable();
+        let command = match args.next().as_deref() {
+            Some("encrypt") => Command::Encrypt(parse_cipher(&mut args)?),
+            Some("decrypt") => Command::Decrypt(parse_cipher(&mut args)?),
+            Some("crack") => Command::Crack,
+            Some(other) => return Err(format!("unknown command: {other:?}")),
+            None => return Err("no command given".to_string()),
+        };
+        let words: Vec<String> = args.collect();
+        let text = (!words.is_empty()).then(|| words.join(" "));
+        Ok(Config { command, text })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["ciphers".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&["encrypt", "--shift", "-3", "hello", "world"])),
+            Ok(Config {
+                command: Command::Encrypt(Cipher::Caesar(-3)),
+                text: Some("hello world".to_string()),
+            })
+        );
+        assert_eq!(
+            Config::build(args(&["decrypt", "--key", "lemon"])),
+            Ok(Config {
+                command: Command::Decrypt(Cipher::Vigenere(Key::new("lemon").unwrap())),
+                text: None,
+            })
+        );
+        assert_eq!(
+            Config::build(args(&["crack", "uryyb"])).unwrap().command,
+            Command::Crack
+        );
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        for bad in [
+            &[][..],
+            &["scramble"],
+            &["encrypt", "hello"],
+            &["encrypt", "--shift"],
+            &["encrypt", "--shift", "three"],
+            &["encrypt", "--key", "not-a-word"],
+            &["decrypt", "--rot13", "--shift", "2"],
+        ] {
+            assert!(Config::build(args(bad)).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn ciphers_undo_themselves() {
+        let text = "Meet me at the usual place.";
+        for cipher in [
+            Cipher::Caesar(7),
+            Cipher::Rot13,
+            Cipher::Vigenere(Key::new("secret").unwrap()),
+        ] {
+            assert_eq!(cipher.decrypt(&cipher.encrypt(text)), text, "{cipher:?}");
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use std::env;
+use std::io::{self, Read};
+use std::process;
+
+use ciphers::{Command, Config};
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: ciphers encrypt|decrypt (--shift N | --rot13 | --key WORD) [TEXT...]");
+        eprintln!("       ciphers crack [TEXT...]");
+        process::exit(2);
+    });
+
+    // Text on the command line gets a newline after it; text from stdin
+    // keeps whatever it ended with.
+    let (text, newline) = match config.text {
+        Some(text) => (text, "\n"),
+        None => {
+            let mut text = String::new();
+            if let Err(err) = io::stdin().read_to_string(&mut text) {
+                eprintln!("couldn't read stdin: {err}");
+                process::exit(1);
+            }
+            (text, "")
+        }
+    };
+
+    let output = match &config.command {
+        Command::Encrypt(cipher) => cipher.encrypt(&text),
+        Command::Decrypt(cipher) => cipher.decrypt(&text),
+        Command::Crack => {
+            let (shift, plaintext) = ciphers::crack_caesar(&text);
+            // On stderr, so stdout is just the plaintext.
+            eprintln!("most likely shift: {shift}");
+            plaintext
+        }
+    };
+    print!("{output}{newline}");
+}
@@ -0,0 +1,113 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::caesar::shift_char;
+
+#[derive(Debug, PartialEq)]
+pub enum KeyError {
+    Empty,
+    NotALetter(char),
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyError::Empty => write!(f, "the key needs at least one letter"),
+            KeyError::NotALetter(c) => write!(f, "the key can only have letters, not {c:?}"),
+        }
+    }
+}
+
+impl Error for KeyError {}
+
+// A Vigenère key is a word, and each of its letters is a Caesar shift: a is
+// 0, b is 1 and so on. The shifts are applied in turn, over and over, so
+// the same plaintext letter comes out differently depending on where it is
+// - which is what defeats the frequency analysis that breaks Caesar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Key(Vec<u8>);
+
+impl Key {
+    pub fn new(word: &str) -> Result<Key, KeyError> {
+        let shifts = word
+            .chars()
+            .map(|c| match c {
+                'a'..='z' | 'A'..='Z' => Ok(c.to_ascii_lowercase() as u8 - b'a'),
+                _ => Err(KeyError::NotALetter(c)),
+            })
+            .collect::<Result<Vec<u8>, KeyError>>()?;
+        if shifts.is_empty() {
+            return Err(KeyError::Empty);
+        }
+        Ok(Key(shifts))
+    }
+
+    // Only letters use up a letter of the key. Spaces and punctuation pass
+    // through without moving it on, as in the traditional cipher.
+    fn apply(&self, text: &str, shift_of: impl Fn(u8) -> u8) -> String {
+        let mut shifts = self.0.iter().cycle();
+        text.chars()
+            .map(|c| {
+                if c.is_ascii_alphabetic() {
+                    shift_char(c, shift_of(*shifts.next().unwrap()))
+                } else {
+                    c
+                }
+            })
+            .collect()
+    }
+
+    pub fn encrypt(&self, text: &str) -> String {
+        self.apply(text, |shift| shift)
+    }
+
+    pub fn decrypt(&self, text: &str) -> String {
+        self.apply(text, |shift| (26 - shift) % 26)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_classic_example() {
+        let key = Key::new("LEMON").unwrap();
+        assert_eq!(key.encrypt("ATTACKATDAWN"), "LXFOPVEFRNHR");
+        assert_eq!(key.decrypt("LXFOPVEFRNHR"), "ATTACKATDAWN");
+    }
+
+    #[test]
+    fn keeps_case_and_skips_non_letters() {
+        let key = Key::new("lemon").unwrap();
+        assert_eq!(key.encrypt("Attack at dawn!"), "Lxfopv ef rnhr!");
+        assert_eq!(key.decrypt("Lxfopv ef rnhr!"), "Attack at dawn!");
+        assert_eq!(key.encrypt("été"), "éeé");
+    }
+
+    #[test]
+    fn a_one_letter_key_is_caesar() {
+        let key = Key::new("d").unwrap();
+        assert_eq!(
+            key.encrypt("Hello, World!"),
+            crate::caesar::encrypt("Hello, World!", 3)
+        );
+        assert_eq!(Key::new("a").unwrap().encrypt("unchanged"), "unchanged");
+    }
+
+    #[test]
+    fn round_trips() {
+        let text = "Sphinx of black quartz, judge my vow. 42 ÷ 6 = 7";
+        for word in ["a", "Key", "zzz", "abcdefghijklmnopqrstuvwxyz"] {
+            let key = Key::new(word).unwrap();
+            assert_eq!(key.decrypt(&key.encrypt(text)), text, "{word}");
+        }
+    }
+
+    #[test]
+    fn rejects_bad_keys() {
+        assert_eq!(Key::new(""), Err(KeyError::Empty));
+        assert_eq!(Key::new("two words"), Err(KeyError::NotALetter(' ')));
+        assert_eq!(Key::new("clé"), Err(KeyError::NotALetter('é')));
+    }
+}
@@ -0,0 +1,65 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn ciphers(args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ciphers"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // Dropping stdin closes it, so a command reading it sees the end.
+    let mut pipe = child.stdin.take().unwrap();
+    if let Some(input) = stdin {
+        pipe.write_all(input.as_bytes()).unwrap();
+    }
+    drop(pipe);
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn encrypts_and_decrypts_arguments() {
+    let output = ciphers(&["encrypt", "--shift", "3", "Hello,", "World!"], None);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "Khoor, Zruog!\n");
+    let output = ciphers(&["decrypt", "--key", "LEMON", "LXFOPVEFRNHR"], None);
+    assert_eq!(stdout(&output), "ATTACKATDAWN\n");
+    assert_eq!(
+        stdout(&ciphers(&["encrypt", "--rot13", "abc"], None)),
+        "nop\n"
+    );
+}
+
+#[test]
+fn reads_stdin_without_text_arguments() {
+    let output = ciphers(&["encrypt", "--shift", "1"], Some("line one\nline two\n"));
+    assert_eq!(stdout(&output), "mjof pof\nmjof uxp\n");
+}
+
+#[test]
+fn cracks_a_caesar_shift() {
+    let plaintext = "The rain in Spain stays mainly in the plain, or so the song \
+                     says, though the weather records tell a different story.\n";
+    let encrypted = ciphers(&["encrypt", "--shift", "11"], Some(plaintext));
+    let output = ciphers(&["crack"], Some(stdout(&encrypted)));
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), plaintext);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "most likely shift: 11\n"
+    );
+}
+
+#[test]
+fn usage_errors() {
+    for args in [&[][..], &["encrypt", "text"], &["decrypt", "--key", "a b"]] {
+        let output = ciphers(args, None);
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: ciphers"));
+    }
+}
@@ -0,0 +1,201 @@
+/* Things learned during this exercise:
+   * `read_until(b'\n', ..)` reads a line as bytes, so a file that isn't
+   UTF-8 passes through untouched, where `read_line` would refuse it.
+   * A trait with one method, `apply`, and a `Vec<Box<dyn Stage>>` make each
+   flag a small piece on its own. Returning `Option` lets a stage drop a
+   line, and `&mut self` lets it remember the lines before.
+   * Keeping one pipeline for all the files is what makes numbering and
+   squeezing carry on from one file to the next, as they do in cat.
+   * Putting the line number in a prefix, apart from the content, keeps the
+   tab after it from being shown as ^I by -T.
+   * `continue 'lines` leaves the inner loop over the stages and goes on to
+   the next line in one step.
+   * cat's -v notation: ^@ to ^_ for the controls, ^? for DEL, and M- before
+   anything with the top bit set, so every byte shows as printable ASCII.
+   * See https://doc.rust-lang.org/std/io/trait.BufRead.html#method.read_until
+   and https://www.gnu.org/software/coreutils/manual/html_node/cat-invocation.html
+*/
+mod line;
+mod pipeline;
+mod stages;
+
+pub use line::{read_line, Ending, Line};
+pub use pipeline::{CopyError, Options, Pipeline};
+pub use stages::{Number, ShowEnds, ShowNonprinting, ShowTabs, Squeeze, Stage};
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+// What to copy: stdin, given as no files or as "-", or a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub options: Options,
+    pub inputs: Vec<Input>,
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut options = Options::default();
+        let mut inputs = Vec::new();
+        for arg in args {
+            // Flags can be grouped, as in -bsA.
+            match arg.strip_prefix('-') {
+                Some(flags) if !flags.is_empty() => {
+                    for flag in flags.chars() {
+                        match flag {
+                            'n' => options.number = true,
+                            'b' => options.number_non_blank = true,
+                            's' => options.squeeze_blank = true,
+                            'v' => options.show_nonprinting = true,
+                            'T' => options.show_tabs = true,
+                            'E' => options.show_ends = true,
+                            // -A is -vET: everything shown.
+                            'A' => {
+                                options.show_nonprinting = true;
+                                options.show_tabs = true;
+                                options.show_ends = true;
+                            }
+                            _ => return Err(format!("unknown option: -{flag}")),
+                        }
+                    }
+                }
+                Some(_) => inputs.push(Input::Stdin),
+                None => inputs.push(Input::File(PathBuf::from(arg))),
+            }
+        }
+        if inputs.is_empty() {
+            inputs.push(Input::Stdin);
+        }
+        Ok(Config { options, inputs })
+    }
+}
+
+// A file that couldn't be read.
+#[derive(Debug)]
+pub struct FileError {
+    pub path: PathBuf,
+    pub err: io::Error,
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.err)
+    }
+}
+
+impl Error for FileError {}
+
+// Copies every input to `output` in turn, returning the files that
+// couldn't be read - which, like cat, don't stop the others being copied.
+// Failing to write is different: that's the error.
+pub fn run(
+    config: &Config,
+    mut stdin: impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<Vec<FileError>> {
+    let mut pipeline = Pipeline::new(config.options);
+    let mut errors = Vec::new();
+    for input in &config.inputs {
+        let (copied, path) = match input {
+            Input::Stdin => (pipeline.copy(&mut stdin, output), PathBuf::from("-")),
+            Input::File(path) => match File::open(path) {
+                Ok(file) => (
+                    pipeline.copy(&mut BufReader::new(file), output),
+                    path.clone(),
+                ),
+                Err(err) => (Err(CopyError::Read(err)), path.clone()),
+            },
+        };
+        match copied {
+            Ok(()) => {}
+            Err(CopyError::Read(err)) => errors.push(FileError { path, err }),
+            Err(CopyError::Write(err)) => return Err(err),
+        }
+    }
+    output.flush()?;
+    Ok(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["concat".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&[])),
+            Ok(Config {
+                options: Options::default(),
+                inputs: vec![Input::Stdin],
+            })
+        );
+        assert_eq!(
+            Config::build(args(&["-bs", "a.txt", "-", "-A"])),
+            Ok(Config {
+                options: Options {
+                    number: false,
+                    number_non_blank: true,
+                    squeeze_blank: true,
+                    show_nonprinting: true,
+                    show_tabs: true,
+                    show_ends: true,
+                },
+                inputs: vec![Input::File(PathBuf::from("a.txt")), Input::Stdin],
+            })
+        );
+        assert_eq!(
+            Config::build(args(&["-nZ"])),
+            Err("unknown option: -Z".to_string())
+        );
+    }
+
+    #[test]
+    fn copies_stdin_with_options() {
+        let config = Config::build(args(&["-nE"])).unwrap();
+        let mut output = Vec::new();
+        let errors = run(&config, "one\r\ntwo".as_bytes(), &mut output).unwrap();
+        assert!(errors.is_empty());
+        assert_eq!(output, b"     1\tone^M$\n     2\ttwo");
+    }
+
+    // A writer that fails, as stdout does when piped into something that
+    // has exited.
+    struct Closed;
+
+    impl Write for Closed {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::from(io::ErrorKind::BrokenPipe))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn stops_when_the_output_fails() {
+        let config =
+            Config::build(args(&["tests/data/blanks.txt", "tests/data/crlf.txt"])).unwrap();
+        let err = run(&config, io::empty(), &mut Closed).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    }
+}
@@ -0,0 +1,109 @@
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ending {
+    Lf,
+    CrLf,
+    // The last line of a file that doesn't end in a newline.
+    None,
+}
+
+// One line on its way through the stages. `prefix` is for line numbers:
+// it's written before the content but isn't part of it, so a stage that
+// rewrites the content - showing tabs as ^I, say - leaves the tab after the
+// number alone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub prefix: String,
+    // Bytes, not a String: cat copies whatever it's given, UTF-8 or not.
+    pub content: Vec<u8>,
+    pub ending: Ending,
+}
+
+impl Line {
+    // A line with nothing on it. As in cat, a CRLF line with nothing before
+    // the CR isn't blank: the CR is on it.
+    pub fn is_blank(&self) -> bool {
+        self.content.is_empty() && self.ending != Ending::CrLf
+    }
+
+    pub fn write_to(&self, output: &mut impl Write) -> io::Result<()> {
+        output.write_all(self.prefix.as_bytes())?;
+        output.write_all(&self.content)?;
+        output.write_all(match self.ending {
+            Ending::Lf => b"\n",
+            Ending::CrLf => b"\r\n",
+            Ending::None => b"",
+        })
+    }
+}
+
+// Reads the next line, or None at the end of the input.
+pub fn read_line(input: &mut impl BufRead) -> io::Result<Option<Line>> {
+    let mut content = Vec::new();
+    if input.read_until(b'\n', &mut content)? == 0 {
+        return Ok(None);
+    }
+    let ending = if content.ends_with(b"\r\n") {
+        content.truncate(content.len() - 2);
+        Ending::CrLf
+    } else if content.ends_with(b"\n") {
+        content.pop();
+        Ending::Lf
+    } else {
+        Ending::None
+    };
+    Ok(Some(Line {
+        prefix: String::new(),
+        content,
+        ending,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(input: &[u8]) -> Vec<Line> {
+        let mut input = input;
+        let mut lines = Vec::new();
+        while let Some(line) = read_line(&mut input).unwrap() {
+            lines.push(line);
+        }
+        lines
+    }
+
+    fn line(content: &[u8], ending: Ending) -> Line {
+        Line {
+            prefix: String::new(),
+            content: content.to_vec(),
+            ending,
+        }
+    }
+
+    #[test]
+    fn splits_off_the_line_endings() {
+        assert_eq!(
+            lines(b"unix\nwindows\r\n\r\nlast"),
+            [
+                line(b"unix", Ending::Lf),
+                line(b"windows", Ending::CrLf),
+                line(b"", Ending::CrLf),
+                line(b"last", Ending::None),
+            ]
+        );
+        assert!(lines(b"").is_empty());
+        // A lone CR is part of the line.
+        assert_eq!(lines(b"a\rb\n"), [line(b"a\rb", Ending::Lf)]);
+    }
+
+    #[test]
+    fn writes_back_what_was_read() {
+        let input = b"one\r\ntwo\n\n\xff\xfe not utf-8\nend";
+        let mut output = Vec::new();
+        for line in lines(input) {
+            line.write_to(&mut output).unwrap();
+        }
+        assert_eq!(output, input);
+    }
+}
@@ -0,0 +1,24 @@
+use std::env;
+use std::io::{self, BufWriter};
+use std::process;
+
+use concat::Config;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: concat [-n] [-b] [-s] [-v] [-T] [-E] [-A] [FILE...]");
+        process::exit(2);
+    });
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    let errors = concat::run(&config, io::stdin().lock(), &mut stdout).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        process::exit(1);
+    });
+    for err in &errors {
+        eprintln!("concat: {err}");
+    }
+    if !errors.is_empty() {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,170 @@
+use std::io::{self, BufRead, Write};
+
+use crate::line::read_line;
+use crate::stages::{Number, ShowEnds, ShowNonprinting, ShowTabs, Squeeze, Stage};
+
+// The flags, each one a stage. Which stages run depends on these, but the
+// order they run in doesn't depend on the order of the flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Options {
+    pub number: bool,
+    pub number_non_blank: bool,
+    pub squeeze_blank: bool,
+    pub show_nonprinting: bool,
+    pub show_tabs: bool,
+    pub show_ends: bool,
+}
+
+// The stages in the order cat applies them. Squeezing comes first, so a
+// dropped line doesn't use up a number; the numbers are in the prefix, so
+// the stages after don't touch them; and the $ goes on last, after
+// everything else has been shown.
+pub struct Pipeline {
+    stages: Vec<Box<dyn Stage>>,
+}
+
+impl Pipeline {
+    pub fn new(options: Options) -> Pipeline {
+        let mut stages: Vec<Box<dyn Stage>> = Vec::new();
+        if options.squeeze_blank {
+            stages.push(Box::new(Squeeze::default()));
+        }
+        // -b wins over -n.
+        if options.number_non_blank {
+            stages.push(Box::new(Number::non_blank()));
+        } else if options.number {
+            stages.push(Box::new(Number::all()));
+        }
+        if options.show_nonprinting {
+            stages.push(Box::new(ShowNonprinting));
+        }
+        if options.show_tabs {
+            stages.push(Box::new(ShowTabs));
+        }
+        if options.show_ends {
+            stages.push(Box::new(ShowEnds));
+        }
+        Pipeline { stages }
+    }
+
+    // Sends every line of `input` through the stages to `output`. The
+    // stages keep their state from one call to the next, so numbering and
+    // squeezing carry on across files.
+    pub fn copy(
+        &mut self,
+        input: &mut impl BufRead,
+        output: &mut impl Write,
+    ) -> Result<(), CopyError> {
+        // With no stages there's nothing to look at, so the input goes
+        // straight through a buffer at a time, with no splitting into lines.
+        if self.stages.is_empty() {
+            loop {
+                let buffer = input.fill_buf().map_err(CopyError::Read)?;
+                if buffer.is_empty() {
+                    return Ok(());
+                }
+                output.write_all(buffer).map_err(CopyError::Write)?;
+                let used = buffer.len();
+                input.consume(used);
+            }
+        }
+        'lines: while let Some(mut line) = read_line(input).map_err(CopyError::Read)? {
+            for stage in &mut self.stages {
+                match stage.apply(line) {
+                    Some(kept) => line = kept,
+                    None => continue 'lines,
+                }
+            }
+            line.write_to(output).map_err(CopyError::Write)?;
+        }
+        Ok(())
+    }
+}
+
+// Which side went wrong. A file that can't be read is reported and
+// skipped, but once the output can't be written there's no point going on.
+#[derive(Debug)]
+pub enum CopyError {
+    Read(io::Error),
+    Write(io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cat(options: Options, inputs: &[&[u8]]) -> String {
+        let mut pipeline = Pipeline::new(options);
+        let mut output = Vec::new();
+        for mut input in inputs.iter().copied() {
+            pipeline.copy(&mut input, &mut output).unwrap();
+        }
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn copies_unchanged_without_options() {
+        let input = b"a\tb\r\n\n\n\x01end";
+        assert_eq!(cat(Options::default(), &[input]).as_bytes(), input);
+    }
+
+    #[test]
+    fn numbering_carries_on_across_inputs() {
+        let options = Options {
+            number: true,
+            ..Options::default()
+        };
+        assert_eq!(
+            cat(options, &[b"a\nb\n", b"c\n"]),
+            "     1\ta\n     2\tb\n     3\tc\n"
+        );
+    }
+
+    #[test]
+    fn squeezing_carries_on_across_inputs() {
+        let options = Options {
+            squeeze_blank: true,
+            ..Options::default()
+        };
+        assert_eq!(cat(options, &[b"a\n\n", b"\n\nb\n"]), "a\n\nb\n");
+    }
+
+    #[test]
+    fn squeezed_lines_are_not_numbered() {
+        let options = Options {
+            number: true,
+            squeeze_blank: true,
+            ..Options::default()
+        };
+        assert_eq!(
+            cat(options, &[b"a\n\n\n\nb\n"]),
+            "     1\ta\n     2\t\n     3\tb\n"
+        );
+    }
+
+    #[test]
+    fn non_blank_numbering_wins() {
+        let options = Options {
+            number: true,
+            number_non_blank: true,
+            ..Options::default()
+        };
+        assert_eq!(cat(options, &[b"a\n\nb\n"]), "     1\ta\n\n     2\tb\n");
+    }
+
+    #[test]
+    fn shows_everything_without_touching_the_numbers() {
+        let options = Options {
+            number_non_blank: true,
+            squeeze_blank: true,
+            show_nonprinting: true,
+            show_tabs: true,
+            show_ends: true,
+            ..Options::default()
+        };
+        assert_eq!(
+            cat(options, &[b"\n\n\nx\ty\r\n\n\x7f"]),
+            "$\n     1\tx^Iy^M$\n$\n     2\t^?"
+        );
+    }
+}
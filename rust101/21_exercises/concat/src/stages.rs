@@ -0,0 +1,261 @@
+use crate::line::{Ending, Line};
+
+// One step of the processing, applied to every line in turn. Returning
+// None drops the line. Stages can keep state between lines - a count, or
+// whether the last line was blank - and since all the files go through
+// the same stages, that carries on from one file to the next, as in cat.
+pub trait Stage {
+    fn apply(&mut self, line: Line) -> Option<Line>;
+}
+
+// -s: a run of blank lines becomes one.
+#[derive(Debug, Default)]
+pub struct Squeeze {
+    last_was_blank: bool,
+}
+
+impl Stage for Squeeze {
+    fn apply(&mut self, line: Line) -> Option<Line> {
+        let blank = line.is_blank();
+        let repeat = blank && self.last_was_blank;
+        self.last_was_blank = blank;
+        if repeat {
+            None
+        } else {
+            Some(line)
+        }
+    }
+}
+
+// -n numbers every line and -b only the ones that aren't blank, in a
+// six-wide column followed by a tab.
+#[derive(Debug)]
+pub struct Number {
+    next: u64,
+    skip_blank: bool,
+}
+
+impl Number {
+    pub fn all() -> Number {
+        Number {
+            next: 1,
+            skip_blank: false,
+        }
+    }
+
+    pub fn non_blank() -> Number {
+        Number {
+            next: 1,
+            skip_blank: true,
+        }
+    }
+}
+
+impl Stage for Number {
+    fn apply(&mut self, mut line: Line) -> Option<Line> {
+        if !(self.skip_blank && line.is_blank()) {
+            line.prefix = format!("{:>6}\t{}", self.next, line.prefix);
+            self.next += 1;
+        }
+        Some(line)
+    }
+}
+
+// -v: control characters as ^X, DEL as ^?, and bytes past ASCII as M-
+// followed by what the byte would be without its top bit. Tabs are left
+// to ShowTabs, but the CR of a CRLF is shown as ^M, leaving a plain LF.
+#[derive(Debug, Default)]
+pub struct ShowNonprinting;
+
+// A seven-bit byte, with controls shown as ^X.
+fn push_caret(byte: u8, out: &mut Vec<u8>) {
+    match byte {
+        0..=31 => out.extend([b'^', byte + 64]),
+        127 => out.extend(b"^?"),
+        _ => out.push(byte),
+    }
+}
+
+impl Stage for ShowNonprinting {
+    fn apply(&mut self, mut line: Line) -> Option<Line> {
+        let mut visible = Vec::with_capacity(line.content.len());
+        for &byte in &line.content {
+            match byte {
+                b'\t' => visible.push(byte),
+                // With the top bit set, even a tab is shown: M-^I.
+                128..=255 => {
+                    visible.extend(b"M-");
+                    push_caret(byte - 128, &mut visible);
+                }
+                _ => push_caret(byte, &mut visible),
+            }
+        }
+        if line.ending == Ending::CrLf {
+            visible.extend(b"^M");
+            line.ending = Ending::Lf;
+        }
+        line.content = visible;
+        Some(line)
+    }
+}
+
+// -T: tabs as ^I.
+#[derive(Debug, Default)]
+pub struct ShowTabs;
+
+impl Stage for ShowTabs {
+    fn apply(&mut self, mut line: Line) -> Option<Line> {
+        if line.content.contains(&b'\t') {
+            let mut shown = Vec::with_capacity(line.content.len() + 1);
+            for &byte in &line.content {
+                match byte {
+                    b'\t' => shown.extend(b"^I"),
+                    _ => shown.push(byte),
+                }
+            }
+            line.content = shown;
+        }
+        Some(line)
+    }
+}
+
+// -E: a $ where each line ends, with the CR of a CRLF shown as ^M before
+// it. A last line with no newline gets none.
+#[derive(Debug, Default)]
+pub struct ShowEnds;
+
+impl Stage for ShowEnds {
+    fn apply(&mut self, mut line: Line) -> Option<Line> {
+        match line.ending {
+            Ending::Lf => line.content.push(b'$'),
+            Ending::CrLf => {
+                line.content.extend(b"^M$");
+                line.ending = Ending::Lf;
+            }
+            Ending::None => {}
+        }
+        Some(line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(content: &[u8], ending: Ending) -> Line {
+        Line {
+            prefix: String::new(),
+            content: content.to_vec(),
+            ending,
+        }
+    }
+
+    fn lf(content: &str) -> Line {
+        line(content.as_bytes(), Ending::Lf)
+    }
+
+    // Runs each line through the stage and keeps what comes out.
+    fn through(stage: &mut impl Stage, lines: Vec<Line>) -> Vec<Line> {
+        lines
+            .into_iter()
+            .filter_map(|line| stage.apply(line))
+            .collect()
+    }
+
+    #[test]
+    fn squeezes_runs_of_blank_lines() {
+        let lines = vec![
+            lf(""),
+            lf(""),
+            lf("a"),
+            lf(""),
+            lf(""),
+            lf(""),
+            lf("b"),
+            lf(""),
+        ];
+        assert_eq!(
+            through(&mut Squeeze::default(), lines),
+            [lf(""), lf("a"), lf(""), lf("b"), lf("")]
+        );
+    }
+
+    #[test]
+    fn a_crlf_line_is_not_blank() {
+        // As in cat: there's a CR on it.
+        let crlf = line(b"", Ending::CrLf);
+        let lines = vec![crlf.clone(), crlf.clone(), lf(""), lf("")];
+        assert_eq!(
+            through(&mut Squeeze::default(), lines),
+            [crlf.clone(), crlf, lf("")]
+        );
+    }
+
+    #[test]
+    fn numbers_every_line() {
+        let numbered = through(&mut Number::all(), vec![lf("a"), lf(""), lf("b")]);
+        let prefixes: Vec<&str> = numbered.iter().map(|line| line.prefix.as_str()).collect();
+        assert_eq!(prefixes, ["     1\t", "     2\t", "     3\t"]);
+    }
+
+    #[test]
+    fn numbers_only_non_blank_lines() {
+        let lines = vec![lf("a"), lf(""), line(b"", Ending::CrLf), lf("b")];
+        let numbered = through(&mut Number::non_blank(), lines);
+        let prefixes: Vec<&str> = numbered.iter().map(|line| line.prefix.as_str()).collect();
+        assert_eq!(prefixes, ["     1\t", "", "     2\t", "     3\t"]);
+    }
+
+    #[test]
+    fn counts_past_six_digits() {
+        let mut number = Number::all();
+        number.next = 1_234_567;
+        assert_eq!(number.apply(lf("x")).unwrap().prefix, "1234567\t");
+    }
+
+    #[test]
+    fn shows_nonprinting_bytes() {
+        let shown = ShowNonprinting.apply(lf("\x01a\tb\x1b\x7f")).unwrap();
+        assert_eq!(shown.content, b"^Aa\tb^[^?");
+        let shown = ShowNonprinting
+            .apply(line(b"\xe9\x89\xff", Ending::Lf))
+            .unwrap();
+        assert_eq!(shown.content, b"M-iM-^IM-^?");
+    }
+
+    #[test]
+    fn shows_the_cr_of_a_crlf() {
+        assert_eq!(
+            ShowNonprinting.apply(line(b"a\tb", Ending::CrLf)),
+            Some(lf("a\tb^M"))
+        );
+        // And then -E adds the $, as it would have anyway.
+        let shown = ShowNonprinting.apply(line(b"a", Ending::CrLf)).unwrap();
+        assert_eq!(ShowEnds.apply(shown), Some(lf("a^M$")));
+    }
+
+    #[test]
+    fn shows_tabs() {
+        let shown = ShowTabs.apply(lf("a\tb\t\t")).unwrap();
+        assert_eq!(shown.content, b"a^Ib^I^I");
+        assert_eq!(ShowTabs.apply(lf("plain")), Some(lf("plain")));
+    }
+
+    #[test]
+    fn shows_line_ends() {
+        assert_eq!(ShowEnds.apply(lf("a")).unwrap().content, b"a$");
+        assert_eq!(ShowEnds.apply(line(b"a", Ending::CrLf)), Some(lf("a^M$")));
+        assert_eq!(
+            ShowEnds.apply(line(b"last", Ending::None)),
+            Some(line(b"last", Ending::None))
+        );
+    }
+
+    #[test]
+    fn the_number_is_not_part_of_the_content() {
+        let numbered = Number::all().apply(lf("a\tb")).unwrap();
+        let shown = ShowTabs.apply(numbered).unwrap();
+        assert_eq!(shown.prefix, "     1\t");
+        assert_eq!(shown.content, b"a^Ib");
+    }
+}
@@ -0,0 +1,126 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn concat_with_stdin(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_concat"))
+        .args(args)
+        .current_dir("tests/data")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn concat(args: &[&str]) -> Output {
+    concat_with_stdin(args, b"")
+}
+
+fn stdout(output: &Output) -> &str {
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn copies_files_unchanged() {
+    let output = concat(&["crlf.txt", "control.txt"]);
+    let mut expected = std::fs::read("tests/data/crlf.txt").unwrap();
+    expected.extend(std::fs::read("tests/data/control.txt").unwrap());
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(output.stdout, expected);
+}
+
+#[test]
+fn reads_stdin_for_a_dash_or_no_files() {
+    let output = concat_with_stdin(&[], b"from stdin\n");
+    assert_eq!(stdout(&output), "from stdin\n");
+    let output = concat_with_stdin(&["control.txt", "-"], b"\nfrom stdin");
+    assert!(stdout(&output).ends_with("no newline\nfrom stdin"));
+}
+
+#[test]
+fn numbers_every_line() {
+    assert_eq!(
+        stdout(&concat(&["-n", "blanks.txt"])),
+        "     1\tfirst line\n     2\t\tindented\n     3\t\n     4\t\n     5\t\n     6\tafter three blanks\n     7\t\n     8\t\n     9\tlast line\n"
+    );
+}
+
+#[test]
+fn numbers_non_blank_lines() {
+    assert_eq!(
+        stdout(&concat(&["-b", "blanks.txt"])),
+        "     1\tfirst line\n     2\t\tindented\n\n\n\n     3\tafter three blanks\n\n\n     4\tlast line\n"
+    );
+}
+
+#[test]
+fn squeezes_blank_lines() {
+    assert_eq!(
+        stdout(&concat(&["-s", "blanks.txt"])),
+        "first line\n\tindented\n\nafter three blanks\n\nlast line\n"
+    );
+}
+
+#[test]
+fn shows_tabs_and_ends_of_crlf_lines() {
+    assert_eq!(
+        stdout(&concat(&["-A", "crlf.txt"])),
+        "name^Iage^M$\nada^I36^M$\n^M$\n^M$\nalan^I41^M$\n"
+    );
+}
+
+#[test]
+fn shows_control_characters() {
+    assert_eq!(
+        stdout(&concat(&["-A", "control.txt"])),
+        "bell^G and escape^[$\ndel^?$\ncafM-CM-)$\nno newline"
+    );
+    assert_eq!(
+        stdout(&concat(&["-v", "control.txt"])),
+        "bell^G and escape^[\ndel^?\ncafM-CM-)\nno newline"
+    );
+}
+
+#[test]
+fn combines_flags_across_files() {
+    let expected = "     1\tfirst line$\n     2\t^Iindented$\n$\n     3\tafter three blanks$\n$\n     4\tlast line$\n     5\tname^Iage^M$\n     6\tada^I36^M$\n     7\t^M$\n     8\t^M$\n     9\talan^I41^M$\n";
+    assert_eq!(
+        stdout(&concat(&["-bsA", "blanks.txt", "crlf.txt"])),
+        expected
+    );
+    assert_eq!(
+        stdout(&concat(&["-b", "-s", "-A", "blanks.txt", "crlf.txt"])),
+        expected
+    );
+}
+
+#[test]
+fn numbering_carries_on_through_stdin() {
+    assert_eq!(
+        stdout(&concat_with_stdin(&["-ns", "blanks.txt", "-", "crlf.txt"], b"stdin\n")),
+        "     1\tfirst line\n     2\t\tindented\n     3\t\n     4\tafter three blanks\n     5\t\n     6\tlast line\n     7\tstdin\n     8\tname\tage\r\n     9\tada\t36\r\n    10\t\r\n    11\t\r\n    12\talan\t41\r\n"
+    );
+}
+
+#[test]
+fn carries_on_past_missing_files() {
+    let output = concat(&["-n", "no_such_file", "crlf.txt", "."]);
+    assert_eq!(output.status.code(), Some(1));
+    // The line count isn't interrupted by the files that couldn't be read.
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("     1\tname\tage\r\n"));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let errors: Vec<&str> = stderr.lines().collect();
+    assert_eq!(errors.len(), 2, "{stderr}");
+    assert!(errors[0].starts_with("concat: no_such_file: "));
+    assert!(errors[1].starts_with("concat: .: "));
+}
+
+#[test]
+fn rejects_unknown_flags() {
+    let output = concat(&["-nx", "blanks.txt"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown option: -x"));
+}
@@ -0,0 +1,188 @@
+use serde::{Deserialize, Serialize};
+
+use crate::error::BookError;
+use crate::phone::Phone;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Contact {
+    pub name: String,
+    pub emails: Vec<String>,
+    pub phones: Vec<Phone>,
+    pub tags: Vec<String>,
+}
+
+impl Contact {
+    pub fn new(name: &str) -> Contact {
+        Contact {
+            name: name.trim().to_string(),
+            emails: Vec::new(),
+            phones: Vec::new(),
+            tags: Vec::new(),
+        }
+    }
+}
+
+// Names are compared without case, so "ada lovelace" finds Ada Lovelace -
+// and can't be added as well.
+pub fn same_name(a: &str, b: &str) -> bool {
+    a.trim().to_lowercase() == b.trim().to_lowercase()
+}
+
+// A change to a contact, from `edit`. Additions that are already there are
+// left as they are; removals that aren't there are errors, since they're
+// most likely a typo.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Edit {
+    pub rename: Option<String>,
+    pub add_emails: Vec<String>,
+    pub remove_emails: Vec<String>,
+    pub add_phones: Vec<Phone>,
+    // Normalized numbers, whatever their kind.
+    pub remove_phones: Vec<String>,
+    pub add_tags: Vec<String>,
+    pub remove_tags: Vec<String>,
+}
+
+impl Edit {
+    pub fn is_empty(&self) -> bool {
+        *self == Edit::default()
+    }
+
+    // Makes the changes to `contact`. On an error, some of them may have
+    // been made, so it's for a copy.
+    pub fn apply(&self, contact: &mut Contact) -> Result<(), BookError> {
+        let missing = |contact: &Contact, what: String| BookError::NotOnContact {
+            name: contact.name.clone(),
+            what,
+        };
+        for email in &self.remove_emails {
+            let before = contact.emails.len();
+            contact
+                .emails
+                .retain(|other| !other.eq_ignore_ascii_case(email));
+            if contact.emails.len() == before {
+                return Err(missing(contact, format!("email {email}")));
+            }
+        }
+        for number in &self.remove_phones {
+            let before = contact.phones.len();
+            contact.phones.retain(|phone| &phone.number != number);
+            if contact.phones.len() == before {
+                return Err(missing(contact, format!("phone {number}")));
+            }
+        }
+        for tag in &self.remove_tags {
+            let before = contact.tags.len();
+            contact
+                .tags
+                .retain(|other| !other.eq_ignore_ascii_case(tag));
+            if contact.tags.len() == before {
+                return Err(missing(contact, format!("tag {tag}")));
+            }
+        }
+
+        add_missing(&mut contact.emails, &self.add_emails, |a, b| {
+            a.eq_ignore_ascii_case(b)
+        });
+        add_missing(&mut contact.phones, &self.add_phones, |a, b| {
+            a.number == b.number
+        });
+        add_missing(&mut contact.tags, &self.add_tags, |a, b| {
+            a.eq_ignore_ascii_case(b)
+        });
+        if let Some(name) = &self.rename {
+            if name.trim().is_empty() {
+                return Err(BookError::EmptyName);
+            }
+            contact.name = name.trim().to_string();
+        }
+        Ok(())
+    }
+}
+
+// Appends whichever of `new` aren't in `values` already.
+fn add_missing<T: Clone>(values: &mut Vec<T>, new: &[T], same: impl Fn(&T, &T) -> bool) {
+    for value in new {
+        if !values.iter().any(|other| same(other, value)) {
+            values.push(value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ada() -> Contact {
+        Contact {
+            name: "Ada Lovelace".to_string(),
+            emails: vec!["ada@example.com".to_string()],
+            phones: vec!["mobile:+44 7700 900123".parse().unwrap()],
+            tags: vec!["maths".to_string()],
+        }
+    }
+
+    #[test]
+    fn adds_what_isnt_there_already() {
+        let mut contact = ada();
+        let edit = Edit {
+            add_emails: vec![
+                "ADA@example.com".to_string(),
+                "countess@example.org".to_string(),
+            ],
+            add_phones: vec![
+                "home:020 7946 0018".parse().unwrap(),
+                "+447700900123".parse().unwrap(),
+            ],
+            add_tags: vec!["Maths".to_string(), "poetry".to_string()],
+            ..Edit::default()
+        };
+        edit.apply(&mut contact).unwrap();
+        assert_eq!(contact.emails, ["ada@example.com", "countess@example.org"]);
+        let numbers: Vec<&str> = contact
+            .phones
+            .iter()
+            .map(|phone| phone.number.as_str())
+            .collect();
+        assert_eq!(numbers, ["+447700900123", "02079460018"]);
+        assert_eq!(contact.tags, ["maths", "poetry"]);
+    }
+
+    #[test]
+    fn removes_and_renames() {
+        let mut contact = ada();
+        let edit = Edit {
+            rename: Some(" Augusta Ada King ".to_string()),
+            remove_emails: vec!["Ada@Example.com".to_string()],
+            remove_phones: vec!["+447700900123".to_string()],
+            remove_tags: vec!["MATHS".to_string()],
+            ..Edit::default()
+        };
+        edit.apply(&mut contact).unwrap();
+        assert_eq!(contact, Contact::new("Augusta Ada King"));
+    }
+
+    #[test]
+    fn removing_what_isnt_there_is_an_error() {
+        let edit = Edit {
+            remove_tags: vec!["poetry".to_string()],
+            ..Edit::default()
+        };
+        assert_eq!(
+            edit.apply(&mut ada()).unwrap_err().to_string(),
+            "Ada Lovelace has no tag poetry"
+        );
+        let edit = Edit {
+            rename: Some("  ".to_string()),
+            ..Edit::default()
+        };
+        assert!(matches!(edit.apply(&mut ada()), Err(BookError::EmptyName)));
+        assert!(Edit::default().is_empty());
+    }
+
+    #[test]
+    fn names_match_without_case() {
+        assert!(same_name("Ada Lovelace", " ada lovelace"));
+        assert!(!same_name("Ada", "Ada Lovelace"));
+    }
+}
@@ -0,0 +1,44 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum BookError {
+    Io { path: PathBuf, err: io::Error },
+    // The file is there but isn't an address book. It's left alone rather
+    // than overwritten, in case it's worth rescuing by hand.
+    Corrupt { path: PathBuf, reason: String },
+    // Written by a newer version of the program, which might have added
+    // things this one would lose by saving over it.
+    UnsupportedVersion { path: PathBuf, version: u64 },
+    NoSuchContact(String),
+    DuplicateName(String),
+    EmptyName,
+    // Asked to remove an email, phone or tag the contact doesn't have.
+    NotOnContact { name: String, what: String },
+}
+
+impl fmt::Display for BookError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BookError::Io { path, err } => write!(f, "{}: {err}", path.display()),
+            BookError::Corrupt { path, reason } => {
+                write!(f, "{} isn't a valid address book: {reason}", path.display())
+            }
+            BookError::UnsupportedVersion { path, version } => write!(
+                f,
+                "{} is version {version} of the address book format, newer than this program knows",
+                path.display()
+            ),
+            BookError::NoSuchContact(name) => write!(f, "there's no contact called {name:?}"),
+            BookError::DuplicateName(name) => {
+                write!(f, "there's already a contact called {name:?}")
+            }
+            BookError::EmptyName => write!(f, "a contact needs a name"),
+            BookError::NotOnContact { name, what } => write!(f, "{name} has no {what}"),
+        }
+    }
+}
+
+impl Error for BookError {}
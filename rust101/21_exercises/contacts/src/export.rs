@@ -0,0 +1,166 @@
+use crate::contact::Contact;
+use crate::phone::PhoneKind;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Vcard,
+}
+
+// One row per contact. The lists go in one field each, split by "; ",
+// which the csv crate quotes if it has to.
+pub fn to_csv(contacts: &[Contact]) -> String {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(["name", "emails", "phones", "tags"])
+        .expect("writing to a Vec can't fail");
+    for contact in contacts {
+        let phones: Vec<String> = contact
+            .phones
+            .iter()
+            .map(|phone| format!("{}:{}", phone.kind.name(), phone.number))
+            .collect();
+        writer
+            .write_record([
+                contact.name.as_str(),
+                &contact.emails.join("; "),
+                &phones.join("; "),
+                &contact.tags.join("; "),
+            ])
+            .expect("writing to a Vec can't fail");
+    }
+    let bytes = writer.into_inner().expect("writing to a Vec can't fail");
+    String::from_utf8(bytes).expect("the fields were all strings")
+}
+
+// vCard 3.0 text values escape backslashes, commas, semicolons and line
+// breaks - the characters that would otherwise split them up.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '\\' | ',' | ';' => {
+                escaped.push('\\');
+                escaped.push(ch);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn tel_type(kind: PhoneKind) -> &'static str {
+    match kind {
+        PhoneKind::Mobile => "CELL",
+        PhoneKind::Home => "HOME",
+        PhoneKind::Work => "WORK",
+        PhoneKind::Other => "VOICE",
+    }
+}
+
+// A vCard 3.0 per contact, with CRLF line ends as the format asks. N, the
+// structured name, is required; with only one name to go on, the last word
+// is taken as the family name and the rest as given names. Lines aren't
+// folded at 75 characters, which readers accept.
+pub fn to_vcard(contacts: &[Contact]) -> String {
+    let mut cards = String::new();
+    let mut line = |text: String| {
+        cards.push_str(&text);
+        cards.push_str("\r\n");
+    };
+    for contact in contacts {
+        line("BEGIN:VCARD".to_string());
+        line("VERSION:3.0".to_string());
+        line(format!("FN:{}", escape(&contact.name)));
+        let (given, family) = match contact.name.rsplit_once(' ') {
+            Some((given, family)) => (given, family),
+            None => ("", contact.name.as_str()),
+        };
+        line(format!("N:{};{};;;", escape(family), escape(given)));
+        for email in &contact.emails {
+            line(format!("EMAIL;TYPE=INTERNET:{}", escape(email)));
+        }
+        for phone in &contact.phones {
+            line(format!(
+                "TEL;TYPE={}:{}",
+                tel_type(phone.kind),
+                phone.number
+            ));
+        }
+        if !contact.tags.is_empty() {
+            let tags: Vec<String> = contact.tags.iter().map(|tag| escape(tag)).collect();
+            line(format!("CATEGORIES:{}", tags.join(",")));
+        }
+        line("END:VCARD".to_string());
+    }
+    cards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<Contact> {
+        vec![
+            Contact {
+                name: "Ada Lovelace".to_string(),
+                emails: vec![
+                    "ada@example.com".to_string(),
+                    "countess@example.org".to_string(),
+                ],
+                phones: vec![
+                    "mobile:+44 7700 900123".parse().unwrap(),
+                    "home:020 7946 0018".parse().unwrap(),
+                ],
+                tags: vec!["maths".to_string(), "poetry, sort of".to_string()],
+            },
+            Contact {
+                name: "Plato".to_string(),
+                emails: vec![],
+                phones: vec!["555 0100".parse().unwrap()],
+                tags: vec![],
+            },
+            Contact {
+                name: "Grace Brewster Hopper".to_string(),
+                emails: vec!["grace@example.com".to_string()],
+                phones: vec!["work:+1 (555) 010-0200".parse().unwrap()],
+                tags: vec!["navy;cobol".to_string()],
+            },
+        ]
+    }
+
+    #[test]
+    fn vcard_matches_the_fixture() {
+        assert_eq!(
+            to_vcard(&sample()),
+            include_str!("../tests/data/sample.vcf")
+        );
+    }
+
+    #[test]
+    fn vcard_lines_end_in_crlf() {
+        let cards = to_vcard(&sample());
+        assert!(cards.ends_with("END:VCARD\r\n"));
+        assert_eq!(cards.matches('\n').count(), cards.matches("\r\n").count());
+        assert_eq!(to_vcard(&[]), "");
+    }
+
+    #[test]
+    fn escapes_vcard_values() {
+        assert_eq!(escape(r"a,b;c\d"), r"a\,b\;c\\d");
+        assert_eq!(escape("two\nlines"), r"two\nlines");
+    }
+
+    #[test]
+    fn csv_has_a_row_per_contact() {
+        assert_eq!(
+            to_csv(&sample()),
+            "name,emails,phones,tags\n\
+             Ada Lovelace,ada@example.com; countess@example.org,mobile:+447700900123; home:02079460018,\"maths; poetry, sort of\"\n\
+             Plato,,other:5550100,\n\
+             Grace Brewster Hopper,grace@example.com,work:+15550100200,navy;cobol\n"
+        );
+        assert_eq!(to_csv(&[]), "name,emails,phones,tags\n");
+    }
+}
@@ -0,0 +1,434 @@
+/* Things learned during this exercise:
+   * A version number in the file is what lets the format change without
+   breaking old files. Reading it on its own first - a struct with just a
+   `version` field, since serde skips the rest - says which struct to read
+   the whole file into.
+   * Upgrading happens in one place, from the old structs to the new, with
+   the new fields given their defaults. Saving always writes the newest
+   version, so an old file is upgraded the first time it changes. A file
+   from a newer version is refused rather than saved over, since that
+   would lose whatever the newer version added.
+   * `#[serde(default)]` fills in a field that's missing, and
+   `#[serde(rename_all = "lowercase")]` writes an enum as "mobile" rather
+   than "Mobile".
+   * Normalizing phone numbers when they come in, not when they're compared,
+   means every comparison - for duplicates, for removal, for search - gets
+   it right without thinking about it.
+   * A derived `Ord` on an enum follows the order of its variants, so
+   `sort_by_key` on the field a search matched in ranks the results, and
+   being stable, keeps ties in name order.
+   * vCard wants CRLF line ends and backslashes before commas and
+   semicolons in values. `.gitattributes` with `-text` keeps git from
+   turning the CRLFs in the fixture into LFs.
+   * See https://serde.rs/attr-default.html and
+   https://www.rfc-editor.org/rfc/rfc2426
+*/
+mod contact;
+mod error;
+mod export;
+mod phone;
+mod search;
+mod store;
+
+pub use contact::{same_name, Contact, Edit};
+pub use error::BookError;
+pub use export::{to_csv, to_vcard, Format};
+pub use phone::{normalize_phone, Phone, PhoneError, PhoneKind};
+pub use search::{best_match, search, Field};
+pub use store::{AddressBook, VERSION};
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Add(Contact),
+    List,
+    Search(String),
+    Edit(String, Edit),
+    Remove(String),
+    Export(Format),
+}
+
+impl Command {
+    // Whether running it changes the book, and so needs saving.
+    pub fn changes_the_book(&self) -> bool {
+        matches!(
+            self,
+            Command::Add(_) | Command::Edit(..) | Command::Remove(_)
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    // From `--file`. None means the default file in the data directory.
+    pub file: Option<PathBuf>,
+    pub command: Command,
+}
+
+fn value(
+    args: &mut impl Iterator<Item = String>,
+    option: &str,
+    what: &str,
+) -> Result<String, String> {
+    args.next().ok_or(format!("{option} needs {what}"))
+}
+
+fn email(address: String) -> Result<String, String> {
+    // Just enough to catch a mix-up with a phone number or a tag.
+    match address.trim().split_once('@') {
+        Some((user, domain)) if !user.is_empty() && !domain.is_empty() => {
+            Ok(address.trim().to_string())
+        }
+        _ => Err(format!("not an email address: {address:?}")),
+    }
+}
+
+fn phone(number: String) -> Result<Phone, String> {
+    number.parse().map_err(|err| format!("{number:?}: {err}"))
+}
+
+fn tag(tag: String) -> Result<String, String> {
+    match tag.trim() {
+        "" => Err("a tag can't be empty".to_string()),
+        tag => Ok(tag.to_string()),
+    }
+}
+
+fn name(args: &mut impl Iterator<Item = String>) -> Result<String, String> {
+    let name = args.next().ok_or("which contact? Give their name")?;
+    if name.trim().is_empty() {
+        return Err("a contact needs a name".to_string());
+    }
+    Ok(name)
+}
+
+fn name_value(args: &mut impl Iterator<Item = String>) -> Result<String, String> {
+    let name = value(args, "--name", "a new name")?;
+    if name.trim().is_empty() {
+        return Err("a contact needs a name".to_string());
+    }
+    Ok(name)
+}
+
+// The options of `add`, which are also what `edit` adds. False if
+// `option` isn't one of them.
+fn add_option(
+    option: &str,
+    args: &mut impl Iterator<Item = String>,
+    edit: &mut Edit,
+) -> Result<bool, String> {
+    match option {
+        "--email" => edit
+            .add_emails
+            .push(email(value(args, option, "an address")?)?),
+        "--phone" => edit
+            .add_phones
+            .push(phone(value(args, option, "a number")?)?),
+        "--tag" => edit.add_tags.push(tag(value(args, option, "a tag")?)?),
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(args: impl Iterator<Item = String>) -> Result<Config, String> {
+        let mut args = args.skip(1).peekable();
+
+        let mut file = None;
+        while let Some(option) = args.next_if(|arg| arg.starts_with("--")) {
+            match option.as_str() {
+                "--file" => file = Some(PathBuf::from(args.next().ok_or("--file needs a path")?)),
+                _ => return Err(format!("unknown option: {option:?}")),
+            }
+        }
+
+        let command = match args.next().as_deref() {
+            Some("add") => {
+                let mut contact = Contact::new(&name(&mut args)?);
+                let mut additions = Edit::default();
+                while let Some(option) = args.next() {
+                    if !add_option(&option, &mut args, &mut additions)? {
+                        return Err(format!(
+                            "add takes --email, --phone or --tag, not {option:?}"
+                        ));
+                    }
+                }
+                additions
+                    .apply(&mut contact)
+                    .expect("only removing can fail");
+                Command::Add(contact)
+            }
+            Some("list") => Command::List,
+            Some("search") => {
+                let query = args.by_ref().collect::<Vec<_>>().join(" ");
+                if query.trim().is_empty() {
+                    return Err("search needs something to look for".to_string());
+                }
+                Command::Search(query)
+            }
+            Some("edit") => {
+                let name = name(&mut args)?;
+                let mut edit = Edit::default();
+                while let Some(option) = args.next() {
+                    if add_option(&option, &mut args, &mut edit)? {
+                        continue;
+                    }
+                    match option.as_str() {
+                        "--name" => edit.rename = Some(name_value(&mut args)?),
+                        "--remove-email" => {
+                            edit.remove_emails
+                                .push(value(&mut args, &option, "an address")?)
+                        }
+                        "--remove-phone" => {
+                            let number = value(&mut args, &option, "a number")?;
+                            edit.remove_phones.push(
+                                normalize_phone(&number)
+                                    .map_err(|err| format!("{number:?}: {err}"))?,
+                            )
+                        }
+                        "--remove-tag" => {
+                            edit.remove_tags.push(value(&mut args, &option, "a tag")?)
+                        }
+                        _ => return Err(format!("unknown edit: {option:?}")),
+                    }
+                }
+                if edit.is_empty() {
+                    return Err(format!("what should change about {name}?"));
+                }
+                Command::Edit(name, edit)
+            }
+            Some("remove") => Command::Remove(name(&mut args)?),
+            Some("export") => {
+                let format = match (args.next().as_deref(), args.next().as_deref()) {
+                    (Some("--format"), Some("csv")) => Format::Csv,
+                    (Some("--format"), Some("vcard")) => Format::Vcard,
+                    (Some("--format"), Some(other)) => {
+                        return Err(format!("--format is csv or vcard, not {other:?}"))
+                    }
+                    _ => return Err("export needs --format csv or --format vcard".to_string()),
+                };
+                Command::Export(format)
+            }
+            Some(other) => return Err(format!("unknown command: {other:?}")),
+            None => return Err("no command given".to_string()),
+        };
+        if let Some(extra) = args.next() {
+            return Err(format!("unexpected argument: {extra:?}"));
+        }
+        Ok(Config { file, command })
+    }
+}
+
+// Where the book lives without `--file`: `contacts/contacts.json` in the
+// data directory. `var` looks up an environment variable -
+// `env::var(..).ok()` outside the tests.
+pub fn default_path(var: impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    // The XDG spec says relative paths are to be ignored.
+    let data_dir = var("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        // Windows.
+        .or_else(|| var("APPDATA").map(PathBuf::from))?;
+    Some(data_dir.join("contacts").join("contacts.json"))
+}
+
+// A contact as `list` and `search` show it: the name, then what there is
+// of the rest, indented.
+pub fn describe(contact: &Contact) -> String {
+    let mut output = format!("{}\n", contact.name);
+    for email in &contact.emails {
+        writeln!(output, "  email: {email}").unwrap();
+    }
+    for phone in &contact.phones {
+        writeln!(output, "  phone: {phone}").unwrap();
+    }
+    if !contact.tags.is_empty() {
+        writeln!(output, "  tags: {}", contact.tags.join(", ")).unwrap();
+    }
+    output
+}
+
+// Runs a command against the book and returns what to print. Saving is
+// left to the caller.
+pub fn run(command: &Command, book: &mut AddressBook) -> Result<String, BookError> {
+    Ok(match command {
+        Command::Add(contact) => format!("Added {}\n", book.add(contact.clone())?.name),
+        Command::Edit(name, edit) => format!("Edited {}\n", book.edit(name, edit)?.name),
+        Command::Remove(name) => format!("Removed {}\n", book.remove(name)?.name),
+        Command::List if book.contacts().is_empty() => "The address book is empty.\n".to_string(),
+        Command::List => book.contacts().iter().map(describe).collect(),
+        Command::Search(query) => {
+            let found = search(book.contacts(), query);
+            if found.is_empty() {
+                format!("No contacts match {query:?}.\n")
+            } else {
+                found
+                    .into_iter()
+                    .map(|(contact, _)| describe(contact))
+                    .collect()
+            }
+        }
+        Command::Export(Format::Csv) => to_csv(book.contacts()),
+        Command::Export(Format::Vcard) => to_vcard(book.contacts()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["contacts".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    fn command(list: &[&str]) -> Result<Command, String> {
+        Config::build(args(list)).map(|config| config.command)
+    }
+
+    #[test]
+    fn builds_commands() {
+        assert_eq!(
+            command(&[
+                "add",
+                "Ada Lovelace",
+                "--email",
+                "ada@example.com",
+                "--phone",
+                "mobile:+44 7700 900123",
+                "--tag",
+                "maths",
+                "--tag",
+                "Maths",
+            ]),
+            Ok(Command::Add(Contact {
+                name: "Ada Lovelace".to_string(),
+                emails: vec!["ada@example.com".to_string()],
+                phones: vec![Phone {
+                    kind: PhoneKind::Mobile,
+                    number: "+447700900123".to_string()
+                }],
+                tags: vec!["maths".to_string()],
+            }))
+        );
+        assert_eq!(command(&["list"]), Ok(Command::List));
+        assert_eq!(
+            command(&["search", "ada", "love"]),
+            Ok(Command::Search("ada love".to_string()))
+        );
+        assert_eq!(
+            command(&[
+                "edit",
+                "ada lovelace",
+                "--name",
+                "Ada King",
+                "--remove-phone",
+                "+44 7700 900123",
+                "--tag",
+                "poetry",
+                "--remove-tag",
+                "maths",
+            ]),
+            Ok(Command::Edit(
+                "ada lovelace".to_string(),
+                Edit {
+                    rename: Some("Ada King".to_string()),
+                    remove_phones: vec!["+447700900123".to_string()],
+                    add_tags: vec!["poetry".to_string()],
+                    remove_tags: vec!["maths".to_string()],
+                    ..Edit::default()
+                }
+            ))
+        );
+        assert_eq!(
+            command(&["remove", "Ada"]),
+            Ok(Command::Remove("Ada".to_string()))
+        );
+        assert_eq!(
+            command(&["export", "--format", "vcard"]),
+            Ok(Command::Export(Format::Vcard))
+        );
+        assert_eq!(
+            Config::build(args(&["--file", "book.json", "export", "--format", "csv"])),
+            Ok(Config {
+                file: Some(PathBuf::from("book.json")),
+                command: Command::Export(Format::Csv),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        for bad in [
+            &[][..],
+            &["--file"],
+            &["call", "Ada"],
+            &["add"],
+            &["add", " "],
+            &["add", "Ada", "--email"],
+            &["add", "Ada", "--email", "ada.example.com"],
+            &["add", "Ada", "--phone", "555-CALL"],
+            &["add", "Ada", "--phone", "fax:555"],
+            &["add", "Ada", "--tag", ""],
+            &["add", "Ada", "--name", "Bea"],
+            &["search"],
+            &["edit", "Ada"],
+            &["edit", "Ada", "--name", ""],
+            &["edit", "Ada", "--nickname", "Addie"],
+            &["remove"],
+            &["remove", "Ada", "Bea"],
+            &["export"],
+            &["export", "--format", "json"],
+            &["list", "--all"],
+        ] {
+            assert!(Config::build(args(bad)).is_err(), "{bad:?}");
+        }
+        assert_eq!(
+            command(&["add", "Ada", "--phone", "555-CALL"]),
+            Err("\"555-CALL\": 'C' doesn't belong in a phone number".to_string())
+        );
+    }
+
+    #[test]
+    fn finds_the_data_directory() {
+        let home = |name: &str| (name == "HOME").then(|| "/home/me".to_string());
+        assert_eq!(
+            default_path(home),
+            Some(PathBuf::from(
+                "/home/me/.local/share/contacts/contacts.json"
+            ))
+        );
+        assert_eq!(default_path(|_| None), None);
+    }
+
+    #[test]
+    fn describes_a_contact() {
+        let contact = Contact {
+            name: "Ada Lovelace".to_string(),
+            emails: vec!["ada@example.com".to_string()],
+            phones: vec![
+                "mobile:+44 7700 900123".parse().unwrap(),
+                "555 0100".parse().unwrap(),
+            ],
+            tags: vec!["maths".to_string(), "poetry".to_string()],
+        };
+        assert_eq!(
+            describe(&contact),
+            "Ada Lovelace\n  email: ada@example.com\n  phone: +447700900123 (mobile)\n  phone: 5550100\n  tags: maths, poetry\n"
+        );
+        assert_eq!(describe(&Contact::new("Plato")), "Plato\n");
+    }
+
+    #[test]
+    fn only_changes_are_saved() {
+        assert!(Command::Remove("Ada".to_string()).changes_the_book());
+        assert!(!Command::Search("Ada".to_string()).changes_the_book());
+        assert!(!Command::Export(Format::Csv).changes_the_book());
+    }
+}
@@ -0,0 +1,36 @@
+use std::env;
+use std::process;
+
+use contacts::{AddressBook, Config};
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: contacts [--file PATH] add NAME [--email ADDRESS] [--phone [KIND:]NUMBER] [--tag TAG]... | list | search QUERY... | edit NAME [--name NEW] [--email|--phone|--tag VALUE] [--remove-email|--remove-phone|--remove-tag VALUE]... | remove NAME | export --format csv|vcard");
+        process::exit(2);
+    });
+
+    let path = config
+        .file
+        .clone()
+        .or_else(|| contacts::default_path(|name| env::var(name).ok()))
+        .unwrap_or_else(|| {
+            eprintln!("couldn't find a data directory for the address book; use --file PATH");
+            process::exit(1);
+        });
+
+    let result = AddressBook::load(&path).and_then(|mut book| {
+        let output = contacts::run(&config.command, &mut book)?;
+        if config.command.changes_the_book() {
+            book.save()?;
+        }
+        Ok(output)
+    });
+    match result {
+        Ok(output) => print!("{output}"),
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
+}
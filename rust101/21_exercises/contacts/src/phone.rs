@@ -0,0 +1,183 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PhoneKind {
+    Mobile,
+    Home,
+    Work,
+    // What a number is without a kind, and what every number from a
+    // version 1 file becomes.
+    #[default]
+    Other,
+}
+
+impl PhoneKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            PhoneKind::Mobile => "mobile",
+            PhoneKind::Home => "home",
+            PhoneKind::Work => "work",
+            PhoneKind::Other => "other",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Phone {
+    #[serde(default)]
+    pub kind: PhoneKind,
+    // Always normalized, so numbers written differently compare equal.
+    pub number: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhoneError {
+    NoDigits,
+    // Something that's neither a digit nor a separator, or a + that isn't
+    // at the start.
+    Unexpected(char),
+    UnknownKind(String),
+}
+
+impl fmt::Display for PhoneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PhoneError::NoDigits => write!(f, "a phone number needs some digits"),
+            PhoneError::Unexpected(ch) => write!(f, "{ch:?} doesn't belong in a phone number"),
+            PhoneError::UnknownKind(kind) => write!(
+                f,
+                "{kind:?} isn't a kind of phone; try mobile, home, work or other"
+            ),
+        }
+    }
+}
+
+impl Error for PhoneError {}
+
+// The ways people write a number that don't change it.
+const SEPARATORS: [char; 6] = [' ', '-', '.', '(', ')', '/'];
+
+// Strips the separators out of a phone number, so "+44 (20) 7946-0018" and
+// "+442079460018" are the same number. A leading + is kept, since it says
+// the number starts with a country code.
+pub fn normalize_phone(raw: &str) -> Result<String, PhoneError> {
+    let raw = raw.trim();
+    let (plus, rest) = match raw.strip_prefix('+') {
+        Some(rest) => ("+", rest),
+        None => ("", raw),
+    };
+    let mut number = plus.to_string();
+    for ch in rest.chars() {
+        match ch {
+            '0'..='9' => number.push(ch),
+            _ if SEPARATORS.contains(&ch) => {}
+            _ => return Err(PhoneError::Unexpected(ch)),
+        }
+    }
+    if number.len() == plus.len() {
+        return Err(PhoneError::NoDigits);
+    }
+    Ok(number)
+}
+
+// `work:+1 555 0100`, or just the number for a kind of other.
+impl FromStr for Phone {
+    type Err = PhoneError;
+
+    fn from_str(s: &str) -> Result<Phone, PhoneError> {
+        let (kind, number) = match s.split_once(':') {
+            Some((kind, number)) => {
+                let kind = match kind.trim().to_lowercase().as_str() {
+                    "mobile" => PhoneKind::Mobile,
+                    "home" => PhoneKind::Home,
+                    "work" => PhoneKind::Work,
+                    "other" => PhoneKind::Other,
+                    _ => return Err(PhoneError::UnknownKind(kind.to_string())),
+                };
+                (kind, number)
+            }
+            None => (PhoneKind::Other, s),
+        };
+        Ok(Phone {
+            kind,
+            number: normalize_phone(number)?,
+        })
+    }
+}
+
+impl fmt::Display for Phone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            PhoneKind::Other => write!(f, "{}", self.number),
+            kind => write!(f, "{} ({})", self.number, kind.name()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_separators() {
+        for (raw, normalized) in [
+            ("+44 (20) 7946-0018", "+442079460018"),
+            ("555.0100", "5550100"),
+            ("  020 7946 0018 ", "02079460018"),
+            ("(555) 010/0", "5550100"),
+            ("+1-555-0100", "+15550100"),
+            ("911", "911"),
+        ] {
+            assert_eq!(normalize_phone(raw), Ok(normalized.to_string()), "{raw}");
+        }
+    }
+
+    #[test]
+    fn rejects_what_isnt_a_number() {
+        assert_eq!(normalize_phone(""), Err(PhoneError::NoDigits));
+        assert_eq!(normalize_phone("+"), Err(PhoneError::NoDigits));
+        assert_eq!(normalize_phone("( - )"), Err(PhoneError::NoDigits));
+        assert_eq!(
+            normalize_phone("555-CALL"),
+            Err(PhoneError::Unexpected('C'))
+        );
+        assert_eq!(normalize_phone("1+555"), Err(PhoneError::Unexpected('+')));
+        assert_eq!(normalize_phone("++1"), Err(PhoneError::Unexpected('+')));
+    }
+
+    #[test]
+    fn parses_a_kind_and_a_number() {
+        assert_eq!(
+            "Work: +1 555 0100".parse(),
+            Ok(Phone {
+                kind: PhoneKind::Work,
+                number: "+15550100".to_string()
+            })
+        );
+        assert_eq!(
+            "555-0100".parse(),
+            Ok(Phone {
+                kind: PhoneKind::Other,
+                number: "5550100".to_string()
+            })
+        );
+        assert_eq!(
+            "fax:555".parse::<Phone>(),
+            Err(PhoneError::UnknownKind("fax".to_string()))
+        );
+    }
+
+    #[test]
+    fn displays_the_kind_unless_other() {
+        assert_eq!(
+            "home:0100".parse::<Phone>().unwrap().to_string(),
+            "0100 (home)"
+        );
+        assert_eq!("0100".parse::<Phone>().unwrap().to_string(), "0100");
+    }
+}
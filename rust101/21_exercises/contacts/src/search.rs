@@ -0,0 +1,132 @@
+use crate::contact::Contact;
+use crate::phone::normalize_phone;
+
+// Where a query matched, best first: a match on the name says more than
+// one on a tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Field {
+    Name,
+    Email,
+    Phone,
+    Tag,
+}
+
+// The best field of `contact` that contains `query`, ignoring case. A
+// query that's a phone number is normalized first, so "946-0018" finds
+// +442079460018.
+pub fn best_match(contact: &Contact, query: &str) -> Option<Field> {
+    let query = query.trim().to_lowercase();
+    let contains = |text: &str| text.to_lowercase().contains(&query);
+    let number = normalize_phone(&query).ok();
+    if contains(&contact.name) {
+        Some(Field::Name)
+    } else if contact.emails.iter().any(|email| contains(email)) {
+        Some(Field::Email)
+    } else if number.is_some_and(|number| {
+        contact
+            .phones
+            .iter()
+            .any(|phone| phone.number.contains(&number))
+    }) {
+        Some(Field::Phone)
+    } else if contact.tags.iter().any(|tag| contains(tag)) {
+        Some(Field::Tag)
+    } else {
+        None
+    }
+}
+
+// The contacts that match, ranked by where they matched. Those that
+// matched in the same field stay in the order they were given in.
+pub fn search<'a>(contacts: &'a [Contact], query: &str) -> Vec<(&'a Contact, Field)> {
+    let mut found: Vec<(&Contact, Field)> = contacts
+        .iter()
+        .filter_map(|contact| Some((contact, best_match(contact, query)?)))
+        .collect();
+    found.sort_by_key(|&(_, field)| field);
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contact(name: &str, emails: &[&str], phones: &[&str], tags: &[&str]) -> Contact {
+        Contact {
+            name: name.to_string(),
+            emails: emails.iter().map(|email| email.to_string()).collect(),
+            phones: phones.iter().map(|phone| phone.parse().unwrap()).collect(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        }
+    }
+
+    fn ranked(contacts: &[Contact], query: &str) -> Vec<(String, Field)> {
+        search(contacts, query)
+            .into_iter()
+            .map(|(contact, field)| (contact.name.clone(), field))
+            .collect()
+    }
+
+    #[test]
+    fn name_matches_come_before_tag_matches() {
+        // In name order, as the book keeps them, the tag match is first.
+        let contacts = [
+            contact("Bea Byron", &[], &[], &["ada-fans"]),
+            contact("Carl", &["carl@ada.org"], &[], &[]),
+            contact("Dot", &[], &[], &[]),
+            contact("Lady ADA", &[], &[], &[]),
+        ];
+        assert_eq!(
+            ranked(&contacts, "ada"),
+            [
+                ("Lady ADA".to_string(), Field::Name),
+                ("Carl".to_string(), Field::Email),
+                ("Bea Byron".to_string(), Field::Tag),
+            ]
+        );
+    }
+
+    #[test]
+    fn a_contact_is_ranked_by_its_best_match() {
+        let contacts = [
+            contact("Tag Only", &[], &[], &["maths"]),
+            contact("Maths Teacher", &["maths@school.example"], &[], &["maths"]),
+        ];
+        assert_eq!(
+            ranked(&contacts, "MATHS"),
+            [
+                ("Maths Teacher".to_string(), Field::Name),
+                ("Tag Only".to_string(), Field::Tag),
+            ]
+        );
+    }
+
+    #[test]
+    fn equal_matches_keep_their_order() {
+        let contacts = [
+            contact("Ann", &[], &[], &["work"]),
+            contact("Bob", &[], &[], &["work"]),
+            contact("Cat", &[], &[], &["homework"]),
+        ];
+        let names: Vec<String> = ranked(&contacts, "work")
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["Ann", "Bob", "Cat"]);
+    }
+
+    #[test]
+    fn phone_numbers_match_however_they_are_written() {
+        let ada = contact("Ada", &[], &["mobile:+44 20 7946 0018"], &[]);
+        assert_eq!(best_match(&ada, "946-0018"), Some(Field::Phone));
+        assert_eq!(best_match(&ada, "(020) 7946"), None);
+        assert_eq!(best_match(&ada, "+4420"), Some(Field::Phone));
+        assert_eq!(best_match(&ada, "3"), None);
+    }
+
+    #[test]
+    fn nothing_matches() {
+        let contacts = [contact("Ada", &["ada@example.com"], &[], &["maths"])];
+        assert!(search(&contacts, "turing").is_empty());
+    }
+}
@@ -0,0 +1,450 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::contact::{same_name, Contact, Edit};
+use crate::error::BookError;
+use crate::phone::{normalize_phone, Phone, PhoneKind};
+
+// The version `save` writes. Files from any version up to this one load.
+pub const VERSION: u64 = 2;
+
+// Just enough of any version to say which it is. Serde skips the fields it
+// wasn't asked for.
+#[derive(Deserialize)]
+struct Versioned {
+    version: u64,
+}
+
+// Version 2: `{"version": 2, "contacts": [{"name": ..., "emails": [...],
+// "phones": [{"kind": "mobile", "number": ...}], "tags": [...]}]}`.
+#[derive(Serialize, Deserialize)]
+struct BookFile {
+    version: u64,
+    contacts: Vec<Contact>,
+}
+
+// Version 1 had at most one email and one phone, with no kind, and no tags.
+#[derive(Deserialize)]
+struct V1File {
+    contacts: Vec<V1Contact>,
+}
+
+#[derive(Deserialize)]
+struct V1Contact {
+    name: String,
+    email: Option<String>,
+    phone: Option<String>,
+}
+
+// Version 1 took phone numbers as they were typed, so they're normalized
+// on the way in; one that won't normalize means the file was edited into
+// something that was never valid.
+fn upgrade(old: V1Contact) -> Result<Contact, String> {
+    let phones = match old.phone {
+        Some(raw) => vec![Phone {
+            kind: PhoneKind::Other,
+            number: normalize_phone(&raw).map_err(|err| format!("{}: {err}", old.name))?,
+        }],
+        None => Vec::new(),
+    };
+    Ok(Contact {
+        name: old.name,
+        emails: old.email.into_iter().collect(),
+        phones,
+        tags: Vec::new(),
+    })
+}
+
+// Reads a file of any version the program knows, upgrading it to the
+// current one.
+fn parse(text: &str, path: &Path) -> Result<Vec<Contact>, BookError> {
+    let corrupt = |reason: String| BookError::Corrupt {
+        path: path.to_path_buf(),
+        reason,
+    };
+    let Versioned { version } =
+        serde_json::from_str(text).map_err(|err| corrupt(err.to_string()))?;
+    let contacts = match version {
+        1 => {
+            let file: V1File =
+                serde_json::from_str(text).map_err(|err| corrupt(err.to_string()))?;
+            file.contacts
+                .into_iter()
+                .map(upgrade)
+                .collect::<Result<Vec<Contact>, String>>()
+                .map_err(corrupt)?
+        }
+        VERSION => {
+            let file: BookFile =
+                serde_json::from_str(text).map_err(|err| corrupt(err.to_string()))?;
+            file.contacts
+        }
+        0 => return Err(corrupt("there's no version 0".to_string())),
+        _ => {
+            return Err(BookError::UnsupportedVersion {
+                path: path.to_path_buf(),
+                version,
+            })
+        }
+    };
+    for (index, contact) in contacts.iter().enumerate() {
+        if contact.name.trim().is_empty() {
+            return Err(corrupt(format!("contact {} has no name", index + 1)));
+        }
+        if contacts[..index]
+            .iter()
+            .any(|other| same_name(&other.name, &contact.name))
+        {
+            return Err(corrupt(format!("{:?} is there twice", contact.name)));
+        }
+    }
+    Ok(contacts)
+}
+
+fn sort_key(contact: &Contact) -> String {
+    contact.name.to_lowercase()
+}
+
+// An address book backed by a JSON file. Changes are made in memory and
+// only written by `save`, which always writes the current version.
+#[derive(Debug)]
+pub struct AddressBook {
+    path: PathBuf,
+    // In order of name, ignoring case.
+    contacts: Vec<Contact>,
+}
+
+impl AddressBook {
+    // A file that doesn't exist yet is an empty book.
+    pub fn load(path: &Path) -> Result<AddressBook, BookError> {
+        let mut contacts = match fs::read_to_string(path) {
+            Ok(text) => parse(&text, path)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => {
+                return Err(BookError::Io {
+                    path: path.to_path_buf(),
+                    err,
+                })
+            }
+        };
+        // In case the file was sorted some other way, by hand or by an
+        // older version.
+        contacts.sort_by_key(sort_key);
+        Ok(AddressBook {
+            path: path.to_path_buf(),
+            contacts,
+        })
+    }
+
+    // Writes to a temporary file next to the real one, then renames it
+    // over the top, so a crash halfway can't leave half a book behind.
+    pub fn save(&self) -> Result<(), BookError> {
+        let io_error = |err| BookError::Io {
+            path: self.path.clone(),
+            err,
+        };
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let file = BookFile {
+            version: VERSION,
+            contacts: self.contacts.clone(),
+        };
+        let text = serde_json::to_string_pretty(&file).expect("contacts always serialize");
+        let temporary = self.path.with_extension("json.tmp");
+        fs::write(&temporary, text + "\n").map_err(io_error)?;
+        fs::rename(&temporary, &self.path).map_err(io_error)
+    }
+
+    pub fn contacts(&self) -> &[Contact] {
+        &self.contacts
+    }
+
+    fn position(&self, name: &str) -> Result<usize, BookError> {
+        self.contacts
+            .iter()
+            .position(|contact| same_name(&contact.name, name))
+            .ok_or_else(|| BookError::NoSuchContact(name.trim().to_string()))
+    }
+
+    pub fn get(&self, name: &str) -> Result<&Contact, BookError> {
+        Ok(&self.contacts[self.position(name)?])
+    }
+
+    // Puts `contact` in its place in the order, unless the name's taken,
+    // and returns where that is.
+    fn insert(&mut self, contact: Contact) -> Result<usize, BookError> {
+        if contact.name.is_empty() {
+            return Err(BookError::EmptyName);
+        }
+        if self.position(&contact.name).is_ok() {
+            return Err(BookError::DuplicateName(contact.name));
+        }
+        let key = sort_key(&contact);
+        let at = self.contacts.partition_point(|other| sort_key(other) < key);
+        self.contacts.insert(at, contact);
+        Ok(at)
+    }
+
+    pub fn add(&mut self, mut contact: Contact) -> Result<&Contact, BookError> {
+        contact.name = contact.name.trim().to_string();
+        let at = self.insert(contact)?;
+        Ok(&self.contacts[at])
+    }
+
+    // Either every change is made or, on an error, none of them.
+    pub fn edit(&mut self, name: &str, edit: &Edit) -> Result<&Contact, BookError> {
+        let at = self.position(name)?;
+        let mut contact = self.contacts[at].clone();
+        edit.apply(&mut contact)?;
+        // Out of the way, so it can go back in under a new name - or its
+        // old one, with its case changed.
+        let old = self.contacts.remove(at);
+        match self.insert(contact) {
+            Ok(at) => Ok(&self.contacts[at]),
+            Err(err) => {
+                self.contacts.insert(at, old);
+                Err(err)
+            }
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) -> Result<Contact, BookError> {
+        let at = self.position(name)?;
+        Ok(self.contacts.remove(at))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A fresh directory under the system temp dir, removed again when the
+    // test is over.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let dir = std::env::temp_dir().join(format!("contacts_{name}_{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+
+        fn file(&self) -> PathBuf {
+            self.0.join("contacts.json")
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn names(book: &AddressBook) -> Vec<&str> {
+        book.contacts()
+            .iter()
+            .map(|contact| contact.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn a_missing_file_is_an_empty_book() {
+        let dir = TempDir::new("missing");
+        assert!(AddressBook::load(&dir.file())
+            .unwrap()
+            .contacts()
+            .is_empty());
+    }
+
+    #[test]
+    fn round_trips_through_the_file() {
+        let dir = TempDir::new("round_trip");
+        let mut book = AddressBook::load(&dir.file()).unwrap();
+        let mut ada = Contact::new("Ada Lovelace");
+        ada.emails.push("ada@example.com".to_string());
+        ada.phones.push("mobile:+44 7700 900123".parse().unwrap());
+        ada.tags.push("maths".to_string());
+        book.add(ada.clone()).unwrap();
+        book.add(Contact::new("  alan turing ")).unwrap();
+        book.add(Contact::new("Charles Babbage")).unwrap();
+        book.save().unwrap();
+
+        let book = AddressBook::load(&dir.file()).unwrap();
+        assert_eq!(
+            names(&book),
+            ["Ada Lovelace", "alan turing", "Charles Babbage"]
+        );
+        assert_eq!(book.get("ADA LOVELACE").unwrap(), &ada);
+        let saved = fs::read_to_string(dir.file()).unwrap();
+        assert!(saved.starts_with("{\n  \"version\": 2,"), "{saved}");
+        assert!(!dir.file().with_extension("json.tmp").exists());
+    }
+
+    #[test]
+    fn names_are_unique_without_case() {
+        let dir = TempDir::new("unique");
+        let mut book = AddressBook::load(&dir.file()).unwrap();
+        book.add(Contact::new("Ada Lovelace")).unwrap();
+        assert!(matches!(
+            book.add(Contact::new("ada lovelace")),
+            Err(BookError::DuplicateName(_))
+        ));
+        assert!(matches!(
+            book.add(Contact::new(" ")),
+            Err(BookError::EmptyName)
+        ));
+    }
+
+    #[test]
+    fn renaming_keeps_the_order() {
+        let dir = TempDir::new("rename");
+        let mut book = AddressBook::load(&dir.file()).unwrap();
+        for name in ["Ada", "Bea", "Cy"] {
+            book.add(Contact::new(name)).unwrap();
+        }
+        let rename = |name: &str| Edit {
+            rename: Some(name.to_string()),
+            ..Edit::default()
+        };
+        assert_eq!(book.edit("ada", &rename("Dot")).unwrap().name, "Dot");
+        assert_eq!(names(&book), ["Bea", "Cy", "Dot"]);
+        // Only a change of case is still the same contact.
+        book.edit("bea", &rename("BEA")).unwrap();
+        assert_eq!(names(&book), ["BEA", "Cy", "Dot"]);
+
+        // A clash changes nothing.
+        assert!(matches!(
+            book.edit("Cy", &rename("dot")),
+            Err(BookError::DuplicateName(_))
+        ));
+        assert_eq!(names(&book), ["BEA", "Cy", "Dot"]);
+        assert!(matches!(
+            book.edit("Eve", &rename("Fay")),
+            Err(BookError::NoSuchContact(_))
+        ));
+    }
+
+    #[test]
+    fn a_failed_edit_changes_nothing() {
+        let dir = TempDir::new("failed_edit");
+        let mut book = AddressBook::load(&dir.file()).unwrap();
+        book.add(Contact::new("Ada")).unwrap();
+        let edit = Edit {
+            add_tags: vec!["maths".to_string()],
+            remove_emails: vec!["ada@example.com".to_string()],
+            ..Edit::default()
+        };
+        assert!(matches!(
+            book.edit("Ada", &edit),
+            Err(BookError::NotOnContact { .. })
+        ));
+        assert_eq!(book.get("Ada").unwrap(), &Contact::new("Ada"));
+    }
+
+    #[test]
+    fn removes() {
+        let dir = TempDir::new("remove");
+        let mut book = AddressBook::load(&dir.file()).unwrap();
+        book.add(Contact::new("Ada")).unwrap();
+        assert_eq!(book.remove("ADA").unwrap().name, "Ada");
+        assert!(matches!(
+            book.remove("Ada"),
+            Err(BookError::NoSuchContact(_))
+        ));
+    }
+
+    #[test]
+    fn loads_version_1_into_version_2() {
+        let book = AddressBook::load(Path::new("tests/data/v1.json")).unwrap();
+        assert_eq!(
+            book.contacts(),
+            [
+                Contact {
+                    name: "Ada Lovelace".to_string(),
+                    emails: vec!["ada@example.com".to_string()],
+                    phones: vec![Phone {
+                        kind: PhoneKind::Other,
+                        number: "+442079460018".to_string(),
+                    }],
+                    tags: vec![],
+                },
+                Contact {
+                    name: "Alan Turing".to_string(),
+                    emails: vec![],
+                    phones: vec![Phone {
+                        kind: PhoneKind::Other,
+                        number: "01619460123".to_string(),
+                    }],
+                    tags: vec![],
+                },
+                Contact {
+                    name: "Grace Hopper".to_string(),
+                    emails: vec!["grace@example.com".to_string()],
+                    phones: vec![],
+                    tags: vec![],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn saving_a_version_1_file_upgrades_it() {
+        let dir = TempDir::new("upgrade");
+        fs::copy("tests/data/v1.json", dir.file()).unwrap();
+        let book = AddressBook::load(&dir.file()).unwrap();
+        book.save().unwrap();
+        let saved: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(dir.file()).unwrap()).unwrap();
+        assert_eq!(saved["version"], 2);
+        assert_eq!(
+            saved["contacts"][1],
+            serde_json::json!({
+                "name": "Alan Turing",
+                "emails": [],
+                "phones": [{"kind": "other", "number": "01619460123"}],
+                "tags": [],
+            })
+        );
+        assert_eq!(
+            AddressBook::load(&dir.file()).unwrap().contacts(),
+            book.contacts()
+        );
+    }
+
+    #[test]
+    fn newer_versions_are_refused() {
+        let dir = TempDir::new("newer");
+        let contents = r#"{"version": 3, "contacts": [], "groups": []}"#;
+        fs::write(dir.file(), contents).unwrap();
+        let err = AddressBook::load(&dir.file()).unwrap_err();
+        assert!(matches!(
+            err,
+            BookError::UnsupportedVersion { version: 3, .. }
+        ));
+        assert_eq!(fs::read_to_string(dir.file()).unwrap(), contents);
+    }
+
+    #[test]
+    fn corrupt_files_are_errors() {
+        let dir = TempDir::new("corrupt");
+        for contents in [
+            "not json",
+            r#"{"contacts": []}"#,
+            r#"{"version": 0, "contacts": []}"#,
+            r#"{"version": 1, "contacts": [{"name": "Ada", "phone": "call me"}]}"#,
+            r#"{"version": 2, "contacts": [{"name": "Ada"}]}"#,
+            r#"{"version": 2, "contacts": [{"name": "", "emails": [], "phones": [], "tags": []}]}"#,
+            r#"{"version": 1, "contacts": [{"name": "Ada"}, {"name": "ADA"}]}"#,
+        ] {
+            fs::write(dir.file(), contents).unwrap();
+            let err = AddressBook::load(&dir.file()).unwrap_err();
+            assert!(matches!(err, BookError::Corrupt { .. }), "{contents}");
+            assert!(err.to_string().contains("isn't a valid address book"));
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+// A directory of its own for each test, removed when it's over.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> TempDir {
+        let dir = std::env::temp_dir().join(format!("contacts_cli_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn contacts(file: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_contacts"))
+        .arg("--file")
+        .arg(file)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+// Runs a command that should work, and returns what it printed.
+fn ok(file: &Path, args: &[&str]) -> String {
+    let output = contacts(file, args);
+    assert_eq!(output.status.code(), Some(0), "{args:?}: {output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn a_session() {
+    let dir = TempDir::new("session");
+    let file = dir.0.join("contacts.json");
+
+    assert_eq!(ok(&file, &["list"]), "The address book is empty.\n");
+    assert_eq!(
+        ok(
+            &file,
+            &[
+                "add",
+                "Grace Hopper",
+                "--email",
+                "grace@example.com",
+                "--tag",
+                "navy"
+            ]
+        ),
+        "Added Grace Hopper\n"
+    );
+    assert_eq!(
+        ok(
+            &file,
+            &[
+                "add",
+                "Ada Lovelace",
+                "--phone",
+                "mobile:+44 7700 900-123",
+                "--tag",
+                "maths"
+            ]
+        ),
+        "Added Ada Lovelace\n"
+    );
+    assert_eq!(
+        ok(&file, &["add", "Mary Somerville", "--tag", "Ada's tutor"]),
+        "Added Mary Somerville\n"
+    );
+    assert_eq!(
+        ok(&file, &["list"]),
+        "Ada Lovelace\n  phone: +447700900123 (mobile)\n  tags: maths\n\
+         Grace Hopper\n  email: grace@example.com\n  tags: navy\n\
+         Mary Somerville\n  tags: Ada's tutor\n"
+    );
+    assert_eq!(
+        ok(&file, &["search", "ADA"]),
+        "Ada Lovelace\n  phone: +447700900123 (mobile)\n  tags: maths\n\
+         Mary Somerville\n  tags: Ada's tutor\n"
+    );
+    assert_eq!(
+        ok(&file, &["search", "7700", "900"]),
+        "Ada Lovelace\n  phone: +447700900123 (mobile)\n  tags: maths\n"
+    );
+    assert_eq!(
+        ok(
+            &file,
+            &[
+                "edit",
+                "ada lovelace",
+                "--name",
+                "Ada King",
+                "--email",
+                "ada@example.com",
+                "--remove-tag",
+                "maths"
+            ]
+        ),
+        "Edited Ada King\n"
+    );
+    assert_eq!(
+        ok(&file, &["remove", "mary somerville"]),
+        "Removed Mary Somerville\n"
+    );
+    assert_eq!(
+        ok(&file, &["search", "tutor"]),
+        "No contacts match \"tutor\".\n"
+    );
+
+    let saved: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+    assert_eq!(
+        saved,
+        serde_json::json!({
+            "version": 2,
+            "contacts": [
+                {
+                    "name": "Ada King",
+                    "emails": ["ada@example.com"],
+                    "phones": [{"kind": "mobile", "number": "+447700900123"}],
+                    "tags": [],
+                },
+                {
+                    "name": "Grace Hopper",
+                    "emails": ["grace@example.com"],
+                    "phones": [],
+                    "tags": ["navy"],
+                },
+            ]
+        })
+    );
+}
+
+#[test]
+fn exports_csv_and_vcard() {
+    let dir = TempDir::new("export");
+    let file = dir.0.join("contacts.json");
+    ok(
+        &file,
+        &[
+            "add",
+            "Ada Lovelace",
+            "--email",
+            "ada@example.com",
+            "--phone",
+            "home:020 7946 0018",
+            "--tag",
+            "maths",
+        ],
+    );
+    ok(&file, &["add", "Plato", "--phone", "555 0100"]);
+    assert_eq!(
+        ok(&file, &["export", "--format", "csv"]),
+        "name,emails,phones,tags\n\
+         Ada Lovelace,ada@example.com,home:02079460018,maths\n\
+         Plato,,other:5550100,\n"
+    );
+    assert_eq!(
+        ok(&file, &["export", "--format", "vcard"]),
+        "BEGIN:VCARD\r\nVERSION:3.0\r\nFN:Ada Lovelace\r\nN:Lovelace;Ada;;;\r\n\
+         EMAIL;TYPE=INTERNET:ada@example.com\r\nTEL;TYPE=HOME:02079460018\r\n\
+         CATEGORIES:maths\r\nEND:VCARD\r\n\
+         BEGIN:VCARD\r\nVERSION:3.0\r\nFN:Plato\r\nN:Plato;;;;\r\n\
+         TEL;TYPE=VOICE:5550100\r\nEND:VCARD\r\n"
+    );
+}
+
+#[test]
+fn reads_a_version_1_file_and_upgrades_it_on_change() {
+    let dir = TempDir::new("upgrade");
+    let file = dir.0.join("contacts.json");
+    fs::copy("tests/data/v1.json", &file).unwrap();
+
+    // Looking doesn't rewrite it.
+    assert_eq!(
+        ok(&file, &["search", "turing"]),
+        "Alan Turing\n  phone: 01619460123\n"
+    );
+    assert_eq!(
+        fs::read_to_string(&file).unwrap(),
+        fs::read_to_string("tests/data/v1.json").unwrap()
+    );
+
+    ok(&file, &["edit", "Alan Turing", "--tag", "codebreaker"]);
+    let saved: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+    assert_eq!(saved["version"], 2);
+    assert_eq!(saved["contacts"].as_array().unwrap().len(), 3);
+    assert_eq!(
+        saved["contacts"][1]["tags"],
+        serde_json::json!(["codebreaker"])
+    );
+}
+
+#[test]
+fn errors_change_nothing() {
+    let dir = TempDir::new("errors");
+    let file = dir.0.join("contacts.json");
+    ok(&file, &["add", "Ada"]);
+    let before = fs::read_to_string(&file).unwrap();
+
+    for (args, message) in [
+        (
+            &["add", "ADA"][..],
+            "error: there's already a contact called \"ADA\"\n",
+        ),
+        (
+            &["remove", "Bea"],
+            "error: there's no contact called \"Bea\"\n",
+        ),
+        (
+            &["edit", "Ada", "--remove-tag", "maths"],
+            "error: Ada has no tag maths\n",
+        ),
+    ] {
+        let output = contacts(&file, args);
+        assert_eq!(output.status.code(), Some(1), "{args:?}");
+        assert_eq!(String::from_utf8_lossy(&output.stderr), message);
+    }
+    assert_eq!(fs::read_to_string(&file).unwrap(), before);
+}
+
+#[test]
+fn a_newer_file_is_not_overwritten() {
+    let dir = TempDir::new("newer");
+    let file = dir.0.join("contacts.json");
+    fs::write(&file, r#"{"version": 3, "contacts": []}"#).unwrap();
+    let output = contacts(&file, &["add", "Ada"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("is version 3 of the address book format"),
+        "{stderr}"
+    );
+    assert_eq!(
+        fs::read_to_string(&file).unwrap(),
+        r#"{"version": 3, "contacts": []}"#
+    );
+}
+
+#[test]
+fn usage_errors() {
+    let dir = TempDir::new("usage");
+    let file = dir.0.join("contacts.json");
+    for args in [
+        &[][..],
+        &["find", "Ada"],
+        &["add", "Ada", "--phone", "call me"],
+        &["export", "--format", "xml"],
+    ] {
+        assert_eq!(contacts(&file, args).status.code(), Some(2), "{args:?}");
+    }
+    assert!(!file.exists());
+}
@@ -0,0 +1,337 @@
+/* Things learned during this exercise:
+   * The csv crate does the fiddly parts of CSV - quoting, embedded commas
+   and newlines - and with serde it reads each row straight into a struct.
+   `#[derive(Deserialize)]` matches fields to the header by name, so column
+   order doesn't matter.
+   * An `Option` field is None for an empty cell, which is just what a
+   missing value is. A cell that's there but isn't a number is still an
+   error, as is a row with the wrong number of fields.
+   * `deserialize()` yields a `Result` per row rather than failing the whole
+   read, so a bad row can be skipped. Its error has a `position()` with the
+   line the row started on - not the same as the record number once a
+   quoted field spans lines.
+   * Statistics can be kept as running totals - count, sum, min, max - so
+   nothing has to be held in memory twice. f64 isn't `Ord`, so min and max
+   come from `f64::min`/`f64::max` rather than `Iterator::min`.
+   * A `BTreeMap` keeps the groups sorted by key, so the output doesn't
+   depend on the order of the input or on hashing.
+   * See https://docs.rs/csv/latest/csv/tutorial/index.html
+*/
+mod record;
+mod stats;
+mod table;
+
+pub use record::{GroupBy, Sale, NUMERIC_COLUMNS};
+pub use stats::{summarize, summarize_groups, ColumnStats, Summary};
+
+use std::error::Error;
+use std::fmt;
+use std::io::Read;
+
+// A row that couldn't be read, and why.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BadRow {
+    pub line: u64,
+    pub message: String,
+}
+
+impl fmt::Display for BadRow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+#[derive(Debug)]
+pub enum ReadError {
+    // Only in strict mode: the first row that couldn't be read.
+    BadRow(BadRow),
+    // The input itself failed - it couldn't be read at all, or the header
+    // was unusable. There's no skipping past that.
+    Csv(csv::Error),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadError::BadRow(row) => write!(f, "{row}"),
+            ReadError::Csv(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+// Turns a row's error into a BadRow, if it's about one row. The csv
+// crate's own messages repeat the position, so only the detail is kept.
+fn bad_row(err: &csv::Error) -> Option<BadRow> {
+    let (pos, message) = match err.kind() {
+        csv::ErrorKind::Deserialize {
+            pos: Some(pos),
+            err,
+        } => (pos, err.to_string()),
+        csv::ErrorKind::UnequalLengths {
+            pos: Some(pos),
+            expected_len,
+            len,
+        } => (pos, format!("expected {expected_len} fields, found {len}")),
+        csv::ErrorKind::Utf8 {
+            pos: Some(pos),
+            err,
+        } => (pos, err.to_string()),
+        _ => return None,
+    };
+    Some(BadRow {
+        line: pos.line(),
+        message,
+    })
+}
+
+// What was read: the rows that made sense, and the ones that were skipped.
+#[derive(Debug, Default, PartialEq)]
+pub struct Sales {
+    pub sales: Vec<Sale>,
+    pub skipped: Vec<BadRow>,
+}
+
+// Reads every row of a CSV with a header. A bad row is skipped, or with
+// `strict` ends the read.
+pub fn read_sales(input: impl Read, strict: bool) -> Result<Sales, ReadError> {
+    let mut reader = csv::Reader::from_reader(input);
+    let mut read = Sales::default();
+    for result in reader.deserialize() {
+        match result {
+            Ok(sale) => read.sales.push(sale),
+            Err(err) => match bad_row(&err) {
+                Some(row) if !strict => read.skipped.push(row),
+                Some(row) => return Err(ReadError::BadRow(row)),
+                None => return Err(ReadError::Csv(err)),
+            },
+        }
+    }
+    Ok(read)
+}
+
+const STAT_HEADERS: [&str; 6] = ["column", "count", "missing", "mean", "min", "max"];
+
+fn stat_cells(column: &str, stats: &ColumnStats) -> Vec<String> {
+    // No values means no mean, min or max, rather than a made-up zero.
+    let number = |value: Option<f64>| value.map_or("-".to_string(), |value| format!("{value:.2}"));
+    vec![
+        column.to_string(),
+        stats.count.to_string(),
+        stats.missing.to_string(),
+        number(stats.mean()),
+        number(stats.min),
+        number(stats.max),
+    ]
+}
+
+pub fn summary_table(summary: &Summary) -> String {
+    let rows: Vec<Vec<String>> = summary
+        .iter()
+        .map(|(column, stats)| stat_cells(column, stats))
+        .collect();
+    table::render(&STAT_HEADERS, &rows, 1)
+}
+
+// One row per group and column, with the group's name in front.
+pub fn grouped_table(sales: &[Sale], group_by: GroupBy) -> String {
+    let mut rows = Vec::new();
+    for (key, summary) in summarize_groups(sales, group_by) {
+        for (column, stats) in &summary {
+            let mut row = vec![key.to_string()];
+            row.extend(stat_cells(column, stats));
+            rows.push(row);
+        }
+    }
+    let mut headers = vec![group_by.name()];
+    headers.extend(STAT_HEADERS);
+    table::render(&headers, &rows, 2)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub group_by: Option<GroupBy>,
+    pub strict: bool,
+    // None, or "-" on the command line, reads stdin.
+    pub path: Option<String>,
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut config = Config {
+            group_by: None,
+            strict: false,
+            path: None,
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--group-by" => {
+                    let column = args.next().ok_or("--group-by needs a column")?;
+                    config.group_by = Some(GroupBy::parse(&column)?);
+                }
+                "--strict" => config.strict = true,
+                _ if arg.starts_with("--") => return Err(format!("unknown option: {arg:?}")),
+                _ if config.path.is_some() => return Err(format!("more than one file: {arg:?}")),
+                _ => config.path = Some(arg),
+            }
+        }
+        if config.path.as_deref() == Some("-") {
+            config.path = None;
+        }
+        Ok(config)
+    }
+}
+
+// The table to print, and the rows that were skipped on the way.
+pub fn run(config: &Config, input: impl Read) -> Result<(String, Vec<BadRow>), ReadError> {
+    let Sales { sales, skipped } = read_sales(input, config.strict)?;
+    let table = match config.group_by {
+        Some(group_by) => grouped_table(&sales, group_by),
+        None => summary_table(&summarize(&sales)),
+    };
+    Ok((table, skipped))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSV: &str = "\
+date,region,product,units,price
+2024-01-01,north,widget,4,2.50
+2024-01-01,south,widget,,3.00
+2024-01-02,north,gadget,2,
+";
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["csv_stats".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    #[test]
+    fn reads_rows_into_sales() {
+        let read = read_sales(CSV.as_bytes(), true).unwrap();
+        assert!(read.skipped.is_empty());
+        assert_eq!(
+            read.sales[1],
+            Sale {
+                date: "2024-01-01".to_string(),
+                region: "south".to_string(),
+                product: "widget".to_string(),
+                units: None,
+                price: Some(3.0),
+            }
+        );
+        assert_eq!(read.sales[2].price, None);
+    }
+
+    #[test]
+    fn columns_are_matched_by_name() {
+        let csv = "units,price,product,region,date\n3,1.5,widget,north,2024-01-01\n";
+        let read = read_sales(csv.as_bytes(), true).unwrap();
+        assert_eq!(
+            (read.sales[0].units, read.sales[0].region.as_str()),
+            (Some(3), "north")
+        );
+    }
+
+    #[test]
+    fn bad_rows_are_skipped_with_their_line() {
+        // A quoted newline makes the bad row start on line 4, not line 3.
+        let csv = "\
+date,region,product,units,price
+2024-01-01,\"north
+east\",widget,4,2.50
+2024-01-01,south,widget,four,3.00
+2024-01-02,north,gadget
+2024-01-02,north,gadget,1,1.00
+";
+        let read = read_sales(csv.as_bytes(), false).unwrap();
+        assert_eq!(read.sales.len(), 2);
+        assert_eq!(read.sales[0].region, "north\neast");
+        assert_eq!(
+            read.skipped,
+            [
+                BadRow {
+                    line: 4,
+                    message: "field 3: invalid digit found in string".to_string(),
+                },
+                BadRow {
+                    line: 5,
+                    message: "expected 5 fields, found 3".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn strict_stops_at_the_first_bad_row() {
+        let csv = "date,region,product,units,price\n2024-01-01,north,widget,1,x\n";
+        match read_sales(csv.as_bytes(), true) {
+            Err(ReadError::BadRow(row)) => {
+                assert_eq!(row.to_string(), "line 2: field 4: invalid float literal")
+            }
+            other => panic!("expected a bad row, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn summary_table_has_a_row_per_column() {
+        let read = read_sales(CSV.as_bytes(), true).unwrap();
+        assert_eq!(
+            summary_table(&summarize(&read.sales)),
+            "column  count  missing  mean   min   max\n\
+             units       2        1  3.00  2.00  4.00\n\
+             price       2        1  2.75  2.50  3.00\n"
+        );
+    }
+
+    #[test]
+    fn empty_groups_have_no_mean() {
+        let read = read_sales(CSV.as_bytes(), true).unwrap();
+        let table = grouped_table(&read.sales, GroupBy::Product);
+        assert_eq!(
+            table,
+            "product  column  count  missing  mean   min   max\n\
+             gadget   units       1        0  2.00  2.00  2.00\n\
+             gadget   price       0        1     -     -     -\n\
+             widget   units       1        1  4.00  4.00  4.00\n\
+             widget   price       2        0  2.75  2.50  3.00\n"
+        );
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&[])),
+            Ok(Config {
+                group_by: None,
+                strict: false,
+                path: None,
+            })
+        );
+        assert_eq!(
+            Config::build(args(&["--strict", "sales.csv", "--group-by", "region"])),
+            Ok(Config {
+                group_by: Some(GroupBy::Region),
+                strict: true,
+                path: Some("sales.csv".to_string()),
+            })
+        );
+        assert_eq!(Config::build(args(&["-"])).unwrap().path, None);
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        assert!(Config::build(args(&["--group-by"])).is_err());
+        assert!(Config::build(args(&["--group-by", "units"])).is_err());
+        assert!(Config::build(args(&["--lenient"])).is_err());
+        assert!(Config::build(args(&["a.csv", "b.csv"])).is_err());
+    }
+}
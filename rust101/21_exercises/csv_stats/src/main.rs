@@ -0,0 +1,38 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, Read};
+use std::process;
+
+use csv_stats::Config;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: csv_stats [--group-by COLUMN] [--strict] [FILE|-]");
+        process::exit(2);
+    });
+
+    let input: Box<dyn Read> = match &config.path {
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(file),
+            Err(err) => {
+                eprintln!("couldn't open {path}: {err}");
+                process::exit(1);
+            }
+        },
+        None => Box::new(io::stdin()),
+    };
+
+    match csv_stats::run(&config, input) {
+        Ok((table, skipped)) => {
+            for row in &skipped {
+                eprintln!("skipping {row}");
+            }
+            print!("{table}");
+        }
+        Err(err) => {
+            eprintln!("couldn't read the CSV: {err}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use serde::Deserialize;
+
+// One row of a sales CSV. The fields are matched to the header by name, so
+// the columns can come in any order. An empty numeric cell is a missing
+// value - None - but anything else that isn't a number is an error.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Sale {
+    pub date: String,
+    pub region: String,
+    pub product: String,
+    pub units: Option<u32>,
+    pub price: Option<f64>,
+}
+
+// The numeric columns, each with how to get its value out of a Sale. A
+// table of function pointers rather than a match, so the statistics code
+// just loops over it and a new column is one line here.
+pub type Accessor = fn(&Sale) -> Option<f64>;
+
+pub const NUMERIC_COLUMNS: [(&str, Accessor); 2] = [
+    ("units", |sale| sale.units.map(f64::from)),
+    ("price", |sale| sale.price),
+];
+
+// The columns `--group-by` can use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    Date,
+    Region,
+    Product,
+}
+
+impl GroupBy {
+    pub fn parse(column: &str) -> Result<GroupBy, String> {
+        match column {
+            "date" => Ok(GroupBy::Date),
+            "region" => Ok(GroupBy::Region),
+            "product" => Ok(GroupBy::Product),
+            _ => Err(format!(
+                "can't group by {column:?}: try date, region or product"
+            )),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            GroupBy::Date => "date",
+            GroupBy::Region => "region",
+            GroupBy::Product => "product",
+        }
+    }
+
+    pub fn key(self, sale: &Sale) -> &str {
+        match self {
+            GroupBy::Date => &sale.date,
+            GroupBy::Region => &sale.region,
+            GroupBy::Product => &sale.product,
+        }
+    }
+}
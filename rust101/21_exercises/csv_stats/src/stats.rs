@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+
+use crate::record::{GroupBy, Sale, NUMERIC_COLUMNS};
+
+// Running statistics for one column. Values are added one at a time, so a
+// huge file never has to be held in memory twice.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ColumnStats {
+    pub count: usize,
+    pub missing: usize,
+    pub sum: f64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl ColumnStats {
+    pub fn add(&mut self, value: Option<f64>) {
+        let Some(value) = value else {
+            self.missing += 1;
+            return;
+        };
+        self.count += 1;
+        self.sum += value;
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+    }
+
+    // None when there are no values to average.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum / self.count as f64)
+    }
+}
+
+// The statistics of every numeric column, in NUMERIC_COLUMNS order.
+pub type Summary = Vec<(&'static str, ColumnStats)>;
+
+pub fn summarize<'a>(sales: impl IntoIterator<Item = &'a Sale>) -> Summary {
+    let mut summary: Summary = NUMERIC_COLUMNS
+        .iter()
+        .map(|&(name, _)| (name, ColumnStats::default()))
+        .collect();
+    for sale in sales {
+        for ((_, stats), (_, value)) in summary.iter_mut().zip(NUMERIC_COLUMNS) {
+            stats.add(value(sale));
+        }
+    }
+    summary
+}
+
+// One Summary per distinct value of the grouping column. A BTreeMap keeps
+// the groups sorted, so the table comes out in the same order every time.
+pub fn summarize_groups(sales: &[Sale], group_by: GroupBy) -> BTreeMap<&str, Summary> {
+    let mut groups: BTreeMap<&str, Vec<&Sale>> = BTreeMap::new();
+    for sale in sales {
+        groups.entry(group_by.key(sale)).or_default().push(sale);
+    }
+    groups
+        .into_iter()
+        .map(|(key, sales)| (key, summarize(sales)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sale(region: &str, units: Option<u32>, price: Option<f64>) -> Sale {
+        Sale {
+            date: "2024-01-01".to_string(),
+            region: region.to_string(),
+            product: "widget".to_string(),
+            units,
+            price,
+        }
+    }
+
+    fn stats<'a>(summary: &'a Summary, column: &str) -> &'a ColumnStats {
+        &summary.iter().find(|(name, _)| *name == column).unwrap().1
+    }
+
+    #[test]
+    fn column_stats() {
+        let mut stats = ColumnStats::default();
+        for value in [Some(3.0), None, Some(-1.0), Some(4.0), None] {
+            stats.add(value);
+        }
+        assert_eq!(
+            stats,
+            ColumnStats {
+                count: 3,
+                missing: 2,
+                sum: 6.0,
+                min: Some(-1.0),
+                max: Some(4.0),
+            }
+        );
+        assert_eq!(stats.mean(), Some(2.0));
+    }
+
+    #[test]
+    fn no_values_means_no_mean() {
+        let mut stats = ColumnStats::default();
+        assert_eq!(stats.mean(), None);
+        stats.add(None);
+        assert_eq!((stats.mean(), stats.min, stats.missing), (None, None, 1));
+    }
+
+    #[test]
+    fn summarizes_every_numeric_column() {
+        let sales = [
+            sale("north", Some(4), Some(2.5)),
+            sale("south", None, Some(3.5)),
+            sale("north", Some(2), None),
+        ];
+        let summary = summarize(&sales);
+        assert_eq!(
+            summary.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+            ["units", "price"]
+        );
+        let units = stats(&summary, "units");
+        assert_eq!(
+            (units.count, units.missing, units.mean()),
+            (2, 1, Some(3.0))
+        );
+        let price = stats(&summary, "price");
+        assert_eq!(
+            (price.min, price.max, price.missing),
+            (Some(2.5), Some(3.5), 1)
+        );
+    }
+
+    #[test]
+    fn summarizing_nothing() {
+        let summary = summarize(&[]);
+        assert!(summary
+            .iter()
+            .all(|(_, stats)| *stats == ColumnStats::default()));
+    }
+
+    #[test]
+    fn groups_are_sorted_and_separate() {
+        let sales = [
+            sale("south", Some(10), None),
+            sale("north", Some(4), None),
+            sale("north", Some(6), None),
+        ];
+        let groups = summarize_groups(&sales, GroupBy::Region);
+        assert_eq!(
+            groups.keys().copied().collect::<Vec<_>>(),
+            ["north", "south"]
+        );
+        assert_eq!(stats(&groups["north"], "units").mean(), Some(5.0));
+        assert_eq!(stats(&groups["south"], "units").sum, 10.0);
+        assert_eq!(stats(&groups["south"], "price").missing, 1);
+    }
+
+    #[test]
+    fn grouping_by_other_columns() {
+        let mut gadget = sale("north", Some(1), None);
+        gadget.product = "gadget".to_string();
+        let sales = [sale("north", Some(2), None), gadget];
+        assert_eq!(summarize_groups(&sales, GroupBy::Product).len(), 2);
+        assert_eq!(summarize_groups(&sales, GroupBy::Date).len(), 1);
+    }
+}
@@ -0,0 +1,70 @@
+// Lays rows out in columns, each as wide as its widest cell. The first
+// `text_columns` columns are names, so they're left-aligned; the rest are
+// numbers, right-aligned so their decimal points line up.
+pub fn render(headers: &[&str], rows: &[Vec<String>], text_columns: usize) -> String {
+    let mut widths: Vec<usize> = headers
+        .iter()
+        .map(|header| header.chars().count())
+        .collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let header_row: Vec<String> = headers.iter().map(|header| header.to_string()).collect();
+    let mut table = String::new();
+    for row in std::iter::once(&header_row).chain(rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, &width))| {
+                if i < text_columns {
+                    format!("{cell:<width$}")
+                } else {
+                    format!("{cell:>width$}")
+                }
+            })
+            .collect();
+        // The last column is left-aligned when it's text, which would leave
+        // trailing spaces.
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|cell| cell.to_string()).collect()
+    }
+
+    #[test]
+    fn aligns_text_left_and_numbers_right() {
+        let rows = [
+            row(&["north", "3", "1.50"]),
+            row(&["south-east", "12", "10.25"]),
+        ];
+        assert_eq!(
+            render(&["region", "count", "mean"], &rows, 1),
+            "region      count   mean\n\
+             north           3   1.50\n\
+             south-east     12  10.25\n"
+        );
+    }
+
+    #[test]
+    fn widths_count_chars_not_bytes() {
+        let rows = [row(&["Zürich", "1"])];
+        assert_eq!(render(&["city", "n"], &rows, 1), "city    n\nZürich  1\n");
+    }
+
+    #[test]
+    fn no_rows_is_just_the_header() {
+        assert_eq!(render(&["a", "b"], &[], 2), "a  b\n");
+    }
+}
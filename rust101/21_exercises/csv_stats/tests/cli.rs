@@ -0,0 +1,111 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const SALES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/sales.csv");
+const MALFORMED: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/malformed.csv");
+
+fn csv_stats(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_csv_stats"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> &str {
+    std::str::from_utf8(&output.stderr).unwrap()
+}
+
+#[test]
+fn summarizes_every_numeric_column() {
+    let output = csv_stats(&[SALES]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stdout(&output),
+        "column  count  missing  mean   min    max\n\
+         units       6        1  4.50  1.00  10.00\n\
+         price       6        1  5.46  2.00  12.00\n"
+    );
+    assert_eq!(stderr(&output), "");
+}
+
+#[test]
+fn groups_by_a_column() {
+    let output = csv_stats(&["--group-by", "region", SALES]);
+    assert_eq!(output.status.code(), Some(0));
+    let lines: Vec<&str> = stdout(&output).lines().collect();
+    assert_eq!(lines.len(), 7);
+    assert_eq!(
+        lines[0],
+        "region  column  count  missing  mean   min    max"
+    );
+    assert_eq!(
+        lines[3],
+        "north   units       3        0  4.00  1.00   7.00"
+    );
+    assert_eq!(
+        lines[6],
+        "south   price       1        1  2.25  2.25   2.25"
+    );
+}
+
+#[test]
+fn reads_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_csv_stats"))
+        .args(["--group-by", "product", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(&std::fs::read(SALES).unwrap())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert!(stdout(&output).starts_with("product  column"));
+    assert!(stdout(&output).contains("\nwidget   units       3        1   7.00   4.00  10.00\n"));
+}
+
+#[test]
+fn lenient_mode_skips_bad_rows() {
+    let output = csv_stats(&[MALFORMED]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stderr(&output),
+        "skipping line 3: field 3: invalid digit found in string\n\
+         skipping line 4: expected 5 fields, found 4\n\
+         skipping line 5: field 3: invalid digit found in string\n"
+    );
+    assert_eq!(
+        stdout(&output),
+        "column  count  missing  mean   min   max\n\
+         units       2        0  5.50  5.00  6.00\n\
+         price       2        0  6.12  2.50  9.75\n"
+    );
+}
+
+#[test]
+fn strict_mode_fails_on_the_first_bad_row() {
+    let output = csv_stats(&["--strict", MALFORMED]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(
+        stderr(&output),
+        "couldn't read the CSV: line 3: field 3: invalid digit found in string\n"
+    );
+    assert_eq!(csv_stats(&["--strict", SALES]).status.code(), Some(0));
+}
+
+#[test]
+fn usage_and_file_errors() {
+    let output = csv_stats(&["--group-by", "price", SALES]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("Usage: csv_stats"));
+    assert_eq!(csv_stats(&["no/such/file.csv"]).status.code(), Some(1));
+}
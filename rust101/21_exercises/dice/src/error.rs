@@ -0,0 +1,74 @@
+use std::error::Error;
+use std::fmt;
+
+// Columns count characters from 1, as an editor would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiceError {
+    Empty,
+    // `what` describes what should have been there; `found` is None at the
+    // end of the input.
+    Expected {
+        what: &'static str,
+        found: Option<char>,
+        column: usize,
+    },
+    ZeroDice {
+        column: usize,
+    },
+    ZeroSides {
+        column: usize,
+    },
+    ZeroKeep {
+        column: usize,
+    },
+    KeepTooMany {
+        keep: u32,
+        count: u32,
+        column: usize,
+    },
+    TooBig {
+        what: &'static str,
+        max: u32,
+        column: usize,
+    },
+}
+
+impl fmt::Display for DiceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DiceError::Empty => write!(f, "empty dice expression"),
+            DiceError::Expected {
+                what,
+                found: Some(ch),
+                column,
+            } => write!(f, "expected {what} at column {column}, found {ch:?}"),
+            DiceError::Expected {
+                what,
+                found: None,
+                column,
+            } => write!(f, "expected {what} at column {column}, found the end"),
+            DiceError::ZeroDice { column } => {
+                write!(f, "can't roll zero dice, at column {column}")
+            }
+            DiceError::ZeroSides { column } => {
+                write!(f, "a die needs at least one side, at column {column}")
+            }
+            DiceError::ZeroKeep { column } => {
+                write!(
+                    f,
+                    "keeping zero dice leaves nothing to add up, at column {column}"
+                )
+            }
+            DiceError::KeepTooMany {
+                keep,
+                count,
+                column,
+            } => write!(f, "can't keep {keep} of {count} dice, at column {column}"),
+            DiceError::TooBig { what, max, column } => {
+                write!(f, "{what} is too big: at most {max}, at column {column}")
+            }
+        }
+    }
+}
+
+impl Error for DiceError {}
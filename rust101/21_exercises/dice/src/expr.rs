@@ -0,0 +1,368 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::DiceError;
+
+// Caps that keep a roll quick and its total far from overflowing an i64:
+// the biggest total is 1,000 × 1,000,000 + 1,000,000.
+pub const MAX_DICE: u32 = 1_000;
+pub const MAX_SIDES: u32 = 1_000_000;
+pub const MAX_MODIFIER: u32 = 1_000_000;
+
+// A parsed expression like `4d6kh3+1`: roll `count` dice with `sides`
+// sides, add up the `keep_highest` biggest (all of them if None), then add
+// `modifier`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiceExpr {
+    pub count: u32,
+    pub sides: u32,
+    pub keep_highest: Option<u32>,
+    pub modifier: i64,
+}
+
+impl DiceExpr {
+    // How many dice are added up.
+    pub fn kept(&self) -> u32 {
+        self.keep_highest.unwrap_or(self.count)
+    }
+
+    pub fn min(&self) -> i64 {
+        i64::from(self.kept()) + self.modifier
+    }
+
+    pub fn max(&self) -> i64 {
+        i64::from(self.kept()) * i64::from(self.sides) + self.modifier
+    }
+}
+
+// Written the way it parses back: `d20` for one die, `3d6kh2-1` and so on.
+impl fmt::Display for DiceExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.count != 1 {
+            write!(f, "{}", self.count)?;
+        }
+        write!(f, "d{}", self.sides)?;
+        if let Some(keep) = self.keep_highest {
+            write!(f, "kh{keep}")?;
+        }
+        match self.modifier {
+            0 => Ok(()),
+            modifier if modifier > 0 => write!(f, "+{modifier}"),
+            modifier => write!(f, "{modifier}"),
+        }
+    }
+}
+
+// A hand-written parser for
+//
+//     expr     = [count] ("d" | "D") sides ["kh" keep] [("+" | "-") modifier]
+//
+// where each of count, sides, keep and modifier is a run of digits. There's
+// so little to it that a cursor over the characters and a function per
+// piece is clearer than a tokenizer.
+struct Parser {
+    chars: Vec<char>,
+    // Index into `chars`; the column is one more.
+    at: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.at).copied()
+    }
+
+    fn column(&self) -> usize {
+        self.at + 1
+    }
+
+    fn expected(&self, what: &'static str) -> DiceError {
+        DiceError::Expected {
+            what,
+            found: self.peek(),
+            column: self.column(),
+        }
+    }
+
+    fn eat(&mut self, options: &[char]) -> bool {
+        let found = self.peek().is_some_and(|ch| options.contains(&ch));
+        if found {
+            self.at += 1;
+        }
+        found
+    }
+
+    // A run of digits, no bigger than `max`. Returns None, consuming
+    // nothing, if there aren't any digits.
+    fn number(&mut self, what: &'static str, max: u32) -> Result<Option<u32>, DiceError> {
+        let column = self.column();
+        let mut value: Option<u32> = None;
+        while let Some(digit) = self.peek().and_then(|ch| ch.to_digit(10)) {
+            self.at += 1;
+            // Past `max` is too big, whether or not it would fit in a u32.
+            value = Some(
+                value
+                    .unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add(digit))
+                    .filter(|&value| value <= max)
+                    .ok_or(DiceError::TooBig { what, max, column })?,
+            );
+        }
+        Ok(value)
+    }
+
+    fn required_number(&mut self, what: &'static str, max: u32) -> Result<u32, DiceError> {
+        self.number(what, max)?.ok_or_else(|| self.expected(what))
+    }
+
+    fn expr(&mut self) -> Result<DiceExpr, DiceError> {
+        let count_column = self.column();
+        // No count means one die: `d20`.
+        let count = self.number("the number of dice", MAX_DICE)?.unwrap_or(1);
+        if count == 0 {
+            return Err(DiceError::ZeroDice {
+                column: count_column,
+            });
+        }
+        if !self.eat(&['d', 'D']) {
+            return Err(self.expected("'d'"));
+        }
+
+        let sides_column = self.column();
+        let sides = self.required_number("the number of sides", MAX_SIDES)?;
+        if sides == 0 {
+            return Err(DiceError::ZeroSides {
+                column: sides_column,
+            });
+        }
+
+        let mut keep_highest = None;
+        if self.eat(&['k']) {
+            if !self.eat(&['h']) {
+                return Err(self.expected("'h', as in kh"));
+            }
+            let column = self.column();
+            let keep = self.required_number("the number of dice to keep", MAX_DICE)?;
+            if keep == 0 {
+                return Err(DiceError::ZeroKeep { column });
+            }
+            if keep > count {
+                return Err(DiceError::KeepTooMany {
+                    keep,
+                    count,
+                    column,
+                });
+            }
+            keep_highest = Some(keep);
+        }
+
+        let mut modifier = 0;
+        if let Some(sign @ ('+' | '-')) = self.peek() {
+            self.at += 1;
+            let size = self.required_number("the number to add", MAX_MODIFIER)?;
+            modifier = if sign == '+' {
+                i64::from(size)
+            } else {
+                -i64::from(size)
+            };
+        }
+
+        if self.peek().is_some() {
+            let what = if keep_highest.is_some() || modifier != 0 {
+                "the end"
+            } else {
+                "'kh', '+', '-' or the end"
+            };
+            return Err(self.expected(what));
+        }
+        Ok(DiceExpr {
+            count,
+            sides,
+            keep_highest,
+            modifier,
+        })
+    }
+}
+
+impl FromStr for DiceExpr {
+    type Err = DiceError;
+
+    // Spaces around the expression are fine, but not inside it.
+    fn from_str(s: &str) -> Result<DiceExpr, DiceError> {
+        if s.trim().is_empty() {
+            return Err(DiceError::Empty);
+        }
+        // Starting past the leading spaces, rather than trimming them off,
+        // keeps the columns right.
+        let leading = s.chars().take_while(|ch| ch.is_whitespace()).count();
+        let chars: Vec<char> = s.trim_end().chars().collect();
+        Parser { chars, at: leading }.expr()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<DiceExpr, DiceError> {
+        s.parse()
+    }
+
+    fn dice(count: u32, sides: u32, keep_highest: Option<u32>, modifier: i64) -> DiceExpr {
+        DiceExpr {
+            count,
+            sides,
+            keep_highest,
+            modifier,
+        }
+    }
+
+    #[test]
+    fn every_form() {
+        assert_eq!(parse("3d6"), Ok(dice(3, 6, None, 0)));
+        assert_eq!(parse("d20"), Ok(dice(1, 20, None, 0)));
+        assert_eq!(parse("3d6+2"), Ok(dice(3, 6, None, 2)));
+        assert_eq!(parse("2d10-1"), Ok(dice(2, 10, None, -1)));
+        assert_eq!(parse("4d6kh3"), Ok(dice(4, 6, Some(3), 0)));
+        assert_eq!(parse("4D6kh3+1"), Ok(dice(4, 6, Some(3), 1)));
+        assert_eq!(parse("  1d1 "), Ok(dice(1, 1, None, 0)));
+        assert_eq!(parse("d6+0"), Ok(dice(1, 6, None, 0)));
+    }
+
+    #[test]
+    fn limits() {
+        assert_eq!(
+            parse("1000d1000000+1000000"),
+            Ok(dice(MAX_DICE, MAX_SIDES, None, 1_000_000))
+        );
+        assert_eq!(
+            parse("1001d6"),
+            Err(DiceError::TooBig {
+                what: "the number of dice",
+                max: 1000,
+                column: 1
+            })
+        );
+        // Too big for a u32 at all, and still reported the same way.
+        assert_eq!(
+            parse("d99999999999"),
+            Err(DiceError::TooBig {
+                what: "the number of sides",
+                max: MAX_SIDES,
+                column: 2
+            })
+        );
+    }
+
+    #[test]
+    fn zeros() {
+        assert_eq!(parse("d0"), Err(DiceError::ZeroSides { column: 2 }));
+        assert_eq!(parse("0d6"), Err(DiceError::ZeroDice { column: 1 }));
+        assert_eq!(parse("4d6kh0"), Err(DiceError::ZeroKeep { column: 6 }));
+    }
+
+    #[test]
+    fn incomplete_expressions() {
+        assert_eq!(parse(""), Err(DiceError::Empty));
+        assert_eq!(parse("   "), Err(DiceError::Empty));
+        assert_eq!(
+            parse("3d"),
+            Err(DiceError::Expected {
+                what: "the number of sides",
+                found: None,
+                column: 3
+            })
+        );
+        assert_eq!(
+            parse("3d6+"),
+            Err(DiceError::Expected {
+                what: "the number to add",
+                found: None,
+                column: 5
+            })
+        );
+        assert_eq!(
+            parse("4d6k3"),
+            Err(DiceError::Expected {
+                what: "'h', as in kh",
+                found: Some('3'),
+                column: 5
+            })
+        );
+        assert_eq!(
+            parse("4d6kh"),
+            Err(DiceError::Expected {
+                what: "the number of dice to keep",
+                found: None,
+                column: 6
+            })
+        );
+    }
+
+    #[test]
+    fn unexpected_characters() {
+        let errors: Vec<String> = ["6", "x6", "3d6x", "3d6+2d4", "3 d6", "3d6kh2kh1", "3d-6"]
+            .iter()
+            .map(|s| parse(s).unwrap_err().to_string())
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                "expected 'd' at column 2, found the end",
+                "expected 'd' at column 1, found 'x'",
+                "expected 'kh', '+', '-' or the end at column 4, found 'x'",
+                "expected the end at column 6, found 'd'",
+                "expected 'd' at column 2, found ' '",
+                "expected the end at column 7, found 'k'",
+                "expected the number of sides at column 3, found '-'",
+            ]
+        );
+    }
+
+    #[test]
+    fn keep_must_not_exceed_the_count() {
+        assert_eq!(parse("4d6kh4"), Ok(dice(4, 6, Some(4), 0)));
+        assert_eq!(
+            parse("2d6kh3").unwrap_err().to_string(),
+            "can't keep 3 of 2 dice, at column 6"
+        );
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        assert_eq!(
+            parse("é"),
+            Err(DiceError::Expected {
+                what: "'d'",
+                found: Some('é'),
+                column: 1
+            })
+        );
+        assert_eq!(
+            parse("  3dx"),
+            Err(DiceError::Expected {
+                what: "the number of sides",
+                found: Some('x'),
+                column: 5
+            })
+        );
+    }
+
+    #[test]
+    fn displays_as_it_parses() {
+        for s in ["d20", "3d6", "3d6+2", "2d10-1", "4d6kh3", "4d6kh3-2"] {
+            assert_eq!(parse(s).unwrap().to_string(), s);
+        }
+        assert_eq!(parse("1D6+0").unwrap().to_string(), "d6");
+    }
+
+    #[test]
+    fn min_and_max() {
+        let expr = parse("4d6kh3+1").unwrap();
+        assert_eq!((expr.min(), expr.max()), (4, 19));
+        assert_eq!(
+            (parse("2d10-1").unwrap().min(), parse("d1").unwrap().max()),
+            (1, 1)
+        );
+    }
+}
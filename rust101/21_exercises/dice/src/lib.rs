@@ -0,0 +1,203 @@
+/* Things learned during this exercise:
+   * A grammar as small as `[N]dS[khK][±M]` doesn't need a tokenizer: a
+   cursor over the characters, with a method per piece, reads it in one
+   pass and knows the column of anything unexpected.
+   * Checked arithmetic while reading digits (`checked_mul`, `checked_add`)
+   catches a number too big for its type, where `parse` would only say
+   "invalid". Putting a cap on counts and sides as well keeps every total
+   far from overflowing.
+   * Passing `&mut impl Rng` into the code that rolls, instead of calling
+   `thread_rng()` inside it, is what makes it testable: a `StdRng` from
+   `seed_from_u64` gives the same rolls every time.
+   * `sort_by_key` is stable, so sorting positions by `Reverse(die)` keeps
+   equal dice in their original order - which decides which of two equal
+   dice "keep highest" drops.
+   * A `BTreeMap` from total to count is a histogram already sorted for
+   printing, and `range(target..)` counts everything at or above a target.
+   * Statistical tests need tolerances of several standard errors: tight
+   enough to catch a wrong formula, loose enough not to fail by chance.
+   * See https://docs.rs/rand/0.8.5/rand/trait.Rng.html and
+   https://en.wikipedia.org/wiki/Dice_notation
+*/
+mod error;
+mod expr;
+mod roll;
+mod stats;
+
+pub use error::DiceError;
+pub use expr::{DiceExpr, MAX_DICE, MAX_MODIFIER, MAX_SIDES};
+pub use roll::{roll, Roll};
+pub use stats::{simulate, Distribution};
+
+use std::fmt::Write;
+
+use rand::Rng;
+
+// The longest bar in a histogram.
+const HISTOGRAM_WIDTH: usize = 40;
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub expr: DiceExpr,
+    pub seed: Option<u64>,
+    // Roll this many times and describe the results, instead of one roll.
+    pub simulate: Option<u64>,
+    // Also give the chance of a total at least this big. Only with
+    // `simulate`.
+    pub target: Option<i64>,
+}
+
+fn option_value<T: std::str::FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    option: &str,
+    what: &str,
+) -> Result<T, String> {
+    let value = args.next().ok_or(format!("{option} needs {what}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("{option} needs {what}, not {value:?}"))
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut expr = None;
+        let mut seed = None;
+        let mut simulate = None;
+        let mut target = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--seed" => seed = Some(option_value(&mut args, "--seed", "a number")?),
+                "--simulate" => {
+                    simulate = Some(option_value(&mut args, "--simulate", "a number of rolls")?)
+                }
+                "--target" => target = Some(option_value(&mut args, "--target", "a total")?),
+                _ if arg.starts_with("--") => return Err(format!("unknown option: {arg:?}")),
+                _ if expr.is_some() => return Err(format!("only one expression, not {arg:?} too")),
+                _ => {
+                    expr = Some(
+                        arg.parse::<DiceExpr>()
+                            .map_err(|err| format!("{arg:?}: {err}"))?,
+                    )
+                }
+            }
+        }
+        let expr = expr.ok_or("which dice? For example 3d6+2")?;
+        if target.is_some() && simulate.is_none() {
+            return Err("--target needs --simulate".to_string());
+        }
+        if simulate == Some(0) {
+            return Err("--simulate needs at least one roll".to_string());
+        }
+        Ok(Config {
+            expr,
+            seed,
+            simulate,
+            target,
+        })
+    }
+}
+
+// What to print: one roll, or a simulation's histogram, mean and chance of
+// hitting the target.
+pub fn run(config: &Config, rng: &mut impl Rng) -> String {
+    let Some(rolls) = config.simulate else {
+        return format!("{}\n", roll(&config.expr, rng));
+    };
+
+    let distribution = simulate(&config.expr, rolls, rng);
+    let mut output = format!("{} rolled {rolls} times:\n", config.expr);
+    output += &distribution.histogram(HISTOGRAM_WIDTH);
+    // There's at least one roll, so there's a mean.
+    writeln!(output, "mean: {:.2}", distribution.mean().unwrap()).unwrap();
+    if let Some(target) = config.target {
+        let chance = 100.0 * distribution.probability_at_least(target);
+        writeln!(output, "chance of {target} or more: {chance:.2}%").unwrap();
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["dice".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&["3d6+2"])),
+            Ok(Config {
+                expr: "3d6+2".parse().unwrap(),
+                seed: None,
+                simulate: None,
+                target: None,
+            })
+        );
+        assert_eq!(
+            Config::build(args(&[
+                "--simulate",
+                "1000",
+                "d20",
+                "--target",
+                "-1",
+                "--seed",
+                "7"
+            ])),
+            Ok(Config {
+                expr: "d20".parse().unwrap(),
+                seed: Some(7),
+                simulate: Some(1000),
+                target: Some(-1),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        for bad in [
+            &[][..],
+            &["3d"],
+            &["d6", "d8"],
+            &["--seed", "x", "d6"],
+            &["--simulate", "d6"],
+            &["--simulate", "0", "d6"],
+            &["--target", "10", "3d6"],
+            &["--verbose", "d6"],
+        ] {
+            assert!(Config::build(args(bad)).is_err(), "{bad:?}");
+        }
+        assert_eq!(
+            Config::build(args(&["0d6"])),
+            Err("\"0d6\": can't roll zero dice, at column 1".to_string())
+        );
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_output() {
+        let config =
+            Config::build(args(&["--simulate", "500", "--target", "12", "2d8kh1+3"])).unwrap();
+        let first = run(&config, &mut StdRng::seed_from_u64(184));
+        assert_eq!(first, run(&config, &mut StdRng::seed_from_u64(184)));
+        assert!(first.starts_with("2d8kh1+3 rolled 500 times:\n"));
+        assert!(first.contains("\nmean: "));
+        assert!(first.ends_with("%\n"));
+    }
+
+    #[test]
+    fn a_single_roll_is_one_line() {
+        let config = Config::build(args(&["4d6kh3"])).unwrap();
+        let output = run(&config, &mut StdRng::seed_from_u64(1));
+        assert_eq!(output.lines().count(), 1);
+        assert!(output.starts_with("4d6kh3: ["));
+    }
+}
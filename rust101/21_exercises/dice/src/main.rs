@@ -0,0 +1,20 @@
+use std::env;
+use std::process;
+
+use dice::Config;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: dice [--seed N] [--simulate ROLLS [--target TOTAL]] EXPRESSION");
+        process::exit(2);
+    });
+    // Without a seed, different rolls every time.
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    print!("{}", dice::run(&config, &mut rng));
+}
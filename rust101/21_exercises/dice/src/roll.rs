@@ -0,0 +1,143 @@
+use std::cmp::Reverse;
+use std::fmt;
+
+use rand::Rng;
+
+use crate::expr::DiceExpr;
+
+// One roll of an expression: every die, whether it counted, and the total.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Roll {
+    pub expr: DiceExpr,
+    pub dice: Vec<u32>,
+    // Lines up with `dice`. All true unless the expression keeps only the
+    // highest few.
+    pub kept: Vec<bool>,
+    pub total: i64,
+}
+
+// Takes the random number generator as an argument rather than making its
+// own, so a seeded one gives the same rolls every time.
+pub fn roll(expr: &DiceExpr, rng: &mut impl Rng) -> Roll {
+    let dice: Vec<u32> = (0..expr.count)
+        .map(|_| rng.gen_range(1..=expr.sides))
+        .collect();
+
+    // Sorting positions by their die, biggest first, and keeping the first
+    // few. The sort is stable, so of two equal dice the earlier one is kept.
+    let mut by_size: Vec<usize> = (0..dice.len()).collect();
+    by_size.sort_by_key(|&i| Reverse(dice[i]));
+    let mut kept = vec![false; dice.len()];
+    for &i in by_size.iter().take(expr.kept() as usize) {
+        kept[i] = true;
+    }
+
+    let sum: i64 = dice
+        .iter()
+        .zip(&kept)
+        .filter(|(_, &kept)| kept)
+        .map(|(&die, _)| i64::from(die))
+        .sum();
+    Roll {
+        expr: *expr,
+        total: sum + expr.modifier,
+        dice,
+        kept,
+    }
+}
+
+// "4d6kh3+1: [6, (2), 5, 3] + 1 = 15", with the dropped dice in brackets.
+impl fmt::Display for Roll {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let dice: Vec<String> = self
+            .dice
+            .iter()
+            .zip(&self.kept)
+            .map(|(die, kept)| {
+                if *kept {
+                    die.to_string()
+                } else {
+                    format!("({die})")
+                }
+            })
+            .collect();
+        write!(f, "{}: [{}]", self.expr, dice.join(", "))?;
+        match self.expr.modifier {
+            0 => {}
+            modifier if modifier > 0 => write!(f, " + {modifier}")?,
+            modifier => write!(f, " - {}", -modifier)?,
+        }
+        write!(f, " = {}", self.total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn expr(s: &str) -> DiceExpr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn totals_add_up() {
+        let mut rng = StdRng::seed_from_u64(184);
+        for s in ["d20", "3d6+2", "2d10-1", "10d4"] {
+            let expr = expr(s);
+            for _ in 0..100 {
+                let roll = roll(&expr, &mut rng);
+                assert_eq!(roll.dice.len(), expr.count as usize);
+                assert!(roll.dice.iter().all(|&die| (1..=expr.sides).contains(&die)));
+                assert!(roll.kept.iter().all(|&kept| kept));
+                let sum: i64 = roll.dice.iter().map(|&die| i64::from(die)).sum();
+                assert_eq!(roll.total, sum + expr.modifier);
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_the_highest() {
+        let mut rng = StdRng::seed_from_u64(1840);
+        let expr = expr("4d6kh3");
+        for _ in 0..200 {
+            let roll = roll(&expr, &mut rng);
+            assert_eq!(roll.kept.iter().filter(|&&kept| kept).count(), 3);
+            let mut sorted = roll.dice.clone();
+            sorted.sort();
+            let dropped = roll.dice[roll.kept.iter().position(|&kept| !kept).unwrap()];
+            // The dropped die is a lowest one, and the total is the rest.
+            assert_eq!(dropped, sorted[0]);
+            assert_eq!(
+                roll.total,
+                sorted[1..].iter().map(|&die| i64::from(die)).sum::<i64>()
+            );
+        }
+    }
+
+    #[test]
+    fn displays_every_die() {
+        let roll = Roll {
+            expr: expr("4d6kh3+1"),
+            dice: vec![6, 2, 5, 2],
+            kept: vec![true, false, true, true],
+            total: 14,
+        };
+        assert_eq!(roll.to_string(), "4d6kh3+1: [6, (2), 5, 2] + 1 = 14");
+        let roll = Roll {
+            expr: expr("d20-3"),
+            dice: vec![1],
+            kept: vec![true],
+            total: -2,
+        };
+        assert_eq!(roll.to_string(), "d20-3: [1] - 3 = -2");
+    }
+
+    #[test]
+    fn equal_dice_keep_the_earlier_one() {
+        // Every die of a d1 is a 1, so which are kept is down to the order.
+        let roll = roll(&expr("3d1kh2"), &mut StdRng::seed_from_u64(0));
+        assert_eq!(roll.kept, [true, true, false]);
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use rand::Rng;
+
+use crate::expr::DiceExpr;
+use crate::roll::roll;
+
+// How often each total came up over many rolls.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    // Sorted by total, for the histogram.
+    pub counts: BTreeMap<i64, u64>,
+    pub rolls: u64,
+}
+
+pub fn simulate(expr: &DiceExpr, rolls: u64, rng: &mut impl Rng) -> Distribution {
+    let mut counts = BTreeMap::new();
+    for _ in 0..rolls {
+        *counts.entry(roll(expr, rng).total).or_insert(0) += 1;
+    }
+    Distribution { counts, rolls }
+}
+
+impl Distribution {
+    // None if there were no rolls.
+    pub fn mean(&self) -> Option<f64> {
+        let sum: f64 = self
+            .counts
+            .iter()
+            .map(|(&total, &count)| total as f64 * count as f64)
+            .sum();
+        (self.rolls > 0).then(|| sum / self.rolls as f64)
+    }
+
+    // The fraction of rolls that came to `target` or more.
+    pub fn probability_at_least(&self, target: i64) -> f64 {
+        if self.rolls == 0 {
+            return 0.0;
+        }
+        let hits: u64 = self.counts.range(target..).map(|(_, &count)| count).sum();
+        hits as f64 / self.rolls as f64
+    }
+
+    // A line per total, with its share of the rolls and a bar. The most
+    // common total gets a bar `width` long and the rest are to scale.
+    pub fn histogram(&self, width: usize) -> String {
+        let most = self.counts.values().copied().max().unwrap_or(0);
+        let label_width = self
+            .counts
+            .keys()
+            .map(|total| total.to_string().len())
+            .max()
+            .unwrap_or(0);
+        let mut histogram = String::new();
+        for (total, &count) in &self.counts {
+            let percent = 100.0 * count as f64 / self.rolls as f64;
+            // Rounded, but any total that came up at all gets some bar.
+            let bar = ((count as f64 / most as f64 * width as f64).round() as usize).max(1);
+            writeln!(
+                histogram,
+                "{total:>label_width$} {percent:>5.1}% {}",
+                "#".repeat(bar)
+            )
+            .unwrap();
+        }
+        histogram
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const ROLLS: u64 = 100_000;
+
+    fn simulate_seeded(s: &str, seed: u64) -> (DiceExpr, Distribution) {
+        let expr: DiceExpr = s.parse().unwrap();
+        let distribution = simulate(&expr, ROLLS, &mut StdRng::seed_from_u64(seed));
+        (expr, distribution)
+    }
+
+    // The tolerances below are loose - several standard errors - so the
+    // tests pass for any seed, not just the one they use.
+
+    #[test]
+    fn a_fair_die() {
+        let (_, distribution) = simulate_seeded("d6", 184);
+        assert_eq!(
+            distribution.counts.keys().copied().collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5, 6]
+        );
+        for &count in distribution.counts.values() {
+            let share = count as f64 / ROLLS as f64;
+            assert!((share - 1.0 / 6.0).abs() < 0.01, "{share}");
+        }
+        assert!((distribution.mean().unwrap() - 3.5).abs() < 0.05);
+    }
+
+    #[test]
+    fn three_d6_plus_two() {
+        let (expr, distribution) = simulate_seeded("3d6+2", 1841);
+        assert_eq!(distribution.counts.values().sum::<u64>(), ROLLS);
+        assert!(distribution
+            .counts
+            .keys()
+            .all(|total| (expr.min()..=expr.max()).contains(total)));
+        assert!((distribution.mean().unwrap() - 12.5).abs() < 0.05);
+        // 20 is three sixes: 1 in 216.
+        assert!((distribution.probability_at_least(20) - 1.0 / 216.0).abs() < 0.002);
+        assert_eq!(distribution.probability_at_least(expr.min()), 1.0);
+        assert_eq!(distribution.probability_at_least(expr.max() + 1), 0.0);
+    }
+
+    #[test]
+    fn keeping_the_highest_raises_the_mean() {
+        // The mean of 4d6 keep highest 3 is 15869/1296, about 12.24 - more
+        // than the 10.5 of plain 3d6.
+        let (_, distribution) = simulate_seeded("4d6kh3", 1842);
+        assert!((distribution.mean().unwrap() - 15869.0 / 1296.0).abs() < 0.05);
+        // At least 3 is certain; 18 needs three sixes among the four, which
+        // is 21 of the 1296 outcomes.
+        assert!((distribution.probability_at_least(18) - 21.0 / 1296.0).abs() < 0.003);
+    }
+
+    #[test]
+    fn histogram_bars_are_to_scale() {
+        let distribution = Distribution {
+            counts: BTreeMap::from([(2, 1), (3, 2), (10, 4)]),
+            rolls: 7,
+        };
+        assert_eq!(
+            distribution.histogram(8),
+            " 2  14.3% ##\n 3  28.6% ####\n10  57.1% ########\n"
+        );
+    }
+
+    #[test]
+    fn no_rolls() {
+        let distribution = Distribution {
+            counts: BTreeMap::new(),
+            rolls: 0,
+        };
+        assert_eq!(distribution.mean(), None);
+        assert_eq!(distribution.probability_at_least(1), 0.0);
+        assert_eq!(distribution.histogram(10), "");
+    }
+}
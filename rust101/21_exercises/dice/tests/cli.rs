@@ -0,0 +1,67 @@
+use std::process::{Command, Output};
+
+fn dice(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_dice"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn rolls_once() {
+    let output = dice(&["--seed", "184", "4d6kh3+1"]);
+    assert_eq!(output.status.code(), Some(0));
+    let line = stdout(&output);
+    assert!(line.starts_with("4d6kh3+1: ["), "{line}");
+    // Four dice, one of them dropped.
+    assert_eq!(line.matches('(').count(), 1, "{line}");
+    assert_eq!(line.matches(", ").count(), 3, "{line}");
+    // The seed decides the roll.
+    assert_eq!(stdout(&dice(&["--seed", "184", "4d6kh3+1"])), line);
+}
+
+#[test]
+fn simulates() {
+    let output = dice(&[
+        "--seed",
+        "1",
+        "--simulate",
+        "20000",
+        "--target",
+        "12",
+        "2d6",
+    ]);
+    assert_eq!(output.status.code(), Some(0));
+    let lines: Vec<&str> = stdout(&output).lines().collect();
+    assert_eq!(lines[0], "2d6 rolled 20000 times:");
+    // Totals 2 to 12, then the mean and the chance.
+    assert_eq!(lines.len(), 1 + 11 + 2);
+    assert!(lines[1].starts_with(" 2 "));
+    assert!(lines[11].starts_with("12 "));
+    let mean: f64 = lines[12].strip_prefix("mean: ").unwrap().parse().unwrap();
+    assert!((mean - 7.0).abs() < 0.1, "{mean}");
+    let chance: f64 = lines[13]
+        .strip_prefix("chance of 12 or more: ")
+        .unwrap()
+        .trim_end_matches('%')
+        .parse()
+        .unwrap();
+    assert!((chance - 100.0 / 36.0).abs() < 0.5, "{chance}");
+}
+
+#[test]
+fn bad_expressions_are_usage_errors() {
+    for expr in ["d0", "0d6", "3d", "3d6+", "4d6kh5"] {
+        let output = dice(&[expr]);
+        assert_eq!(output.status.code(), Some(2), "{expr}");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains(expr) && stderr.contains("column"),
+            "{stderr}"
+        );
+    }
+}
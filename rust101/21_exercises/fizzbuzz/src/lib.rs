@@ -0,0 +1,326 @@
+/* Things learned during this exercise:
+   * FizzBuzz, but the rules are data rather than code: a list of
+   `(divisor, word)` pairs, tried in order. Every rule that divides n adds
+   its word, so with 3=Fizz and 5=Buzz, 15 is "FizzBuzz" - and with the
+   rules the other way round it would be "BuzzFizz". A number no rule
+   divides is printed as itself.
+       cargo run -- --up-to 21 --rule 3=Fizz --rule 5=Buzz --rule 7=Bazz
+   Without any `--rule` it uses the classic two; `--up-to` defaults to 100.
+   * Writing the rules as data means the classic game is one set of
+   arguments among many, and adding a rule doesn't touch the code.
+   * `fizzbuzz_line` does one number; the `FizzBuzzExt` trait adds a
+   `.fizzbuzz(&rules)` adapter to any iterator of numbers, so
+   `(1..=15).fizzbuzz(&rules)` works like `map` would. An extension trait
+   with a blanket impl is how a crate adds methods to types it doesn't own.
+   * The property test - a number no rule divides prints as itself - runs
+   on random numbers and rule sets, seeded so a failure can be repeated.
+*/
+use std::fmt;
+
+// A divisor and the word it adds. The divisor is never zero.
+pub type Rule = (u32, String);
+
+pub fn classic_rules() -> Vec<Rule> {
+    vec![(3, "Fizz".to_string()), (5, "Buzz".to_string())]
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RuleError {
+    MissingEquals(String),
+    BadDivisor(String),
+    ZeroDivisor,
+    EmptyWord,
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleError::MissingEquals(rule) => {
+                write!(f, "a rule looks like DIVISOR=WORD, not {rule:?}")
+            }
+            RuleError::BadDivisor(divisor) => {
+                write!(f, "the divisor must be a whole number, not {divisor:?}")
+            }
+            RuleError::ZeroDivisor => write!(f, "the divisor can't be zero"),
+            RuleError::EmptyWord => write!(f, "the word can't be empty"),
+        }
+    }
+}
+
+impl std::error::Error for RuleError {}
+
+/// Reads a rule written as `DIVISOR=WORD`.
+///
+/// ```
+/// assert_eq!(fizzbuzz::parse_rule("7=Buzzazz"), Ok((7, "Buzzazz".to_string())));
+/// ```
+pub fn parse_rule(rule: &str) -> Result<Rule, RuleError> {
+    let (divisor, word) = rule
+        .split_once('=')
+        .ok_or_else(|| RuleError::MissingEquals(rule.to_string()))?;
+    let divisor: u32 = divisor
+        .trim()
+        .parse()
+        .map_err(|_| RuleError::BadDivisor(divisor.to_string()))?;
+    if divisor == 0 {
+        return Err(RuleError::ZeroDivisor);
+    }
+    if word.is_empty() {
+        return Err(RuleError::EmptyWord);
+    }
+    Ok((divisor, word.to_string()))
+}
+
+/// The words of every rule dividing `n`, in rule order, or `n` itself if
+/// none do.
+///
+/// ```
+/// let rules = fizzbuzz::classic_rules();
+/// assert_eq!(fizzbuzz::fizzbuzz_line(9, &rules), "Fizz");
+/// assert_eq!(fizzbuzz::fizzbuzz_line(15, &rules), "FizzBuzz");
+/// assert_eq!(fizzbuzz::fizzbuzz_line(16, &rules), "16");
+/// ```
+pub fn fizzbuzz_line(n: u32, rules: &[Rule]) -> String {
+    let words: String = rules
+        .iter()
+        .filter(|(divisor, _)| n.is_multiple_of(*divisor))
+        .map(|(_, word)| word.as_str())
+        .collect();
+    if words.is_empty() {
+        n.to_string()
+    } else {
+        words
+    }
+}
+
+// The iterator returned by `.fizzbuzz(&rules)`.
+pub struct FizzBuzz<'a, I> {
+    numbers: I,
+    rules: &'a [Rule],
+}
+
+impl<I: Iterator<Item = u32>> Iterator for FizzBuzz<'_, I> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let n = self.numbers.next()?;
+        Some(fizzbuzz_line(n, self.rules))
+    }
+
+    // One line per number, so as many as the numbers have left.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.numbers.size_hint()
+    }
+}
+
+pub trait FizzBuzzExt: Iterator<Item = u32> + Sized {
+    /// Turns each number into its FizzBuzz line.
+    ///
+    /// ```
+    /// use fizzbuzz::FizzBuzzExt;
+    ///
+    /// let rules = fizzbuzz::classic_rules();
+    /// let lines: Vec<String> = (9..=11).fizzbuzz(&rules).collect();
+    /// assert_eq!(lines, ["Fizz", "Buzz", "11"]);
+    /// ```
+    fn fizzbuzz(self, rules: &[Rule]) -> FizzBuzz<'_, Self> {
+        FizzBuzz {
+            numbers: self,
+            rules,
+        }
+    }
+}
+
+// Every iterator of u32 gets the method.
+impl<I: Iterator<Item = u32>> FizzBuzzExt for I {}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub up_to: u32,
+    pub rules: Vec<Rule>,
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut up_to = 100;
+        let mut rules = Vec::new();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--up-to" => {
+                    let value = args.next().ok_or("--up-to needs a number")?;
+                    up_to = value
+                        .parse()
+                        .map_err(|_| format!("not a number to count up to: {value:?}"))?;
+                }
+                "--rule" => {
+                    let value = args.next().ok_or("--rule needs DIVISOR=WORD")?;
+                    rules.push(parse_rule(&value).map_err(|err| err.to_string())?);
+                }
+                _ => return Err(format!("unknown argument: {arg:?}")),
+            }
+        }
+        if rules.is_empty() {
+            rules = classic_rules();
+        }
+        Ok(Config { up_to, rules })
+    }
+}
+
+// All the lines from 1 to `config.up_to`, each followed by a newline.
+pub fn run(config: &Config) -> String {
+    (1..=config.up_to)
+        .fizzbuzz(&config.rules)
+        .map(|line| line + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn make_rules(pairs: &[(u32, &str)]) -> Vec<Rule> {
+        pairs
+            .iter()
+            .map(|&(divisor, word)| (divisor, word.to_string()))
+            .collect()
+    }
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["fizzbuzz".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    #[test]
+    fn classic_one_to_fifteen() {
+        let lines: Vec<String> = (1..=15).fizzbuzz(&classic_rules()).collect();
+        assert_eq!(
+            lines,
+            [
+                "1", "2", "Fizz", "4", "Buzz", "Fizz", "7", "8", "Fizz", "Buzz", "11", "Fizz",
+                "13", "14", "FizzBuzz"
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_rules() {
+        let rules = make_rules(&[(2, "Even"), (7, "Buzzazz")]);
+        assert_eq!(fizzbuzz_line(4, &rules), "Even");
+        assert_eq!(fizzbuzz_line(7, &rules), "Buzzazz");
+        assert_eq!(fizzbuzz_line(9, &rules), "9");
+    }
+
+    #[test]
+    fn overlapping_divisors_concatenate_in_rule_order() {
+        let rules = make_rules(&[(5, "Buzz"), (3, "Fizz"), (15, "!")]);
+        assert_eq!(fizzbuzz_line(15, &rules), "BuzzFizz!");
+        assert_eq!(fizzbuzz_line(30, &rules), "BuzzFizz!");
+        assert_eq!(fizzbuzz_line(6, &rules), "Fizz");
+        // The same divisor twice adds its words twice.
+        let doubled = make_rules(&[(2, "a"), (2, "b")]);
+        assert_eq!(fizzbuzz_line(2, &doubled), "ab");
+    }
+
+    #[test]
+    fn no_rules_prints_every_number() {
+        assert_eq!(fizzbuzz_line(15, &[]), "15");
+    }
+
+    #[test]
+    fn zero_is_divisible_by_everything() {
+        assert_eq!(fizzbuzz_line(0, &classic_rules()), "FizzBuzz");
+    }
+
+    #[test]
+    fn numbers_no_rule_divides_print_themselves() {
+        let mut rng = StdRng::seed_from_u64(172);
+        for _ in 0..1_000 {
+            let ruleset: Vec<Rule> = (0..rng.gen_range(0..5))
+                .map(|i| (rng.gen_range(1..50), format!("w{i}")))
+                .collect();
+            let n = rng.gen_range(0..=u32::MAX);
+            if ruleset
+                .iter()
+                .all(|(divisor, _)| !n.is_multiple_of(*divisor))
+            {
+                assert_eq!(fizzbuzz_line(n, &ruleset), n.to_string(), "{ruleset:?}");
+            } else {
+                assert_ne!(fizzbuzz_line(n, &ruleset), n.to_string(), "{ruleset:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn parses_rules() {
+        assert_eq!(parse_rule("7=Buzzazz"), Ok((7, "Buzzazz".to_string())));
+        // Only the first '=' splits, so the word can contain one.
+        assert_eq!(parse_rule("2=a=b"), Ok((2, "a=b".to_string())));
+        assert_eq!(
+            parse_rule("7"),
+            Err(RuleError::MissingEquals("7".to_string()))
+        );
+        assert_eq!(
+            parse_rule("x=Fizz"),
+            Err(RuleError::BadDivisor("x".to_string()))
+        );
+        assert_eq!(parse_rule("0=Zero"), Err(RuleError::ZeroDivisor));
+        assert_eq!(parse_rule("3="), Err(RuleError::EmptyWord));
+    }
+
+    #[test]
+    fn config_defaults_to_the_classic_game() {
+        assert_eq!(
+            Config::build(args(&[])),
+            Ok(Config {
+                up_to: 100,
+                rules: classic_rules()
+            })
+        );
+    }
+
+    #[test]
+    fn config_reads_flags_in_order() {
+        let config = Config::build(args(&[
+            "--rule", "7=Bazz", "--up-to", "21", "--rule", "2=E",
+        ]));
+        assert_eq!(
+            config,
+            Ok(Config {
+                up_to: 21,
+                rules: make_rules(&[(7, "Bazz"), (2, "E")])
+            })
+        );
+    }
+
+    #[test]
+    fn config_errors() {
+        assert!(Config::build(args(&["--up-to"])).is_err());
+        assert!(Config::build(args(&["--up-to", "-3"])).is_err());
+        assert_eq!(
+            Config::build(args(&["--rule", "0=x"])),
+            Err("the divisor can't be zero".to_string())
+        );
+        assert!(Config::build(args(&["15"])).is_err());
+    }
+
+    #[test]
+    fn run_prints_one_line_per_number() {
+        let config = Config {
+            up_to: 5,
+            rules: classic_rules(),
+        };
+        assert_eq!(run(&config), "1\n2\nFizz\n4\nBuzz\n");
+        let none = Config {
+            up_to: 0,
+            rules: classic_rules(),
+        };
+        assert_eq!(run(&none), "");
+    }
+}
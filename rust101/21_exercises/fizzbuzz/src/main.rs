@@ -0,0 +1,13 @@
+use std::env;
+use std::process;
+
+use fizzbuzz::Config;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: fizzbuzz [--up-to N] [--rule DIVISOR=WORD]...");
+        process::exit(2);
+    });
+    print!("{}", fizzbuzz::run(&config));
+}
@@ -0,0 +1,40 @@
+use std::process::Command;
+
+fn fizzbuzz(args: &[&str]) -> (Option<i32>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_fizzbuzz"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.code(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn classic_game_by_default() {
+    let (code, stdout) = fizzbuzz(&["--up-to", "15"]);
+    assert_eq!(code, Some(0));
+    assert_eq!(
+        stdout,
+        "1\n2\nFizz\n4\nBuzz\nFizz\n7\n8\nFizz\nBuzz\n11\nFizz\n13\n14\nFizzBuzz\n"
+    );
+    assert_eq!(fizzbuzz(&[]).1.lines().count(), 100);
+}
+
+#[test]
+fn rules_replace_the_classic_ones() {
+    let (_, stdout) = fizzbuzz(&["--up-to", "14", "--rule", "7=Buzzazz", "--rule", "2=Even"]);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines[2], "3");
+    assert_eq!(lines[6], "Buzzazz");
+    assert_eq!(lines[13], "BuzzazzEven");
+}
+
+#[test]
+fn bad_rules_are_usage_errors() {
+    assert_eq!(
+        fizzbuzz(&["--rule", "seven=Buzz"]),
+        (Some(2), String::new())
+    );
+}
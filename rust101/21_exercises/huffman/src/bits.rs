@@ -0,0 +1,103 @@
+// Bits are packed most significant first: the first bit written is the
+// top bit of the first byte. The last byte is padded with zeros.
+#[derive(Debug, Default)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    // How many bits of the last byte are in use, 0 to 7. 0 means a new
+    // byte is needed for the next bit.
+    used: u32,
+}
+
+impl BitWriter {
+    pub fn new() -> BitWriter {
+        BitWriter::default()
+    }
+
+    // The low `len` bits of `bits`, highest first.
+    pub fn write(&mut self, bits: u64, len: u32) {
+        for shift in (0..len).rev() {
+            if self.used == 0 {
+                self.bytes.push(0);
+            }
+            let bit = ((bits >> shift) & 1) as u8;
+            let last = self.bytes.len() - 1;
+            self.bytes[last] |= bit << (7 - self.used);
+            self.used = (self.used + 1) % 8;
+        }
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+#[derive(Debug)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    // Counts bits, not bytes.
+    at: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> BitReader<'a> {
+        BitReader { bytes, at: 0 }
+    }
+
+    // The next bit, or None past the end.
+    pub fn read(&mut self) -> Option<bool> {
+        let byte = self.bytes.get(self.at / 8)?;
+        let bit = byte >> (7 - self.at % 8) & 1 == 1;
+        self.at += 1;
+        Some(bit)
+    }
+
+    // How many bytes have been started on.
+    pub fn bytes_used(&self) -> usize {
+        self.at.div_ceil(8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packs_bits_highest_first() {
+        let mut writer = BitWriter::new();
+        writer.write(0b1, 1);
+        writer.write(0b011, 3);
+        writer.write(0b1_1110_0001, 9);
+        assert_eq!(writer.into_bytes(), [0b1011_1111, 0b0000_1000]);
+    }
+
+    #[test]
+    fn writes_whole_words() {
+        let mut writer = BitWriter::new();
+        writer.write(u64::MAX, 64);
+        writer.write(0, 0);
+        assert_eq!(writer.into_bytes(), [0xff; 8]);
+        assert!(BitWriter::new().into_bytes().is_empty());
+    }
+
+    #[test]
+    fn reads_back_what_was_written() {
+        let mut writer = BitWriter::new();
+        let pattern = [
+            true, false, false, true, true, true, false, true, false, true, true,
+        ];
+        for &bit in &pattern {
+            writer.write(u64::from(bit), 1);
+        }
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+        for &bit in &pattern {
+            assert_eq!(reader.read(), Some(bit));
+        }
+        assert_eq!(reader.bytes_used(), 2);
+        // The padding, then the end.
+        for _ in 0..5 {
+            assert_eq!(reader.read(), Some(false));
+        }
+        assert_eq!(reader.read(), None);
+    }
+}
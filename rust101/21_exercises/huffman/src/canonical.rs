@@ -0,0 +1,233 @@
+use crate::bits::BitReader;
+
+// The longest code there can be in a container, since a code is kept in a
+// u64. A Huffman tree only gets that deep when counts grow like the
+// Fibonacci numbers - past 10^13 bytes of input for a 64-bit code.
+pub const MAX_LENGTH: u8 = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Code {
+    pub bits: u64,
+    pub len: u8,
+}
+
+// The bytes that have codes, shortest code first and by byte value among
+// equal lengths. This order is what makes the codes canonical.
+fn canonical_order(lengths: &[u8; 256]) -> Vec<u8> {
+    let mut symbols: Vec<u8> = (0..=u8::MAX)
+        .filter(|&byte| lengths[usize::from(byte)] > 0)
+        .collect();
+    symbols.sort_by_key(|&byte| lengths[usize::from(byte)]);
+    symbols
+}
+
+// Canonical Huffman codes: in canonical order each code is one more than
+// the last, shifted left whenever the length goes up. Any two trees with
+// the same lengths get the same codes this way, so the lengths are all a
+// file needs to store.
+pub fn canonical_codes(lengths: &[u8; 256]) -> [Option<Code>; 256] {
+    let mut codes = [None; 256];
+    // Wider than a code, since a full table ends with one past the longest.
+    let mut next = 0u128;
+    let mut previous_len = 0;
+    for byte in canonical_order(lengths) {
+        let len = lengths[usize::from(byte)];
+        next <<= len - previous_len;
+        codes[usize::from(byte)] = Some(Code {
+            bits: next as u64,
+            len,
+        });
+        next += 1;
+        previous_len = len;
+    }
+    codes
+}
+
+// Why a table of lengths can't be a code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TableError {
+    TooLong(u8),
+    // More codes of some length than there's room for: no prefix code
+    // has lengths like these.
+    Oversubscribed,
+    // Room left over, for codes no byte has. Only one byte on its own
+    // leaves any - half the room, as its code has to be a bit long.
+    Incomplete,
+}
+
+// Reads canonical codes back, a bit at a time, without building a tree.
+// After reading `len` bits, the codes of that length are the `counts[len]`
+// values from `first[len]` on, so it's a subtraction and a comparison per
+// bit.
+#[derive(Debug)]
+pub struct Decoder {
+    // How many codes there are of each length.
+    counts: Vec<u64>,
+    symbols: Vec<u8>,
+}
+
+impl Decoder {
+    pub fn new(lengths: &[u8; 256]) -> Result<Decoder, TableError> {
+        let mut counts = vec![0u64; usize::from(MAX_LENGTH) + 1];
+        for &len in lengths {
+            if len > MAX_LENGTH {
+                return Err(TableError::TooLong(len));
+            }
+            counts[usize::from(len)] += 1;
+        }
+        counts[0] = 0;
+        // Each length has twice the room of the one before, less what the
+        // shorter codes took.
+        let mut room = 1u128;
+        for &count in &counts[1..] {
+            room *= 2;
+            if u128::from(count) > room {
+                return Err(TableError::Oversubscribed);
+            }
+            room -= u128::from(count);
+        }
+        let symbols = canonical_order(lengths);
+        if room > 0 && symbols.len() > 1 {
+            return Err(TableError::Incomplete);
+        }
+        Ok(Decoder { counts, symbols })
+    }
+
+    // The next byte, or None if the bits run out first or make a code no
+    // byte has. With no bytes at all, nothing decodes.
+    pub fn decode(&self, reader: &mut BitReader) -> Option<u8> {
+        // u128s, as one past the last 64-bit code doesn't fit in a u64.
+        let mut code = 0u128;
+        // The first code of the current length, and how many symbols come
+        // before it in canonical order.
+        let mut first = 0u128;
+        let mut index = 0u128;
+        for &count in &self.counts[1..] {
+            let count = u128::from(count);
+            code |= u128::from(reader.read()?);
+            if code - first < count {
+                return Some(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bits::BitWriter;
+
+    fn lengths(of: &[(u8, u8)]) -> [u8; 256] {
+        let mut lengths = [0; 256];
+        for &(byte, len) in of {
+            lengths[usize::from(byte)] = len;
+        }
+        lengths
+    }
+
+    fn code(bits: u64, len: u8) -> Option<Code> {
+        Some(Code { bits, len })
+    }
+
+    #[test]
+    fn assigns_canonical_codes() {
+        // The example in RFC 1951, section 3.2.2: A-H with lengths
+        // 3, 3, 3, 3, 3, 2, 4, 4.
+        let table = lengths(&[
+            (b'A', 3),
+            (b'B', 3),
+            (b'C', 3),
+            (b'D', 3),
+            (b'E', 3),
+            (b'F', 2),
+            (b'G', 4),
+            (b'H', 4),
+        ]);
+        let codes = canonical_codes(&table);
+        let expected = [
+            (b'A', code(0b010, 3)),
+            (b'B', code(0b011, 3)),
+            (b'C', code(0b100, 3)),
+            (b'D', code(0b101, 3)),
+            (b'E', code(0b110, 3)),
+            (b'F', code(0b00, 2)),
+            (b'G', code(0b1110, 4)),
+            (b'H', code(0b1111, 4)),
+        ];
+        for (byte, expected) in expected {
+            assert_eq!(codes[usize::from(byte)], expected, "{}", char::from(byte));
+        }
+        assert_eq!(codes.iter().flatten().count(), 8);
+    }
+
+    #[test]
+    fn decodes_what_was_encoded() {
+        let table = lengths(&[(b'a', 1), (b'b', 2), (b'c', 3), (b'd', 3)]);
+        let codes = canonical_codes(&table);
+        let text = b"abacabadcab";
+        let mut writer = BitWriter::new();
+        for &byte in text {
+            let code = codes[usize::from(byte)].unwrap();
+            writer.write(code.bits, u32::from(code.len));
+        }
+        let bytes = writer.into_bytes();
+        let decoder = Decoder::new(&table).unwrap();
+        let mut reader = BitReader::new(&bytes);
+        let decoded: Vec<u8> = (0..text.len())
+            .map(|_| decoder.decode(&mut reader).unwrap())
+            .collect();
+        assert_eq!(decoded, text);
+    }
+
+    #[test]
+    fn a_single_one_bit_code() {
+        let table = lengths(&[(b'z', 1)]);
+        assert_eq!(canonical_codes(&table)[usize::from(b'z')], code(0, 1));
+        let decoder = Decoder::new(&table).unwrap();
+        let mut reader = BitReader::new(&[0b0100_0000]);
+        assert_eq!(decoder.decode(&mut reader), Some(b'z'));
+        // A 1 is the code that isn't there.
+        assert_eq!(decoder.decode(&mut reader), None);
+    }
+
+    #[test]
+    fn codes_up_to_the_longest() {
+        // 1, 01, 001, ... down to two codes of 64 bits.
+        let mut table = [0; 256];
+        for len in 1..=MAX_LENGTH {
+            table[usize::from(len)] = len;
+        }
+        table[0] = MAX_LENGTH;
+        let codes = canonical_codes(&table);
+        assert_eq!(codes[1], code(0, 1));
+        assert_eq!(codes[64], code(u64::MAX, 64));
+        let decoder = Decoder::new(&table).unwrap();
+        let mut writer = BitWriter::new();
+        writer.write(u64::MAX, 64);
+        writer.write(0, 1);
+        let bytes = writer.into_bytes();
+        let mut reader = BitReader::new(&bytes);
+        assert_eq!(decoder.decode(&mut reader), Some(64));
+        assert_eq!(decoder.decode(&mut reader), Some(1));
+    }
+
+    #[test]
+    fn rejects_impossible_tables() {
+        assert_eq!(
+            Decoder::new(&lengths(&[(1, 1), (2, 1), (3, 1)])).unwrap_err(),
+            TableError::Oversubscribed
+        );
+        assert_eq!(
+            Decoder::new(&lengths(&[(1, 1), (2, 2)])).unwrap_err(),
+            TableError::Incomplete
+        );
+        assert_eq!(
+            Decoder::new(&lengths(&[(1, 65)])).unwrap_err(),
+            TableError::TooLong(65)
+        );
+    }
+}
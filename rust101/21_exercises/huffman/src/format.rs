@@ -0,0 +1,278 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::bits::{BitReader, BitWriter};
+use crate::canonical::{canonical_codes, Decoder, TableError, MAX_LENGTH};
+use crate::tree::{code_lengths, count};
+
+// A compressed file is
+//
+//     "HUFF"                  magic
+//     length                  u64, little-endian: bytes when decompressed
+//     symbols                 u16, little-endian: how many bytes have codes
+//     (byte, code length)     one pair for each of them
+//     data                    the codes, most significant bit first,
+//                             padded with zeros to a whole byte
+//
+// The code lengths are enough to rebuild the canonical codes, and the
+// length says where the data stops - the padding could be the start of a
+// code otherwise.
+pub const MAGIC: &[u8; 4] = b"HUFF";
+
+const HEADER_LEN: usize = MAGIC.len() + 8 + 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatError {
+    BadMagic,
+    // The file stops in the middle of the header or the table.
+    Truncated,
+    BadTable(&'static str),
+    // The data doesn't decode to `length` bytes, or there's more after it.
+    Corrupt(&'static str),
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FormatError::BadMagic => write!(f, "not a compressed file: it doesn't start with HUFF"),
+            FormatError::Truncated => write!(f, "the file ends in the middle of its header"),
+            FormatError::BadTable(why) => write!(f, "bad code table: {why}"),
+            FormatError::Corrupt(why) => write!(f, "corrupt data: {why}"),
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+pub fn compress(input: &[u8]) -> Vec<u8> {
+    let lengths = code_lengths(&count(input));
+    let codes = canonical_codes(&lengths);
+
+    let mut output = MAGIC.to_vec();
+    output.extend_from_slice(&(input.len() as u64).to_le_bytes());
+    let symbols: Vec<u8> = (0..=u8::MAX)
+        .filter(|&byte| lengths[usize::from(byte)] > 0)
+        .collect();
+    output.extend_from_slice(&(symbols.len() as u16).to_le_bytes());
+    for byte in symbols {
+        output.extend_from_slice(&[byte, lengths[usize::from(byte)]]);
+    }
+
+    let mut writer = BitWriter::new();
+    for &byte in input {
+        // Every byte of the input was counted, so has a code.
+        let code = codes[usize::from(byte)].unwrap();
+        writer.write(code.bits, u32::from(code.len));
+    }
+    output.extend(writer.into_bytes());
+    output
+}
+
+fn read_table(table: &[u8]) -> Result<[u8; 256], FormatError> {
+    let mut lengths = [0; 256];
+    for pair in table.chunks_exact(2) {
+        let (byte, len) = (usize::from(pair[0]), pair[1]);
+        if lengths[byte] != 0 {
+            return Err(FormatError::BadTable("a byte is listed twice"));
+        }
+        if len == 0 || len > MAX_LENGTH {
+            return Err(FormatError::BadTable("code lengths go from 1 to 64"));
+        }
+        lengths[byte] = len;
+    }
+    Ok(lengths)
+}
+
+pub fn decompress(input: &[u8]) -> Result<Vec<u8>, FormatError> {
+    if !input.starts_with(MAGIC) {
+        // Too short to hold the magic is still not one of ours.
+        return Err(FormatError::BadMagic);
+    }
+    if input.len() < HEADER_LEN {
+        return Err(FormatError::Truncated);
+    }
+    let length = u64::from_le_bytes(input[4..12].try_into().unwrap());
+    let symbols = usize::from(u16::from_le_bytes(input[12..14].try_into().unwrap()));
+    if symbols > 256 {
+        return Err(FormatError::BadTable("more than 256 bytes have codes"));
+    }
+    let table_end = HEADER_LEN + 2 * symbols;
+    let table = input
+        .get(HEADER_LEN..table_end)
+        .ok_or(FormatError::Truncated)?;
+    let decoder = Decoder::new(&read_table(table)?).map_err(|err| {
+        FormatError::BadTable(match err {
+            // read_table already caught these.
+            TableError::TooLong(_) => "code lengths go from 1 to 64",
+            TableError::Oversubscribed => "more codes than the lengths have room for",
+            TableError::Incomplete => "the lengths leave codes unused",
+        })
+    })?;
+
+    let data = &input[table_end..];
+    // Every byte takes at least a bit, so a length the data can't hold is
+    // caught here - before it's trusted with an allocation.
+    if length > data.len() as u64 * 8 {
+        return Err(FormatError::Corrupt("it ends early"));
+    }
+    let mut output = Vec::with_capacity(length as usize);
+    let mut reader = BitReader::new(data);
+    for _ in 0..length {
+        let byte = decoder.decode(&mut reader).ok_or(FormatError::Corrupt(
+            "it ends early, or has a code no byte has",
+        ))?;
+        output.push(byte);
+    }
+    if reader.bytes_used() != data.len() {
+        return Err(FormatError::Corrupt("there's more after the end"));
+    }
+    // The padding is zeros, for the same bytes to come out the same.
+    while let Some(bit) = reader.read() {
+        if bit {
+            return Err(FormatError::Corrupt("the padding isn't zeros"));
+        }
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn the_layout() {
+        let compressed = compress(b"aab");
+        assert_eq!(
+            compressed,
+            [
+                b'H',
+                b'U',
+                b'F',
+                b'F', //
+                3,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0, // length
+                2,
+                0, // symbols
+                b'a',
+                1,
+                b'b',
+                1, //
+                0b0010_0000,
+            ]
+        );
+        assert_eq!(decompress(&compressed).unwrap(), b"aab");
+    }
+
+    #[test]
+    fn empty_input() {
+        let compressed = compress(b"");
+        assert_eq!(compressed.len(), HEADER_LEN);
+        assert_eq!(decompress(&compressed).unwrap(), b"");
+    }
+
+    #[test]
+    fn one_byte_over_and_over() {
+        let input = vec![b'x'; 1000];
+        let compressed = compress(&input);
+        // One bit each.
+        assert_eq!(compressed.len(), HEADER_LEN + 2 + 125);
+        assert_eq!(decompress(&compressed).unwrap(), input);
+        assert_eq!(decompress(&compress(b"x")).unwrap(), b"x");
+    }
+
+    #[test]
+    fn round_trips_random_input() {
+        let mut rng = StdRng::seed_from_u64(199);
+        for round in 0..200 {
+            let len = rng.gen_range(0..2000);
+            // Some with every byte value, some with only a few.
+            let alphabet = if round % 2 == 0 {
+                256
+            } else {
+                rng.gen_range(1..8)
+            };
+            let input: Vec<u8> = (0..len).map(|_| rng.gen_range(0..alphabet) as u8).collect();
+            assert_eq!(
+                decompress(&compress(&input)).unwrap(),
+                input,
+                "round {round}"
+            );
+        }
+    }
+
+    #[test]
+    fn skewed_input_shrinks() {
+        let mut rng = StdRng::seed_from_u64(199);
+        let input: Vec<u8> = (0..10_000)
+            .map(|_| if rng.gen_bool(0.9) { b'a' } else { rng.gen() })
+            .collect();
+        let compressed = compress(&input);
+        assert!(compressed.len() < input.len() / 2, "{}", compressed.len());
+        assert_eq!(decompress(&compressed).unwrap(), input);
+    }
+
+    #[test]
+    fn rejects_what_it_didnt_write() {
+        assert_eq!(decompress(b""), Err(FormatError::BadMagic));
+        assert_eq!(decompress(b"PK\x03\x04...."), Err(FormatError::BadMagic));
+        let good = compress(b"hello, world");
+        assert_eq!(decompress(&good[..10]), Err(FormatError::Truncated));
+        assert_eq!(
+            decompress(&good[..HEADER_LEN + 3]),
+            Err(FormatError::Truncated)
+        );
+        assert!(matches!(
+            decompress(&good[..good.len() - 1]),
+            Err(FormatError::Corrupt(_))
+        ));
+        let mut longer = good.clone();
+        longer.push(0);
+        assert_eq!(
+            decompress(&longer),
+            Err(FormatError::Corrupt("there's more after the end"))
+        );
+    }
+
+    #[test]
+    fn rejects_bad_tables() {
+        let table = |pairs: &[u8]| {
+            let mut file = MAGIC.to_vec();
+            file.extend_from_slice(&1u64.to_le_bytes());
+            file.extend_from_slice(&(pairs.len() as u16 / 2).to_le_bytes());
+            file.extend_from_slice(pairs);
+            file.push(0);
+            decompress(&file)
+        };
+        assert_eq!(table(&[b'a', 1]), Ok(b"a".to_vec()));
+        for bad in [
+            &[b'a', 0][..],
+            &[b'a', 65],
+            &[b'a', 1, b'a', 1],
+            &[b'a', 1, b'b', 1, b'c', 1],
+            &[b'a', 1, b'b', 2],
+        ] {
+            assert!(
+                matches!(table(bad), Err(FormatError::BadTable(_))),
+                "{bad:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn a_huge_length_is_not_trusted() {
+        let mut file = compress(b"abc");
+        file[4..12].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(
+            decompress(&file),
+            Err(FormatError::Corrupt("it ends early"))
+        );
+    }
+}
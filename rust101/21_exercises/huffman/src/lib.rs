@@ -0,0 +1,186 @@
+/* Things learned during this exercise:
+   * `BinaryHeap` pops the biggest item, so `Reverse((weight, index))` makes
+   it a min-heap of the lightest nodes. The index breaks ties, which keeps
+   the tree the same from one run to the next.
+   * The nodes of a tree can live in a `Vec` and point at each other by
+   index, which is simpler than `Box`es when a node is built from two that
+   already exist.
+   * Canonical codes are decided by the code lengths alone, so a file only
+   has to store one length per byte, not the tree. They also decode
+   without a tree: a count of codes of each length is enough.
+   * A tree with one leaf gives that byte a code of no bits, so a file of
+   one repeated byte needs a special case - a one-bit code here.
+   * The padding at the end of the last byte could be read as codes, so
+   the original length has to be stored, and the decoder stops there.
+   * A length read from a file can't be trusted with `with_capacity` until
+   it's been checked against how much data there is.
+   * See https://en.wikipedia.org/wiki/Canonical_Huffman_code and
+   https://datatracker.ietf.org/doc/html/rfc1951#section-3.2.2
+*/
+mod bits;
+mod canonical;
+mod format;
+mod tree;
+
+pub use bits::{BitReader, BitWriter};
+pub use canonical::{canonical_codes, Code, Decoder, TableError, MAX_LENGTH};
+pub use format::{compress, decompress, FormatError, MAGIC};
+pub use tree::{code_lengths, count};
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Compress,
+    Decompress,
+}
+
+// None for stdin or stdout, which is what `-` or leaving the file out
+// means.
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub mode: Mode,
+    pub input: Option<PathBuf>,
+    pub output: Option<PathBuf>,
+}
+
+fn path(arg: String) -> Option<PathBuf> {
+    (arg != "-").then(|| PathBuf::from(arg))
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mode = match args.next().as_deref() {
+            Some("compress") => Mode::Compress,
+            Some("decompress") => Mode::Decompress,
+            Some(other) => return Err(format!("unknown command: {other:?}")),
+            None => return Err("compress or decompress?".to_string()),
+        };
+        let input = args.next().and_then(path);
+        let output = args.next().and_then(path);
+        if let Some(extra) = args.next() {
+            return Err(format!("too many arguments: {extra:?}"));
+        }
+        Ok(Config {
+            mode,
+            input,
+            output,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum RunError {
+    Read(io::Error),
+    Write(io::Error),
+    Format(FormatError),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::Read(err) => write!(f, "can't read the input: {err}"),
+            RunError::Write(err) => write!(f, "can't write the output: {err}"),
+            RunError::Format(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for RunError {}
+
+// Reads all of the input before writing anything, as the codes depend on
+// every byte. Nothing is written if it won't decompress, so a corrupt file
+// doesn't leave half an output behind.
+pub fn run(config: &Config, mut stdin: impl Read, stdout: &mut impl Write) -> Result<(), RunError> {
+    let input = match &config.input {
+        Some(path) => fs::read(path).map_err(RunError::Read)?,
+        None => {
+            let mut input = Vec::new();
+            stdin.read_to_end(&mut input).map_err(RunError::Read)?;
+            input
+        }
+    };
+    let output = match config.mode {
+        Mode::Compress => compress(&input),
+        Mode::Decompress => decompress(&input).map_err(RunError::Format)?,
+    };
+    match &config.output {
+        Some(path) => fs::write(path, output),
+        None => stdout.write_all(&output).and_then(|()| stdout.flush()),
+    }
+    .map_err(RunError::Write)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["huffman".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&["compress"])),
+            Ok(Config {
+                mode: Mode::Compress,
+                input: None,
+                output: None,
+            })
+        );
+        assert_eq!(
+            Config::build(args(&["decompress", "-", "out.txt"])),
+            Ok(Config {
+                mode: Mode::Decompress,
+                input: None,
+                output: Some(PathBuf::from("out.txt")),
+            })
+        );
+        assert_eq!(
+            Config::build(args(&["compress", "in.txt", "-"])),
+            Ok(Config {
+                mode: Mode::Compress,
+                input: Some(PathBuf::from("in.txt")),
+                output: None,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        for bad in [&[][..], &["squash"], &["compress", "a", "b", "c"]] {
+            assert!(Config::build(args(bad)).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn runs_on_stdin_and_stdout() {
+        let config = |mode| Config {
+            mode,
+            input: None,
+            output: None,
+        };
+        let mut compressed = Vec::new();
+        run(&config(Mode::Compress), &b"so it goes"[..], &mut compressed).unwrap();
+        assert!(compressed.starts_with(MAGIC));
+        let mut output = Vec::new();
+        run(&config(Mode::Decompress), &compressed[..], &mut output).unwrap();
+        assert_eq!(output, b"so it goes");
+
+        let mut output = Vec::new();
+        let err = run(&config(Mode::Decompress), &b"so it goes"[..], &mut output).unwrap_err();
+        assert!(matches!(err, RunError::Format(FormatError::BadMagic)));
+        assert!(output.is_empty());
+    }
+}
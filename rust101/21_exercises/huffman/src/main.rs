@@ -0,0 +1,17 @@
+use std::env;
+use std::io;
+use std::process;
+
+use huffman::Config;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: huffman compress|decompress [INPUT [OUTPUT]]");
+        process::exit(2);
+    });
+    if let Err(err) = huffman::run(&config, io::stdin().lock(), &mut io::stdout().lock()) {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
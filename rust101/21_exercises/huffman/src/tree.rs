@@ -0,0 +1,132 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+// How often each byte value appears.
+pub fn count(bytes: &[u8]) -> [u64; 256] {
+    let mut counts = [0; 256];
+    for &byte in bytes {
+        counts[usize::from(byte)] += 1;
+    }
+    counts
+}
+
+enum Node {
+    Leaf(u8),
+    // Indexes of the two children in the arena.
+    Internal(usize, usize),
+}
+
+// The length of each byte's code in a Huffman tree for `counts`, or 0 for
+// bytes that never appear. Only the lengths are needed: the canonical
+// codes are worked out from them, so the tree itself can be thrown away.
+//
+// The two lightest nodes are joined until one is left. Nodes live in a
+// Vec and the heap holds (weight, index) pairs, in `Reverse` since
+// `BinaryHeap` pops the biggest. The index breaks ties between equal
+// weights, so the same counts always give the same tree.
+//
+// One distinct byte would be a tree that's only a root, with a code of
+// no bits - which can't be written down. It gets a one-bit code instead.
+pub fn code_lengths(counts: &[u64; 256]) -> [u8; 256] {
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for (byte, &count) in (0..=u8::MAX).zip(counts) {
+        if count > 0 {
+            heap.push(Reverse((count, nodes.len())));
+            nodes.push(Node::Leaf(byte));
+        }
+    }
+
+    let mut lengths = [0; 256];
+    if let [Node::Leaf(byte)] = nodes[..] {
+        lengths[usize::from(byte)] = 1;
+        return lengths;
+    }
+    while heap.len() > 1 {
+        let Reverse((left_weight, left)) = heap.pop().unwrap();
+        let Reverse((right_weight, right)) = heap.pop().unwrap();
+        heap.push(Reverse((left_weight + right_weight, nodes.len())));
+        nodes.push(Node::Internal(left, right));
+    }
+
+    // Walked with a stack rather than recursion; the root is the last
+    // node made.
+    let Some(Reverse((_, root))) = heap.pop() else {
+        return lengths;
+    };
+    let mut stack = vec![(root, 0)];
+    while let Some((index, depth)) = stack.pop() {
+        match nodes[index] {
+            Node::Leaf(byte) => lengths[usize::from(byte)] = depth,
+            Node::Internal(left, right) => {
+                stack.push((left, depth + 1));
+                stack.push((right, depth + 1));
+            }
+        }
+    }
+    lengths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lengths_of(text: &[u8]) -> Vec<(char, u8)> {
+        let lengths = code_lengths(&count(text));
+        (0..=u8::MAX)
+            .filter(|&byte| lengths[usize::from(byte)] > 0)
+            .map(|byte| (char::from(byte), lengths[usize::from(byte)]))
+            .collect()
+    }
+
+    #[test]
+    fn counts_bytes() {
+        let counts = count(b"abracadabra");
+        assert_eq!(
+            (counts[usize::from(b'a')], counts[usize::from(b'r')]),
+            (5, 2)
+        );
+        assert_eq!(counts.iter().sum::<u64>(), 11);
+    }
+
+    #[test]
+    fn common_bytes_get_shorter_codes() {
+        // a: 5, b: 2, r: 2, c: 1, d: 1.
+        assert_eq!(
+            lengths_of(b"abracadabra"),
+            [('a', 1), ('b', 3), ('c', 3), ('d', 3), ('r', 3)]
+        );
+        assert_eq!(lengths_of(b"aaaabbc"), [('a', 1), ('b', 2), ('c', 2)]);
+    }
+
+    #[test]
+    fn equal_counts_give_equal_lengths() {
+        assert_eq!(
+            lengths_of(b"abcd"),
+            [('a', 2), ('b', 2), ('c', 2), ('d', 2)]
+        );
+        let every_byte: Vec<u8> = (0..=u8::MAX).collect();
+        assert!(code_lengths(&count(&every_byte))
+            .iter()
+            .all(|&length| length == 8));
+    }
+
+    #[test]
+    fn one_distinct_byte_gets_one_bit() {
+        assert_eq!(lengths_of(b"zzzzzz"), [('z', 1)]);
+        assert_eq!(lengths_of(b"z"), [('z', 1)]);
+        assert_eq!(lengths_of(b""), []);
+    }
+
+    #[test]
+    fn the_lengths_make_a_complete_code() {
+        // Kraft: with two or more symbols, the sum of 2^-length is 1.
+        let lengths = code_lengths(&count(b"the quick brown fox jumps over the lazy dog"));
+        let sum: f64 = lengths
+            .iter()
+            .filter(|&&length| length > 0)
+            .map(|&length| 0.5f64.powi(i32::from(length)))
+            .sum();
+        assert_eq!(sum, 1.0);
+    }
+}
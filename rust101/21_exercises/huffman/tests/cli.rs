@@ -0,0 +1,130 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> TempDir {
+        let dir = std::env::temp_dir().join(format!("huffman_cli_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn huffman(args: &[&str], stdin: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_huffman"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+// Through stdin and stdout both ways, checking each step succeeds.
+fn round_trip(input: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let compressed = huffman(&["compress"], input);
+    assert_eq!(compressed.status.code(), Some(0), "{compressed:?}");
+    let decompressed = huffman(&["decompress", "-"], &compressed.stdout);
+    assert_eq!(decompressed.status.code(), Some(0), "{decompressed:?}");
+    (compressed.stdout, decompressed.stdout)
+}
+
+#[test]
+fn text_round_trips_and_shrinks() {
+    let text = fs::read("tests/data/sample.txt").unwrap();
+    let (compressed, decompressed) = round_trip(&text);
+    assert_eq!(decompressed, text);
+    assert!(
+        compressed.len() < text.len() * 2 / 3,
+        "{}",
+        compressed.len()
+    );
+}
+
+#[test]
+fn compressed_input_round_trips_but_doesnt_shrink() {
+    let gzipped = fs::read("tests/data/sample.txt.gz").unwrap();
+    let (compressed, decompressed) = round_trip(&gzipped);
+    assert_eq!(decompressed, gzipped);
+    assert!(
+        compressed.len() > gzipped.len() * 9 / 10,
+        "{}",
+        compressed.len()
+    );
+}
+
+#[test]
+fn empty_and_repeated_input() {
+    assert_eq!(round_trip(b"").1, b"");
+    let repeated = vec![0u8; 4096];
+    let (compressed, decompressed) = round_trip(&repeated);
+    assert_eq!(decompressed, repeated);
+    assert!(compressed.len() < 600, "{}", compressed.len());
+}
+
+#[test]
+fn reads_and_writes_files() {
+    let dir = TempDir::new("files");
+    let (compressed, restored) = (dir.0.join("sample.huff"), dir.0.join("sample.txt"));
+    let input = "tests/data/sample.txt";
+    let output = huffman(&["compress", input, compressed.to_str().unwrap()], b"");
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+    assert!(output.stdout.is_empty());
+    let output = huffman(
+        &[
+            "decompress",
+            compressed.to_str().unwrap(),
+            restored.to_str().unwrap(),
+        ],
+        b"",
+    );
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+    assert_eq!(fs::read(restored).unwrap(), fs::read(input).unwrap());
+}
+
+#[test]
+fn corrupt_input_is_an_error_and_writes_nothing() {
+    let dir = TempDir::new("corrupt");
+    let restored = dir.0.join("restored.txt");
+    let output = huffman(
+        &[
+            "decompress",
+            "tests/data/sample.txt",
+            restored.to_str().unwrap(),
+        ],
+        b"",
+    );
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: not a compressed file: it doesn't start with HUFF\n"
+    );
+    assert!(!restored.exists());
+
+    let mut compressed = round_trip(b"a few words, not many").0;
+    compressed.truncate(compressed.len() - 2);
+    let output = huffman(&["decompress"], &compressed);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn usage_errors() {
+    let output = huffman(&["explode"], b"");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: huffman"));
+    let output = huffman(&["compress", "tests/data/missing.txt"], b"");
+    assert_eq!(output.status.code(), Some(1));
+}
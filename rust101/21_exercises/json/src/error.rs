@@ -0,0 +1,54 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    UnexpectedChar(char),
+    UnexpectedEnd,
+    InvalidEscape,
+    // A \uD800-\uDFFF escape that isn't half of a high-low pair.
+    LoneSurrogate,
+    // Raw tabs, newlines and the like have to be escaped inside strings.
+    ControlCharacter,
+    // Too big for an f64, like 1e400.
+    NumberOutOfRange,
+    TooDeep(usize),
+}
+
+// Lines and columns count from 1, and columns count characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonError {
+    pub kind: ErrorKind,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl JsonError {
+    // An error at byte `offset` of `input`.
+    pub(crate) fn at(input: &str, offset: usize, kind: ErrorKind) -> JsonError {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        JsonError {
+            kind,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::UnexpectedChar(ch) => write!(f, "unexpected {ch:?}")?,
+            ErrorKind::UnexpectedEnd => write!(f, "unexpected end of input")?,
+            ErrorKind::InvalidEscape => write!(f, "invalid escape")?,
+            ErrorKind::LoneSurrogate => write!(f, "lone surrogate in \\u escape")?,
+            ErrorKind::ControlCharacter => write!(f, "unescaped control character in string")?,
+            ErrorKind::NumberOutOfRange => write!(f, "number out of range")?,
+            ErrorKind::TooDeep(max) => write!(f, "nested more than {max} deep")?,
+        }
+        write!(f, " at line {}, column {}", self.line, self.column)
+    }
+}
+
+impl Error for JsonError {}
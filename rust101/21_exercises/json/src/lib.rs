@@ -0,0 +1,84 @@
+/* Things learned during this exercise:
+   * A JSON parser and printer written by hand - no serde - to see what one
+   involves. `parse` turns text into a `JsonValue` tree; `to_string` and
+   `to_string_pretty` turn a tree back into text.
+       cargo run < file.json      (pretty-prints it, or says what's wrong)
+   * Recursive descent: a method per kind of value, choosing which from
+   the first character alone - '[' is an array, '"' a string, '-' or a
+   digit a number. Arrays and objects call back into `value` for what's
+   inside them.
+   * Recursion needs a limit. A document of nothing but '[' is valid up to
+   where it ends, and parsing it would use a stack frame per '[' - so past
+   `MAX_DEPTH` levels it's an error instead of a stack overflow.
+   * An error says where it is, as a line and column a person can find.
+   The parser tracks only a byte offset and works out the line and column
+   when there's an error, which keeps the common path cheap.
+   * JSON's `\uXXXX` escapes are UTF-16, so characters beyond U+FFFF come
+   as two escapes, a surrogate pair. A lone half is no character at all -
+   Rust's `char` can't hold one - so it's rejected.
+   * The tests go through the classic problem cases from JSONTestSuite
+   (https://github.com/nst/JSONTestSuite): trailing commas, lone
+   surrogates, leading zeros, `-0`, very deep nesting - and check that
+   parse, print, parse gives back the same value.
+*/
+mod error;
+mod parse;
+mod print;
+mod value;
+
+pub use error::{ErrorKind, JsonError};
+pub use parse::{parse, parse_with_max_depth, MAX_DEPTH};
+pub use print::{to_string, to_string_pretty};
+pub use value::JsonValue;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENTS: &[&str] = &[
+        "null",
+        "-0",
+        "1e10",
+        "[1.5, -2, 3e-7, 12345678901234567890]",
+        r#""tab\t, quote\", é, 😀, \u0000""#,
+        r#"{"name": "Zoë", "tags": ["a", "b"], "nested": {"deep": [[[]]], "none": null}}"#,
+        r#"{"dup": 1, "dup": 2}"#,
+        "[[[[[[[[[[[[[[[[[[[[1]]]]]]]]]]]]]]]]]]]]",
+    ];
+
+    // parse -> print -> parse gives the same value, both ways of printing.
+    #[test]
+    fn round_trips() {
+        for document in DOCUMENTS {
+            let value = parse(document).unwrap();
+            assert_eq!(parse(&to_string(&value)), Ok(value.clone()), "{document}");
+            assert_eq!(parse(&to_string_pretty(&value)), Ok(value), "{document}");
+        }
+    }
+
+    // And printing is stable: printing what was parsed from the printed text
+    // gives the same text again.
+    #[test]
+    fn printing_is_a_fixed_point() {
+        for document in DOCUMENTS {
+            let once = to_string(&parse(document).unwrap());
+            let twice = to_string(&parse(&once).unwrap());
+            assert_eq!(once, twice);
+        }
+    }
+
+    #[test]
+    fn negative_zero_survives_a_round_trip() {
+        let JsonValue::Number(n) = parse(&to_string(&parse("-0").unwrap())).unwrap() else {
+            panic!("not a number");
+        };
+        assert!(n == 0.0 && n.is_sign_negative());
+    }
+
+    #[test]
+    fn deep_nesting_at_the_limit_round_trips() {
+        let nested = "[".repeat(MAX_DEPTH) + &"]".repeat(MAX_DEPTH);
+        let value = parse(&nested).unwrap();
+        assert_eq!(to_string(&value), nested);
+    }
+}
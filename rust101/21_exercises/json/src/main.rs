@@ -0,0 +1,19 @@
+use std::io::{self, Read};
+use std::process;
+
+// Reads JSON from stdin and pretty-prints it. A parse error is reported
+// with its line and column, and exit code 1.
+fn main() {
+    let mut input = String::new();
+    if let Err(err) = io::stdin().read_to_string(&mut input) {
+        eprintln!("couldn't read stdin: {err}");
+        process::exit(1);
+    }
+    match json::parse(&input) {
+        Ok(value) => println!("{}", json::to_string_pretty(&value)),
+        Err(err) => {
+            eprintln!("invalid JSON: {err}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,518 @@
+use crate::{ErrorKind, JsonError, JsonValue};
+
+/// How deeply arrays and objects can nest before `parse` gives up. Each
+/// level is a recursive call, so without a limit a document of a million
+/// `[`s would overflow the stack.
+pub const MAX_DEPTH: usize = 128;
+
+/// Parses a complete JSON document, as RFC 8259 defines it: one value,
+/// with nothing but whitespace around it.
+///
+/// ```
+/// use json::JsonValue;
+///
+/// let value = json::parse(r#"{"tags": ["a", "b"], "ok": true}"#).unwrap();
+/// assert_eq!(value.get("ok"), Some(&JsonValue::Bool(true)));
+///
+/// let err = json::parse("[1, 2,]").unwrap_err();
+/// assert_eq!(err.to_string(), "unexpected ']' at line 1, column 7");
+/// ```
+pub fn parse(input: &str) -> Result<JsonValue, JsonError> {
+    parse_with_max_depth(input, MAX_DEPTH)
+}
+
+pub fn parse_with_max_depth(input: &str, max_depth: usize) -> Result<JsonValue, JsonError> {
+    let mut parser = Parser {
+        input,
+        pos: 0,
+        depth: 0,
+        max_depth,
+    };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(value),
+        Some(ch) => Err(parser.error(ErrorKind::UnexpectedChar(ch))),
+    }
+}
+
+// A recursive-descent parser: one method per kind of value, each reading
+// exactly that value and leaving `pos` just after it. Arrays and objects
+// call `value` for their contents, which is the "recursive".
+struct Parser<'a> {
+    input: &'a str,
+    // A byte offset, always on a character boundary.
+    pos: usize,
+    depth: usize,
+    max_depth: usize,
+}
+
+type Parsed<T> = Result<T, JsonError>;
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn error(&self, kind: ErrorKind) -> JsonError {
+        self.error_at(self.pos, kind)
+    }
+
+    fn error_at(&self, offset: usize, kind: ErrorKind) -> JsonError {
+        JsonError::at(self.input, offset, kind)
+    }
+
+    // The error for whatever's at `pos`, when it isn't what was wanted.
+    fn unexpected(&self) -> JsonError {
+        match self.peek() {
+            Some(ch) => self.error(ErrorKind::UnexpectedChar(ch)),
+            None => self.error(ErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn expect(&mut self, wanted: char) -> Parsed<()> {
+        if self.peek() == Some(wanted) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    // Only these four count; JSON has no comments, and no other spaces.
+    fn skip_whitespace(&mut self) {
+        while let Some(' ' | '\t' | '\n' | '\r') = self.peek() {
+            self.bump();
+        }
+    }
+
+    fn value(&mut self) -> Parsed<JsonValue> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('n') => self.literal("null", JsonValue::Null),
+            Some('t') => self.literal("true", JsonValue::Bool(true)),
+            Some('f') => self.literal("false", JsonValue::Bool(false)),
+            Some('"') => self.string().map(JsonValue::String),
+            Some('[') => self.nested(Parser::array),
+            Some('{') => self.nested(Parser::object),
+            Some('-' | '0'..='9') => self.number(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: JsonValue) -> Parsed<JsonValue> {
+        for wanted in word.chars() {
+            self.expect(wanted)?;
+        }
+        Ok(value)
+    }
+
+    // Counts the nesting around `parse_contents`, failing rather than
+    // recursing past the limit.
+    fn nested(&mut self, parse_contents: fn(&mut Self) -> Parsed<JsonValue>) -> Parsed<JsonValue> {
+        if self.depth == self.max_depth {
+            return Err(self.error(ErrorKind::TooDeep(self.max_depth)));
+        }
+        self.depth += 1;
+        let value = parse_contents(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn array(&mut self) -> Parsed<JsonValue> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.bump();
+            return Ok(JsonValue::Array(items));
+        }
+        loop {
+            // After a ',' a value has to follow, so in `[1,]` it's `value`
+            // that finds the ']' - trailing commas need no special case.
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.bump(),
+                Some(']') => {
+                    self.bump();
+                    return Ok(JsonValue::Array(items));
+                }
+                _ => return Err(self.unexpected()),
+            };
+        }
+    }
+
+    fn object(&mut self) -> Parsed<JsonValue> {
+        self.expect('{')?;
+        let mut members = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(JsonValue::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.unexpected());
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            let value = self.value()?;
+            members.push((key, value));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.bump(),
+                Some('}') => {
+                    self.bump();
+                    return Ok(JsonValue::Object(members));
+                }
+                _ => return Err(self.unexpected()),
+            };
+        }
+    }
+
+    // -?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?
+    //
+    // Stricter than `f64::from_str`, which would take "01", "1." or "+1":
+    // the text is checked against JSON's grammar first, then handed over.
+    fn number(&mut self) -> Parsed<JsonValue> {
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.bump();
+        }
+        match self.peek() {
+            Some('0') => {
+                self.bump();
+            }
+            Some('1'..='9') => self.digits(),
+            _ => return Err(self.unexpected()),
+        }
+        if self.peek() == Some('.') {
+            self.bump();
+            self.at_least_one_digit()?;
+        }
+        if let Some('e' | 'E') = self.peek() {
+            self.bump();
+            if let Some('+' | '-') = self.peek() {
+                self.bump();
+            }
+            self.at_least_one_digit()?;
+        }
+        let n: f64 = self.input[start..self.pos]
+            .parse()
+            .expect("the grammar only lets through valid floats");
+        if n.is_infinite() {
+            return Err(self.error_at(start, ErrorKind::NumberOutOfRange));
+        }
+        Ok(JsonValue::Number(n))
+    }
+
+    fn digits(&mut self) {
+        while let Some('0'..='9') = self.peek() {
+            self.bump();
+        }
+    }
+
+    fn at_least_one_digit(&mut self) -> Parsed<()> {
+        match self.peek() {
+            Some('0'..='9') => {
+                self.digits();
+                Ok(())
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn string(&mut self) -> Parsed<String> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            let start = self.pos;
+            match self.bump() {
+                None => return Err(self.error(ErrorKind::UnexpectedEnd)),
+                Some('"') => return Ok(s),
+                Some('\\') => s.push(self.escape(start)?),
+                Some('\0'..='\u{1f}') => {
+                    return Err(self.error_at(start, ErrorKind::ControlCharacter));
+                }
+                Some(ch) => s.push(ch),
+            }
+        }
+    }
+
+    // The character for the escape whose backslash was at `start`.
+    fn escape(&mut self, start: usize) -> Parsed<char> {
+        let ch = match self.bump() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => return self.unicode_escape(start),
+            None => return Err(self.error(ErrorKind::UnexpectedEnd)),
+            Some(_) => return Err(self.error_at(start, ErrorKind::InvalidEscape)),
+        };
+        Ok(ch)
+    }
+
+    // \uXXXX is a UTF-16 code unit. Characters outside the Basic
+    // Multilingual Plane take two: a high surrogate (D800-DBFF) then a low
+    // one (DC00-DFFF), like "\uD83D\uDE00" for 😀. Either half on its own
+    // isn't a character, and a Rust String can't hold one.
+    fn unicode_escape(&mut self, start: usize) -> Parsed<char> {
+        let lone = |parser: &Self| Err(parser.error_at(start, ErrorKind::LoneSurrogate));
+        let first = self.hex4(start)?;
+        let code = match first {
+            0xD800..=0xDBFF => {
+                if !self.input[self.pos..].starts_with("\\u") {
+                    return lone(self);
+                }
+                self.pos += 2;
+                let second = self.hex4(start)?;
+                if !(0xDC00..=0xDFFF).contains(&second) {
+                    return lone(self);
+                }
+                0x10000 + ((first - 0xD800) << 10) + (second - 0xDC00)
+            }
+            0xDC00..=0xDFFF => return lone(self),
+            _ => first,
+        };
+        Ok(char::from_u32(code).expect("surrogates were ruled out above"))
+    }
+
+    fn hex4(&mut self, start: usize) -> Parsed<u32> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = match self.bump() {
+                Some(ch) => ch.to_digit(16),
+                None => return Err(self.error(ErrorKind::UnexpectedEnd)),
+            };
+            let digit = digit.ok_or_else(|| self.error_at(start, ErrorKind::InvalidEscape))?;
+            code = code * 16 + digit;
+        }
+        Ok(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use JsonValue::{Array, Bool, Null, Number, Object};
+
+    fn error(input: &str) -> String {
+        parse(input).unwrap_err().to_string()
+    }
+
+    fn string(s: &str) -> JsonValue {
+        JsonValue::String(s.to_string())
+    }
+
+    #[test]
+    fn scalars() {
+        assert_eq!(parse("null"), Ok(Null));
+        assert_eq!(parse(" true "), Ok(Bool(true)));
+        assert_eq!(parse("\n\tfalse\r\n"), Ok(Bool(false)));
+        assert_eq!(parse(r#""hi""#), Ok(string("hi")));
+        assert_eq!(parse("42"), Ok(Number(42.0)));
+    }
+
+    #[test]
+    fn numbers() {
+        let cases: &[(&str, f64)] = &[
+            ("0", 0.0),
+            ("-1", -1.0),
+            ("3.25", 3.25),
+            ("1e10", 1e10),
+            ("1E+2", 100.0),
+            ("2.5e-3", 0.0025),
+            ("-0.0", -0.0),
+            ("1.7976931348623157e308", f64::MAX),
+            ("5e-324", 5e-324),
+        ];
+        for &(input, expected) in cases {
+            assert_eq!(parse(input), Ok(Number(expected)), "{input}");
+        }
+    }
+
+    #[test]
+    fn negative_zero_keeps_its_sign() {
+        let Ok(Number(n)) = parse("-0") else {
+            panic!("not a number");
+        };
+        assert_eq!(n, 0.0);
+        assert!(n.is_sign_negative());
+    }
+
+    #[test]
+    fn rejects_numbers_outside_the_grammar() {
+        let cases: &[(&str, &str)] = &[
+            ("01", "unexpected '1' at line 1, column 2"),
+            ("-", "unexpected end of input at line 1, column 2"),
+            ("-a", "unexpected 'a' at line 1, column 2"),
+            ("+1", "unexpected '+' at line 1, column 1"),
+            (".5", "unexpected '.' at line 1, column 1"),
+            ("1.", "unexpected end of input at line 1, column 3"),
+            ("1.e3", "unexpected 'e' at line 1, column 3"),
+            ("1e", "unexpected end of input at line 1, column 3"),
+            ("1e+", "unexpected end of input at line 1, column 4"),
+            ("0x10", "unexpected 'x' at line 1, column 2"),
+            ("NaN", "unexpected 'N' at line 1, column 1"),
+            ("Infinity", "unexpected 'I' at line 1, column 1"),
+            ("[1e400]", "number out of range at line 1, column 2"),
+        ];
+        for &(input, expected) in cases {
+            assert_eq!(error(input), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            parse(r#""a\"b\\c\/d\n\t\r\b\f""#),
+            Ok(string("a\"b\\c/d\n\t\r\u{8}\u{c}"))
+        );
+        assert_eq!(parse(r#""\u0041\u00e9\u4E2D""#), Ok(string("Aé中")));
+        assert_eq!(parse(r#""\uD83D\uDE00""#), Ok(string("😀")));
+        assert_eq!(parse(r#""\ud83d\ude00""#), Ok(string("😀")));
+        assert_eq!(parse("\"😀 raw\""), Ok(string("😀 raw")));
+    }
+
+    #[test]
+    fn rejects_bad_escapes() {
+        let cases: &[(&str, &str)] = &[
+            (r#""\x""#, "invalid escape at line 1, column 2"),
+            (r#""\u12""#, "invalid escape at line 1, column 2"),
+            (r#""ab\uZZZZ""#, "invalid escape at line 1, column 4"),
+            (r#""\"#, "unexpected end of input at line 1, column 3"),
+        ];
+        for &(input, expected) in cases {
+            assert_eq!(error(input), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn rejects_lone_surrogates() {
+        let cases = [
+            r#""\uD800""#,
+            r#""\uDBFF x""#,
+            r#""\uDC00""#,
+            r#""\uDFFF\uD800""#,
+            r#""\uD800A""#,
+            r#""\uD800\uD800""#,
+        ];
+        for input in cases {
+            assert_eq!(
+                error(input),
+                "lone surrogate in \\u escape at line 1, column 2",
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_raw_control_characters() {
+        assert_eq!(
+            error("\"tab\there\""),
+            "unescaped control character in string at line 1, column 5"
+        );
+        assert_eq!(
+            error("\"no end"),
+            "unexpected end of input at line 1, column 8"
+        );
+    }
+
+    #[test]
+    fn arrays_and_objects() {
+        assert_eq!(parse("[]"), Ok(Array(vec![])));
+        assert_eq!(parse("{ }"), Ok(Object(vec![])));
+        assert_eq!(
+            parse(r#"[1, "two", [null], {"k": false}]"#),
+            Ok(Array(vec![
+                Number(1.0),
+                string("two"),
+                Array(vec![Null]),
+                Object(vec![("k".to_string(), Bool(false))]),
+            ]))
+        );
+        assert_eq!(
+            parse(r#"{"b": 1, "a": 2, "b": 3}"#),
+            Ok(Object(vec![
+                ("b".to_string(), Number(1.0)),
+                ("a".to_string(), Number(2.0)),
+                ("b".to_string(), Number(3.0)),
+            ]))
+        );
+    }
+
+    #[test]
+    fn rejects_trailing_commas_and_other_slips() {
+        let cases: &[(&str, &str)] = &[
+            ("[1,]", "unexpected ']' at line 1, column 4"),
+            ("[1, 2, ]", "unexpected ']' at line 1, column 8"),
+            (r#"{"a": 1,}"#, "unexpected '}' at line 1, column 9"),
+            ("[,]", "unexpected ',' at line 1, column 2"),
+            ("[1 2]", "unexpected '2' at line 1, column 4"),
+            ("[1", "unexpected end of input at line 1, column 3"),
+            (r#"{"a" 1}"#, "unexpected '1' at line 1, column 6"),
+            (r#"{a: 1}"#, "unexpected 'a' at line 1, column 2"),
+            (r#"{"a": }"#, "unexpected '}' at line 1, column 7"),
+            ("{'a': 1}", "unexpected '\\'' at line 1, column 2"),
+            ("", "unexpected end of input at line 1, column 1"),
+            ("   ", "unexpected end of input at line 1, column 4"),
+            ("nul", "unexpected end of input at line 1, column 4"),
+            ("nulL", "unexpected 'L' at line 1, column 4"),
+            ("true false", "unexpected 'f' at line 1, column 6"),
+            ("[] // comment", "unexpected '/' at line 1, column 4"),
+        ];
+        for &(input, expected) in cases {
+            assert_eq!(error(input), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn positions_count_lines_and_characters() {
+        let input = "{\n  \"name\": \"Zoë\",\n  \"age\": ,\n}";
+        assert_eq!(error(input), "unexpected ',' at line 3, column 10");
+        let input = "[\"é\", \"ü\" x]";
+        assert_eq!(error(input), "unexpected 'x' at line 1, column 11");
+    }
+
+    #[test]
+    fn deep_nesting_up_to_the_limit() {
+        let depth = MAX_DEPTH;
+        let nested = "[".repeat(depth) + &"]".repeat(depth);
+        assert!(parse(&nested).is_ok());
+        let objects = r#"{"a":"#.repeat(depth) + "1" + &"}".repeat(depth);
+        assert!(parse(&objects).is_ok());
+    }
+
+    #[test]
+    fn deeper_nesting_is_an_error_not_a_stack_overflow() {
+        let too_deep = "[".repeat(MAX_DEPTH + 1) + &"]".repeat(MAX_DEPTH + 1);
+        assert_eq!(
+            error(&too_deep),
+            format!(
+                "nested more than 128 deep at line 1, column {}",
+                MAX_DEPTH + 1
+            )
+        );
+        let absurd = "[".repeat(1_000_000);
+        assert_eq!(
+            parse(&absurd).unwrap_err().kind,
+            ErrorKind::TooDeep(MAX_DEPTH)
+        );
+        assert!(parse_with_max_depth("[[1]]", 1).is_err());
+        assert!(parse_with_max_depth("[[1]]", 2).is_ok());
+    }
+}
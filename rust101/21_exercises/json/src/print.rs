@@ -0,0 +1,194 @@
+use std::fmt::Write;
+
+use crate::JsonValue;
+
+/// Writes the value as compact JSON, with no whitespace at all.
+///
+/// ```
+/// let value = json::parse(r#"{ "a": [1, 2.5, "x"] }"#).unwrap();
+/// assert_eq!(json::to_string(&value), r#"{"a":[1,2.5,"x"]}"#);
+/// ```
+pub fn to_string(value: &JsonValue) -> String {
+    let mut out = String::new();
+    write_value(&mut out, value, None);
+    out
+}
+
+/// Writes the value with each array item and object member on its own
+/// line, indented two spaces per level.
+///
+/// ```
+/// let value = json::parse(r#"{"a": [1, 2], "b": {}}"#).unwrap();
+/// assert_eq!(
+///     json::to_string_pretty(&value),
+///     "{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": {}\n}"
+/// );
+/// ```
+pub fn to_string_pretty(value: &JsonValue) -> String {
+    let mut out = String::new();
+    write_value(&mut out, value, Some(0));
+    out
+}
+
+// `indent` is the current level when pretty-printing, or None for compact
+// output - one writer for both, so they can't disagree about anything but
+// whitespace.
+fn write_value(out: &mut String, value: &JsonValue, indent: Option<usize>) {
+    match value {
+        JsonValue::Null => out.push_str("null"),
+        JsonValue::Bool(b) => write!(out, "{b}").unwrap(),
+        JsonValue::Number(n) => write_number(out, *n),
+        JsonValue::String(s) => write_string(out, s),
+        JsonValue::Array(items) => {
+            write_container(out, ('[', ']'), items, indent, |out, item, indent| {
+                write_value(out, item, indent)
+            })
+        }
+        JsonValue::Object(members) => write_container(
+            out,
+            ('{', '}'),
+            members,
+            indent,
+            |out, (key, value), indent| {
+                write_string(out, key);
+                out.push(':');
+                if indent.is_some() {
+                    out.push(' ');
+                }
+                write_value(out, value, indent);
+            },
+        ),
+    }
+}
+
+fn write_container<T>(
+    out: &mut String,
+    (open, close): (char, char),
+    elements: &[T],
+    indent: Option<usize>,
+    write_element: impl Fn(&mut String, &T, Option<usize>),
+) {
+    out.push(open);
+    if elements.is_empty() {
+        out.push(close);
+        return;
+    }
+    let inner = indent.map(|level| level + 1);
+    for (i, element) in elements.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        new_line(out, inner);
+        write_element(out, element, inner);
+    }
+    new_line(out, indent);
+    out.push(close);
+}
+
+fn new_line(out: &mut String, indent: Option<usize>) {
+    if let Some(level) = indent {
+        out.push('\n');
+        out.push_str(&"  ".repeat(level));
+    }
+}
+
+// Whole numbers print without a fraction - `1`, not `1.0` - and `-0` keeps
+// its sign. Anything else uses Debug, which is the shortest text that
+// parses back to the same f64 and switches to an exponent for very big or
+// small values (`1e300` rather than 301 digits). JSON has no NaN or
+// infinity, so those - which `parse` never makes - become null, as in
+// JavaScript's JSON.stringify.
+fn write_number(out: &mut String, n: f64) {
+    if !n.is_finite() {
+        out.push_str("null");
+    } else if n.fract() == 0.0 && n.abs() < 1e16 {
+        write!(out, "{n}").unwrap();
+    } else {
+        write!(out, "{n:?}").unwrap();
+    }
+}
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            '\0'..='\u{1f}' => write!(out, "\\u{:04x}", ch as u32).unwrap(),
+            // Everything else, non-ASCII included, is fine as it is in UTF-8.
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use JsonValue::*;
+
+    #[test]
+    fn scalars() {
+        assert_eq!(to_string(&Null), "null");
+        assert_eq!(to_string(&Bool(false)), "false");
+        assert_eq!(to_string(&String("hi".to_string())), r#""hi""#);
+    }
+
+    #[test]
+    fn numbers() {
+        let cases: &[(f64, &str)] = &[
+            (0.0, "0"),
+            (-0.0, "-0"),
+            (42.0, "42"),
+            (-7.0, "-7"),
+            (2.5, "2.5"),
+            (0.1, "0.1"),
+            (1e10, "10000000000"),
+            (1e16, "1e16"),
+            (1e300, "1e300"),
+            (1.5e-7, "1.5e-7"),
+            (f64::NAN, "null"),
+            (f64::INFINITY, "null"),
+        ];
+        for &(n, expected) in cases {
+            assert_eq!(to_string(&Number(n)), expected, "{n}");
+        }
+    }
+
+    #[test]
+    fn escapes_strings() {
+        let s = "quote \" backslash \\ newline \n tab \t bell \u{7} é /";
+        assert_eq!(
+            to_string(&String(s.to_string())),
+            r#""quote \" backslash \\ newline \n tab \t bell \u0007 é /""#
+        );
+    }
+
+    #[test]
+    fn compact_containers() {
+        let value = Object(vec![
+            ("list".to_string(), Array(vec![Number(1.0), Null])),
+            ("empty".to_string(), Object(vec![])),
+        ]);
+        assert_eq!(to_string(&value), r#"{"list":[1,null],"empty":{}}"#);
+        assert_eq!(value.to_string(), to_string(&value));
+    }
+
+    #[test]
+    fn pretty_containers() {
+        let value = Array(vec![
+            Object(vec![("a".to_string(), Array(vec![]))]),
+            Number(2.0),
+        ]);
+        assert_eq!(
+            to_string_pretty(&value),
+            "[\n  {\n    \"a\": []\n  },\n  2\n]"
+        );
+        assert_eq!(to_string_pretty(&Null), "null");
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt;
+
+// Every JSON number is an f64, as in JavaScript. Object members stay in the
+// order they were written - a Vec of pairs rather than a map - so printing
+// a parsed document gives back the same order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// The value of an object's member, or None if this isn't an object or
+    /// has no such key. With duplicate keys the last one wins, as in most
+    /// parsers.
+    ///
+    /// ```
+    /// let value = json::parse(r#"{"a": 1, "b": 2}"#).unwrap();
+    /// assert_eq!(value.get("b"), Some(&json::JsonValue::Number(2.0)));
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(members) => members
+                .iter()
+                .rev()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+// Compact JSON, like `json::to_string`.
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", crate::to_string(self))
+    }
+}
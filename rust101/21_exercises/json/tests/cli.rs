@@ -0,0 +1,38 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn json(stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_json"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn pretty_prints_stdin() {
+    let output = json(r#"{"a":[1,true],"b":"x"}"#);
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "{\n  \"a\": [\n    1,\n    true\n  ],\n  \"b\": \"x\"\n}\n"
+    );
+}
+
+#[test]
+fn reports_where_the_error_is() {
+    let output = json("{\n  \"a\": [1, 2,]\n}\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "invalid JSON: unexpected ']' at line 2, column 14\n"
+    );
+}
@@ -0,0 +1,34 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum KvsError {
+    Io {
+        path: PathBuf,
+        err: io::Error,
+    },
+    // A record before the last one doesn't parse. That can't be a write
+    // cut short, so the log is left alone for someone to look at.
+    Corrupt {
+        path: PathBuf,
+        line: usize,
+        reason: String,
+    },
+    KeyNotFound(String),
+}
+
+impl fmt::Display for KvsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KvsError::Io { path, err } => write!(f, "{}: {err}", path.display()),
+            KvsError::Corrupt { path, line, reason } => {
+                write!(f, "{} line {line} is corrupt: {reason}", path.display())
+            }
+            KvsError::KeyNotFound(key) => write!(f, "key not found: {key:?}"),
+        }
+    }
+}
+
+impl Error for KvsError {}
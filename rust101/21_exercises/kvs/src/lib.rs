@@ -0,0 +1,139 @@
+/* Things learned during this exercise:
+   * An append-only log is about the simplest way to make changes durable:
+   never change what's been written, only add to the end. Reading the
+   store back is replaying every change in order, and the state is
+   whatever the last one for each key said.
+   * The catch is that the log never shrinks. Compaction rewrites it with
+   one record per live key; writing that to a new file and renaming it over
+   the old means there's never a moment without a whole log on disk.
+   * `OpenOptions::new().append(true)` makes every write go on the end,
+   wherever anything else has moved the file to.
+   * One JSON object per line makes the log easy to read by hand, and
+   because JSON escapes newlines in strings, a newline always ends a
+   record. A last line without one was being written when the program
+   stopped, so it's dropped - and cut off the file with `set_len`, or the
+   next record would be stuck on the end of it.
+   * `#[serde(tag = "op")]` writes an enum as an object with the variant in
+   a field, `{"op":"set",...}`, instead of wrapping it in another object.
+   * `slice::split_inclusive` splits bytes but keeps the separator, which
+   is what tells a finished record from a cut-off one.
+   * See https://doc.rust-lang.org/std/fs/struct.OpenOptions.html and
+   https://serde.rs/enum-representations.html
+*/
+mod error;
+mod log;
+mod store;
+
+pub use error::KvsError;
+pub use log::{read_log, Record, Replay};
+pub use store::{KvStore, DEFAULT_COMPACTION_THRESHOLD};
+
+use std::path::PathBuf;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Set { key: String, value: String },
+    Get { key: String },
+    Remove { key: String },
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub file: PathBuf,
+    pub command: Command,
+}
+
+fn key(arg: Option<String>, command: &str) -> Result<String, String> {
+    arg.ok_or(format!("{command} needs a key"))
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut file = PathBuf::from("kvs.log");
+        let mut name = args.next();
+        if name.as_deref() == Some("--file") {
+            file = args.next().ok_or("--file needs a path")?.into();
+            name = args.next();
+        }
+        let name = name.ok_or("which command? set, get or rm")?;
+        let command = match name.as_str() {
+            "set" => {
+                let key = key(args.next(), "set")?;
+                let value = args.next().ok_or("set needs a value")?;
+                Command::Set { key, value }
+            }
+            "get" => Command::Get {
+                key: key(args.next(), "get")?,
+            },
+            "rm" => Command::Remove {
+                key: key(args.next(), "rm")?,
+            },
+            _ => return Err(format!("unknown command: {name:?}")),
+        };
+        if let Some(extra) = args.next() {
+            return Err(format!("unexpected argument: {extra:?}"));
+        }
+        Ok(Config { file, command })
+    }
+}
+
+// Runs the command, returning what to print: the value for `get`, and
+// nothing otherwise. Getting or removing a missing key is an error.
+pub fn run(command: Command, store: &mut KvStore) -> Result<Option<String>, KvsError> {
+    match command {
+        Command::Set { key, value } => store.set(key, value).map(|()| None),
+        Command::Get { key } => match store.get(&key) {
+            Some(value) => Ok(Some(value.to_string())),
+            None => Err(KvsError::KeyNotFound(key)),
+        },
+        Command::Remove { key } => store.remove(&key).map(|()| None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["kvs".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&["set", "a", "1"])),
+            Ok(Config {
+                file: PathBuf::from("kvs.log"),
+                command: Command::Set {
+                    key: "a".to_string(),
+                    value: "1".to_string()
+                }
+            })
+        );
+        assert_eq!(
+            Config::build(args(&["--file", "db.log", "rm", "a"])),
+            Ok(Config {
+                file: PathBuf::from("db.log"),
+                command: Command::Remove {
+                    key: "a".to_string()
+                }
+            })
+        );
+        for bad in [
+            &[][..],
+            &["set", "a"],
+            &["get"],
+            &["get", "a", "b"],
+            &["--file"],
+            &["list"],
+        ] {
+            assert!(Config::build(args(bad)).is_err(), "{bad:?}");
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use serde::{Deserialize, Serialize};
+
+// One line of the log: `{"op":"set","key":"a","value":"1"}` or
+// `{"op":"remove","key":"a"}`. JSON escapes newlines inside strings, so a
+// record is always exactly one line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum Record {
+    Set { key: String, value: String },
+    Remove { key: String },
+}
+
+impl Record {
+    // The record as written to the log, newline and all.
+    pub fn to_line(&self) -> Vec<u8> {
+        let mut line = serde_json::to_vec(self).expect("a record always serializes");
+        line.push(b'\n');
+        line
+    }
+}
+
+// What reading a log found: the records, and how many bytes of it they
+// take up. Anything after that is a record that was being written when the
+// program stopped.
+#[derive(Debug, PartialEq)]
+pub struct Replay {
+    pub records: Vec<Record>,
+    pub valid_len: usize,
+}
+
+// Reads every complete record. Each one is written with its newline in a
+// single write, so a crash can only leave the last line unfinished - and
+// one without a newline is ignored. A finished line that doesn't parse is
+// a different matter, and gives its line number and the reason.
+pub fn read_log(bytes: &[u8]) -> Result<Replay, (usize, String)> {
+    let mut records = Vec::new();
+    let mut valid_len = 0;
+    for (index, line) in bytes.split_inclusive(|&byte| byte == b'\n').enumerate() {
+        let Some(json) = line.strip_suffix(b"\n") else {
+            break;
+        };
+        let record = serde_json::from_slice(json).map_err(|err| (index + 1, err.to_string()))?;
+        records.push(record);
+        valid_len += line.len();
+    }
+    Ok(Replay { records, valid_len })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(key: &str, value: &str) -> Record {
+        Record::Set {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn records_are_single_lines() {
+        assert_eq!(
+            set("a", "1").to_line(),
+            b"{\"op\":\"set\",\"key\":\"a\",\"value\":\"1\"}\n"
+        );
+        let line = set("multi\nline", "x\ny").to_line();
+        assert_eq!(line.iter().filter(|&&byte| byte == b'\n').count(), 1);
+    }
+
+    #[test]
+    fn reads_records_back() {
+        let records = vec![
+            set("a", "1"),
+            Record::Remove {
+                key: "a".to_string(),
+            },
+        ];
+        let bytes: Vec<u8> = records.iter().flat_map(Record::to_line).collect();
+        assert_eq!(
+            read_log(&bytes),
+            Ok(Replay {
+                records,
+                valid_len: bytes.len()
+            })
+        );
+        assert_eq!(
+            read_log(b""),
+            Ok(Replay {
+                records: Vec::new(),
+                valid_len: 0
+            })
+        );
+    }
+
+    #[test]
+    fn ignores_an_unfinished_last_record() {
+        let mut bytes = set("a", "1").to_line();
+        let whole = bytes.len();
+        bytes.extend_from_slice(b"{\"op\":\"set\",\"ke");
+        assert_eq!(
+            read_log(&bytes),
+            Ok(Replay {
+                records: vec![set("a", "1")],
+                valid_len: whole
+            })
+        );
+    }
+
+    #[test]
+    fn reports_a_bad_finished_record() {
+        let mut bytes = set("a", "1").to_line();
+        bytes.extend_from_slice(b"not json\n");
+        bytes.extend(set("b", "2").to_line());
+        let (line, _) = read_log(&bytes).unwrap_err();
+        assert_eq!(line, 2);
+    }
+}
@@ -0,0 +1,31 @@
+use std::env;
+use std::process;
+
+use kvs::{Config, KvStore};
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: kvs [--file PATH] set KEY VALUE | get KEY | rm KEY");
+        process::exit(2);
+    });
+
+    let result = KvStore::open(&config.file).and_then(|mut store| {
+        if store.truncated_bytes() > 0 {
+            eprintln!(
+                "warning: dropped a partly written record ({} bytes) from the end of {}",
+                store.truncated_bytes(),
+                config.file.display()
+            );
+        }
+        kvs::run(config.command, &mut store)
+    });
+    match result {
+        Ok(Some(value)) => println!("{value}"),
+        Ok(None) => {}
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
+}
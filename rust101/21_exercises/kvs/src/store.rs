@@ -0,0 +1,371 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::error::KvsError;
+use crate::log::{read_log, Record};
+
+// How many records can be dead - overwritten, removed, or removals
+// themselves - before the log is rewritten without them.
+pub const DEFAULT_COMPACTION_THRESHOLD: usize = 1000;
+
+// A string-to-string map kept in a log file. Every change is appended to
+// the log as it's made, so nothing is lost if the program stops; opening
+// the store replays the log to rebuild the map in memory.
+//
+// The log only grows, so every overwrite and removal leaves dead records
+// behind. Once there are more than the threshold, the store compacts: it
+// writes a new log with one record per live key and swaps it in.
+#[derive(Debug)]
+pub struct KvStore {
+    path: PathBuf,
+    // Opened for appending, so every write goes on the end.
+    log: File,
+    index: BTreeMap<String, String>,
+    dead: usize,
+    compaction_threshold: usize,
+    truncated: usize,
+}
+
+impl KvStore {
+    // A log that doesn't exist yet is an empty store. If the last record
+    // was only partly written, it's cut off the end of the file: the write
+    // it belonged to never finished, so it never happened.
+    pub fn open(path: &Path) -> Result<KvStore, KvsError> {
+        let io_error = |err| KvsError::Io {
+            path: path.to_path_buf(),
+            err,
+        };
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(io_error(err)),
+        };
+        let replay = read_log(&bytes).map_err(|(line, reason)| KvsError::Corrupt {
+            path: path.to_path_buf(),
+            line,
+            reason,
+        })?;
+
+        let mut index = BTreeMap::new();
+        let mut dead = 0;
+        for record in replay.records {
+            match record {
+                Record::Set { key, value } => {
+                    if index.insert(key, value).is_some() {
+                        dead += 1;
+                    }
+                }
+                // The set it undoes and the removal itself.
+                Record::Remove { key } => {
+                    if index.remove(&key).is_some() {
+                        dead += 2;
+                    } else {
+                        dead += 1;
+                    }
+                }
+            }
+        }
+
+        let log = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(io_error)?;
+        let truncated = bytes.len() - replay.valid_len;
+        if truncated > 0 {
+            // Otherwise the next record would be glued on to the broken
+            // one, and the line would be lost along with it.
+            log.set_len(replay.valid_len as u64).map_err(io_error)?;
+        }
+        let mut store = KvStore {
+            path: path.to_path_buf(),
+            log,
+            index,
+            dead,
+            compaction_threshold: DEFAULT_COMPACTION_THRESHOLD,
+            truncated,
+        };
+        store.maybe_compact()?;
+        Ok(store)
+    }
+
+    pub fn set_compaction_threshold(&mut self, threshold: usize) {
+        self.compaction_threshold = threshold;
+    }
+
+    // How many bytes of partly written record `open` cut off the end.
+    pub fn truncated_bytes(&self) -> usize {
+        self.truncated
+    }
+
+    // How many records in the log no longer count for anything.
+    pub fn dead_records(&self) -> usize {
+        self.dead
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.index.get(key).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    pub fn set(&mut self, key: String, value: String) -> Result<(), KvsError> {
+        self.append(&Record::Set {
+            key: key.clone(),
+            value: value.clone(),
+        })?;
+        if self.index.insert(key, value).is_some() {
+            self.dead += 1;
+        }
+        self.maybe_compact()
+    }
+
+    pub fn remove(&mut self, key: &str) -> Result<(), KvsError> {
+        if !self.index.contains_key(key) {
+            return Err(KvsError::KeyNotFound(key.to_string()));
+        }
+        self.append(&Record::Remove {
+            key: key.to_string(),
+        })?;
+        self.index.remove(key);
+        self.dead += 2;
+        self.maybe_compact()
+    }
+
+    // Rewrites the log with only the live records. The new log goes in a
+    // file of its own, which is renamed over the old one once it's
+    // complete, so a crash part way through leaves the old log as it was.
+    pub fn compact(&mut self) -> Result<(), KvsError> {
+        let compacted = self.path.with_extension("compacting");
+        let io_error = |err| KvsError::Io {
+            path: compacted.clone(),
+            err,
+        };
+        let mut file = File::create(&compacted).map_err(io_error)?;
+        for (key, value) in &self.index {
+            let record = Record::Set {
+                key: key.clone(),
+                value: value.clone(),
+            };
+            file.write_all(&record.to_line()).map_err(io_error)?;
+        }
+        file.sync_all().map_err(io_error)?;
+        fs::rename(&compacted, &self.path).map_err(io_error)?;
+
+        self.log = OpenOptions::new()
+            .append(true)
+            .open(&self.path)
+            .map_err(|err| KvsError::Io {
+                path: self.path.clone(),
+                err,
+            })?;
+        self.dead = 0;
+        Ok(())
+    }
+
+    fn maybe_compact(&mut self) -> Result<(), KvsError> {
+        if self.dead > self.compaction_threshold {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    // One write per record, so a crash can't leave a newline without the
+    // record before it.
+    fn append(&mut self, record: &Record) -> Result<(), KvsError> {
+        self.log
+            .write_all(&record.to_line())
+            .map_err(|err| KvsError::Io {
+                path: self.path.clone(),
+                err,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A fresh directory under the system temp dir, removed again when the
+    // test is over.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let dir = std::env::temp_dir().join(format!("kvs_{name}_{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+
+        fn file(&self) -> PathBuf {
+            self.0.join("kvs.log")
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn set(store: &mut KvStore, key: &str, value: &str) {
+        store.set(key.to_string(), value.to_string()).unwrap();
+    }
+
+    fn file_len(path: &Path) -> u64 {
+        fs::metadata(path).unwrap().len()
+    }
+
+    #[test]
+    fn reads_back_after_reopening() {
+        let dir = TempDir::new("reopen");
+        {
+            let mut store = KvStore::open(&dir.file()).unwrap();
+            assert!(store.is_empty());
+            set(&mut store, "name", "Ferris");
+            set(&mut store, "multi\nline", "still\none record");
+            assert_eq!(store.get("name"), Some("Ferris"));
+        }
+        let store = KvStore::open(&dir.file()).unwrap();
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.get("name"), Some("Ferris"));
+        assert_eq!(store.get("multi\nline"), Some("still\none record"));
+        assert_eq!(store.get("nope"), None);
+        assert_eq!(store.truncated_bytes(), 0);
+    }
+
+    #[test]
+    fn later_sets_win() {
+        let dir = TempDir::new("overwrite");
+        let mut store = KvStore::open(&dir.file()).unwrap();
+        set(&mut store, "colour", "red");
+        set(&mut store, "colour", "green");
+        set(&mut store, "colour", "blue");
+        assert_eq!(store.get("colour"), Some("blue"));
+        assert_eq!(store.dead_records(), 2);
+        drop(store);
+        let store = KvStore::open(&dir.file()).unwrap();
+        assert_eq!(store.get("colour"), Some("blue"));
+        assert_eq!(store.dead_records(), 2);
+    }
+
+    #[test]
+    fn removes() {
+        let dir = TempDir::new("remove");
+        let mut store = KvStore::open(&dir.file()).unwrap();
+        set(&mut store, "a", "1");
+        set(&mut store, "b", "2");
+        store.remove("a").unwrap();
+        assert_eq!(store.get("a"), None);
+        assert!(matches!(
+            store.remove("a"),
+            Err(KvsError::KeyNotFound(key)) if key == "a"
+        ));
+        drop(store);
+        let mut store = KvStore::open(&dir.file()).unwrap();
+        assert_eq!(store.get("a"), None);
+        assert_eq!(store.get("b"), Some("2"));
+        assert_eq!(store.dead_records(), 2);
+        // Removed keys can come back.
+        set(&mut store, "a", "3");
+        assert_eq!(store.get("a"), Some("3"));
+    }
+
+    #[test]
+    fn compaction_shrinks_the_log_and_keeps_the_data() {
+        let dir = TempDir::new("compact");
+        let mut store = KvStore::open(&dir.file()).unwrap();
+        store.set_compaction_threshold(usize::MAX);
+        for round in 0..100 {
+            for key in ["a", "b", "c"] {
+                set(&mut store, key, &format!("{key}{round}"));
+            }
+        }
+        set(&mut store, "gone", "soon");
+        store.remove("gone").unwrap();
+        let before = file_len(&dir.file());
+
+        store.compact().unwrap();
+        assert!(file_len(&dir.file()) < before / 50);
+        assert_eq!(store.dead_records(), 0);
+        assert_eq!(store.get("a"), Some("a99"));
+        // Still appending to the new log.
+        set(&mut store, "d", "new");
+        drop(store);
+
+        let store = KvStore::open(&dir.file()).unwrap();
+        assert_eq!(store.len(), 4);
+        assert_eq!(store.get("c"), Some("c99"));
+        assert_eq!(store.get("d"), Some("new"));
+        assert_eq!(store.get("gone"), None);
+        assert!(!dir.0.join("kvs.compacting").exists());
+    }
+
+    #[test]
+    fn compacts_by_itself_past_the_threshold() {
+        let dir = TempDir::new("threshold");
+        let mut store = KvStore::open(&dir.file()).unwrap();
+        store.set_compaction_threshold(10);
+        for value in 0..10 {
+            set(&mut store, "key", &value.to_string());
+        }
+        assert_eq!(store.dead_records(), 9);
+        let before = file_len(&dir.file());
+        set(&mut store, "key", "10");
+        set(&mut store, "key", "11");
+        assert_eq!(store.dead_records(), 0);
+        assert!(file_len(&dir.file()) < before);
+        drop(store);
+        assert_eq!(KvStore::open(&dir.file()).unwrap().get("key"), Some("11"));
+    }
+
+    #[test]
+    fn recovers_from_a_record_cut_short() {
+        let dir = TempDir::new("truncated");
+        let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/truncated.log");
+        fs::copy(fixture, dir.file()).unwrap();
+        let full = file_len(&dir.file());
+
+        let mut store = KvStore::open(&dir.file()).unwrap();
+        assert_eq!(store.get("language"), Some("Rust"));
+        assert_eq!(store.get("mascot"), Some("Ferris"));
+        assert_eq!(store.get("edition"), None);
+        assert_eq!(store.get("year"), None);
+        assert!(store.truncated_bytes() > 0);
+        assert_eq!(file_len(&dir.file()), full - store.truncated_bytes() as u64);
+
+        // New records go after the last good one, not on the end of the
+        // broken one.
+        set(&mut store, "year", "2015");
+        drop(store);
+        let store = KvStore::open(&dir.file()).unwrap();
+        assert_eq!(store.truncated_bytes(), 0);
+        assert_eq!(store.get("year"), Some("2015"));
+        assert_eq!(store.get("mascot"), Some("Ferris"));
+    }
+
+    #[test]
+    fn refuses_a_corrupt_log() {
+        let dir = TempDir::new("corrupt");
+        fs::write(
+            dir.file(),
+            "{\"op\":\"set\",\"key\":\"a\",\"value\":\"1\"}\n{\"op\":\"set\"}\n",
+        )
+        .unwrap();
+        let before = fs::read(dir.file()).unwrap();
+        assert!(matches!(
+            KvStore::open(&dir.file()),
+            Err(KvsError::Corrupt { line: 2, .. })
+        ));
+        assert_eq!(fs::read(dir.file()).unwrap(), before);
+    }
+}
@@ -0,0 +1,81 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+// A directory of its own for each test, removed when it's over.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> TempDir {
+        let dir = std::env::temp_dir().join(format!("kvs_cli_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn kvs(file: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_kvs"))
+        .arg("--file")
+        .arg(file)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+// Runs a command that should work, and returns what it printed.
+fn ok(file: &Path, args: &[&str]) -> String {
+    let output = kvs(file, args);
+    assert_eq!(output.status.code(), Some(0), "{args:?}: {output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn a_session() {
+    let dir = TempDir::new("session");
+    let file = dir.0.join("kvs.log");
+
+    assert_eq!(ok(&file, &["set", "greeting", "hello world"]), "");
+    assert_eq!(ok(&file, &["get", "greeting"]), "hello world\n");
+    assert_eq!(ok(&file, &["set", "greeting", "hi"]), "");
+    assert_eq!(ok(&file, &["get", "greeting"]), "hi\n");
+    assert_eq!(ok(&file, &["rm", "greeting"]), "");
+
+    let output = kvs(&file, &["get", "greeting"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stderr(&output), "error: key not found: \"greeting\"\n");
+    let output = kvs(&file, &["rm", "greeting"]);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn warns_about_a_record_cut_short() {
+    let dir = TempDir::new("truncated");
+    let file = dir.0.join("kvs.log");
+    fs::copy("tests/data/truncated.log", &file).unwrap();
+
+    let output = kvs(&file, &["get", "mascot"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "Ferris\n");
+    assert!(stderr(&output).starts_with("warning: dropped a partly written record (28 bytes)"));
+    // It's gone now, so there's nothing to warn about the second time.
+    assert_eq!(stderr(&kvs(&file, &["get", "mascot"])), "");
+}
+
+#[test]
+fn bad_arguments_are_usage_errors() {
+    let dir = TempDir::new("usage");
+    let output = kvs(&dir.0.join("kvs.log"), &["set", "only-a-key"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("Usage: kvs"));
+}
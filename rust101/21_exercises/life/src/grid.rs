@@ -0,0 +1,248 @@
+use std::fmt;
+
+use crate::pattern::Pattern;
+
+// What's past the edge of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Boundary {
+    // The edges join up, left to right and top to bottom, like a torus - a
+    // glider leaving one side comes back on the other.
+    #[default]
+    Wrap,
+    // Nothing: cells past the edge are always dead.
+    Dead,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    width: usize,
+    height: usize,
+    boundary: Boundary,
+    // Row by row: (x, y) is at y * width + x.
+    cells: Vec<bool>,
+}
+
+impl Grid {
+    // An empty grid.
+    //
+    // # Panics
+    //
+    // If either side is zero.
+    pub fn new(width: usize, height: usize, boundary: Boundary) -> Grid {
+        assert!(width > 0 && height > 0, "a grid needs at least one cell");
+        Grid {
+            width,
+            height,
+            boundary,
+            cells: vec![false; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_alive(&self, x: usize, y: usize) -> bool {
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, alive: bool) {
+        self.cells[y * self.width + x] = alive;
+    }
+
+    pub fn population(&self) -> usize {
+        self.cells.iter().filter(|&&alive| alive).count()
+    }
+
+    // Brings the live cells of `pattern` to life with its top left corner
+    // at (x, y). Cells that land past the edge wrap round or are lost,
+    // depending on the boundary.
+    pub fn place(&mut self, pattern: &Pattern, x: usize, y: usize) {
+        for &(dx, dy) in &pattern.live {
+            let (px, py) = (x + dx, y + dy);
+            match self.boundary {
+                Boundary::Wrap => self.set(px % self.width, py % self.height, true),
+                Boundary::Dead if px < self.width && py < self.height => self.set(px, py, true),
+                Boundary::Dead => {}
+            }
+        }
+    }
+
+    // The cell at (x + dx, y + dy), where that might be past the edge.
+    fn is_alive_at(&self, x: usize, y: usize, dx: isize, dy: isize) -> bool {
+        let (width, height) = (self.width as isize, self.height as isize);
+        let (mut nx, mut ny) = (x as isize + dx, y as isize + dy);
+        match self.boundary {
+            Boundary::Wrap => {
+                nx = nx.rem_euclid(width);
+                ny = ny.rem_euclid(height);
+            }
+            Boundary::Dead => {
+                if !(0..width).contains(&nx) || !(0..height).contains(&ny) {
+                    return false;
+                }
+            }
+        }
+        self.is_alive(nx as usize, ny as usize)
+    }
+
+    pub fn live_neighbours(&self, x: usize, y: usize) -> usize {
+        let mut count = 0;
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if (dx, dy) != (0, 0) && self.is_alive_at(x, y, dx, dy) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    // The next generation. A live cell with two or three live neighbours
+    // lives on, a dead one with exactly three comes to life, and every
+    // other cell is dead. Every cell changes at once, so this reads the
+    // old grid and builds a new one rather than updating in place.
+    pub fn step(&self) -> Grid {
+        let mut next = Grid::new(self.width, self.height, self.boundary);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let alive = matches!(
+                    (self.is_alive(x, y), self.live_neighbours(x, y)),
+                    (true, 2) | (_, 3)
+                );
+                next.set(x, y, alive);
+            }
+        }
+        next
+    }
+}
+
+// The same plaintext format `parse_plaintext` reads, without comments.
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.cells.chunks(self.width) {
+            let line: String = row
+                .iter()
+                .map(|&alive| if alive { 'O' } else { '.' })
+                .collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pattern::{builtin, parse_plaintext, Pattern};
+
+    fn grid_with(pattern: &Pattern, size: usize, boundary: Boundary, at: (usize, usize)) -> Grid {
+        let mut grid = Grid::new(size, size, boundary);
+        grid.place(pattern, at.0, at.1);
+        grid
+    }
+
+    fn steps(grid: &Grid, n: usize) -> Grid {
+        (0..n).fold(grid.clone(), |grid, _| grid.step())
+    }
+
+    #[test]
+    fn the_blinker_has_period_two() {
+        let blinker = builtin("blinker").unwrap();
+        for boundary in [Boundary::Wrap, Boundary::Dead] {
+            let grid = grid_with(&blinker, 5, boundary, (1, 2));
+            let flipped = grid.step();
+            assert_eq!(flipped.to_string(), ".....\n..O..\n..O..\n..O..\n.....\n");
+            assert_ne!(flipped, grid);
+            assert_eq!(flipped.step(), grid);
+        }
+    }
+
+    #[test]
+    fn the_glider_moves_diagonally_and_wraps() {
+        let glider = builtin("glider").unwrap();
+        // Starting in the corner, so after a few moves it's part way across
+        // every edge.
+        for start in [0, 3, 6, 7] {
+            let grid = grid_with(&glider, 8, Boundary::Wrap, (start, start));
+            let moved = grid_with(&glider, 8, Boundary::Wrap, (start + 1, start + 1));
+            assert_eq!(steps(&grid, 4), moved, "from {start}");
+            assert_eq!(steps(&grid, 32), grid);
+        }
+    }
+
+    #[test]
+    fn the_glider_dies_against_a_dead_border() {
+        let glider = builtin("glider").unwrap();
+        let grid = grid_with(&glider, 6, Boundary::Dead, (0, 0));
+        // It turns into a block in the far corner, and stays there.
+        let end = steps(&grid, 40);
+        assert_eq!(end.population(), 4);
+        assert!(end.is_alive(4, 4) && end.is_alive(5, 5));
+        assert_eq!(end.step(), end);
+    }
+
+    #[test]
+    fn still_lifes_do_not_change() {
+        let beehive = parse_plaintext(".OO.\nO..O\n.OO.").unwrap();
+        let boat = parse_plaintext("OO.\nO.O\n.O.").unwrap();
+        for pattern in [builtin("block").unwrap(), beehive, boat] {
+            for boundary in [Boundary::Wrap, Boundary::Dead] {
+                let grid = grid_with(&pattern, 6, boundary, (1, 1));
+                assert_eq!(grid.step(), grid, "{grid}");
+            }
+        }
+    }
+
+    #[test]
+    fn lonely_and_crowded_cells_die() {
+        let mut grid = Grid::new(5, 5, Boundary::Dead);
+        grid.set(0, 0, true);
+        assert_eq!(grid.step().population(), 0);
+        // A plus sign: the middle has four neighbours, so it dies, and
+        // each arm has three, so the corners of the square come to life.
+        let plus = parse_plaintext(".O.\nOOO\n.O.").unwrap();
+        let next = grid_with(&plus, 5, Boundary::Dead, (1, 1)).step();
+        assert_eq!(next.to_string(), ".....\n.OOO.\n.O.O.\n.OOO.\n.....\n");
+    }
+
+    #[test]
+    fn counts_neighbours_across_the_edges() {
+        let mut wrapped = Grid::new(4, 4, Boundary::Wrap);
+        wrapped.set(3, 3, true);
+        wrapped.set(0, 3, true);
+        assert_eq!(wrapped.live_neighbours(0, 0), 2);
+        let mut dead = Grid::new(4, 4, Boundary::Dead);
+        dead.set(3, 3, true);
+        assert_eq!(dead.live_neighbours(0, 0), 0);
+        assert_eq!(dead.live_neighbours(2, 2), 1);
+    }
+
+    #[test]
+    fn the_gun_fires_a_glider_every_30_generations() {
+        let gun = builtin("gosper-gun").unwrap();
+        let mut grid = Grid::new(60, 30, Boundary::Dead);
+        grid.place(&gun, 1, 1);
+        let initial = grid.population();
+        assert_eq!(steps(&grid, 30).population(), initial + 5);
+        assert_eq!(steps(&grid, 60).population(), initial + 10);
+    }
+
+    #[test]
+    fn placing_off_the_edge_depends_on_the_boundary() {
+        let block = builtin("block").unwrap();
+        assert_eq!(grid_with(&block, 3, Boundary::Wrap, (2, 2)).population(), 4);
+        assert_eq!(grid_with(&block, 3, Boundary::Dead, (2, 2)).population(), 1);
+    }
+
+    #[test]
+    fn displays_as_plaintext() {
+        let glider = builtin("glider").unwrap();
+        let grid = grid_with(&glider, 3, Boundary::Wrap, (0, 0));
+        assert_eq!(parse_plaintext(&grid.to_string()), Ok(glider));
+    }
+}
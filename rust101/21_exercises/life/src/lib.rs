@@ -0,0 +1,258 @@
+/* Things learned during this exercise:
+   * Every cell in a generation changes at the same moment, so the next
+   generation has to be worked out from a copy of the old one. Updating
+   in place would let cells see neighbours that have already moved on.
+   * Keeping the rule (`step`) and the parser pure - no printing, no
+   sleeping - is what makes them easy to test. The animation is just a
+   loop around them.
+   * `rem_euclid` is the modulo that's never negative, so -1 wraps to the
+   last column rather than staying -1 as `%` would leave it.
+   * A 2D grid fits in a flat Vec with (x, y) at `y * width + x`, and
+   `chunks(width)` gives the rows back.
+   * ANSI escape codes move the terminal's cursor: "\x1b[2J" clears the
+   screen and "\x1b[H" goes back to the top left, so each frame draws over
+   the last.
+   * Writing to `impl Write` instead of straight to stdout lets the tests
+   collect the frames in a Vec<u8>.
+   * See https://conwaylife.com/wiki/Plaintext and
+   https://doc.rust-lang.org/std/primitive.isize.html#method.rem_euclid
+*/
+mod grid;
+mod pattern;
+
+pub use grid::{Boundary, Grid};
+pub use pattern::{builtin, parse_plaintext, ParseError, Pattern, BUILTINS};
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+// Clears the screen and puts the cursor back at the top left.
+pub const CLEAR: &str = "\x1b[2J\x1b[H";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Builtin(String),
+    File(PathBuf),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub source: Source,
+    pub width: usize,
+    pub height: usize,
+    pub generations: usize,
+    pub delay: Duration,
+    pub boundary: Boundary,
+}
+
+fn option_value<T: std::str::FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    option: &str,
+    what: &str,
+) -> Result<T, String> {
+    let value = args.next().ok_or(format!("{option} needs {what}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("{option} needs {what}, not {value:?}"))
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut config = Config {
+            source: Source::Builtin("glider".to_string()),
+            width: 40,
+            height: 20,
+            generations: 100,
+            delay: Duration::from_millis(100),
+            boundary: Boundary::Wrap,
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--pattern" => {
+                    let name: String = option_value(&mut args, "--pattern", "a name")?;
+                    if builtin(&name).is_none() {
+                        let names: Vec<&str> = BUILTINS.iter().map(|(name, _)| *name).collect();
+                        return Err(format!(
+                            "unknown pattern {name:?}; try {}",
+                            names.join(", ")
+                        ));
+                    }
+                    config.source = Source::Builtin(name);
+                }
+                "--file" => {
+                    config.source = Source::File(option_value(&mut args, "--file", "a path")?)
+                }
+                "--width" => config.width = option_value(&mut args, "--width", "a number")?,
+                "--height" => config.height = option_value(&mut args, "--height", "a number")?,
+                "--generations" => {
+                    config.generations = option_value(&mut args, "--generations", "a number")?
+                }
+                "--delay" => {
+                    let millis = option_value(&mut args, "--delay", "a number of milliseconds")?;
+                    config.delay = Duration::from_millis(millis);
+                }
+                "--dead-border" => config.boundary = Boundary::Dead,
+                _ => return Err(format!("unknown option: {arg:?}")),
+            }
+        }
+        if config.width == 0 || config.height == 0 {
+            return Err("the grid needs a width and height of at least 1".to_string());
+        }
+        Ok(config)
+    }
+}
+
+// The pattern doesn't fit on the grid.
+#[derive(Debug, PartialEq)]
+pub struct TooBig {
+    pub pattern: (usize, usize),
+    pub grid: (usize, usize),
+}
+
+impl fmt::Display for TooBig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the pattern is {}x{}, too big for a {}x{} grid",
+            self.pattern.0, self.pattern.1, self.grid.0, self.grid.1
+        )
+    }
+}
+
+impl Error for TooBig {}
+
+// A grid of the configured size with `pattern` in the middle of it.
+pub fn starting_grid(config: &Config, pattern: &Pattern) -> Result<Grid, TooBig> {
+    if pattern.width > config.width || pattern.height > config.height {
+        return Err(TooBig {
+            pattern: (pattern.width, pattern.height),
+            grid: (config.width, config.height),
+        });
+    }
+    let mut grid = Grid::new(config.width, config.height, config.boundary);
+    grid.place(
+        pattern,
+        (config.width - pattern.width) / 2,
+        (config.height - pattern.height) / 2,
+    );
+    Ok(grid)
+}
+
+// One screenful: the grid, and which generation it is.
+pub fn frame(grid: &Grid, generation: usize) -> String {
+    format!(
+        "{CLEAR}{grid}Generation {generation}, population {}\n",
+        grid.population()
+    )
+}
+
+// Draws `grid` and the `generations` after it, waiting `delay` between
+// frames.
+pub fn animate(
+    mut grid: Grid,
+    generations: usize,
+    delay: Duration,
+    mut output: impl Write,
+) -> io::Result<()> {
+    for generation in 0..=generations {
+        if generation > 0 {
+            thread::sleep(delay);
+            grid = grid.step();
+        }
+        output.write_all(frame(&grid, generation).as_bytes())?;
+        // Stdout is line buffered, but a frame should appear all at once.
+        output.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["life".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    #[test]
+    fn builds_config() {
+        let config = Config::build(args(&[
+            "--pattern",
+            "gosper-gun",
+            "--width",
+            "50",
+            "--generations",
+            "3",
+            "--delay",
+            "0",
+            "--dead-border",
+        ]))
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                source: Source::Builtin("gosper-gun".to_string()),
+                width: 50,
+                height: 20,
+                generations: 3,
+                delay: Duration::ZERO,
+                boundary: Boundary::Dead,
+            }
+        );
+        assert_eq!(
+            Config::build(args(&["--file", "p.cells"])).unwrap().source,
+            Source::File(PathBuf::from("p.cells"))
+        );
+        for bad in [
+            &["--pattern", "spaceship"][..],
+            &["--width", "0"],
+            &["--delay", "soon"],
+            &["glider"],
+        ] {
+            assert!(Config::build(args(bad)).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn centres_the_pattern() {
+        let mut config = Config::build(args(&["--width", "5", "--height", "5"])).unwrap();
+        let blinker = builtin("blinker").unwrap();
+        let grid = starting_grid(&config, &blinker).unwrap();
+        assert_eq!(grid.to_string(), ".....\n.....\n.OOO.\n.....\n.....\n");
+        config.width = 2;
+        assert_eq!(
+            starting_grid(&config, &blinker).unwrap_err().to_string(),
+            "the pattern is 3x1, too big for a 2x5 grid"
+        );
+    }
+
+    #[test]
+    fn animates_each_generation() {
+        let blinker = builtin("blinker").unwrap();
+        let mut grid = Grid::new(3, 3, Boundary::Dead);
+        grid.place(&blinker, 0, 1);
+        let mut output = Vec::new();
+        animate(grid, 2, Duration::ZERO, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let frames: Vec<&str> = output.split(CLEAR).skip(1).collect();
+        assert_eq!(
+            frames,
+            [
+                "...\nOOO\n...\nGeneration 0, population 3\n",
+                ".O.\n.O.\n.O.\nGeneration 1, population 3\n",
+                "...\nOOO\n...\nGeneration 2, population 3\n",
+            ]
+        );
+        assert!(output.starts_with(CLEAR));
+    }
+}
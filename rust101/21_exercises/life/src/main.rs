@@ -0,0 +1,32 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::process;
+
+use life::{Config, Source};
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: life [--pattern NAME | --file PATH] [--width W] [--height H] [--generations N] [--delay MS] [--dead-border]");
+        process::exit(2);
+    });
+
+    let pattern = match &config.source {
+        Source::Builtin(name) => Ok(life::builtin(name).expect("checked by Config::build")),
+        Source::File(path) => fs::read_to_string(path)
+            .map_err(|err| err.to_string())
+            .and_then(|text| life::parse_plaintext(&text).map_err(|err| err.to_string()))
+            .map_err(|err| format!("{}: {err}", path.display())),
+    };
+    let grid = pattern
+        .and_then(|pattern| life::starting_grid(&config, &pattern).map_err(|err| err.to_string()));
+    let result = grid.and_then(|grid| {
+        life::animate(grid, config.generations, config.delay, io::stdout().lock())
+            .map_err(|err| err.to_string())
+    });
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
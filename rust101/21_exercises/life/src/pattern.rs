@@ -0,0 +1,221 @@
+use std::error::Error;
+use std::fmt;
+
+// Lines and columns count from 1, as an editor would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    // No rows at all, once comments are left out.
+    Empty,
+    InvalidCell {
+        ch: char,
+        line: usize,
+        column: usize,
+    },
+    // Every row has to be as wide as the first.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "the pattern has no rows"),
+            ParseError::InvalidCell { ch, line, column } => write!(
+                f,
+                "line {line}, column {column}: {ch:?} isn't a cell - use '.' or 'O'"
+            ),
+            ParseError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} is {found} cells wide, but the first row is {expected}"
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// A rectangle of cells to put on a grid, as the coordinates of the live
+// ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    pub width: usize,
+    pub height: usize,
+    // (x, y), row by row.
+    pub live: Vec<(usize, usize)>,
+}
+
+// Reads the plaintext format used by LifeWiki's .cells files: one row per
+// line, '.' for a dead cell and 'O' for a live one, with comment lines
+// starting with '!'.
+//
+//     !Name: Glider
+//     .O.
+//     ..O
+//     OOO
+pub fn parse_plaintext(text: &str) -> Result<Pattern, ParseError> {
+    let mut width = None;
+    let mut height = 0;
+    let mut live = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        if line.starts_with('!') {
+            continue;
+        }
+        let line_number = index + 1;
+        let row: Vec<char> = line.trim_end().chars().collect();
+        let expected = *width.get_or_insert(row.len());
+        if row.len() != expected {
+            return Err(ParseError::Ragged {
+                line: line_number,
+                expected,
+                found: row.len(),
+            });
+        }
+        for (x, &ch) in row.iter().enumerate() {
+            match ch {
+                'O' => live.push((x, height)),
+                '.' => {}
+                _ => {
+                    return Err(ParseError::InvalidCell {
+                        ch,
+                        line: line_number,
+                        column: x + 1,
+                    })
+                }
+            }
+        }
+        height += 1;
+    }
+    match width {
+        None | Some(0) => Err(ParseError::Empty),
+        Some(width) => Ok(Pattern {
+            width,
+            height,
+            live,
+        }),
+    }
+}
+
+pub const GLIDER: &str = "\
+!Name: Glider
+.O.
+..O
+OOO
+";
+
+pub const BLINKER: &str = "\
+!Name: Blinker
+OOO
+";
+
+pub const BLOCK: &str = "\
+!Name: Block
+OO
+OO
+";
+
+pub const GOSPER_GLIDER_GUN: &str = "\
+!Name: Gosper glider gun
+........................O...........
+......................O.O...........
+............OO......OO............OO
+...........O...O....OO............OO
+OO........O.....O...OO..............
+OO........O...O.OO....O.O...........
+..........O.....O.......O...........
+...........O...O....................
+............OO......................
+";
+
+pub const BUILTINS: [(&str, &str); 4] = [
+    ("glider", GLIDER),
+    ("blinker", BLINKER),
+    ("block", BLOCK),
+    ("gosper-gun", GOSPER_GLIDER_GUN),
+];
+
+pub fn builtin(name: &str) -> Option<Pattern> {
+    let (_, text) = BUILTINS.iter().find(|(builtin, _)| *builtin == name)?;
+    Some(parse_plaintext(text).expect("the built-in patterns parse"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_glider() {
+        assert_eq!(
+            parse_plaintext(GLIDER),
+            Ok(Pattern {
+                width: 3,
+                height: 3,
+                live: vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)],
+            })
+        );
+    }
+
+    #[test]
+    fn every_builtin_parses() {
+        for (name, _) in BUILTINS {
+            assert!(builtin(name).is_some(), "{name}");
+        }
+        let gun = builtin("gosper-gun").unwrap();
+        assert_eq!((gun.width, gun.height, gun.live.len()), (36, 9, 36));
+        assert_eq!(builtin("spaceship"), None);
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        assert_eq!(
+            parse_plaintext("!Name: Broken\n.O.\n..O\nOO\n"),
+            Err(ParseError::Ragged {
+                line: 4,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            parse_plaintext("OO\nOOO"),
+            Err(ParseError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 3
+            })
+        );
+        // A blank line is a row with no cells.
+        assert!(matches!(
+            parse_plaintext("OO\n\nOO"),
+            Err(ParseError::Ragged { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_other_characters() {
+        assert_eq!(
+            parse_plaintext("..O\n.*."),
+            Err(ParseError::InvalidCell {
+                ch: '*',
+                line: 2,
+                column: 2
+            })
+        );
+        assert_eq!(
+            parse_plaintext("..O\n.*.").unwrap_err().to_string(),
+            "line 2, column 2: '*' isn't a cell - use '.' or 'O'"
+        );
+    }
+
+    #[test]
+    fn needs_some_rows() {
+        assert_eq!(parse_plaintext(""), Err(ParseError::Empty));
+        assert_eq!(parse_plaintext("!Only a comment\n"), Err(ParseError::Empty));
+        assert_eq!(parse_plaintext("\n"), Err(ParseError::Empty));
+    }
+}
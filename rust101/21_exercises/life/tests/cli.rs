@@ -0,0 +1,68 @@
+use std::process::{Command, Output};
+
+fn life(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_life"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn animates_a_pattern_file() {
+    let output = life(&[
+        "--file",
+        "tests/data/beacon.cells",
+        "--width",
+        "6",
+        "--height",
+        "6",
+        "--generations",
+        "1",
+        "--delay",
+        "0",
+    ]);
+    assert_eq!(output.status.code(), Some(0), "{}", stderr(&output));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "\x1b[2J\x1b[H......\n.OO...\n.OO...\n...OO.\n...OO.\n......\nGeneration 0, population 8\n\
+         \x1b[2J\x1b[H......\n.OO...\n.O....\n....O.\n...OO.\n......\nGeneration 1, population 6\n"
+    );
+}
+
+#[test]
+fn ragged_files_are_reported() {
+    let output = life(&["--file", "tests/data/ragged.cells", "--generations", "0"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stderr(&output),
+        "error: tests/data/ragged.cells: line 4 is 2 cells wide, but the first row is 3\n"
+    );
+}
+
+#[test]
+fn patterns_must_fit() {
+    let output = life(&[
+        "--pattern",
+        "gosper-gun",
+        "--width",
+        "20",
+        "--generations",
+        "0",
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stderr(&output),
+        "error: the pattern is 36x9, too big for a 20x20 grid\n"
+    );
+}
+
+#[test]
+fn unknown_patterns_are_usage_errors() {
+    let output = life(&["--pattern", "spaceship"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("try glider, blinker, block, gosper-gun"));
+}
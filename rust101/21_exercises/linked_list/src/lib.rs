@@ -0,0 +1,337 @@
+/* Things learned during this exercise:
+   * A singly linked stack, following "Learning Rust With Entirely Too Many
+   Linked Lists" (https://rust-unofficial.github.io/too-many-lists/) up to
+   its second list: `push`, `pop`, `peek`, `peek_mut` and the three
+   iterators, all in safe Rust.
+   * `Option<Box<Node<T>>>` is the link: a Box owns the next node, and None
+   ends the list. The compiler stores None as a null pointer, so the Option
+   costs nothing.
+   * Most of the work is moving ownership around without leaving a hole.
+   Rust won't let a field be moved out of `&mut self` and left empty, even
+   for a moment, so `Option::take` swaps the link for None and hands over
+   what was there.
+   * `as_deref` and `as_deref_mut` turn `&Option<Box<Node>>` into
+   `Option<&Node>`, which is what the borrowing iterators walk along.
+   * The three iterators are the three ways of looking at a collection:
+   `into_iter` owns it (T), `iter` borrows it (&T), `iter_mut` borrows it
+   mutably (&mut T).
+   * The derived Drop is recursive - dropping a node drops its Box, which
+   drops the next node - so a long list overflows the stack. Ours loops
+   instead.
+*/
+
+/// A last-in, first-out stack of `T`s.
+///
+/// ```
+/// use linked_list::List;
+///
+/// let mut list = List::new();
+/// list.push(1);
+/// list.push(2);
+/// assert_eq!(list.pop(), Some(2));
+/// assert_eq!(list.peek(), Some(&1));
+/// ```
+pub struct List<T> {
+    head: Link<T>,
+}
+
+// The list owns its first node, each node owns the next, and so on: one
+// chain of single ownership, so there's never a question of who frees what.
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        List { head: None }
+    }
+
+    /// Puts `elem` on top.
+    pub fn push(&mut self, elem: T) {
+        // `next: self.head` on its own would move the head out of a field
+        // behind `&mut self` - error[E0507] - so `take` moves it out and
+        // leaves None until the new node is put back as the head.
+        let new_node = Box::new(Node {
+            elem,
+            next: self.head.take(),
+        });
+        self.head = Some(new_node);
+    }
+
+    /// Takes the top element off, giving the caller ownership of it.
+    pub fn pop(&mut self) -> Option<T> {
+        // `take` gives us the whole first node; its `next` becomes the head,
+        // and the element is moved out as the Box is dropped.
+        self.head.take().map(|node| {
+            self.head = node.next;
+            node.elem
+        })
+    }
+
+    /// Borrows the top element. The borrow is tied to `&self`, so the list
+    /// can't be changed while it's held.
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    /// Borrows the top element mutably, to change it in place.
+    ///
+    /// ```
+    /// let mut list = linked_list::List::new();
+    /// list.push(1);
+    /// if let Some(top) = list.peek_mut() {
+    ///     *top *= 10;
+    /// }
+    /// assert_eq!(list.pop(), Some(10));
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.elem)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Borrows each element in turn, top first.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+
+    /// Borrows each element mutably in turn, top first.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head.as_deref_mut(),
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        // Each node is detached from the one after it before it's dropped,
+        // so dropping it has nothing further to follow. One loop, however
+        // long the list, instead of one nested drop call per node.
+        let mut link = self.head.take();
+        while let Some(mut node) = link {
+            link = node.next.take();
+        }
+    }
+}
+
+/// Pops the elements, top first. Made by `into_iter`, which takes the list
+/// by value - it's used up.
+pub struct IntoIter<T>(List<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop()
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+/// Made by `iter`. It holds a reference to the next node, and the `'a`
+/// says those references come from - and can't outlive - the list.
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        // `Option<&Node>` is Copy, so `map` here copies the reference and
+        // leaves `self.next` to be overwritten - shared references can be
+        // handed out as often as we like.
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// Made by `iter_mut`.
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        // A `&mut` isn't Copy, so unlike Iter this has to `take` it: the
+        // node's reference moves out of the iterator, which splits it into
+        // `&mut elem` for the caller and `&mut next` to keep. No element can
+        // be handed out twice, which is what lets the borrow checker accept
+        // several `&mut T` living at once.
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            &mut node.elem
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    fn one_two_three() -> List<i32> {
+        // Pushed 1, 2, 3, so 3 is on top.
+        let mut list = List::new();
+        for n in [1, 2, 3] {
+            list.push(n);
+        }
+        list
+    }
+
+    #[test]
+    fn new_list_is_empty() {
+        let mut list: List<i32> = List::new();
+        assert!(list.is_empty());
+        assert_eq!(list.pop(), None);
+        assert_eq!(list.peek(), None);
+        assert!(List::<u8>::default().is_empty());
+    }
+
+    #[test]
+    fn push_and_pop_are_last_in_first_out() {
+        let mut list = one_two_three();
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(2));
+        list.push(4);
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn pop_gives_ownership() {
+        let mut list = List::new();
+        list.push(String::from("owned"));
+        let s: String = list.pop().unwrap();
+        assert_eq!(s, "owned");
+    }
+
+    #[test]
+    fn peek_does_not_remove() {
+        let list = one_two_three();
+        assert_eq!(list.peek(), Some(&3));
+        assert_eq!(list.peek(), Some(&3));
+    }
+
+    #[test]
+    fn peek_mut_changes_the_top() {
+        let mut list = one_two_three();
+        *list.peek_mut().unwrap() = 30;
+        assert_eq!(list.peek(), Some(&30));
+        assert_eq!(List::<i32>::new().peek_mut(), None);
+    }
+
+    #[test]
+    fn into_iter() {
+        let items: Vec<i32> = one_two_three().into_iter().collect();
+        assert_eq!(items, [3, 2, 1]);
+        assert_eq!(List::<i32>::new().into_iter().next(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let list = one_two_three();
+        let items: Vec<&i32> = list.iter().collect();
+        assert_eq!(items, [&3, &2, &1]);
+        // Still usable afterwards, and `for` works on a reference.
+        let mut sum = 0;
+        for n in &list {
+            sum += n;
+        }
+        assert_eq!(sum, 6);
+        assert_eq!(List::<i32>::new().iter().next(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = one_two_three();
+        for n in list.iter_mut() {
+            *n *= 10;
+        }
+        for n in &mut list {
+            *n += 1;
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [31, 21, 11]);
+        assert_eq!(List::<i32>::new().iter_mut().next(), None);
+    }
+
+    #[test]
+    fn iter_mut_references_can_live_together() {
+        let mut list = one_two_three();
+        let mut iter = list.iter_mut();
+        let first = iter.next().unwrap();
+        let second = iter.next().unwrap();
+        std::mem::swap(first, second);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [2, 3, 1]);
+    }
+
+    #[test]
+    fn drop_drops_every_element() {
+        // Each element holds an Rc; once the list's gone, only ours is left.
+        let counter = Rc::new(Cell::new(0));
+        let mut list = List::new();
+        for _ in 0..10 {
+            list.push(Rc::clone(&counter));
+        }
+        assert_eq!(Rc::strong_count(&counter), 11);
+        drop(list);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn dropping_a_million_elements_does_not_overflow_the_stack() {
+        // The derived, recursive drop would need a stack frame per node -
+        // far more than a test thread's stack.
+        let mut list = List::new();
+        for n in 0..1_000_000 {
+            list.push(n);
+        }
+        drop(list);
+    }
+}
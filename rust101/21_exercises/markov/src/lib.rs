@@ -0,0 +1,168 @@
+/* Things learned during this exercise:
+   * A Markov chain only remembers the last few steps - the order. For
+   text, that means counting which words followed each run of N words, then
+   generating by repeatedly picking a follower of the last N words output,
+   weighted by how often it came up.
+   * A `Vec<String>` is a fine HashMap key: it's Eq and Hash whenever its
+   items are, and a lookup can be done with a `&[String]` slice because Vec
+   borrows as one.
+   * Seeding the RNG isn't enough for repeatable output on its own. HashMap
+   iteration order is randomised per process, so anything chosen by walking
+   one changes from run to run; a BTreeMap always walks in key order.
+   * Weighted choice without a library: pick a number below the total
+   count, then walk the options taking away each count until the pick
+   falls inside one.
+   * Low orders give nonsense and high ones copy the text out word for
+   word, since most runs of several words only appear once.
+   * See https://doc.rust-lang.org/std/collections/hash_map/index.html and
+   https://docs.rs/rand/0.8.5/rand/trait.Rng.html#method.gen_range
+*/
+mod model;
+
+pub use model::{ends_sentence, train, Model, MAX_OVERRUN};
+
+use std::error::Error;
+use std::fmt;
+use std::path::PathBuf;
+
+use rand::Rng;
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub order: usize,
+    pub words: usize,
+    pub seed: Option<u64>,
+    pub corpus: PathBuf,
+}
+
+fn option_value<T: std::str::FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    option: &str,
+    what: &str,
+) -> Result<T, String> {
+    let value = args.next().ok_or(format!("{option} needs {what}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("{option} needs {what}, not {value:?}"))
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut order = 2;
+        let mut words = 50;
+        let mut seed = None;
+        let mut corpus = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--order" => order = option_value(&mut args, "--order", "a number")?,
+                "--words" => words = option_value(&mut args, "--words", "a number")?,
+                "--seed" => seed = Some(option_value(&mut args, "--seed", "a number")?),
+                _ if arg.starts_with("--") => return Err(format!("unknown option: {arg:?}")),
+                _ if corpus.is_some() => return Err(format!("only one corpus, not {arg:?} too")),
+                _ => corpus = Some(PathBuf::from(arg)),
+            }
+        }
+        if order == 0 {
+            return Err("--order needs to be at least 1".to_string());
+        }
+        let corpus = corpus.ok_or("which text to learn from? Give the path to a file")?;
+        Ok(Config {
+            order,
+            words,
+            seed,
+            corpus,
+        })
+    }
+}
+
+// The corpus has fewer words than the order, so there's nothing to learn.
+#[derive(Debug, PartialEq)]
+pub struct TooShort {
+    pub order: usize,
+}
+
+impl fmt::Display for TooShort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the text needs at least {} words for order {}",
+            self.order, self.order
+        )
+    }
+}
+
+impl Error for TooShort {}
+
+pub fn run(config: &Config, corpus: &str, rng: &mut impl Rng) -> Result<String, TooShort> {
+    let model = train(corpus, config.order);
+    if model.is_empty() {
+        return Err(TooShort {
+            order: config.order,
+        });
+    }
+    Ok(model.generate(config.words, rng))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["markov".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&["text.txt"])),
+            Ok(Config {
+                order: 2,
+                words: 50,
+                seed: None,
+                corpus: PathBuf::from("text.txt"),
+            })
+        );
+        assert_eq!(
+            Config::build(args(&[
+                "--words", "10", "text.txt", "--order", "3", "--seed", "9"
+            ])),
+            Ok(Config {
+                order: 3,
+                words: 10,
+                seed: Some(9),
+                corpus: PathBuf::from("text.txt"),
+            })
+        );
+        for bad in [
+            &[][..],
+            &["--order", "0", "text.txt"],
+            &["--words", "many", "text.txt"],
+            &["a.txt", "b.txt"],
+            &["--temperature", "2", "text.txt"],
+        ] {
+            assert!(Config::build(args(bad)).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn refuses_a_corpus_shorter_than_the_order() {
+        let config = Config::build(args(&["--order", "4", "--words", "4", "x"])).unwrap();
+        let mut rng = StdRng::seed_from_u64(191);
+        assert_eq!(
+            run(&config, "only three words", &mut rng),
+            Err(TooShort { order: 4 })
+        );
+        assert_eq!(
+            run(&config, "now there are four", &mut rng),
+            Ok("now there are four".to_string())
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use std::env;
+use std::fs;
+use std::process;
+
+use markov::Config;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: markov [--order N] [--words N] [--seed N] CORPUS");
+        process::exit(2);
+    });
+    let corpus = fs::read_to_string(&config.corpus).unwrap_or_else(|err| {
+        eprintln!("{}: {err}", config.corpus.display());
+        process::exit(1);
+    });
+    // Without a seed, different text every time.
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    match markov::run(&config, &corpus, &mut rng) {
+        Ok(text) => println!("{text}"),
+        Err(err) => {
+            eprintln!("{}: {err}", config.corpus.display());
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::{BTreeMap, HashMap};
+
+use rand::Rng;
+
+// Once the text is as long as was asked for, it can run on by up to this
+// many words looking for the end of a sentence.
+pub const MAX_OVERRUN: usize = 20;
+
+// Whether a word finishes a sentence: it ends in '.', '!' or '?', maybe
+// with a closing quote or bracket after it.
+pub fn ends_sentence(word: &str) -> bool {
+    word.trim_end_matches(['"', '\'', ')', ']'])
+        .ends_with(['.', '!', '?'])
+}
+
+// A word-level Markov model: for every run of `order` words in the text,
+// how often each word came next.
+//
+// Words are whatever is between the whitespace, punctuation and all, so
+// "mat." and "mat" are different words - which is what lets the model
+// know where sentences end.
+#[derive(Debug)]
+pub struct Model {
+    order: usize,
+    // The followers are in a BTreeMap, not a HashMap, so they're always
+    // visited in the same order. Picking one by walking a HashMap would
+    // choose differently on every run, even with the same seed.
+    chains: HashMap<Vec<String>, BTreeMap<String, u32>>,
+    // Every run of `order` words that starts a sentence, in the order they
+    // appear, repeats included - so common openings are picked more often.
+    starts: Vec<Vec<String>>,
+}
+
+// Builds a model of order `order` from `text`. A text shorter than the
+// order has no runs of that many words, and gives an empty model.
+//
+// # Panics
+//
+// If `order` is zero.
+pub fn train(text: &str, order: usize) -> Model {
+    assert!(order > 0, "a Markov model needs an order of at least 1");
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let mut chains: HashMap<Vec<String>, BTreeMap<String, u32>> = HashMap::new();
+    let mut starts = Vec::new();
+    for (i, window) in words.windows(order).enumerate() {
+        let state: Vec<String> = window.iter().map(|word| word.to_string()).collect();
+        if i == 0 || ends_sentence(words[i - 1]) {
+            starts.push(state.clone());
+        }
+        if let Some(next) = words.get(i + order) {
+            *chains
+                .entry(state)
+                .or_default()
+                .entry(next.to_string())
+                .or_insert(0) += 1;
+        }
+    }
+    Model {
+        order,
+        chains,
+        starts,
+    }
+}
+
+impl Model {
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    // True if there was too little text to learn anything from.
+    pub fn is_empty(&self) -> bool {
+        self.starts.is_empty()
+    }
+
+    // Picks a follower of `state`, each as likely as it was in the text.
+    // None if nothing ever followed it - the end of the text.
+    fn next_word(&self, state: &[String], rng: &mut impl Rng) -> Option<&str> {
+        let followers = self.chains.get(state)?;
+        let total: u32 = followers.values().sum();
+        let mut pick = rng.gen_range(0..total);
+        for (word, &count) in followers {
+            if pick < count {
+                return Some(word);
+            }
+            pick -= count;
+        }
+        unreachable!("the pick is less than the total of the counts")
+    }
+
+    // Generates about `words` words of text, starting at the start of a
+    // sentence. Once it has enough, it carries on to the end of the
+    // sentence if that's within MAX_OVERRUN words; if not, it stops at
+    // exactly `words`. If it reaches the end of the text, it starts again
+    // at a new sentence. An empty model generates nothing.
+    pub fn generate(&self, words: usize, rng: &mut impl Rng) -> String {
+        if self.is_empty() || words == 0 {
+            return String::new();
+        }
+        let mut output: Vec<&str> = Vec::new();
+        let mut state: Vec<String> = Vec::new();
+        while output.len() < words + MAX_OVERRUN {
+            if output.len() >= words && output.last().is_some_and(|word| ends_sentence(word)) {
+                break;
+            }
+            match self.next_word(&state, rng) {
+                Some(word) => {
+                    output.push(word);
+                    state.remove(0);
+                    state.push(word.to_string());
+                }
+                // At the very beginning, or a dead end.
+                None => {
+                    let start = &self.starts[rng.gen_range(0..self.starts.len())];
+                    output.extend(start.iter().map(String::as_str));
+                    state.clone_from(start);
+                }
+            }
+        }
+        if !output.last().is_some_and(|word| ends_sentence(word)) {
+            output.truncate(words);
+        }
+        output.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    const CORPUS: &str = include_str!("../tests/data/corpus.txt");
+
+    fn rng(seed: u64) -> StdRng {
+        StdRng::seed_from_u64(seed)
+    }
+
+    fn words(text: &str) -> Vec<&str> {
+        text.split_whitespace().collect()
+    }
+
+    #[test]
+    fn counts_followers() {
+        let model = train("a b a b a c", 1);
+        let a = &model.chains[&vec!["a".to_string()]];
+        assert_eq!(a.get("b"), Some(&2));
+        assert_eq!(a.get("c"), Some(&1));
+        // Nothing follows the last word.
+        assert!(!model.chains.contains_key(&vec!["c".to_string()]));
+        let model = train("one two three. four five", 2);
+        assert_eq!(model.starts, [["one", "two"], ["four", "five"]]);
+    }
+
+    #[test]
+    fn finds_sentence_ends() {
+        for word in ["mat.", "rug!", "dog?", "said.\"", "(really.)"] {
+            assert!(ends_sentence(word), "{word}");
+        }
+        for word in ["cat,", "Mr", "\"quoted\"", ""] {
+            assert!(!ends_sentence(word), "{word}");
+        }
+    }
+
+    #[test]
+    fn is_deterministic_for_a_seed() {
+        let model = train(CORPUS, 2);
+        let first = model.generate(30, &mut rng(191));
+        assert_eq!(model.generate(30, &mut rng(191)), first);
+        let others: HashSet<String> = (0..10)
+            .map(|seed| model.generate(30, &mut rng(seed)))
+            .collect();
+        assert!(others.len() > 1);
+    }
+
+    #[test]
+    fn every_pair_is_in_the_text_at_order_1() {
+        let training = words(CORPUS);
+        let pairs: HashSet<(&str, &str)> = training.windows(2).map(|w| (w[0], w[1])).collect();
+        let model = train(CORPUS, 1);
+        // The last word of the corpus, "mat.", also comes earlier, so the
+        // model never reaches a dead end and has to jump to a new sentence.
+        for seed in 0..50 {
+            let text = model.generate(40, &mut rng(seed));
+            for pair in words(&text).windows(2) {
+                assert!(pairs.contains(&(pair[0], pair[1])), "{pair:?} in {text:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn starts_and_ends_with_sentences() {
+        let starts: HashSet<&str> = ["The", "A", "Did", "Then"].into();
+        let model = train(CORPUS, 2);
+        for seed in 0..50 {
+            let text = model.generate(15, &mut rng(seed));
+            let text_words = words(&text);
+            assert!(starts.contains(text_words[0]), "{text:?}");
+            // Sentences in this corpus are short, so one always ends in
+            // time.
+            assert!(ends_sentence(text_words.last().unwrap()), "{text:?}");
+            assert!((15..=15 + MAX_OVERRUN).contains(&text_words.len()));
+        }
+    }
+
+    #[test]
+    fn stops_at_the_length_without_a_sentence_end() {
+        let model = train("round and round and round and round", 1);
+        let text = model.generate(5, &mut rng(191));
+        assert_eq!(words(&text).len(), 5);
+    }
+
+    #[test]
+    fn restarts_at_a_dead_end() {
+        // Every state but the last has only one follower, and the last has
+        // none, so the text repeats.
+        let model = train("Just three words", 2);
+        assert_eq!(
+            model.generate(7, &mut rng(191)),
+            "Just three words Just three words Just"
+        );
+    }
+
+    #[test]
+    fn handles_corpora_shorter_than_the_order() {
+        let model = train("too short", 3);
+        assert!(model.is_empty());
+        assert_eq!(model.generate(10, &mut rng(191)), "");
+        assert!(train("", 1).is_empty());
+        // Exactly `order` words: nothing follows, but there's a start.
+        let model = train("just enough words", 3);
+        assert!(!model.is_empty());
+        assert_eq!(model.generate(2, &mut rng(191)), "just enough");
+    }
+
+    #[test]
+    #[should_panic(expected = "an order of at least 1")]
+    fn order_zero_panics() {
+        train(CORPUS, 0);
+    }
+}
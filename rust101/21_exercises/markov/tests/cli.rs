@@ -0,0 +1,35 @@
+use std::process::{Command, Output};
+
+fn markov(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_markov"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn the_same_seed_gives_the_same_text() {
+    let args = ["--seed", "191", "--words", "12", "tests/data/corpus.txt"];
+    let first = markov(&args);
+    assert_eq!(first.status.code(), Some(0));
+    assert_eq!(first.stdout, markov(&args).stdout);
+    let text = String::from_utf8(first.stdout).unwrap();
+    assert!(text.split_whitespace().count() >= 12, "{text:?}");
+}
+
+#[test]
+fn too_high_an_order_is_explained() {
+    let output = markov(&["--order", "100", "tests/data/corpus.txt"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "tests/data/corpus.txt: the text needs at least 100 words for order 100\n"
+    );
+}
+
+#[test]
+fn a_missing_corpus_is_an_error() {
+    let output = markov(&["tests/data/no_such_file.txt"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("tests/data/no_such_file.txt: "));
+}
@@ -0,0 +1,53 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixError {
+    // A row of `from_rows` with a different length from the first. Rows
+    // count from 1.
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    // The two sides of an operation don't fit together. The sizes are
+    // (rows, columns).
+    Mismatch {
+        op: &'static str,
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    NotSquare(usize, usize),
+    // No single solution: some row is a combination of the others, as far
+    // as floating point can tell.
+    Singular,
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatrixError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {row} has {found} entries, but the first has {expected}"
+            ),
+            MatrixError::Mismatch {
+                op,
+                left: (left_rows, left_cols),
+                right: (right_rows, right_cols),
+            } => write!(
+                f,
+                "can't {op} a {left_rows}×{left_cols} matrix and a {right_rows}×{right_cols} one"
+            ),
+            MatrixError::NotSquare(rows, cols) => {
+                write!(f, "a {rows}×{cols} matrix isn't square")
+            }
+            MatrixError::Singular => write!(f, "the matrix is singular"),
+        }
+    }
+}
+
+impl Error for MatrixError {}
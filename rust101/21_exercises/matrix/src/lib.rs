@@ -0,0 +1,31 @@
+/* Things learned during this exercise:
+   * A matrix as one `Vec<f64>`, row after row, and its two sizes, instead
+   of a `Vec<Vec<f64>>`: one allocation, rows that can't differ in length,
+   and each row a slice.
+   * `Index<(usize, usize)>` is what makes `m[(row, col)]` work. Its output
+   is a reference, so `IndexMut` lets the same syntax assign.
+   * Operators are traits, and their signatures are fixed: `Add::add`
+   returns `Self::Output`, not a Result, so a size mismatch in `a + b` can
+   only panic. The `checked_*` methods return the error, as `checked_add`
+   does for integers.
+   * Implementing the operators for `&Matrix` lets `&a * &b` borrow
+   instead of using both up. A small `macro_rules!` writes the owned
+   versions on top, for all three operators at once.
+   * `Display` can read the caller's precision with `f.precision()`, which
+   is how `{:.2}` reaches each entry. Aligning columns means formatting
+   every entry first, to know the widest.
+   * Gaussian elimination without pivoting divides by whatever is on the
+   diagonal - a zero, or something tiny that magnifies rounding errors.
+   Swapping up the row with the biggest entry avoids both. A pivot that's
+   zero after rounding is compared against a tolerance, never `== 0.0`.
+   * `split_at_mut` gives a `&` to the pivot row and a `&mut` to the rows
+   below at the same time, which indexing one `Vec` twice can't.
+   * See https://doc.rust-lang.org/std/ops/index.html and
+   https://en.wikipedia.org/wiki/Gaussian_elimination#Partial_pivoting
+*/
+mod error;
+mod matrix;
+mod solve;
+
+pub use error::MatrixError;
+pub use matrix::Matrix;
@@ -0,0 +1,461 @@
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+use crate::error::MatrixError;
+
+/// A `rows` × `cols` matrix of `f64`s.
+///
+/// ```
+/// use matrix::Matrix;
+///
+/// let a = Matrix::from_rows(&[[1.0, 2.0], [3.0, 4.0]]).unwrap();
+/// assert_eq!(a[(1, 0)], 3.0);
+/// assert_eq!(&a * &Matrix::identity(2), a);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    // Row-major: row `r` is `data[r * cols..(r + 1) * cols]`, so a row is
+    // a slice and walking along one stays in the same stretch of memory.
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix {
+            rows,
+            cols,
+            data: vec![0.0; rows * cols],
+        }
+    }
+
+    /// The `n` × `n` matrix that multiplying by changes nothing: ones down
+    /// the diagonal, zeros everywhere else.
+    pub fn identity(n: usize) -> Self {
+        let mut identity = Matrix::zeros(n, n);
+        for i in 0..n {
+            identity[(i, i)] = 1.0;
+        }
+        identity
+    }
+
+    /// Takes arrays, slices or `Vec`s, one per row. They all have to be as
+    /// long as the first.
+    ///
+    /// ```
+    /// use matrix::{Matrix, MatrixError};
+    ///
+    /// let rows = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0]];
+    /// assert_eq!(
+    ///     Matrix::from_rows(&rows),
+    ///     Err(MatrixError::Ragged { row: 2, expected: 3, found: 2 })
+    /// );
+    /// ```
+    pub fn from_rows<R: AsRef<[f64]>>(rows: &[R]) -> Result<Self, MatrixError> {
+        let cols = rows.first().map_or(0, |row| row.as_ref().len());
+        let mut data = Vec::with_capacity(rows.len() * cols);
+        for (index, row) in rows.iter().enumerate() {
+            let row = row.as_ref();
+            if row.len() != cols {
+                return Err(MatrixError::Ragged {
+                    row: index + 1,
+                    expected: cols,
+                    found: row.len(),
+                });
+            }
+            data.extend_from_slice(row);
+        }
+        Ok(Matrix {
+            rows: rows.len(),
+            cols,
+            data,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// (rows, columns), the order sizes are given in throughout.
+    pub fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// The entry at `row`, `col`, or None outside the matrix. Indexing with
+    /// `m[(row, col)]` panics there instead.
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        (row < self.rows && col < self.cols).then(|| self.data[row * self.cols + col])
+    }
+
+    /// # Panics
+    ///
+    /// If there's no row `row`.
+    pub fn row(&self, row: usize) -> &[f64] {
+        assert!(row < self.rows, "row {row} of a {}-row matrix", self.rows);
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Rows become columns: entry (r, c) moves to (c, r).
+    pub fn transpose(&self) -> Matrix {
+        let mut transposed = Matrix::zeros(self.cols, self.rows);
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                transposed[(col, row)] = self[(row, col)];
+            }
+        }
+        transposed
+    }
+
+    // Entry by entry, for `+` and `-`.
+    fn zip_with(
+        &self,
+        other: &Matrix,
+        op: &'static str,
+        f: impl Fn(f64, f64) -> f64,
+    ) -> Result<Matrix, MatrixError> {
+        if self.dims() != other.dims() {
+            return Err(MatrixError::Mismatch {
+                op,
+                left: self.dims(),
+                right: other.dims(),
+            });
+        }
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .iter()
+                .zip(&other.data)
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+        })
+    }
+
+    /// `self + other`, or an error if they aren't the same size.
+    pub fn checked_add(&self, other: &Matrix) -> Result<Matrix, MatrixError> {
+        self.zip_with(other, "add", |a, b| a + b)
+    }
+
+    /// `self - other`, or an error if they aren't the same size.
+    pub fn checked_sub(&self, other: &Matrix) -> Result<Matrix, MatrixError> {
+        self.zip_with(other, "subtract", |a, b| a - b)
+    }
+
+    /// The matrix product `self × other`, or an error unless `self` has as
+    /// many columns as `other` has rows.
+    ///
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// let row = Matrix::from_rows(&[[1.0, 2.0, 3.0]]).unwrap();
+    /// let column = row.transpose();
+    /// assert_eq!(row.checked_mul(&column).unwrap()[(0, 0)], 14.0);
+    /// assert_eq!(column.checked_mul(&row).unwrap().dims(), (3, 3));
+    /// assert!(row.checked_mul(&row).is_err());
+    /// ```
+    pub fn checked_mul(&self, other: &Matrix) -> Result<Matrix, MatrixError> {
+        if self.cols != other.rows {
+            return Err(MatrixError::Mismatch {
+                op: "multiply",
+                left: self.dims(),
+                right: other.dims(),
+            });
+        }
+        let mut product = Matrix::zeros(self.rows, other.cols);
+        for row in 0..self.rows {
+            // Going along a row of `other` for each entry of ours, rather
+            // than down a column of it, keeps every loop on a slice.
+            for (k, &a) in self.row(row).iter().enumerate() {
+                let out = row * product.cols;
+                for (entry, &b) in product.data[out..out + other.cols]
+                    .iter_mut()
+                    .zip(other.row(k))
+                {
+                    *entry += a * b;
+                }
+            }
+        }
+        Ok(product)
+    }
+
+    /// Every entry times `factor`. Any matrix can be scaled, so there's no
+    /// checked version.
+    pub fn scale(&self, factor: f64) -> Matrix {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(|&entry| entry * factor).collect(),
+        }
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    /// # Panics
+    ///
+    /// Outside the matrix - `get` is the version that doesn't.
+    fn index(&self, (row, col): (usize, usize)) -> &f64 {
+        assert!(
+            row < self.rows && col < self.cols,
+            "({row}, {col}) is outside a {}×{} matrix",
+            self.rows,
+            self.cols
+        );
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        assert!(
+            row < self.rows && col < self.cols,
+            "({row}, {col}) is outside a {}×{} matrix",
+            self.rows,
+            self.cols
+        );
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+// The operators can't return a Result - `a + b` has to be a Matrix - so
+// they panic when the sizes don't fit, with the checked method's error as
+// the message. That's the same bargain as `+` on integers, which panics
+// on overflow in debug builds where `checked_add` returns None.
+//
+// Each is written for references, so `&a * &b` doesn't use up `a` and
+// `b`, and the owned versions borrow and pass them on.
+macro_rules! operator {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        /// # Panics
+        ///
+        /// If the sizes don't fit. The checked method with the same name
+        /// returns the error instead.
+        impl $trait<&Matrix> for &Matrix {
+            type Output = Matrix;
+
+            fn $method(self, other: &Matrix) -> Matrix {
+                self.$checked(other).unwrap_or_else(|err| panic!("{err}"))
+            }
+        }
+
+        impl $trait for Matrix {
+            type Output = Matrix;
+
+            fn $method(self, other: Matrix) -> Matrix {
+                (&self).$method(&other)
+            }
+        }
+    };
+}
+
+operator!(Add, add, checked_add);
+operator!(Sub, sub, checked_sub);
+operator!(Mul, mul, checked_mul);
+
+impl Mul<f64> for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, factor: f64) -> Matrix {
+        self.scale(factor)
+    }
+}
+
+impl Mul<f64> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, factor: f64) -> Matrix {
+        self.scale(factor)
+    }
+}
+
+/// A line per row, with each column's entries right-aligned to the widest
+/// of them. A precision applies to every entry.
+///
+/// ```
+/// use matrix::Matrix;
+///
+/// let m = Matrix::from_rows(&[[1.0, -2.5], [10.0, 0.25]]).unwrap();
+/// assert_eq!(m.to_string(), " 1  -2.5\n10  0.25");
+/// assert_eq!(format!("{m:.1}"), " 1.0  -2.5\n10.0   0.2");
+/// ```
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let entries: Vec<String> = self
+            .data
+            .iter()
+            .map(|entry| match f.precision() {
+                Some(precision) => format!("{entry:.precision$}"),
+                None => entry.to_string(),
+            })
+            .collect();
+        let widths: Vec<usize> = (0..self.cols)
+            .map(|col| {
+                (0..self.rows)
+                    .map(|row| entries[row * self.cols + col].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for (col, width) in widths.iter().enumerate() {
+                if col > 0 {
+                    write!(f, "  ")?;
+                }
+                write!(f, "{:>width$}", entries[row * self.cols + col])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix<const N: usize>(rows: &[[f64; N]]) -> Matrix {
+        Matrix::from_rows(rows).unwrap()
+    }
+
+    #[test]
+    fn constructors() {
+        let zeros = Matrix::zeros(2, 3);
+        assert_eq!(zeros.dims(), (2, 3));
+        assert_eq!(zeros.row(1), [0.0; 3]);
+        assert_eq!(
+            Matrix::identity(3),
+            matrix(&[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]])
+        );
+        assert_eq!(Matrix::from_rows::<[f64; 0]>(&[]).unwrap().dims(), (0, 0));
+        assert_eq!(
+            Matrix::from_rows(&[vec![1.0], vec![2.0, 3.0]]),
+            Err(MatrixError::Ragged {
+                row: 2,
+                expected: 1,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn indexes_by_row_then_column() {
+        let mut m = matrix(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!((m[(0, 2)], m[(1, 0)]), (3.0, 4.0));
+        m[(1, 2)] = 60.0;
+        assert_eq!(m.row(1), [4.0, 5.0, 60.0]);
+        assert_eq!(m.get(1, 2), Some(60.0));
+        assert_eq!((m.get(2, 0), m.get(0, 3)), (None, None));
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 3) is outside a 2×3 matrix")]
+    fn indexing_outside_panics() {
+        // Past the end of the row, though still inside the data.
+        let _ = Matrix::zeros(2, 3)[(0, 3)];
+    }
+
+    #[test]
+    fn identity_changes_nothing() {
+        let a = matrix(&[[1.0, -2.0, 3.5], [0.0, 4.0, -1.0]]);
+        assert_eq!(&Matrix::identity(2) * &a, a);
+        assert_eq!(&a * &Matrix::identity(3), a);
+        assert_eq!(Matrix::identity(4).transpose(), Matrix::identity(4));
+        assert_eq!(
+            &Matrix::identity(3) * &Matrix::identity(3),
+            Matrix::identity(3)
+        );
+        assert_eq!(Matrix::identity(0).dims(), (0, 0));
+    }
+
+    #[test]
+    fn multiplies_by_hand_computed_examples() {
+        let a = matrix(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = matrix(&[[7.0, 8.0], [9.0, 10.0], [11.0, 12.0]]);
+        // 1·7 + 2·9 + 3·11 = 58, 1·8 + 2·10 + 3·12 = 64, and so on.
+        assert_eq!(&a * &b, matrix(&[[58.0, 64.0], [139.0, 154.0]]));
+        assert_eq!(
+            &b * &a,
+            matrix(&[[39.0, 54.0, 69.0], [49.0, 68.0, 87.0], [59.0, 82.0, 105.0]])
+        );
+        // Rotating a quarter turn twice is a half turn.
+        let quarter = matrix(&[[0.0, -1.0], [1.0, 0.0]]);
+        assert_eq!(&quarter * &quarter, Matrix::identity(2) * -1.0);
+        // (AB)ᵀ = BᵀAᵀ.
+        assert_eq!((&a * &b).transpose(), &b.transpose() * &a.transpose());
+    }
+
+    #[test]
+    fn adds_subtracts_and_scales() {
+        let a = matrix(&[[1.0, 2.0], [3.0, 4.0]]);
+        let b = matrix(&[[0.5, -1.0], [2.0, 0.0]]);
+        assert_eq!(&a + &b, matrix(&[[1.5, 1.0], [5.0, 4.0]]));
+        assert_eq!(&a - &b, matrix(&[[0.5, 3.0], [1.0, 4.0]]));
+        assert_eq!(&a * 2.0, &a + &a);
+        assert_eq!(a.clone() - a.clone(), Matrix::zeros(2, 2));
+        assert_eq!(a.scale(0.5) * 2.0, a);
+    }
+
+    #[test]
+    fn checked_methods_report_mismatches() {
+        let (two_by_three, three_by_two) = (Matrix::zeros(2, 3), Matrix::zeros(3, 2));
+        assert_eq!(
+            two_by_three.checked_add(&three_by_two),
+            Err(MatrixError::Mismatch {
+                op: "add",
+                left: (2, 3),
+                right: (3, 2)
+            })
+        );
+        assert!(two_by_three.checked_sub(&three_by_two).is_err());
+        assert!(two_by_three.checked_mul(&two_by_three).is_err());
+        assert_eq!(
+            two_by_three.checked_mul(&three_by_two).unwrap().dims(),
+            (2, 2)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "can't multiply a 2×3 matrix and a 2×3 one")]
+    fn operators_panic_on_mismatches() {
+        let _ = Matrix::zeros(2, 3) * Matrix::zeros(2, 3);
+    }
+
+    #[test]
+    fn transposes() {
+        let a = matrix(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(a.transpose(), matrix(&[[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]));
+        assert_eq!(a.transpose().transpose(), a);
+    }
+
+    #[test]
+    fn displays_aligned_columns() {
+        let m = matrix(&[[1.0, -20.0, 3.0], [100.5, 2.0, -0.125], [0.0, 0.0, 0.0]]);
+        assert_eq!(
+            m.to_string(),
+            "    1  -20       3\n\
+             100.5    2  -0.125\n\
+             \x20   0    0       0"
+        );
+        assert_eq!(
+            format!("{m:.2}"),
+            "  1.00  -20.00   3.00\n\
+             100.50    2.00  -0.12\n\
+             \x20 0.00    0.00   0.00"
+        );
+        assert_eq!(Matrix::zeros(0, 0).to_string(), "");
+        assert_eq!(Matrix::zeros(1, 1).to_string(), "0");
+    }
+}
@@ -0,0 +1,160 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+impl Matrix {
+    /// The `x` with `self × x = b`, for a square `self` and a `b` with an
+    /// entry for each row.
+    ///
+    /// ```
+    /// use matrix::Matrix;
+    ///
+    /// // 2x + y = 5 and x - y = 1.
+    /// let a = Matrix::from_rows(&[[2.0, 1.0], [1.0, -1.0]]).unwrap();
+    /// assert_eq!(a.solve(&[5.0, 1.0]).unwrap(), [2.0, 1.0]);
+    /// ```
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare(self.rows(), self.cols()));
+        }
+        if b.len() != self.rows() {
+            return Err(MatrixError::Mismatch {
+                op: "solve",
+                left: self.dims(),
+                right: (b.len(), 1),
+            });
+        }
+
+        // Gaussian elimination on a copy with `b` as an extra column: clear
+        // out everything below the diagonal, a column at a time, then work
+        // back up from the last row.
+        let n = self.rows();
+        let mut rows: Vec<Vec<f64>> = (0..n)
+            .map(|row| {
+                let mut augmented = self.row(row).to_vec();
+                augmented.push(b[row]);
+                augmented
+            })
+            .collect();
+        // A pivot this small, next to the biggest entry, is a zero that
+        // rounding has left a little off.
+        let biggest = (0..n)
+            .flat_map(|row| self.row(row))
+            .fold(0.0, |max: f64, &entry| max.max(entry.abs()));
+        let tolerance = biggest * n as f64 * f64::EPSILON;
+
+        for col in 0..n {
+            // Partial pivoting: swap up the row with the biggest entry in
+            // this column. Dividing by a tiny pivot would blow up the
+            // rounding errors in everything below it.
+            let pivot = (col..n)
+                .max_by(|&a, &b| rows[a][col].abs().total_cmp(&rows[b][col].abs()))
+                .unwrap();
+            if rows[pivot][col].abs() <= tolerance {
+                return Err(MatrixError::Singular);
+            }
+            rows.swap(col, pivot);
+            let (done, below) = rows.split_at_mut(col + 1);
+            let pivot_row = &done[col];
+            for row in below {
+                let factor = row[col] / pivot_row[col];
+                for (entry, &above) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                    *entry -= factor * above;
+                }
+            }
+        }
+
+        let mut x = vec![0.0; n];
+        for row in (0..n).rev() {
+            let known: f64 = (row + 1..n).map(|col| rows[row][col] * x[col]).sum();
+            x[row] = (rows[row][n] - known) / rows[row][row];
+        }
+        Ok(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn assert_close(found: &[f64], expected: &[f64]) {
+        assert_eq!(found.len(), expected.len());
+        for (found, expected) in found.iter().zip(expected) {
+            assert!((found - expected).abs() < 1e-9, "{found:?} vs {expected:?}");
+        }
+    }
+
+    #[test]
+    fn solves_a_small_system() {
+        // x + 2y + 3z = 14, 2x - y + z = 3, 3x + y - z = 2: x = 1, y = 2,
+        // z = 3.
+        let a = Matrix::from_rows(&[[1.0, 2.0, 3.0], [2.0, -1.0, 1.0], [3.0, 1.0, -1.0]]).unwrap();
+        assert_close(&a.solve(&[14.0, 3.0, 2.0]).unwrap(), &[1.0, 2.0, 3.0]);
+        assert_eq!(
+            Matrix::identity(3).solve(&[4.0, 5.0, 6.0]).unwrap(),
+            [4.0, 5.0, 6.0]
+        );
+    }
+
+    #[test]
+    fn needs_pivoting() {
+        // The first pivot is 0, so without swapping rows this divides by it.
+        let a = Matrix::from_rows(&[[0.0, 1.0], [1.0, 1.0]]).unwrap();
+        assert_close(&a.solve(&[2.0, 3.0]).unwrap(), &[1.0, 2.0]);
+        // And with a tiny first pivot, not swapping loses the answer to
+        // rounding.
+        let a = Matrix::from_rows(&[[1e-20, 1.0], [1.0, 1.0]]).unwrap();
+        assert_close(&a.solve(&[1.0, 2.0]).unwrap(), &[1.0, 1.0]);
+    }
+
+    #[test]
+    fn singular_matrices_have_no_solution() {
+        // The second row is twice the first.
+        let a = Matrix::from_rows(&[[1.0, 2.0], [2.0, 4.0]]).unwrap();
+        assert_eq!(a.solve(&[1.0, 2.0]), Err(MatrixError::Singular));
+        // The third is the sum of the others, which only shows up after
+        // rounding makes it nearly zero rather than zero.
+        let a = Matrix::from_rows(&[[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.5, 0.7, 0.9]]).unwrap();
+        assert_eq!(a.solve(&[1.0, 2.0, 3.0]), Err(MatrixError::Singular));
+        assert_eq!(
+            Matrix::zeros(2, 2).solve(&[0.0, 0.0]),
+            Err(MatrixError::Singular)
+        );
+    }
+
+    #[test]
+    fn rejects_the_wrong_shapes() {
+        assert_eq!(
+            Matrix::zeros(2, 3).solve(&[1.0, 2.0]),
+            Err(MatrixError::NotSquare(2, 3))
+        );
+        assert_eq!(
+            Matrix::identity(2).solve(&[1.0]),
+            Err(MatrixError::Mismatch {
+                op: "solve",
+                left: (2, 2),
+                right: (1, 1)
+            })
+        );
+        assert_eq!(Matrix::identity(0).solve(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn solutions_satisfy_random_systems() {
+        let mut rng = StdRng::seed_from_u64(200);
+        for _ in 0..100 {
+            let n = rng.gen_range(1..=6);
+            let rows: Vec<Vec<f64>> = (0..n)
+                .map(|_| (0..n).map(|_| rng.gen_range(-10.0..10.0)).collect())
+                .collect();
+            let a = Matrix::from_rows(&rows).unwrap();
+            let expected: Vec<f64> = (0..n).map(|_| rng.gen_range(-10.0..10.0)).collect();
+            let column = Matrix::from_rows(std::slice::from_ref(&expected))
+                .unwrap()
+                .transpose();
+            let b = (&a * &column).transpose();
+            assert_close(&a.solve(b.row(0)).unwrap(), &expected);
+        }
+    }
+}
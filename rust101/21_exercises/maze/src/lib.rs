@@ -0,0 +1,133 @@
+/* Things learned during this exercise:
+   * A perfect maze - one way, and only one, between any two cells - is a
+   spanning tree of the grid: every cell connected, with one passage fewer
+   than there are cells. That's also an easy thing to test.
+   * Recursive backtracking is a depth-first walk that knocks through to a
+   random unvisited neighbour and backs up when it's stuck. Written as
+   actual recursion it's as deep as the longest corridor, which on a big
+   maze overflows the stack; a Vec used as a stack has no such limit.
+   * Breadth-first search with a VecDeque finds the shortest route without
+   weighing anything up: cells come off the queue in order of distance, and
+   remembering where each came from lets the route be walked back.
+   * A wall between two cells belongs to both, so storing only the east and
+   south walls of each cell means there's no way for the two sides to
+   disagree.
+   * Box-drawing corners depend on which of their four arms have a wall.
+   As four bits that's an index from 0 to 15 into a table of characters.
+   * `rand::seq::SliceRandom::choose` picks from a slice, and `StdRng` with
+   a seed makes it the same pick every time.
+   * See https://en.wikipedia.org/wiki/Maze_generation_algorithm and
+   https://doc.rust-lang.org/std/collections/struct.VecDeque.html
+*/
+mod maze;
+mod parse;
+mod render;
+mod solve;
+
+pub use maze::{generate, Cell, Direction, Maze};
+pub use parse::{parse, ParseError};
+pub use render::{render, Style};
+pub use solve::solve;
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub width: usize,
+    pub height: usize,
+    pub seed: Option<u64>,
+    pub solve: bool,
+    pub style: Style,
+}
+
+fn option_value<T: std::str::FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    option: &str,
+    what: &str,
+) -> Result<T, String> {
+    let value = args.next().ok_or(format!("{option} needs {what}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("{option} needs {what}, not {value:?}"))
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut config = Config {
+            width: 10,
+            height: 10,
+            seed: None,
+            solve: false,
+            style: Style::Unicode,
+        };
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--width" => config.width = option_value(&mut args, "--width", "a number")?,
+                "--height" => config.height = option_value(&mut args, "--height", "a number")?,
+                "--seed" => config.seed = Some(option_value(&mut args, "--seed", "a number")?),
+                "--solve" => config.solve = true,
+                "--ascii" => config.style = Style::Ascii,
+                _ => return Err(format!("unknown option: {arg:?}")),
+            }
+        }
+        if config.width == 0 || config.height == 0 {
+            return Err("the maze needs a width and height of at least 1".to_string());
+        }
+        Ok(config)
+    }
+}
+
+// Generates the maze and draws it, solved if asked.
+pub fn run(config: &Config, seed: u64) -> String {
+    let maze = generate(config.width, config.height, seed);
+    let path = if config.solve { solve(&maze) } else { None };
+    render(&maze, config.style, path.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["maze".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&[
+                "--width", "20", "--solve", "--seed", "5", "--ascii"
+            ])),
+            Ok(Config {
+                width: 20,
+                height: 10,
+                seed: Some(5),
+                solve: true,
+                style: Style::Ascii,
+            })
+        );
+        for bad in [
+            &["--height", "0"][..],
+            &["--width"],
+            &["--seed", "-1"],
+            &["big"],
+        ] {
+            assert!(Config::build(args(bad)).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn solving_draws_the_path() {
+        let mut config = Config::build(args(&["--ascii"])).unwrap();
+        let plain = run(&config, 193);
+        config.solve = true;
+        let solved = run(&config, 193);
+        assert!(!plain.contains('*'));
+        assert!(solved.contains('*'));
+        assert_eq!(solved.replace('*', " "), plain);
+    }
+}
@@ -0,0 +1,15 @@
+use std::env;
+use std::process;
+
+use maze::Config;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: maze [--width W] [--height H] [--seed N] [--solve] [--ascii]");
+        process::exit(2);
+    });
+    // Without a seed, a different maze every time.
+    let seed = config.seed.unwrap_or_else(rand::random);
+    print!("{}", maze::run(&config, seed));
+}
@@ -0,0 +1,262 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cell {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+}
+
+// A grid of cells with walls between them. Each wall is either there or
+// knocked through; the outside edge is always wall.
+//
+// Only the walls on the east and south of each cell are stored - the west
+// wall of a cell is the east wall of the one next to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Maze {
+    width: usize,
+    height: usize,
+    // Whether there's a way through to the east, and to the south, of each
+    // cell, row by row.
+    east: Vec<bool>,
+    south: Vec<bool>,
+}
+
+impl Maze {
+    // A maze with every wall up.
+    //
+    // # Panics
+    //
+    // If either side is zero.
+    pub fn new(width: usize, height: usize) -> Maze {
+        assert!(width > 0 && height > 0, "a maze needs at least one cell");
+        Maze {
+            width,
+            height,
+            east: vec![false; width * height],
+            south: vec![false; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    // Where the solver starts and finishes: the top left and bottom right
+    // corners.
+    pub fn entrance(&self) -> Cell {
+        Cell { x: 0, y: 0 }
+    }
+
+    pub fn exit(&self) -> Cell {
+        Cell {
+            x: self.width - 1,
+            y: self.height - 1,
+        }
+    }
+
+    fn index(&self, cell: Cell) -> usize {
+        cell.y * self.width + cell.x
+    }
+
+    // The cell next door, if it's inside the maze. Walls don't matter.
+    pub fn neighbour(&self, cell: Cell, direction: Direction) -> Option<Cell> {
+        let Cell { x, y } = cell;
+        match direction {
+            Direction::North if y > 0 => Some(Cell { x, y: y - 1 }),
+            Direction::East if x + 1 < self.width => Some(Cell { x: x + 1, y }),
+            Direction::South if y + 1 < self.height => Some(Cell { x, y: y + 1 }),
+            Direction::West if x > 0 => Some(Cell { x: x - 1, y }),
+            _ => None,
+        }
+    }
+
+    pub fn is_open(&self, cell: Cell, direction: Direction) -> bool {
+        match (direction, self.neighbour(cell, direction)) {
+            (_, None) => false,
+            (Direction::East, Some(_)) => self.east[self.index(cell)],
+            (Direction::South, Some(_)) => self.south[self.index(cell)],
+            (Direction::West, Some(next)) => self.east[self.index(next)],
+            (Direction::North, Some(next)) => self.south[self.index(next)],
+        }
+    }
+
+    // Knocks down (or puts back) the wall on one side of `cell`.
+    //
+    // # Panics
+    //
+    // If that side is the outside edge.
+    pub fn set_open(&mut self, cell: Cell, direction: Direction, open: bool) {
+        let next = self
+            .neighbour(cell, direction)
+            .expect("the outside wall can't be opened");
+        match direction {
+            Direction::East => {
+                let i = self.index(cell);
+                self.east[i] = open;
+            }
+            Direction::South => {
+                let i = self.index(cell);
+                self.south[i] = open;
+            }
+            Direction::West => {
+                let i = self.index(next);
+                self.east[i] = open;
+            }
+            Direction::North => {
+                let i = self.index(next);
+                self.south[i] = open;
+            }
+        }
+    }
+
+    // The cells that can be walked to from `cell` in one step.
+    pub fn passages(&self, cell: Cell) -> impl Iterator<Item = Cell> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter(move |&direction| self.is_open(cell, direction))
+            .filter_map(move |direction| self.neighbour(cell, direction))
+    }
+
+    // How many walls have been knocked through.
+    pub fn passage_count(&self) -> usize {
+        self.east
+            .iter()
+            .chain(&self.south)
+            .filter(|&&open| open)
+            .count()
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = Cell> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| Cell { x, y }))
+    }
+}
+
+// Carves a maze by recursive backtracking: from the current cell, knock
+// through to a random neighbour that hasn't been visited and carry on from
+// there; when every neighbour has been, back up to the last cell that
+// still has one. Every cell gets visited once and joined to the maze by
+// one wall, so there's exactly one way between any two cells.
+//
+// The recursion is an explicit stack, so a big maze can't overflow the
+// real one. The same seed always gives the same maze.
+//
+// # Panics
+//
+// If either side is zero.
+pub fn generate(width: usize, height: usize, seed: u64) -> Maze {
+    let mut maze = Maze::new(width, height);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut visited = vec![false; width * height];
+    let start = maze.entrance();
+    visited[maze.index(start)] = true;
+    let mut stack = vec![start];
+    while let Some(&cell) = stack.last() {
+        let unvisited: Vec<(Direction, Cell)> = Direction::ALL
+            .into_iter()
+            .filter_map(|direction| Some((direction, maze.neighbour(cell, direction)?)))
+            .filter(|&(_, next)| !visited[maze.index(next)])
+            .collect();
+        match unvisited.choose(&mut rng) {
+            Some(&(direction, next)) => {
+                maze.set_open(cell, direction, true);
+                visited[maze.index(next)] = true;
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    maze
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    // Every cell reachable from the entrance.
+    fn reachable(maze: &Maze) -> HashSet<Cell> {
+        let mut seen = HashSet::from([maze.entrance()]);
+        let mut todo = vec![maze.entrance()];
+        while let Some(cell) = todo.pop() {
+            for next in maze.passages(cell) {
+                if seen.insert(next) {
+                    todo.push(next);
+                }
+            }
+        }
+        seen
+    }
+
+    #[test]
+    fn walls_are_shared_between_neighbours() {
+        let mut maze = Maze::new(3, 2);
+        let cell = Cell { x: 1, y: 0 };
+        maze.set_open(cell, Direction::West, true);
+        assert!(maze.is_open(Cell { x: 0, y: 0 }, Direction::East));
+        maze.set_open(cell, Direction::South, true);
+        assert!(maze.is_open(Cell { x: 1, y: 1 }, Direction::North));
+        assert_eq!(maze.passage_count(), 2);
+        assert_eq!(
+            maze.passages(cell).collect::<Vec<_>>(),
+            [Cell { x: 1, y: 1 }, Cell { x: 0, y: 0 }]
+        );
+        assert!(!maze.is_open(cell, Direction::North));
+    }
+
+    #[test]
+    #[should_panic(expected = "the outside wall can't be opened")]
+    fn the_outside_stays_shut() {
+        Maze::new(2, 2).set_open(Cell { x: 1, y: 1 }, Direction::East, true);
+    }
+
+    #[test]
+    fn generated_mazes_are_perfect() {
+        // A spanning tree: connected, with one passage fewer than cells. One
+        // more would make a loop - two ways between some cells - and one
+        // fewer would leave a part cut off.
+        for (width, height) in [(1, 1), (1, 7), (7, 1), (2, 2), (10, 10), (31, 17)] {
+            for seed in 0..10 {
+                let maze = generate(width, height, seed);
+                assert_eq!(maze.passage_count(), width * height - 1, "{width}x{height}");
+                assert_eq!(reachable(&maze).len(), width * height, "{width}x{height}");
+            }
+        }
+    }
+
+    #[test]
+    fn a_seed_gives_the_same_maze() {
+        assert_eq!(generate(12, 8, 193), generate(12, 8, 193));
+        assert_ne!(generate(12, 8, 193), generate(12, 8, 194));
+    }
+
+    #[test]
+    fn big_mazes_do_not_overflow_the_stack() {
+        let maze = generate(300, 300, 193);
+        assert_eq!(maze.passage_count(), 300 * 300 - 1);
+    }
+}
@@ -0,0 +1,196 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::maze::{Cell, Direction, Maze};
+
+// Lines and columns count from 1, as an editor would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    // A maze needs 2H + 1 lines for H rows of cells, so at least 3 and
+    // always odd.
+    LineCount(usize),
+    // The first line sets the width, 4W + 1 characters for W cells.
+    BadWidth(usize),
+    LineLength {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    // Where a wall goes, something that's neither wall nor gap.
+    Unexpected {
+        ch: char,
+        line: usize,
+        column: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::LineCount(count) => write!(
+                f,
+                "a maze needs an odd number of lines, at least 3, not {count}"
+            ),
+            ParseError::BadWidth(width) => write!(
+                f,
+                "the first line is {width} characters; a maze W cells wide needs 4W + 1"
+            ),
+            ParseError::LineLength {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} is {found} characters, but the first is {expected}"
+            ),
+            ParseError::Unexpected { ch, line, column } => {
+                write!(
+                    f,
+                    "line {line}, column {column}: {ch:?} isn't a wall or a gap"
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// Either style's wall, or a gap - which might have the path drawn in it.
+fn is_wall(ch: char, walls: [char; 2], line: usize, column: usize) -> Result<bool, ParseError> {
+    match ch {
+        _ if walls.contains(&ch) => Ok(true),
+        ' ' | '*' | '•' => Ok(false),
+        _ => Err(ParseError::Unexpected { ch, line, column }),
+    }
+}
+
+// Reads back what `render` draws, in either style, with or without a path
+// on it - the path is ignored. Only the walls between cells count: the
+// corners and the outside edge are skipped, since the maze's edge is always
+// wall, bar the entrance and exit.
+pub fn parse(text: &str) -> Result<Maze, ParseError> {
+    let lines: Vec<Vec<char>> = text.lines().map(|line| line.chars().collect()).collect();
+    if lines.len() < 3 || lines.len().is_multiple_of(2) {
+        return Err(ParseError::LineCount(lines.len()));
+    }
+    let columns = lines[0].len();
+    if columns < 5 || columns % 4 != 1 {
+        return Err(ParseError::BadWidth(columns));
+    }
+    for (index, line) in lines.iter().enumerate() {
+        if line.len() != columns {
+            return Err(ParseError::LineLength {
+                line: index + 1,
+                expected: columns,
+                found: line.len(),
+            });
+        }
+    }
+
+    let (width, height) = ((columns - 1) / 4, (lines.len() - 1) / 2);
+    let mut maze = Maze::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            let cell = Cell { x, y };
+            // The wall on the right of the cell, in its own line.
+            if x + 1 < width {
+                let (line, column) = (2 * y + 1, 4 * x + 4);
+                if !is_wall(lines[line][column], ['|', '│'], line + 1, column + 1)? {
+                    maze.set_open(cell, Direction::East, true);
+                }
+            }
+            // The wall under it: three characters that have to agree.
+            if y + 1 < height {
+                let line = 2 * y + 2;
+                let walls = (4 * x + 1..4 * x + 4)
+                    .map(|column| is_wall(lines[line][column], ['-', '─'], line + 1, column + 1))
+                    .collect::<Result<Vec<bool>, ParseError>>()?;
+                if walls.iter().any(|&wall| wall != walls[0]) {
+                    let column = 4 * x + 2;
+                    return Err(ParseError::Unexpected {
+                        ch: lines[line][column],
+                        line: line + 1,
+                        column: column + 1,
+                    });
+                }
+                if !walls[0] {
+                    maze.set_open(cell, Direction::South, true);
+                }
+            }
+        }
+    }
+    Ok(maze)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze::generate;
+    use crate::render::{render, Style};
+    use crate::solve::solve;
+
+    #[test]
+    fn reads_back_what_was_drawn() {
+        for seed in 0..20 {
+            let maze = generate(7, 5, seed);
+            let path = solve(&maze).unwrap();
+            for style in [Style::Unicode, Style::Ascii] {
+                assert_eq!(parse(&render(&maze, style, None)), Ok(maze.clone()));
+                assert_eq!(parse(&render(&maze, style, Some(&path))), Ok(maze.clone()));
+            }
+        }
+    }
+
+    #[test]
+    fn reads_the_fixtures() {
+        for fixture in [
+            include_str!("../tests/data/ascii.txt"),
+            include_str!("../tests/data/unicode.txt"),
+        ] {
+            let maze = parse(fixture).unwrap();
+            let style = if fixture.contains('+') {
+                Style::Ascii
+            } else {
+                Style::Unicode
+            };
+            assert_eq!(render(&maze, style, None), fixture);
+            assert_eq!(maze.passage_count(), maze.width() * maze.height() - 1);
+        }
+    }
+
+    #[test]
+    fn rejects_bad_shapes() {
+        assert_eq!(parse(""), Err(ParseError::LineCount(0)));
+        assert_eq!(parse("+---+\n|   |\n"), Err(ParseError::LineCount(2)));
+        assert_eq!(parse("+--+\n|  |\n+--+\n"), Err(ParseError::BadWidth(4)));
+        assert_eq!(
+            parse("+---+\n|   \n+---+\n"),
+            Err(ParseError::LineLength {
+                line: 2,
+                expected: 5,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_strange_walls() {
+        assert_eq!(
+            parse("+---+---+\n|   #   |\n+---+---+\n"),
+            Err(ParseError::Unexpected {
+                ch: '#',
+                line: 2,
+                column: 5
+            })
+        );
+        // Half a wall.
+        assert_eq!(
+            parse("+   +\n|   |\n+ - +\n|   |\n+   +\n"),
+            Err(ParseError::Unexpected {
+                ch: '-',
+                line: 3,
+                column: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::HashSet;
+
+use crate::maze::{Cell, Direction, Maze};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    // Box-drawing characters: ┌───┬───┐
+    #[default]
+    Unicode,
+    // Plain ASCII, for terminals and files that can't take Unicode:
+    // +---+---+
+    Ascii,
+}
+
+// Box-drawing corners, indexed by which arms they have: 1 up, 2 right,
+// 4 down, 8 left.
+const CORNERS: [char; 16] = [
+    ' ', '╵', '╶', '└', '╷', '│', '┌', '├', '╴', '┘', '─', '┴', '┐', '┤', '┬', '┼',
+];
+
+impl Style {
+    pub fn horizontal(self) -> char {
+        match self {
+            Style::Unicode => '─',
+            Style::Ascii => '-',
+        }
+    }
+
+    pub fn vertical(self) -> char {
+        match self {
+            Style::Unicode => '│',
+            Style::Ascii => '|',
+        }
+    }
+
+    pub fn path(self) -> char {
+        match self {
+            Style::Unicode => '•',
+            Style::Ascii => '*',
+        }
+    }
+
+    fn corner(self, up: bool, right: bool, down: bool, left: bool) -> char {
+        match self {
+            Style::Unicode => {
+                CORNERS[usize::from(up)
+                    | usize::from(right) << 1
+                    | usize::from(down) << 2
+                    | usize::from(left) << 3]
+            }
+            Style::Ascii => '+',
+        }
+    }
+}
+
+// Each cell is three characters wide and one high, with a character of
+// wall on every side - so a maze W cells wide and H high takes 4W + 1
+// columns and 2H + 1 lines. The entrance is a gap in the top wall above the
+// top left cell and the exit one in the bottom wall below the bottom right:
+//
+//     ╷   ┌───────┐
+//     │   │       │
+//     │   ╵   ╷   │
+//     │       │   │
+//     └───────┘   ╵
+struct Layout<'a> {
+    maze: &'a Maze,
+    style: Style,
+    on_path: HashSet<Cell>,
+    // Consecutive cells of the path, smaller first.
+    steps: HashSet<(Cell, Cell)>,
+}
+
+impl Layout<'_> {
+    // Whether the wall along the top of row `y` (y == height for the
+    // bottom edge) above column `x` is there.
+    fn horizontal_wall(&self, x: usize, y: usize) -> bool {
+        let maze = self.maze;
+        if y == 0 {
+            x != 0
+        } else if y == maze.height() {
+            x != maze.width() - 1
+        } else {
+            !maze.is_open(Cell { x, y: y - 1 }, Direction::South)
+        }
+    }
+
+    // Whether the wall on the left of column `x` (x == width for the right
+    // edge) in row `y` is there.
+    fn vertical_wall(&self, x: usize, y: usize) -> bool {
+        x == 0
+            || x == self.maze.width()
+            || !self.maze.is_open(Cell { x: x - 1, y }, Direction::East)
+    }
+
+    fn is_step(&self, a: Cell, b: Cell) -> bool {
+        self.steps.contains(&(a.min(b), a.max(b)))
+    }
+
+    // The line of corners and walls along the top of row `y`.
+    fn wall_line(&self, y: usize) -> String {
+        let (width, height) = (self.maze.width(), self.maze.height());
+        let mut line = String::new();
+        for x in 0..=width {
+            let up = y > 0 && self.vertical_wall(x, y - 1);
+            let down = y < height && self.vertical_wall(x, y);
+            let left = x > 0 && self.horizontal_wall(x - 1, y);
+            let right = x < width && self.horizontal_wall(x, y);
+            line.push(self.style.corner(up, right, down, left));
+            if x == width {
+                break;
+            }
+            let segment = if right { self.style.horizontal() } else { ' ' };
+            let inside_step =
+                y > 0 && y < height && self.is_step(Cell { x, y: y - 1 }, Cell { x, y });
+            let middle = if inside_step {
+                self.style.path()
+            } else {
+                segment
+            };
+            line.extend([segment, middle, segment]);
+        }
+        line
+    }
+
+    fn cell_line(&self, y: usize) -> String {
+        let width = self.maze.width();
+        let mut line = String::new();
+        for x in 0..=width {
+            let wall = if self.vertical_wall(x, y) {
+                self.style.vertical()
+            } else if self.is_step(Cell { x: x - 1, y }, Cell { x, y }) {
+                self.style.path()
+            } else {
+                ' '
+            };
+            line.push(wall);
+            if x == width {
+                break;
+            }
+            let middle = if self.on_path.contains(&Cell { x, y }) {
+                self.style.path()
+            } else {
+                ' '
+            };
+            line.extend([' ', middle, ' ']);
+        }
+        line
+    }
+}
+
+// Draws the maze, one line per row of walls and one per row of cells, each
+// ending in a newline. With a path, its cells and the gaps between them
+// are marked.
+pub fn render(maze: &Maze, style: Style, path: Option<&[Cell]>) -> String {
+    let path = path.unwrap_or_default();
+    let layout = Layout {
+        maze,
+        style,
+        on_path: path.iter().copied().collect(),
+        steps: path
+            .windows(2)
+            .map(|step| (step[0].min(step[1]), step[0].max(step[1])))
+            .collect(),
+    };
+    let mut text = String::new();
+    for y in 0..maze.height() {
+        text.push_str(&layout.wall_line(y));
+        text.push('\n');
+        text.push_str(&layout.cell_line(y));
+        text.push('\n');
+    }
+    text.push_str(&layout.wall_line(maze.height()));
+    text.push('\n');
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze::generate;
+    use crate::solve::solve;
+
+    fn two_by_two() -> Maze {
+        // In at the top left, across, down, and back along the bottom row.
+        let mut maze = Maze::new(2, 2);
+        maze.set_open(Cell { x: 0, y: 0 }, Direction::East, true);
+        maze.set_open(Cell { x: 1, y: 0 }, Direction::South, true);
+        maze.set_open(Cell { x: 1, y: 1 }, Direction::West, true);
+        maze
+    }
+
+    #[test]
+    fn draws_with_box_characters() {
+        assert_eq!(
+            render(&two_by_two(), Style::Unicode, None),
+            "╷   ╶───┐\n\
+             │       │\n\
+             ├───╴   │\n\
+             │       │\n\
+             └───╴   ╵\n"
+        );
+    }
+
+    #[test]
+    fn draws_in_ascii_with_the_path() {
+        let maze = two_by_two();
+        let path = solve(&maze).unwrap();
+        assert_eq!(
+            render(&maze, Style::Ascii, Some(&path)),
+            "+   +---+\n\
+             | * * * |\n\
+             +---+ * +\n\
+             |     * |\n\
+             +---+   +\n"
+        );
+    }
+
+    #[test]
+    fn every_line_is_the_same_width() {
+        let maze = generate(9, 4, 193);
+        for style in [Style::Unicode, Style::Ascii] {
+            let text = render(&maze, style, solve(&maze).as_deref());
+            let lines: Vec<&str> = text.lines().collect();
+            assert_eq!(lines.len(), 9);
+            assert!(lines.iter().all(|line| line.chars().count() == 37));
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::VecDeque;
+
+use crate::maze::{Cell, Maze};
+
+// The shortest way from the entrance to the exit, both included, found by
+// breadth-first search: cells are visited in order of how far they are
+// from the entrance, so the first time the exit comes up is by a shortest
+// route. Remembering where each cell was reached from lets the route be
+// walked back afterwards.
+//
+// None if there's no way through, which a generated maze always has, but
+// one read from a file might not.
+pub fn solve(maze: &Maze) -> Option<Vec<Cell>> {
+    let (entrance, exit) = (maze.entrance(), maze.exit());
+    let index = |cell: Cell| cell.y * maze.width() + cell.x;
+    let mut came_from: Vec<Option<Cell>> = vec![None; maze.width() * maze.height()];
+    let mut queue = VecDeque::from([entrance]);
+    // The entrance needs marking as seen, but it wasn't reached from
+    // anywhere: it's its own starting point.
+    came_from[index(entrance)] = Some(entrance);
+    while let Some(cell) = queue.pop_front() {
+        if cell == exit {
+            let mut path = vec![exit];
+            let mut at = exit;
+            while at != entrance {
+                at = came_from[index(at)].expect("every queued cell was reached from somewhere");
+                path.push(at);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for next in maze.passages(cell) {
+            if came_from[index(next)].is_none() {
+                came_from[index(next)] = Some(cell);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maze::{generate, Direction};
+
+    fn cell(x: usize, y: usize) -> Cell {
+        Cell { x, y }
+    }
+
+    #[test]
+    fn solves_generated_mazes() {
+        for seed in 0..20 {
+            let maze = generate(15, 9, seed);
+            let path = solve(&maze).expect("a generated maze has a way through");
+            assert_eq!(path.first(), Some(&maze.entrance()));
+            assert_eq!(path.last(), Some(&maze.exit()));
+            // Each step goes through an open wall...
+            for step in path.windows(2) {
+                assert!(
+                    maze.passages(step[0]).any(|next| next == step[1]),
+                    "{step:?}"
+                );
+            }
+            // ...and no cell comes up twice.
+            let mut cells = path.clone();
+            cells.sort();
+            cells.dedup();
+            assert_eq!(cells.len(), path.len());
+        }
+    }
+
+    #[test]
+    fn finds_the_shortest_way() {
+        // An open 3x3 room: the shortest way is four steps, five cells.
+        let mut maze = Maze::new(3, 3);
+        for cell in maze.cells().collect::<Vec<_>>() {
+            for direction in [Direction::East, Direction::South] {
+                if maze.neighbour(cell, direction).is_some() {
+                    maze.set_open(cell, direction, true);
+                }
+            }
+        }
+        assert_eq!(solve(&maze).unwrap().len(), 5);
+    }
+
+    #[test]
+    fn a_single_cell_is_its_own_way_through() {
+        assert_eq!(solve(&Maze::new(1, 1)), Some(vec![cell(0, 0)]));
+    }
+
+    #[test]
+    fn a_walled_off_exit_has_no_way_through() {
+        let mut maze = Maze::new(2, 2);
+        maze.set_open(cell(0, 0), Direction::East, true);
+        maze.set_open(cell(0, 0), Direction::South, true);
+        assert_eq!(solve(&maze), None);
+    }
+}
@@ -0,0 +1,48 @@
+use std::fs;
+use std::process::{Command, Output};
+
+fn maze(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_maze"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn draws_the_fixture_mazes() {
+    let unicode = maze(&["--width", "4", "--height", "3", "--seed", "2"]);
+    assert_eq!(unicode.status.code(), Some(0));
+    assert_eq!(
+        stdout(&unicode),
+        fs::read_to_string("tests/data/unicode.txt").unwrap()
+    );
+    let ascii = maze(&["--width", "5", "--height", "4", "--seed", "3", "--ascii"]);
+    assert_eq!(
+        stdout(&ascii),
+        fs::read_to_string("tests/data/ascii.txt").unwrap()
+    );
+}
+
+#[test]
+fn solves() {
+    let output = maze(&["--width", "3", "--height", "2", "--seed", "1", "--solve"]);
+    assert_eq!(
+        stdout(&output),
+        "╷   ┌───────┐\n\
+         │ • │ • • • │\n\
+         │ • ╵ • ╷ • │\n\
+         │ • • • │ • │\n\
+         └───────┘   ╵\n"
+    );
+}
+
+#[test]
+fn bad_sizes_are_usage_errors() {
+    let output = maze(&["--width", "0"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: maze"));
+}
@@ -0,0 +1,55 @@
+/* Things learned during this exercise:
+   * Tokenizing and parsing are separate steps with separate errors: the
+   tokenizer knows about quotes and backslashes, and the parser about
+   pipes and redirects, so neither has to think about the other. A
+   tokenizer that's a pure function of a string is also the part most
+   worth testing hard, since that's where the fiddly cases are.
+   * `Option<String>` for the word being built tells "no word yet" apart
+   from `""`, which is a word - an empty argument - that has to be kept.
+   * `slice::split` on the pipe token cuts a line into its commands, and
+   an empty piece is an empty command, wherever it is.
+   * `Command` takes a `Stdio` for each stream: `Stdio::piped()` to read
+   it from the parent, `Stdio::from(child.stdout)` to hand one child's
+   output to the next, and `Stdio::from(File)` for a redirect.
+   * Every child has to be started before any is waited for, or a full
+   pipe between two of them would block for ever. Dropping the parent's
+   copy of a pipe is what lets the reader see the end of it.
+   * `cd` can't be a program: a child changing its directory doesn't
+   change the shell's. Keeping the directory in the shell and passing it
+   to `current_dir` leaves the process's own directory alone.
+   * A program that isn't found is an `io::ErrorKind::NotFound` from
+   `spawn`, which sh reports with status 127.
+   * See https://doc.rust-lang.org/std/process/struct.Command.html and
+   https://pubs.opengroup.org/onlinepubs/9699919799/utilities/V3_chap02.html
+*/
+mod parse;
+mod shell;
+mod tokenize;
+
+pub use parse::{parse, ParseError, Pipeline, Redirect, SimpleCommand};
+pub use shell::{Flow, Shell, NOT_FOUND, NOT_RUNNABLE};
+pub use tokenize::{tokenize, Token, TokenizeError};
+
+use std::io::{self, BufRead, Write};
+
+// Reads lines and runs them until `exit` or the end of the input, and
+// returns the status to exit with.
+pub fn repl(
+    shell: &mut Shell,
+    input: impl BufRead,
+    mut output: impl Write,
+    mut errors: impl Write,
+) -> io::Result<i32> {
+    let mut lines = input.lines();
+    loop {
+        write!(output, "$ ")?;
+        output.flush()?;
+        let Some(line) = lines.next().transpose()? else {
+            writeln!(output)?;
+            return Ok(shell.status());
+        };
+        if let Flow::Exit(code) = shell.run_line(&line, &mut output, &mut errors)? {
+            return Ok(code);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use std::env;
+use std::io;
+use std::process;
+
+use minishell::Shell;
+
+fn main() {
+    let cwd = env::current_dir().unwrap_or_else(|err| {
+        eprintln!("minishell: can't find the current directory: {err}");
+        process::exit(1);
+    });
+    let home = env::var_os("HOME").map(Into::into);
+    let mut shell = Shell::new(cwd, home);
+    match minishell::repl(&mut shell, io::stdin().lock(), io::stdout(), io::stderr()) {
+        Ok(code) => process::exit(code),
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::tokenize::Token;
+
+// Where a command's output goes, when it isn't the next command or the
+// terminal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redirect {
+    pub path: String,
+    // `>>` rather than `>`.
+    pub append: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimpleCommand {
+    // The program, then its arguments. Never empty.
+    pub argv: Vec<String>,
+    pub stdin: Option<String>,
+    pub stdout: Option<Redirect>,
+}
+
+// Commands joined by pipes, each one's output going to the next one's
+// input. Only the first can read from a file, and only the last can
+// write to one, since everywhere else that's what the pipe is for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pipeline {
+    pub commands: Vec<SimpleCommand>,
+}
+
+// Commands count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    // The command before, between or after a `|` has no words, as in
+    // `| wc`, `ls | | wc` or `ls |`.
+    EmptyCommand {
+        command: usize,
+    },
+    // A `<`, `>` or `>>` at the end, or followed by another operator.
+    MissingPath(&'static str),
+    // Two of `<`, or of `>` and `>>`, on one command.
+    TwoRedirects {
+        command: usize,
+        operator: &'static str,
+    },
+    ReadInMiddle {
+        command: usize,
+    },
+    WriteInMiddle {
+        command: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::EmptyCommand { command } => {
+                write!(f, "command {command} of the pipeline is empty")
+            }
+            ParseError::MissingPath(operator) => write!(f, "{operator} needs a file name"),
+            ParseError::TwoRedirects { command, operator } => {
+                write!(f, "command {command} has {operator} twice")
+            }
+            ParseError::ReadInMiddle { command } => write!(
+                f,
+                "only the first command can read from a file, not command {command}"
+            ),
+            ParseError::WriteInMiddle { command } => write!(
+                f,
+                "only the last command can write to a file, not command {command}"
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+fn operator(token: &Token) -> &'static str {
+    match token {
+        Token::Word(_) => "a word",
+        Token::Pipe => "|",
+        Token::ReadFrom => "<",
+        Token::WriteTo => ">",
+        Token::AppendTo => ">>",
+    }
+}
+
+// One command's tokens, with no pipes among them.
+fn simple_command(tokens: &[Token], command: usize) -> Result<SimpleCommand, ParseError> {
+    let mut argv = Vec::new();
+    let mut stdin = None;
+    let mut stdout = None;
+    let mut tokens = tokens.iter();
+    while let Some(token) = tokens.next() {
+        let path = match token {
+            Token::Word(word) => {
+                argv.push(word.clone());
+                continue;
+            }
+            _ => match tokens.next() {
+                Some(Token::Word(path)) => path.clone(),
+                _ => return Err(ParseError::MissingPath(operator(token))),
+            },
+        };
+        let twice = ParseError::TwoRedirects {
+            command,
+            operator: operator(token),
+        };
+        if *token == Token::ReadFrom {
+            if stdin.replace(path).is_some() {
+                return Err(twice);
+            }
+        } else {
+            let append = *token == Token::AppendTo;
+            if stdout.replace(Redirect { path, append }).is_some() {
+                return Err(twice);
+            }
+        }
+    }
+    if argv.is_empty() {
+        return Err(ParseError::EmptyCommand { command });
+    }
+    Ok(SimpleCommand {
+        argv,
+        stdin,
+        stdout,
+    })
+}
+
+// Turns a line's tokens into a pipeline, or None for a line with nothing
+// on it.
+pub fn parse(tokens: &[Token]) -> Result<Option<Pipeline>, ParseError> {
+    if tokens.is_empty() {
+        return Ok(None);
+    }
+    let segments: Vec<&[Token]> = tokens.split(|token| *token == Token::Pipe).collect();
+    let last = segments.len();
+    let mut commands = Vec::new();
+    for (index, segment) in segments.into_iter().enumerate() {
+        let number = index + 1;
+        let command = simple_command(segment, number)?;
+        if command.stdin.is_some() && number != 1 {
+            return Err(ParseError::ReadInMiddle { command: number });
+        }
+        if command.stdout.is_some() && number != last {
+            return Err(ParseError::WriteInMiddle { command: number });
+        }
+        commands.push(command);
+    }
+    Ok(Some(Pipeline { commands }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenize::tokenize;
+
+    fn parse_line(line: &str) -> Result<Option<Pipeline>, ParseError> {
+        parse(&tokenize(line).unwrap())
+    }
+
+    fn command(argv: &[&str]) -> SimpleCommand {
+        SimpleCommand {
+            argv: argv.iter().map(|arg| arg.to_string()).collect(),
+            stdin: None,
+            stdout: None,
+        }
+    }
+
+    #[test]
+    fn a_single_command() {
+        assert_eq!(
+            parse_line("ls -l"),
+            Ok(Some(Pipeline {
+                commands: vec![command(&["ls", "-l"])]
+            }))
+        );
+        assert_eq!(parse_line(""), Ok(None));
+        assert_eq!(parse_line("   "), Ok(None));
+    }
+
+    #[test]
+    fn a_pipeline_with_redirects() {
+        let mut sort = command(&["sort"]);
+        sort.stdin = Some("in.txt".to_string());
+        let mut grep = command(&["grep", "foo"]);
+        grep.stdout = Some(Redirect {
+            path: "out.txt".to_string(),
+            append: false,
+        });
+        assert_eq!(
+            parse_line("sort < in.txt | uniq | grep foo > out.txt"),
+            Ok(Some(Pipeline {
+                commands: vec![sort, command(&["uniq"]), grep]
+            }))
+        );
+    }
+
+    #[test]
+    fn redirects_can_go_anywhere_in_the_command() {
+        let mut expected = command(&["sort", "-r"]);
+        expected.stdin = Some("in".to_string());
+        expected.stdout = Some(Redirect {
+            path: "log".to_string(),
+            append: true,
+        });
+        let pipeline = Pipeline {
+            commands: vec![expected],
+        };
+        assert_eq!(
+            parse_line(">> log sort < in -r"),
+            Ok(Some(pipeline.clone()))
+        );
+        assert_eq!(parse_line("sort -r <in >>log"), Ok(Some(pipeline)));
+    }
+
+    #[test]
+    fn empty_pipeline_segments() {
+        for (line, command) in [
+            ("| wc", 1),
+            ("ls |", 2),
+            ("ls | | wc", 2),
+            ("ls || wc", 2),
+            ("|", 1),
+            ("> out.txt", 1),
+            ("ls | > out.txt", 2),
+        ] {
+            assert_eq!(
+                parse_line(line),
+                Err(ParseError::EmptyCommand { command }),
+                "{line}"
+            );
+        }
+        assert_eq!(
+            parse_line("ls | | wc").unwrap_err().to_string(),
+            "command 2 of the pipeline is empty"
+        );
+    }
+
+    #[test]
+    fn a_redirect_needs_a_path() {
+        assert_eq!(parse_line("ls >"), Err(ParseError::MissingPath(">")));
+        assert_eq!(parse_line("ls >> | wc"), Err(ParseError::MissingPath(">>")));
+        assert_eq!(parse_line("wc < > x"), Err(ParseError::MissingPath("<")));
+        // A quoted operator is a word, so it's a path like any other.
+        assert_eq!(
+            parse_line("ls > '|'").unwrap().unwrap().commands[0].stdout,
+            Some(Redirect {
+                path: "|".to_string(),
+                append: false
+            })
+        );
+    }
+
+    #[test]
+    fn redirects_only_at_the_ends() {
+        assert_eq!(
+            parse_line("ls | wc < in"),
+            Err(ParseError::ReadInMiddle { command: 2 })
+        );
+        assert_eq!(
+            parse_line("ls > out | wc"),
+            Err(ParseError::WriteInMiddle { command: 1 })
+        );
+        assert_eq!(
+            parse_line("ls > a >> b"),
+            Err(ParseError::TwoRedirects {
+                command: 1,
+                operator: ">>"
+            })
+        );
+        assert_eq!(
+            parse_line("wc < a < b"),
+            Err(ParseError::TwoRedirects {
+                command: 1,
+                operator: "<"
+            })
+        );
+    }
+}
@@ -0,0 +1,471 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdout, Command, ExitStatus, Stdio};
+
+use crate::parse::{parse, Pipeline, Redirect, SimpleCommand};
+use crate::tokenize::tokenize;
+
+// sh's statuses for a command it couldn't run.
+pub const NOT_FOUND: i32 = 127;
+pub const NOT_RUNNABLE: i32 = 126;
+
+// What the REPL should do after a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flow {
+    Continue,
+    Exit(i32),
+}
+
+// The shell's state between lines. The working directory is kept here
+// rather than in the process, so `cd` doesn't change the directory of
+// anything else in the same process - the tests, for one.
+#[derive(Debug)]
+pub struct Shell {
+    cwd: PathBuf,
+    home: Option<PathBuf>,
+    // The last pipeline's, which `exit` with no argument exits with.
+    status: i32,
+}
+
+#[cfg(unix)]
+fn status_code(status: ExitStatus) -> i32 {
+    use std::os::unix::process::ExitStatusExt;
+    // Killed by a signal is 128 plus its number, as in sh.
+    status
+        .code()
+        .or(status.signal().map(|signal| 128 + signal))
+        .unwrap_or(1)
+}
+
+#[cfg(not(unix))]
+fn status_code(status: ExitStatus) -> i32 {
+    status.code().unwrap_or(1)
+}
+
+impl Shell {
+    pub fn new(cwd: PathBuf, home: Option<PathBuf>) -> Shell {
+        Shell {
+            cwd,
+            home,
+            status: 0,
+        }
+    }
+
+    pub fn cwd(&self) -> &Path {
+        &self.cwd
+    }
+
+    pub fn status(&self) -> i32 {
+        self.status
+    }
+
+    fn path(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.cwd.join(path)
+    }
+
+    fn open_output(&self, redirect: &Redirect) -> io::Result<File> {
+        OpenOptions::new()
+            .write(true)
+            .create(true)
+            .append(redirect.append)
+            .truncate(!redirect.append)
+            .open(self.path(&redirect.path))
+    }
+
+    // Runs one line, writing what the commands print to `output` and the
+    // shell's own complaints to `errors`.
+    pub fn run_line(
+        &mut self,
+        line: &str,
+        output: &mut impl Write,
+        errors: &mut impl Write,
+    ) -> io::Result<Flow> {
+        let pipeline = match tokenize(line)
+            .map_err(|err| err.to_string())
+            .and_then(|tokens| parse(&tokens).map_err(|err| err.to_string()))
+        {
+            Ok(Some(pipeline)) => pipeline,
+            Ok(None) => return Ok(Flow::Continue),
+            Err(err) => {
+                writeln!(errors, "minishell: {err}")?;
+                self.status = 2;
+                return Ok(Flow::Continue);
+            }
+        };
+        if let Some(builtin) = pipeline
+            .commands
+            .iter()
+            .find(|command| is_builtin(&command.argv[0]))
+        {
+            if pipeline.commands.len() > 1 {
+                writeln!(
+                    errors,
+                    "minishell: {} can't be part of a pipeline",
+                    builtin.argv[0]
+                )?;
+                self.status = 2;
+                return Ok(Flow::Continue);
+            }
+            return self.builtin(&pipeline.commands[0], output, errors);
+        }
+        self.status = self.spawn(&pipeline, output, errors)?;
+        if self.status != 0 {
+            writeln!(errors, "[exit {}]", self.status)?;
+        }
+        Ok(Flow::Continue)
+    }
+
+    fn builtin(
+        &mut self,
+        command: &SimpleCommand,
+        output: &mut impl Write,
+        errors: &mut impl Write,
+    ) -> io::Result<Flow> {
+        let (name, args) = (command.argv[0].as_str(), &command.argv[1..]);
+        let result = match name {
+            "exit" => match args {
+                [] => return Ok(Flow::Exit(self.status)),
+                [code] => match code.parse() {
+                    Ok(code) => return Ok(Flow::Exit(code)),
+                    Err(_) => Err(format!("exit: {code:?} isn't a number")),
+                },
+                _ => Err("exit: too many arguments".to_string()),
+            },
+            "cd" => self.cd(args),
+            _ => self.pwd(args, command.stdout.as_ref(), output),
+        };
+        self.status = match result {
+            Ok(()) => 0,
+            Err(err) => {
+                writeln!(errors, "minishell: {err}")?;
+                1
+            }
+        };
+        Ok(Flow::Continue)
+    }
+
+    // `cd` on its own goes home.
+    fn cd(&mut self, args: &[String]) -> Result<(), String> {
+        let target = match args {
+            [] => self.home.clone().ok_or("cd: no home directory")?,
+            [dir] => self.path(dir),
+            _ => return Err("cd: too many arguments".to_string()),
+        };
+        // Canonical, so `..` is taken off rather than piling up.
+        let dir =
+            fs::canonicalize(&target).map_err(|err| format!("cd: {}: {err}", target.display()))?;
+        if !dir.is_dir() {
+            return Err(format!("cd: {}: not a directory", target.display()));
+        }
+        self.cwd = dir;
+        Ok(())
+    }
+
+    fn pwd(
+        &self,
+        args: &[String],
+        redirect: Option<&Redirect>,
+        output: &mut impl Write,
+    ) -> Result<(), String> {
+        if !args.is_empty() {
+            return Err("pwd: takes no arguments".to_string());
+        }
+        let line = format!("{}\n", self.cwd.display());
+        match redirect {
+            Some(redirect) => self
+                .open_output(redirect)
+                .and_then(|mut file| file.write_all(line.as_bytes()))
+                .map_err(|err| format!("{}: {err}", redirect.path)),
+            None => output
+                .write_all(line.as_bytes())
+                .map_err(|err| err.to_string()),
+        }
+    }
+
+    // Starts every command of the pipeline, each reading from the one
+    // before, then copies the last one's output to `output` and waits for
+    // them all. Returns the last command's status, as sh does.
+    //
+    // A command that can't be started - not found, or a file it should
+    // read or write won't open - is reported and skipped, and the next
+    // command reads nothing in its place.
+    fn spawn(
+        &self,
+        pipeline: &Pipeline,
+        output: &mut impl Write,
+        errors: &mut impl Write,
+    ) -> io::Result<i32> {
+        let mut children: Vec<Child> = Vec::new();
+        // The previous command's output, or None if it has none to give.
+        let mut previous: Option<ChildStdout> = None;
+        let mut status = 0;
+        for (index, command) in pipeline.commands.iter().enumerate() {
+            match self.start(command, index == 0, previous.take()) {
+                Ok(mut child) => {
+                    previous = child.stdout.take();
+                    children.push(child);
+                    status = 0;
+                }
+                Err((message, code)) => {
+                    writeln!(errors, "minishell: {message}")?;
+                    status = code;
+                }
+            }
+        }
+
+        // The last command's output is piped here, unless it went to a
+        // file or the command didn't start.
+        let copied = match previous.as_mut() {
+            Some(stdout) => io::copy(stdout, output).map(|_| ()),
+            None => Ok(()),
+        };
+        drop(previous);
+        let mut last_status = None;
+        for mut child in children {
+            last_status = Some(child.wait()?);
+        }
+        copied?;
+        output.flush()?;
+        // The last child is the last command only if it started; if it
+        // didn't, `status` already says why.
+        Ok(match last_status {
+            Some(exit) if status == 0 => status_code(exit),
+            _ => status,
+        })
+    }
+
+    // Starts one command. `previous` is the output of the command before;
+    // `first` with no redirect reads the shell's own stdin, and anything
+    // else without either reads nothing.
+    fn start(
+        &self,
+        command: &SimpleCommand,
+        first: bool,
+        previous: Option<ChildStdout>,
+    ) -> Result<Child, (String, i32)> {
+        let program = &command.argv[0];
+        // A path with a slash is relative to the shell's directory; a bare
+        // name is looked up in $PATH.
+        let mut process = if program.contains('/') {
+            Command::new(self.path(program))
+        } else {
+            Command::new(program)
+        };
+        process.args(&command.argv[1..]).current_dir(&self.cwd);
+
+        let failed = |path: &str, err: io::Error| (format!("{path}: {err}"), 1);
+        let stdin = match (&command.stdin, previous) {
+            (Some(path), _) => {
+                Stdio::from(File::open(self.path(path)).map_err(|err| failed(path, err))?)
+            }
+            (None, Some(previous)) => Stdio::from(previous),
+            (None, None) if first => Stdio::inherit(),
+            (None, None) => Stdio::null(),
+        };
+        let stdout = match &command.stdout {
+            Some(redirect) => Stdio::from(
+                self.open_output(redirect)
+                    .map_err(|err| failed(&redirect.path, err))?,
+            ),
+            None => Stdio::piped(),
+        };
+        process
+            .stdin(stdin)
+            .stdout(stdout)
+            .spawn()
+            .map_err(|err| match err.kind() {
+                io::ErrorKind::NotFound => (format!("{program}: command not found"), NOT_FOUND),
+                _ => (format!("{program}: {err}"), NOT_RUNNABLE),
+            })
+    }
+}
+
+fn is_builtin(name: &str) -> bool {
+    matches!(name, "cd" | "exit" | "pwd")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A fresh directory under the system temp dir, removed again when the
+    // test is over.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let dir = std::env::temp_dir().join(format!("minishell_{name}_{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            // Canonical, to compare with what `cd` leaves.
+            TempDir(fs::canonicalize(dir).unwrap())
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    // Runs a line and returns the flow, what it printed and its errors.
+    fn run(shell: &mut Shell, line: &str) -> (Flow, String, String) {
+        let (mut output, mut errors) = (Vec::new(), Vec::new());
+        let flow = shell.run_line(line, &mut output, &mut errors).unwrap();
+        (
+            flow,
+            String::from_utf8(output).unwrap(),
+            String::from_utf8(errors).unwrap(),
+        )
+    }
+
+    fn output(shell: &mut Shell, line: &str) -> String {
+        let (flow, output, errors) = run(shell, line);
+        assert_eq!((flow, errors.as_str()), (Flow::Continue, ""), "{line}");
+        output
+    }
+
+    #[test]
+    fn runs_a_pipeline() {
+        let dir = TempDir::new("pipeline");
+        let mut shell = Shell::new(dir.0.clone(), None);
+        assert_eq!(output(&mut shell, "echo hello world"), "hello world\n");
+        assert_eq!(
+            output(&mut shell, r#"printf "b\na\nc\n" | sort | tr a-z A-Z"#),
+            "A\nB\nC\n"
+        );
+        assert_eq!(shell.status(), 0);
+    }
+
+    #[test]
+    fn redirects_in_and_out() {
+        let dir = TempDir::new("redirect");
+        let mut shell = Shell::new(dir.0.clone(), None);
+        assert_eq!(output(&mut shell, "echo one > 'out file.txt'"), "");
+        assert_eq!(output(&mut shell, "echo two >> out\\ file.txt"), "");
+        assert_eq!(
+            fs::read_to_string(dir.0.join("out file.txt")).unwrap(),
+            "one\ntwo\n"
+        );
+        assert_eq!(
+            output(
+                &mut shell,
+                "tr a-z A-Z < \"out file.txt\" | sort -r > sorted"
+            ),
+            ""
+        );
+        assert_eq!(
+            fs::read_to_string(dir.0.join("sorted")).unwrap(),
+            "TWO\nONE\n"
+        );
+        // > starts again.
+        output(&mut shell, "echo three > sorted");
+        assert_eq!(fs::read_to_string(dir.0.join("sorted")).unwrap(), "three\n");
+    }
+
+    #[test]
+    fn reports_exit_statuses() {
+        let dir = TempDir::new("status");
+        let mut shell = Shell::new(dir.0.clone(), None);
+        let (flow, _, errors) = run(&mut shell, "sh -c 'exit 3'");
+        assert_eq!((flow, errors.as_str()), (Flow::Continue, "[exit 3]\n"));
+        assert_eq!(shell.status(), 3);
+        // The last command's status is the pipeline's.
+        run(&mut shell, "sh -c 'exit 3' | true");
+        assert_eq!(shell.status(), 0);
+        run(&mut shell, "true | false");
+        assert_eq!(shell.status(), 1);
+    }
+
+    #[test]
+    fn missing_commands_and_files() {
+        let dir = TempDir::new("missing");
+        let mut shell = Shell::new(dir.0.clone(), None);
+        let (_, _, errors) = run(&mut shell, "no-such-command-here --help");
+        assert_eq!(
+            errors,
+            "minishell: no-such-command-here: command not found\n[exit 127]\n"
+        );
+        assert_eq!(shell.status(), NOT_FOUND);
+
+        // The rest of the pipeline still runs, reading nothing.
+        let (_, output, errors) = run(&mut shell, "no-such-command-here | wc -l");
+        assert_eq!(output.trim(), "0");
+        assert_eq!(
+            errors,
+            "minishell: no-such-command-here: command not found\n"
+        );
+
+        let (_, _, errors) = run(&mut shell, "cat < absent.txt");
+        assert!(errors.starts_with("minishell: absent.txt: "), "{errors}");
+        assert!(errors.ends_with("[exit 1]\n"), "{errors}");
+    }
+
+    #[test]
+    fn syntax_errors_run_nothing() {
+        let dir = TempDir::new("syntax");
+        let mut shell = Shell::new(dir.0.clone(), None);
+        let (_, output, errors) = run(&mut shell, "echo \"never closed > out.txt");
+        assert_eq!(output, "");
+        assert_eq!(errors, "minishell: the \" at column 6 is never closed\n");
+        let (_, _, errors) = run(&mut shell, "echo hi | | wc > out.txt");
+        assert_eq!(errors, "minishell: command 2 of the pipeline is empty\n");
+        assert_eq!(shell.status(), 2);
+        assert_eq!(fs::read_dir(&dir.0).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn cd_and_pwd() {
+        let dir = TempDir::new("cd");
+        fs::create_dir_all(dir.0.join("a/b")).unwrap();
+        let mut shell = Shell::new(dir.0.clone(), Some(dir.0.join("a")));
+        assert_eq!(output(&mut shell, "cd a/b"), "");
+        assert_eq!(shell.cwd(), dir.0.join("a/b"));
+        assert_eq!(
+            output(&mut shell, "pwd"),
+            format!("{}\n", dir.0.join("a/b").display())
+        );
+        // Commands run in the shell's directory, not the process's.
+        output(&mut shell, "touch here");
+        assert!(dir.0.join("a/b/here").exists());
+
+        output(&mut shell, "cd ../..");
+        assert_eq!(shell.cwd(), dir.0);
+        output(&mut shell, "cd");
+        assert_eq!(shell.cwd(), dir.0.join("a"));
+        output(&mut shell, "pwd > where");
+        assert_eq!(
+            fs::read_to_string(dir.0.join("a/where")).unwrap(),
+            format!("{}\n", dir.0.join("a").display())
+        );
+
+        let (_, _, errors) = run(&mut shell, "cd nowhere");
+        assert!(errors.starts_with("minishell: cd: "), "{errors}");
+        assert_eq!(shell.status(), 1);
+        assert_eq!(shell.cwd(), dir.0.join("a"));
+        let (_, _, errors) = run(&mut shell, "cd where");
+        assert!(errors.ends_with("where: not a directory\n"), "{errors}");
+    }
+
+    #[test]
+    fn exit() {
+        let dir = TempDir::new("exit");
+        let mut shell = Shell::new(dir.0.clone(), None);
+        assert_eq!(run(&mut shell, "exit 4").0, Flow::Exit(4));
+        run(&mut shell, "false");
+        assert_eq!(run(&mut shell, "exit").0, Flow::Exit(1));
+        let (flow, _, errors) = run(&mut shell, "exit soon");
+        assert_eq!(flow, Flow::Continue);
+        assert_eq!(errors, "minishell: exit: \"soon\" isn't a number\n");
+    }
+
+    #[test]
+    fn builtins_are_not_piped() {
+        let dir = TempDir::new("builtin_pipe");
+        let mut shell = Shell::new(dir.0.clone(), None);
+        let (_, output, errors) = run(&mut shell, "pwd | wc -c");
+        assert_eq!(output, "");
+        assert_eq!(errors, "minishell: pwd can't be part of a pipeline\n");
+    }
+}
@@ -0,0 +1,230 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    Word(String),
+    // |
+    Pipe,
+    // <
+    ReadFrom,
+    // >
+    WriteTo,
+    // >>
+    AppendTo,
+}
+
+// Columns count characters from 1, as an editor would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenizeError {
+    // The column is the opening quote's.
+    UnterminatedQuote { quote: char, column: usize },
+    // A backslash at the very end, with nothing to escape.
+    TrailingBackslash { column: usize },
+}
+
+impl fmt::Display for TokenizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TokenizeError::UnterminatedQuote { quote, column } => {
+                write!(f, "the {quote} at column {column} is never closed")
+            }
+            TokenizeError::TrailingBackslash { column } => {
+                write!(
+                    f,
+                    "the \\ at column {column} has nothing after it to escape"
+                )
+            }
+        }
+    }
+}
+
+impl Error for TokenizeError {}
+
+// Splits a command line into words and operators, the way sh does without
+// its variables, globs and substitutions:
+//
+// * Spaces and tabs separate words, and `|`, `<`, `>` and `>>` are
+//   operators wherever they are, so `ls|wc` is three tokens.
+// * A backslash makes the next character part of the word, so `a\ b` is
+//   one word and `\|` isn't a pipe.
+// * Inside double quotes everything is part of the word except `\"` and
+//   `\\`, which are a quote and a backslash. Inside single quotes
+//   everything is, backslashes too.
+// * Quoted and unquoted parts next to each other are one word, so
+//   `"a b"c` is `a bc`, and `""` is a word with nothing in it.
+pub fn tokenize(line: &str) -> Result<Vec<Token>, TokenizeError> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens = Vec::new();
+    // None between words. Some("") is a word that's only quotes so far.
+    let mut word: Option<String> = None;
+    let mut at = 0;
+    while at < chars.len() {
+        let ch = chars[at];
+        let column = at + 1;
+        at += 1;
+        match ch {
+            ' ' | '\t' => tokens.extend(word.take().map(Token::Word)),
+            '|' | '<' | '>' => {
+                tokens.extend(word.take().map(Token::Word));
+                tokens.push(match ch {
+                    '|' => Token::Pipe,
+                    '<' => Token::ReadFrom,
+                    _ if chars.get(at) == Some(&'>') => {
+                        at += 1;
+                        Token::AppendTo
+                    }
+                    _ => Token::WriteTo,
+                });
+            }
+            '\\' => {
+                let escaped = *chars
+                    .get(at)
+                    .ok_or(TokenizeError::TrailingBackslash { column })?;
+                at += 1;
+                word.get_or_insert_with(String::new).push(escaped);
+            }
+            '"' | '\'' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    let inside = *chars
+                        .get(at)
+                        .ok_or(TokenizeError::UnterminatedQuote { quote: ch, column })?;
+                    at += 1;
+                    match inside {
+                        _ if inside == ch => break,
+                        '\\' if ch == '"' && matches!(chars.get(at), Some('"' | '\\')) => {
+                            word.push(chars[at]);
+                            at += 1;
+                        }
+                        _ => word.push(inside),
+                    }
+                }
+            }
+            _ => word.get_or_insert_with(String::new).push(ch),
+        }
+    }
+    tokens.extend(word.map(Token::Word));
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(line: &str) -> Vec<String> {
+        tokenize(line)
+            .unwrap()
+            .into_iter()
+            .map(|token| match token {
+                Token::Word(word) => word,
+                other => panic!("{other:?} in {line:?}"),
+            })
+            .collect()
+    }
+
+    fn word(word: &str) -> Token {
+        Token::Word(word.to_string())
+    }
+
+    #[test]
+    fn splits_on_spaces_and_tabs() {
+        assert_eq!(words("ls -l  /tmp"), ["ls", "-l", "/tmp"]);
+        assert_eq!(words("  \techo\thi  "), ["echo", "hi"]);
+        assert!(tokenize("").unwrap().is_empty());
+        assert!(tokenize("   ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn finds_operators_with_or_without_spaces() {
+        let expected = [
+            word("ls"),
+            Token::Pipe,
+            word("grep"),
+            word("foo"),
+            Token::WriteTo,
+            word("out.txt"),
+        ];
+        assert_eq!(tokenize("ls | grep foo > out.txt").unwrap(), expected);
+        assert_eq!(tokenize("ls|grep foo>out.txt").unwrap(), expected);
+        assert_eq!(
+            tokenize("sort<in >>log").unwrap(),
+            [
+                word("sort"),
+                Token::ReadFrom,
+                word("in"),
+                Token::AppendTo,
+                word("log")
+            ]
+        );
+        // Two > with a space between them are two operators.
+        assert_eq!(tokenize("> >").unwrap(), [Token::WriteTo, Token::WriteTo]);
+        assert_eq!(tokenize("||").unwrap(), [Token::Pipe, Token::Pipe]);
+    }
+
+    #[test]
+    fn double_quotes_keep_spaces_and_operators() {
+        assert_eq!(words(r#"echo "hello   world""#), ["echo", "hello   world"]);
+        assert_eq!(words(r#"grep "a | b > c""#), ["grep", "a | b > c"]);
+        assert_eq!(words(r#""a b"c"#), ["a bc"]);
+        assert_eq!(words(r#"x"y"z"#), ["xyz"]);
+        assert_eq!(words(r#"'single "quoted"'"#), ["single \"quoted\""]);
+    }
+
+    #[test]
+    fn empty_quotes_are_an_empty_word() {
+        assert_eq!(words(r#"printf "" x"#), ["printf", "", "x"]);
+        assert_eq!(words("''"), [""]);
+    }
+
+    #[test]
+    fn backslashes_escape() {
+        assert_eq!(words(r"cat my\ file.txt"), ["cat", "my file.txt"]);
+        assert_eq!(
+            words(r#"echo \| \> \\ \"hi\""#),
+            ["echo", "|", ">", "\\", "\"hi\""]
+        );
+        // Inside double quotes only \" and \\ are escapes.
+        assert_eq!(words(r#""a \"b\" \\ \n""#), [r#"a "b" \ \n"#]);
+        // And inside single quotes nothing is.
+        assert_eq!(words(r"'a\ b\'"), [r"a\ b\"]);
+    }
+
+    #[test]
+    fn unterminated_quotes() {
+        assert_eq!(
+            tokenize(r#"echo "hello"#),
+            Err(TokenizeError::UnterminatedQuote {
+                quote: '"',
+                column: 6
+            })
+        );
+        assert_eq!(
+            tokenize("echo 'it"),
+            Err(TokenizeError::UnterminatedQuote {
+                quote: '\'',
+                column: 6
+            })
+        );
+        // An escaped quote doesn't close the string.
+        assert_eq!(
+            tokenize(r#""ab\""#),
+            Err(TokenizeError::UnterminatedQuote {
+                quote: '"',
+                column: 1
+            })
+        );
+        assert_eq!(
+            tokenize(r#"é "x"#).unwrap_err().to_string(),
+            "the \" at column 3 is never closed"
+        );
+    }
+
+    #[test]
+    fn a_trailing_backslash() {
+        assert_eq!(
+            tokenize(r"echo \"),
+            Err(TokenizeError::TrailingBackslash { column: 6 })
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+// A directory of its own for each test, removed when it's over.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> TempDir {
+        let dir = std::env::temp_dir().join(format!("minishell_cli_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(fs::canonicalize(dir).unwrap())
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+// Runs the shell in `dir` with `script` as its input.
+fn minishell(dir: &TempDir, script: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_minishell"))
+        .current_dir(&dir.0)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(script.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> &str {
+    std::str::from_utf8(&output.stderr).unwrap()
+}
+
+#[test]
+fn pipes_into_a_file() {
+    let dir = TempDir::new("pipes");
+    fs::write(dir.0.join("words.txt"), "pear\napple\nfig\napple\n").unwrap();
+    let output = minishell(
+        &dir,
+        "sort < words.txt | uniq | grep p > out.txt\ncat out.txt\n",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "$ $ apple\npear\n$ \n");
+    assert_eq!(stderr(&output), "");
+    assert_eq!(
+        fs::read_to_string(dir.0.join("out.txt")).unwrap(),
+        "apple\npear\n"
+    );
+}
+
+#[test]
+fn quoting_reaches_the_program_intact() {
+    let dir = TempDir::new("quoting");
+    let output = minishell(
+        &dir,
+        "printf '%s|' \"two  spaces\" a\\ b \"\" 'x > y' > args.txt\n",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        fs::read_to_string(dir.0.join("args.txt")).unwrap(),
+        "two  spaces|a b||x > y|"
+    );
+}
+
+#[test]
+fn appends_and_changes_directory() {
+    let dir = TempDir::new("cd");
+    fs::create_dir(dir.0.join("sub")).unwrap();
+    let output = minishell(
+        &dir,
+        "cd sub\necho first > log\necho second >> log\npwd\ncd ..\nwc -l < sub/log\n",
+    );
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        fs::read_to_string(dir.0.join("sub/log")).unwrap(),
+        "first\nsecond\n"
+    );
+    let stdout = stdout(&output);
+    assert!(
+        stdout.contains(&format!("{}\n", dir.0.join("sub").display())),
+        "{stdout}"
+    );
+    assert!(stdout.ends_with("$ 2\n$ \n"), "{stdout}");
+}
+
+#[test]
+fn reports_statuses_and_exits_with_the_code_given() {
+    let dir = TempDir::new("status");
+    let output = minishell(
+        &dir,
+        "sh -c 'exit 5'\nnot-a-real-command\nexit 7\necho unreachable\n",
+    );
+    assert_eq!(output.status.code(), Some(7));
+    assert_eq!(
+        stderr(&output),
+        "[exit 5]\nminishell: not-a-real-command: command not found\n[exit 127]\n"
+    );
+    assert!(!stdout(&output).contains("unreachable"));
+}
+
+#[test]
+fn the_end_of_input_exits_with_the_last_status() {
+    let dir = TempDir::new("eof");
+    let output = minishell(&dir, "false\n");
+    assert_eq!(output.status.code(), Some(1));
+    let output = minishell(&dir, "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "$ \n");
+}
+
+#[test]
+fn syntax_errors_are_reported_and_the_shell_goes_on() {
+    let dir = TempDir::new("syntax");
+    let output = minishell(&dir, "echo \"oops\n| wc\necho fine\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stderr(&output),
+        "minishell: the \" at column 6 is never closed\n\
+         minishell: command 1 of the pipeline is empty\n"
+    );
+    assert!(stdout(&output).ends_with("$ fine\n$ \n"));
+}
@@ -0,0 +1,259 @@
+/* Things learned during this exercise:
+   * English reads big numbers in groups of three digits, each followed by
+   a scale word: 1,234,567 is "one million", "two hundred thirty-four
+   thousand", "five hundred sixty-seven". So the whole job is naming a
+   number below 1000, plus a table of scales - and a function that calls
+   itself for the part above the biggest scale that fits.
+   * `i64::MIN` has no positive i64 to match it: its magnitude is one more
+   than `i64::MAX`, so `-n` overflows (a panic in debug builds). Working on
+   `n.unsigned_abs()`, a u64, sidesteps that - every i64's magnitude fits.
+   * Building the words up in a `Vec` and joining with " " means no
+   piece has to worry about the spaces around it, so there are never two in
+   a row or one at the end.
+   * Ordinals only change the last word: "forty-two" becomes "forty-second"
+   and "one hundred" becomes "one hundredth". `rfind` with an array of
+   chars finds where that last word starts, after a space or a hyphen.
+   * See https://doc.rust-lang.org/std/primitive.i64.html#method.unsigned_abs
+*/
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+// Indexed by the tens digit; 0 and 1 are never used, since numbers below
+// twenty have their own names.
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+// Each scale is a thousand times the one before. u64::MAX is about 18
+// quintillion, so quintillion is as far as it goes.
+const SCALES: [(u64, &str); 6] = [
+    (1_000_000_000_000_000_000, "quintillion"),
+    (1_000_000_000_000_000, "quadrillion"),
+    (1_000_000_000_000, "trillion"),
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1_000, "thousand"),
+];
+
+// 1 to 99, with the hyphen American style puts between the tens and ones.
+fn below_hundred(n: u64) -> String {
+    match n {
+        0..=19 => ONES[n as usize].to_string(),
+        _ if n.is_multiple_of(10) => TENS[(n / 10) as usize].to_string(),
+        _ => format!("{}-{}", TENS[(n / 10) as usize], ONES[(n % 10) as usize]),
+    }
+}
+
+// Pushes the words for `n`, which mustn't be zero: zero is only ever said
+// for the whole number, never for a group ("one million", not "one million
+// zero thousand zero").
+fn push_words(n: u64, words: &mut Vec<String>) {
+    if let Some(&(scale, name)) = SCALES.iter().find(|&&(scale, _)| n >= scale) {
+        push_words(n / scale, words);
+        words.push(name.to_string());
+        if !n.is_multiple_of(scale) {
+            push_words(n % scale, words);
+        }
+        return;
+    }
+    if n >= 100 {
+        words.push(ONES[(n / 100) as usize].to_string());
+        words.push("hundred".to_string());
+    }
+    // American style: no "and" - "one hundred one", not "one hundred and
+    // one".
+    if !n.is_multiple_of(100) {
+        words.push(below_hundred(n % 100));
+    }
+}
+
+pub fn number_to_words(n: i64) -> String {
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+    let mut words = Vec::new();
+    if n < 0 {
+        words.push("negative".to_string());
+    }
+    push_words(n.unsigned_abs(), &mut words);
+    words.join(" ")
+}
+
+fn ordinal_word(word: &str) -> String {
+    match word {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        _ => match word.strip_suffix('y') {
+            // twenty -> twentieth
+            Some(stem) => format!("{stem}ieth"),
+            None => format!("{word}th"),
+        },
+    }
+}
+
+// "forty-second", "one hundredth", "negative first".
+pub fn number_to_ordinal(n: i64) -> String {
+    let cardinal = number_to_words(n);
+    // The last word starts after the last space or hyphen, whichever is
+    // later - or it's the whole thing.
+    let split = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = cardinal.split_at(split);
+    format!("{head}{}", ordinal_word(last))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_numbers() {
+        assert_eq!(number_to_words(0), "zero");
+        assert_eq!(number_to_words(7), "seven");
+        assert_eq!(number_to_words(13), "thirteen");
+        assert_eq!(number_to_words(20), "twenty");
+        assert_eq!(number_to_words(21), "twenty-one");
+        assert_eq!(number_to_words(99), "ninety-nine");
+    }
+
+    #[test]
+    fn hundreds_without_and() {
+        assert_eq!(number_to_words(100), "one hundred");
+        assert_eq!(number_to_words(101), "one hundred one");
+        assert_eq!(number_to_words(342), "three hundred forty-two");
+        assert_eq!(number_to_words(910), "nine hundred ten");
+    }
+
+    #[test]
+    fn scales() {
+        assert_eq!(
+            number_to_words(1_234),
+            "one thousand two hundred thirty-four"
+        );
+        assert_eq!(number_to_words(1_000_001), "one million one");
+        assert_eq!(
+            number_to_words(12_000_345_000),
+            "twelve billion three hundred forty-five thousand"
+        );
+        assert_eq!(
+            number_to_words(999_999),
+            "nine hundred ninety-nine thousand nine hundred ninety-nine"
+        );
+    }
+
+    #[test]
+    fn exact_powers_of_ten() {
+        let expected = [
+            "one",
+            "ten",
+            "one hundred",
+            "one thousand",
+            "ten thousand",
+            "one hundred thousand",
+            "one million",
+            "ten million",
+            "one hundred million",
+            "one billion",
+            "ten billion",
+            "one hundred billion",
+            "one trillion",
+            "ten trillion",
+            "one hundred trillion",
+            "one quadrillion",
+            "ten quadrillion",
+            "one hundred quadrillion",
+            "one quintillion",
+        ];
+        for (power, words) in expected.iter().enumerate() {
+            assert_eq!(number_to_words(10i64.pow(power as u32)), *words);
+        }
+    }
+
+    #[test]
+    fn negative_numbers() {
+        assert_eq!(number_to_words(-1), "negative one");
+        assert_eq!(
+            number_to_words(-1_234),
+            "negative one thousand two hundred thirty-four"
+        );
+    }
+
+    #[test]
+    fn the_ends_of_the_range() {
+        let digits = "nine quintillion two hundred twenty-three quadrillion three hundred \
+                      seventy-two trillion thirty-six billion eight hundred fifty-four \
+                      million seven hundred seventy-five thousand eight hundred";
+        assert_eq!(number_to_words(i64::MAX), format!("{digits} seven"));
+        assert_eq!(
+            number_to_words(i64::MIN),
+            format!("negative {digits} eight")
+        );
+    }
+
+    #[test]
+    fn no_stray_spaces_or_hyphens() {
+        for n in 0..=10_000 {
+            let words = number_to_words(n);
+            assert!(!words.contains("  "), "{n}: {words:?}");
+            assert_eq!(words.trim(), words, "{n}");
+            assert!(!words.contains("zero") || n == 0, "{n}: {words:?}");
+            assert!(
+                !words.contains("--") && !words.ends_with('-'),
+                "{n}: {words:?}"
+            );
+            // Hyphens only join tens and ones.
+            assert_eq!(
+                n % 100 > 20 && n % 10 != 0,
+                words.contains('-'),
+                "{n}: {words:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn ordinals() {
+        for (n, ordinal) in [
+            (0, "zeroth"),
+            (1, "first"),
+            (2, "second"),
+            (3, "third"),
+            (5, "fifth"),
+            (8, "eighth"),
+            (9, "ninth"),
+            (11, "eleventh"),
+            (12, "twelfth"),
+            (20, "twentieth"),
+            (42, "forty-second"),
+            (100, "one hundredth"),
+            (101, "one hundred first"),
+            (1_000_000, "one millionth"),
+            (-3, "negative third"),
+        ] {
+            assert_eq!(number_to_ordinal(n), ordinal);
+        }
+    }
+}
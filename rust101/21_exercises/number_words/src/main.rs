@@ -0,0 +1,66 @@
+use std::env;
+use std::io::{self, BufRead};
+use std::process;
+
+// Converts one line or argument, or says why it can't.
+fn convert(input: &str, ordinal: bool) -> Result<String, String> {
+    let n: i64 = input.trim().parse().map_err(|_| {
+        format!(
+            "not a whole number between {} and {}: {input:?}",
+            i64::MIN,
+            i64::MAX
+        )
+    })?;
+    Ok(if ordinal {
+        number_words::number_to_ordinal(n)
+    } else {
+        number_words::number_to_words(n)
+    })
+}
+
+// Usage: number_words [--ordinal] [N...]. With no numbers it converts each
+// line of stdin. A line that isn't a number is reported and skipped, and
+// the exit code is 1 at the end.
+fn main() {
+    let mut ordinal = false;
+    let mut numbers = Vec::new();
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--ordinal" => ordinal = true,
+            // A negative number starts with '-' too, so only treat it as an
+            // option if it isn't one.
+            _ if arg.starts_with("--") => {
+                eprintln!("unknown option: {arg:?}");
+                eprintln!("Usage: number_words [--ordinal] [N...]");
+                process::exit(2);
+            }
+            _ => numbers.push(arg),
+        }
+    }
+
+    let mut failed = false;
+    let mut print = |input: &str| match convert(input, ordinal) {
+        Ok(words) => println!("{words}"),
+        Err(err) => {
+            eprintln!("{err}");
+            failed = true;
+        }
+    };
+    if numbers.is_empty() {
+        for line in io::stdin().lock().lines() {
+            match line {
+                Ok(line) if line.trim().is_empty() => {}
+                Ok(line) => print(&line),
+                Err(err) => {
+                    eprintln!("couldn't read stdin: {err}");
+                    process::exit(1);
+                }
+            }
+        }
+    } else {
+        numbers.iter().for_each(|number| print(number));
+    }
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,61 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn number_words(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_number_words"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn converts_arguments() {
+    let output = number_words(&["42", "-1234", "1000000"], "");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        stdout(&output),
+        "forty-two\nnegative one thousand two hundred thirty-four\none million\n"
+    );
+}
+
+#[test]
+fn converts_ordinals() {
+    let output = number_words(&["--ordinal", "42", "1"], "");
+    assert_eq!(stdout(&output), "forty-second\nfirst\n");
+}
+
+#[test]
+fn converts_stdin_lines() {
+    let output = number_words(&["--ordinal"], "3\n\n  20  \n");
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "third\ntwentieth\n");
+}
+
+#[test]
+fn bad_numbers_are_reported_and_skipped() {
+    let output = number_words(&[], "7\nseven\n9223372036854775808\n8\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "seven\neight\n");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(stderr.lines().count(), 2);
+    assert!(stderr.contains("\"seven\""));
+}
+
+#[test]
+fn unknown_options_are_usage_errors() {
+    assert_eq!(number_words(&["--cardinal"], "").status.code(), Some(2));
+}
@@ -0,0 +1,88 @@
+// A fixed-length array of bits, 64 to a word. A `Vec<bool>` spends a byte
+// on each flag - eight times the memory, and eight times as much for the
+// cache to hold - which is most of what a sieve does.
+pub struct BitVec {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    // `len` bits, all set.
+    pub fn ones(len: usize) -> BitVec {
+        let mut words = vec![u64::MAX; len.div_ceil(64)];
+        // Clear the unused bits in the last word, so they're never counted.
+        if !len.is_multiple_of(64) {
+            *words.last_mut().unwrap() = (1 << (len % 64)) - 1;
+        }
+        BitVec { words, len }
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.len, "bit {i} is out of range for {}", self.len);
+        self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    pub fn clear(&mut self, i: usize) {
+        assert!(i < self.len, "bit {i} is out of range for {}", self.len);
+        self.words[i / 64] &= !(1 << (i % 64));
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    // The positions of the set bits, in order. Skips a whole word at a time
+    // when it's empty.
+    pub fn ones_positions(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1; // clears the lowest set bit
+                Some(w * 64 + bit)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_all_set_without_stray_bits() {
+        for len in [0, 1, 63, 64, 65, 200] {
+            let bits = BitVec::ones(len);
+            assert_eq!(bits.count_ones(), len);
+            assert_eq!(
+                bits.ones_positions().collect::<Vec<_>>(),
+                (0..len).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn clearing_bits() {
+        let mut bits = BitVec::ones(130);
+        for i in [0, 63, 64, 129] {
+            bits.clear(i);
+            assert!(!bits.get(i));
+        }
+        bits.clear(0);
+        assert!(bits.get(1) && bits.get(65));
+        assert_eq!(bits.count_ones(), 126);
+        assert_eq!(bits.ones_positions().take(3).collect::<Vec<_>>(), [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "bit 10 is out of range for 10")]
+    fn out_of_range() {
+        BitVec::ones(10).get(10);
+    }
+}
@@ -0,0 +1,173 @@
+use std::sync::OnceLock;
+
+use crate::sieve::sieve;
+
+// Primes up to 2^16 are sieved once and shared. They're enough to test any
+// u32 by trial division - its square root is below 2^16 - and bigger numbers
+// carry on with the candidates from `candidates_after_sieve`.
+const SIEVE_LIMIT: u64 = 1 << 16;
+
+fn small_primes() -> &'static [u64] {
+    static PRIMES: OnceLock<Vec<u64>> = OnceLock::new();
+    PRIMES.get_or_init(|| sieve(SIEVE_LIMIT as usize))
+}
+
+// Every number past the sieve of the form 6k ± 1 - all primes above 3 are
+// one. Some aren't prime (65,545 is 5 × 13,109), but dividing by one of
+// those is harmless: its prime factors were already divided out.
+fn candidates_after_sieve() -> impl Iterator<Item = u64> {
+    // 65,537 is 6 × 10,923 - 1.
+    (SIEVE_LIMIT + 1..).step_by(6).flat_map(|n| [n, n + 2])
+}
+
+// Every divisor trial division might need, in order.
+fn trial_divisors() -> impl Iterator<Item = u64> {
+    small_primes()
+        .iter()
+        .copied()
+        .chain(candidates_after_sieve())
+}
+
+/// Whether `n` is prime, by trial division up to its square root.
+///
+/// Quick for anything up to around 10^12; a large prime near `u64::MAX`
+/// means a billion or so divisions.
+///
+/// ```
+/// assert!(numbers::is_prime(65_537));
+/// assert!(!numbers::is_prime(65_536));
+/// ```
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let root = n.isqrt();
+    trial_divisors()
+        .take_while(|&d| d <= root)
+        .all(|d| !n.is_multiple_of(d))
+}
+
+/// The prime factors of `n` and how many times each divides it, smallest
+/// first. 1 has no prime factors, and neither - by convention here - has 0.
+///
+/// ```
+/// assert_eq!(numbers::factorize(360), [(2, 3), (3, 2), (5, 1)]);
+/// ```
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    factorize_with(n, trial_divisors())
+}
+
+// `factorize` with the divisors to try given. They have to include every
+// prime that might divide `n`, in order; the tests use this to skip ahead
+// to divisors near 2^32 without a billion divisions to get there.
+fn factorize_with(n: u64, divisors: impl Iterator<Item = u64>) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    if n == 0 {
+        return factors;
+    }
+    let mut rest = n;
+    for d in divisors {
+        // What's left has no factor below d, so if d² is bigger it can only
+        // be prime (or 1). Dividing rather than squaring, as d² overflows a
+        // u64 once d reaches 2^32 - which a big prime takes it to.
+        if d > rest / d {
+            break;
+        }
+        let mut power = 0;
+        while rest.is_multiple_of(d) {
+            rest /= d;
+            power += 1;
+        }
+        if power > 0 {
+            factors.push((d, power));
+        }
+    }
+    if rest > 1 {
+        factors.push((rest, 1));
+    }
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn product(factors: &[(u64, u32)]) -> u64 {
+        factors.iter().map(|&(p, k)| p.pow(k)).product()
+    }
+
+    #[test]
+    fn small_numbers() {
+        let primes: Vec<u64> = (0..30).filter(|&n| is_prime(n)).collect();
+        assert_eq!(primes, [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    }
+
+    #[test]
+    fn agrees_with_the_sieve() {
+        let primes = sieve(100_000);
+        let by_trial: Vec<u64> = (0..=100_000).filter(|&n| is_prime(n)).collect();
+        assert_eq!(by_trial, primes);
+    }
+
+    #[test]
+    fn primes_past_the_sieve() {
+        assert!(is_prime(4_294_967_291)); // the largest prime below 2^32
+        assert!(is_prime(1_000_000_007));
+        assert!(!is_prime(65_539 * 65_543));
+        assert!(!is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn factorizing_one_and_zero() {
+        assert_eq!(factorize(1), []);
+        assert_eq!(factorize(0), []);
+    }
+
+    #[test]
+    fn factorizing_a_prime() {
+        assert_eq!(factorize(2), [(2, 1)]);
+        assert_eq!(factorize(997), [(997, 1)]);
+        assert_eq!(factorize(4_294_967_291), [(4_294_967_291, 1)]);
+    }
+
+    #[test]
+    fn factorizing_perfect_powers() {
+        assert_eq!(factorize(1 << 63), [(2, 63)]);
+        assert_eq!(factorize(3u64.pow(40)), [(3, 40)]);
+        assert_eq!(factorize(65_537 * 65_537), [(65_537, 2)]);
+    }
+
+    #[test]
+    fn factorizing_u32_max() {
+        // 2^32 - 1 = (2^16 - 1)(2^16 + 1) = 3 × 5 × 17 × 257 × 65,537.
+        let factors = factorize(u32::MAX as u64);
+        assert_eq!(factors, [(3, 1), (5, 1), (17, 1), (257, 1), (65_537, 1)]);
+        assert_eq!(product(&factors), u32::MAX as u64);
+    }
+
+    #[test]
+    fn factorizing_two_big_primes() {
+        assert_eq!(
+            factorize(1_000_003 * 1_000_033),
+            [(1_000_003, 1), (1_000_033, 1)]
+        );
+    }
+
+    #[test]
+    fn trial_division_stops_without_overflowing() {
+        // The largest prime below 2^64. Trial division goes on until d
+        // reaches 2^32, where d² no longer fits in a u64.
+        let prime = 18_446_744_073_709_551_557;
+        assert_eq!(factorize_with(prime, (1 << 32) - 10..), [(prime, 1)]);
+    }
+
+    #[test]
+    fn factors_multiply_back() {
+        for n in (1..10_000).chain([u64::MAX, u64::MAX - 1, 600_851_475_143]) {
+            let factors = factorize(n);
+            assert_eq!(product(&factors), n, "{n}");
+            assert!(factors.iter().all(|&(p, _)| is_prime(p)), "{n}");
+            assert!(factors.windows(2).all(|w| w[0].0 < w[1].0), "{n}");
+        }
+    }
+}
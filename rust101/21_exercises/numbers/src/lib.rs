@@ -0,0 +1,39 @@
+/* Things learned during this exercise:
+   * The Sieve of Eratosthenes finds every prime up to a limit by crossing
+   off the multiples of each prime in turn; whatever's left is prime. It
+   keeps one flag per number, so the flags are bits in `u64` words
+   (`BitVec`) rather than a `Vec<bool>`, which takes a byte each.
+       cargo run -- --up-to 100
+       cargo run -- --factor 360
+   * A number with no prime factor up to its square root is prime, so trial
+   division only needs the primes up to 2^16 for a u32. Past that, numbers
+   of the form 6k ± 1 stand in for primes - every prime above 3 is one.
+   * `OnceLock` computes the shared table of small primes the first time
+   it's needed, once, even with several threads asking at once.
+   * Factorizing is trial division too, dividing each factor out as it's
+   found: once d² is bigger than what's left, what's left is prime.
+   * `u64::isqrt` and `is_multiple_of` avoid the float rounding of
+   `(n as f64).sqrt()` and the modulo-by-zero trap of `n % d == 0`.
+*/
+mod bits;
+mod factor;
+mod sieve;
+
+pub use factor::{factorize, is_prime};
+pub use sieve::sieve;
+
+/// Writes a factorization the usual way, like `2^3 × 3^2 × 5`.
+///
+/// ```
+/// assert_eq!(numbers::format_factors(&numbers::factorize(360)), "2^3 × 3^2 × 5");
+/// ```
+pub fn format_factors(factors: &[(u64, u32)]) -> String {
+    let terms: Vec<String> = factors
+        .iter()
+        .map(|&(prime, power)| match power {
+            1 => prime.to_string(),
+            _ => format!("{prime}^{power}"),
+        })
+        .collect();
+    terms.join(" × ")
+}
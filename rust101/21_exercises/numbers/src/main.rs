@@ -0,0 +1,52 @@
+use std::env;
+use std::process;
+
+use numbers::{factorize, format_factors, sieve};
+
+const USAGE: &str = "Usage: primes --up-to N | primes --factor N";
+
+enum Command {
+    UpTo(usize),
+    Factor(u64),
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let [flag, value] = args else {
+        return Err("expected a flag and a number".to_string());
+    };
+    let number = |what| format!("{what} needs a whole number, not {value:?}");
+    match flag.as_str() {
+        "--up-to" => value
+            .parse()
+            .map(Command::UpTo)
+            .map_err(|_| number("--up-to")),
+        "--factor" => value
+            .parse()
+            .map(Command::Factor)
+            .map_err(|_| number("--factor")),
+        _ => Err(format!("unknown flag: {flag}")),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = parse_args(&args).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        eprintln!("{USAGE}");
+        process::exit(2);
+    });
+    match command {
+        // One prime per line, so the output can go to `wc -l` or `tail`.
+        Command::UpTo(limit) => {
+            for prime in sieve(limit) {
+                println!("{prime}");
+            }
+        }
+        Command::Factor(0) => {
+            eprintln!("0 has no prime factorization");
+            process::exit(1);
+        }
+        Command::Factor(1) => println!("1 has no prime factors"),
+        Command::Factor(n) => println!("{n} = {}", format_factors(&factorize(n))),
+    }
+}
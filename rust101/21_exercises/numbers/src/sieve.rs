@@ -0,0 +1,81 @@
+use crate::bits::BitVec;
+
+/// Every prime up to and including `limit`, smallest first, found with the
+/// Sieve of Eratosthenes.
+///
+/// ```
+/// assert_eq!(numbers::sieve(20), [2, 3, 5, 7, 11, 13, 17, 19]);
+/// ```
+///
+/// # Panics
+///
+/// If `limit` is `usize::MAX`: there's a bit for every number from 0 to
+/// `limit`, and that's one more than a `usize` can count.
+pub fn sieve(limit: usize) -> Vec<u64> {
+    if limit < 2 {
+        return Vec::new();
+    }
+    // Bit i says whether i might still be prime. Crossing off starts at p²,
+    // since smaller multiples of p have a smaller factor that already did,
+    // and stops once p² is past the limit: every composite up to the limit
+    // has a factor no bigger than its square root.
+    let len = limit
+        .checked_add(1)
+        .expect("the sieve's limit must be below usize::MAX");
+    let mut candidates = BitVec::ones(len);
+    candidates.clear(0);
+    candidates.clear(1);
+    let mut p = 2;
+    while p <= limit / p {
+        if candidates.get(p) {
+            for multiple in (p * p..=limit).step_by(p) {
+                candidates.clear(multiple);
+            }
+        }
+        p += 1;
+    }
+    let mut primes = Vec::with_capacity(candidates.count_ones());
+    primes.extend(candidates.ones_positions().map(|n| n as u64));
+    primes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "below usize::MAX")]
+    fn the_largest_limit_is_rejected() {
+        sieve(usize::MAX);
+    }
+
+    #[test]
+    fn tiny_limits() {
+        assert_eq!(sieve(0), []);
+        assert_eq!(sieve(1), []);
+        assert_eq!(sieve(2), [2]);
+        assert_eq!(sieve(3), [2, 3]);
+        assert_eq!(sieve(4), [2, 3]);
+    }
+
+    #[test]
+    fn known_prime_counts() {
+        assert_eq!(sieve(100).len(), 25);
+        assert_eq!(sieve(1_000).len(), 168);
+        assert_eq!(sieve(1_000_000).len(), 78_498);
+    }
+
+    #[test]
+    fn includes_the_limit_when_it_is_prime() {
+        assert_eq!(sieve(997).last(), Some(&997));
+        assert_eq!(sieve(1_000).last(), Some(&997));
+    }
+
+    #[test]
+    fn limits_around_word_boundaries() {
+        // 61 and 67 are the primes either side of 64.
+        assert_eq!(sieve(64).last(), Some(&61));
+        assert_eq!(sieve(67).last(), Some(&67));
+        assert_eq!(sieve(128).last(), Some(&127));
+    }
+}
@@ -0,0 +1,53 @@
+use std::process::{Command, Output};
+
+fn primes(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_primes"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn lists_primes_one_per_line() {
+    let output = primes(&["--up-to", "20"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "2\n3\n5\n7\n11\n13\n17\n19\n");
+    assert_eq!(stdout(&primes(&["--up-to", "1000"])).lines().count(), 168);
+    assert_eq!(stdout(&primes(&["--up-to", "1"])), "");
+}
+
+#[test]
+fn factorizes() {
+    assert_eq!(
+        stdout(&primes(&["--factor", "360"])),
+        "360 = 2^3 × 3^2 × 5\n"
+    );
+    assert_eq!(
+        stdout(&primes(&["--factor", "4294967295"])),
+        "4294967295 = 3 × 5 × 17 × 257 × 65537\n"
+    );
+    assert_eq!(
+        stdout(&primes(&["--factor", "1"])),
+        "1 has no prime factors\n"
+    );
+    assert_eq!(primes(&["--factor", "0"]).status.code(), Some(1));
+}
+
+#[test]
+fn usage_errors() {
+    for args in [
+        &[][..],
+        &["--up-to"],
+        &["--factor", "-4"],
+        &["--count", "3"],
+        &["--up-to", "3", "4"],
+    ] {
+        let output = primes(args);
+        assert_eq!(output.status.code(), Some(2), "{args:?}");
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: primes"));
+    }
+}
@@ -0,0 +1,289 @@
+/* Things learned during this exercise:
+   * Keeping the rules in one pure function - `beats(a, b)` - means the
+   game loop, the strategies and the tests all agree on who won, and the
+   rules can be checked exhaustively: there are only nine pairings.
+   * `FromStr` is what `str::parse` calls, so `"rock".parse::<Move>()`
+   works like parsing a number, and the error type says what went wrong.
+   * A trait with a default method (`observe` does nothing unless a
+   strategy overrides it) lets simple strategies skip what they don't need.
+   `Box<dyn Strategy>` picks one at run time from the command line.
+   * A "model" can be as small as three counters. Predicting the
+   opponent's favourite move beats anyone with a habit, and ties need a
+   rule, or the prediction depends on the order of an enum.
+   * Taking the input as `impl BufRead` and the output as `impl Write`
+   lets the tests play a whole match with a byte string for stdin and a
+   `Vec<u8>` for stdout, and the binary pass the real ones.
+   * Best of N means first to more than half: a best of 5 ends at 3 wins,
+   and draws don't count towards it.
+   * See https://doc.rust-lang.org/std/str/trait.FromStr.html and
+   https://doc.rust-lang.org/book/ch18-02-trait-objects.html
+*/
+mod moves;
+mod strategy;
+
+pub use moves::{beats, Move, Outcome, ParseMoveError};
+pub use strategy::{FrequencyModel, Strategy, StrategyKind};
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Scoreboard {
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+impl Scoreboard {
+    pub fn record(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Win => self.wins += 1,
+            Outcome::Lose => self.losses += 1,
+            Outcome::Draw => self.draws += 1,
+        }
+    }
+
+    pub fn rounds(&self) -> u32 {
+        self.wins + self.losses + self.draws
+    }
+}
+
+impl fmt::Display for Scoreboard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "you {}, computer {}, draws {}",
+            self.wins, self.losses, self.draws
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub best_of: u32,
+    pub strategy: StrategyKind,
+    pub seed: Option<u64>,
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut config = Config {
+            best_of: 3,
+            strategy: StrategyKind::default(),
+            seed: None,
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--best-of" => {
+                    let value = value()?;
+                    config.best_of = value
+                        .parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or(format!("--best-of needs a number of rounds, not {value:?}"))?;
+                }
+                "--strategy" => config.strategy = value()?.parse()?,
+                "--seed" => {
+                    let value = value()?;
+                    config.seed = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("not a seed: {value:?}"))?,
+                    );
+                }
+                _ => return Err(format!("unknown argument: {arg:?}")),
+            }
+        }
+        Ok(config)
+    }
+}
+
+// Plays a match: a round per move read from `input`, until one side has
+// won more than half of `best_of` rounds. Input that isn't a move is
+// answered and asked again. Stops early, returning the score so far, if the
+// input runs out.
+pub fn play(
+    best_of: u32,
+    strategy: &mut dyn Strategy,
+    input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<Scoreboard> {
+    let needed = best_of / 2 + 1;
+    let mut score = Scoreboard::default();
+    let mut lines = input.lines();
+    while score.wins < needed && score.losses < needed {
+        write!(output, "Round {} - your move (r/p/s): ", score.rounds() + 1)?;
+        output.flush()?;
+        let Some(line) = lines.next().transpose()? else {
+            writeln!(output, "\nMatch abandoned with {score}.")?;
+            return Ok(score);
+        };
+        let player: Move = match line.parse() {
+            Ok(player) => player,
+            Err(err) => {
+                writeln!(output, "{err}")?;
+                continue;
+            }
+        };
+
+        // The computer chooses before it hears the player's move, so it
+        // can't cheat.
+        let computer = strategy.choose();
+        strategy.observe(player);
+        let outcome = beats(player, computer);
+        score.record(outcome);
+        let verdict = match outcome {
+            Outcome::Win => "you win the round",
+            Outcome::Lose => "the computer wins the round",
+            Outcome::Draw => "a draw",
+        };
+        writeln!(
+            output,
+            "You played {player}, the computer played {computer}: {verdict}."
+        )?;
+        writeln!(output, "Score: {score}")?;
+    }
+
+    let winner = if score.wins > score.losses {
+        "You win"
+    } else {
+        "The computer wins"
+    };
+    writeln!(
+        output,
+        "{winner} the match {}-{}!",
+        score.wins, score.losses
+    )?;
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["rock_paper_scissors".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    // Always plays the same move.
+    struct Stubborn(Move);
+
+    impl Strategy for Stubborn {
+        fn choose(&mut self) -> Move {
+            self.0
+        }
+    }
+
+    fn play_against(m: Move, best_of: u32, input: &str) -> (Scoreboard, String) {
+        let mut output = Vec::new();
+        let score = play(best_of, &mut Stubborn(m), input.as_bytes(), &mut output).unwrap();
+        (score, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn first_to_more_than_half_wins() {
+        // Against rock: paper wins, rock draws, scissors loses.
+        let (score, output) = play_against(Move::Rock, 5, "p\nr\ns\np\np\nr\n");
+        assert_eq!(
+            score,
+            Scoreboard {
+                wins: 3,
+                losses: 1,
+                draws: 1
+            }
+        );
+        assert!(output.ends_with("Score: you 3, computer 1, draws 1\nYou win the match 3-1!\n"));
+        // The last line of input was never needed.
+        assert_eq!(output.matches("Round ").count(), 5);
+    }
+
+    #[test]
+    fn the_computer_can_win() {
+        let (score, output) = play_against(Move::Paper, 3, "rock\nROCK\n");
+        assert_eq!((score.wins, score.losses), (0, 2));
+        assert!(output.ends_with("The computer wins the match 0-2!\n"));
+    }
+
+    #[test]
+    fn bad_moves_are_asked_again() {
+        let (score, output) = play_against(Move::Scissors, 1, "lizard\n\nr\n");
+        assert_eq!(score.rounds(), 1);
+        assert_eq!(
+            output,
+            "Round 1 - your move (r/p/s): \"lizard\" isn't a move: try r, p or s\n\
+             Round 1 - your move (r/p/s): \"\" isn't a move: try r, p or s\n\
+             Round 1 - your move (r/p/s): You played rock, the computer played scissors: you win the round.\n\
+             Score: you 1, computer 0, draws 0\n\
+             You win the match 1-0!\n"
+        );
+    }
+
+    #[test]
+    fn running_out_of_input_abandons_the_match() {
+        let (score, output) = play_against(Move::Rock, 3, "p\n");
+        assert_eq!(score.wins, 1);
+        assert!(output.ends_with("\nMatch abandoned with you 1, computer 0, draws 0.\n"));
+    }
+
+    #[test]
+    fn the_strategy_sees_the_moves() {
+        // Beat-last knows the player's last move by the second round.
+        let mut strategy = StrategyKind::BeatLast.build(StdRng::seed_from_u64(185));
+        let mut output = Vec::new();
+        play(5, strategy.as_mut(), "r\nr\nr\nr\n".as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let rounds: Vec<&str> = output
+            .lines()
+            .filter(|line| line.contains("You played"))
+            .collect();
+        assert!(rounds.len() >= 2);
+        for round in &rounds[1..] {
+            assert!(round.contains("the computer played paper"), "{round}");
+        }
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&[])),
+            Ok(Config {
+                best_of: 3,
+                strategy: StrategyKind::Random,
+                seed: None
+            })
+        );
+        assert_eq!(
+            Config::build(args(&[
+                "--strategy",
+                "predictor",
+                "--best-of",
+                "7",
+                "--seed",
+                "1"
+            ])),
+            Ok(Config {
+                best_of: 7,
+                strategy: StrategyKind::Predictor,
+                seed: Some(1)
+            })
+        );
+        for bad in [
+            &["--best-of", "0"][..],
+            &["--best-of"],
+            &["--strategy", "cheat"],
+            &["--seed", "-1"],
+            &["rock"],
+        ] {
+            assert!(Config::build(args(bad)).is_err(), "{bad:?}");
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::env;
+use std::io;
+use std::process;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rock_paper_scissors::Config;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: rock_paper_scissors [--best-of N] [--strategy random|beat-last|predictor] [--seed N]");
+        process::exit(2);
+    });
+    let rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let mut strategy = config.strategy.build(rng);
+
+    println!(
+        "Best of {} against the {} strategy.",
+        config.best_of, config.strategy
+    );
+    let result = rock_paper_scissors::play(
+        config.best_of,
+        strategy.as_mut(),
+        io::stdin().lock(),
+        io::stdout(),
+    );
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move {
+    pub const ALL: [Move; 3] = [Move::Rock, Move::Paper, Move::Scissors];
+
+    // The move that beats this one.
+    pub fn beaten_by(self) -> Move {
+        match self {
+            Move::Rock => Move::Paper,
+            Move::Paper => Move::Scissors,
+            Move::Scissors => Move::Rock,
+        }
+    }
+
+    // Its place in `ALL`, for indexing arrays of per-move counts.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Move::Rock => "rock",
+            Move::Paper => "paper",
+            Move::Scissors => "scissors",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ParseMoveError(String);
+
+impl fmt::Display for ParseMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?} isn't a move: try r, p or s", self.0)
+    }
+}
+
+impl std::error::Error for ParseMoveError {}
+
+// "r" or "rock", in any case, with spaces around it allowed.
+impl FromStr for Move {
+    type Err = ParseMoveError;
+
+    fn from_str(s: &str) -> Result<Move, ParseMoveError> {
+        match s.trim().to_lowercase().as_str() {
+            "r" | "rock" => Ok(Move::Rock),
+            "p" | "paper" => Ok(Move::Paper),
+            "s" | "scissors" => Ok(Move::Scissors),
+            _ => Err(ParseMoveError(s.trim().to_string())),
+        }
+    }
+}
+
+// How a round went for whoever played the first move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Lose,
+    Draw,
+}
+
+pub fn beats(a: Move, b: Move) -> Outcome {
+    if a == b {
+        Outcome::Draw
+    } else if a == b.beaten_by() {
+        Outcome::Win
+    } else {
+        Outcome::Lose
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_pairing() {
+        use Move::*;
+        let table = [
+            (Rock, Rock, Outcome::Draw),
+            (Rock, Paper, Outcome::Lose),
+            (Rock, Scissors, Outcome::Win),
+            (Paper, Rock, Outcome::Win),
+            (Paper, Paper, Outcome::Draw),
+            (Paper, Scissors, Outcome::Lose),
+            (Scissors, Rock, Outcome::Lose),
+            (Scissors, Paper, Outcome::Win),
+            (Scissors, Scissors, Outcome::Draw),
+        ];
+        for (a, b, outcome) in table {
+            assert_eq!(beats(a, b), outcome, "{a} against {b}");
+        }
+    }
+
+    #[test]
+    fn outcomes_are_symmetric() {
+        for a in Move::ALL {
+            for b in Move::ALL {
+                let flipped = match beats(b, a) {
+                    Outcome::Win => Outcome::Lose,
+                    Outcome::Lose => Outcome::Win,
+                    Outcome::Draw => Outcome::Draw,
+                };
+                assert_eq!(beats(a, b), flipped);
+            }
+            assert_eq!(beats(a.beaten_by(), a), Outcome::Win);
+        }
+    }
+
+    #[test]
+    fn parses_short_and_long_forms() {
+        assert_eq!("r".parse(), Ok(Move::Rock));
+        assert_eq!(" Paper ".parse(), Ok(Move::Paper));
+        assert_eq!("SCISSORS".parse(), Ok(Move::Scissors));
+        assert_eq!("S".parse(), Ok(Move::Scissors));
+        assert_eq!(
+            "lizard".parse::<Move>().unwrap_err().to_string(),
+            "\"lizard\" isn't a move: try r, p or s"
+        );
+        assert!("".parse::<Move>().is_err());
+        assert!("ro".parse::<Move>().is_err());
+    }
+
+    #[test]
+    fn displays_names_that_parse_back() {
+        for m in Move::ALL {
+            assert_eq!(m.to_string().parse(), Ok(m));
+            assert_eq!(Move::ALL[m.index()], m);
+        }
+    }
+}
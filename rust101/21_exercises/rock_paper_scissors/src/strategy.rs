@@ -0,0 +1,234 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::moves::Move;
+
+// A computer player. It picks a move, then hears what its opponent played
+// that round, so it can learn from it.
+pub trait Strategy {
+    fn choose(&mut self) -> Move;
+    fn observe(&mut self, _opponent: Move) {}
+}
+
+fn random_move(rng: &mut StdRng) -> Move {
+    *Move::ALL.choose(rng).unwrap()
+}
+
+// Can't be predicted, so can't be beaten on average either.
+pub struct Random {
+    rng: StdRng,
+}
+
+impl Strategy for Random {
+    fn choose(&mut self) -> Move {
+        random_move(&mut self.rng)
+    }
+}
+
+// Plays whatever beats the opponent's last move - a good bet against
+// someone who repeats themselves, and easy to exploit once spotted.
+pub struct BeatLast {
+    last: Option<Move>,
+    rng: StdRng,
+}
+
+impl Strategy for BeatLast {
+    fn choose(&mut self) -> Move {
+        match self.last {
+            Some(last) => last.beaten_by(),
+            None => random_move(&mut self.rng),
+        }
+    }
+
+    fn observe(&mut self, opponent: Move) {
+        self.last = Some(opponent);
+    }
+}
+
+// How often the opponent has played each move, and when they last did.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrequencyModel {
+    counts: [u32; 3],
+    // The round each move was last played in.
+    last_seen: [Option<usize>; 3],
+    rounds: usize,
+}
+
+impl FrequencyModel {
+    pub fn record(&mut self, m: Move) {
+        self.counts[m.index()] += 1;
+        self.last_seen[m.index()] = Some(self.rounds);
+        self.rounds += 1;
+    }
+
+    pub fn count(&self, m: Move) -> u32 {
+        self.counts[m.index()]
+    }
+
+    // The move played most often. Of moves played equally often, the one
+    // played most recently - people tend to stick with what they just did.
+    // None before anything has been seen.
+    pub fn predict(&self) -> Option<Move> {
+        Move::ALL
+            .into_iter()
+            .filter(|m| self.count(*m) > 0)
+            .max_by_key(|m| (self.count(*m), self.last_seen[m.index()]))
+    }
+}
+
+// Expects the opponent's favourite move and plays what beats it.
+pub struct Predictor {
+    model: FrequencyModel,
+    rng: StdRng,
+}
+
+impl Strategy for Predictor {
+    fn choose(&mut self) -> Move {
+        match self.model.predict() {
+            Some(expected) => expected.beaten_by(),
+            None => random_move(&mut self.rng),
+        }
+    }
+
+    fn observe(&mut self, opponent: Move) {
+        self.model.record(opponent);
+    }
+}
+
+// The strategies to choose from on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StrategyKind {
+    #[default]
+    Random,
+    BeatLast,
+    Predictor,
+}
+
+impl StrategyKind {
+    pub const ALL: [StrategyKind; 3] = [
+        StrategyKind::Random,
+        StrategyKind::BeatLast,
+        StrategyKind::Predictor,
+    ];
+
+    // Every strategy gets the random number generator, even the ones that
+    // only need it for their first move.
+    pub fn build(self, rng: StdRng) -> Box<dyn Strategy> {
+        match self {
+            StrategyKind::Random => Box::new(Random { rng }),
+            StrategyKind::BeatLast => Box::new(BeatLast { last: None, rng }),
+            StrategyKind::Predictor => Box::new(Predictor {
+                model: FrequencyModel::default(),
+                rng,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for StrategyKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            StrategyKind::Random => "random",
+            StrategyKind::BeatLast => "beat-last",
+            StrategyKind::Predictor => "predictor",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for StrategyKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<StrategyKind, String> {
+        StrategyKind::ALL
+            .into_iter()
+            .find(|kind| kind.to_string() == s)
+            .ok_or_else(|| format!("no strategy called {s:?}: try random, beat-last or predictor"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use Move::*;
+
+    fn model(history: &[Move]) -> FrequencyModel {
+        let mut model = FrequencyModel::default();
+        history.iter().for_each(|&m| model.record(m));
+        model
+    }
+
+    #[test]
+    fn predicts_the_favourite_move() {
+        assert_eq!(model(&[]).predict(), None);
+        assert_eq!(model(&[Rock]).predict(), Some(Rock));
+        assert_eq!(
+            model(&[Rock, Paper, Paper, Scissors, Rock, Paper]).predict(),
+            Some(Paper)
+        );
+        let counted = model(&[Scissors, Rock, Scissors]);
+        assert_eq!((counted.count(Scissors), counted.count(Paper)), (2, 0));
+    }
+
+    #[test]
+    fn ties_go_to_the_latest_move() {
+        assert_eq!(model(&[Rock, Paper]).predict(), Some(Paper));
+        assert_eq!(model(&[Paper, Rock]).predict(), Some(Rock));
+        assert_eq!(
+            model(&[Rock, Scissors, Scissors, Rock]).predict(),
+            Some(Rock)
+        );
+        // Rock and scissors are tied, and paper's recent but behind.
+        assert_eq!(
+            model(&[Rock, Scissors, Rock, Scissors, Paper]).predict(),
+            Some(Scissors)
+        );
+    }
+
+    #[test]
+    fn the_predictor_beats_the_prediction() {
+        let mut predictor = StrategyKind::Predictor.build(StdRng::seed_from_u64(185));
+        for m in [Rock, Rock, Scissors] {
+            predictor.observe(m);
+        }
+        assert_eq!(predictor.choose(), Paper);
+        for m in [Scissors, Scissors] {
+            predictor.observe(m);
+        }
+        assert_eq!(predictor.choose(), Rock);
+    }
+
+    #[test]
+    fn beat_last_beats_the_last_move() {
+        let mut beat_last = StrategyKind::BeatLast.build(StdRng::seed_from_u64(185));
+        for m in Move::ALL {
+            beat_last.observe(m);
+            assert_eq!(beat_last.choose(), m.beaten_by());
+        }
+    }
+
+    #[test]
+    fn random_plays_everything() {
+        let mut random = StrategyKind::Random.build(StdRng::seed_from_u64(185));
+        let mut counts = [0; 3];
+        for _ in 0..3000 {
+            counts[random.choose().index()] += 1;
+        }
+        assert!(
+            counts.iter().all(|&count| (800..1200).contains(&count)),
+            "{counts:?}"
+        );
+    }
+
+    #[test]
+    fn names_parse_back() {
+        for kind in StrategyKind::ALL {
+            assert_eq!(kind.to_string().parse(), Ok(kind));
+        }
+        assert!("mirror".parse::<StrategyKind>().is_err());
+    }
+}
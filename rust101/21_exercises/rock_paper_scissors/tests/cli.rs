@@ -0,0 +1,72 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn rock_paper_scissors(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rock_paper_scissors"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn last_lines(output: &Output, n: usize) -> Vec<String> {
+    let stdout = String::from_utf8(output.stdout.clone()).unwrap();
+    let lines: Vec<String> = stdout.lines().map(str::to_string).collect();
+    lines[lines.len() - n..].to_vec()
+}
+
+#[test]
+fn a_seeded_match_against_random() {
+    let moves = "r\np\ns\nr\nbanana\np\ns\nr\np\n";
+    let output = rock_paper_scissors(&["--seed", "185", "--best-of", "5"], moves);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout.clone()).unwrap();
+    assert!(stdout.starts_with("Best of 5 against the random strategy.\n"));
+    assert!(stdout.contains("\"banana\" isn't a move: try r, p or s\n"));
+    assert_eq!(
+        last_lines(&output, 2),
+        [
+            "Score: you 2, computer 3, draws 3",
+            "The computer wins the match 2-3!"
+        ]
+    );
+    // The same seed plays the same match.
+    assert_eq!(
+        rock_paper_scissors(&["--seed", "185", "--best-of", "5"], moves).stdout,
+        output.stdout
+    );
+}
+
+#[test]
+fn the_predictor_punishes_a_habit() {
+    // Only the first move is a guess; after that it always expects rock.
+    let output = rock_paper_scissors(&["--strategy", "predictor", "--seed", "1"], "r\nr\nr\nr\n");
+    let stdout = String::from_utf8(output.stdout.clone()).unwrap();
+    assert!(
+        stdout.contains("Round 2 - your move (r/p/s): You played rock, the computer played paper")
+    );
+    assert!(last_lines(&output, 1)[0].starts_with("The computer wins the match"));
+}
+
+#[test]
+fn running_out_of_moves_abandons_the_match() {
+    let output = rock_paper_scissors(&["--seed", "2", "--best-of", "9"], "r\n");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(last_lines(&output, 1)[0].starts_with("Match abandoned with you "));
+}
+
+#[test]
+fn usage_errors() {
+    let output = rock_paper_scissors(&["--strategy", "cheat"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no strategy called \"cheat\""));
+}
@@ -0,0 +1,47 @@
+use crate::Metrics;
+
+/// Insertion sort: grows a sorted prefix one element at a time, swapping
+/// each new element left until the one before it isn't bigger.
+///
+/// Stable - an element only moves past strictly bigger ones, so equal
+/// elements never pass each other. O(n²) comparisons and swaps in general,
+/// but only n - 1 comparisons on data that's already sorted.
+pub fn insertion_sort<T: Ord>(slice: &mut [T], metrics: &mut Metrics) {
+    for i in 1..slice.len() {
+        let mut j = i;
+        while j > 0 && metrics.less(&slice[j], &slice[j - 1]) {
+            metrics.swap(slice, j, j - 1);
+            j -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts() {
+        let mut numbers = [5, 2, 9, 1, 5, 6];
+        insertion_sort(&mut numbers, &mut Metrics::new());
+        assert_eq!(numbers, [1, 2, 5, 5, 6, 9]);
+    }
+
+    #[test]
+    fn sorted_input_takes_no_swaps() {
+        let mut numbers: Vec<u32> = (0..100).collect();
+        let mut metrics = Metrics::new();
+        insertion_sort(&mut numbers, &mut metrics);
+        assert_eq!(metrics.comparisons, 99);
+        assert_eq!(metrics.swaps, 0);
+    }
+
+    #[test]
+    fn reversed_input_takes_a_swap_per_inverted_pair() {
+        let mut numbers: Vec<u32> = (0..100).rev().collect();
+        let mut metrics = Metrics::new();
+        insertion_sort(&mut numbers, &mut metrics);
+        assert_eq!(metrics.swaps, 100 * 99 / 2);
+        assert_eq!(metrics.comparisons, 100 * 99 / 2);
+    }
+}
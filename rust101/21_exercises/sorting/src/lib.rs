@@ -0,0 +1,207 @@
+/* Things learned during this exercise:
+   * Three sorts, each generic over `T: Ord`, each counting its work in a
+   `Metrics` passed in as `&mut`:
+     * insertion sort - simple, stable, O(n²), but fast on nearly-sorted
+     data;
+     * merge sort - stable, O(n log n) always, but needs room for the
+     merged output;
+     * quicksort - in place and usually the fastest, O(n²) at worst, and
+     not stable.
+       cargo run --release -- --algorithm quick --size 100000
+   * A sort is stable if equal elements keep their original order. It only
+   shows when the elements compare equal while being different - sorting
+   people by age, say. The tests sort `(key, original index)` pairs by key
+   alone to see it.
+   * Passing `&mut Metrics` in, rather than keeping a global counter, means
+   each sort's counts are separate and the tests can run in parallel.
+   * The sorts work on different shapes: insertion sort and quicksort swap
+   elements within a `&mut [T]`; merge sort moves them into a new Vec, so it
+   takes and returns a `Vec<T>`. `Algorithm::sort` hides the difference.
+*/
+mod insertion;
+mod merge;
+mod metrics;
+mod quick;
+
+pub use insertion::insertion_sort;
+pub use merge::merge_sort;
+pub use metrics::Metrics;
+pub use quick::quicksort;
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    Insertion,
+    Merge,
+    Quick,
+}
+
+impl Algorithm {
+    pub const ALL: [Algorithm; 3] = [Algorithm::Insertion, Algorithm::Merge, Algorithm::Quick];
+
+    pub fn sort<T: Ord>(self, mut items: Vec<T>, metrics: &mut Metrics) -> Vec<T> {
+        match self {
+            Algorithm::Insertion => insertion_sort(&mut items, metrics),
+            Algorithm::Merge => return merge_sort(items, metrics),
+            Algorithm::Quick => quicksort(&mut items, metrics),
+        }
+        items
+    }
+
+    pub fn is_stable(self) -> bool {
+        self != Algorithm::Quick
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Algorithm::Insertion => "insertion",
+            Algorithm::Merge => "merge",
+            Algorithm::Quick => "quick",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Algorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Algorithm, String> {
+        Algorithm::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.to_string() == s)
+            .ok_or_else(|| format!("unknown algorithm {s:?}: try insertion, merge or quick"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::cmp::Ordering;
+
+    // Compares by key only, so two Keyed with the same key are "equal" to a
+    // sort but can still be told apart by where they started.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Keyed {
+        key: u8,
+        original_index: usize,
+    }
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    // A few keys repeated many times, numbered in their starting order.
+    fn keyed(rng: &mut StdRng, len: usize) -> Vec<Keyed> {
+        (0..len)
+            .map(|original_index| Keyed {
+                key: rng.gen_range(0..5),
+                original_index,
+            })
+            .collect()
+    }
+
+    // Whether equal keys are still in their original order.
+    fn kept_order(sorted: &[Keyed]) -> bool {
+        sorted
+            .windows(2)
+            .all(|w| w[0].key < w[1].key || w[0].original_index < w[1].original_index)
+    }
+
+    #[test]
+    fn stable_sorts_keep_equal_keys_in_order() {
+        let mut rng = StdRng::seed_from_u64(175);
+        for _ in 0..200 {
+            let len = rng.gen_range(0..200);
+            let items = keyed(&mut rng, len);
+            for algorithm in [Algorithm::Insertion, Algorithm::Merge] {
+                let sorted = algorithm.sort(items.clone(), &mut Metrics::new());
+                assert!(kept_order(&sorted), "{algorithm} reordered equal keys");
+            }
+        }
+    }
+
+    #[test]
+    fn quicksort_can_reorder_equal_keys() {
+        // The pivot, the middle 1, is swapped to the end and then back into
+        // the middle - past the other 1.
+        let items = [(1, 0), (1, 1), (0, 2)].map(|(key, original_index)| Keyed {
+            key,
+            original_index,
+        });
+        let sorted = Algorithm::Quick.sort(items.to_vec(), &mut Metrics::new());
+        let order: Vec<usize> = sorted.iter().map(|k| k.original_index).collect();
+        assert_eq!(order, [2, 1, 0]);
+        assert!(!kept_order(&sorted));
+
+        let mut rng = StdRng::seed_from_u64(1750);
+        let reordered = (0..50)
+            .map(|_| keyed(&mut rng, 100))
+            .filter(|items| !kept_order(&Algorithm::Quick.sort(items.clone(), &mut Metrics::new())))
+            .count();
+        assert!(reordered > 0);
+    }
+
+    #[test]
+    fn all_algorithms_agree_with_sort_unstable() {
+        let mut rng = StdRng::seed_from_u64(17500);
+        for _ in 0..300 {
+            let len = rng.gen_range(0..300);
+            let numbers: Vec<i32> = (0..len).map(|_| rng.gen_range(-50..50)).collect();
+            let mut expected = numbers.clone();
+            expected.sort_unstable();
+            for algorithm in Algorithm::ALL {
+                let sorted = algorithm.sort(numbers.clone(), &mut Metrics::new());
+                assert_eq!(sorted, expected, "{algorithm} on {numbers:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn empty_and_single_element_take_no_work() {
+        for algorithm in Algorithm::ALL {
+            let mut metrics = Metrics::new();
+            assert_eq!(algorithm.sort(Vec::<u8>::new(), &mut metrics), []);
+            assert_eq!(algorithm.sort(vec![1], &mut metrics), [1]);
+            assert_eq!(metrics, Metrics::new(), "{algorithm}");
+        }
+    }
+
+    #[test]
+    fn metrics_accumulate_across_sorts() {
+        let mut metrics = Metrics::new();
+        insertion_sort(&mut [2, 1], &mut metrics);
+        insertion_sort(&mut [2, 1], &mut metrics);
+        assert_eq!(
+            metrics,
+            Metrics {
+                comparisons: 2,
+                swaps: 2,
+                moves: 0
+            }
+        );
+        assert_eq!(metrics.to_string(), "2 comparisons, 2 swaps, 0 moves");
+    }
+
+    #[test]
+    fn parses_algorithm_names() {
+        for algorithm in Algorithm::ALL {
+            assert_eq!(algorithm.to_string().parse(), Ok(algorithm));
+        }
+        assert!("bogo".parse::<Algorithm>().is_err());
+        assert!(Algorithm::Merge.is_stable() && !Algorithm::Quick.is_stable());
+    }
+}
@@ -0,0 +1,63 @@
+use std::env;
+use std::process;
+use std::time::Instant;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use sorting::{Algorithm, Metrics};
+
+const USAGE: &str = "Usage: sorting [--algorithm insertion|merge|quick] [--size N] [--seed N]";
+
+struct Config {
+    algorithm: Algorithm,
+    size: usize,
+    seed: Option<u64>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+    let mut config = Config {
+        algorithm: Algorithm::Quick,
+        size: 10_000,
+        seed: None,
+    };
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or(format!("{flag} needs a value"))?;
+        let number = || format!("{flag} needs a whole number, not {value:?}");
+        match flag.as_str() {
+            "--algorithm" => config.algorithm = value.parse()?,
+            "--size" => config.size = value.parse().map_err(|_| number())?,
+            "--seed" => config.seed = Some(value.parse().map_err(|_| number())?),
+            _ => return Err(format!("unknown flag: {flag}")),
+        }
+    }
+    Ok(config)
+}
+
+fn main() {
+    let config = parse_args(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        eprintln!("{USAGE}");
+        process::exit(2);
+    });
+
+    // The same seed gives the same numbers, to compare algorithms fairly.
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let numbers: Vec<u32> = (0..config.size).map(|_| rng.gen()).collect();
+
+    let mut metrics = Metrics::new();
+    let start = Instant::now();
+    let sorted = config.algorithm.sort(numbers, &mut metrics);
+    let elapsed = start.elapsed();
+
+    assert!(
+        sorted.windows(2).all(|w| w[0] <= w[1]),
+        "the output isn't sorted"
+    );
+    println!(
+        "{} sort of {} random numbers: {metrics} in {elapsed:.2?}",
+        config.algorithm, config.size
+    );
+}
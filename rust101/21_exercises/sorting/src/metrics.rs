@@ -0,0 +1,44 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+// What a sort did, counted as it goes. The sorts do every comparison and
+// swap through these methods, so the counts can't miss one.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Metrics {
+    pub comparisons: u64,
+    pub swaps: u64,
+    // Elements moved into a new place without a swap - merge sort works
+    // this way.
+    pub moves: u64,
+}
+
+impl Metrics {
+    pub fn new() -> Metrics {
+        Metrics::default()
+    }
+
+    pub fn compare<T: Ord>(&mut self, a: &T, b: &T) -> Ordering {
+        self.comparisons += 1;
+        a.cmp(b)
+    }
+
+    // `a < b`, as a counted comparison.
+    pub fn less<T: Ord>(&mut self, a: &T, b: &T) -> bool {
+        self.compare(a, b) == Ordering::Less
+    }
+
+    pub fn swap<T>(&mut self, slice: &mut [T], i: usize, j: usize) {
+        self.swaps += 1;
+        slice.swap(i, j);
+    }
+}
+
+impl fmt::Display for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} comparisons, {} swaps, {} moves",
+            self.comparisons, self.swaps, self.moves
+        )
+    }
+}
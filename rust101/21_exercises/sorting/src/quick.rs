@@ -0,0 +1,85 @@
+use crate::Metrics;
+
+/// Quicksort: picks a pivot, swaps everything smaller to its left
+/// (partitioning), then sorts the two sides separately.
+///
+/// Not stable - partitioning swaps elements over long distances, past
+/// others equal to them. O(n log n) comparisons on average, O(n²) at worst.
+/// The pivot is the middle element, so already-sorted input, the classic
+/// worst case for a first- or last-element pivot, is a best case here.
+pub fn quicksort<T: Ord>(slice: &mut [T], metrics: &mut Metrics) {
+    // Recursing only into the smaller side, and looping on the bigger one,
+    // keeps the recursion depth at log n even when the split is lopsided.
+    let mut rest = slice;
+    while rest.len() > 1 {
+        let pivot = partition(rest, metrics);
+        let (left, right) = rest.split_at_mut(pivot);
+        let right = &mut right[1..]; // the pivot is in its final place
+        if left.len() < right.len() {
+            quicksort(left, metrics);
+            rest = right;
+        } else {
+            quicksort(right, metrics);
+            rest = left;
+        }
+    }
+}
+
+// Lomuto's partition: moves the pivot to the end, then keeps everything
+// before `store` smaller than it. Returns where the pivot ends up.
+fn partition<T: Ord>(slice: &mut [T], metrics: &mut Metrics) -> usize {
+    let last = slice.len() - 1;
+    metrics.swap(slice, slice.len() / 2, last);
+    let mut store = 0;
+    for i in 0..last {
+        if metrics.less(&slice[i], &slice[last]) {
+            if i != store {
+                metrics.swap(slice, i, store);
+            }
+            store += 1;
+        }
+    }
+    metrics.swap(slice, store, last);
+    store
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts() {
+        let mut numbers = [5, 2, 9, 1, 5, 6];
+        quicksort(&mut numbers, &mut Metrics::new());
+        assert_eq!(numbers, [1, 2, 5, 5, 6, 9]);
+    }
+
+    #[test]
+    fn partition_puts_the_pivot_in_place() {
+        let mut numbers = [7, 3, 8, 5, 1, 9, 2];
+        let pivot = partition(&mut numbers, &mut Metrics::new());
+        // The middle element, 5, was the pivot.
+        assert_eq!(numbers[pivot], 5);
+        assert!(numbers[..pivot].iter().all(|&x| x < 5));
+        assert!(numbers[pivot + 1..].iter().all(|&x| x >= 5));
+    }
+
+    #[test]
+    fn sorted_input_is_not_the_worst_case() {
+        let mut numbers: Vec<u32> = (0..1024).collect();
+        let mut metrics = Metrics::new();
+        quicksort(&mut numbers, &mut metrics);
+        assert!(metrics.comparisons < 1024 * 11, "{metrics}");
+    }
+
+    #[test]
+    fn all_equal_input_is_quadratic() {
+        // Nothing is less than the pivot, so every partition peels off one
+        // element. A three-way partition would fix it; this one is kept
+        // simple.
+        let mut numbers = [4; 100];
+        let mut metrics = Metrics::new();
+        quicksort(&mut numbers, &mut metrics);
+        assert_eq!(metrics.comparisons, 100 * 99 / 2);
+    }
+}
@@ -0,0 +1,42 @@
+use std::process::{Command, Output};
+
+fn sorting(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_sorting"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn prints_the_metrics() {
+    let output = sorting(&["--algorithm", "insertion", "--size", "3", "--seed", "1"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.starts_with("insertion sort of 3 random numbers: "),
+        "{stdout}"
+    );
+    assert!(stdout.contains(" comparisons, "), "{stdout}");
+}
+
+#[test]
+fn the_same_seed_gives_the_same_counts() {
+    let counts = |output: Output| {
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        stdout.split(" in ").next().unwrap().to_string()
+    };
+    let args = ["--algorithm", "merge", "--size", "500", "--seed", "42"];
+    assert_eq!(counts(sorting(&args)), counts(sorting(&args)));
+}
+
+#[test]
+fn usage_errors() {
+    for args in [
+        &["--algorithm", "bogo"][..],
+        &["--size"],
+        &["--size", "-1"],
+        &["--speed", "1"],
+    ] {
+        assert_eq!(sorting(args).status.code(), Some(2), "{args:?}");
+    }
+}
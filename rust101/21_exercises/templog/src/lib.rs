@@ -0,0 +1,325 @@
+/* Things learned during this exercise:
+   * A date without a crate for it is three numbers and a table of month
+   lengths - plus the leap year rule, which has two exceptions. Deriving
+   `Ord` on a struct compares its fields in order, so year, month, day
+   sort as dates should, and a timestamp whose first field is its date
+   sorts by date, then time.
+   * `split_once(['T', ' '])` splits at the first of several characters.
+   * Slice patterns like `let [at, celsius] = fields[..] else { .. }` check
+   the count and name the parts in one go.
+   * Collecting bad lines instead of stopping at the first lets one typo
+   in a long log be reported without losing the rest of it.
+   * A `BTreeMap` keyed by date groups and sorts at once; walking from the
+   first day to the last with `Date::next` then finds the days that are
+   missing from it.
+   * Scaling to a range divides by the range, which is zero when every
+   value is the same - so that case has to be picked out before dividing,
+   not patched up after.
+   * Terminals tell programs their width in `$COLUMNS` (when the shell
+   exports it); anything more reliable needs a crate or an ioctl.
+   * See https://en.wikipedia.org/wiki/Sparkline and
+   https://en.wikipedia.org/wiki/ISO_8601
+*/
+mod reading;
+mod sparkline;
+mod stats;
+mod time;
+
+pub use reading::{parse_line, parse_log, BadLine, LineError, Log, Reading, ABSOLUTE_ZERO};
+pub use sparkline::sparkline;
+pub use stats::{daily, Day, DayStats};
+pub use time::{Date, TimeError, Timestamp};
+
+use std::fmt::Write;
+
+// The width to use when the terminal doesn't say.
+pub const DEFAULT_WIDTH: usize = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Unit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl Unit {
+    pub fn convert(self, celsius: f64) -> f64 {
+        match self {
+            Unit::Celsius => celsius,
+            Unit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Unit::Celsius => "°C",
+            Unit::Fahrenheit => "°F",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    // None, or "-", means stdin.
+    pub path: Option<String>,
+    // Only readings from these days, both included.
+    pub from: Option<Date>,
+    pub to: Option<Date>,
+    pub unit: Unit,
+    // From `--width`; None means the terminal's.
+    pub width: Option<usize>,
+}
+
+fn option_value<T: std::str::FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    option: &str,
+    what: &str,
+) -> Result<T, String> {
+    let value = args.next().ok_or(format!("{option} needs {what}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("{option} needs {what}, not {value:?}"))
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut path = None;
+        let mut from = None;
+        let mut to = None;
+        let mut unit = Unit::default();
+        let mut width = None;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--from" => from = Some(option_value(&mut args, "--from", "a date, YYYY-MM-DD")?),
+                "--to" => to = Some(option_value(&mut args, "--to", "a date, YYYY-MM-DD")?),
+                "--unit" => {
+                    unit = match args.next().as_deref() {
+                        Some("c" | "C") => Unit::Celsius,
+                        Some("f" | "F") => Unit::Fahrenheit,
+                        Some(other) => return Err(format!("--unit is c or f, not {other:?}")),
+                        None => return Err("--unit needs c or f".to_string()),
+                    }
+                }
+                "--width" => {
+                    let columns: usize = option_value(&mut args, "--width", "a number of columns")?;
+                    if columns == 0 {
+                        return Err("--width needs at least one column".to_string());
+                    }
+                    width = Some(columns);
+                }
+                _ if arg.starts_with("--") => return Err(format!("unknown option: {arg:?}")),
+                _ if path.is_some() => return Err(format!("only one log, not {arg:?} too")),
+                _ => path = Some(arg),
+            }
+        }
+        if let (Some(from), Some(to)) = (from, to) {
+            if from > to {
+                return Err(format!("--from {from} is after --to {to}"));
+            }
+        }
+        Ok(Config {
+            path,
+            from,
+            to,
+            unit,
+            width,
+        })
+    }
+
+    fn keeps(&self, reading: &Reading) -> bool {
+        let date = reading.at.date;
+        self.from.is_none_or(|from| date >= from) && self.to.is_none_or(|to| date <= to)
+    }
+}
+
+// The terminal's width, from `$COLUMNS`. `var` looks up an environment
+// variable - `env::var(..).ok()` outside the tests.
+pub fn terminal_width(var: impl Fn(&str) -> Option<String>) -> usize {
+    var("COLUMNS")
+        .and_then(|columns| columns.trim().parse().ok())
+        .filter(|&columns| columns > 0)
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+// A table of the days, then the whole series as a sparkline `width` wide.
+pub fn report(readings: &[Reading], unit: Unit, width: usize) -> String {
+    let (Some(first), Some(last)) = (readings.first(), readings.last()) else {
+        return "No readings.\n".to_string();
+    };
+    let mut output = format!(
+        "{:<10}  {:>5}  {:>7}  {:>7}  {:>7}\n",
+        "date", "count", "min", "mean", "max"
+    );
+    for day in daily(readings) {
+        match day.stats {
+            Some(stats) => writeln!(
+                output,
+                "{}  {:>5}  {:>7.1}  {:>7.1}  {:>7.1}",
+                day.date,
+                stats.count,
+                unit.convert(stats.min),
+                unit.convert(stats.mean),
+                unit.convert(stats.max)
+            ),
+            None => writeln!(
+                output,
+                "{}  {:>5}  {:>7}  {:>7}  {:>7}",
+                day.date, 0, "-", "-", "-"
+            ),
+        }
+        .unwrap();
+    }
+
+    let values: Vec<f64> = readings
+        .iter()
+        .map(|reading| unit.convert(reading.celsius))
+        .collect();
+    let low = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let high = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let plural = if readings.len() == 1 { "" } else { "s" };
+    writeln!(
+        output,
+        "\n{} reading{plural} from {} to {}, {low:.1} to {high:.1} {}:",
+        readings.len(),
+        first.at,
+        last.at,
+        unit.symbol()
+    )
+    .unwrap();
+    output += &sparkline(&values, width);
+    output.push('\n');
+    output
+}
+
+// Reads the log and reports on the readings in the date range, returning
+// the report and the lines that were skipped.
+pub fn run(config: &Config, text: &str, width: usize) -> (String, Vec<BadLine>) {
+    let Log {
+        mut readings,
+        bad_lines,
+    } = parse_log(text);
+    readings.retain(|reading| config.keeps(reading));
+    (report(&readings, config.unit, width), bad_lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["templog".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    fn date(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&[])),
+            Ok(Config {
+                path: None,
+                from: None,
+                to: None,
+                unit: Unit::Celsius,
+                width: None,
+            })
+        );
+        assert_eq!(
+            Config::build(args(&[
+                "--unit",
+                "f",
+                "--from",
+                "2024-03-01",
+                "temps.log",
+                "--to",
+                "2024-03-01",
+                "--width",
+                "40"
+            ])),
+            Ok(Config {
+                path: Some("temps.log".to_string()),
+                from: Some(date("2024-03-01")),
+                to: Some(date("2024-03-01")),
+                unit: Unit::Fahrenheit,
+                width: Some(40),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        for bad in [
+            &["--from"][..],
+            &["--from", "March"],
+            &["--to", "2024-02-30"],
+            &["--unit", "k"],
+            &["--unit"],
+            &["--width", "0"],
+            &["--width", "wide"],
+            &["--verbose"],
+            &["a.log", "b.log"],
+        ] {
+            assert!(Config::build(args(bad)).is_err(), "{bad:?}");
+        }
+        assert_eq!(
+            Config::build(args(&["--from", "2024-03-02", "--to", "2024-03-01"])),
+            Err("--from 2024-03-02 is after --to 2024-03-01".to_string())
+        );
+    }
+
+    #[test]
+    fn converts_to_fahrenheit() {
+        assert_eq!(Unit::Fahrenheit.convert(100.0), 212.0);
+        assert_eq!(Unit::Fahrenheit.convert(-40.0), -40.0);
+        assert_eq!(Unit::Celsius.convert(21.5), 21.5);
+    }
+
+    #[test]
+    fn finds_the_terminal_width() {
+        let columns =
+            |value: &'static str| move |name: &str| (name == "COLUMNS").then(|| value.to_string());
+        assert_eq!(terminal_width(columns("120")), 120);
+        assert_eq!(terminal_width(columns("0")), DEFAULT_WIDTH);
+        assert_eq!(terminal_width(columns("wide")), DEFAULT_WIDTH);
+        assert_eq!(terminal_width(|_| None), DEFAULT_WIDTH);
+    }
+
+    #[test]
+    fn reports_in_the_chosen_unit() {
+        let log = "2024-03-01T06:00,0\n2024-03-01T12:00,10\n2024-03-02T12:00,-5\n";
+        let config = Config::build(args(&["--unit", "f"])).unwrap();
+        let (output, bad_lines) = run(&config, log, 10);
+        assert!(bad_lines.is_empty());
+        assert_eq!(
+            output,
+            "date        count      min     mean      max\n\
+             2024-03-01      2     32.0     41.0     50.0\n\
+             2024-03-02      1     23.0     23.0     23.0\n\
+             \n\
+             3 readings from 2024-03-01 06:00 to 2024-03-02 12:00, 23.0 to 50.0 °F:\n\
+             ----###___\n"
+        );
+    }
+
+    #[test]
+    fn filters_by_date() {
+        let log =
+            "2024-03-01T12:00,1\n2024-03-02T12:00,2\n2024-03-03T12:00,3\n2024-03-04T12:00,4\n";
+        let config = Config::build(args(&["--from", "2024-03-02", "--to", "2024-03-03"])).unwrap();
+        let (output, _) = run(&config, log, 4);
+        assert!(output.contains("2 readings from 2024-03-02 12:00 to 2024-03-03 12:00"));
+        assert!(!output.contains("2024-03-01  "));
+        assert!(!output.contains("2024-03-04  "));
+
+        let config = Config::build(args(&["--from", "2025-01-01"])).unwrap();
+        assert_eq!(run(&config, log, 4).0, "No readings.\n");
+    }
+}
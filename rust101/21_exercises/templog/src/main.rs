@@ -0,0 +1,38 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+
+use templog::Config;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: templog [--from YYYY-MM-DD] [--to YYYY-MM-DD] [--unit c|f] [--width COLUMNS] [FILE|-]");
+        process::exit(2);
+    });
+
+    let text = match config.path.as_deref() {
+        Some(path) if path != "-" => fs::read_to_string(path).unwrap_or_else(|err| {
+            eprintln!("couldn't read {path}: {err}");
+            process::exit(1);
+        }),
+        _ => {
+            let mut text = String::new();
+            if let Err(err) = io::stdin().read_to_string(&mut text) {
+                eprintln!("couldn't read stdin: {err}");
+                process::exit(1);
+            }
+            text
+        }
+    };
+
+    let width = config
+        .width
+        .unwrap_or_else(|| templog::terminal_width(|name| env::var(name).ok()));
+    let (report, bad_lines) = templog::run(&config, &text, width);
+    for line in &bad_lines {
+        eprintln!("skipping {line}");
+    }
+    print!("{report}");
+}
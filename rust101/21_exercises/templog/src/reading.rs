@@ -0,0 +1,191 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::time::{TimeError, Timestamp};
+
+// The coldest anything can be.
+pub const ABSOLUTE_ZERO: f64 = -273.15;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reading {
+    pub at: Timestamp,
+    pub celsius: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LineError {
+    // No comma, or more than one.
+    Fields(usize),
+    Timestamp(TimeError),
+    NotANumber(String),
+    // NaN and the infinities parse as f64s, but aren't temperatures.
+    NotFinite(String),
+    BelowAbsoluteZero(f64),
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LineError::Fields(count) => {
+                write!(f, "expected timestamp,celsius but found {count} fields")
+            }
+            LineError::Timestamp(err) => write!(f, "{err}"),
+            LineError::NotANumber(text) => write!(f, "{text:?} isn't a temperature"),
+            LineError::NotFinite(text) => write!(f, "{text:?} isn't a temperature"),
+            LineError::BelowAbsoluteZero(celsius) => {
+                write!(f, "{celsius} °C is below absolute zero")
+            }
+        }
+    }
+}
+
+impl Error for LineError {}
+
+// A line that couldn't be read, and why. Lines count from 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BadLine {
+    pub line: usize,
+    pub err: LineError,
+}
+
+impl fmt::Display for BadLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.err)
+    }
+}
+
+// `2024-03-01T08:00,4.5`, with spaces around either field allowed.
+pub fn parse_line(line: &str) -> Result<Reading, LineError> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    let [at, celsius] = fields[..] else {
+        return Err(LineError::Fields(fields.len()));
+    };
+    let at = at.parse().map_err(LineError::Timestamp)?;
+    let value: f64 = celsius
+        .parse()
+        .map_err(|_| LineError::NotANumber(celsius.to_string()))?;
+    if !value.is_finite() {
+        return Err(LineError::NotFinite(celsius.to_string()));
+    }
+    if value < ABSOLUTE_ZERO {
+        return Err(LineError::BelowAbsoluteZero(value));
+    }
+    Ok(Reading { at, celsius: value })
+}
+
+// What was read: the readings in time order, and the lines that were
+// skipped.
+#[derive(Debug, Default, PartialEq)]
+pub struct Log {
+    pub readings: Vec<Reading>,
+    pub bad_lines: Vec<BadLine>,
+}
+
+// Reads every line of a log, skipping blank lines and `#` comments. A line
+// that doesn't make sense is kept as a BadLine and the rest are still
+// read. Loggers don't always write in order, so the readings are sorted;
+// the sort is stable, so two at the same time stay as they were.
+pub fn parse_log(text: &str) -> Log {
+    let mut log = Log::default();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match parse_line(line) {
+            Ok(reading) => log.readings.push(reading),
+            Err(err) => log.bad_lines.push(BadLine {
+                line: index + 1,
+                err,
+            }),
+        }
+    }
+    log.readings.sort_by_key(|reading| reading.at);
+    log
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> Timestamp {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_a_line() {
+        assert_eq!(
+            parse_line("2024-03-01T08:00,4.5"),
+            Ok(Reading {
+                at: at("2024-03-01T08:00"),
+                celsius: 4.5
+            })
+        );
+        assert_eq!(
+            parse_line(" 2024-03-01 08:00:30 , -12 "),
+            Ok(Reading {
+                at: at("2024-03-01T08:00:30"),
+                celsius: -12.0
+            })
+        );
+    }
+
+    #[test]
+    fn reports_what_is_wrong_with_a_line() {
+        let errors: Vec<String> = [
+            "2024-03-01T08:00",
+            "2024-03-01T08:00,4.5,dry",
+            "2024-03-01T8:00,4.5",
+            "2024-02-30T08:00,4.5",
+            "2024-03-01T08:00,warm",
+            "2024-03-01T08:00,",
+            "2024-03-01T08:00,NaN",
+            "2024-03-01T08:00,-300",
+        ]
+        .iter()
+        .map(|line| parse_line(line).unwrap_err().to_string())
+        .collect();
+        assert_eq!(
+            errors,
+            [
+                "expected timestamp,celsius but found 1 fields",
+                "expected timestamp,celsius but found 3 fields",
+                "\"2024-03-01T8:00\" isn't a date (YYYY-MM-DD) or a time (YYYY-MM-DDTHH:MM[:SS])",
+                "there's no such date as \"2024-02-30T08:00\"",
+                "\"warm\" isn't a temperature",
+                "\"\" isn't a temperature",
+                "\"NaN\" isn't a temperature",
+                "-300 °C is below absolute zero",
+            ]
+        );
+    }
+
+    #[test]
+    fn sorts_the_readings_and_keeps_the_bad_lines() {
+        let log = parse_log(
+            "# a comment\n\
+             2024-03-02T09:00,3\n\
+             \n\
+             2024-03-01T18:00,2\n\
+             oops\n\
+             2024-03-01T06:00,1\n\
+             2024-03-02T09:00,4\n",
+        );
+        let values: Vec<f64> = log.readings.iter().map(|reading| reading.celsius).collect();
+        // The two at 09:00 keep their order.
+        assert_eq!(values, [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(
+            log.bad_lines,
+            [BadLine {
+                line: 5,
+                err: LineError::Fields(1)
+            }]
+        );
+    }
+
+    #[test]
+    fn an_empty_log_has_nothing() {
+        assert_eq!(parse_log(""), Log::default());
+        assert_eq!(parse_log("# only a comment\n\n"), Log::default());
+    }
+}
@@ -0,0 +1,82 @@
+// From lowest to highest. Plain ASCII, so the chart looks the same in any
+// terminal and any font.
+const LEVELS: [char; 8] = ['_', '.', '-', '~', '=', '+', '*', '#'];
+
+// The mean of each of `width` runs of `values`, in order. With more
+// values than columns each column averages a few; with fewer, a value
+// spreads over several columns.
+fn columns(values: &[f64], width: usize) -> Vec<f64> {
+    let count = values.len();
+    (0..width)
+        .map(|column| {
+            let start = column * count / width;
+            let end = ((column + 1) * count / width).max(start + 1);
+            let run = &values[start..end];
+            run.iter().sum::<f64>() / run.len() as f64
+        })
+        .collect()
+}
+
+// A chart of `values` in `width` characters, each as high as its column's
+// mean between the lowest and the highest. Nothing to show, or no room to
+// show it, is an empty string. When every value is the same there's no
+// range to scale by, so the line is flat, halfway up.
+pub fn sparkline(values: &[f64], width: usize) -> String {
+    if values.is_empty() || width == 0 {
+        return String::new();
+    }
+    let columns = columns(values, width);
+    let low = columns.iter().copied().fold(f64::INFINITY, f64::min);
+    let high = columns.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let top = LEVELS.len() - 1;
+    columns
+        .iter()
+        .map(|&value| {
+            if high > low {
+                LEVELS[((value - low) / (high - low) * top as f64).round() as usize]
+            } else {
+                LEVELS[LEVELS.len() / 2]
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans_the_levels() {
+        let values: Vec<f64> = (0..8).map(f64::from).collect();
+        assert_eq!(sparkline(&values, 8), "_.-~=+*#");
+        assert_eq!(sparkline(&[10.0, 0.0, 5.0, 10.0], 4), "#_=#");
+    }
+
+    #[test]
+    fn averages_down_to_the_width() {
+        // Pairs: 0 and 2, 4 and 6, 14 and 14.
+        assert_eq!(sparkline(&[0.0, 2.0, 4.0, 6.0, 14.0, 14.0], 3), "_-#");
+        assert_eq!(
+            sparkline(&[1.0, 2.0, 3.0, 4.0, 5.0], 80).chars().count(),
+            80
+        );
+    }
+
+    #[test]
+    fn stretches_up_to_the_width() {
+        assert_eq!(sparkline(&[0.0, 1.0], 6), "___###");
+        assert_eq!(sparkline(&[0.0, 1.0, 0.5], 6), "__##==");
+    }
+
+    #[test]
+    fn identical_values_are_flat() {
+        assert_eq!(sparkline(&[21.5; 10], 5), "=====");
+        assert_eq!(sparkline(&[-3.0], 4), "====");
+    }
+
+    #[test]
+    fn nothing_to_draw() {
+        assert_eq!(sparkline(&[], 10), "");
+        assert_eq!(sparkline(&[1.0, 2.0], 0), "");
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::BTreeMap;
+
+use crate::reading::Reading;
+use crate::time::Date;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DayStats {
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+}
+
+// A day between the first reading and the last. A day with no readings
+// is still there, with no stats, so a gap in the log shows up as one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Day {
+    pub date: Date,
+    pub stats: Option<DayStats>,
+}
+
+// Running totals, for a day's readings.
+#[derive(Default)]
+struct Totals {
+    count: usize,
+    sum: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Totals {
+    fn add(&mut self, celsius: f64) {
+        if self.count == 0 {
+            (self.min, self.max) = (celsius, celsius);
+        }
+        self.count += 1;
+        self.sum += celsius;
+        self.min = self.min.min(celsius);
+        self.max = self.max.max(celsius);
+    }
+}
+
+// Every day from the first reading to the last, in order, whatever order
+// the readings are in.
+pub fn daily(readings: &[Reading]) -> Vec<Day> {
+    let mut totals: BTreeMap<Date, Totals> = BTreeMap::new();
+    for reading in readings {
+        totals
+            .entry(reading.at.date)
+            .or_default()
+            .add(reading.celsius);
+    }
+    let (Some(&first), Some(&last)) = (totals.keys().next(), totals.keys().next_back()) else {
+        return Vec::new();
+    };
+
+    let mut days = Vec::new();
+    let mut date = first;
+    loop {
+        let stats = totals.get(&date).map(|totals| DayStats {
+            count: totals.count,
+            min: totals.min,
+            max: totals.max,
+            mean: totals.sum / totals.count as f64,
+        });
+        days.push(Day { date, stats });
+        if date == last {
+            return days;
+        }
+        date = date.next();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reading(at: &str, celsius: f64) -> Reading {
+        Reading {
+            at: at.parse().unwrap(),
+            celsius,
+        }
+    }
+
+    fn date(s: &str) -> Date {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn summarizes_each_day() {
+        let readings = [
+            reading("2024-03-01T06:00", 2.0),
+            reading("2024-03-01T12:00", 9.0),
+            reading("2024-03-01T18:00", 4.0),
+            reading("2024-03-02T12:00", -1.5),
+        ];
+        assert_eq!(
+            daily(&readings),
+            [
+                Day {
+                    date: date("2024-03-01"),
+                    stats: Some(DayStats {
+                        count: 3,
+                        min: 2.0,
+                        max: 9.0,
+                        mean: 5.0
+                    })
+                },
+                Day {
+                    date: date("2024-03-02"),
+                    stats: Some(DayStats {
+                        count: 1,
+                        min: -1.5,
+                        max: -1.5,
+                        mean: -1.5
+                    })
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn fills_in_missing_days() {
+        let readings = [
+            reading("2024-02-28T12:00", 1.0),
+            reading("2024-03-02T12:00", 3.0),
+        ];
+        let days = daily(&readings);
+        let dates: Vec<String> = days.iter().map(|day| day.date.to_string()).collect();
+        assert_eq!(
+            dates,
+            ["2024-02-28", "2024-02-29", "2024-03-01", "2024-03-02"]
+        );
+        let counts: Vec<usize> = days
+            .iter()
+            .map(|day| day.stats.map_or(0, |stats| stats.count))
+            .collect();
+        assert_eq!(counts, [1, 0, 0, 1]);
+    }
+
+    #[test]
+    fn order_does_not_matter() {
+        let readings = [
+            reading("2024-03-02T12:00", 3.0),
+            reading("2024-03-01T12:00", 1.0),
+            reading("2024-03-02T06:00", 5.0),
+        ];
+        let mut sorted = readings;
+        sorted.sort_by_key(|reading| reading.at);
+        assert_eq!(daily(&readings), daily(&sorted));
+        assert_eq!(daily(&readings)[1].stats.unwrap().mean, 4.0);
+    }
+
+    #[test]
+    fn no_readings_no_days() {
+        assert!(daily(&[]).is_empty());
+    }
+}
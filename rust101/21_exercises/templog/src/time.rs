@@ -0,0 +1,261 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: u32,
+    pub month: u32,
+    pub day: u32,
+}
+
+// A time of day on a date, to the second, with no time zone: the log is
+// taken to be in whatever zone it was written in. The derived order is by
+// date, then time, since that's the order of the fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    pub date: Date,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeError {
+    // Not laid out as `YYYY-MM-DD`, or `YYYY-MM-DDTHH:MM[:SS]`.
+    Format(String),
+    NoSuchDate(String),
+    NoSuchTime(String),
+}
+
+impl fmt::Display for TimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimeError::Format(text) => write!(
+                f,
+                "{text:?} isn't a date (YYYY-MM-DD) or a time (YYYY-MM-DDTHH:MM[:SS])"
+            ),
+            TimeError::NoSuchDate(text) => write!(f, "there's no such date as {text:?}"),
+            TimeError::NoSuchTime(text) => write!(f, "there's no such time as {text:?}"),
+        }
+    }
+}
+
+impl Error for TimeError {}
+
+fn is_leap_year(year: u32) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Exactly `digits` ASCII digits. `str::parse` alone would also take a sign.
+fn number(text: &str, digits: usize) -> Option<u32> {
+    if text.len() == digits && text.bytes().all(|byte| byte.is_ascii_digit()) {
+        text.parse().ok()
+    } else {
+        None
+    }
+}
+
+impl Date {
+    // The day after, for walking through a range of days.
+    pub fn next(self) -> Date {
+        if self.day < days_in_month(self.year, self.month) {
+            Date {
+                day: self.day + 1,
+                ..self
+            }
+        } else if self.month < 12 {
+            Date {
+                month: self.month + 1,
+                day: 1,
+                ..self
+            }
+        } else {
+            Date {
+                year: self.year + 1,
+                month: 1,
+                day: 1,
+            }
+        }
+    }
+}
+
+impl FromStr for Date {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Date, TimeError> {
+        let format = || TimeError::Format(s.to_string());
+        let mut parts = s.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(format());
+        };
+        let date = Date {
+            year: number(year, 4).ok_or_else(format)?,
+            month: number(month, 2).ok_or_else(format)?,
+            day: number(day, 2).ok_or_else(format)?,
+        };
+        if !(1..=12).contains(&date.month)
+            || !(1..=days_in_month(date.year, date.month)).contains(&date.day)
+        {
+            return Err(TimeError::NoSuchDate(s.to_string()));
+        }
+        Ok(date)
+    }
+}
+
+// The date and time can be split by a T, as ISO 8601 has it, or a space.
+impl FromStr for Timestamp {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Timestamp, TimeError> {
+        let format = || TimeError::Format(s.to_string());
+        let (date, time) = s.split_once(['T', ' ']).ok_or_else(format)?;
+        // A bad date reports the whole timestamp, not just its first half.
+        let date = date.parse().map_err(|err| match err {
+            TimeError::NoSuchDate(_) => TimeError::NoSuchDate(s.to_string()),
+            _ => format(),
+        })?;
+        let parts: Vec<&str> = time.split(':').collect();
+        let (hour, minute, second) = match parts[..] {
+            [hour, minute] => (hour, minute, "00"),
+            [hour, minute, second] => (hour, minute, second),
+            _ => return Err(format()),
+        };
+        let timestamp = Timestamp {
+            date,
+            hour: number(hour, 2).ok_or_else(format)?,
+            minute: number(minute, 2).ok_or_else(format)?,
+            second: number(second, 2).ok_or_else(format)?,
+        };
+        if timestamp.hour > 23 || timestamp.minute > 59 || timestamp.second > 59 {
+            return Err(TimeError::NoSuchTime(s.to_string()));
+        }
+        Ok(timestamp)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+// Seconds only when there are some.
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {:02}:{:02}", self.date, self.hour, self.minute)?;
+        if self.second != 0 {
+            write!(f, ":{:02}", self.second)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u32, month: u32, day: u32) -> Date {
+        Date { year, month, day }
+    }
+
+    #[test]
+    fn parses_dates() {
+        assert_eq!("2024-03-01".parse(), Ok(date(2024, 3, 1)));
+        assert_eq!("2024-02-29".parse(), Ok(date(2024, 2, 29)));
+        assert_eq!("2000-02-29".parse(), Ok(date(2000, 2, 29)));
+        for bad in [
+            "2023-02-29",
+            "1900-02-29",
+            "2024-04-31",
+            "2024-13-01",
+            "2024-00-10",
+            "2024-01-00",
+        ] {
+            assert_eq!(
+                bad.parse::<Date>(),
+                Err(TimeError::NoSuchDate(bad.to_string()))
+            );
+        }
+        for bad in [
+            "",
+            "2024-3-1",
+            "24-03-01",
+            "2024/03/01",
+            "2024-03-01-02",
+            "2024-+3-01",
+        ] {
+            assert_eq!(bad.parse::<Date>(), Err(TimeError::Format(bad.to_string())));
+        }
+    }
+
+    #[test]
+    fn parses_timestamps() {
+        let at = |hour, minute, second| Timestamp {
+            date: date(2024, 3, 1),
+            hour,
+            minute,
+            second,
+        };
+        assert_eq!("2024-03-01T08:30".parse(), Ok(at(8, 30, 0)));
+        assert_eq!("2024-03-01 23:59:59".parse(), Ok(at(23, 59, 59)));
+        assert_eq!(
+            "2024-03-01T24:00".parse::<Timestamp>(),
+            Err(TimeError::NoSuchTime("2024-03-01T24:00".to_string()))
+        );
+        assert_eq!(
+            "2023-02-29T10:00".parse::<Timestamp>(),
+            Err(TimeError::NoSuchDate("2023-02-29T10:00".to_string()))
+        );
+        for bad in [
+            "2024-03-01",
+            "2024-03-01T8:30",
+            "2024-03-01T08",
+            "2024-03-01T08:30:00:00",
+            "yesterday 08:30",
+        ] {
+            assert_eq!(
+                bad.parse::<Timestamp>(),
+                Err(TimeError::Format(bad.to_string())),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn orders_by_date_then_time() {
+        let parse = |s: &str| s.parse::<Timestamp>().unwrap();
+        assert!(parse("2024-03-01T23:59") < parse("2024-03-02T00:00"));
+        assert!(parse("2023-12-31T12:00") < parse("2024-01-01T00:00"));
+        assert!(parse("2024-03-01T08:00:01") > parse("2024-03-01T08:00"));
+    }
+
+    #[test]
+    fn walks_through_days() {
+        assert_eq!(date(2024, 2, 28).next(), date(2024, 2, 29));
+        assert_eq!(date(2024, 2, 29).next(), date(2024, 3, 1));
+        assert_eq!(date(2023, 2, 28).next(), date(2023, 3, 1));
+        assert_eq!(date(2024, 4, 30).next(), date(2024, 5, 1));
+        assert_eq!(date(2024, 12, 31).next(), date(2025, 1, 1));
+    }
+
+    #[test]
+    fn displays_as_it_parses() {
+        assert_eq!(date(2024, 3, 1).to_string(), "2024-03-01");
+        let at: Timestamp = "2024-03-01T08:05".parse().unwrap();
+        assert_eq!(at.to_string(), "2024-03-01 08:05");
+        let at: Timestamp = "2024-03-01T08:05:09".parse().unwrap();
+        assert_eq!(at.to_string(), "2024-03-01 08:05:09");
+    }
+}
@@ -0,0 +1,142 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const MARCH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/march.log");
+const SINGLE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/single.log");
+const FLAT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/flat.log");
+
+fn templog(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_templog"))
+        .args(args)
+        .env_remove("COLUMNS")
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> &str {
+    std::str::from_utf8(&output.stderr).unwrap()
+}
+
+#[test]
+fn sorts_the_readings_and_shows_the_missing_day() {
+    let output = templog(&["--width", "30", MARCH]);
+    assert_eq!(
+        stdout(&output),
+        "date        count      min     mean      max\n\
+         2024-03-01      3      2.5      6.0      9.5\n\
+         2024-03-02      3      1.0      4.7      8.0\n\
+         2024-03-03      0        -        -        -\n\
+         2024-03-04      2      4.5      8.5     12.5\n\
+         2024-03-05      1     11.0     11.0     11.0\n\
+         \n\
+         9 readings from 2024-03-01 06:00 to 2024-03-05 12:00, 1.0 to 12.5 °C:\n\
+         ....+++~~~____===-------###***\n"
+    );
+    assert_eq!(
+        stderr(&output),
+        "skipping line 9: there's no such time as \"2024-03-02T99:00\"\n\
+         skipping line 14: \"hot\" isn't a temperature\n"
+    );
+}
+
+#[test]
+fn filters_and_converts() {
+    let output = templog(&[
+        "--from",
+        "2024-03-02",
+        "--to",
+        "2024-03-04",
+        "--unit",
+        "f",
+        "--width",
+        "30",
+        MARCH,
+    ]);
+    assert_eq!(
+        stdout(&output),
+        "date        count      min     mean      max\n\
+         2024-03-02      3     33.8     40.4     46.4\n\
+         2024-03-03      0        -        -        -\n\
+         2024-03-04      2     40.1     47.3     54.5\n\
+         \n\
+         5 readings from 2024-03-02 06:00 to 2024-03-04 12:00, 33.8 to 54.5 °F:\n\
+         ______======------------######\n"
+    );
+}
+
+#[test]
+fn a_single_reading() {
+    assert_eq!(
+        stdout(&templog(&["--width", "12", SINGLE])),
+        "date        count      min     mean      max\n\
+         2024-06-21      1     24.5     24.5     24.5\n\
+         \n\
+         1 reading from 2024-06-21 12:00 to 2024-06-21 12:00, 24.5 to 24.5 °C:\n\
+         ============\n"
+    );
+}
+
+#[test]
+fn identical_values_are_a_flat_line() {
+    let output = templog(&["--width", "12", FLAT]);
+    assert!(stdout(&output).ends_with(", 18.0 to 18.0 °C:\n============\n"));
+}
+
+#[test]
+fn the_chart_fills_the_terminal() {
+    let output = Command::new(env!("CARGO_BIN_EXE_templog"))
+        .arg(MARCH)
+        .env("COLUMNS", "50")
+        .output()
+        .unwrap();
+    let chart = stdout(&output).lines().last().unwrap();
+    assert_eq!(chart.chars().count(), 50);
+
+    // Without $COLUMNS, 80.
+    let output = templog(&[MARCH]);
+    let chart = stdout(&output).lines().last().unwrap();
+    assert_eq!(chart.chars().count(), 80);
+}
+
+#[test]
+fn reads_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_templog"))
+        .args(["--width", "4", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"2024-03-01T12:00,5\n2024-03-01T13:00,7\n")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(stdout(&output)
+        .ends_with("2 readings from 2024-03-01 12:00 to 2024-03-01 13:00, 5.0 to 7.0 °C:\n__##\n"));
+}
+
+#[test]
+fn nothing_in_range() {
+    assert_eq!(
+        stdout(&templog(&["--from", "2024-04-01", MARCH])),
+        "No readings.\n"
+    );
+}
+
+#[test]
+fn errors() {
+    let output = templog(&["--unit", "kelvin", MARCH]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("--unit is c or f, not \"kelvin\""));
+
+    let output = templog(&["tests/data/no_such.log"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).starts_with("couldn't read tests/data/no_such.log: "));
+}
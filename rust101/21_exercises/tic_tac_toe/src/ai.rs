@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use crate::board::{Board, Status};
+
+// A perfect player, by negamax: a position's score for the player to move
+// is the best of minus the scores of the positions each move leads to -
+// since what's good for one player is exactly as bad for the other.
+//
+// Scores are memoized by board. Many move orders reach the same position,
+// so there are only a few thousand to work out, against hundreds of
+// thousands of move sequences.
+#[derive(Debug, Default)]
+pub struct Minimax {
+    scores: HashMap<Board, i32>,
+}
+
+impl Minimax {
+    pub fn new() -> Minimax {
+        Minimax::default()
+    }
+
+    // Positive if the player to move can force a win, zero for a draw with
+    // best play, negative for a loss. A win scores higher the more cells
+    // are left, so the AI takes a quick win over a slow one, and puts off a
+    // loss it can't avoid.
+    pub fn score(&mut self, board: &Board) -> i32 {
+        if let Some(&score) = self.scores.get(board) {
+            return score;
+        }
+        let empty = board.empty_cells().count() as i32;
+        let score = match board.status() {
+            // The last move won, so the player to move has lost.
+            Status::Won(_) => -(empty + 1),
+            Status::Draw => 0,
+            Status::InProgress => board
+                .empty_cells()
+                .map(|cell| -self.score(&after(board, cell)))
+                .max()
+                .unwrap(),
+        };
+        self.scores.insert(*board, score);
+        score
+    }
+
+    // The best move for the player to move, or None if the game is over.
+    // Of equally good moves, the lowest-numbered cell.
+    pub fn choose(&mut self, board: &Board) -> Option<usize> {
+        if board.status() != Status::InProgress {
+            return None;
+        }
+        let cells: Vec<usize> = board.empty_cells().collect();
+        let scores: Vec<i32> = cells
+            .iter()
+            .map(|&cell| -self.score(&after(board, cell)))
+            .collect();
+        let best = *scores.iter().max()?;
+        cells
+            .into_iter()
+            .zip(scores)
+            .find_map(|(cell, score)| (score == best).then_some(cell))
+    }
+}
+
+fn after(board: &Board, cell: usize) -> Board {
+    let mut next = *board;
+    next.play(cell).expect("only empty cells are tried");
+    next
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Player;
+
+    fn board(moves: &[usize]) -> Board {
+        let mut board = Board::new();
+        for &cell in moves {
+            board.play(cell).unwrap();
+        }
+        board
+    }
+
+    #[test]
+    fn perfect_play_is_a_draw() {
+        assert_eq!(Minimax::new().score(&Board::new()), 0);
+    }
+
+    #[test]
+    fn takes_a_win() {
+        // X X .
+        // O O .
+        // . . .   X to move takes 2 rather than blocking at 5.
+        assert_eq!(Minimax::new().choose(&board(&[0, 3, 1, 4])), Some(2));
+    }
+
+    #[test]
+    fn blocks_a_win() {
+        // X X .
+        // . O .
+        // . . .   O has to block at 2.
+        assert_eq!(Minimax::new().choose(&board(&[0, 4, 1])), Some(2));
+    }
+
+    #[test]
+    fn nothing_to_choose_when_the_game_is_over() {
+        assert_eq!(Minimax::new().choose(&board(&[0, 3, 1, 4, 2])), None);
+    }
+
+    // Plays the AI as `ai` against every possible sequence of replies, and
+    // returns how many games that was and how many the AI won. Panics if the AI ever loses.
+    fn never_loses(board: Board, ai: Player, minimax: &mut Minimax) -> (usize, usize) {
+        match board.status() {
+            Status::Won(winner) => {
+                assert_eq!(winner, ai, "the AI lost:\n{board}");
+                return (1, 1);
+            }
+            Status::Draw => return (1, 0),
+            Status::InProgress => {}
+        }
+        if board.to_move() == ai {
+            let cell = minimax.choose(&board).unwrap();
+            never_loses(after(&board, cell), ai, minimax)
+        } else {
+            board
+                .empty_cells()
+                .map(|cell| never_loses(after(&board, cell), ai, minimax))
+                .fold((0, 0), |(games, wins), (more_games, more_wins)| {
+                    (games + more_games, wins + more_wins)
+                })
+        }
+    }
+
+    #[test]
+    fn never_loses_as_either_side() {
+        let mut minimax = Minimax::new();
+        for ai in [Player::X, Player::O] {
+            let (games, wins) = never_loses(Board::new(), ai, &mut minimax);
+            // Not just safe: some of the opponent's mistakes get punished.
+            assert!(
+                wins > 0 && wins < games,
+                "{ai}: {wins} wins in {games} games"
+            );
+        }
+    }
+}
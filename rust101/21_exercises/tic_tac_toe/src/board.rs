@@ -0,0 +1,300 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Player {
+    X,
+    O,
+}
+
+impl Player {
+    pub fn other(self) -> Player {
+        match self {
+            Player::X => Player::O,
+            Player::O => Player::X,
+        }
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mark = match self {
+            Player::X => "X",
+            Player::O => "O",
+        };
+        write!(f, "{mark}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    InProgress,
+    Won(Player),
+    Draw,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveError {
+    // Cells are numbered 0 to 8.
+    OffBoard(usize),
+    Occupied(usize),
+    GameOver,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MoveError::OffBoard(cell) => write!(f, "there's no cell {cell}"),
+            MoveError::Occupied(cell) => {
+                write!(f, "{} is already taken", Board::coordinates(*cell))
+            }
+            MoveError::GameOver => write!(f, "the game is already over"),
+        }
+    }
+}
+
+impl Error for MoveError {}
+
+// The three rows, three columns and two diagonals, as cell numbers.
+pub const LINES: [[usize; 3]; 8] = [
+    [0, 1, 2],
+    [3, 4, 5],
+    [6, 7, 8],
+    [0, 3, 6],
+    [1, 4, 7],
+    [2, 5, 8],
+    [0, 4, 8],
+    [2, 4, 6],
+];
+
+// Nine cells, numbered row by row from the top left:
+//
+//     0 1 2
+//     3 4 5
+//     6 7 8
+//
+// X always goes first, so whose turn it is follows from how many marks
+// there are, and doesn't need storing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Board {
+    cells: [Option<Player>; 9],
+}
+
+impl Board {
+    pub fn new() -> Board {
+        Board::default()
+    }
+
+    pub fn cell(&self, cell: usize) -> Option<Player> {
+        self.cells[cell]
+    }
+
+    pub fn to_move(&self) -> Player {
+        let marks = self.cells.iter().flatten().count();
+        if marks.is_multiple_of(2) {
+            Player::X
+        } else {
+            Player::O
+        }
+    }
+
+    pub fn empty_cells(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..9).filter(|&cell| self.cells[cell].is_none())
+    }
+
+    pub fn winner(&self) -> Option<Player> {
+        LINES.iter().find_map(|&[a, b, c]| {
+            let player = self.cells[a]?;
+            (self.cells[b] == Some(player) && self.cells[c] == Some(player)).then_some(player)
+        })
+    }
+
+    pub fn status(&self) -> Status {
+        match self.winner() {
+            Some(player) => Status::Won(player),
+            None if self.cells.iter().all(Option::is_some) => Status::Draw,
+            None => Status::InProgress,
+        }
+    }
+
+    // Puts the next player's mark in `cell`. Checks the move is legal
+    // first, so a rejected move leaves the board as it was.
+    pub fn play(&mut self, cell: usize) -> Result<Player, MoveError> {
+        if cell >= 9 {
+            return Err(MoveError::OffBoard(cell));
+        }
+        if self.status() != Status::InProgress {
+            return Err(MoveError::GameOver);
+        }
+        if self.cells[cell].is_some() {
+            return Err(MoveError::Occupied(cell));
+        }
+        let player = self.to_move();
+        self.cells[cell] = Some(player);
+        Ok(player)
+    }
+
+    // Like "b2": a letter for the column and a number for the row, as
+    // the board is drawn.
+    pub fn coordinates(cell: usize) -> String {
+        let column = char::from(b'a' + (cell % 3) as u8);
+        format!("{column}{}", cell / 3 + 1)
+    }
+
+    // The reverse of `coordinates`, in either case and either order
+    // ("b2", "B2" or "2b").
+    pub fn parse_coordinates(s: &str) -> Option<usize> {
+        let s = s.trim().to_ascii_lowercase();
+        let mut chars = s.chars();
+        let (first, second) = (chars.next()?, chars.next()?);
+        if chars.next().is_some() {
+            return None;
+        }
+        let (column, row) = if first.is_ascii_digit() {
+            (second, first)
+        } else {
+            (first, second)
+        };
+        let column = match column {
+            'a'..='c' => column as usize - 'a' as usize,
+            _ => return None,
+        };
+        let row = match row {
+            '1'..='3' => row as usize - '1' as usize,
+            _ => return None,
+        };
+        Some(row * 3 + column)
+    }
+}
+
+//       a   b   c
+//     1 X | O |
+//      ---+---+---
+//     2   | X |
+//      ---+---+---
+//     3   |   | O
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "  a   b   c")?;
+        for row in 0..3 {
+            if row > 0 {
+                writeln!(f, " ---+---+---")?;
+            }
+            let marks: Vec<String> = (0..3)
+                .map(|column| match self.cells[row * 3 + column] {
+                    Some(player) => player.to_string(),
+                    None => " ".to_string(),
+                })
+                .collect();
+            // No trailing space after an empty last cell.
+            let line = format!("{} {} | {} | {}", row + 1, marks[0], marks[1], marks[2]);
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(moves: &[usize]) -> Board {
+        let mut board = Board::new();
+        for &cell in moves {
+            board.play(cell).unwrap();
+        }
+        board
+    }
+
+    #[test]
+    fn every_line_wins() {
+        for line in LINES {
+            for player in [Player::X, Player::O] {
+                // Fill the line with one player's marks directly, so the
+                // test doesn't depend on finding a legal move order.
+                let mut board = Board::new();
+                for cell in line {
+                    board.cells[cell] = Some(player);
+                }
+                assert_eq!(board.status(), Status::Won(player), "{line:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn two_in_a_line_is_not_a_win() {
+        let board = board(&[0, 3, 1]);
+        assert_eq!(board.status(), Status::InProgress);
+        assert_eq!(board.winner(), None);
+    }
+
+    #[test]
+    fn a_full_board_with_no_line_is_a_draw() {
+        // X O X
+        // X O O
+        // O X X
+        let board = board(&[0, 1, 2, 4, 3, 5, 7, 6, 8]);
+        assert_eq!(board.empty_cells().count(), 0);
+        assert_eq!(board.status(), Status::Draw);
+    }
+
+    #[test]
+    fn a_win_on_the_last_move_is_not_a_draw() {
+        // X O X
+        // O X O
+        // O X X - X completes the diagonal with the ninth mark.
+        let board = board(&[0, 1, 2, 3, 4, 6, 7, 5, 8]);
+        assert_eq!(board.status(), Status::Won(Player::X));
+    }
+
+    #[test]
+    fn turns_alternate() {
+        let mut board = Board::new();
+        assert_eq!(board.play(4), Ok(Player::X));
+        assert_eq!(board.play(0), Ok(Player::O));
+        assert_eq!(board.to_move(), Player::X);
+    }
+
+    #[test]
+    fn rejects_illegal_moves() {
+        let mut board = board(&[4]);
+        assert_eq!(board.play(4), Err(MoveError::Occupied(4)));
+        assert_eq!(board.play(9), Err(MoveError::OffBoard(9)));
+        // Still O's turn, with the board unchanged.
+        assert_eq!(board.to_move(), Player::O);
+        assert_eq!(board.empty_cells().count(), 8);
+
+        let mut won = self::board(&[0, 3, 1, 4, 2]);
+        assert_eq!(won.play(8), Err(MoveError::GameOver));
+        assert_eq!(MoveError::Occupied(4).to_string(), "b2 is already taken");
+    }
+
+    #[test]
+    fn coordinates_round_trip() {
+        for cell in 0..9 {
+            assert_eq!(
+                Board::parse_coordinates(&Board::coordinates(cell)),
+                Some(cell)
+            );
+        }
+        assert_eq!(Board::parse_coordinates("C1"), Some(2));
+        assert_eq!(Board::parse_coordinates(" 3a "), Some(6));
+        for bad in ["", "a", "d1", "a4", "a1b", "11", "bb"] {
+            assert_eq!(Board::parse_coordinates(bad), None, "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn displays_with_coordinates() {
+        let board = board(&[0, 1, 4, 8]);
+        assert_eq!(
+            board.to_string(),
+            "  a   b   c\n\
+             1 X | O |\n\
+             \x20---+---+---\n\
+             2   | X |\n\
+             \x20---+---+---\n\
+             3   |   | O\n"
+        );
+    }
+}
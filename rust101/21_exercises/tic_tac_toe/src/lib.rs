@@ -0,0 +1,142 @@
+/* Things learned during this exercise:
+   * A board of nine cells fits in `[Option<Player>; 9]`, and with X always
+   going first, whose turn it is can be worked out from the marks instead
+   of kept in sync by hand.
+   * Listing the eight winning lines as data - three rows, three columns,
+   two diagonals - turns win detection into one `find_map` over the table.
+   * Validating a move before changing anything means a rejected move
+   can't leave the board half-updated.
+   * Minimax assumes both players play their best. Negamax is the same but
+   scores from the point of view of the player to move, so one function
+   serves both sides: my score is minus my opponent's.
+   * Deriving `Hash` and `Eq` on the board lets it be a `HashMap` key, so
+   each position is scored only once however many move orders reach it.
+   That's memoization.
+   * Keeping all the rules in types with no I/O, and the game loop generic
+   over `BufRead` and `Write`, means the tests can play whole games - and
+   check every possible game against the AI.
+   * See https://en.wikipedia.org/wiki/Negamax
+*/
+mod ai;
+mod board;
+
+pub use ai::Minimax;
+pub use board::{Board, MoveError, Player, Status, LINES};
+
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    TwoPlayer,
+    // The computer plays this side.
+    VsAi(Player),
+}
+
+// Runs a game, reading the human moves from `input` and writing the board
+// and messages to `output`. Returns the final status - InProgress if the
+// input ran out first.
+pub fn play(mode: Mode, input: impl BufRead, mut output: impl Write) -> io::Result<Status> {
+    let mut board = Board::new();
+    let mut minimax = Minimax::new();
+    let mut lines = input.lines();
+    loop {
+        write!(output, "\n{board}")?;
+        match board.status() {
+            Status::Won(player) => {
+                writeln!(output, "{player} wins!")?;
+                return Ok(board.status());
+            }
+            Status::Draw => {
+                writeln!(output, "It's a draw.")?;
+                return Ok(board.status());
+            }
+            Status::InProgress => {}
+        }
+
+        let player = board.to_move();
+        if mode == Mode::VsAi(player) {
+            let cell = minimax.choose(&board).expect("the game isn't over");
+            board.play(cell).expect("the AI only picks empty cells");
+            writeln!(
+                output,
+                "The computer ({player}) plays {}.",
+                Board::coordinates(cell)
+            )?;
+            continue;
+        }
+
+        // Ask until there's a legal move.
+        loop {
+            write!(output, "{player} to move (like b2): ")?;
+            output.flush()?;
+            let Some(line) = lines.next().transpose()? else {
+                writeln!(output, "\nGame abandoned.")?;
+                return Ok(Status::InProgress);
+            };
+            let Some(cell) = Board::parse_coordinates(&line) else {
+                writeln!(
+                    output,
+                    "{:?} isn't a cell: give a column a-c and a row 1-3",
+                    line.trim()
+                )?;
+                continue;
+            };
+            match board.play(cell) {
+                Ok(_) => break,
+                Err(err) => writeln!(output, "{err}")?,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(mode: Mode, input: &str) -> (Status, String) {
+        let mut output = Vec::new();
+        let status = play(mode, input.as_bytes(), &mut output).unwrap();
+        (status, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn two_players() {
+        let (status, output) = run(Mode::TwoPlayer, "a1\na2\nb1\nb2\nc1\n");
+        assert_eq!(status, Status::Won(Player::X));
+        assert!(output
+            .ends_with("1 X | X | X\n ---+---+---\n2 O | O |\n ---+---+---\n3   |   |\nX wins!\n"));
+    }
+
+    #[test]
+    fn bad_moves_are_asked_again() {
+        let (_, output) = run(Mode::TwoPlayer, "b2\nb2\nz9\n");
+        assert!(output.contains("O to move (like b2): b2 is already taken\n"));
+        assert!(output.contains(
+            "O to move (like b2): \"z9\" isn't a cell: give a column a-c and a row 1-3\n"
+        ));
+        assert!(output.ends_with("Game abandoned.\n"));
+    }
+
+    #[test]
+    fn the_ai_can_go_first() {
+        let (status, output) = run(Mode::VsAi(Player::X), "");
+        assert_eq!(status, Status::InProgress);
+        assert!(output.contains("The computer (X) plays a1.\n"));
+    }
+
+    #[test]
+    fn the_ai_punishes_a_mistake() {
+        // After X a1, O b1 is a mistake: X can force a win.
+        let (status, output) = run(Mode::VsAi(Player::X), "b1\nc3\n");
+        assert_eq!(status, Status::Won(Player::X), "{output}");
+        assert!(output.ends_with("X wins!\n"));
+    }
+
+    #[test]
+    fn the_ai_holds_a_draw() {
+        // Sensible moves as X, and the AI as O doesn't give anything away.
+        let (status, output) = run(Mode::VsAi(Player::O), "b2\nc3\nb1\na2\na3\n");
+        assert_eq!(status, Status::Draw, "{output}");
+        assert!(output.ends_with("It's a draw.\n"));
+    }
+}
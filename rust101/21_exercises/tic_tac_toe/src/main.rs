@@ -0,0 +1,25 @@
+use std::env;
+use std::io;
+use std::process;
+
+use tic_tac_toe::{Mode, Player};
+
+// Usage: tic_tac_toe [--vs-ai [--ai-first]]. Two people take turns by
+// default; with --vs-ai the computer plays O, or X with --ai-first.
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let mode = match args.as_slice() {
+        [] => Mode::TwoPlayer,
+        ["--vs-ai"] => Mode::VsAi(Player::O),
+        ["--vs-ai", "--ai-first"] | ["--ai-first", "--vs-ai"] => Mode::VsAi(Player::X),
+        _ => {
+            eprintln!("Usage: tic_tac_toe [--vs-ai [--ai-first]]");
+            process::exit(2);
+        }
+    };
+    if let Err(err) = tic_tac_toe::play(mode, io::stdin().lock(), io::stdout()) {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
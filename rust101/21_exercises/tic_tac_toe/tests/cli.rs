@@ -0,0 +1,54 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn tic_tac_toe(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tic_tac_toe"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn two_players_to_a_draw() {
+    let output = tic_tac_toe(&[], "a1\nb2\nc3\nb1\nb3\na3\nc1\nc2\na2\n");
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.ends_with(
+            "  a   b   c\n\
+             1 X | O | X\n\
+             \x20---+---+---\n\
+             2 X | O | O\n\
+             \x20---+---+---\n\
+             3 O | X | X\n\
+             It's a draw.\n"
+        ),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn against_the_ai() {
+    let output = tic_tac_toe(&["--vs-ai", "--ai-first"], "b1\nc3\n");
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("The computer (X) plays").count(), 3);
+    assert!(stdout.ends_with("X wins!\n"));
+}
+
+#[test]
+fn usage_errors() {
+    for args in [&["--ai-first"][..], &["--vs-ai", "extra"]] {
+        assert_eq!(tic_tac_toe(args, "").status.code(), Some(2), "{args:?}");
+    }
+}
@@ -0,0 +1,89 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+// Where a stopwatch gets the time from. The real one asks the OS; tests
+// use a fake they can move on by hand, so timing something doesn't mean
+// waiting for it.
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+// A shared clock works too, so a test can keep hold of the fake and
+// advance it while the stopwatch has a reference.
+impl<C: Clock> Clock for &C {
+    fn now(&self) -> Instant {
+        (*self).now()
+    }
+}
+
+// A clock that only moves when told to: by `advance`, or by `tick` every
+// time it's read. There's no way to make an Instant from nothing, so it
+// starts at whatever the real time was when it was made.
+#[derive(Debug)]
+pub struct FakeClock {
+    now: Cell<Instant>,
+    tick: Duration,
+}
+
+impl FakeClock {
+    pub fn new() -> FakeClock {
+        FakeClock::ticking(Duration::ZERO)
+    }
+
+    // Moves on by `tick` after each reading.
+    pub fn ticking(tick: Duration) -> FakeClock {
+        FakeClock {
+            now: Cell::new(Instant::now()),
+            tick,
+        }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.now.set(self.now.get() + by);
+    }
+}
+
+impl Default for FakeClock {
+    fn default() -> Self {
+        FakeClock::new()
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> Instant {
+        let now = self.now.get();
+        self.advance(self.tick);
+        now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fake_time_only_moves_when_told() {
+        let clock = FakeClock::new();
+        let start = clock.now();
+        assert_eq!(clock.now(), start);
+        clock.advance(Duration::from_secs(3));
+        assert_eq!(clock.now() - start, Duration::from_secs(3));
+    }
+
+    #[test]
+    fn ticking_time_moves_on_every_reading() {
+        let clock = FakeClock::ticking(Duration::from_millis(10));
+        let start = clock.now();
+        assert_eq!(clock.now() - start, Duration::from_millis(10));
+        assert_eq!(clock.now() - start, Duration::from_millis(20));
+    }
+}
@@ -0,0 +1,293 @@
+use std::time::Duration;
+
+use crate::error::DurationError;
+
+// Every unit a duration can be written in, biggest first, with the names
+// it goes by and how many milliseconds it is. The first name is the one
+// `humanize` uses; the singular is that without the final 's'.
+const UNITS: [(&[&str], u64); 5] = [
+    (&["days", "day", "d"], 86_400_000),
+    (&["hours", "hour", "hrs", "hr", "h"], 3_600_000),
+    (&["minutes", "minute", "mins", "min", "m"], 60_000),
+    (&["seconds", "second", "secs", "sec", "s"], 1_000),
+    (&["milliseconds", "millisecond", "ms"], 1),
+];
+
+// A hand-written parser for
+//
+//     duration  = component { [spaces] component }
+//     component = digits [spaces] unit
+//
+// so "1h30m", "90s" and "2 days 4 hours" all work. Units are any of the
+// names in UNITS, in any case, each at most once and in any order.
+struct Parser {
+    chars: Vec<char>,
+    // Index into `chars`; the column is one more.
+    at: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.at).copied()
+    }
+
+    fn column(&self) -> usize {
+        self.at + 1
+    }
+
+    fn expected(&self, what: &'static str) -> DurationError {
+        DurationError::Expected {
+            what,
+            found: self.peek(),
+            column: self.column(),
+        }
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.at += 1;
+        }
+    }
+
+    // Consumes characters while `keep` says so and returns them.
+    fn take_while(&mut self, keep: impl Fn(char) -> bool) -> String {
+        let start = self.at;
+        while self.peek().is_some_and(&keep) {
+            self.at += 1;
+        }
+        self.chars[start..self.at].iter().collect()
+    }
+
+    fn duration(&mut self) -> Result<Duration, DurationError> {
+        let mut millis: u64 = 0;
+        let mut seen = [false; UNITS.len()];
+        while self.peek().is_some() {
+            let digits = self.take_while(|ch| ch.is_ascii_digit());
+            if digits.is_empty() {
+                return Err(self.expected("a number"));
+            }
+            // Only digits, so the one way this can fail is being too big.
+            let count: u64 = digits.parse().map_err(|_| DurationError::TooLong)?;
+
+            self.skip_spaces();
+            let column = self.column();
+            let unit = self.take_while(|ch| ch.is_alphabetic());
+            if unit.is_empty() {
+                return Err(self.expected("a unit, like h or minutes"));
+            }
+            let index = UNITS
+                .iter()
+                .position(|(names, _)| names.contains(&unit.to_lowercase().as_str()))
+                .ok_or_else(|| DurationError::UnknownUnit {
+                    unit: unit.clone(),
+                    column,
+                })?;
+            if seen[index] {
+                return Err(DurationError::RepeatedUnit { unit, column });
+            }
+            seen[index] = true;
+
+            millis = count
+                .checked_mul(UNITS[index].1)
+                .and_then(|component| millis.checked_add(component))
+                .ok_or(DurationError::TooLong)?;
+            self.skip_spaces();
+        }
+        Ok(Duration::from_millis(millis))
+    }
+}
+
+// Reads a duration like "1h30m", "90s", "2d4h" or "1 hour 30 minutes".
+// Spaces around it are fine; a bare number, with no unit, isn't.
+pub fn parse_duration(s: &str) -> Result<Duration, DurationError> {
+    if s.trim().is_empty() {
+        return Err(DurationError::Empty);
+    }
+    // Starting past the leading spaces, rather than trimming them off,
+    // keeps the columns right.
+    let leading = s.chars().take_while(|ch| ch.is_whitespace()).count();
+    let chars: Vec<char> = s.trim_end().chars().collect();
+    Parser { chars, at: leading }.duration()
+}
+
+// Writes a duration out in words, biggest unit first and leaving out the
+// ones that are zero: "1 hour 30 minutes", "2 days 1 second". Anything
+// under a millisecond is dropped, and nothing at all is "0 seconds". The
+// result parses back with `parse_duration`.
+pub fn humanize(duration: Duration) -> String {
+    // A u64 of milliseconds is over 500 million years, so this only
+    // saturates for durations no one will write.
+    let mut millis = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+    let mut parts = Vec::new();
+    for (names, size) in UNITS {
+        let count = millis / size;
+        millis %= size;
+        let plural = names[0];
+        match count {
+            0 => {}
+            1 => parts.push(format!("1 {}", &plural[..plural.len() - 1])),
+            _ => parts.push(format!("{count} {plural}")),
+        }
+    }
+    if parts.is_empty() {
+        return "0 seconds".to_string();
+    }
+    parts.join(" ")
+}
+
+// How a stopwatch shows time: minutes, seconds and hundredths, as
+// "01:23.45", with the hours in front once there are any - "1:02:03.45".
+pub fn format_clock(duration: Duration) -> String {
+    let centis = duration.as_millis() / 10;
+    let (hours, minutes) = (centis / 360_000, centis / 6_000 % 60);
+    let (seconds, centis) = (centis / 100 % 60, centis % 100);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}.{centis:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}.{centis:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: u64 = 3600;
+
+    #[test]
+    fn parses_compact_durations() {
+        assert_eq!(parse_duration("1h30m"), Ok(Duration::from_secs(5400)));
+        assert_eq!(parse_duration("90s"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("2d4h"), Ok(Duration::from_secs(52 * HOUR)));
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("30m1h"), Ok(Duration::from_secs(5400)));
+        assert_eq!(parse_duration("1H30M"), Ok(Duration::from_secs(5400)));
+    }
+
+    #[test]
+    fn parses_words_and_spaces() {
+        assert_eq!(
+            parse_duration("1 hour 30 minutes"),
+            Ok(Duration::from_secs(5400))
+        );
+        assert_eq!(
+            parse_duration("  2 days, 1 sec"),
+            Err(DurationError::Expected {
+                what: "a number",
+                found: Some(','),
+                column: 9
+            })
+        );
+        assert_eq!(
+            parse_duration(" 1 hr 5 mins "),
+            Ok(Duration::from_secs(3900))
+        );
+    }
+
+    #[test]
+    fn zero_is_a_duration() {
+        assert_eq!(parse_duration("0s"), Ok(Duration::ZERO));
+        assert_eq!(parse_duration("0h0m"), Ok(Duration::ZERO));
+        assert_eq!(humanize(Duration::ZERO), "0 seconds");
+        assert_eq!(humanize(Duration::from_micros(999)), "0 seconds");
+    }
+
+    #[test]
+    fn rejects_bad_durations() {
+        assert_eq!(parse_duration(""), Err(DurationError::Empty));
+        assert_eq!(parse_duration("   "), Err(DurationError::Empty));
+        assert_eq!(
+            parse_duration("90"),
+            Err(DurationError::Expected {
+                what: "a unit, like h or minutes",
+                found: None,
+                column: 3
+            })
+        );
+        assert_eq!(
+            parse_duration("h"),
+            Err(DurationError::Expected {
+                what: "a number",
+                found: Some('h'),
+                column: 1
+            })
+        );
+        assert_eq!(
+            parse_duration("1.5h"),
+            Err(DurationError::Expected {
+                what: "a unit, like h or minutes",
+                found: Some('.'),
+                column: 2
+            })
+        );
+        assert_eq!(
+            parse_duration("3 weeks"),
+            Err(DurationError::UnknownUnit {
+                unit: "weeks".to_string(),
+                column: 3
+            })
+        );
+        assert_eq!(
+            parse_duration("1h 2hours"),
+            Err(DurationError::RepeatedUnit {
+                unit: "hours".to_string(),
+                column: 5
+            })
+        );
+        assert_eq!(
+            parse_duration("99999999999999999999s"),
+            Err(DurationError::TooLong)
+        );
+        assert_eq!(
+            parse_duration("9999999999999999d"),
+            Err(DurationError::TooLong)
+        );
+    }
+
+    #[test]
+    fn humanizes() {
+        assert_eq!(humanize(Duration::from_secs(5400)), "1 hour 30 minutes");
+        assert_eq!(humanize(Duration::from_secs(1)), "1 second");
+        assert_eq!(
+            humanize(Duration::from_millis(2 * 86_400_000 + 1_001)),
+            "2 days 1 second 1 millisecond"
+        );
+        assert_eq!(humanize(Duration::from_secs(HOUR)), "1 hour");
+    }
+
+    #[test]
+    fn carries_overflowing_components() {
+        assert_eq!(
+            humanize(parse_duration("90m").unwrap()),
+            "1 hour 30 minutes"
+        );
+        assert_eq!(humanize(parse_duration("25h").unwrap()), "1 day 1 hour");
+        assert_eq!(
+            humanize(parse_duration("61s 1000ms").unwrap()),
+            "1 minute 2 seconds"
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        for text in ["1h30m", "90s", "2d4h", "1d23h59m59s999ms", "0s", "7ms"] {
+            let duration = parse_duration(text).unwrap();
+            assert_eq!(parse_duration(&humanize(duration)), Ok(duration), "{text}");
+        }
+        for millis in (0..200_000_000).step_by(999_983) {
+            let duration = Duration::from_millis(millis);
+            assert_eq!(parse_duration(&humanize(duration)), Ok(duration));
+        }
+    }
+
+    #[test]
+    fn formats_like_a_stopwatch() {
+        assert_eq!(format_clock(Duration::ZERO), "00:00.00");
+        assert_eq!(format_clock(Duration::from_millis(83_456)), "01:23.45");
+        assert_eq!(format_clock(Duration::from_millis(3_723_450)), "1:02:03.45");
+        assert_eq!(
+            format_clock(Duration::from_secs(100 * HOUR)),
+            "100:00:00.00"
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use std::error::Error;
+use std::fmt;
+
+// Columns count characters from 1, as an editor would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DurationError {
+    Empty,
+    // `what` describes what should have been there; `found` is None at the
+    // end of the input.
+    Expected {
+        what: &'static str,
+        found: Option<char>,
+        column: usize,
+    },
+    UnknownUnit {
+        unit: String,
+        column: usize,
+    },
+    // "1h2h": each unit can only be given once.
+    RepeatedUnit {
+        unit: String,
+        column: usize,
+    },
+    // More than a `Duration` can hold.
+    TooLong,
+}
+
+impl fmt::Display for DurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DurationError::Empty => write!(f, "empty duration"),
+            DurationError::Expected {
+                what,
+                found: Some(ch),
+                column,
+            } => write!(f, "expected {what} at column {column}, found {ch:?}"),
+            DurationError::Expected {
+                what,
+                found: None,
+                column,
+            } => write!(f, "expected {what} at column {column}, found the end"),
+            DurationError::UnknownUnit { unit, column } => {
+                write!(f, "unknown unit {unit:?} at column {column}")
+            }
+            DurationError::RepeatedUnit { unit, column } => {
+                write!(f, "{unit:?} at column {column} repeats a unit")
+            }
+            DurationError::TooLong => write!(f, "duration is too long"),
+        }
+    }
+}
+
+impl Error for DurationError {}
@@ -0,0 +1,236 @@
+/* Things learned during this exercise:
+   * `Instant` is for measuring: it only ever goes forwards, and subtracting
+   one from a later one gives a `Duration`. It can't be turned into a date
+   or made from a number - `SystemTime` is the one for wall-clock time.
+   * Code that reads the clock is hard to test without waiting. Hiding
+   `Instant::now()` behind a trait means tests can hand in a fake clock
+   and move it on by hand, and a default type parameter -
+   `Stopwatch<C: Clock = SystemClock>` - keeps the real one the easy one to
+   use.
+   * The fake changes the time through `&self`, since the stopwatch only
+   reads it, so it keeps it in a `Cell`. Implementing the trait for `&C`
+   lets the test keep the clock and lend the stopwatch a reference.
+   * `Duration` has no parser or pretty-printer of its own. Doing the sums in
+   whole milliseconds with checked arithmetic keeps "90m" exact and turns
+   silly input into an error, not an overflow.
+   * Formatting and parsing the same words means `humanize` output can be
+   fed straight back in, which makes round trips an easy test.
+   * See https://doc.rust-lang.org/std/time/struct.Instant.html and
+   https://doc.rust-lang.org/std/time/struct.Duration.html
+*/
+mod clock;
+mod duration;
+mod error;
+mod stopwatch;
+
+pub use clock::{Clock, FakeClock, SystemClock};
+pub use duration::{format_clock, humanize, parse_duration};
+pub use error::DurationError;
+pub use stopwatch::Stopwatch;
+
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Stopwatch,
+    // Prints the duration in seconds.
+    Parse(String),
+    // Prints the duration in words. A bare number is a count of seconds.
+    Humanize(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub command: Command,
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let name = args
+            .next()
+            .ok_or("which command? stopwatch, parse or humanize")?;
+        // The duration can be one argument or several: "1h 30m" and
+        // 1h 30m are the same.
+        let rest: Vec<String> = args.collect();
+        let duration = || {
+            if rest.is_empty() {
+                Err(format!("{name} needs a duration, like 1h30m"))
+            } else {
+                Ok(rest.join(" "))
+            }
+        };
+        let command = match name.as_str() {
+            "stopwatch" if rest.is_empty() => Command::Stopwatch,
+            "stopwatch" => return Err("stopwatch doesn't take any arguments".to_string()),
+            "parse" => Command::Parse(duration()?),
+            "humanize" => Command::Humanize(duration()?),
+            _ => return Err(format!("unknown command: {name:?}")),
+        };
+        Ok(Config { command })
+    }
+}
+
+// Whole seconds, with the milliseconds after a point if there are any:
+// "5400", "1.5".
+fn seconds(duration: Duration) -> String {
+    let millis = duration.subsec_millis();
+    if millis == 0 {
+        duration.as_secs().to_string()
+    } else {
+        let fraction = format!("{millis:03}");
+        format!("{}.{}", duration.as_secs(), fraction.trim_end_matches('0'))
+    }
+}
+
+// Runs `parse` or `humanize`, returning what to print. The stopwatch is
+// interactive, so it has `run_stopwatch` instead.
+pub fn run(command: &Command) -> Result<String, DurationError> {
+    match command {
+        Command::Stopwatch => panic!("the stopwatch runs with run_stopwatch"),
+        Command::Parse(text) => Ok(seconds(parse_duration(text)?)),
+        Command::Humanize(text) => {
+            let duration = match text.trim().parse() {
+                Ok(secs) => Duration::from_secs(secs),
+                Err(_) => parse_duration(text)?,
+            };
+            Ok(humanize(duration))
+        }
+    }
+}
+
+// Starts the stopwatch and takes a lap each time Enter is pressed, until
+// "q" or the end of the input.
+pub fn run_stopwatch<C: Clock>(
+    stopwatch: &mut Stopwatch<C>,
+    input: impl BufRead,
+    mut output: impl Write,
+) -> io::Result<()> {
+    writeln!(
+        output,
+        "Started. Press Enter for a lap, or type q and Enter to stop."
+    )?;
+    stopwatch.start();
+    let mut lines = input.lines();
+    while let Some(line) = lines.next().transpose()? {
+        match line.trim() {
+            "" => {
+                let lap = stopwatch.lap();
+                // The total as the lap ended - reading the clock again
+                // would be a little later.
+                let total: Duration = stopwatch.laps().iter().sum();
+                writeln!(
+                    output,
+                    "Lap {}: {} (total {})",
+                    stopwatch.laps().len(),
+                    format_clock(lap),
+                    format_clock(total)
+                )?;
+            }
+            "q" | "quit" => break,
+            _ => writeln!(output, "Press Enter for a lap, or type q to stop.")?,
+        }
+    }
+    stopwatch.stop();
+    writeln!(output, "Stopped at {}", format_clock(stopwatch.elapsed()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["timing".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    fn command(args_: &[&str]) -> Result<Command, String> {
+        Config::build(args(args_)).map(|config| config.command)
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(command(&["stopwatch"]), Ok(Command::Stopwatch));
+        assert_eq!(
+            command(&["parse", "1h30m"]),
+            Ok(Command::Parse("1h30m".to_string()))
+        );
+        assert_eq!(
+            command(&["humanize", "1", "hour", "90m"]),
+            Ok(Command::Humanize("1 hour 90m".to_string()))
+        );
+        assert!(command(&[]).is_err());
+        assert!(command(&["parse"]).is_err());
+        assert!(command(&["stopwatch", "now"]).is_err());
+        assert!(command(&["wait", "5s"]).is_err());
+    }
+
+    #[test]
+    fn runs_parse_and_humanize() {
+        let run_ = |command| run(&command);
+        assert_eq!(
+            run_(Command::Parse("1h30m".to_string())),
+            Ok("5400".to_string())
+        );
+        assert_eq!(
+            run_(Command::Parse("1s500ms".to_string())),
+            Ok("1.5".to_string())
+        );
+        assert_eq!(
+            run_(Command::Parse("25ms".to_string())),
+            Ok("0.025".to_string())
+        );
+        assert_eq!(
+            run_(Command::Humanize("90m".to_string())),
+            Ok("1 hour 30 minutes".to_string())
+        );
+        assert_eq!(
+            run_(Command::Humanize("3661".to_string())),
+            Ok("1 hour 1 minute 1 second".to_string())
+        );
+        assert_eq!(
+            run_(Command::Parse("forever".to_string())),
+            Err(DurationError::Expected {
+                what: "a number",
+                found: Some('f'),
+                column: 1
+            })
+        );
+    }
+
+    #[test]
+    fn laps_on_enter() {
+        // Every reading of the clock is a second after the last: one for
+        // starting, then one per lap and a last one for stopping.
+        let clock = FakeClock::ticking(Duration::from_secs(1));
+        let mut stopwatch = Stopwatch::with_clock(&clock);
+        let mut output = Vec::new();
+        run_stopwatch(&mut stopwatch, "\n\nwhat?\n\nq\n\n".as_bytes(), &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Started. Press Enter for a lap, or type q and Enter to stop.\n\
+             Lap 1: 00:01.00 (total 00:01.00)\n\
+             Lap 2: 00:01.00 (total 00:02.00)\n\
+             Press Enter for a lap, or type q to stop.\n\
+             Lap 3: 00:01.00 (total 00:03.00)\n\
+             Stopped at 00:04.00\n"
+        );
+        assert!(!stopwatch.is_running());
+        assert_eq!(stopwatch.laps().len(), 3);
+    }
+
+    #[test]
+    fn the_end_of_the_input_stops_it() {
+        let clock = FakeClock::ticking(Duration::from_millis(250));
+        let mut stopwatch = Stopwatch::with_clock(&clock);
+        let mut output = Vec::new();
+        run_stopwatch(&mut stopwatch, "\n".as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.ends_with("Lap 1: 00:00.25 (total 00:00.25)\nStopped at 00:00.50\n"));
+    }
+}
@@ -0,0 +1,27 @@
+use std::env;
+use std::io;
+use std::process;
+
+use timing::{Command, Config, Stopwatch};
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: timing stopwatch | parse DURATION | humanize DURATION");
+        process::exit(2);
+    });
+    let result = match &config.command {
+        Command::Stopwatch => {
+            let mut stopwatch = Stopwatch::new();
+            timing::run_stopwatch(&mut stopwatch, io::stdin().lock(), io::stdout())
+                .map_err(|err| err.to_string())
+        }
+        command => timing::run(command)
+            .map(|answer| println!("{answer}"))
+            .map_err(|err| err.to_string()),
+    };
+    if let Err(err) = result {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,189 @@
+use std::time::{Duration, Instant};
+
+use crate::clock::{Clock, SystemClock};
+
+// Times how long it's been running, across any number of stops and starts,
+// and splits that time into laps.
+//
+// Time while it's stopped doesn't count, towards the total or the current
+// lap.
+#[derive(Debug)]
+pub struct Stopwatch<C: Clock = SystemClock> {
+    clock: C,
+    // When the current run started; None while stopped.
+    running_since: Option<Instant>,
+    // The time from runs that have already been stopped.
+    banked: Duration,
+    // The total when the last lap ended.
+    last_lap_at: Duration,
+    laps: Vec<Duration>,
+}
+
+impl Stopwatch {
+    pub fn new() -> Stopwatch {
+        Stopwatch::with_clock(SystemClock)
+    }
+}
+
+impl Default for Stopwatch {
+    fn default() -> Self {
+        Stopwatch::new()
+    }
+}
+
+impl<C: Clock> Stopwatch<C> {
+    // A stopped stopwatch reading zero.
+    pub fn with_clock(clock: C) -> Stopwatch<C> {
+        Stopwatch {
+            clock,
+            running_since: None,
+            banked: Duration::ZERO,
+            last_lap_at: Duration::ZERO,
+            laps: Vec::new(),
+        }
+    }
+
+    // Starting one that's already running changes nothing.
+    pub fn start(&mut self) {
+        if self.running_since.is_none() {
+            self.running_since = Some(self.clock.now());
+        }
+    }
+
+    pub fn stop(&mut self) {
+        if let Some(since) = self.running_since.take() {
+            self.banked += self.clock.now() - since;
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    // The total time it's been running.
+    pub fn elapsed(&self) -> Duration {
+        match self.running_since {
+            Some(since) => self.banked + (self.clock.now() - since),
+            None => self.banked,
+        }
+    }
+
+    // Ends the current lap and starts the next, returning how long the one
+    // that ended took.
+    pub fn lap(&mut self) -> Duration {
+        let now = self.elapsed();
+        let lap = now - self.last_lap_at;
+        self.last_lap_at = now;
+        self.laps.push(lap);
+        lap
+    }
+
+    pub fn laps(&self) -> &[Duration] {
+        &self.laps
+    }
+
+    // Back to zero, stopped, with no laps.
+    pub fn reset(&mut self) {
+        self.running_since = None;
+        self.banked = Duration::ZERO;
+        self.last_lap_at = Duration::ZERO;
+        self.laps.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FakeClock;
+
+    fn secs(secs: u64) -> Duration {
+        Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn starts_stopped_at_zero() {
+        let clock = FakeClock::new();
+        let stopwatch = Stopwatch::with_clock(&clock);
+        clock.advance(secs(5));
+        assert!(!stopwatch.is_running());
+        assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+        assert!(stopwatch.laps().is_empty());
+    }
+
+    #[test]
+    fn laps_add_up_to_the_total() {
+        let clock = FakeClock::new();
+        let mut stopwatch = Stopwatch::with_clock(&clock);
+        stopwatch.start();
+        clock.advance(secs(2));
+        assert_eq!(stopwatch.lap(), secs(2));
+        clock.advance(secs(3));
+        assert_eq!(stopwatch.lap(), secs(3));
+        clock.advance(Duration::from_millis(500));
+        assert_eq!(stopwatch.lap(), Duration::from_millis(500));
+        assert_eq!(
+            stopwatch.laps(),
+            [secs(2), secs(3), Duration::from_millis(500)]
+        );
+        assert_eq!(stopwatch.elapsed(), Duration::from_millis(5500));
+        assert_eq!(
+            stopwatch.laps().iter().sum::<Duration>(),
+            stopwatch.elapsed()
+        );
+    }
+
+    #[test]
+    fn stopped_time_does_not_count() {
+        let clock = FakeClock::new();
+        let mut stopwatch = Stopwatch::with_clock(&clock);
+        stopwatch.start();
+        clock.advance(secs(4));
+        stopwatch.stop();
+        clock.advance(secs(60));
+        assert_eq!(stopwatch.elapsed(), secs(4));
+
+        stopwatch.start();
+        clock.advance(secs(1));
+        assert!(stopwatch.is_running());
+        assert_eq!(stopwatch.elapsed(), secs(5));
+        // The lap spans the stop, but not the minute spent stopped.
+        assert_eq!(stopwatch.lap(), secs(5));
+    }
+
+    #[test]
+    fn starting_and_stopping_twice_changes_nothing() {
+        let clock = FakeClock::new();
+        let mut stopwatch = Stopwatch::with_clock(&clock);
+        stopwatch.start();
+        clock.advance(secs(1));
+        stopwatch.start();
+        clock.advance(secs(1));
+        stopwatch.stop();
+        stopwatch.stop();
+        assert_eq!(stopwatch.elapsed(), secs(2));
+    }
+
+    #[test]
+    fn resets() {
+        let clock = FakeClock::new();
+        let mut stopwatch = Stopwatch::with_clock(&clock);
+        stopwatch.start();
+        clock.advance(secs(3));
+        stopwatch.lap();
+        stopwatch.reset();
+        assert!(!stopwatch.is_running());
+        assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+        assert!(stopwatch.laps().is_empty());
+        stopwatch.start();
+        clock.advance(secs(1));
+        assert_eq!(stopwatch.lap(), secs(1));
+    }
+
+    #[test]
+    fn the_real_clock_moves_forwards() {
+        let mut stopwatch = Stopwatch::new();
+        stopwatch.start();
+        let first = stopwatch.elapsed();
+        assert!(stopwatch.elapsed() >= first);
+    }
+}
@@ -0,0 +1,58 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn timing(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_timing"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn parses_and_humanizes() {
+    let output = timing(&["parse", "1h30m"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "5400\n");
+    assert_eq!(stdout(&timing(&["parse", "2", "days"])), "172800\n");
+    assert_eq!(stdout(&timing(&["humanize", "90m"])), "1 hour 30 minutes\n");
+    assert_eq!(stdout(&timing(&["humanize", "0"])), "0 seconds\n");
+}
+
+#[test]
+fn bad_durations_are_explained() {
+    let output = timing(&["parse", "3 fortnights"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: unknown unit \"fortnights\" at column 3\n"
+    );
+}
+
+#[test]
+fn unknown_commands_are_usage_errors() {
+    let output = timing(&["sleep", "5s"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: timing"));
+}
+
+#[test]
+fn the_stopwatch_laps_until_q() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_timing"))
+        .arg("stopwatch")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(b"\n\nq\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let lines: Vec<&str> = stdout(&output).lines().collect();
+    assert_eq!(lines.len(), 4, "{lines:?}");
+    assert!(lines[1].starts_with("Lap 1: "));
+    assert!(lines[2].starts_with("Lap 2: "));
+    assert!(lines[3].starts_with("Stopped at "));
+}
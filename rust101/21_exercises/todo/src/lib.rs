@@ -0,0 +1,257 @@
+/* Things learned during this exercise:
+   * `#[derive(Serialize, Deserialize)]` on the item struct is all serde
+   needs to read and write the whole list as JSON. A field missing from the
+   file is a deserialize error, which is how a hand-edited file that lost
+   its `done` gets caught.
+   * `io::ErrorKind::NotFound` tells "no file yet" - fine, start empty -
+   apart from errors worth reporting, like a permission problem.
+   * Writing a file in place can leave it half-written if the program dies
+   part way. Writing a temporary file and `fs::rename`-ing it over the old
+   one swaps the whole thing at once.
+   * There's no data directory in std. On Linux the convention is
+   `$XDG_DATA_HOME`, falling back to `~/.local/share`; crates like `dirs`
+   know the rules for every platform. Passing the environment lookup in as
+   a closure lets the tests try it without changing the real environment.
+   * IDs people type need to stay put. A program that runs once per
+   command forgets everything in between, so what it mustn't reuse has to
+   be in the file: here a `next_id` that only goes up, so an old ID can
+   never quietly point at a new item.
+   * `#[serde(default)]` on a new field lets files written before it
+   existed still load, with the field's default in their place.
+   * See https://serde.rs/derive.html and
+   https://specifications.freedesktop.org/basedir-spec/latest/
+*/
+mod store;
+
+pub use store::{Filter, Item, StoreError, TodoStore};
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Add(String),
+    List(Filter),
+    Done(u32),
+    Remove(u32),
+    Edit(u32, String),
+}
+
+impl Command {
+    // Whether running it changes the list, and so needs saving.
+    pub fn changes_the_list(&self) -> bool {
+        !matches!(self, Command::List(_))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    // From `--file`. None means the default file in the data directory.
+    pub file: Option<PathBuf>,
+    pub command: Command,
+}
+
+fn parse_id(arg: Option<String>) -> Result<u32, String> {
+    let arg = arg.ok_or("which item? Give its ID")?;
+    arg.parse()
+        .ok()
+        .filter(|&id| id > 0)
+        .ok_or(format!("not an item ID: {arg:?}"))
+}
+
+fn rest_as_text(args: impl Iterator<Item = String>) -> Result<String, String> {
+    let text = args.collect::<Vec<_>>().join(" ");
+    if text.trim().is_empty() {
+        return Err("the item needs some text".to_string());
+    }
+    Ok(text)
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(args: impl Iterator<Item = String>) -> Result<Config, String> {
+        let mut args = args.skip(1).peekable();
+
+        let mut file = None;
+        while let Some(option) = args.next_if(|arg| arg.starts_with("--")) {
+            match option.as_str() {
+                "--file" => file = Some(PathBuf::from(args.next().ok_or("--file needs a path")?)),
+                _ => return Err(format!("unknown option: {option:?}")),
+            }
+        }
+
+        let command = match args.next().as_deref() {
+            Some("add") => Command::Add(rest_as_text(args.by_ref())?),
+            Some("list") => {
+                let filter = match args.next().as_deref() {
+                    None => Filter::Pending,
+                    Some("--all") => Filter::All,
+                    Some("--done") => Filter::Done,
+                    Some(other) => {
+                        return Err(format!("list takes --all or --done, not {other:?}"))
+                    }
+                };
+                Command::List(filter)
+            }
+            Some("done") => Command::Done(parse_id(args.next())?),
+            Some("rm") => Command::Remove(parse_id(args.next())?),
+            Some("edit") => {
+                let id = parse_id(args.next())?;
+                Command::Edit(id, rest_as_text(args.by_ref())?)
+            }
+            Some(other) => return Err(format!("unknown command: {other:?}")),
+            None => return Err("no command given".to_string()),
+        };
+        if let Some(extra) = args.next() {
+            return Err(format!("unexpected argument: {extra:?}"));
+        }
+        Ok(Config { file, command })
+    }
+}
+
+// Where the list lives without `--file`: `todo/todos.json` in the data
+// directory. `var` looks up an environment variable - `env::var(..).ok()`
+// outside the tests.
+pub fn default_path(var: impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    // The XDG spec says relative paths are to be ignored.
+    let data_dir = var("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| var("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+        // Windows.
+        .or_else(|| var("APPDATA").map(PathBuf::from))?;
+    Some(data_dir.join("todo").join("todos.json"))
+}
+
+fn describe(verb: &str, item: &Item) -> String {
+    format!("{verb} {}: {}\n", item.id, item.text)
+}
+
+// Runs a command against the store and returns what to print. Saving is
+// left to the caller.
+pub fn run(command: &Command, store: &mut TodoStore) -> Result<String, StoreError> {
+    Ok(match command {
+        Command::Add(text) => describe("Added", store.add(text)?),
+        Command::Done(id) => describe("Done", store.complete(*id)?),
+        Command::Remove(id) => describe("Removed", &store.remove(*id)?),
+        Command::Edit(id, text) => describe("Edited", store.edit(*id, text)?),
+        Command::List(filter) => {
+            let mut output = String::new();
+            for item in store.items(*filter) {
+                let check = if item.done { 'x' } else { ' ' };
+                writeln!(output, "{:>3} [{check}] {}", item.id, item.text).unwrap();
+            }
+            if output.is_empty() {
+                output = match filter {
+                    Filter::Pending => "Nothing to do.\n",
+                    Filter::Done => "Nothing done yet.\n",
+                    Filter::All => "The list is empty.\n",
+                }
+                .to_string();
+            }
+            output
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::path::Path;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["todo".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    fn command(list: &[&str]) -> Result<Command, String> {
+        Config::build(args(list)).map(|config| config.command)
+    }
+
+    #[test]
+    fn builds_commands() {
+        assert_eq!(
+            command(&["add", "buy", "milk"]),
+            Ok(Command::Add("buy milk".to_string()))
+        );
+        assert_eq!(command(&["list"]), Ok(Command::List(Filter::Pending)));
+        assert_eq!(command(&["list", "--all"]), Ok(Command::List(Filter::All)));
+        assert_eq!(
+            command(&["list", "--done"]),
+            Ok(Command::List(Filter::Done))
+        );
+        assert_eq!(command(&["done", "3"]), Ok(Command::Done(3)));
+        assert_eq!(command(&["rm", "12"]), Ok(Command::Remove(12)));
+        assert_eq!(
+            command(&["edit", "1", "buy", "bread"]),
+            Ok(Command::Edit(1, "buy bread".to_string()))
+        );
+    }
+
+    #[test]
+    fn file_option() {
+        assert_eq!(
+            Config::build(args(&["--file", "my.json", "list"])),
+            Ok(Config {
+                file: Some(PathBuf::from("my.json")),
+                command: Command::List(Filter::Pending),
+            })
+        );
+        assert_eq!(Config::build(args(&["list"])).unwrap().file, None);
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        for bad in [
+            &[][..],
+            &["--file"],
+            &["--verbose", "list"],
+            &["todo"],
+            &["add"],
+            &["add", " "],
+            &["list", "--pending"],
+            &["list", "--all", "--done"],
+            &["done"],
+            &["done", "0"],
+            &["done", "first"],
+            &["rm", "1", "2"],
+            &["edit", "1"],
+        ] {
+            assert!(Config::build(args(bad)).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn finds_the_data_directory() {
+        let lookup = |vars: &[(&str, &str)]| {
+            let vars: HashMap<String, String> = vars
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect();
+            default_path(move |name| vars.get(name).cloned())
+        };
+        assert_eq!(
+            lookup(&[("XDG_DATA_HOME", "/data"), ("HOME", "/home/me")]),
+            Some(PathBuf::from("/data/todo/todos.json"))
+        );
+        assert_eq!(
+            lookup(&[("XDG_DATA_HOME", "relative"), ("HOME", "/home/me")]),
+            Some(Path::new("/home/me/.local/share/todo/todos.json").to_path_buf())
+        );
+        assert_eq!(
+            lookup(&[("APPDATA", "/appdata")]),
+            Some(PathBuf::from("/appdata/todo/todos.json"))
+        );
+        assert_eq!(lookup(&[]), None);
+    }
+
+    #[test]
+    fn only_list_leaves_the_list_alone() {
+        assert!(!Command::List(Filter::All).changes_the_list());
+        assert!(Command::Done(1).changes_the_list());
+        assert!(Command::Add("x".to_string()).changes_the_list());
+    }
+}
@@ -0,0 +1,36 @@
+use std::env;
+use std::process;
+
+use todo::{Config, TodoStore};
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: todo [--file PATH] add TEXT... | list [--all | --done] | done ID | rm ID | edit ID TEXT...");
+        process::exit(2);
+    });
+
+    let path = config
+        .file
+        .clone()
+        .or_else(|| todo::default_path(|name| env::var(name).ok()))
+        .unwrap_or_else(|| {
+            eprintln!("couldn't find a data directory for the list; use --file PATH");
+            process::exit(1);
+        });
+
+    let result = TodoStore::load(&path).and_then(|mut store| {
+        let output = todo::run(&config.command, &mut store)?;
+        if config.command.changes_the_list() {
+            store.save()?;
+        }
+        Ok(output)
+    });
+    match result {
+        Ok(output) => print!("{output}"),
+        Err(err) => {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+    }
+}
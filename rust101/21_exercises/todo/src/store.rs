@@ -0,0 +1,404 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Item {
+    pub id: u32,
+    pub text: String,
+    pub done: bool,
+}
+
+// The on-disk format:
+// `{"next_id": 2, "items": [{"id": 1, "text": "...", "done": false}]}`.
+//
+// Files written before `next_id` was stored don't have it. They load with
+// 0, and `load` works out the real value from the items.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TodoFile {
+    #[serde(default)]
+    next_id: u32,
+    items: Vec<Item>,
+}
+
+#[derive(Debug)]
+pub enum StoreError {
+    Io { path: PathBuf, err: io::Error },
+    // The file is there but isn't a todo list. It's left alone rather than
+    // overwritten, in case it's worth rescuing by hand.
+    Corrupt { path: PathBuf, reason: String },
+    NoSuchItem(u32),
+    EmptyText,
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StoreError::Io { path, err } => write!(f, "{}: {err}", path.display()),
+            StoreError::Corrupt { path, reason } => {
+                write!(f, "{} isn't a valid todo file: {reason}", path.display())
+            }
+            StoreError::NoSuchItem(id) => write!(f, "there's no item {id}"),
+            StoreError::EmptyText => write!(f, "an item needs some text"),
+        }
+    }
+}
+
+impl Error for StoreError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Filter {
+    #[default]
+    Pending,
+    Done,
+    All,
+}
+
+impl Filter {
+    fn keeps(self, item: &Item) -> bool {
+        match self {
+            Filter::Pending => !item.done,
+            Filter::Done => item.done,
+            Filter::All => true,
+        }
+    }
+}
+
+fn checked_text(text: &str) -> Result<String, StoreError> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(StoreError::EmptyText);
+    }
+    Ok(text.to_string())
+}
+
+// A todo list backed by a JSON file. Changes are made in memory and only
+// written by `save`.
+//
+// IDs are never reused: after `rm 3`, a later `done 3` should fail, not
+// tick off whatever was added since. Each run of the program is a session
+// of its own, so the only way to remember that 3 was ever used is in the
+// file - which keeps the next ID to hand out, one past the highest ever
+// given.
+#[derive(Debug)]
+pub struct TodoStore {
+    path: PathBuf,
+    items: Vec<Item>,
+    next_id: u32,
+}
+
+impl TodoStore {
+    // A file that doesn't exist yet is an empty list.
+    pub fn load(path: &Path) -> Result<TodoStore, StoreError> {
+        let mut file = match fs::read_to_string(path) {
+            Ok(text) => {
+                let corrupt = |reason: String| StoreError::Corrupt {
+                    path: path.to_path_buf(),
+                    reason,
+                };
+                let file: TodoFile =
+                    serde_json::from_str(&text).map_err(|err| corrupt(err.to_string()))?;
+                let mut ids = BTreeSet::new();
+                for item in &file.items {
+                    if item.id == 0 || !ids.insert(item.id) {
+                        return Err(corrupt(format!(
+                            "item {} is there twice, or isn't a valid ID",
+                            item.id
+                        )));
+                    }
+                }
+                file
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => TodoFile::default(),
+            Err(err) => {
+                return Err(StoreError::Io {
+                    path: path.to_path_buf(),
+                    err,
+                })
+            }
+        };
+        // Past every item in the file, whatever it says - an old file has
+        // no `next_id`, and a hand-edited one might have it too low.
+        file.items.sort_by_key(|item| item.id);
+        let highest = file.items.last().map_or(0, |item| item.id);
+        let after_highest = highest.checked_add(1).ok_or_else(|| StoreError::Corrupt {
+            path: path.to_path_buf(),
+            reason: format!("item {highest} leaves no ID for the next one"),
+        })?;
+        Ok(TodoStore {
+            path: path.to_path_buf(),
+            next_id: file.next_id.max(after_highest),
+            items: file.items,
+        })
+    }
+
+    // Writes to a temporary file next to the real one, then renames it
+    // over the top. A rename within a directory replaces the file in one
+    // go, so a crash halfway can't leave half a list behind.
+    pub fn save(&self) -> Result<(), StoreError> {
+        let io_error = |err| StoreError::Io {
+            path: self.path.clone(),
+            err,
+        };
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let file = TodoFile {
+            next_id: self.next_id,
+            items: self.items.clone(),
+        };
+        let text = serde_json::to_string_pretty(&file).expect("a list of items always serializes");
+        let temporary = self.path.with_extension("json.tmp");
+        fs::write(&temporary, text + "\n").map_err(io_error)?;
+        fs::rename(&temporary, &self.path).map_err(io_error)
+    }
+
+    pub fn items(&self, filter: Filter) -> impl Iterator<Item = &Item> {
+        self.items.iter().filter(move |item| filter.keeps(item))
+    }
+
+    pub fn add(&mut self, text: &str) -> Result<&Item, StoreError> {
+        let item = Item {
+            id: self.next_id,
+            text: checked_text(text)?,
+            done: false,
+        };
+        // Nothing is added when the ID after this one wouldn't fit, so the
+        // store is left as it was.
+        self.next_id = self
+            .next_id
+            .checked_add(1)
+            .ok_or_else(|| StoreError::Corrupt {
+                path: self.path.clone(),
+                reason: format!("every ID up to {} has been used", u32::MAX),
+            })?;
+        // The new ID is the highest, so it goes on the end and the list
+        // stays in ID order.
+        self.items.push(item);
+        Ok(self.items.last().unwrap())
+    }
+
+    fn get_mut(&mut self, id: u32) -> Result<&mut Item, StoreError> {
+        self.items
+            .iter_mut()
+            .find(|item| item.id == id)
+            .ok_or(StoreError::NoSuchItem(id))
+    }
+
+    pub fn complete(&mut self, id: u32) -> Result<&Item, StoreError> {
+        let item = self.get_mut(id)?;
+        item.done = true;
+        Ok(item)
+    }
+
+    pub fn edit(&mut self, id: u32, text: &str) -> Result<&Item, StoreError> {
+        let text = checked_text(text)?;
+        let item = self.get_mut(id)?;
+        item.text = text;
+        Ok(item)
+    }
+
+    pub fn remove(&mut self, id: u32) -> Result<Item, StoreError> {
+        let at = self
+            .items
+            .iter()
+            .position(|item| item.id == id)
+            .ok_or(StoreError::NoSuchItem(id))?;
+        Ok(self.items.remove(at))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A fresh directory under the system temp dir, removed again when the
+    // test is over.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let dir = std::env::temp_dir().join(format!("todo_{name}_{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            TempDir(dir)
+        }
+
+        fn file(&self) -> PathBuf {
+            self.0.join("todos.json")
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn texts(store: &TodoStore, filter: Filter) -> Vec<&str> {
+        store.items(filter).map(|item| item.text.as_str()).collect()
+    }
+
+    #[test]
+    fn a_missing_file_is_an_empty_list() {
+        let dir = TempDir::new("missing");
+        let store = TodoStore::load(&dir.file()).unwrap();
+        assert_eq!(store.items(Filter::All).count(), 0);
+    }
+
+    #[test]
+    fn round_trips_through_the_file() {
+        let dir = TempDir::new("round_trip");
+        let mut store = TodoStore::load(&dir.file()).unwrap();
+        store.add("buy milk").unwrap();
+        store.add("  write tests  ").unwrap();
+        store.complete(1).unwrap();
+        store.save().unwrap();
+
+        let store = TodoStore::load(&dir.file()).unwrap();
+        assert_eq!(
+            store.items(Filter::All).cloned().collect::<Vec<_>>(),
+            [
+                Item {
+                    id: 1,
+                    text: "buy milk".to_string(),
+                    done: true,
+                },
+                Item {
+                    id: 2,
+                    text: "write tests".to_string(),
+                    done: false,
+                },
+            ]
+        );
+        assert!(!dir.file().with_extension("json.tmp").exists());
+    }
+
+    #[test]
+    fn saving_creates_the_directory() {
+        let dir = TempDir::new("nested");
+        let path = dir.0.join("a/b/todos.json");
+        let mut store = TodoStore::load(&path).unwrap();
+        store.add("x").unwrap();
+        store.save().unwrap();
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn filters() {
+        let dir = TempDir::new("filters");
+        let mut store = TodoStore::load(&dir.file()).unwrap();
+        for text in ["a", "b", "c"] {
+            store.add(text).unwrap();
+        }
+        store.complete(2).unwrap();
+        assert_eq!(texts(&store, Filter::Pending), ["a", "c"]);
+        assert_eq!(texts(&store, Filter::Done), ["b"]);
+        assert_eq!(texts(&store, Filter::All), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn removed_ids_are_never_reused() {
+        let dir = TempDir::new("reuse");
+        let mut store = TodoStore::load(&dir.file()).unwrap();
+        for text in ["a", "b", "c"] {
+            store.add(text).unwrap();
+        }
+        assert_eq!(store.remove(2).unwrap().text, "b");
+        assert_eq!(store.add("d").unwrap().id, 4);
+        assert!(matches!(store.complete(2), Err(StoreError::NoSuchItem(2))));
+        // Removing the highest doesn't give it back either, once saved.
+        store.remove(4).unwrap();
+        store.save().unwrap();
+
+        let mut store = TodoStore::load(&dir.file()).unwrap();
+        assert_eq!(store.add("e").unwrap().id, 5);
+        assert_eq!(texts(&store, Filter::All), ["a", "c", "e"]);
+    }
+
+    #[test]
+    fn files_without_a_next_id_still_load() {
+        let dir = TempDir::new("old_format");
+        fs::write(
+            dir.file(),
+            r#"{"items": [{"id": 1, "text": "a", "done": false}, {"id": 3, "text": "c", "done": true}]}"#,
+        )
+        .unwrap();
+        let mut store = TodoStore::load(&dir.file()).unwrap();
+        assert_eq!(texts(&store, Filter::All), ["a", "c"]);
+        assert_eq!(store.add("d").unwrap().id, 4);
+
+        // Nor is a `next_id` that's behind the items trusted.
+        fs::write(
+            dir.file(),
+            r#"{"next_id": 1, "items": [{"id": 2, "text": "b", "done": false}]}"#,
+        )
+        .unwrap();
+        let mut store = TodoStore::load(&dir.file()).unwrap();
+        assert_eq!(store.add("c").unwrap().id, 3);
+    }
+
+    #[test]
+    fn edits_text() {
+        let dir = TempDir::new("edit");
+        let mut store = TodoStore::load(&dir.file()).unwrap();
+        store.add("by milk").unwrap();
+        assert_eq!(store.edit(1, "buy milk").unwrap().text, "buy milk");
+        assert!(matches!(store.edit(1, "   "), Err(StoreError::EmptyText)));
+        assert!(matches!(store.edit(9, "x"), Err(StoreError::NoSuchItem(9))));
+        assert!(matches!(store.add(""), Err(StoreError::EmptyText)));
+        assert_eq!(texts(&store, Filter::All), ["buy milk"]);
+    }
+
+    #[test]
+    fn corrupt_files_are_errors_and_left_alone() {
+        let dir = TempDir::new("corrupt");
+        for contents in [
+            "not json",
+            r#"{"items": [{"id": 1, "text": "a"}]}"#,
+            r#"{"items": [{"id": 1, "text": "a", "done": false}, {"id": 1, "text": "b", "done": true}]}"#,
+            r#"{"items": [{"id": 0, "text": "a", "done": false}]}"#,
+        ] {
+            fs::write(dir.file(), contents).unwrap();
+            let err = TodoStore::load(&dir.file()).unwrap_err();
+            assert!(matches!(err, StoreError::Corrupt { .. }), "{contents}");
+            assert!(err.to_string().contains("isn't a valid todo file"));
+            assert_eq!(fs::read_to_string(dir.file()).unwrap(), contents);
+        }
+    }
+
+    #[test]
+    fn running_out_of_ids_is_an_error() {
+        let dir = TempDir::new("out_of_ids");
+        let contents = format!(
+            r#"{{"items": [{{"id": {}, "text": "a", "done": false}}]}}"#,
+            u32::MAX
+        );
+        fs::write(dir.file(), &contents).unwrap();
+        let err = TodoStore::load(&dir.file()).unwrap_err();
+        assert!(matches!(err, StoreError::Corrupt { .. }));
+        assert_eq!(fs::read_to_string(dir.file()).unwrap(), contents);
+
+        let contents = format!(r#"{{"next_id": {}, "items": []}}"#, u32::MAX - 1);
+        fs::write(dir.file(), contents).unwrap();
+        let mut store = TodoStore::load(&dir.file()).unwrap();
+        assert_eq!(store.add("a").unwrap().id, u32::MAX - 1);
+        assert!(matches!(store.add("b"), Err(StoreError::Corrupt { .. })));
+        assert_eq!(texts(&store, Filter::All), ["a"]);
+    }
+
+    #[test]
+    fn an_unreadable_path_is_an_io_error() {
+        // A directory where the file should be.
+        let dir = TempDir::new("unreadable");
+        assert!(matches!(
+            TodoStore::load(&dir.0),
+            Err(StoreError::Io { .. })
+        ));
+    }
+}
@@ -0,0 +1,153 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+// A directory of its own for each test, removed when it's over.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> TempDir {
+        let dir = std::env::temp_dir().join(format!("todo_cli_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        TempDir(dir)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn todo(file: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_todo"))
+        .arg("--file")
+        .arg(file)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+// Runs a command that should work, and returns what it printed.
+fn ok(file: &Path, args: &[&str]) -> String {
+    let output = todo(file, args);
+    assert_eq!(output.status.code(), Some(0), "{args:?}: {output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn a_session() {
+    let dir = TempDir::new("session");
+    let file = dir.0.join("todos.json");
+
+    assert_eq!(ok(&file, &["list"]), "Nothing to do.\n");
+    assert_eq!(ok(&file, &["add", "buy", "milk"]), "Added 1: buy milk\n");
+    assert_eq!(
+        ok(&file, &["add", "write the report"]),
+        "Added 2: write the report\n"
+    );
+    assert_eq!(ok(&file, &["add", "call Sam"]), "Added 3: call Sam\n");
+    assert_eq!(ok(&file, &["done", "1"]), "Done 1: buy milk\n");
+    assert_eq!(
+        ok(&file, &["list"]),
+        "  2 [ ] write the report\n  3 [ ] call Sam\n"
+    );
+    assert_eq!(ok(&file, &["list", "--done"]), "  1 [x] buy milk\n");
+    assert_eq!(
+        ok(&file, &["edit", "3", "call Sam back"]),
+        "Edited 3: call Sam back\n"
+    );
+    assert_eq!(ok(&file, &["rm", "2"]), "Removed 2: write the report\n");
+    assert_eq!(
+        ok(&file, &["list", "--all"]),
+        "  1 [x] buy milk\n  3 [ ] call Sam back\n"
+    );
+
+    let saved: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+    assert_eq!(
+        saved,
+        serde_json::json!({
+            "next_id": 4,
+            "items": [
+                {"id": 1, "text": "buy milk", "done": true},
+                {"id": 3, "text": "call Sam back", "done": false},
+            ]
+        })
+    );
+}
+
+#[test]
+fn removed_ids_stay_retired_from_one_run_to_the_next() {
+    let dir = TempDir::new("retired");
+    let file = dir.0.join("todos.json");
+    for text in ["a", "b", "c"] {
+        ok(&file, &["add", text]);
+    }
+    assert_eq!(ok(&file, &["rm", "2"]), "Removed 2: b\n");
+    assert_eq!(ok(&file, &["add", "new"]), "Added 4: new\n");
+    assert_eq!(ok(&file, &["rm", "4"]), "Removed 4: new\n");
+    assert_eq!(ok(&file, &["add", "newer"]), "Added 5: newer\n");
+
+    let output = todo(&file, &["done", "2"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        ok(&file, &["list"]),
+        "  1 [ ] a\n  3 [ ] c\n  5 [ ] newer\n"
+    );
+}
+
+#[test]
+fn missing_items_are_errors_and_change_nothing() {
+    let dir = TempDir::new("missing");
+    let file = dir.0.join("todos.json");
+    ok(&file, &["add", "only item"]);
+    let before = fs::read_to_string(&file).unwrap();
+
+    for args in [&["done", "7"][..], &["rm", "7"], &["edit", "7", "x"]] {
+        let output = todo(&file, args);
+        assert_eq!(output.status.code(), Some(1), "{args:?}");
+        assert_eq!(
+            String::from_utf8_lossy(&output.stderr),
+            "error: there's no item 7\n"
+        );
+    }
+    assert_eq!(fs::read_to_string(&file).unwrap(), before);
+}
+
+#[test]
+fn a_corrupt_file_is_reported_not_overwritten() {
+    let dir = TempDir::new("corrupt");
+    let file = dir.0.join("todos.json");
+    fs::write(&file, "{\"items\": [oops").unwrap();
+
+    let output = todo(&file, &["add", "something"]);
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("isn't a valid todo file"), "{stderr}");
+    assert_eq!(fs::read_to_string(&file).unwrap(), "{\"items\": [oops");
+}
+
+#[test]
+fn defaults_to_the_data_directory() {
+    let dir = TempDir::new("default");
+    let output = Command::new(env!("CARGO_BIN_EXE_todo"))
+        .args(["add", "from the default file"])
+        .env("XDG_DATA_HOME", &dir.0)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let saved = fs::read_to_string(dir.0.join("todo/todos.json")).unwrap();
+    assert!(saved.contains("from the default file"));
+}
+
+#[test]
+fn usage_errors() {
+    let dir = TempDir::new("usage");
+    let file = dir.0.join("todos.json");
+    for args in [&[][..], &["finish", "1"], &["done", "one"], &["add"]] {
+        assert_eq!(todo(&file, args).status.code(), Some(2), "{args:?}");
+    }
+    assert!(!file.exists());
+}
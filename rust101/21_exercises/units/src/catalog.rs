@@ -0,0 +1,208 @@
+use std::fmt;
+
+use crate::quantity::{ABSOLUTE_ZERO_CELSIUS, KILOGRAMS_PER_POUND, METERS_PER_FOOT};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Length,
+    Mass,
+    Temperature,
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Dimension::Length => "length",
+            Dimension::Mass => "mass",
+            Dimension::Temperature => "temperature",
+        };
+        write!(f, "{name}")
+    }
+}
+
+// The runtime version of the types in `quantity`, for when the units come
+// from what someone typed. Every unit of a dimension converts through one
+// base unit - metres, kilograms or kelvin - as
+//
+//     base = (value + offset) * scale
+//
+// The offset is zero for everything but temperatures, whose scales don't
+// start at the same place.
+#[derive(Debug, PartialEq)]
+pub struct Unit {
+    pub symbol: &'static str,
+    pub dimension: Dimension,
+    scale: f64,
+    offset: f64,
+    // Everything it can be called, lower case. The symbol is looked up
+    // too.
+    aliases: &'static [&'static str],
+}
+
+impl Unit {
+    pub fn to_base(&self, value: f64) -> f64 {
+        (value + self.offset) * self.scale
+    }
+
+    pub fn from_base(&self, base: f64) -> f64 {
+        base / self.scale - self.offset
+    }
+}
+
+const fn linear(
+    symbol: &'static str,
+    dimension: Dimension,
+    scale: f64,
+    aliases: &'static [&'static str],
+) -> Unit {
+    Unit {
+        symbol,
+        dimension,
+        scale,
+        offset: 0.0,
+        aliases,
+    }
+}
+
+const METERS_PER_MILE: f64 = 5280.0 * METERS_PER_FOOT;
+
+pub const UNITS: &[Unit] = &[
+    linear(
+        "m",
+        Dimension::Length,
+        1.0,
+        &["metre", "metres", "meter", "meters"],
+    ),
+    linear(
+        "km",
+        Dimension::Length,
+        1000.0,
+        &["kilometre", "kilometres", "kilometer", "kilometers"],
+    ),
+    linear(
+        "cm",
+        Dimension::Length,
+        0.01,
+        &["centimetre", "centimetres", "centimeter", "centimeters"],
+    ),
+    linear(
+        "mm",
+        Dimension::Length,
+        0.001,
+        &["millimetre", "millimetres", "millimeter", "millimeters"],
+    ),
+    linear("mi", Dimension::Length, METERS_PER_MILE, &["mile", "miles"]),
+    linear(
+        "yd",
+        Dimension::Length,
+        3.0 * METERS_PER_FOOT,
+        &["yard", "yards"],
+    ),
+    linear("ft", Dimension::Length, METERS_PER_FOOT, &["foot", "feet"]),
+    linear(
+        "in",
+        Dimension::Length,
+        METERS_PER_FOOT / 12.0,
+        &["inch", "inches"],
+    ),
+    linear(
+        "kg",
+        Dimension::Mass,
+        1.0,
+        &["kilogram", "kilograms", "kilo", "kilos"],
+    ),
+    linear("g", Dimension::Mass, 0.001, &["gram", "grams"]),
+    linear(
+        "lb",
+        Dimension::Mass,
+        KILOGRAMS_PER_POUND,
+        &["lbs", "pound", "pounds"],
+    ),
+    linear(
+        "oz",
+        Dimension::Mass,
+        KILOGRAMS_PER_POUND / 16.0,
+        &["ounce", "ounces"],
+    ),
+    Unit {
+        symbol: "°C",
+        dimension: Dimension::Temperature,
+        scale: 1.0,
+        offset: -ABSOLUTE_ZERO_CELSIUS,
+        aliases: &["c", "celsius", "centigrade", "degc", "degrees celsius"],
+    },
+    Unit {
+        symbol: "°F",
+        dimension: Dimension::Temperature,
+        scale: 5.0 / 9.0,
+        // Absolute zero is -459.67 °F.
+        offset: 459.67,
+        aliases: &["f", "fahrenheit", "degf", "degrees fahrenheit"],
+    },
+    Unit {
+        symbol: "K",
+        dimension: Dimension::Temperature,
+        scale: 1.0,
+        offset: 0.0,
+        aliases: &["kelvin", "kelvins"],
+    },
+];
+
+// Finds a unit by symbol or alias, ignoring case: "KM", "km" and
+// "Kilometres" are all the same unit.
+pub fn lookup(name: &str) -> Option<&'static Unit> {
+    let name = name.to_lowercase();
+    UNITS
+        .iter()
+        .find(|unit| unit.symbol.to_lowercase() == name || unit.aliases.contains(&name.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aliases_ignore_case() {
+        for name in ["km", "KM", "Km", "kilometres", "Kilometers", "KILOMETRE"] {
+            assert_eq!(lookup(name).map(|unit| unit.symbol), Some("km"), "{name}");
+        }
+        assert_eq!(lookup("°c").map(|unit| unit.symbol), Some("°C"));
+        assert_eq!(
+            lookup("Degrees Fahrenheit").map(|unit| unit.symbol),
+            Some("°F")
+        );
+        assert_eq!(lookup("k").map(|unit| unit.symbol), Some("K"));
+        assert_eq!(lookup("LBS").map(|unit| unit.symbol), Some("lb"));
+        assert_eq!(lookup("parsecs"), None);
+        assert_eq!(lookup(""), None);
+    }
+
+    #[test]
+    fn no_name_means_two_units() {
+        let mut names: Vec<String> = UNITS
+            .iter()
+            .flat_map(|unit| {
+                let symbol = unit.symbol.to_lowercase();
+                unit.aliases
+                    .iter()
+                    .map(|alias| alias.to_string())
+                    .chain([symbol])
+            })
+            .collect();
+        let count = names.len();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), count);
+    }
+
+    #[test]
+    fn base_units_are_exact() {
+        let mile = lookup("mi").unwrap();
+        assert_eq!(mile.to_base(1.0), 1609.344);
+        let celsius = lookup("c").unwrap();
+        assert_eq!(celsius.to_base(0.0), 273.15);
+        assert_eq!(celsius.from_base(0.0), -273.15);
+        let fahrenheit = lookup("f").unwrap();
+        assert_eq!(fahrenheit.to_base(-459.67), 0.0);
+    }
+}
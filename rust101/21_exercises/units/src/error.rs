@@ -0,0 +1,54 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::catalog::Dimension;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConvertError {
+    // The query didn't start with a number. Holds the query.
+    NotANumber(String),
+    // A number and nothing else.
+    MissingUnit,
+    // A unit to convert from, but not one to convert to.
+    MissingTarget(&'static str),
+    UnknownUnit(String),
+    Incompatible {
+        from: &'static str,
+        from_dimension: Dimension,
+        to: &'static str,
+        to_dimension: Dimension,
+    },
+    BelowAbsoluteZero {
+        value: String,
+        unit: &'static str,
+    },
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConvertError::NotANumber(query) => {
+                write!(f, "{query:?} should start with a number")
+            }
+            ConvertError::MissingUnit => {
+                write!(f, "which units? For example \"5 km to miles\"")
+            }
+            ConvertError::MissingTarget(from) => write!(f, "convert {from} to what?"),
+            ConvertError::UnknownUnit(name) => write!(f, "unknown unit {name:?}"),
+            ConvertError::Incompatible {
+                from,
+                from_dimension,
+                to,
+                to_dimension,
+            } => write!(
+                f,
+                "can't convert {from} ({from_dimension}) to {to} ({to_dimension})"
+            ),
+            ConvertError::BelowAbsoluteZero { value, unit } => {
+                write!(f, "{value} {unit} is below absolute zero")
+            }
+        }
+    }
+}
+
+impl Error for ConvertError {}
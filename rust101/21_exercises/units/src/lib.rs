@@ -0,0 +1,310 @@
+/* Things learned during this exercise:
+   * A newtype - `struct Meters(f64)` - costs nothing at runtime but is a
+   different type to the compiler, so a mass can't end up where a length
+   was wanted. Mixing them up is a compile error, not a wrong answer.
+   * `From` is for conversions that always work; implementing it also gives
+   `into()` for free. `TryFrom` is for ones that can fail, like Celsius to
+   Kelvin below absolute zero, and gives `try_into()`.
+   * The operators are traits too: `impl Add for Meters` is what makes
+   `a + b` work, and leaving out `Add<Feet> for Meters` is what stops
+   metres and feet being added without a conversion. `Mul<f64>` scales.
+   * Writing the same impls for seven types is what `macro_rules!` is for.
+   * A command line has no types, so the CLI needs the same rules at
+   runtime: each unit knows its dimension, and converting checks it.
+   * Linear units convert with one factor. Temperatures need an offset too,
+   since 0 °C and 0 °F aren't the same temperature, and the order matters:
+   add the offset, then scale.
+   * trybuild checks that code which shouldn't compile doesn't, and with the
+   error expected.
+   * See https://doc.rust-lang.org/rust-by-example/generics/new_types.html
+   and https://doc.rust-lang.org/std/convert/trait.TryFrom.html
+*/
+mod catalog;
+mod error;
+mod quantity;
+
+pub use catalog::{lookup, Dimension, Unit, UNITS};
+pub use error::ConvertError;
+pub use quantity::{
+    BelowAbsoluteZero, Celsius, Fahrenheit, Feet, Kelvin, Kilograms, Meters, Pounds,
+    ABSOLUTE_ZERO_CELSIUS, KILOGRAMS_PER_POUND, METERS_PER_FOOT,
+};
+
+// Words that can go between the two units: "5 km to miles".
+const SEPARATORS: [&str; 4] = ["to", "in", "into", "as"];
+
+#[derive(Debug, PartialEq)]
+pub struct Query {
+    pub value: f64,
+    pub from: &'static Unit,
+    pub to: &'static Unit,
+}
+
+// Both sides of a split, if they're both units.
+fn units(from: &[&str], to: &[&str]) -> Option<(&'static Unit, &'static Unit)> {
+    Some((lookup(&from.join(" "))?, lookup(&to.join(" "))?))
+}
+
+// Reads a query like "5 km to miles", "-40F in C" or "3 ft cm": a number,
+// the unit it's in and the unit wanted, with an optional "to", "in",
+// "into" or "as" between them. Some units have names of more than one word
+// ("degrees celsius") and one is itself "in", so every split is tried and
+// the first that gives two units wins.
+pub fn parse_query(query: &str) -> Result<Query, ConvertError> {
+    let query = query.trim();
+    let number_end = query
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(query.len());
+    let value = query[..number_end]
+        .parse()
+        .map_err(|_| ConvertError::NotANumber(query.to_string()))?;
+    let words: Vec<&str> = query[number_end..].split_whitespace().collect();
+    if words.is_empty() {
+        return Err(ConvertError::MissingUnit);
+    }
+
+    let separated = (1..words.len())
+        .filter(|&i| SEPARATORS.contains(&words[i].to_lowercase().as_str()))
+        .find_map(|i| units(&words[..i], &words[i + 1..]));
+    let adjacent = || (1..words.len()).find_map(|i| units(&words[..i], &words[i..]));
+    if let Some((from, to)) = separated.or_else(adjacent) {
+        return Ok(Query { value, from, to });
+    }
+
+    // Nothing fitted, so say what's wrong with the most likely reading:
+    // the first word is the unit to convert from.
+    let from = lookup(words[0]).ok_or_else(|| ConvertError::UnknownUnit(words[0].to_string()))?;
+    let mut rest = &words[1..];
+    if rest
+        .first()
+        .is_some_and(|word| SEPARATORS.contains(&word.to_lowercase().as_str()))
+    {
+        rest = &rest[1..];
+    }
+    if rest.is_empty() {
+        return Err(ConvertError::MissingTarget(from.symbol));
+    }
+    Err(ConvertError::UnknownUnit(rest.join(" ")))
+}
+
+// Converts between units of the same dimension.
+pub fn convert(value: f64, from: &Unit, to: &Unit) -> Result<f64, ConvertError> {
+    if from.dimension != to.dimension {
+        return Err(ConvertError::Incompatible {
+            from: from.symbol,
+            from_dimension: from.dimension,
+            to: to.symbol,
+            to_dimension: to.dimension,
+        });
+    }
+    let base = from.to_base(value);
+    if from.dimension == Dimension::Temperature && base < 0.0 {
+        return Err(ConvertError::BelowAbsoluteZero {
+            value: format_value(value),
+            unit: from.symbol,
+        });
+    }
+    Ok(to.from_base(base))
+}
+
+// Up to six decimal places, without trailing zeros: 3.106856, 212, 0.5.
+pub fn format_value(value: f64) -> String {
+    let fixed = format!("{value:.6}");
+    let trimmed = fixed.trim_end_matches('0').trim_end_matches('.');
+    match trimmed {
+        // Rounding something tiny and negative gives "-0".
+        "-0" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub query: String,
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        // Quoted or not, "5 km to miles" is the same query.
+        let query = args.collect::<Vec<String>>().join(" ");
+        if query.trim().is_empty() {
+            return Err("what to convert? For example \"5 km to miles\"".to_string());
+        }
+        Ok(Config { query })
+    }
+}
+
+// The answer, as "5 km = 3.106856 mi".
+pub fn run(config: &Config) -> Result<String, ConvertError> {
+    let Query { value, from, to } = parse_query(&config.query)?;
+    let converted = convert(value, from, to)?;
+    Ok(format!(
+        "{} {} = {} {}",
+        format_value(value),
+        from.symbol,
+        format_value(converted),
+        to.symbol
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["convert".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    fn symbols(query: &str) -> Result<(f64, &str, &str), ConvertError> {
+        parse_query(query).map(|query| (query.value, query.from.symbol, query.to.symbol))
+    }
+
+    fn run_query(query: &str) -> Result<String, ConvertError> {
+        run(&Config {
+            query: query.to_string(),
+        })
+    }
+
+    #[test]
+    fn parses_queries() {
+        assert_eq!(symbols("5 km to miles"), Ok((5.0, "km", "mi")));
+        assert_eq!(symbols("5 kilometres to MILES"), Ok((5.0, "km", "mi")));
+        assert_eq!(symbols("5 KM into mi"), Ok((5.0, "km", "mi")));
+        assert_eq!(symbols("-40F in C"), Ok((-40.0, "°F", "°C")));
+        assert_eq!(symbols("2.5 lbs kg"), Ok((2.5, "lb", "kg")));
+        assert_eq!(
+            symbols("451 degrees fahrenheit as degrees celsius"),
+            Ok((451.0, "°F", "°C"))
+        );
+    }
+
+    #[test]
+    fn in_can_be_a_unit_or_a_separator() {
+        assert_eq!(symbols("12 in to cm"), Ok((12.0, "in", "cm")));
+        assert_eq!(symbols("3 ft in in"), Ok((3.0, "ft", "in")));
+        assert_eq!(symbols("12 in in cm"), Ok((12.0, "in", "cm")));
+        assert_eq!(symbols("12 in cm"), Ok((12.0, "in", "cm")));
+    }
+
+    #[test]
+    fn reports_bad_queries() {
+        assert_eq!(
+            symbols("km to miles"),
+            Err(ConvertError::NotANumber("km to miles".to_string()))
+        );
+        assert_eq!(
+            symbols("1-2 km to m"),
+            Err(ConvertError::NotANumber("1-2 km to m".to_string()))
+        );
+        assert_eq!(symbols("5"), Err(ConvertError::MissingUnit));
+        assert_eq!(symbols("5 km"), Err(ConvertError::MissingTarget("km")));
+        assert_eq!(symbols("5 km to"), Err(ConvertError::MissingTarget("km")));
+        assert_eq!(
+            symbols("5 parsecs to km"),
+            Err(ConvertError::UnknownUnit("parsecs".to_string()))
+        );
+        assert_eq!(
+            symbols("5 km to light years"),
+            Err(ConvertError::UnknownUnit("light years".to_string()))
+        );
+    }
+
+    #[test]
+    fn converts_against_known_values() {
+        let cases = [
+            ("1 mi to km", 1.609_344),
+            ("1 in to cm", 2.54),
+            ("1 yd to m", 0.9144),
+            ("1 km to ft", 3_280.839_895_013_123),
+            ("1 lb to g", 453.592_37),
+            ("1 kg to oz", 35.273_961_949_580_41),
+            ("100 C to F", 212.0),
+            ("98.6 F to C", 37.0),
+            ("0 K to C", -273.15),
+            ("-40 C to F", -40.0),
+            ("0 F to K", 255.372_222_222_222_2),
+        ];
+        for (query, expected) in cases {
+            let Query { value, from, to } = parse_query(query).unwrap();
+            let actual = convert(value, from, to).unwrap();
+            assert!((actual - expected).abs() < 1e-9, "{query}: {actual}");
+        }
+    }
+
+    #[test]
+    fn refuses_to_mix_dimensions() {
+        assert_eq!(
+            run_query("5 kg to m"),
+            Err(ConvertError::Incompatible {
+                from: "kg",
+                from_dimension: Dimension::Mass,
+                to: "m",
+                to_dimension: Dimension::Length,
+            })
+        );
+        assert_eq!(
+            run_query("5 kg to m").unwrap_err().to_string(),
+            "can't convert kg (mass) to m (length)"
+        );
+        assert!(run_query("20 C to ft").is_err());
+    }
+
+    #[test]
+    fn refuses_temperatures_below_absolute_zero() {
+        assert_eq!(
+            run_query("-500 F to C").unwrap_err().to_string(),
+            "-500 °F is below absolute zero"
+        );
+        assert!(run_query("-1 K to C").is_err());
+        assert_eq!(
+            run_query("-273.15 C to K"),
+            Ok("-273.15 °C = 0 K".to_string())
+        );
+    }
+
+    #[test]
+    fn formats_values() {
+        assert_eq!(format_value(3.106_855_961_2), "3.106856");
+        assert_eq!(format_value(212.0), "212");
+        assert_eq!(format_value(0.5), "0.5");
+        assert_eq!(format_value(-0.000_000_1), "0");
+        assert_eq!(format_value(1e-7), "0");
+    }
+
+    #[test]
+    fn runs() {
+        assert_eq!(
+            run_query("5 km to miles"),
+            Ok("5 km = 3.106856 mi".to_string())
+        );
+        assert_eq!(
+            run_query("100 celsius in F"),
+            Ok("100 °C = 212 °F".to_string())
+        );
+        assert_eq!(run_query("5 km to km"), Ok("5 km = 5 km".to_string()));
+    }
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&["5", "km", "to", "miles"])),
+            Ok(Config {
+                query: "5 km to miles".to_string()
+            })
+        );
+        assert_eq!(
+            Config::build(args(&["5 km to miles"])),
+            Ok(Config {
+                query: "5 km to miles".to_string()
+            })
+        );
+        assert!(Config::build(args(&[])).is_err());
+        assert!(Config::build(args(&[" "])).is_err());
+    }
+}
@@ -0,0 +1,19 @@
+use std::env;
+use std::process;
+
+use units::Config;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: convert NUMBER UNIT [to] UNIT");
+        process::exit(2);
+    });
+    match units::run(&config) {
+        Ok(answer) => println!("{answer}"),
+        Err(err) => {
+            eprintln!("{:?}: {err}", config.query);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+// Both exact, by definition.
+pub const METERS_PER_FOOT: f64 = 0.3048;
+pub const KILOGRAMS_PER_POUND: f64 = 0.453_592_37;
+// 0 °C is 273.15 K, so absolute zero is -273.15 °C.
+pub const ABSOLUTE_ZERO_CELSIUS: f64 = -273.15;
+
+// Each unit is its own type wrapping an f64, so a length in feet can't be
+// passed where one in metres is wanted, or added to one, without saying
+// how to convert it. The arithmetic is only between values of the same
+// unit, plus scaling by a plain number.
+//
+// `$symbol` is what Display puts after the number.
+macro_rules! units {
+    ($($name:ident => $symbol:literal),* $(,)?) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+            pub struct $name(pub f64);
+
+            impl Add for $name {
+                type Output = $name;
+
+                fn add(self, other: $name) -> $name {
+                    $name(self.0 + other.0)
+                }
+            }
+
+            impl Sub for $name {
+                type Output = $name;
+
+                fn sub(self, other: $name) -> $name {
+                    $name(self.0 - other.0)
+                }
+            }
+
+            impl AddAssign for $name {
+                fn add_assign(&mut self, other: $name) {
+                    self.0 += other.0;
+                }
+            }
+
+            impl SubAssign for $name {
+                fn sub_assign(&mut self, other: $name) {
+                    self.0 -= other.0;
+                }
+            }
+
+            impl Neg for $name {
+                type Output = $name;
+
+                fn neg(self) -> $name {
+                    $name(-self.0)
+                }
+            }
+
+            // Scaling: twice three metres is six metres.
+            impl Mul<f64> for $name {
+                type Output = $name;
+
+                fn mul(self, factor: f64) -> $name {
+                    $name(self.0 * factor)
+                }
+            }
+
+            impl Div<f64> for $name {
+                type Output = $name;
+
+                fn div(self, divisor: f64) -> $name {
+                    $name(self.0 / divisor)
+                }
+            }
+
+            // A precision applies to the number: `{:.2}` gives "3.28 ft".
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    match f.precision() {
+                        Some(precision) => write!(f, "{:.*} {}", precision, self.0, $symbol),
+                        None => write!(f, "{} {}", self.0, $symbol),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+units! {
+    Meters => "m",
+    Feet => "ft",
+    Kilograms => "kg",
+    Pounds => "lb",
+    Celsius => "°C",
+    Fahrenheit => "°F",
+    Kelvin => "K",
+}
+
+impl From<Feet> for Meters {
+    fn from(feet: Feet) -> Meters {
+        Meters(feet.0 * METERS_PER_FOOT)
+    }
+}
+
+impl From<Meters> for Feet {
+    fn from(meters: Meters) -> Feet {
+        Feet(meters.0 / METERS_PER_FOOT)
+    }
+}
+
+impl From<Pounds> for Kilograms {
+    fn from(pounds: Pounds) -> Kilograms {
+        Kilograms(pounds.0 * KILOGRAMS_PER_POUND)
+    }
+}
+
+impl From<Kilograms> for Pounds {
+    fn from(kilograms: Kilograms) -> Pounds {
+        Pounds(kilograms.0 / KILOGRAMS_PER_POUND)
+    }
+}
+
+impl From<Celsius> for Fahrenheit {
+    fn from(celsius: Celsius) -> Fahrenheit {
+        Fahrenheit(celsius.0 * 9.0 / 5.0 + 32.0)
+    }
+}
+
+impl From<Fahrenheit> for Celsius {
+    fn from(fahrenheit: Fahrenheit) -> Celsius {
+        Celsius((fahrenheit.0 - 32.0) * 5.0 / 9.0)
+    }
+}
+
+impl From<Kelvin> for Celsius {
+    fn from(kelvin: Kelvin) -> Celsius {
+        Celsius(kelvin.0 + ABSOLUTE_ZERO_CELSIUS)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BelowAbsoluteZero(pub Celsius);
+
+impl fmt::Display for BelowAbsoluteZero {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is below absolute zero", self.0)
+    }
+}
+
+impl Error for BelowAbsoluteZero {}
+
+// Kelvin starts at absolute zero, so a negative one is meaningless: going
+// from Celsius is the one conversion here that can fail.
+impl TryFrom<Celsius> for Kelvin {
+    type Error = BelowAbsoluteZero;
+
+    fn try_from(celsius: Celsius) -> Result<Kelvin, BelowAbsoluteZero> {
+        if celsius.0 < ABSOLUTE_ZERO_CELSIUS {
+            return Err(BelowAbsoluteZero(celsius));
+        }
+        Ok(Kelvin(celsius.0 - ABSOLUTE_ZERO_CELSIUS))
+    }
+}
+
+impl TryFrom<Fahrenheit> for Kelvin {
+    type Error = BelowAbsoluteZero;
+
+    fn try_from(fahrenheit: Fahrenheit) -> Result<Kelvin, BelowAbsoluteZero> {
+        Kelvin::try_from(Celsius::from(fahrenheit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{actual} isn't {expected}"
+        );
+    }
+
+    #[test]
+    fn lengths() {
+        assert_close(Meters::from(Feet(1.0)).0, 0.3048);
+        assert_close(Feet::from(Meters(1.0)).0, 3.280_839_895_013_123);
+        assert_close(Feet::from(Meters::from(Feet(5280.0))).0, 5280.0);
+    }
+
+    #[test]
+    fn masses() {
+        assert_close(Kilograms::from(Pounds(1.0)).0, 0.453_592_37);
+        assert_close(Pounds::from(Kilograms(1.0)).0, 2.204_622_621_848_775_8);
+    }
+
+    #[test]
+    fn temperatures() {
+        assert_close(Fahrenheit::from(Celsius(100.0)).0, 212.0);
+        assert_close(Fahrenheit::from(Celsius(0.0)).0, 32.0);
+        assert_close(Celsius::from(Fahrenheit(-40.0)).0, -40.0);
+        assert_close(Celsius::from(Kelvin(0.0)).0, -273.15);
+        assert_close(Kelvin::try_from(Celsius(25.0)).unwrap().0, 298.15);
+        assert_close(Kelvin::try_from(Fahrenheit(-459.67)).unwrap().0, 0.0);
+    }
+
+    #[test]
+    fn nothing_is_colder_than_absolute_zero() {
+        assert_eq!(
+            Kelvin::try_from(Celsius(-300.0)),
+            Err(BelowAbsoluteZero(Celsius(-300.0)))
+        );
+        assert_eq!(
+            Kelvin::try_from(Celsius(-300.0)).unwrap_err().to_string(),
+            "-300 °C is below absolute zero"
+        );
+        assert!(Kelvin::try_from(Fahrenheit(-500.0)).is_err());
+    }
+
+    #[test]
+    fn arithmetic_within_a_unit() {
+        assert_eq!(Meters(1.5) + Meters(2.0), Meters(3.5));
+        assert_eq!(Pounds(10.0) - Pounds(4.0), Pounds(6.0));
+        assert_eq!(-Celsius(5.0), Celsius(-5.0));
+        assert_eq!(Feet(3.0) * 2.0, Feet(6.0));
+        assert_eq!(Kilograms(9.0) / 3.0, Kilograms(3.0));
+        let mut total = Meters::default();
+        for leg in [Meters(100.0), Meters::from(Feet(1000.0))] {
+            total += leg;
+        }
+        assert_close(total.0, 404.8);
+        total -= Meters(4.8);
+        assert_close(total.0, 400.0);
+        assert!(Feet(1.0) < Feet(2.0));
+    }
+
+    #[test]
+    fn displays_with_the_symbol() {
+        assert_eq!(Meters(3.0).to_string(), "3 m");
+        assert_eq!(format!("{:.2}", Feet::from(Meters(1.0))), "3.28 ft");
+        assert_eq!(format!("{:.1}", Fahrenheit(98.64)), "98.6 °F");
+    }
+}
@@ -0,0 +1,61 @@
+use std::process::{Command, Output};
+
+fn convert(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_convert"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn converts() {
+    let output = convert(&["5", "km", "to", "miles"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout(&output), "5 km = 3.106856 mi\n");
+    assert_eq!(
+        stdout(&convert(&["-40", "F", "to", "C"])),
+        "-40 °F = -40 °C\n"
+    );
+    assert_eq!(
+        stdout(&convert(&["10 Pounds in KG"])),
+        "10 lb = 4.535924 kg\n"
+    );
+}
+
+#[test]
+fn incompatible_units_are_explained() {
+    let output = convert(&["5", "kg", "to", "m"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stderr(&output),
+        "\"5 kg to m\": can't convert kg (mass) to m (length)\n"
+    );
+}
+
+#[test]
+fn unknown_units_are_named() {
+    let output = convert(&["3", "furlongs", "to", "m"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stderr(&output),
+        "\"3 furlongs to m\": unknown unit \"furlongs\"\n"
+    );
+    let output = convert(&["3", "km"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stderr(&output), "\"3 km\": convert km to what?\n");
+}
+
+#[test]
+fn no_arguments_is_a_usage_error() {
+    let output = convert(&[]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).contains("Usage: convert"));
+}
@@ -0,0 +1,12 @@
+// Mixing up units is meant to be a compile error, so the only way to test
+// it is to compile some code that does. Each `fail` file in tests/ui must
+// fail with exactly the errors in the matching .stderr file, and each
+// `pass` file must build and run. After changing the types,
+// `TRYBUILD=overwrite cargo test` rewrites the .stderr files - check the
+// diff before committing them.
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass_*.rs");
+    t.compile_fail("tests/ui/fail_*.rs");
+}
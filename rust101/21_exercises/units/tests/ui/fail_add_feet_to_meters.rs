@@ -0,0 +1,5 @@
+use units::{Feet, Meters};
+
+fn main() {
+    let _ = Meters(1.0) + Feet(1.0);
+}
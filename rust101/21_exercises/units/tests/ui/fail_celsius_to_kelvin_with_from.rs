@@ -0,0 +1,6 @@
+use units::{Celsius, Kelvin};
+
+fn main() {
+    // Only TryFrom: it can fail.
+    let _ = Kelvin::from(Celsius(-300.0));
+}
@@ -0,0 +1,5 @@
+use units::{Kilograms, Meters};
+
+fn main() {
+    let _: Meters = Kilograms(1.0).into();
+}
@@ -0,0 +1,11 @@
+use units::{Celsius, Fahrenheit, Feet, Kelvin, Kilograms, Meters, Pounds};
+
+fn main() {
+    let height: Meters = Feet(6.0).into();
+    let total = height + Meters(1.0);
+    assert!(total > Meters(2.8));
+    let _: Pounds = Kilograms(70.0).into();
+    let _: Fahrenheit = Celsius(20.0).into();
+    let kelvin: Result<Kelvin, _> = Celsius(20.0).try_into();
+    assert!(kelvin.is_ok());
+}
@@ -0,0 +1,152 @@
+use std::io::{self, BufRead};
+use std::ops::{Add, AddAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Counts {
+    // Newlines, as wc counts them: a last line without one isn't counted.
+    pub lines: u64,
+    // Runs of anything but whitespace.
+    pub words: u64,
+    // Unicode characters, counted by the bytes that start them in UTF-8.
+    pub chars: u64,
+    pub bytes: u64,
+}
+
+impl Add for Counts {
+    type Output = Counts;
+
+    fn add(self, other: Counts) -> Counts {
+        Counts {
+            lines: self.lines + other.lines,
+            words: self.words + other.words,
+            chars: self.chars + other.chars,
+            bytes: self.bytes + other.bytes,
+        }
+    }
+}
+
+impl AddAssign for Counts {
+    fn add_assign(&mut self, other: Counts) {
+        *self = *self + other;
+    }
+}
+
+// A UTF-8 continuation byte is 0b10xxxxxx. Every character has exactly one
+// byte that isn't one, so counting those counts characters - without
+// decoding anything, and without caring where the buffer splits a
+// character in two.
+fn starts_a_char(byte: u8) -> bool {
+    byte & 0b1100_0000 != 0b1000_0000
+}
+
+// Counts everything in `reader`, a buffer at a time, so the file never has
+// to fit in memory.
+//
+// Words are split on ASCII whitespace, as wc does under the C locale;
+// Unicode spaces like U+00A0 count as part of a word. Nothing is decoded,
+// so bytes that aren't valid UTF-8 are counted by the same rule: a stray
+// lead or ASCII-range byte is a character of its own, but a stray
+// continuation byte (0x80 to 0xBF) isn't counted at all.
+pub fn count(mut reader: impl BufRead) -> io::Result<Counts> {
+    let mut counts = Counts::default();
+    // Carried from one buffer to the next, in case a word spans them.
+    let mut in_word = false;
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            break;
+        }
+        for &byte in buffer {
+            if byte == b'\n' {
+                counts.lines += 1;
+            }
+            if starts_a_char(byte) {
+                counts.chars += 1;
+            }
+            if byte.is_ascii_whitespace() || byte == 0x0b {
+                in_word = false;
+            } else if !in_word {
+                in_word = true;
+                counts.words += 1;
+            }
+        }
+        counts.bytes += buffer.len() as u64;
+        let used = buffer.len();
+        reader.consume(used);
+    }
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    fn counts_of(text: &str) -> Counts {
+        count(text.as_bytes()).unwrap()
+    }
+
+    fn counts(lines: u64, words: u64, chars: u64, bytes: u64) -> Counts {
+        Counts {
+            lines,
+            words,
+            chars,
+            bytes,
+        }
+    }
+
+    #[test]
+    fn counts_plain_text() {
+        assert_eq!(counts_of("hello world\n"), counts(1, 2, 12, 12));
+        assert_eq!(counts_of(""), Counts::default());
+        assert_eq!(counts_of("\n\n\n"), counts(3, 0, 3, 3));
+        assert_eq!(counts_of("  lots   of\tspace \r\n"), counts(1, 3, 20, 20));
+        // Vertical tab and form feed are whitespace too.
+        assert_eq!(counts_of("a\x0bb\x0cc"), counts(0, 3, 5, 5));
+    }
+
+    #[test]
+    fn a_last_line_without_a_newline_is_not_a_line() {
+        assert_eq!(counts_of("one two\nthree"), counts(1, 3, 13, 13));
+        assert_eq!(counts_of("no newline"), counts(0, 2, 10, 10));
+    }
+
+    #[test]
+    fn chars_and_bytes_differ_for_multibyte_text() {
+        assert_eq!(counts_of("café\n"), counts(1, 1, 5, 6));
+        assert_eq!(counts_of("日本語"), counts(0, 1, 3, 9));
+        assert_eq!(counts_of("🦀 🦀"), counts(0, 2, 3, 9));
+        // No-break space isn't ASCII whitespace, so this is one word.
+        assert_eq!(counts_of("a\u{a0}b"), counts(0, 1, 3, 4));
+    }
+
+    #[test]
+    fn words_and_chars_can_span_buffers() {
+        let text = "naïve café 日本語 ".repeat(100);
+        let whole = counts_of(&text);
+        assert_eq!(whole, counts(0, 300, 1500, 2300));
+        // A buffer of one byte splits every word and character.
+        for capacity in [1, 2, 3, 7] {
+            let reader = BufReader::with_capacity(capacity, text.as_bytes());
+            assert_eq!(count(reader).unwrap(), whole, "{capacity}");
+        }
+    }
+
+    #[test]
+    fn invalid_utf8_bytes_are_chars() {
+        assert_eq!(count(&b"\xff\xfe"[..]).unwrap(), counts(0, 1, 2, 2));
+    }
+
+    #[test]
+    fn stray_continuation_bytes_are_not_chars() {
+        assert_eq!(count(&b"\x80"[..]).unwrap(), counts(0, 1, 0, 1));
+        assert_eq!(count(&b"a\xbf\x80b"[..]).unwrap(), counts(0, 1, 2, 4));
+    }
+
+    #[test]
+    fn totals_add_up() {
+        let mut total = counts(1, 2, 3, 4);
+        total += counts(10, 20, 30, 40);
+        assert_eq!(total, counts(11, 22, 33, 44));
+    }
+}
@@ -0,0 +1,311 @@
+/* Things learned during this exercise:
+   * `BufRead::fill_buf` hands over whatever is in the buffer, reading more
+   only when it's empty, and `consume` says how much of it was used. Going
+   through a file that way never holds more than one buffer of it, however
+   big the file is.
+   * Anything that spans buffers - here, being part way through a word -
+   has to be kept in a variable outside the loop.
+   * A byte and a character are only the same for ASCII. UTF-8 spends up to
+   four bytes on a character, and its continuation bytes all start with the
+   bits 10, so characters can be counted without decoding them.
+   * `impl Add for Counts` lets the total be a plain sum.
+   * wc keeps going after a file it can't read, reports it, and still
+   fails at the end - so errors are collected, not returned on the first
+   one.
+   * `{:>width$}` right-aligns to a width only known at runtime.
+   * See https://doc.rust-lang.org/std/io/trait.BufRead.html#tymethod.fill_buf
+   and https://en.wikipedia.org/wiki/UTF-8#Encoding
+*/
+mod count;
+
+pub use count::{count, Counts};
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::PathBuf;
+
+// When counting stdin there's no size to go on, so like wc the columns
+// are at least this wide.
+const STDIN_WIDTH: usize = 7;
+
+// Which counts to show. They're always shown in this order, whichever
+// order the flags came in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Columns {
+    pub lines: bool,
+    pub words: bool,
+    pub chars: bool,
+    pub bytes: bool,
+}
+
+impl Columns {
+    fn values(self, counts: Counts) -> Vec<u64> {
+        [
+            (self.lines, counts.lines),
+            (self.words, counts.words),
+            (self.chars, counts.chars),
+            (self.bytes, counts.bytes),
+        ]
+        .into_iter()
+        .filter(|&(shown, _)| shown)
+        .map(|(_, value)| value)
+        .collect()
+    }
+}
+
+// What there is to count: stdin, given as no files or as "-", or a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Input {
+    fn name(&self) -> Option<String> {
+        match self {
+            Input::Stdin => None,
+            Input::File(path) => Some(path.display().to_string()),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub columns: Columns,
+    pub inputs: Vec<Input>,
+}
+
+impl Config {
+    // `args` is the full argument list, program name first, as from
+    // `env::args()`.
+    pub fn build(mut args: impl Iterator<Item = String>) -> Result<Config, String> {
+        args.next();
+
+        let mut columns = Columns::default();
+        let mut inputs = Vec::new();
+        for arg in args {
+            // Flags can be grouped, as in -lw.
+            match arg.strip_prefix('-') {
+                Some(flags) if !flags.is_empty() => {
+                    for flag in flags.chars() {
+                        match flag {
+                            'l' => columns.lines = true,
+                            'w' => columns.words = true,
+                            'm' => columns.chars = true,
+                            'c' => columns.bytes = true,
+                            _ => return Err(format!("unknown option: -{flag}")),
+                        }
+                    }
+                }
+                Some(_) => inputs.push(Input::Stdin),
+                None => inputs.push(Input::File(PathBuf::from(arg))),
+            }
+        }
+        // No flags means wc's default: lines, words and bytes.
+        if columns == Columns::default() {
+            columns = Columns {
+                lines: true,
+                words: true,
+                chars: false,
+                bytes: true,
+            };
+        }
+        if inputs.is_empty() {
+            inputs.push(Input::Stdin);
+        }
+        Ok(Config { columns, inputs })
+    }
+}
+
+// A file that couldn't be read.
+#[derive(Debug)]
+pub struct FileError {
+    pub path: PathBuf,
+    pub err: io::Error,
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.err)
+    }
+}
+
+impl Error for FileError {}
+
+// The table wc prints: one row per input, then a total if `with_total`.
+// The numbers are right-aligned in columns as wide as the
+// biggest of them, and at least STDIN_WIDTH when stdin is among them.
+pub fn format_rows(
+    rows: &[(Counts, Option<String>)],
+    columns: Columns,
+    with_total: bool,
+    reads_stdin: bool,
+) -> String {
+    let mut rows = rows.to_vec();
+    if with_total {
+        let total = rows
+            .iter()
+            .fold(Counts::default(), |total, (counts, _)| total + *counts);
+        rows.push((total, Some("total".to_string())));
+    }
+    let digits = rows
+        .iter()
+        .flat_map(|(counts, _)| columns.values(*counts))
+        .map(|value| value.to_string().len())
+        .max()
+        .unwrap_or(1);
+    let width = if reads_stdin {
+        digits.max(STDIN_WIDTH)
+    } else {
+        digits
+    };
+
+    let mut output = String::new();
+    for (counts, name) in &rows {
+        let values: Vec<String> = columns
+            .values(*counts)
+            .iter()
+            .map(|value| format!("{value:>width$}"))
+            .collect();
+        output.push_str(&values.join(" "));
+        if let Some(name) = name {
+            output.push(' ');
+            output.push_str(name);
+        }
+        output.push('\n');
+    }
+    output
+}
+
+// Counts every input, returning the table and the files that couldn't be
+// read. Those are left out of the table, but don't stop the others being
+// counted.
+pub fn run(config: &Config, mut stdin: impl BufRead) -> (String, Vec<FileError>) {
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for input in &config.inputs {
+        let counts = match input {
+            Input::Stdin => count(&mut stdin).map_err(|err| FileError {
+                path: PathBuf::from("-"),
+                err,
+            }),
+            Input::File(path) => File::open(path)
+                .and_then(|file| count(BufReader::new(file)))
+                .map_err(|err| FileError {
+                    path: path.clone(),
+                    err,
+                }),
+        };
+        match counts {
+            Ok(counts) => rows.push((counts, input.name())),
+            Err(err) => errors.push(err),
+        }
+    }
+    // Like wc, the total is there whenever there's more than one input,
+    // even if some of them couldn't be read.
+    let with_total = config.inputs.len() > 1;
+    let reads_stdin = config.inputs.contains(&Input::Stdin);
+    (
+        format_rows(&rows, config.columns, with_total, reads_stdin),
+        errors,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> impl Iterator<Item = String> {
+        let mut all = vec!["wordcount".to_string()];
+        all.extend(args.iter().map(|arg| arg.to_string()));
+        all.into_iter()
+    }
+
+    const ALL: Columns = Columns {
+        lines: true,
+        words: true,
+        chars: true,
+        bytes: true,
+    };
+
+    #[test]
+    fn builds_config() {
+        assert_eq!(
+            Config::build(args(&[])),
+            Ok(Config {
+                columns: Columns {
+                    lines: true,
+                    words: true,
+                    chars: false,
+                    bytes: true
+                },
+                inputs: vec![Input::Stdin],
+            })
+        );
+        assert_eq!(
+            Config::build(args(&["-cm", "a.txt", "-", "-wl"])),
+            Ok(Config {
+                columns: ALL,
+                inputs: vec![Input::File(PathBuf::from("a.txt")), Input::Stdin],
+            })
+        );
+        assert!(Config::build(args(&["-x"])).is_err());
+    }
+
+    #[test]
+    fn aligns_columns_to_the_biggest_number() {
+        let rows = [
+            (
+                Counts {
+                    lines: 1,
+                    words: 22,
+                    chars: 333,
+                    bytes: 4444,
+                },
+                Some("a".to_string()),
+            ),
+            (Counts::default(), Some("b".to_string())),
+        ];
+        assert_eq!(
+            format_rows(&rows, ALL, true, false),
+            "   1   22  333 4444 a\n   0    0    0    0 b\n   1   22  333 4444 total\n"
+        );
+        let lines_only = Columns {
+            lines: true,
+            words: false,
+            chars: false,
+            bytes: false,
+        };
+        assert_eq!(format_rows(&rows[..1], lines_only, false, false), "1 a\n");
+        assert_eq!(
+            format_rows(&[(Counts::default(), None)], lines_only, false, true),
+            "      0\n"
+        );
+    }
+
+    #[test]
+    fn counts_stdin() {
+        let config = Config::build(args(&[])).unwrap();
+        let (output, errors) = run(&config, "one two\nthree\n".as_bytes());
+        assert_eq!(output, "      2       3      14\n");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn carries_on_past_missing_files() {
+        let config = Config::build(args(&[
+            "-l",
+            "tests/data/no_such_file",
+            "tests/data/utf8.txt",
+        ]))
+        .unwrap();
+        let (output, errors) = run(&config, io::empty());
+        assert_eq!(output, "3 tests/data/utf8.txt\n3 total\n");
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0]
+            .to_string()
+            .starts_with("tests/data/no_such_file: "));
+    }
+}
@@ -0,0 +1,21 @@
+use std::env;
+use std::io;
+use std::process;
+
+use wordcount::Config;
+
+fn main() {
+    let config = Config::build(env::args()).unwrap_or_else(|err| {
+        eprintln!("Problem parsing arguments: {err}");
+        eprintln!("Usage: wordcount [-l] [-w] [-m] [-c] [FILE...]");
+        process::exit(2);
+    });
+    let (output, errors) = wordcount::run(&config, io::stdin().lock());
+    print!("{output}");
+    for err in &errors {
+        eprintln!("wordcount: {err}");
+    }
+    if !errors.is_empty() {
+        process::exit(1);
+    }
+}
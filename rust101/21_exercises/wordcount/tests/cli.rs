@@ -0,0 +1,91 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn wordcount(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_wordcount"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    assert_eq!(output.status.code(), Some(0), "{output:?}");
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn a_file_without_a_trailing_newline() {
+    assert_eq!(
+        stdout(&wordcount(&["tests/data/no_newline.txt"])),
+        " 1  5 23 tests/data/no_newline.txt\n"
+    );
+}
+
+#[test]
+fn a_file_of_only_whitespace() {
+    assert_eq!(
+        stdout(&wordcount(&["tests/data/whitespace.txt"])),
+        " 3  0 10 tests/data/whitespace.txt\n"
+    );
+}
+
+#[test]
+fn chars_and_bytes_differ_for_utf8() {
+    assert_eq!(
+        stdout(&wordcount(&["-m", "tests/data/utf8.txt"])),
+        "26 tests/data/utf8.txt\n"
+    );
+    assert_eq!(
+        stdout(&wordcount(&["-c", "tests/data/utf8.txt"])),
+        "46 tests/data/utf8.txt\n"
+    );
+    assert_eq!(
+        stdout(&wordcount(&["-lwmc", "tests/data/utf8.txt"])),
+        " 3  6 26 46 tests/data/utf8.txt\n"
+    );
+}
+
+#[test]
+fn totals_three_files() {
+    assert_eq!(
+        stdout(&wordcount(&[
+            "tests/data/no_newline.txt",
+            "tests/data/whitespace.txt",
+            "tests/data/utf8.txt",
+        ])),
+        " 1  5 23 tests/data/no_newline.txt\n \
+         3  0 10 tests/data/whitespace.txt\n \
+         3  6 46 tests/data/utf8.txt\n \
+         7 11 79 total\n"
+    );
+}
+
+#[test]
+fn reads_stdin_without_files() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_wordcount"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all("héllo wörld\n".as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(stdout(&output), "      1       2      14\n");
+}
+
+#[test]
+fn missing_files_are_reported_and_fail_the_run() {
+    let output = wordcount(&["tests/data/utf8.txt", "tests/data/missing.txt"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        " 3  6 46 tests/data/utf8.txt\n 3  6 46 total\n"
+    );
+    assert!(
+        String::from_utf8_lossy(&output.stderr).starts_with("wordcount: tests/data/missing.txt: ")
+    );
+}